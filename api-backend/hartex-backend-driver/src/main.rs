@@ -36,12 +36,18 @@ use std::env;
 use std::future;
 use std::time::Duration;
 
+use axum::middleware::from_fn;
+use axum::middleware::from_fn_with_state;
+use axum::routing::get;
 use axum::routing::post;
+use axum::routing::put;
 use axum::Router;
 use bb8_postgres::bb8::Pool;
 use bb8_postgres::tokio_postgres::NoTls;
 use bb8_postgres::PostgresConnectionManager;
 use dotenvy::Error;
+use hartex_backend_layers::auth::authenticate;
+use hartex_backend_layers::auth::authorize_dashboard_admin;
 use hartex_errors::dotenv;
 use hartex_log::log;
 use miette::IntoDiagnostic;
@@ -77,6 +83,13 @@ pub async fn main() -> miette::Result<()> {
     let manager = PostgresConnectionManager::new_from_stringlike(api_pgsql_url, NoTls).into_diagnostic()?;
     let pool = Pool::builder().build(manager).await.into_diagnostic()?;
 
+    let hartex_pgsql_url = env::var("HARTEX_NIGHTLY_PGSQL_URL").into_diagnostic()?;
+
+    log::debug!("building discord frontend database connection pool");
+    let manager = PostgresConnectionManager::new_from_stringlike(hartex_pgsql_url, NoTls)
+        .into_diagnostic()?;
+    let discord_frontend_pool = Pool::builder().build(manager).await.into_diagnostic()?;
+
     log::debug!("starting axum server");
     let app = Router::new()
         .layer(TraceLayer::new_for_http())
@@ -86,7 +99,42 @@ pub async fn main() -> miette::Result<()> {
             post(hartex_backend_routes::uptime::post_uptime)
                 .patch(hartex_backend_routes::uptime::patch_uptime),
         )
-        .with_state(pool);
+        .with_state(pool)
+        .merge(
+            Router::new()
+                .route(
+                    "/api/:version/stats/commands",
+                    get(hartex_backend_routes::commands::get_command_usage),
+                )
+                .route_layer(from_fn(authenticate))
+                .merge(
+                    Router::new()
+                        .route(
+                            "/api/:version/guilds/:guild_id/stats/commands",
+                            get(hartex_backend_routes::commands::get_guild_command_usage),
+                        )
+                        .route(
+                            "/api/:version/guilds/:guild_id/configuration",
+                            put(hartex_backend_routes::configuration::put_configuration),
+                        )
+                        .route(
+                            "/api/:version/guilds/:guild_id/users/:user_id/infractions",
+                            get(hartex_backend_routes::infractions::get_infraction_history),
+                        )
+                        .route(
+                            "/api/:version/guilds/:guild_id/infractions/:case_number",
+                            get(hartex_backend_routes::infractions::get_infraction)
+                                .patch(hartex_backend_routes::infractions::patch_infraction)
+                                .delete(hartex_backend_routes::infractions::delete_infraction),
+                        )
+                        .route_layer(from_fn_with_state(
+                            discord_frontend_pool.clone(),
+                            authorize_dashboard_admin,
+                        ))
+                        .route_layer(from_fn(authenticate)),
+                )
+                .with_state(discord_frontend_pool),
+        );
 
     let domain = env::var("API_DOMAIN").into_diagnostic()?;
     let listener = TcpListener::bind(&domain).await.into_diagnostic()?;
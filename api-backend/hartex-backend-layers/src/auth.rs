@@ -0,0 +1,376 @@
+/*
+ * SPDX-License-Identifier: AGPL-3.0-only
+ *
+ * This file is part of HarTex.
+ *
+ * HarTex
+ * Copyright (c) 2021-2024 HarTex Project Developers
+ *
+ * HarTex is free software; you can redistribute it and/or modify
+ * it under the terms of the GNU Affero General Public License as published by
+ * the Free Software Foundation; either version 3 of the License, or
+ * (at your option) any later version.
+ *
+ * HarTex is distributed in the hope that it will be useful,
+ * but WITHOUT ANY WARRANTY; without even the implied warranty of
+ * MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+ * GNU Affero General Public License for more details.
+ *
+ * You should have received a copy of the GNU Affero General Public License along
+ * with HarTex. If not, see <https://www.gnu.org/licenses/>.
+ */
+
+//! # Authentication Layers
+//!
+//! Layers authenticating requests by the Discord OAuth2 access token of the user, and authorizing
+//! requests to the routes of a guild to the administrators of its dashboard and its owner.
+
+use std::collections::HashMap;
+use std::sync::Arc;
+use std::sync::LazyLock;
+use std::sync::Mutex;
+use std::time::Duration;
+use std::time::Instant;
+
+use axum::async_trait;
+use axum::extract::FromRequestParts;
+use axum::extract::Path;
+use axum::extract::Request;
+use axum::extract::State;
+use axum::http::request::Parts;
+use axum::http::StatusCode;
+use axum::middleware::Next;
+use axum::response::IntoResponse;
+use axum::response::Response as AxumResponse;
+use bb8_postgres::bb8::Pool;
+use bb8_postgres::tokio_postgres::Error;
+use bb8_postgres::tokio_postgres::GenericClient;
+use bb8_postgres::tokio_postgres::NoTls;
+use bb8_postgres::PostgresConnectionManager;
+use hartex_backend_models::Response;
+use hartex_database_queries::configuration::queries::dashboard_admins_and_enabled_plugins_select_by_guild_id::dashboard_admins_and_enabled_plugins_select_by_guild_id;
+use hartex_database_queries::discord_frontend::queries::cached_guild_select_by_id::cached_guild_select_by_id;
+use hartex_log::log;
+use http_body_util::BodyExt;
+use http_body_util::Collected;
+use http_body_util::Empty;
+use hyper::body::Buf;
+use hyper::body::Bytes;
+use hyper::body::Incoming;
+use hyper::client::conn::http1::handshake;
+use hyper::client::conn::http1::SendRequest;
+use hyper::header::AUTHORIZATION;
+use hyper::header::HOST;
+use hyper::header::USER_AGENT;
+use hyper_util::rt::TokioIo;
+use miette::IntoDiagnostic;
+use serde::Deserialize;
+use tokio::net::TcpStream;
+use tokio_rustls::rustls::pki_types::ServerName;
+use tokio_rustls::rustls::ClientConfig;
+use tokio_rustls::rustls::RootCertStore;
+use tokio_rustls::TlsConnector;
+use webpki_roots::TLS_SERVER_ROOTS;
+
+/// The host of the Discord API.
+const DISCORD_HOST: &str = "discord.com";
+
+/// The user agent of the requests made to the Discord API.
+const USER_AGENT_VALUE: &str = "DiscordBot (https://github.com/TeamHarTex/HarTex, v0.6.0) Backend";
+
+/// The duration for which the resolved user of an access token is reused.
+///
+/// This bounds how long a revoked access token keeps being accepted.
+const CURRENT_USER_TTL: Duration = Duration::from_secs(60);
+
+/// The maximum number of idle connections to the Discord API kept for reuse.
+const MAX_IDLE_CONNECTIONS: usize = 8;
+
+/// The client for the Discord API shared by every request.
+static DISCORD: LazyLock<DiscordClient> = LazyLock::new(DiscordClient::new);
+
+/// The resolved users of access tokens and when they were resolved, by access token.
+static CURRENT_USERS: LazyLock<Mutex<HashMap<String, (Instant, String)>>> =
+    LazyLock::new(|| Mutex::new(HashMap::new()));
+
+/// The authenticated user making a request.
+///
+/// This is inserted into the request by the [`authenticate`] layer, and extracting it from a
+/// request that has not been authenticated rejects the request.
+#[derive(Clone, Debug)]
+pub struct Principal {
+    user_id: String,
+}
+
+impl Principal {
+    /// The id of the authenticated user.
+    #[must_use]
+    pub fn user_id(&self) -> &str {
+        self.user_id.as_str()
+    }
+}
+
+#[async_trait]
+impl<S> FromRequestParts<S> for Principal
+where
+    S: Send + Sync,
+{
+    type Rejection = AxumResponse;
+
+    async fn from_request_parts(parts: &mut Parts, _: &S) -> Result<Self, Self::Rejection> {
+        parts.extensions.get::<Principal>().cloned().ok_or_else(|| {
+            (StatusCode::UNAUTHORIZED, Response::<()>::unauthorized()).into_response()
+        })
+    }
+}
+
+/// Authenticates a request by the Discord OAuth2 access token in its `Authorization` header,
+/// rejecting it if the token is missing or not accepted by Discord.
+pub async fn authenticate(mut request: Request, next: Next) -> AxumResponse {
+    let Some(token) = request
+        .headers()
+        .get(AUTHORIZATION)
+        .and_then(|value| value.to_str().ok())
+        .and_then(|value| value.strip_prefix("Bearer "))
+    else {
+        return (StatusCode::UNAUTHORIZED, Response::<()>::unauthorized()).into_response();
+    };
+
+    log::trace!("resolving the user of the access token");
+    let user_id = match current_user_id(token).await {
+        Ok(Some(user_id)) => user_id,
+        Ok(None) => {
+            return (StatusCode::UNAUTHORIZED, Response::<()>::unauthorized()).into_response();
+        }
+        Err(error) => {
+            log::error!("{error:?}");
+
+            return (
+                StatusCode::INTERNAL_SERVER_ERROR,
+                Response::<()>::internal_server_error(),
+            )
+                .into_response();
+        }
+    };
+
+    request.extensions_mut().insert(Principal { user_id });
+
+    next.run(request).await
+}
+
+/// Authorizes a request to the routes of a guild, rejecting it unless the authenticated user is
+/// an administrator of the dashboard of the guild or the owner of the guild.
+///
+/// The owner is always authorized such that the configuration of a guild that has not been
+/// configured yet, and thus has no administrators, can be stored.
+///
+/// This must be applied within the [`authenticate`] layer.
+pub async fn authorize_dashboard_admin(
+    State(pool): State<Pool<PostgresConnectionManager<NoTls>>>,
+    Path(parameters): Path<HashMap<String, String>>,
+    principal: Principal,
+    request: Request,
+    next: Next,
+) -> AxumResponse {
+    let Some(guild_id) = parameters.get("guild_id") else {
+        return (StatusCode::FORBIDDEN, Response::<()>::forbidden()).into_response();
+    };
+
+    log::trace!("retrieving connection from database pool");
+    let Ok(connection) = pool.get().await else {
+        return (
+            StatusCode::INTERNAL_SERVER_ERROR,
+            Response::<()>::internal_server_error(),
+        )
+            .into_response();
+    };
+
+    match authorized(connection.client(), guild_id, principal.user_id()).await {
+        Ok(true) => next.run(request).await,
+        Ok(false) => (StatusCode::FORBIDDEN, Response::<()>::forbidden()).into_response(),
+        Err(error) => {
+            log::error!("{error:?}");
+
+            (
+                StatusCode::INTERNAL_SERVER_ERROR,
+                Response::<()>::internal_server_error(),
+            )
+                .into_response()
+        }
+    }
+}
+
+/// Returns whether a user is an administrator of the dashboard of a guild or its owner.
+async fn authorized<C: cornucopia_async::GenericClient>(
+    client: &C,
+    guild_id: &str,
+    user_id: &str,
+) -> Result<bool, Error> {
+    log::trace!("querying dashboard admins");
+    let configuration = dashboard_admins_and_enabled_plugins_select_by_guild_id()
+        .bind(client, &guild_id)
+        .opt()
+        .await?;
+    if configuration.is_some_and(|configuration| {
+        configuration
+            .dashboard_admins
+            .iter()
+            .any(|admin_id| admin_id == user_id)
+    }) {
+        return Ok(true);
+    }
+
+    log::trace!("querying guild owner");
+    let guild = cached_guild_select_by_id()
+        .bind(client, &guild_id)
+        .opt()
+        .await?;
+
+    Ok(guild.is_some_and(|guild| guild.owner_id == user_id))
+}
+
+/// The current user of an access token, as returned by the Discord API.
+#[derive(Deserialize)]
+struct CurrentUser {
+    id: String,
+}
+
+/// A client for the Discord API, reusing its connections across requests.
+struct DiscordClient {
+    connector: TlsConnector,
+    idle: Mutex<Vec<SendRequest<Empty<Bytes>>>>,
+}
+
+impl DiscordClient {
+    /// Creates a client without any connections.
+    fn new() -> Self {
+        let mut root_cert_store = RootCertStore::empty();
+        root_cert_store.extend(TLS_SERVER_ROOTS.iter().cloned());
+        let tls_config = ClientConfig::builder()
+            .with_root_certificates(root_cert_store)
+            .with_no_client_auth();
+
+        Self {
+            connector: TlsConnector::from(Arc::new(tls_config)),
+            idle: Mutex::new(Vec::new()),
+        }
+    }
+
+    /// Sends a `GET` request to a path of the Discord API on behalf of the user of an access
+    /// token, returning the status and the body of the response.
+    ///
+    /// The request is sent over an idle connection if there is one, and over a new connection
+    /// should the idle connection have failed in the meantime.
+    async fn get(&self, path: &str, token: &str) -> miette::Result<(StatusCode, Collected<Bytes>)> {
+        let request = || {
+            hyper::Request::get(format!("https://{DISCORD_HOST}{path}"))
+                .header(HOST, DISCORD_HOST)
+                .header(AUTHORIZATION, format!("Bearer {token}"))
+                .header(USER_AGENT, USER_AGENT_VALUE)
+                .body(Empty::<Bytes>::new())
+                .into_diagnostic()
+        };
+
+        if let Some(mut sender) = self.idle_connection() {
+            let response = match sender.ready().await {
+                Ok(()) => sender.send_request(request()?).await,
+                Err(error) => Err(error),
+            };
+
+            match response {
+                Ok(response) => return self.read(sender, response).await,
+                Err(error) => log::trace!("idle connection to the discord api failed: {error}"),
+            }
+        }
+
+        let mut sender = self.connect().await?;
+        let response = sender.send_request(request()?).await.into_diagnostic()?;
+
+        self.read(sender, response).await
+    }
+
+    /// Opens a new connection to the Discord API.
+    async fn connect(&self) -> miette::Result<SendRequest<Empty<Bytes>>> {
+        let stream = TcpStream::connect((DISCORD_HOST, 443))
+            .await
+            .into_diagnostic()?;
+        let stream = self
+            .connector
+            .connect(
+                ServerName::try_from(DISCORD_HOST).into_diagnostic()?,
+                stream,
+            )
+            .await
+            .into_diagnostic()?;
+
+        let (sender, connection) = handshake(TokioIo::new(stream)).await.into_diagnostic()?;
+        tokio::spawn(async move {
+            if let Err(error) = connection.await {
+                log::error!("{error:?}");
+            }
+        });
+
+        Ok(sender)
+    }
+
+    /// Takes an idle connection that is still open, if any.
+    fn idle_connection(&self) -> Option<SendRequest<Empty<Bytes>>> {
+        let mut idle = self.idle.lock().unwrap();
+        while let Some(sender) = idle.pop() {
+            if !sender.is_closed() {
+                return Some(sender);
+            }
+        }
+
+        None
+    }
+
+    /// Reads the body of a response, after which its connection is kept for reuse.
+    async fn read(
+        &self,
+        sender: SendRequest<Empty<Bytes>>,
+        response: hyper::Response<Incoming>,
+    ) -> miette::Result<(StatusCode, Collected<Bytes>)> {
+        let status = response.status();
+        let body = response.into_body().collect().await.into_diagnostic()?;
+
+        let mut idle = self.idle.lock().unwrap();
+        if !sender.is_closed() && idle.len() < MAX_IDLE_CONNECTIONS {
+            idle.push(sender);
+        }
+
+        Ok((status, body))
+    }
+}
+
+/// Resolves the id of the user of a Discord OAuth2 access token, or `None` if Discord does not
+/// accept the token.
+///
+/// Resolved users are cached for [`CURRENT_USER_TTL`], such that consecutive requests with the
+/// same token do not each reach the Discord API.
+async fn current_user_id(token: &str) -> miette::Result<Option<String>> {
+    if let Some((resolved_at, user_id)) = CURRENT_USERS.lock().unwrap().get(token) {
+        if resolved_at.elapsed() < CURRENT_USER_TTL {
+            return Ok(Some(user_id.clone()));
+        }
+    }
+
+    let (status, body) = DISCORD.get("/api/v10/users/@me", token).await?;
+    if status == StatusCode::UNAUTHORIZED {
+        return Ok(None);
+    }
+
+    if !status.is_success() {
+        miette::bail!(
+            "the discord api responded with status {status} when resolving an access token"
+        );
+    }
+
+    let user: CurrentUser = serde_json::from_reader(body.aggregate().reader()).into_diagnostic()?;
+
+    let mut users = CURRENT_USERS.lock().unwrap();
+    users.retain(|_, (resolved_at, _)| resolved_at.elapsed() < CURRENT_USER_TTL);
+    users.insert(token.to_string(), (Instant::now(), user.id.clone()));
+
+    Ok(Some(user.id))
+}
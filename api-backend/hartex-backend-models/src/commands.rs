@@ -0,0 +1,137 @@
+/*
+ * SPDX-License-Identifier: AGPL-3.0-only
+ *
+ * This file is part of HarTex.
+ *
+ * HarTex
+ * Copyright (c) 2021-2024 HarTex Project Developers
+ *
+ * HarTex is free software; you can redistribute it and/or modify
+ * it under the terms of the GNU Affero General Public License as published by
+ * the Free Software Foundation; either version 3 of the License, or
+ * (at your option) any later version.
+ *
+ * HarTex is distributed in the hope that it will be useful,
+ * but WITHOUT ANY WARRANTY; without even the implied warranty of
+ * MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+ * GNU Affero General Public License for more details.
+ *
+ * You should have received a copy of the GNU Affero General Public License along
+ * with HarTex. If not, see <https://www.gnu.org/licenses/>.
+ */
+
+//! # Command Usage Models V2
+//!
+//! Models for the command usage API specification V2 of the backend.
+
+use serde::Deserialize;
+use serde::Serialize;
+
+/// The aggregated usage statistics of a command.
+#[derive(Clone, Deserialize, Serialize)]
+pub struct CommandUsage {
+    command: String,
+    invocations: i64,
+    failures: i64,
+    rejections: i64,
+    average_latency_ms: i64,
+}
+
+impl CommandUsage {
+    /// Constructs the usage statistics of a command from its aggregated rollups.
+    #[must_use]
+    pub fn new(
+        command: impl Into<String>,
+        invocations: i64,
+        failures: i64,
+        rejections: i64,
+        total_latency_ms: i64,
+    ) -> Self {
+        Self {
+            command: command.into(),
+            invocations,
+            failures,
+            rejections,
+            average_latency_ms: total_latency_ms / invocations.max(1),
+        }
+    }
+
+    /// The name of the command.
+    #[must_use]
+    pub fn command(&self) -> &str {
+        self.command.as_str()
+    }
+
+    /// The number of times the command has been invoked.
+    #[must_use]
+    pub fn invocations(&self) -> i64 {
+        self.invocations
+    }
+
+    /// The number of invocations that have failed.
+    #[must_use]
+    pub fn failures(&self) -> i64 {
+        self.failures
+    }
+
+    /// The number of invocations that have been rejected before the command was executed.
+    #[must_use]
+    pub fn rejections(&self) -> i64 {
+        self.rejections
+    }
+
+    /// The average latency of the invocations, in milliseconds.
+    #[must_use]
+    pub fn average_latency_ms(&self) -> i64 {
+        self.average_latency_ms
+    }
+}
+
+/// A query for command usage statistics.
+#[allow(clippy::module_name_repetitions)]
+#[derive(Deserialize, Serialize)]
+pub struct CommandUsageQuery {
+    days: Option<i64>,
+}
+
+impl CommandUsageQuery {
+    /// Create a new command usage query.
+    #[must_use]
+    pub fn new(days: Option<i64>) -> Self {
+        Self { days }
+    }
+
+    /// The number of days, including today, to aggregate the statistics over, at most 365.
+    #[must_use]
+    pub fn days(&self) -> i64 {
+        self.days.unwrap_or(30).clamp(1, 365)
+    }
+}
+
+/// A response to a command usage query.
+#[allow(clippy::module_name_repetitions)]
+#[derive(Clone, Deserialize, Serialize)]
+pub struct CommandUsageResponse {
+    commands: Vec<CommandUsage>,
+    days: i64,
+}
+
+impl CommandUsageResponse {
+    /// Constructs a response to a command usage query.
+    #[must_use]
+    pub fn new(commands: Vec<CommandUsage>, days: i64) -> Self {
+        Self { commands, days }
+    }
+
+    /// The usage statistics of each command, most invoked first.
+    #[must_use]
+    pub fn commands(&self) -> &[CommandUsage] {
+        self.commands.as_slice()
+    }
+
+    /// The number of days the statistics are aggregated over.
+    #[must_use]
+    pub fn days(&self) -> i64 {
+        self.days
+    }
+}
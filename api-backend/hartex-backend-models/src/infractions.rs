@@ -0,0 +1,212 @@
+/*
+ * SPDX-License-Identifier: AGPL-3.0-only
+ *
+ * This file is part of HarTex.
+ *
+ * HarTex
+ * Copyright (c) 2021-2024 HarTex Project Developers
+ *
+ * HarTex is free software; you can redistribute it and/or modify
+ * it under the terms of the GNU Affero General Public License as published by
+ * the Free Software Foundation; either version 3 of the License, or
+ * (at your option) any later version.
+ *
+ * HarTex is distributed in the hope that it will be useful,
+ * but WITHOUT ANY WARRANTY; without even the implied warranty of
+ * MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+ * GNU Affero General Public License for more details.
+ *
+ * You should have received a copy of the GNU Affero General Public License along
+ * with HarTex. If not, see <https://www.gnu.org/licenses/>.
+ */
+
+//! # Infraction Models V2
+//!
+//! Models for the infraction API specification V2 of the backend.
+
+use serde::Deserialize;
+use serde::Serialize;
+
+/// An infraction case.
+#[derive(Clone, Deserialize, Serialize)]
+pub struct Infraction {
+    case_number: i64,
+    user_id: String,
+    moderator_id: String,
+    kind: String,
+    reason: String,
+    created_at: i64,
+    pardoned: bool,
+}
+
+impl Infraction {
+    /// Constructs a new infraction case.
+    #[must_use]
+    pub fn new(
+        case_number: i64,
+        user_id: impl Into<String>,
+        moderator_id: impl Into<String>,
+        kind: impl Into<String>,
+        reason: impl Into<String>,
+        created_at: i64,
+        pardoned: bool,
+    ) -> Self {
+        Self {
+            case_number,
+            user_id: user_id.into(),
+            moderator_id: moderator_id.into(),
+            kind: kind.into(),
+            reason: reason.into(),
+            created_at,
+            pardoned,
+        }
+    }
+
+    /// The case number of the infraction, unique within a guild.
+    #[must_use]
+    pub fn case_number(&self) -> i64 {
+        self.case_number
+    }
+
+    /// The id of the user the infraction was issued to.
+    #[must_use]
+    pub fn user_id(&self) -> &str {
+        self.user_id.as_str()
+    }
+
+    /// The id of the moderator that issued the infraction.
+    #[must_use]
+    pub fn moderator_id(&self) -> &str {
+        self.moderator_id.as_str()
+    }
+
+    /// The kind of the infraction.
+    #[must_use]
+    pub fn kind(&self) -> &str {
+        self.kind.as_str()
+    }
+
+    /// The reason of the infraction.
+    #[must_use]
+    pub fn reason(&self) -> &str {
+        self.reason.as_str()
+    }
+
+    /// The unix timestamp at which the infraction was issued.
+    #[must_use]
+    pub fn created_at(&self) -> i64 {
+        self.created_at
+    }
+
+    /// Whether the infraction has been pardoned.
+    #[must_use]
+    pub fn pardoned(&self) -> bool {
+        self.pardoned
+    }
+}
+
+/// A query for the infraction history of a user.
+#[allow(clippy::module_name_repetitions)]
+#[derive(Deserialize, Serialize)]
+pub struct InfractionHistoryQuery {
+    page: Option<i64>,
+    per_page: Option<i64>,
+}
+
+impl InfractionHistoryQuery {
+    /// Create a new infraction history query.
+    #[must_use]
+    pub fn new(page: Option<i64>, per_page: Option<i64>) -> Self {
+        Self { page, per_page }
+    }
+
+    /// The page of the infraction history to return, starting from 1.
+    #[must_use]
+    pub fn page(&self) -> i64 {
+        self.page.unwrap_or(1).max(1)
+    }
+
+    /// The number of infractions to return per page, at most 50.
+    #[must_use]
+    pub fn per_page(&self) -> i64 {
+        self.per_page.unwrap_or(10).clamp(1, 50)
+    }
+}
+
+/// A response to an infraction history query.
+#[allow(clippy::module_name_repetitions)]
+#[derive(Clone, Deserialize, Serialize)]
+pub struct InfractionHistoryResponse {
+    infractions: Vec<Infraction>,
+    page: i64,
+    page_count: i64,
+    total: i64,
+}
+
+impl InfractionHistoryResponse {
+    /// Constructs a response to an infraction history query.
+    #[must_use]
+    pub fn new(infractions: Vec<Infraction>, page: i64, page_count: i64, total: i64) -> Self {
+        Self {
+            infractions,
+            page,
+            page_count,
+            total,
+        }
+    }
+
+    /// The infractions on the current page.
+    #[must_use]
+    pub fn infractions(&self) -> &[Infraction] {
+        self.infractions.as_slice()
+    }
+
+    /// The current page.
+    #[must_use]
+    pub fn page(&self) -> i64 {
+        self.page
+    }
+
+    /// The total number of pages.
+    #[must_use]
+    pub fn page_count(&self) -> i64 {
+        self.page_count
+    }
+
+    /// The total number of infractions of the user.
+    #[must_use]
+    pub fn total(&self) -> i64 {
+        self.total
+    }
+}
+
+/// An infraction update payload.
+///
+/// Every update is recorded in the infraction audit log under the authenticated user performing
+/// it.
+#[allow(clippy::module_name_repetitions)]
+#[derive(Clone, Deserialize, Serialize)]
+pub struct InfractionUpdate {
+    reason: Option<String>,
+    pardoned: Option<bool>,
+}
+
+impl InfractionUpdate {
+    /// Constructs a new infraction update payload.
+    #[must_use]
+    pub fn new(reason: Option<String>, pardoned: Option<bool>) -> Self {
+        Self { reason, pardoned }
+    }
+
+    /// The new reason of the infraction, if it is to be updated.
+    #[must_use]
+    pub fn reason(&self) -> Option<&str> {
+        self.reason.as_deref()
+    }
+
+    /// Whether the infraction is pardoned, if it is to be updated.
+    #[must_use]
+    pub fn pardoned(&self) -> Option<bool> {
+        self.pardoned
+    }
+}
@@ -43,6 +43,8 @@ use serde::Deserialize;
 use serde::Serialize;
 
 pub use hartex_discord_configuration_models as config;
+pub mod commands;
+pub mod infractions;
 pub mod uptime;
 
 /// Specifies the API version to be used for a given API request.
@@ -97,6 +99,42 @@ where
         })
     }
 
+    /// Constructs a response object with a status code of 400 and the given message.
+    pub fn bad_request(message: impl Into<String>) -> Json<Response<T>> {
+        Json(Self {
+            code: 400,
+            message: message.into(),
+            data: None,
+        })
+    }
+
+    /// Constructs a response object with a status code of 401 and its corresponding message.
+    pub fn unauthorized() -> Json<Response<T>> {
+        Json(Self {
+            code: 401,
+            message: String::from("unauthorized"),
+            data: None,
+        })
+    }
+
+    /// Constructs a response object with a status code of 403 and its corresponding message.
+    pub fn forbidden() -> Json<Response<T>> {
+        Json(Self {
+            code: 403,
+            message: String::from("forbidden"),
+            data: None,
+        })
+    }
+
+    /// Constructs a response object with a status code of 404 and its corresponding message.
+    pub fn not_found() -> Json<Response<T>> {
+        Json(Self {
+            code: 404,
+            message: String::from("not found"),
+            data: None,
+        })
+    }
+
     /// Constructs a response object with a status code of 200 and its corresponding message.
     pub fn ok(value: T) -> Json<Response<T>> {
         Json(Self {
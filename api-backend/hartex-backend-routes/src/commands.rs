@@ -0,0 +1,160 @@
+/*
+ * SPDX-License-Identifier: AGPL-3.0-only
+ *
+ * This file is part of HarTex.
+ *
+ * HarTex
+ * Copyright (c) 2021-2024 HarTex Project Developers
+ *
+ * HarTex is free software; you can redistribute it and/or modify
+ * it under the terms of the GNU Affero General Public License as published by
+ * the Free Software Foundation; either version 3 of the License, or
+ * (at your option) any later version.
+ *
+ * HarTex is distributed in the hope that it will be useful,
+ * but WITHOUT ANY WARRANTY; without even the implied warranty of
+ * MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+ * GNU Affero General Public License for more details.
+ *
+ * You should have received a copy of the GNU Affero General Public License along
+ * with HarTex. If not, see <https://www.gnu.org/licenses/>.
+ */
+
+//! # Command Usage Routes
+//!
+//! Routes interacting with the command usage API.
+
+use axum::extract::Path;
+use axum::extract::Query;
+use axum::extract::State;
+use axum::http::StatusCode;
+use axum::Json;
+use bb8_postgres::bb8::Pool;
+use bb8_postgres::tokio_postgres::GenericClient;
+use bb8_postgres::tokio_postgres::NoTls;
+use bb8_postgres::PostgresConnectionManager;
+use hartex_backend_models::commands::CommandUsage;
+use hartex_backend_models::commands::CommandUsageQuery;
+use hartex_backend_models::commands::CommandUsageResponse;
+use hartex_backend_models::APIVersion;
+use hartex_backend_models::Response;
+use hartex_database_queries::discord_frontend::queries::command_usage_rollup_select_by_guild_id_since::command_usage_rollup_select_by_guild_id_since;
+use hartex_database_queries::discord_frontend::queries::command_usage_rollup_select_since::command_usage_rollup_select_since;
+use hartex_log::log;
+use time::Duration;
+use time::OffsetDateTime;
+use time::Time;
+
+/// # `GET /stats/commands`
+///
+/// Obtain the usage statistics of every command across all guilds over the past days.
+#[allow(clippy::module_name_repetitions)]
+pub async fn get_command_usage(
+    _: APIVersion,
+    Query(query): Query<CommandUsageQuery>,
+    State(pool): State<Pool<PostgresConnectionManager<NoTls>>>,
+) -> (StatusCode, Json<Response<CommandUsageResponse>>) {
+    log::trace!("retrieving connection from database pool");
+    let Ok(connection) = pool.get().await else {
+        return (
+            StatusCode::INTERNAL_SERVER_ERROR,
+            Response::internal_server_error(),
+        );
+    };
+    let client = connection.client();
+
+    log::trace!("querying command usage rollups");
+    let result = command_usage_rollup_select_since()
+        .bind(client, &since(query.days()))
+        .all()
+        .await;
+    let usages = match result {
+        Ok(usages) => usages,
+        Err(error) => {
+            log::error!("{error:?}");
+
+            return (
+                StatusCode::INTERNAL_SERVER_ERROR,
+                Response::internal_server_error(),
+            );
+        }
+    };
+
+    let commands = usages
+        .into_iter()
+        .map(|usage| {
+            CommandUsage::new(
+                usage.command,
+                usage.invocations,
+                usage.failures,
+                usage.rejections,
+                usage.total_latency_ms,
+            )
+        })
+        .collect();
+
+    (
+        StatusCode::OK,
+        Response::ok(CommandUsageResponse::new(commands, query.days())),
+    )
+}
+
+/// # `GET /guilds/:guild_id/stats/commands`
+///
+/// Obtain the usage statistics of every command in a guild over the past days.
+#[allow(clippy::module_name_repetitions)]
+pub async fn get_guild_command_usage(
+    _: APIVersion,
+    Path((_, guild_id)): Path<(String, String)>,
+    Query(query): Query<CommandUsageQuery>,
+    State(pool): State<Pool<PostgresConnectionManager<NoTls>>>,
+) -> (StatusCode, Json<Response<CommandUsageResponse>>) {
+    log::trace!("retrieving connection from database pool");
+    let Ok(connection) = pool.get().await else {
+        return (
+            StatusCode::INTERNAL_SERVER_ERROR,
+            Response::internal_server_error(),
+        );
+    };
+    let client = connection.client();
+
+    log::trace!("querying command usage rollups");
+    let result = command_usage_rollup_select_by_guild_id_since()
+        .bind(client, &guild_id, &since(query.days()))
+        .all()
+        .await;
+    let usages = match result {
+        Ok(usages) => usages,
+        Err(error) => {
+            log::error!("{error:?}");
+
+            return (
+                StatusCode::INTERNAL_SERVER_ERROR,
+                Response::internal_server_error(),
+            );
+        }
+    };
+
+    let commands = usages
+        .into_iter()
+        .map(|usage| {
+            CommandUsage::new(
+                usage.command,
+                usage.invocations,
+                usage.failures,
+                usage.rejections,
+                usage.total_latency_ms,
+            )
+        })
+        .collect();
+
+    (
+        StatusCode::OK,
+        Response::ok(CommandUsageResponse::new(commands, query.days())),
+    )
+}
+
+/// Returns the start of the first day of the given number of days, including today.
+fn since(days: i64) -> OffsetDateTime {
+    OffsetDateTime::now_utc().replace_time(Time::MIDNIGHT) - Duration::days(days - 1)
+}
@@ -0,0 +1,212 @@
+/*
+ * SPDX-License-Identifier: AGPL-3.0-only
+ *
+ * This file is part of HarTex.
+ *
+ * HarTex
+ * Copyright (c) 2021-2024 HarTex Project Developers
+ *
+ * HarTex is free software; you can redistribute it and/or modify
+ * it under the terms of the GNU Affero General Public License as published by
+ * the Free Software Foundation; either version 3 of the License, or
+ * (at your option) any later version.
+ *
+ * HarTex is distributed in the hope that it will be useful,
+ * but WITHOUT ANY WARRANTY; without even the implied warranty of
+ * MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+ * GNU Affero General Public License for more details.
+ *
+ * You should have received a copy of the GNU Affero General Public License along
+ * with HarTex. If not, see <https://www.gnu.org/licenses/>.
+ */
+
+//! # Configuration Routes
+//!
+//! Routes interacting with the configuration API.
+
+use axum::extract::Path;
+use axum::extract::State;
+use axum::http::StatusCode;
+use axum::Json;
+use bb8_postgres::bb8::Pool;
+use bb8_postgres::tokio_postgres::Error;
+use bb8_postgres::tokio_postgres::NoTls;
+use bb8_postgres::tokio_postgres::Transaction;
+use bb8_postgres::PostgresConnectionManager;
+use hartex_backend_models::config::plugins::modlog::logger::ModlogFormat;
+use hartex_backend_models::config::Configuration;
+use hartex_backend_models::APIVersion;
+use hartex_backend_models::Response;
+use hartex_database_queries::configuration::queries::guild_configuration_upsert::guild_configuration_upsert;
+use hartex_database_queries::configuration::queries::modlog_logger_insert::modlog_logger_insert;
+use hartex_database_queries::configuration::queries::modlog_loggers_delete_by_guild_id::modlog_loggers_delete_by_guild_id;
+use hartex_database_queries::configuration::queries::permission_level_insert::permission_level_insert;
+use hartex_database_queries::configuration::queries::permission_level_override_insert::permission_level_override_insert;
+use hartex_database_queries::configuration::queries::permission_level_overrides_delete_by_guild_id::permission_level_overrides_delete_by_guild_id;
+use hartex_database_queries::configuration::queries::permission_levels_delete_by_guild_id::permission_levels_delete_by_guild_id;
+use hartex_discord_configuration_luart::evaluate_config;
+use hartex_log::log;
+
+/// # `PUT /guilds/:guild_id/configuration`
+///
+/// Store the configuration of a guild, given as its Lua source in the request body.
+///
+/// The configuration is evaluated before being stored, and is rejected if it fails to evaluate.
+/// Storing the configuration notifies every worker of the update.
+#[allow(clippy::module_name_repetitions)]
+pub async fn put_configuration(
+    _: APIVersion,
+    Path((_, guild_id)): Path<(String, String)>,
+    State(pool): State<Pool<PostgresConnectionManager<NoTls>>>,
+    source: String,
+) -> (StatusCode, Json<Response<()>>) {
+    log::trace!("evaluating configuration");
+    let configuration = match evaluate_config(&source) {
+        Ok(configuration) => configuration,
+        Err(error) => {
+            return (
+                StatusCode::BAD_REQUEST,
+                Response::bad_request(error.to_string()),
+            );
+        }
+    };
+
+    log::trace!("retrieving connection from database pool");
+    let Ok(mut connection) = pool.get().await else {
+        return (
+            StatusCode::INTERNAL_SERVER_ERROR,
+            Response::internal_server_error(),
+        );
+    };
+    let Ok(transaction) = connection.transaction().await else {
+        return (
+            StatusCode::INTERNAL_SERVER_ERROR,
+            Response::internal_server_error(),
+        );
+    };
+
+    log::trace!("storing configuration");
+    let result = match store(&transaction, &guild_id, configuration).await {
+        Ok(()) => transaction.commit().await,
+        Err(error) => Err(error),
+    };
+
+    if let Err(error) = result {
+        log::error!("{error:?}");
+
+        return (
+            StatusCode::INTERNAL_SERVER_ERROR,
+            Response::internal_server_error(),
+        );
+    }
+
+    (StatusCode::OK, Response::ok(()))
+}
+
+/// Stores every section of the configuration of a guild, replacing the sections stored before.
+async fn store(
+    transaction: &Transaction<'_>,
+    guild_id: &str,
+    configuration: Configuration,
+) -> Result<(), Error> {
+    let appearance = configuration.appearance;
+    let permissions = configuration.permissions;
+    let plugins = configuration.plugins;
+
+    let mut enabled_plugins = Vec::new();
+    if let Some(plugins) = &plugins {
+        if plugins
+            .management
+            .as_ref()
+            .is_some_and(|plugin| plugin.enabled)
+        {
+            enabled_plugins.push("management");
+        }
+
+        if plugins.modlog.as_ref().is_some_and(|plugin| plugin.enabled) {
+            enabled_plugins.push("modlog");
+        }
+
+        if plugins
+            .utilities
+            .as_ref()
+            .is_some_and(|plugin| plugin.enabled)
+        {
+            enabled_plugins.push("utilities");
+        }
+    }
+
+    guild_configuration_upsert()
+        .bind(
+            transaction,
+            &guild_id,
+            &configuration.dashboard.admins,
+            &configuration.dashboard.editors.unwrap_or_default(),
+            &configuration.dashboard.viewers.unwrap_or_default(),
+            &appearance
+                .as_ref()
+                .and_then(|appearance| appearance.nickname.clone())
+                .unwrap_or_default(),
+            &appearance
+                .and_then(|appearance| appearance.colour)
+                .map_or(0, i64::from),
+            &enabled_plugins,
+        )
+        .await?;
+
+    modlog_loggers_delete_by_guild_id()
+        .bind(transaction, &guild_id)
+        .await?;
+
+    for logger in plugins
+        .and_then(|plugins| plugins.modlog)
+        .map(|modlog| modlog.loggers)
+        .unwrap_or_default()
+    {
+        let events = logger
+            .events
+            .iter_names()
+            .map(|(name, _)| name)
+            .collect::<Vec<_>>();
+        let format = match logger.format {
+            ModlogFormat::Default => "default",
+            ModlogFormat::Pretty => "pretty",
+        };
+
+        modlog_logger_insert()
+            .bind(transaction, &guild_id, &logger.channel, &events, &format)
+            .await?;
+    }
+
+    permission_levels_delete_by_guild_id()
+        .bind(transaction, &guild_id)
+        .await?;
+    permission_level_overrides_delete_by_guild_id()
+        .bind(transaction, &guild_id)
+        .await?;
+
+    let Some(permissions) = permissions else {
+        return Ok(());
+    };
+
+    for (kind, levels) in [("role", permissions.roles), ("user", permissions.users)] {
+        for (target_id, level) in levels.unwrap_or_default() {
+            permission_level_insert()
+                .bind(transaction, &guild_id, &kind, &target_id, &i16::from(level))
+                .await?;
+        }
+    }
+
+    for (kind, levels) in [
+        ("plugin", permissions.plugins),
+        ("command", permissions.commands),
+    ] {
+        for (name, level) in levels.unwrap_or_default() {
+            permission_level_override_insert()
+                .bind(transaction, &guild_id, &kind, &name, &i16::from(level))
+                .await?;
+        }
+    }
+
+    Ok(())
+}
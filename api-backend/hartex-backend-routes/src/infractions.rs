@@ -0,0 +1,398 @@
+/*
+ * SPDX-License-Identifier: AGPL-3.0-only
+ *
+ * This file is part of HarTex.
+ *
+ * HarTex
+ * Copyright (c) 2021-2024 HarTex Project Developers
+ *
+ * HarTex is free software; you can redistribute it and/or modify
+ * it under the terms of the GNU Affero General Public License as published by
+ * the Free Software Foundation; either version 3 of the License, or
+ * (at your option) any later version.
+ *
+ * HarTex is distributed in the hope that it will be useful,
+ * but WITHOUT ANY WARRANTY; without even the implied warranty of
+ * MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+ * GNU Affero General Public License for more details.
+ *
+ * You should have received a copy of the GNU Affero General Public License along
+ * with HarTex. If not, see <https://www.gnu.org/licenses/>.
+ */
+
+//! # Infraction Routes
+//!
+//! Routes interacting with the infraction API.
+
+use axum::extract::Path;
+use axum::extract::Query;
+use axum::extract::State;
+use axum::http::StatusCode;
+use axum::Json;
+use bb8_postgres::bb8::Pool;
+use bb8_postgres::tokio_postgres::Error;
+use bb8_postgres::tokio_postgres::GenericClient;
+use bb8_postgres::tokio_postgres::NoTls;
+use bb8_postgres::tokio_postgres::Transaction;
+use bb8_postgres::PostgresConnectionManager;
+use hartex_backend_layers::auth::Principal;
+use hartex_backend_models::infractions::Infraction;
+use hartex_backend_models::infractions::InfractionHistoryQuery;
+use hartex_backend_models::infractions::InfractionHistoryResponse;
+use hartex_backend_models::infractions::InfractionUpdate;
+use hartex_backend_models::APIVersion;
+use hartex_backend_models::Response;
+use hartex_database_queries::discord_frontend::queries::infraction_audit_log_insert::infraction_audit_log_insert;
+use hartex_database_queries::discord_frontend::queries::infraction_count_by_user_id_and_guild_id::infraction_count_by_user_id_and_guild_id;
+use hartex_database_queries::discord_frontend::queries::infraction_delete::infraction_delete;
+use hartex_database_queries::discord_frontend::queries::infraction_select_by_case_number_and_guild_id::infraction_select_by_case_number_and_guild_id;
+use hartex_database_queries::discord_frontend::queries::infraction_select_by_user_id_and_guild_id::infraction_select_by_user_id_and_guild_id;
+use hartex_database_queries::discord_frontend::queries::infraction_update_pardoned::infraction_update_pardoned;
+use hartex_database_queries::discord_frontend::queries::infraction_update_reason::infraction_update_reason;
+use hartex_log::log;
+use time::OffsetDateTime;
+
+/// # `GET /guilds/:guild_id/users/:user_id/infractions`
+///
+/// Obtain the infraction history of a user in a guild, paginated.
+#[allow(clippy::module_name_repetitions)]
+pub async fn get_infraction_history(
+    _: APIVersion,
+    Path((_, guild_id, user_id)): Path<(String, String, String)>,
+    Query(query): Query<InfractionHistoryQuery>,
+    State(pool): State<Pool<PostgresConnectionManager<NoTls>>>,
+) -> (StatusCode, Json<Response<InfractionHistoryResponse>>) {
+    log::trace!("retrieving connection from database pool");
+    let Ok(connection) = pool.get().await else {
+        return (
+            StatusCode::INTERNAL_SERVER_ERROR,
+            Response::internal_server_error(),
+        );
+    };
+    let client = connection.client();
+
+    log::trace!("querying infraction count");
+    let total = match infraction_count_by_user_id_and_guild_id()
+        .bind(client, &user_id, &guild_id)
+        .one()
+        .await
+    {
+        Ok(total) => total,
+        Err(error) => {
+            log::error!("{error:?}");
+
+            return (
+                StatusCode::INTERNAL_SERVER_ERROR,
+                Response::internal_server_error(),
+            );
+        }
+    };
+
+    let per_page = query.per_page();
+    let page_count = ((total + per_page - 1) / per_page).max(1);
+    let page = query.page().min(page_count);
+
+    log::trace!("querying infractions");
+    let result = infraction_select_by_user_id_and_guild_id()
+        .bind(
+            client,
+            &user_id,
+            &guild_id,
+            &per_page,
+            &((page - 1) * per_page),
+        )
+        .all()
+        .await;
+    let infractions = match result {
+        Ok(infractions) => infractions,
+        Err(error) => {
+            log::error!("{error:?}");
+
+            return (
+                StatusCode::INTERNAL_SERVER_ERROR,
+                Response::internal_server_error(),
+            );
+        }
+    };
+
+    let infractions = infractions
+        .into_iter()
+        .map(|infraction| {
+            Infraction::new(
+                infraction.case_number,
+                infraction.user_id,
+                infraction.moderator_id,
+                infraction.kind,
+                infraction.reason,
+                infraction.created_at.unix_timestamp(),
+                infraction.pardoned,
+            )
+        })
+        .collect();
+
+    (
+        StatusCode::OK,
+        Response::ok(InfractionHistoryResponse::new(
+            infractions,
+            page,
+            page_count,
+            total,
+        )),
+    )
+}
+
+/// # `GET /guilds/:guild_id/infractions/:case_number`
+///
+/// Obtain an infraction case of a guild by its case number.
+#[allow(clippy::module_name_repetitions)]
+pub async fn get_infraction(
+    _: APIVersion,
+    Path((_, guild_id, case_number)): Path<(String, String, i64)>,
+    State(pool): State<Pool<PostgresConnectionManager<NoTls>>>,
+) -> (StatusCode, Json<Response<Infraction>>) {
+    log::trace!("retrieving connection from database pool");
+    let Ok(connection) = pool.get().await else {
+        return (
+            StatusCode::INTERNAL_SERVER_ERROR,
+            Response::internal_server_error(),
+        );
+    };
+    let client = connection.client();
+
+    log::trace!("querying infraction");
+    match select_infraction(client, &guild_id, case_number).await {
+        Ok(Some(infraction)) => (StatusCode::OK, Response::ok(infraction)),
+        Ok(None) => (StatusCode::NOT_FOUND, Response::not_found()),
+        Err(error) => {
+            log::error!("{error:?}");
+
+            (
+                StatusCode::INTERNAL_SERVER_ERROR,
+                Response::internal_server_error(),
+            )
+        }
+    }
+}
+
+/// # `PATCH /guilds/:guild_id/infractions/:case_number`
+///
+/// Update the reason or the pardoned status of an infraction case. Every change made is recorded
+/// in the infraction audit log under the authenticated user.
+#[allow(clippy::module_name_repetitions)]
+pub async fn patch_infraction(
+    _: APIVersion,
+    Path((_, guild_id, case_number)): Path<(String, String, i64)>,
+    principal: Principal,
+    State(pool): State<Pool<PostgresConnectionManager<NoTls>>>,
+    Json(update): Json<InfractionUpdate>,
+) -> (StatusCode, Json<Response<Infraction>>) {
+    log::trace!("retrieving connection from database pool");
+    let Ok(mut connection) = pool.get().await else {
+        return (
+            StatusCode::INTERNAL_SERVER_ERROR,
+            Response::internal_server_error(),
+        );
+    };
+    let Ok(transaction) = connection.transaction().await else {
+        return (
+            StatusCode::INTERNAL_SERVER_ERROR,
+            Response::internal_server_error(),
+        );
+    };
+
+    log::trace!("updating infraction");
+    let result = async {
+        let Some(infraction) = select_infraction(&transaction, &guild_id, case_number).await?
+        else {
+            return Ok(None);
+        };
+
+        if let Some(reason) = update
+            .reason()
+            .filter(|reason| *reason != infraction.reason())
+        {
+            infraction_update_reason()
+                .bind(&transaction, &reason, &case_number, &guild_id)
+                .await?;
+            audit(
+                &transaction,
+                &guild_id,
+                case_number,
+                principal.user_id(),
+                "reason",
+                Some(infraction.reason().to_string()),
+                Some(reason.to_string()),
+            )
+            .await?;
+        }
+
+        if let Some(pardoned) = update
+            .pardoned()
+            .filter(|pardoned| *pardoned != infraction.pardoned())
+        {
+            infraction_update_pardoned()
+                .bind(&transaction, &pardoned, &case_number, &guild_id)
+                .await?;
+            audit(
+                &transaction,
+                &guild_id,
+                case_number,
+                principal.user_id(),
+                if pardoned { "pardon" } else { "unpardon" },
+                None,
+                None,
+            )
+            .await?;
+        }
+
+        select_infraction(&transaction, &guild_id, case_number).await
+    }
+    .await;
+
+    match result {
+        Ok(Some(infraction)) => {
+            if let Err(error) = transaction.commit().await {
+                log::error!("{error:?}");
+
+                return (
+                    StatusCode::INTERNAL_SERVER_ERROR,
+                    Response::internal_server_error(),
+                );
+            }
+
+            (StatusCode::OK, Response::ok(infraction))
+        }
+        Ok(None) => (StatusCode::NOT_FOUND, Response::not_found()),
+        Err(error) => {
+            log::error!("{error:?}");
+
+            (
+                StatusCode::INTERNAL_SERVER_ERROR,
+                Response::internal_server_error(),
+            )
+        }
+    }
+}
+
+/// # `DELETE /guilds/:guild_id/infractions/:case_number`
+///
+/// Delete an infraction case. The deletion is recorded in the infraction audit log under the
+/// authenticated user.
+#[allow(clippy::module_name_repetitions)]
+pub async fn delete_infraction(
+    _: APIVersion,
+    Path((_, guild_id, case_number)): Path<(String, String, i64)>,
+    principal: Principal,
+    State(pool): State<Pool<PostgresConnectionManager<NoTls>>>,
+) -> (StatusCode, Json<Response<()>>) {
+    log::trace!("retrieving connection from database pool");
+    let Ok(mut connection) = pool.get().await else {
+        return (
+            StatusCode::INTERNAL_SERVER_ERROR,
+            Response::internal_server_error(),
+        );
+    };
+    let Ok(transaction) = connection.transaction().await else {
+        return (
+            StatusCode::INTERNAL_SERVER_ERROR,
+            Response::internal_server_error(),
+        );
+    };
+
+    log::trace!("deleting infraction");
+    let result = async {
+        let Some(infraction) = select_infraction(&transaction, &guild_id, case_number).await?
+        else {
+            return Ok(false);
+        };
+
+        infraction_delete()
+            .bind(&transaction, &case_number, &guild_id)
+            .await?;
+        audit(
+            &transaction,
+            &guild_id,
+            case_number,
+            principal.user_id(),
+            "delete",
+            Some(infraction.reason().to_string()),
+            None,
+        )
+        .await?;
+
+        Ok::<_, Error>(true)
+    }
+    .await;
+
+    match result {
+        Ok(true) => {
+            if let Err(error) = transaction.commit().await {
+                log::error!("{error:?}");
+
+                return (
+                    StatusCode::INTERNAL_SERVER_ERROR,
+                    Response::internal_server_error(),
+                );
+            }
+
+            (StatusCode::OK, Response::ok(()))
+        }
+        Ok(false) => (StatusCode::NOT_FOUND, Response::not_found()),
+        Err(error) => {
+            log::error!("{error:?}");
+
+            (
+                StatusCode::INTERNAL_SERVER_ERROR,
+                Response::internal_server_error(),
+            )
+        }
+    }
+}
+
+/// Selects an infraction case by its case number.
+async fn select_infraction<C: cornucopia_async::GenericClient>(
+    client: &C,
+    guild_id: &str,
+    case_number: i64,
+) -> Result<Option<Infraction>, Error> {
+    let infraction = infraction_select_by_case_number_and_guild_id()
+        .bind(client, &case_number, &guild_id)
+        .opt()
+        .await?;
+
+    Ok(infraction.map(|infraction| {
+        Infraction::new(
+            infraction.case_number,
+            infraction.user_id,
+            infraction.moderator_id,
+            infraction.kind,
+            infraction.reason,
+            infraction.created_at.unix_timestamp(),
+            infraction.pardoned,
+        )
+    }))
+}
+
+/// Records a mutation performed on an infraction case in the audit log.
+async fn audit(
+    transaction: &Transaction<'_>,
+    guild_id: &str,
+    case_number: i64,
+    actor_id: &str,
+    action: &str,
+    old_reason: Option<String>,
+    new_reason: Option<String>,
+) -> Result<u64, Error> {
+    infraction_audit_log_insert()
+        .bind(
+            transaction,
+            &guild_id,
+            &case_number,
+            &actor_id,
+            &action,
+            &old_reason,
+            &new_reason,
+            &OffsetDateTime::now_utc(),
+        )
+        .await
+}
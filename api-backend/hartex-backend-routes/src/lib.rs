@@ -28,4 +28,7 @@
 #![deny(unsafe_code)]
 #![deny(warnings)]
 
+pub mod commands;
+pub mod configuration;
+pub mod infractions;
 pub mod uptime;
@@ -0,0 +1,31 @@
+CREATE TABLE IF NOT EXISTS "Nightly"."CommandUsages" (
+    "command" TEXT NOT NULL,
+    "subcommand" TEXT,
+    "guild_id" TEXT,
+    "locale" TEXT NOT NULL,
+    "latency_ms" BIGINT NOT NULL,
+    "outcome" TEXT NOT NULL,
+    "error_hash" TEXT,
+    "invoked_at" TIMESTAMP WITH TIME ZONE NOT NULL
+);
+
+CREATE INDEX IF NOT EXISTS "CommandUsagesCommandInvokedAtIndex"
+ON "Nightly"."CommandUsages" ("command", "invoked_at");
+
+CREATE INDEX IF NOT EXISTS "CommandUsagesGuildIdInvokedAtIndex"
+ON "Nightly"."CommandUsages" ("guild_id", "invoked_at");
+
+-- daily rollups of command usages; invocations outside of guilds have an empty guild id
+CREATE TABLE IF NOT EXISTS "Nightly"."CommandUsageRollups" (
+    "day" TIMESTAMP WITH TIME ZONE NOT NULL,
+    "command" TEXT NOT NULL,
+    "guild_id" TEXT NOT NULL,
+    "invocations" BIGINT NOT NULL,
+    "failures" BIGINT NOT NULL,
+    "rejections" BIGINT NOT NULL,
+    "total_latency_ms" BIGINT NOT NULL,
+    PRIMARY KEY("day", "command", "guild_id")
+);
+
+CREATE INDEX IF NOT EXISTS "CommandUsageRollupsGuildIdDayIndex"
+ON "Nightly"."CommandUsageRollups" ("guild_id", "day");
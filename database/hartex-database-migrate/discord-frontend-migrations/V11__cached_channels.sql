@@ -0,0 +1,33 @@
+CREATE TABLE IF NOT EXISTS "Nightly"."CachedChannels" (
+    "id" TEXT NOT NULL PRIMARY KEY,
+    "guild_id" TEXT NOT NULL,
+    "kind" SMALLINT NOT NULL,
+    "name" TEXT,
+    "nsfw" BOOLEAN,
+    "parent_id" TEXT,
+    "permission_overwrites" JSONB,
+    "position" INTEGER,
+    "rate_limit_per_user" INTEGER,
+    "topic" TEXT
+);
+
+CREATE INDEX IF NOT EXISTS "CachedChannelsGuildIdIndex"
+ON "Nightly"."CachedChannels" ("guild_id");
+
+-- only active threads are cached; archived threads are removed
+CREATE TABLE IF NOT EXISTS "Nightly"."CachedThreads" (
+    "id" TEXT NOT NULL PRIMARY KEY,
+    "guild_id" TEXT NOT NULL,
+    "kind" SMALLINT NOT NULL,
+    "locked" BOOLEAN NOT NULL,
+    "name" TEXT,
+    "owner_id" TEXT,
+    "parent_id" TEXT,
+    "rate_limit_per_user" INTEGER
+);
+
+CREATE INDEX IF NOT EXISTS "CachedThreadsGuildIdIndex"
+ON "Nightly"."CachedThreads" ("guild_id");
+
+CREATE INDEX IF NOT EXISTS "CachedThreadsParentIdIndex"
+ON "Nightly"."CachedThreads" ("parent_id");
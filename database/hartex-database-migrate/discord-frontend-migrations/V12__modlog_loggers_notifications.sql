@@ -0,0 +1,19 @@
+-- loggers are stored separately from the rest of the configuration, hence changes to them notify
+-- the workers as well, such that their cached modlog configuration is invalidated
+CREATE OR REPLACE FUNCTION "Nightly"."NotifyGuildConfigurationSectionUpdated"() RETURNS TRIGGER AS $$
+BEGIN
+    IF TG_OP = 'DELETE' THEN
+        PERFORM pg_notify('guild_configuration_updated', OLD."guild_id");
+    ELSE
+        PERFORM pg_notify('guild_configuration_updated', NEW."guild_id");
+    END IF;
+
+    RETURN NULL;
+END;
+$$ LANGUAGE plpgsql;
+
+DROP TRIGGER IF EXISTS "GuildModlogLoggersUpdated" ON "Nightly"."GuildModlogLoggers";
+
+CREATE TRIGGER "GuildModlogLoggersUpdated"
+AFTER INSERT OR UPDATE OR DELETE ON "Nightly"."GuildModlogLoggers"
+FOR EACH ROW EXECUTE FUNCTION "Nightly"."NotifyGuildConfigurationSectionUpdated"();
@@ -0,0 +1,11 @@
+ALTER TABLE "Nightly"."CachedMembers"
+ADD COLUMN IF NOT EXISTS "avatar" TEXT;
+
+-- only the voice states of users connected to a voice channel are cached; the voice state of a user
+-- is removed once they disconnect
+CREATE TABLE IF NOT EXISTS "Nightly"."CachedVoiceStates" (
+    "channel_id" TEXT,
+    "guild_id" TEXT NOT NULL,
+    "user_id" TEXT NOT NULL,
+    PRIMARY KEY("guild_id", "user_id")
+);
@@ -0,0 +1,13 @@
+-- permission levels are stored separately from the rest of the configuration and are cached by the
+-- workers, hence changes to them notify the workers as well
+DROP TRIGGER IF EXISTS "GuildPermissionLevelsUpdated" ON "Nightly"."GuildPermissionLevels";
+
+CREATE TRIGGER "GuildPermissionLevelsUpdated"
+AFTER INSERT OR UPDATE OR DELETE ON "Nightly"."GuildPermissionLevels"
+FOR EACH ROW EXECUTE FUNCTION "Nightly"."NotifyGuildConfigurationSectionUpdated"();
+
+DROP TRIGGER IF EXISTS "GuildPermissionLevelOverridesUpdated" ON "Nightly"."GuildPermissionLevelOverrides";
+
+CREATE TRIGGER "GuildPermissionLevelOverridesUpdated"
+AFTER INSERT OR UPDATE OR DELETE ON "Nightly"."GuildPermissionLevelOverrides"
+FOR EACH ROW EXECUTE FUNCTION "Nightly"."NotifyGuildConfigurationSectionUpdated"();
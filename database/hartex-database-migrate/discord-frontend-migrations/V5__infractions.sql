@@ -0,0 +1,24 @@
+CREATE TABLE IF NOT EXISTS "Nightly"."Infractions" (
+    "guild_id" TEXT NOT NULL,
+    "case_number" BIGINT NOT NULL,
+    "user_id" TEXT NOT NULL,
+    "moderator_id" TEXT NOT NULL,
+    "kind" TEXT NOT NULL,
+    "reason" TEXT NOT NULL,
+    "created_at" TIMESTAMP WITH TIME ZONE NOT NULL,
+    "pardoned" BOOLEAN NOT NULL DEFAULT FALSE,
+    PRIMARY KEY("guild_id", "case_number")
+);
+
+CREATE INDEX IF NOT EXISTS "InfractionsGuildIdUserIdIndex"
+ON "Nightly"."Infractions" ("guild_id", "user_id");
+
+CREATE TABLE IF NOT EXISTS "Nightly"."InfractionAuditLogs" (
+    "guild_id" TEXT NOT NULL,
+    "case_number" BIGINT NOT NULL,
+    "actor_id" TEXT NOT NULL,
+    "action" TEXT NOT NULL,
+    "old_reason" TEXT,
+    "new_reason" TEXT,
+    "timestamp" TIMESTAMP WITH TIME ZONE NOT NULL
+);
@@ -0,0 +1,7 @@
+CREATE TABLE IF NOT EXISTS "Nightly"."GuildModlogLoggers" (
+    "guild_id" TEXT NOT NULL,
+    "channel_id" TEXT NOT NULL,
+    "events" TEXT ARRAY NOT NULL,
+    "format" TEXT NOT NULL,
+    PRIMARY KEY("guild_id", "channel_id")
+);
@@ -0,0 +1,15 @@
+CREATE TABLE IF NOT EXISTS "Nightly"."CachedMessages" (
+    "id" TEXT NOT NULL PRIMARY KEY,
+    "channel_id" TEXT NOT NULL,
+    "guild_id" TEXT,
+    "author_id" TEXT NOT NULL,
+    "content" TEXT NOT NULL,
+    "attachments" TEXT[] NOT NULL,
+    "embeds" TEXT[] NOT NULL,
+    "timestamp" TIMESTAMP WITH TIME ZONE NOT NULL,
+    "edited_timestamp" TIMESTAMP WITH TIME ZONE,
+    "deleted" BOOLEAN NOT NULL DEFAULT FALSE
+);
+
+CREATE INDEX IF NOT EXISTS "CachedMessagesChannelIdTimestampIndex"
+ON "Nightly"."CachedMessages" ("channel_id", "timestamp");
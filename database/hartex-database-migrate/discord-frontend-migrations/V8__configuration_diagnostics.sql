@@ -0,0 +1,22 @@
+CREATE TABLE IF NOT EXISTS "Nightly"."GuildConfigurationDiagnostics" (
+    "guild_id" TEXT NOT NULL,
+    "source" TEXT NOT NULL,
+    "message" TEXT NOT NULL,
+    "timestamp" TIMESTAMP WITH TIME ZONE NOT NULL
+);
+
+CREATE INDEX IF NOT EXISTS "GuildConfigurationDiagnosticsGuildIdIndex"
+ON "Nightly"."GuildConfigurationDiagnostics" ("guild_id");
+
+CREATE OR REPLACE FUNCTION "Nightly"."NotifyGuildConfigurationUpdated"() RETURNS TRIGGER AS $$
+BEGIN
+    PERFORM pg_notify('guild_configuration_updated', NEW."guild_id");
+    RETURN NEW;
+END;
+$$ LANGUAGE plpgsql;
+
+DROP TRIGGER IF EXISTS "GuildConfigurationUpdated" ON "Nightly"."GuildConfigurations";
+
+CREATE TRIGGER "GuildConfigurationUpdated"
+AFTER INSERT OR UPDATE ON "Nightly"."GuildConfigurations"
+FOR EACH ROW EXECUTE FUNCTION "Nightly"."NotifyGuildConfigurationUpdated"();
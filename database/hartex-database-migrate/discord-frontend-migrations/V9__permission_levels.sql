@@ -0,0 +1,15 @@
+CREATE TABLE IF NOT EXISTS "Nightly"."GuildPermissionLevels" (
+    "guild_id" TEXT NOT NULL,
+    "kind" TEXT NOT NULL,
+    "target_id" TEXT NOT NULL,
+    "level" SMALLINT NOT NULL,
+    PRIMARY KEY("guild_id", "kind", "target_id")
+);
+
+CREATE TABLE IF NOT EXISTS "Nightly"."GuildPermissionLevelOverrides" (
+    "guild_id" TEXT NOT NULL,
+    "kind" TEXT NOT NULL,
+    "name" TEXT NOT NULL,
+    "level" SMALLINT NOT NULL,
+    PRIMARY KEY("guild_id", "kind", "name")
+);
@@ -3,7 +3,588 @@
 #[allow(clippy::all, clippy::pedantic)] #[allow(unused_variables)]
 #[allow(unused_imports)] #[allow(dead_code)] pub mod types { }#[allow(clippy::all, clippy::pedantic)] #[allow(unused_variables)]
 #[allow(unused_imports)] #[allow(dead_code)] pub mod queries
-{ pub mod plugin_enabled
+{ pub mod appearance_select_by_guild_id
+{ use futures::{{StreamExt, TryStreamExt}};use futures; use cornucopia_async::GenericClient;#[derive( Debug, Clone, PartialEq,)] pub struct AppearanceSelectByGuildId
+{ pub appearance_nickname : String,pub appearance_colour : i64,}pub struct AppearanceSelectByGuildIdBorrowed<'a> { pub appearance_nickname : &'a str,pub appearance_colour : i64,}
+impl<'a> From<AppearanceSelectByGuildIdBorrowed<'a>> for AppearanceSelectByGuildId
+{
+    fn from(AppearanceSelectByGuildIdBorrowed { appearance_nickname,appearance_colour,}: AppearanceSelectByGuildIdBorrowed<'a>) ->
+    Self { Self { appearance_nickname: appearance_nickname.into(),appearance_colour,} }
+}pub struct AppearanceSelectByGuildIdQuery<'a, C: GenericClient, T, const N: usize>
+{
+    client: &'a  C, params:
+    [&'a (dyn postgres_types::ToSql + Sync); N], stmt: &'a mut
+    cornucopia_async::private::Stmt, extractor: fn(&tokio_postgres::Row) -> AppearanceSelectByGuildIdBorrowed,
+    mapper: fn(AppearanceSelectByGuildIdBorrowed) -> T,
+} impl<'a, C, T:'a, const N: usize> AppearanceSelectByGuildIdQuery<'a, C, T, N> where C:
+GenericClient
+{
+    pub fn map<R>(self, mapper: fn(AppearanceSelectByGuildIdBorrowed) -> R) ->
+    AppearanceSelectByGuildIdQuery<'a,C,R,N>
+    {
+        AppearanceSelectByGuildIdQuery
+        {
+            client: self.client, params: self.params, stmt: self.stmt,
+            extractor: self.extractor, mapper,
+        }
+    } pub async fn one(self) -> Result<T, tokio_postgres::Error>
+    {
+        let stmt = self.stmt.prepare(self.client).await?; let row =
+        self.client.query_one(stmt, &self.params).await?;
+        Ok((self.mapper)((self.extractor)(&row)))
+    } pub async fn all(self) -> Result<Vec<T>, tokio_postgres::Error>
+    { self.iter().await?.try_collect().await } pub async fn opt(self) ->
+    Result<Option<T>, tokio_postgres::Error>
+    {
+        let stmt = self.stmt.prepare(self.client).await?;
+        Ok(self.client.query_opt(stmt, &self.params) .await?
+        .map(|row| (self.mapper)((self.extractor)(&row))))
+    } pub async fn iter(self,) -> Result<impl futures::Stream<Item = Result<T,
+    tokio_postgres::Error>> + 'a, tokio_postgres::Error>
+    {
+        let stmt = self.stmt.prepare(self.client).await?; let it =
+        self.client.query_raw(stmt,
+        cornucopia_async::private::slice_iter(&self.params)) .await?
+        .map(move |res|
+        res.map(|row| (self.mapper)((self.extractor)(&row)))) .into_stream();
+        Ok(it)
+    }
+}pub fn appearance_select_by_guild_id() -> AppearanceSelectByGuildIdStmt
+{ AppearanceSelectByGuildIdStmt(cornucopia_async::private::Stmt::new("SELECT
+    \"appearance_nickname\",
+    \"appearance_colour\"
+FROM
+    \"Nightly\".\"GuildConfigurations\"
+WHERE
+    \"guild_id\" = $1")) } pub struct
+AppearanceSelectByGuildIdStmt(cornucopia_async::private::Stmt); impl AppearanceSelectByGuildIdStmt
+{ pub fn bind<'a, C:
+GenericClient,T1:
+cornucopia_async::StringSql,>(&'a mut self, client: &'a  C,
+guild_id: &'a T1,) -> AppearanceSelectByGuildIdQuery<'a,C,
+AppearanceSelectByGuildId, 1>
+{
+    AppearanceSelectByGuildIdQuery
+    {
+        client, params: [guild_id,], stmt: &mut self.0, extractor:
+        |row| { AppearanceSelectByGuildIdBorrowed { appearance_nickname: row.get(0),appearance_colour: row.get(1),} }, mapper: |it| { <AppearanceSelectByGuildId>::from(it) },
+    }
+} }}pub mod configuration_diagnostic_insert
+{ use futures::{{StreamExt, TryStreamExt}};use futures; use cornucopia_async::GenericClient;#[derive( Debug)] pub struct ConfigurationDiagnosticInsertParams<T1: cornucopia_async::StringSql,T2: cornucopia_async::StringSql,T3: cornucopia_async::StringSql,> { pub guild_id: T1,pub source: T2,pub message: T3,pub timestamp: time::OffsetDateTime,}pub fn configuration_diagnostic_insert() -> ConfigurationDiagnosticInsertStmt
+{ ConfigurationDiagnosticInsertStmt(cornucopia_async::private::Stmt::new("INSERT INTO \"Nightly\".\"GuildConfigurationDiagnostics\" (\"guild_id\", \"source\", \"message\", \"timestamp\")
+VALUES ($1, $2, $3, $4)")) } pub struct
+ConfigurationDiagnosticInsertStmt(cornucopia_async::private::Stmt); impl ConfigurationDiagnosticInsertStmt
+{ pub async fn bind<'a, C:
+GenericClient,T1:
+cornucopia_async::StringSql,T2:
+cornucopia_async::StringSql,T3:
+cornucopia_async::StringSql,>(&'a mut self, client: &'a  C,
+guild_id: &'a T1,source: &'a T2,message: &'a T3,timestamp: &'a time::OffsetDateTime,) -> Result<u64, tokio_postgres::Error>
+{
+    let stmt = self.0.prepare(client).await?;
+    client.execute(stmt, &[guild_id,source,message,timestamp,]).await
+} }impl <'a, C: GenericClient + Send + Sync, T1: cornucopia_async::StringSql,T2: cornucopia_async::StringSql,T3: cornucopia_async::StringSql,>
+cornucopia_async::Params<'a, ConfigurationDiagnosticInsertParams<T1,T2,T3,>, std::pin::Pin<Box<dyn futures::Future<Output = Result<u64,
+tokio_postgres::Error>> + Send + 'a>>, C> for ConfigurationDiagnosticInsertStmt
+{
+    fn
+    params(&'a mut self, client: &'a  C, params: &'a
+    ConfigurationDiagnosticInsertParams<T1,T2,T3,>) -> std::pin::Pin<Box<dyn futures::Future<Output = Result<u64,
+    tokio_postgres::Error>> + Send + 'a>>
+    { Box::pin(self.bind(client, &params.guild_id,&params.source,&params.message,&params.timestamp,)) }
+}}pub mod dashboard_admins_and_enabled_plugins_select_by_guild_id
+{ use futures::{{StreamExt, TryStreamExt}};use futures; use cornucopia_async::GenericClient;#[derive( Debug, Clone, PartialEq,)] pub struct DashboardAdminsAndEnabledPluginsSelectByGuildId
+{ pub dashboard_admins : Vec<String>,pub enabled_plugins : Vec<String>,}pub struct DashboardAdminsAndEnabledPluginsSelectByGuildIdBorrowed<'a> { pub dashboard_admins : cornucopia_async::ArrayIterator<'a, &'a str>,pub enabled_plugins : cornucopia_async::ArrayIterator<'a, &'a str>,}
+impl<'a> From<DashboardAdminsAndEnabledPluginsSelectByGuildIdBorrowed<'a>> for DashboardAdminsAndEnabledPluginsSelectByGuildId
+{
+    fn from(DashboardAdminsAndEnabledPluginsSelectByGuildIdBorrowed { dashboard_admins,enabled_plugins,}: DashboardAdminsAndEnabledPluginsSelectByGuildIdBorrowed<'a>) ->
+    Self { Self { dashboard_admins: dashboard_admins.map(|v| v.into()).collect(),enabled_plugins: enabled_plugins.map(|v| v.into()).collect(),} }
+}pub struct DashboardAdminsAndEnabledPluginsSelectByGuildIdQuery<'a, C: GenericClient, T, const N: usize>
+{
+    client: &'a  C, params:
+    [&'a (dyn postgres_types::ToSql + Sync); N], stmt: &'a mut
+    cornucopia_async::private::Stmt, extractor: fn(&tokio_postgres::Row) -> DashboardAdminsAndEnabledPluginsSelectByGuildIdBorrowed,
+    mapper: fn(DashboardAdminsAndEnabledPluginsSelectByGuildIdBorrowed) -> T,
+} impl<'a, C, T:'a, const N: usize> DashboardAdminsAndEnabledPluginsSelectByGuildIdQuery<'a, C, T, N> where C:
+GenericClient
+{
+    pub fn map<R>(self, mapper: fn(DashboardAdminsAndEnabledPluginsSelectByGuildIdBorrowed) -> R) ->
+    DashboardAdminsAndEnabledPluginsSelectByGuildIdQuery<'a,C,R,N>
+    {
+        DashboardAdminsAndEnabledPluginsSelectByGuildIdQuery
+        {
+            client: self.client, params: self.params, stmt: self.stmt,
+            extractor: self.extractor, mapper,
+        }
+    } pub async fn one(self) -> Result<T, tokio_postgres::Error>
+    {
+        let stmt = self.stmt.prepare(self.client).await?; let row =
+        self.client.query_one(stmt, &self.params).await?;
+        Ok((self.mapper)((self.extractor)(&row)))
+    } pub async fn all(self) -> Result<Vec<T>, tokio_postgres::Error>
+    { self.iter().await?.try_collect().await } pub async fn opt(self) ->
+    Result<Option<T>, tokio_postgres::Error>
+    {
+        let stmt = self.stmt.prepare(self.client).await?;
+        Ok(self.client.query_opt(stmt, &self.params) .await?
+        .map(|row| (self.mapper)((self.extractor)(&row))))
+    } pub async fn iter(self,) -> Result<impl futures::Stream<Item = Result<T,
+    tokio_postgres::Error>> + 'a, tokio_postgres::Error>
+    {
+        let stmt = self.stmt.prepare(self.client).await?; let it =
+        self.client.query_raw(stmt,
+        cornucopia_async::private::slice_iter(&self.params)) .await?
+        .map(move |res|
+        res.map(|row| (self.mapper)((self.extractor)(&row)))) .into_stream();
+        Ok(it)
+    }
+}pub fn dashboard_admins_and_enabled_plugins_select_by_guild_id() -> DashboardAdminsAndEnabledPluginsSelectByGuildIdStmt
+{ DashboardAdminsAndEnabledPluginsSelectByGuildIdStmt(cornucopia_async::private::Stmt::new("SELECT
+    \"dashboard_admins\",
+    \"enabled_plugins\"
+FROM
+    \"Nightly\".\"GuildConfigurations\"
+WHERE
+    \"guild_id\" = $1")) } pub struct
+DashboardAdminsAndEnabledPluginsSelectByGuildIdStmt(cornucopia_async::private::Stmt); impl DashboardAdminsAndEnabledPluginsSelectByGuildIdStmt
+{ pub fn bind<'a, C:
+GenericClient,T1:
+cornucopia_async::StringSql,>(&'a mut self, client: &'a  C,
+guild_id: &'a T1,) -> DashboardAdminsAndEnabledPluginsSelectByGuildIdQuery<'a,C,
+DashboardAdminsAndEnabledPluginsSelectByGuildId, 1>
+{
+    DashboardAdminsAndEnabledPluginsSelectByGuildIdQuery
+    {
+        client, params: [guild_id,], stmt: &mut self.0, extractor:
+        |row| { DashboardAdminsAndEnabledPluginsSelectByGuildIdBorrowed { dashboard_admins: row.get(0),enabled_plugins: row.get(1),} }, mapper: |it| { <DashboardAdminsAndEnabledPluginsSelectByGuildId>::from(it) },
+    }
+} }}pub mod enabled_plugins_append
+{ use futures::{{StreamExt, TryStreamExt}};use futures; use cornucopia_async::GenericClient;#[derive( Debug)] pub struct EnabledPluginsAppendParams<T1: cornucopia_async::StringSql,T2: cornucopia_async::StringSql,> { pub plugin: T1,pub guild_id: T2,}pub fn enabled_plugins_append() -> EnabledPluginsAppendStmt
+{ EnabledPluginsAppendStmt(cornucopia_async::private::Stmt::new("UPDATE
+    \"Nightly\".\"GuildConfigurations\"
+SET
+    \"enabled_plugins\" = array_append(\"enabled_plugins\", $1)
+WHERE
+    \"guild_id\" = $2 AND
+    NOT \"enabled_plugins\" @> array[ $1 ]")) } pub struct
+EnabledPluginsAppendStmt(cornucopia_async::private::Stmt); impl EnabledPluginsAppendStmt
+{ pub async fn bind<'a, C:
+GenericClient,T1:
+cornucopia_async::StringSql,T2:
+cornucopia_async::StringSql,>(&'a mut self, client: &'a  C,
+plugin: &'a T1,guild_id: &'a T2,) -> Result<u64, tokio_postgres::Error>
+{
+    let stmt = self.0.prepare(client).await?;
+    client.execute(stmt, &[plugin,guild_id,]).await
+} }impl <'a, C: GenericClient + Send + Sync, T1: cornucopia_async::StringSql,T2: cornucopia_async::StringSql,>
+cornucopia_async::Params<'a, EnabledPluginsAppendParams<T1,T2,>, std::pin::Pin<Box<dyn futures::Future<Output = Result<u64,
+tokio_postgres::Error>> + Send + 'a>>, C> for EnabledPluginsAppendStmt
+{
+    fn
+    params(&'a mut self, client: &'a  C, params: &'a
+    EnabledPluginsAppendParams<T1,T2,>) -> std::pin::Pin<Box<dyn futures::Future<Output = Result<u64,
+    tokio_postgres::Error>> + Send + 'a>>
+    { Box::pin(self.bind(client, &params.plugin,&params.guild_id,)) }
+}}pub mod enabled_plugins_remove
+{ use futures::{{StreamExt, TryStreamExt}};use futures; use cornucopia_async::GenericClient;#[derive( Debug)] pub struct EnabledPluginsRemoveParams<T1: cornucopia_async::StringSql,T2: cornucopia_async::StringSql,> { pub plugin: T1,pub guild_id: T2,}pub fn enabled_plugins_remove() -> EnabledPluginsRemoveStmt
+{ EnabledPluginsRemoveStmt(cornucopia_async::private::Stmt::new("UPDATE
+    \"Nightly\".\"GuildConfigurations\"
+SET
+    \"enabled_plugins\" = array_remove(\"enabled_plugins\", $1)
+WHERE
+    \"guild_id\" = $2 AND
+    \"enabled_plugins\" @> array[ $1 ]")) } pub struct
+EnabledPluginsRemoveStmt(cornucopia_async::private::Stmt); impl EnabledPluginsRemoveStmt
+{ pub async fn bind<'a, C:
+GenericClient,T1:
+cornucopia_async::StringSql,T2:
+cornucopia_async::StringSql,>(&'a mut self, client: &'a  C,
+plugin: &'a T1,guild_id: &'a T2,) -> Result<u64, tokio_postgres::Error>
+{
+    let stmt = self.0.prepare(client).await?;
+    client.execute(stmt, &[plugin,guild_id,]).await
+} }impl <'a, C: GenericClient + Send + Sync, T1: cornucopia_async::StringSql,T2: cornucopia_async::StringSql,>
+cornucopia_async::Params<'a, EnabledPluginsRemoveParams<T1,T2,>, std::pin::Pin<Box<dyn futures::Future<Output = Result<u64,
+tokio_postgres::Error>> + Send + 'a>>, C> for EnabledPluginsRemoveStmt
+{
+    fn
+    params(&'a mut self, client: &'a  C, params: &'a
+    EnabledPluginsRemoveParams<T1,T2,>) -> std::pin::Pin<Box<dyn futures::Future<Output = Result<u64,
+    tokio_postgres::Error>> + Send + 'a>>
+    { Box::pin(self.bind(client, &params.plugin,&params.guild_id,)) }
+}}pub mod guild_configuration_upsert
+{ use futures::{{StreamExt, TryStreamExt}};use futures; use cornucopia_async::GenericClient;#[derive( Debug)] pub struct GuildConfigurationUpsertParams<T1: cornucopia_async::StringSql,T2: cornucopia_async::StringSql,T3: cornucopia_async::ArraySql<Item = T2>,T4: cornucopia_async::StringSql,T5: cornucopia_async::ArraySql<Item = T4>,T6: cornucopia_async::StringSql,T7: cornucopia_async::ArraySql<Item = T6>,T8: cornucopia_async::StringSql,T9: cornucopia_async::StringSql,T10: cornucopia_async::ArraySql<Item = T9>,> { pub guild_id: T1,pub dashboard_admins: T3,pub dashboard_editors: T5,pub dashboard_viewers: T7,pub appearance_nickname: T8,pub appearance_colour: i64,pub enabled_plugins: T10,}pub fn guild_configuration_upsert() -> GuildConfigurationUpsertStmt
+{ GuildConfigurationUpsertStmt(cornucopia_async::private::Stmt::new("INSERT INTO \"Nightly\".\"GuildConfigurations\" (\"guild_id\", \"dashboard_admins\", \"dashboard_editors\", \"dashboard_viewers\", \"appearance_nickname\", \"appearance_colour\", \"enabled_plugins\")
+VALUES ($1, $2, $3, $4, $5, $6, $7)
+ON CONFLICT (\"guild_id\") DO UPDATE
+    SET
+        \"dashboard_admins\" = $2,
+        \"dashboard_editors\" = $3,
+        \"dashboard_viewers\" = $4,
+        \"appearance_nickname\" = $5,
+        \"appearance_colour\" = $6,
+        \"enabled_plugins\" = $7")) } pub struct
+GuildConfigurationUpsertStmt(cornucopia_async::private::Stmt); impl GuildConfigurationUpsertStmt
+{ pub async fn bind<'a, C:
+GenericClient,T1:
+cornucopia_async::StringSql,T2:
+cornucopia_async::StringSql,T3:
+cornucopia_async::ArraySql<Item = T2>,T4:
+cornucopia_async::StringSql,T5:
+cornucopia_async::ArraySql<Item = T4>,T6:
+cornucopia_async::StringSql,T7:
+cornucopia_async::ArraySql<Item = T6>,T8:
+cornucopia_async::StringSql,T9:
+cornucopia_async::StringSql,T10:
+cornucopia_async::ArraySql<Item = T9>,>(&'a mut self, client: &'a  C,
+guild_id: &'a T1,dashboard_admins: &'a T3,dashboard_editors: &'a T5,dashboard_viewers: &'a T7,appearance_nickname: &'a T8,appearance_colour: &'a i64,enabled_plugins: &'a T10,) -> Result<u64, tokio_postgres::Error>
+{
+    let stmt = self.0.prepare(client).await?;
+    client.execute(stmt, &[guild_id,dashboard_admins,dashboard_editors,dashboard_viewers,appearance_nickname,appearance_colour,enabled_plugins,]).await
+} }impl <'a, C: GenericClient + Send + Sync, T1: cornucopia_async::StringSql,T2: cornucopia_async::StringSql,T3: cornucopia_async::ArraySql<Item = T2>,T4: cornucopia_async::StringSql,T5: cornucopia_async::ArraySql<Item = T4>,T6: cornucopia_async::StringSql,T7: cornucopia_async::ArraySql<Item = T6>,T8: cornucopia_async::StringSql,T9: cornucopia_async::StringSql,T10: cornucopia_async::ArraySql<Item = T9>,>
+cornucopia_async::Params<'a, GuildConfigurationUpsertParams<T1,T2,T3,T4,T5,T6,T7,T8,T9,T10,>, std::pin::Pin<Box<dyn futures::Future<Output = Result<u64,
+tokio_postgres::Error>> + Send + 'a>>, C> for GuildConfigurationUpsertStmt
+{
+    fn
+    params(&'a mut self, client: &'a  C, params: &'a
+    GuildConfigurationUpsertParams<T1,T2,T3,T4,T5,T6,T7,T8,T9,T10,>) -> std::pin::Pin<Box<dyn futures::Future<Output = Result<u64,
+    tokio_postgres::Error>> + Send + 'a>>
+    { Box::pin(self.bind(client, &params.guild_id,&params.dashboard_admins,&params.dashboard_editors,&params.dashboard_viewers,&params.appearance_nickname,&params.appearance_colour,&params.enabled_plugins,)) }
+}}pub mod modlog_logger_insert
+{ use futures::{{StreamExt, TryStreamExt}};use futures; use cornucopia_async::GenericClient;#[derive( Debug)] pub struct ModlogLoggerInsertParams<T1: cornucopia_async::StringSql,T2: cornucopia_async::StringSql,T3: cornucopia_async::StringSql,T4: cornucopia_async::ArraySql<Item = T3>,T5: cornucopia_async::StringSql,> { pub guild_id: T1,pub channel_id: T2,pub events: T4,pub format: T5,}pub fn modlog_logger_insert() -> ModlogLoggerInsertStmt
+{ ModlogLoggerInsertStmt(cornucopia_async::private::Stmt::new("INSERT INTO \"Nightly\".\"GuildModlogLoggers\" (\"guild_id\", \"channel_id\", \"events\", \"format\")
+VALUES ($1, $2, $3, $4)")) } pub struct
+ModlogLoggerInsertStmt(cornucopia_async::private::Stmt); impl ModlogLoggerInsertStmt
+{ pub async fn bind<'a, C:
+GenericClient,T1:
+cornucopia_async::StringSql,T2:
+cornucopia_async::StringSql,T3:
+cornucopia_async::StringSql,T4:
+cornucopia_async::ArraySql<Item = T3>,T5:
+cornucopia_async::StringSql,>(&'a mut self, client: &'a  C,
+guild_id: &'a T1,channel_id: &'a T2,events: &'a T4,format: &'a T5,) -> Result<u64, tokio_postgres::Error>
+{
+    let stmt = self.0.prepare(client).await?;
+    client.execute(stmt, &[guild_id,channel_id,events,format,]).await
+} }impl <'a, C: GenericClient + Send + Sync, T1: cornucopia_async::StringSql,T2: cornucopia_async::StringSql,T3: cornucopia_async::StringSql,T4: cornucopia_async::ArraySql<Item = T3>,T5: cornucopia_async::StringSql,>
+cornucopia_async::Params<'a, ModlogLoggerInsertParams<T1,T2,T3,T4,T5,>, std::pin::Pin<Box<dyn futures::Future<Output = Result<u64,
+tokio_postgres::Error>> + Send + 'a>>, C> for ModlogLoggerInsertStmt
+{
+    fn
+    params(&'a mut self, client: &'a  C, params: &'a
+    ModlogLoggerInsertParams<T1,T2,T3,T4,T5,>) -> std::pin::Pin<Box<dyn futures::Future<Output = Result<u64,
+    tokio_postgres::Error>> + Send + 'a>>
+    { Box::pin(self.bind(client, &params.guild_id,&params.channel_id,&params.events,&params.format,)) }
+}}pub mod modlog_loggers_delete_by_guild_id
+{ use futures::{{StreamExt, TryStreamExt}};use futures; use cornucopia_async::GenericClient;pub fn modlog_loggers_delete_by_guild_id() -> ModlogLoggersDeleteByGuildIdStmt
+{ ModlogLoggersDeleteByGuildIdStmt(cornucopia_async::private::Stmt::new("DELETE FROM
+    \"Nightly\".\"GuildModlogLoggers\"
+WHERE
+    \"guild_id\" = $1")) } pub struct
+ModlogLoggersDeleteByGuildIdStmt(cornucopia_async::private::Stmt); impl ModlogLoggersDeleteByGuildIdStmt
+{ pub async fn bind<'a, C:
+GenericClient,T1:
+cornucopia_async::StringSql,>(&'a mut self, client: &'a  C,
+guild_id: &'a T1,) -> Result<u64, tokio_postgres::Error>
+{
+    let stmt = self.0.prepare(client).await?;
+    client.execute(stmt, &[guild_id,]).await
+} }}pub mod modlog_loggers_select_by_guild_id
+{ use futures::{{StreamExt, TryStreamExt}};use futures; use cornucopia_async::GenericClient;#[derive( Debug, Clone, PartialEq,)] pub struct ModlogLoggersSelectByGuildId
+{ pub channel_id : String,pub events : Vec<String>,pub format : String,}pub struct ModlogLoggersSelectByGuildIdBorrowed<'a> { pub channel_id : &'a str,pub events : cornucopia_async::ArrayIterator<'a, &'a str>,pub format : &'a str,}
+impl<'a> From<ModlogLoggersSelectByGuildIdBorrowed<'a>> for ModlogLoggersSelectByGuildId
+{
+    fn from(ModlogLoggersSelectByGuildIdBorrowed { channel_id,events,format,}: ModlogLoggersSelectByGuildIdBorrowed<'a>) ->
+    Self { Self { channel_id: channel_id.into(),events: events.map(|v| v.into()).collect(),format: format.into(),} }
+}pub struct ModlogLoggersSelectByGuildIdQuery<'a, C: GenericClient, T, const N: usize>
+{
+    client: &'a  C, params:
+    [&'a (dyn postgres_types::ToSql + Sync); N], stmt: &'a mut
+    cornucopia_async::private::Stmt, extractor: fn(&tokio_postgres::Row) -> ModlogLoggersSelectByGuildIdBorrowed,
+    mapper: fn(ModlogLoggersSelectByGuildIdBorrowed) -> T,
+} impl<'a, C, T:'a, const N: usize> ModlogLoggersSelectByGuildIdQuery<'a, C, T, N> where C:
+GenericClient
+{
+    pub fn map<R>(self, mapper: fn(ModlogLoggersSelectByGuildIdBorrowed) -> R) ->
+    ModlogLoggersSelectByGuildIdQuery<'a,C,R,N>
+    {
+        ModlogLoggersSelectByGuildIdQuery
+        {
+            client: self.client, params: self.params, stmt: self.stmt,
+            extractor: self.extractor, mapper,
+        }
+    } pub async fn one(self) -> Result<T, tokio_postgres::Error>
+    {
+        let stmt = self.stmt.prepare(self.client).await?; let row =
+        self.client.query_one(stmt, &self.params).await?;
+        Ok((self.mapper)((self.extractor)(&row)))
+    } pub async fn all(self) -> Result<Vec<T>, tokio_postgres::Error>
+    { self.iter().await?.try_collect().await } pub async fn opt(self) ->
+    Result<Option<T>, tokio_postgres::Error>
+    {
+        let stmt = self.stmt.prepare(self.client).await?;
+        Ok(self.client.query_opt(stmt, &self.params) .await?
+        .map(|row| (self.mapper)((self.extractor)(&row))))
+    } pub async fn iter(self,) -> Result<impl futures::Stream<Item = Result<T,
+    tokio_postgres::Error>> + 'a, tokio_postgres::Error>
+    {
+        let stmt = self.stmt.prepare(self.client).await?; let it =
+        self.client.query_raw(stmt,
+        cornucopia_async::private::slice_iter(&self.params)) .await?
+        .map(move |res|
+        res.map(|row| (self.mapper)((self.extractor)(&row)))) .into_stream();
+        Ok(it)
+    }
+}pub fn modlog_loggers_select_by_guild_id() -> ModlogLoggersSelectByGuildIdStmt
+{ ModlogLoggersSelectByGuildIdStmt(cornucopia_async::private::Stmt::new("SELECT
+    \"channel_id\",
+    \"events\",
+    \"format\"
+FROM
+    \"Nightly\".\"GuildModlogLoggers\"
+WHERE
+    \"guild_id\" = $1")) } pub struct
+ModlogLoggersSelectByGuildIdStmt(cornucopia_async::private::Stmt); impl ModlogLoggersSelectByGuildIdStmt
+{ pub fn bind<'a, C:
+GenericClient,T1:
+cornucopia_async::StringSql,>(&'a mut self, client: &'a  C,
+guild_id: &'a T1,) -> ModlogLoggersSelectByGuildIdQuery<'a,C,
+ModlogLoggersSelectByGuildId, 1>
+{
+    ModlogLoggersSelectByGuildIdQuery
+    {
+        client, params: [guild_id,], stmt: &mut self.0, extractor:
+        |row| { ModlogLoggersSelectByGuildIdBorrowed { channel_id: row.get(0),events: row.get(1),format: row.get(2),} }, mapper: |it| { <ModlogLoggersSelectByGuildId>::from(it) },
+    }
+} }}pub mod permission_level_insert
+{ use futures::{{StreamExt, TryStreamExt}};use futures; use cornucopia_async::GenericClient;#[derive( Debug)] pub struct PermissionLevelInsertParams<T1: cornucopia_async::StringSql,T2: cornucopia_async::StringSql,T3: cornucopia_async::StringSql,> { pub guild_id: T1,pub kind: T2,pub target_id: T3,pub level: i16,}pub fn permission_level_insert() -> PermissionLevelInsertStmt
+{ PermissionLevelInsertStmt(cornucopia_async::private::Stmt::new("INSERT INTO \"Nightly\".\"GuildPermissionLevels\" (\"guild_id\", \"kind\", \"target_id\", \"level\")
+VALUES ($1, $2, $3, $4)")) } pub struct
+PermissionLevelInsertStmt(cornucopia_async::private::Stmt); impl PermissionLevelInsertStmt
+{ pub async fn bind<'a, C:
+GenericClient,T1:
+cornucopia_async::StringSql,T2:
+cornucopia_async::StringSql,T3:
+cornucopia_async::StringSql,>(&'a mut self, client: &'a  C,
+guild_id: &'a T1,kind: &'a T2,target_id: &'a T3,level: &'a i16,) -> Result<u64, tokio_postgres::Error>
+{
+    let stmt = self.0.prepare(client).await?;
+    client.execute(stmt, &[guild_id,kind,target_id,level,]).await
+} }impl <'a, C: GenericClient + Send + Sync, T1: cornucopia_async::StringSql,T2: cornucopia_async::StringSql,T3: cornucopia_async::StringSql,>
+cornucopia_async::Params<'a, PermissionLevelInsertParams<T1,T2,T3,>, std::pin::Pin<Box<dyn futures::Future<Output = Result<u64,
+tokio_postgres::Error>> + Send + 'a>>, C> for PermissionLevelInsertStmt
+{
+    fn
+    params(&'a mut self, client: &'a  C, params: &'a
+    PermissionLevelInsertParams<T1,T2,T3,>) -> std::pin::Pin<Box<dyn futures::Future<Output = Result<u64,
+    tokio_postgres::Error>> + Send + 'a>>
+    { Box::pin(self.bind(client, &params.guild_id,&params.kind,&params.target_id,&params.level,)) }
+}}pub mod permission_level_override_insert
+{ use futures::{{StreamExt, TryStreamExt}};use futures; use cornucopia_async::GenericClient;#[derive( Debug)] pub struct PermissionLevelOverrideInsertParams<T1: cornucopia_async::StringSql,T2: cornucopia_async::StringSql,T3: cornucopia_async::StringSql,> { pub guild_id: T1,pub kind: T2,pub name: T3,pub level: i16,}pub fn permission_level_override_insert() -> PermissionLevelOverrideInsertStmt
+{ PermissionLevelOverrideInsertStmt(cornucopia_async::private::Stmt::new("INSERT INTO \"Nightly\".\"GuildPermissionLevelOverrides\" (\"guild_id\", \"kind\", \"name\", \"level\")
+VALUES ($1, $2, $3, $4)")) } pub struct
+PermissionLevelOverrideInsertStmt(cornucopia_async::private::Stmt); impl PermissionLevelOverrideInsertStmt
+{ pub async fn bind<'a, C:
+GenericClient,T1:
+cornucopia_async::StringSql,T2:
+cornucopia_async::StringSql,T3:
+cornucopia_async::StringSql,>(&'a mut self, client: &'a  C,
+guild_id: &'a T1,kind: &'a T2,name: &'a T3,level: &'a i16,) -> Result<u64, tokio_postgres::Error>
+{
+    let stmt = self.0.prepare(client).await?;
+    client.execute(stmt, &[guild_id,kind,name,level,]).await
+} }impl <'a, C: GenericClient + Send + Sync, T1: cornucopia_async::StringSql,T2: cornucopia_async::StringSql,T3: cornucopia_async::StringSql,>
+cornucopia_async::Params<'a, PermissionLevelOverrideInsertParams<T1,T2,T3,>, std::pin::Pin<Box<dyn futures::Future<Output = Result<u64,
+tokio_postgres::Error>> + Send + 'a>>, C> for PermissionLevelOverrideInsertStmt
+{
+    fn
+    params(&'a mut self, client: &'a  C, params: &'a
+    PermissionLevelOverrideInsertParams<T1,T2,T3,>) -> std::pin::Pin<Box<dyn futures::Future<Output = Result<u64,
+    tokio_postgres::Error>> + Send + 'a>>
+    { Box::pin(self.bind(client, &params.guild_id,&params.kind,&params.name,&params.level,)) }
+}}pub mod permission_level_override_select_by_guild_id_and_names
+{ use futures::{{StreamExt, TryStreamExt}};use futures; use cornucopia_async::GenericClient;#[derive( Debug)] pub struct PermissionLevelOverrideSelectByGuildIdAndNamesParams<T1: cornucopia_async::StringSql,T2: cornucopia_async::StringSql,T3: cornucopia_async::StringSql,> { pub guild_id: T1,pub plugin: T2,pub command: T3,}#[derive( Debug, Clone, PartialEq,)] pub struct PermissionLevelOverrideSelectByGuildIdAndNames
+{ pub kind : String,pub level : i16,}pub struct PermissionLevelOverrideSelectByGuildIdAndNamesBorrowed<'a> { pub kind : &'a str,pub level : i16,}
+impl<'a> From<PermissionLevelOverrideSelectByGuildIdAndNamesBorrowed<'a>> for PermissionLevelOverrideSelectByGuildIdAndNames
+{
+    fn from(PermissionLevelOverrideSelectByGuildIdAndNamesBorrowed { kind,level,}: PermissionLevelOverrideSelectByGuildIdAndNamesBorrowed<'a>) ->
+    Self { Self { kind: kind.into(),level,} }
+}pub struct PermissionLevelOverrideSelectByGuildIdAndNamesQuery<'a, C: GenericClient, T, const N: usize>
+{
+    client: &'a  C, params:
+    [&'a (dyn postgres_types::ToSql + Sync); N], stmt: &'a mut
+    cornucopia_async::private::Stmt, extractor: fn(&tokio_postgres::Row) -> PermissionLevelOverrideSelectByGuildIdAndNamesBorrowed,
+    mapper: fn(PermissionLevelOverrideSelectByGuildIdAndNamesBorrowed) -> T,
+} impl<'a, C, T:'a, const N: usize> PermissionLevelOverrideSelectByGuildIdAndNamesQuery<'a, C, T, N> where C:
+GenericClient
+{
+    pub fn map<R>(self, mapper: fn(PermissionLevelOverrideSelectByGuildIdAndNamesBorrowed) -> R) ->
+    PermissionLevelOverrideSelectByGuildIdAndNamesQuery<'a,C,R,N>
+    {
+        PermissionLevelOverrideSelectByGuildIdAndNamesQuery
+        {
+            client: self.client, params: self.params, stmt: self.stmt,
+            extractor: self.extractor, mapper,
+        }
+    } pub async fn one(self) -> Result<T, tokio_postgres::Error>
+    {
+        let stmt = self.stmt.prepare(self.client).await?; let row =
+        self.client.query_one(stmt, &self.params).await?;
+        Ok((self.mapper)((self.extractor)(&row)))
+    } pub async fn all(self) -> Result<Vec<T>, tokio_postgres::Error>
+    { self.iter().await?.try_collect().await } pub async fn opt(self) ->
+    Result<Option<T>, tokio_postgres::Error>
+    {
+        let stmt = self.stmt.prepare(self.client).await?;
+        Ok(self.client.query_opt(stmt, &self.params) .await?
+        .map(|row| (self.mapper)((self.extractor)(&row))))
+    } pub async fn iter(self,) -> Result<impl futures::Stream<Item = Result<T,
+    tokio_postgres::Error>> + 'a, tokio_postgres::Error>
+    {
+        let stmt = self.stmt.prepare(self.client).await?; let it =
+        self.client.query_raw(stmt,
+        cornucopia_async::private::slice_iter(&self.params)) .await?
+        .map(move |res|
+        res.map(|row| (self.mapper)((self.extractor)(&row)))) .into_stream();
+        Ok(it)
+    }
+}pub fn permission_level_override_select_by_guild_id_and_names() -> PermissionLevelOverrideSelectByGuildIdAndNamesStmt
+{ PermissionLevelOverrideSelectByGuildIdAndNamesStmt(cornucopia_async::private::Stmt::new("SELECT
+    \"kind\",
+    \"level\"
+FROM
+    \"Nightly\".\"GuildPermissionLevelOverrides\"
+WHERE
+    \"guild_id\" = $1 AND (
+        (\"kind\" = 'plugin' AND \"name\" = $2) OR
+        (\"kind\" = 'command' AND \"name\" = $3)
+    )")) } pub struct
+PermissionLevelOverrideSelectByGuildIdAndNamesStmt(cornucopia_async::private::Stmt); impl PermissionLevelOverrideSelectByGuildIdAndNamesStmt
+{ pub fn bind<'a, C:
+GenericClient,T1:
+cornucopia_async::StringSql,T2:
+cornucopia_async::StringSql,T3:
+cornucopia_async::StringSql,>(&'a mut self, client: &'a  C,
+guild_id: &'a T1,plugin: &'a T2,command: &'a T3,) -> PermissionLevelOverrideSelectByGuildIdAndNamesQuery<'a,C,
+PermissionLevelOverrideSelectByGuildIdAndNames, 3>
+{
+    PermissionLevelOverrideSelectByGuildIdAndNamesQuery
+    {
+        client, params: [guild_id,plugin,command,], stmt: &mut self.0, extractor:
+        |row| { PermissionLevelOverrideSelectByGuildIdAndNamesBorrowed { kind: row.get(0),level: row.get(1),} }, mapper: |it| { <PermissionLevelOverrideSelectByGuildIdAndNames>::from(it) },
+    }
+} }impl <'a, C: GenericClient,T1: cornucopia_async::StringSql,T2: cornucopia_async::StringSql,T3: cornucopia_async::StringSql,> cornucopia_async::Params<'a,
+PermissionLevelOverrideSelectByGuildIdAndNamesParams<T1,T2,T3,>, PermissionLevelOverrideSelectByGuildIdAndNamesQuery<'a, C,
+PermissionLevelOverrideSelectByGuildIdAndNames, 3>, C> for PermissionLevelOverrideSelectByGuildIdAndNamesStmt
+{
+    fn
+    params(&'a mut self, client: &'a  C, params: &'a
+    PermissionLevelOverrideSelectByGuildIdAndNamesParams<T1,T2,T3,>) -> PermissionLevelOverrideSelectByGuildIdAndNamesQuery<'a, C,
+    PermissionLevelOverrideSelectByGuildIdAndNames, 3>
+    { self.bind(client, &params.guild_id,&params.plugin,&params.command,) }
+}}pub mod permission_level_overrides_delete_by_guild_id
+{ use futures::{{StreamExt, TryStreamExt}};use futures; use cornucopia_async::GenericClient;pub fn permission_level_overrides_delete_by_guild_id() -> PermissionLevelOverridesDeleteByGuildIdStmt
+{ PermissionLevelOverridesDeleteByGuildIdStmt(cornucopia_async::private::Stmt::new("DELETE FROM
+    \"Nightly\".\"GuildPermissionLevelOverrides\"
+WHERE
+    \"guild_id\" = $1")) } pub struct
+PermissionLevelOverridesDeleteByGuildIdStmt(cornucopia_async::private::Stmt); impl PermissionLevelOverridesDeleteByGuildIdStmt
+{ pub async fn bind<'a, C:
+GenericClient,T1:
+cornucopia_async::StringSql,>(&'a mut self, client: &'a  C,
+guild_id: &'a T1,) -> Result<u64, tokio_postgres::Error>
+{
+    let stmt = self.0.prepare(client).await?;
+    client.execute(stmt, &[guild_id,]).await
+} }}pub mod permission_level_select_by_guild_id_and_member
+{ use futures::{{StreamExt, TryStreamExt}};use futures; use cornucopia_async::GenericClient;#[derive( Debug)] pub struct PermissionLevelSelectByGuildIdAndMemberParams<T1: cornucopia_async::StringSql,T2: cornucopia_async::StringSql,T3: cornucopia_async::ArraySql<Item = T2>,T4: cornucopia_async::StringSql,> { pub guild_id: T1,pub role_ids: T3,pub user_id: T4,}pub struct I16Query<'a, C: GenericClient, T, const N: usize>
+{
+    client: &'a  C, params:
+    [&'a (dyn postgres_types::ToSql + Sync); N], stmt: &'a mut
+    cornucopia_async::private::Stmt, extractor: fn(&tokio_postgres::Row) -> i16,
+    mapper: fn(i16) -> T,
+} impl<'a, C, T:'a, const N: usize> I16Query<'a, C, T, N> where C:
+GenericClient
+{
+    pub fn map<R>(self, mapper: fn(i16) -> R) ->
+    I16Query<'a,C,R,N>
+    {
+        I16Query
+        {
+            client: self.client, params: self.params, stmt: self.stmt,
+            extractor: self.extractor, mapper,
+        }
+    } pub async fn one(self) -> Result<T, tokio_postgres::Error>
+    {
+        let stmt = self.stmt.prepare(self.client).await?; let row =
+        self.client.query_one(stmt, &self.params).await?;
+        Ok((self.mapper)((self.extractor)(&row)))
+    } pub async fn all(self) -> Result<Vec<T>, tokio_postgres::Error>
+    { self.iter().await?.try_collect().await } pub async fn opt(self) ->
+    Result<Option<T>, tokio_postgres::Error>
+    {
+        let stmt = self.stmt.prepare(self.client).await?;
+        Ok(self.client.query_opt(stmt, &self.params) .await?
+        .map(|row| (self.mapper)((self.extractor)(&row))))
+    } pub async fn iter(self,) -> Result<impl futures::Stream<Item = Result<T,
+    tokio_postgres::Error>> + 'a, tokio_postgres::Error>
+    {
+        let stmt = self.stmt.prepare(self.client).await?; let it =
+        self.client.query_raw(stmt,
+        cornucopia_async::private::slice_iter(&self.params)) .await?
+        .map(move |res|
+        res.map(|row| (self.mapper)((self.extractor)(&row)))) .into_stream();
+        Ok(it)
+    }
+}pub fn permission_level_select_by_guild_id_and_member() -> PermissionLevelSelectByGuildIdAndMemberStmt
+{ PermissionLevelSelectByGuildIdAndMemberStmt(cornucopia_async::private::Stmt::new("SELECT
+    COALESCE(MAX(\"level\"), 0::SMALLINT)
+FROM
+    \"Nightly\".\"GuildPermissionLevels\"
+WHERE
+    \"guild_id\" = $1 AND (
+        (\"kind\" = 'role' AND \"target_id\" = ANY($2)) OR
+        (\"kind\" = 'user' AND \"target_id\" = $3)
+    )")) } pub struct
+PermissionLevelSelectByGuildIdAndMemberStmt(cornucopia_async::private::Stmt); impl PermissionLevelSelectByGuildIdAndMemberStmt
+{ pub fn bind<'a, C:
+GenericClient,T1:
+cornucopia_async::StringSql,T2:
+cornucopia_async::StringSql,T3:
+cornucopia_async::ArraySql<Item = T2>,T4:
+cornucopia_async::StringSql,>(&'a mut self, client: &'a  C,
+guild_id: &'a T1,role_ids: &'a T3,user_id: &'a T4,) -> I16Query<'a,C,
+i16, 3>
+{
+    I16Query
+    {
+        client, params: [guild_id,role_ids,user_id,], stmt: &mut self.0, extractor:
+        |row| { row.get(0) }, mapper: |it| { it },
+    }
+} }impl <'a, C: GenericClient,T1: cornucopia_async::StringSql,T2: cornucopia_async::StringSql,T3: cornucopia_async::ArraySql<Item = T2>,T4: cornucopia_async::StringSql,> cornucopia_async::Params<'a,
+PermissionLevelSelectByGuildIdAndMemberParams<T1,T2,T3,T4,>, I16Query<'a, C,
+i16, 3>, C> for PermissionLevelSelectByGuildIdAndMemberStmt
+{
+    fn
+    params(&'a mut self, client: &'a  C, params: &'a
+    PermissionLevelSelectByGuildIdAndMemberParams<T1,T2,T3,T4,>) -> I16Query<'a, C,
+    i16, 3>
+    { self.bind(client, &params.guild_id,&params.role_ids,&params.user_id,) }
+}}pub mod permission_levels_delete_by_guild_id
+{ use futures::{{StreamExt, TryStreamExt}};use futures; use cornucopia_async::GenericClient;pub fn permission_levels_delete_by_guild_id() -> PermissionLevelsDeleteByGuildIdStmt
+{ PermissionLevelsDeleteByGuildIdStmt(cornucopia_async::private::Stmt::new("DELETE FROM
+    \"Nightly\".\"GuildPermissionLevels\"
+WHERE
+    \"guild_id\" = $1")) } pub struct
+PermissionLevelsDeleteByGuildIdStmt(cornucopia_async::private::Stmt); impl PermissionLevelsDeleteByGuildIdStmt
+{ pub async fn bind<'a, C:
+GenericClient,T1:
+cornucopia_async::StringSql,>(&'a mut self, client: &'a  C,
+guild_id: &'a T1,) -> Result<u64, tokio_postgres::Error>
+{
+    let stmt = self.0.prepare(client).await?;
+    client.execute(stmt, &[guild_id,]).await
+} }}pub mod plugin_enabled
 { use futures::{{StreamExt, TryStreamExt}};use futures; use cornucopia_async::GenericClient;#[derive( Debug)] pub struct PluginEnabledParams<T1: cornucopia_async::StringSql,T2: cornucopia_async::StringSql,> { pub plugin: T1,pub guild_id: T2,}pub struct BoolQuery<'a, C: GenericClient, T, const N: usize>
 {
     client: &'a  C, params:
@@ -3,26 +3,79 @@
 #[allow(clippy::all, clippy::pedantic)] #[allow(unused_variables)]
 #[allow(unused_imports)] #[allow(dead_code)] pub mod types { }#[allow(clippy::all, clippy::pedantic)] #[allow(unused_variables)]
 #[allow(unused_imports)] #[allow(dead_code)] pub mod queries
-{ pub mod cached_emoji_select_by_guild_id
-{ use futures::{{StreamExt, TryStreamExt}};use futures; use cornucopia_async::GenericClient;#[derive( Debug, Clone, PartialEq,)] pub struct CachedEmojiSelectByGuildId
-{ pub id : String,pub guild_id : String,pub animated : bool,pub name : String,pub managed : bool,}pub struct CachedEmojiSelectByGuildIdBorrowed<'a> { pub id : &'a str,pub guild_id : &'a str,pub animated : bool,pub name : &'a str,pub managed : bool,}
-impl<'a> From<CachedEmojiSelectByGuildIdBorrowed<'a>> for CachedEmojiSelectByGuildId
+{ pub mod cached_channel_delete_by_guild_id
+{ use futures::{{StreamExt, TryStreamExt}};use futures; use cornucopia_async::GenericClient;pub struct StringQuery<'a, C: GenericClient, T, const N: usize>
 {
-    fn from(CachedEmojiSelectByGuildIdBorrowed { id,guild_id,animated,name,managed,}: CachedEmojiSelectByGuildIdBorrowed<'a>) ->
-    Self { Self { id: id.into(),guild_id: guild_id.into(),animated,name: name.into(),managed,} }
-}pub struct CachedEmojiSelectByGuildIdQuery<'a, C: GenericClient, T, const N: usize>
+    client: &'a  C, params:
+    [&'a (dyn postgres_types::ToSql + Sync); N], stmt: &'a mut
+    cornucopia_async::private::Stmt, extractor: fn(&tokio_postgres::Row) -> &'a str,
+    mapper: fn(&'a str) -> T,
+} impl<'a, C, T:'a, const N: usize> StringQuery<'a, C, T, N> where C:
+GenericClient
+{
+    pub fn map<R>(self, mapper: fn(&'a str) -> R) ->
+    StringQuery<'a,C,R,N>
+    {
+        StringQuery
+        {
+            client: self.client, params: self.params, stmt: self.stmt,
+            extractor: self.extractor, mapper,
+        }
+    } pub async fn one(self) -> Result<T, tokio_postgres::Error>
+    {
+        let stmt = self.stmt.prepare(self.client).await?; let row =
+        self.client.query_one(stmt, &self.params).await?;
+        Ok((self.mapper)((self.extractor)(&row)))
+    } pub async fn all(self) -> Result<Vec<T>, tokio_postgres::Error>
+    { self.iter().await?.try_collect().await } pub async fn opt(self) ->
+    Result<Option<T>, tokio_postgres::Error>
+    {
+        let stmt = self.stmt.prepare(self.client).await?;
+        Ok(self.client.query_opt(stmt, &self.params) .await?
+        .map(|row| (self.mapper)((self.extractor)(&row))))
+    } pub async fn iter(self,) -> Result<impl futures::Stream<Item = Result<T,
+    tokio_postgres::Error>> + 'a, tokio_postgres::Error>
+    {
+        let stmt = self.stmt.prepare(self.client).await?; let it =
+        self.client.query_raw(stmt,
+        cornucopia_async::private::slice_iter(&self.params)) .await?
+        .map(move |res|
+        res.map(|row| (self.mapper)((self.extractor)(&row)))) .into_stream();
+        Ok(it)
+    }
+}pub fn cached_channel_delete_by_guild_id() -> CachedChannelDeleteByGuildIdStmt
+{ CachedChannelDeleteByGuildIdStmt(cornucopia_async::private::Stmt::new("DELETE FROM
+    \"DiscordFrontend\".\"Nightly\".\"CachedChannels\"
+WHERE
+    \"guild_id\" = $1
+RETURNING
+    \"id\"")) } pub struct
+CachedChannelDeleteByGuildIdStmt(cornucopia_async::private::Stmt); impl CachedChannelDeleteByGuildIdStmt
+{ pub fn bind<'a, C:
+GenericClient,T1:
+cornucopia_async::StringSql,>(&'a mut self, client: &'a  C,
+guild_id: &'a T1,) -> StringQuery<'a,C,
+String, 1>
+{
+    StringQuery
+    {
+        client, params: [guild_id,], stmt: &mut self.0, extractor:
+        |row| { row.get(0) }, mapper: |it| { it.into() },
+    }
+} }}pub mod cached_channel_delete_by_guild_id_except_ids
+{ use futures::{{StreamExt, TryStreamExt}};use futures; use cornucopia_async::GenericClient;#[derive( Debug)] pub struct CachedChannelDeleteByGuildIdExceptIdsParams<T1: cornucopia_async::StringSql,T2: cornucopia_async::StringSql,T3: cornucopia_async::ArraySql<Item = T2>,> { pub guild_id: T1,pub ids: T3,}pub struct StringQuery<'a, C: GenericClient, T, const N: usize>
 {
     client: &'a  C, params:
     [&'a (dyn postgres_types::ToSql + Sync); N], stmt: &'a mut
-    cornucopia_async::private::Stmt, extractor: fn(&tokio_postgres::Row) -> CachedEmojiSelectByGuildIdBorrowed,
-    mapper: fn(CachedEmojiSelectByGuildIdBorrowed) -> T,
-} impl<'a, C, T:'a, const N: usize> CachedEmojiSelectByGuildIdQuery<'a, C, T, N> where C:
+    cornucopia_async::private::Stmt, extractor: fn(&tokio_postgres::Row) -> &'a str,
+    mapper: fn(&'a str) -> T,
+} impl<'a, C, T:'a, const N: usize> StringQuery<'a, C, T, N> where C:
 GenericClient
 {
-    pub fn map<R>(self, mapper: fn(CachedEmojiSelectByGuildIdBorrowed) -> R) ->
-    CachedEmojiSelectByGuildIdQuery<'a,C,R,N>
+    pub fn map<R>(self, mapper: fn(&'a str) -> R) ->
+    StringQuery<'a,C,R,N>
     {
-        CachedEmojiSelectByGuildIdQuery
+        StringQuery
         {
             client: self.client, params: self.params, stmt: self.stmt,
             extractor: self.extractor, mapper,
@@ -49,45 +102,136 @@ GenericClient
         res.map(|row| (self.mapper)((self.extractor)(&row)))) .into_stream();
         Ok(it)
     }
-}pub fn cached_emoji_select_by_guild_id() -> CachedEmojiSelectByGuildIdStmt
-{ CachedEmojiSelectByGuildIdStmt(cornucopia_async::private::Stmt::new("SELECT
+}pub fn cached_channel_delete_by_guild_id_except_ids() -> CachedChannelDeleteByGuildIdExceptIdsStmt
+{ CachedChannelDeleteByGuildIdExceptIdsStmt(cornucopia_async::private::Stmt::new("DELETE FROM
+    \"DiscordFrontend\".\"Nightly\".\"CachedChannels\"
+WHERE
+    \"guild_id\" = $1 AND
+    NOT (\"id\" = ANY($2))
+RETURNING
+    \"id\"")) } pub struct
+CachedChannelDeleteByGuildIdExceptIdsStmt(cornucopia_async::private::Stmt); impl CachedChannelDeleteByGuildIdExceptIdsStmt
+{ pub fn bind<'a, C:
+GenericClient,T1:
+cornucopia_async::StringSql,T2:
+cornucopia_async::StringSql,T3:
+cornucopia_async::ArraySql<Item = T2>,>(&'a mut self, client: &'a  C,
+guild_id: &'a T1,ids: &'a T3,) -> StringQuery<'a,C,
+String, 2>
+{
+    StringQuery
+    {
+        client, params: [guild_id,ids,], stmt: &mut self.0, extractor:
+        |row| { row.get(0) }, mapper: |it| { it.into() },
+    }
+} }impl <'a, C: GenericClient,T1: cornucopia_async::StringSql,T2: cornucopia_async::StringSql,T3: cornucopia_async::ArraySql<Item = T2>,> cornucopia_async::Params<'a,
+CachedChannelDeleteByGuildIdExceptIdsParams<T1,T2,T3,>, StringQuery<'a, C,
+String, 2>, C> for CachedChannelDeleteByGuildIdExceptIdsStmt
+{
+    fn
+    params(&'a mut self, client: &'a  C, params: &'a
+    CachedChannelDeleteByGuildIdExceptIdsParams<T1,T2,T3,>) -> StringQuery<'a, C,
+    String, 2>
+    { self.bind(client, &params.guild_id,&params.ids,) }
+}}pub mod cached_channel_delete_by_id
+{ use futures::{{StreamExt, TryStreamExt}};use futures; use cornucopia_async::GenericClient;pub fn cached_channel_delete_by_id() -> CachedChannelDeleteByIdStmt
+{ CachedChannelDeleteByIdStmt(cornucopia_async::private::Stmt::new("DELETE FROM
+    \"DiscordFrontend\".\"Nightly\".\"CachedChannels\"
+WHERE
+    \"id\" = $1")) } pub struct
+CachedChannelDeleteByIdStmt(cornucopia_async::private::Stmt); impl CachedChannelDeleteByIdStmt
+{ pub async fn bind<'a, C:
+GenericClient,T1:
+cornucopia_async::StringSql,>(&'a mut self, client: &'a  C,
+id: &'a T1,) -> Result<u64, tokio_postgres::Error>
+{
+    let stmt = self.0.prepare(client).await?;
+    client.execute(stmt, &[id,]).await
+} }}pub mod cached_channel_select_by_guild_id
+{ use futures::{{StreamExt, TryStreamExt}};use futures; use cornucopia_async::GenericClient;#[derive( Debug, Clone, PartialEq,)] pub struct CachedChannelSelectByGuildId
+{ pub id : String,pub guild_id : String,pub kind : i16,pub name : Option<String>,pub nsfw : Option<bool>,pub parent_id : Option<String>,pub permission_overwrites : Option<serde_json::Value>,pub position : Option<i32>,pub rate_limit_per_user : Option<i32>,pub topic : Option<String>,}pub struct CachedChannelSelectByGuildIdBorrowed<'a> { pub id : &'a str,pub guild_id : &'a str,pub kind : i16,pub name : Option<&'a str>,pub nsfw : Option<bool>,pub parent_id : Option<&'a str>,pub permission_overwrites : Option<postgres_types::Json<&'a serde_json::value::RawValue>>,pub position : Option<i32>,pub rate_limit_per_user : Option<i32>,pub topic : Option<&'a str>,}
+impl<'a> From<CachedChannelSelectByGuildIdBorrowed<'a>> for CachedChannelSelectByGuildId
+{
+    fn from(CachedChannelSelectByGuildIdBorrowed { id,guild_id,kind,name,nsfw,parent_id,permission_overwrites,position,rate_limit_per_user,topic,}: CachedChannelSelectByGuildIdBorrowed<'a>) ->
+    Self { Self { id: id.into(),guild_id: guild_id.into(),kind,name: name.map(|v| v.into()),nsfw,parent_id: parent_id.map(|v| v.into()),permission_overwrites: permission_overwrites.map(|v| serde_json::from_str(v.0.get()).unwrap()),position,rate_limit_per_user,topic: topic.map(|v| v.into()),} }
+}pub struct CachedChannelSelectByGuildIdQuery<'a, C: GenericClient, T, const N: usize>
+{
+    client: &'a  C, params:
+    [&'a (dyn postgres_types::ToSql + Sync); N], stmt: &'a mut
+    cornucopia_async::private::Stmt, extractor: fn(&tokio_postgres::Row) -> CachedChannelSelectByGuildIdBorrowed,
+    mapper: fn(CachedChannelSelectByGuildIdBorrowed) -> T,
+} impl<'a, C, T:'a, const N: usize> CachedChannelSelectByGuildIdQuery<'a, C, T, N> where C:
+GenericClient
+{
+    pub fn map<R>(self, mapper: fn(CachedChannelSelectByGuildIdBorrowed) -> R) ->
+    CachedChannelSelectByGuildIdQuery<'a,C,R,N>
+    {
+        CachedChannelSelectByGuildIdQuery
+        {
+            client: self.client, params: self.params, stmt: self.stmt,
+            extractor: self.extractor, mapper,
+        }
+    } pub async fn one(self) -> Result<T, tokio_postgres::Error>
+    {
+        let stmt = self.stmt.prepare(self.client).await?; let row =
+        self.client.query_one(stmt, &self.params).await?;
+        Ok((self.mapper)((self.extractor)(&row)))
+    } pub async fn all(self) -> Result<Vec<T>, tokio_postgres::Error>
+    { self.iter().await?.try_collect().await } pub async fn opt(self) ->
+    Result<Option<T>, tokio_postgres::Error>
+    {
+        let stmt = self.stmt.prepare(self.client).await?;
+        Ok(self.client.query_opt(stmt, &self.params) .await?
+        .map(|row| (self.mapper)((self.extractor)(&row))))
+    } pub async fn iter(self,) -> Result<impl futures::Stream<Item = Result<T,
+    tokio_postgres::Error>> + 'a, tokio_postgres::Error>
+    {
+        let stmt = self.stmt.prepare(self.client).await?; let it =
+        self.client.query_raw(stmt,
+        cornucopia_async::private::slice_iter(&self.params)) .await?
+        .map(move |res|
+        res.map(|row| (self.mapper)((self.extractor)(&row)))) .into_stream();
+        Ok(it)
+    }
+}pub fn cached_channel_select_by_guild_id() -> CachedChannelSelectByGuildIdStmt
+{ CachedChannelSelectByGuildIdStmt(cornucopia_async::private::Stmt::new("SELECT
     *
 FROM
-    \"DiscordFrontend\".\"Nightly\".\"CachedEmojis\"
+    \"DiscordFrontend\".\"Nightly\".\"CachedChannels\"
 WHERE
     \"guild_id\" = $1")) } pub struct
-CachedEmojiSelectByGuildIdStmt(cornucopia_async::private::Stmt); impl CachedEmojiSelectByGuildIdStmt
+CachedChannelSelectByGuildIdStmt(cornucopia_async::private::Stmt); impl CachedChannelSelectByGuildIdStmt
 { pub fn bind<'a, C:
 GenericClient,T1:
 cornucopia_async::StringSql,>(&'a mut self, client: &'a  C,
-guild_id: &'a T1,) -> CachedEmojiSelectByGuildIdQuery<'a,C,
-CachedEmojiSelectByGuildId, 1>
+guild_id: &'a T1,) -> CachedChannelSelectByGuildIdQuery<'a,C,
+CachedChannelSelectByGuildId, 1>
 {
-    CachedEmojiSelectByGuildIdQuery
+    CachedChannelSelectByGuildIdQuery
     {
         client, params: [guild_id,], stmt: &mut self.0, extractor:
-        |row| { CachedEmojiSelectByGuildIdBorrowed { id: row.get(0),guild_id: row.get(1),animated: row.get(2),name: row.get(3),managed: row.get(4),} }, mapper: |it| { <CachedEmojiSelectByGuildId>::from(it) },
+        |row| { CachedChannelSelectByGuildIdBorrowed { id: row.get(0),guild_id: row.get(1),kind: row.get(2),name: row.get(3),nsfw: row.get(4),parent_id: row.get(5),permission_overwrites: row.get(6),position: row.get(7),rate_limit_per_user: row.get(8),topic: row.get(9),} }, mapper: |it| { <CachedChannelSelectByGuildId>::from(it) },
     }
-} }}pub mod cached_emoji_select_by_id
-{ use futures::{{StreamExt, TryStreamExt}};use futures; use cornucopia_async::GenericClient;#[derive( Debug, Clone, PartialEq,)] pub struct CachedEmojiSelectById
-{ pub id : String,pub guild_id : String,pub animated : bool,pub name : String,pub managed : bool,}pub struct CachedEmojiSelectByIdBorrowed<'a> { pub id : &'a str,pub guild_id : &'a str,pub animated : bool,pub name : &'a str,pub managed : bool,}
-impl<'a> From<CachedEmojiSelectByIdBorrowed<'a>> for CachedEmojiSelectById
+} }}pub mod cached_channel_select_by_id
+{ use futures::{{StreamExt, TryStreamExt}};use futures; use cornucopia_async::GenericClient;#[derive( Debug, Clone, PartialEq,)] pub struct CachedChannelSelectById
+{ pub id : String,pub guild_id : String,pub kind : i16,pub name : Option<String>,pub nsfw : Option<bool>,pub parent_id : Option<String>,pub permission_overwrites : Option<serde_json::Value>,pub position : Option<i32>,pub rate_limit_per_user : Option<i32>,pub topic : Option<String>,}pub struct CachedChannelSelectByIdBorrowed<'a> { pub id : &'a str,pub guild_id : &'a str,pub kind : i16,pub name : Option<&'a str>,pub nsfw : Option<bool>,pub parent_id : Option<&'a str>,pub permission_overwrites : Option<postgres_types::Json<&'a serde_json::value::RawValue>>,pub position : Option<i32>,pub rate_limit_per_user : Option<i32>,pub topic : Option<&'a str>,}
+impl<'a> From<CachedChannelSelectByIdBorrowed<'a>> for CachedChannelSelectById
 {
-    fn from(CachedEmojiSelectByIdBorrowed { id,guild_id,animated,name,managed,}: CachedEmojiSelectByIdBorrowed<'a>) ->
-    Self { Self { id: id.into(),guild_id: guild_id.into(),animated,name: name.into(),managed,} }
-}pub struct CachedEmojiSelectByIdQuery<'a, C: GenericClient, T, const N: usize>
+    fn from(CachedChannelSelectByIdBorrowed { id,guild_id,kind,name,nsfw,parent_id,permission_overwrites,position,rate_limit_per_user,topic,}: CachedChannelSelectByIdBorrowed<'a>) ->
+    Self { Self { id: id.into(),guild_id: guild_id.into(),kind,name: name.map(|v| v.into()),nsfw,parent_id: parent_id.map(|v| v.into()),permission_overwrites: permission_overwrites.map(|v| serde_json::from_str(v.0.get()).unwrap()),position,rate_limit_per_user,topic: topic.map(|v| v.into()),} }
+}pub struct CachedChannelSelectByIdQuery<'a, C: GenericClient, T, const N: usize>
 {
     client: &'a  C, params:
     [&'a (dyn postgres_types::ToSql + Sync); N], stmt: &'a mut
-    cornucopia_async::private::Stmt, extractor: fn(&tokio_postgres::Row) -> CachedEmojiSelectByIdBorrowed,
-    mapper: fn(CachedEmojiSelectByIdBorrowed) -> T,
-} impl<'a, C, T:'a, const N: usize> CachedEmojiSelectByIdQuery<'a, C, T, N> where C:
+    cornucopia_async::private::Stmt, extractor: fn(&tokio_postgres::Row) -> CachedChannelSelectByIdBorrowed,
+    mapper: fn(CachedChannelSelectByIdBorrowed) -> T,
+} impl<'a, C, T:'a, const N: usize> CachedChannelSelectByIdQuery<'a, C, T, N> where C:
 GenericClient
 {
-    pub fn map<R>(self, mapper: fn(CachedEmojiSelectByIdBorrowed) -> R) ->
-    CachedEmojiSelectByIdQuery<'a,C,R,N>
+    pub fn map<R>(self, mapper: fn(CachedChannelSelectByIdBorrowed) -> R) ->
+    CachedChannelSelectByIdQuery<'a,C,R,N>
     {
-        CachedEmojiSelectByIdQuery
+        CachedChannelSelectByIdQuery
         {
             client: self.client, params: self.params, stmt: self.stmt,
             extractor: self.extractor, mapper,
@@ -114,74 +258,76 @@ GenericClient
         res.map(|row| (self.mapper)((self.extractor)(&row)))) .into_stream();
         Ok(it)
     }
-}pub fn cached_emoji_select_by_id() -> CachedEmojiSelectByIdStmt
-{ CachedEmojiSelectByIdStmt(cornucopia_async::private::Stmt::new("SELECT
+}pub fn cached_channel_select_by_id() -> CachedChannelSelectByIdStmt
+{ CachedChannelSelectByIdStmt(cornucopia_async::private::Stmt::new("SELECT
     *
 FROM
-    \"DiscordFrontend\".\"Nightly\".\"CachedEmojis\"
+    \"DiscordFrontend\".\"Nightly\".\"CachedChannels\"
 WHERE
     \"id\" = $1")) } pub struct
-CachedEmojiSelectByIdStmt(cornucopia_async::private::Stmt); impl CachedEmojiSelectByIdStmt
+CachedChannelSelectByIdStmt(cornucopia_async::private::Stmt); impl CachedChannelSelectByIdStmt
 { pub fn bind<'a, C:
 GenericClient,T1:
 cornucopia_async::StringSql,>(&'a mut self, client: &'a  C,
-id: &'a T1,) -> CachedEmojiSelectByIdQuery<'a,C,
-CachedEmojiSelectById, 1>
+id: &'a T1,) -> CachedChannelSelectByIdQuery<'a,C,
+CachedChannelSelectById, 1>
 {
-    CachedEmojiSelectByIdQuery
+    CachedChannelSelectByIdQuery
     {
         client, params: [id,], stmt: &mut self.0, extractor:
-        |row| { CachedEmojiSelectByIdBorrowed { id: row.get(0),guild_id: row.get(1),animated: row.get(2),name: row.get(3),managed: row.get(4),} }, mapper: |it| { <CachedEmojiSelectById>::from(it) },
+        |row| { CachedChannelSelectByIdBorrowed { id: row.get(0),guild_id: row.get(1),kind: row.get(2),name: row.get(3),nsfw: row.get(4),parent_id: row.get(5),permission_overwrites: row.get(6),position: row.get(7),rate_limit_per_user: row.get(8),topic: row.get(9),} }, mapper: |it| { <CachedChannelSelectById>::from(it) },
     }
-} }}pub mod cached_emoji_upsert
-{ use futures::{{StreamExt, TryStreamExt}};use futures; use cornucopia_async::GenericClient;#[derive( Debug)] pub struct CachedEmojiUpsertParams<T1: cornucopia_async::StringSql,T2: cornucopia_async::StringSql,T3: cornucopia_async::StringSql,> { pub animated: bool,pub name: T1,pub id: T2,pub guild_id: T3,pub managed: bool,}pub fn cached_emoji_upsert() -> CachedEmojiUpsertStmt
-{ CachedEmojiUpsertStmt(cornucopia_async::private::Stmt::new("INSERT INTO \"DiscordFrontend\".\"Nightly\".\"CachedEmojis\" (\"animated\", \"name\", \"id\", \"guild_id\", \"managed\")
-VALUES ($1, $2, $3, $4, $5)
+} }}pub mod cached_channel_upsert
+{ use futures::{{StreamExt, TryStreamExt}};use futures; use cornucopia_async::GenericClient;#[derive( Debug)] pub struct CachedChannelUpsertParams<T1: cornucopia_async::StringSql,T2: cornucopia_async::StringSql,T3: cornucopia_async::StringSql,T4: cornucopia_async::StringSql,T5: cornucopia_async::JsonSql,T6: cornucopia_async::StringSql,> { pub id: T1,pub guild_id: T2,pub kind: i16,pub name: Option<T3>,pub nsfw: Option<bool>,pub parent_id: Option<T4>,pub permission_overwrites: Option<T5>,pub position: Option<i32>,pub rate_limit_per_user: Option<i32>,pub topic: Option<T6>,}pub fn cached_channel_upsert() -> CachedChannelUpsertStmt
+{ CachedChannelUpsertStmt(cornucopia_async::private::Stmt::new("INSERT INTO \"DiscordFrontend\".\"Nightly\".\"CachedChannels\" (\"id\", \"guild_id\", \"kind\", \"name\", \"nsfw\", \"parent_id\", \"permission_overwrites\", \"position\", \"rate_limit_per_user\", \"topic\")
+VALUES ($1, $2, $3, $4, $5, $6, $7, $8, $9, $10)
 ON CONFLICT (\"id\") DO UPDATE
     SET
-        \"guild_id\" = $4,
-        \"animated\" = $1,
-        \"name\" = $2,
-        \"managed\" = $5")) } pub struct
-CachedEmojiUpsertStmt(cornucopia_async::private::Stmt); impl CachedEmojiUpsertStmt
+        \"guild_id\" = $2,
+        \"kind\" = $3,
+        \"name\" = $4,
+        \"nsfw\" = $5,
+        \"parent_id\" = $6,
+        \"permission_overwrites\" = $7,
+        \"position\" = $8,
+        \"rate_limit_per_user\" = $9,
+        \"topic\" = $10")) } pub struct
+CachedChannelUpsertStmt(cornucopia_async::private::Stmt); impl CachedChannelUpsertStmt
 { pub async fn bind<'a, C:
 GenericClient,T1:
 cornucopia_async::StringSql,T2:
 cornucopia_async::StringSql,T3:
+cornucopia_async::StringSql,T4:
+cornucopia_async::StringSql,T5:
+cornucopia_async::JsonSql,T6:
 cornucopia_async::StringSql,>(&'a mut self, client: &'a  C,
-animated: &'a bool,name: &'a T1,id: &'a T2,guild_id: &'a T3,managed: &'a bool,) -> Result<u64, tokio_postgres::Error>
+id: &'a T1,guild_id: &'a T2,kind: &'a i16,name: &'a Option<T3>,nsfw: &'a Option<bool>,parent_id: &'a Option<T4>,permission_overwrites: &'a Option<T5>,position: &'a Option<i32>,rate_limit_per_user: &'a Option<i32>,topic: &'a Option<T6>,) -> Result<u64, tokio_postgres::Error>
 {
     let stmt = self.0.prepare(client).await?;
-    client.execute(stmt, &[animated,name,id,guild_id,managed,]).await
-} }impl <'a, C: GenericClient + Send + Sync, T1: cornucopia_async::StringSql,T2: cornucopia_async::StringSql,T3: cornucopia_async::StringSql,>
-cornucopia_async::Params<'a, CachedEmojiUpsertParams<T1,T2,T3,>, std::pin::Pin<Box<dyn futures::Future<Output = Result<u64,
-tokio_postgres::Error>> + Send + 'a>>, C> for CachedEmojiUpsertStmt
+    client.execute(stmt, &[id,guild_id,kind,name,nsfw,parent_id,permission_overwrites,position,rate_limit_per_user,topic,]).await
+} }impl <'a, C: GenericClient + Send + Sync, T1: cornucopia_async::StringSql,T2: cornucopia_async::StringSql,T3: cornucopia_async::StringSql,T4: cornucopia_async::StringSql,T5: cornucopia_async::JsonSql,T6: cornucopia_async::StringSql,>
+cornucopia_async::Params<'a, CachedChannelUpsertParams<T1,T2,T3,T4,T5,T6,>, std::pin::Pin<Box<dyn futures::Future<Output = Result<u64,
+tokio_postgres::Error>> + Send + 'a>>, C> for CachedChannelUpsertStmt
 {
     fn
     params(&'a mut self, client: &'a  C, params: &'a
-    CachedEmojiUpsertParams<T1,T2,T3,>) -> std::pin::Pin<Box<dyn futures::Future<Output = Result<u64,
+    CachedChannelUpsertParams<T1,T2,T3,T4,T5,T6,>) -> std::pin::Pin<Box<dyn futures::Future<Output = Result<u64,
     tokio_postgres::Error>> + Send + 'a>>
-    { Box::pin(self.bind(client, &params.animated,&params.name,&params.id,&params.guild_id,&params.managed,)) }
-}}pub mod cached_guild_select_by_id
-{ use futures::{{StreamExt, TryStreamExt}};use futures; use cornucopia_async::GenericClient;#[derive( Debug, Clone, PartialEq,)] pub struct CachedGuildSelectById
-{ pub default_message_notifications : i16,pub explicit_content_filter : i16,pub features : Vec<String>,pub icon : Option<String>,pub id : String,pub large : bool,pub mfa_level : i16,pub name : String,pub owner_id : String,pub premium_subscription_count : Option<i64>,pub premium_tier : i16,pub verification_level : i16,}pub struct CachedGuildSelectByIdBorrowed<'a> { pub default_message_notifications : i16,pub explicit_content_filter : i16,pub features : cornucopia_async::ArrayIterator<'a, &'a str>,pub icon : Option<&'a str>,pub id : &'a str,pub large : bool,pub mfa_level : i16,pub name : &'a str,pub owner_id : &'a str,pub premium_subscription_count : Option<i64>,pub premium_tier : i16,pub verification_level : i16,}
-impl<'a> From<CachedGuildSelectByIdBorrowed<'a>> for CachedGuildSelectById
-{
-    fn from(CachedGuildSelectByIdBorrowed { default_message_notifications,explicit_content_filter,features,icon,id,large,mfa_level,name,owner_id,premium_subscription_count,premium_tier,verification_level,}: CachedGuildSelectByIdBorrowed<'a>) ->
-    Self { Self { default_message_notifications,explicit_content_filter,features: features.map(|v| v.into()).collect(),icon: icon.map(|v| v.into()),id: id.into(),large,mfa_level,name: name.into(),owner_id: owner_id.into(),premium_subscription_count,premium_tier,verification_level,} }
-}pub struct CachedGuildSelectByIdQuery<'a, C: GenericClient, T, const N: usize>
+    { Box::pin(self.bind(client, &params.id,&params.guild_id,&params.kind,&params.name,&params.nsfw,&params.parent_id,&params.permission_overwrites,&params.position,&params.rate_limit_per_user,&params.topic,)) }
+}}pub mod cached_emoji_delete_by_guild_id
+{ use futures::{{StreamExt, TryStreamExt}};use futures; use cornucopia_async::GenericClient;pub struct StringQuery<'a, C: GenericClient, T, const N: usize>
 {
     client: &'a  C, params:
     [&'a (dyn postgres_types::ToSql + Sync); N], stmt: &'a mut
-    cornucopia_async::private::Stmt, extractor: fn(&tokio_postgres::Row) -> CachedGuildSelectByIdBorrowed,
-    mapper: fn(CachedGuildSelectByIdBorrowed) -> T,
-} impl<'a, C, T:'a, const N: usize> CachedGuildSelectByIdQuery<'a, C, T, N> where C:
+    cornucopia_async::private::Stmt, extractor: fn(&tokio_postgres::Row) -> &'a str,
+    mapper: fn(&'a str) -> T,
+} impl<'a, C, T:'a, const N: usize> StringQuery<'a, C, T, N> where C:
 GenericClient
 {
-    pub fn map<R>(self, mapper: fn(CachedGuildSelectByIdBorrowed) -> R) ->
-    CachedGuildSelectByIdQuery<'a,C,R,N>
+    pub fn map<R>(self, mapper: fn(&'a str) -> R) ->
+    StringQuery<'a,C,R,N>
     {
-        CachedGuildSelectByIdQuery
+        StringQuery
         {
             client: self.client, params: self.params, stmt: self.stmt,
             extractor: self.extractor, mapper,
@@ -208,85 +354,2040 @@ GenericClient
         res.map(|row| (self.mapper)((self.extractor)(&row)))) .into_stream();
         Ok(it)
     }
-}pub fn cached_guild_select_by_id() -> CachedGuildSelectByIdStmt
-{ CachedGuildSelectByIdStmt(cornucopia_async::private::Stmt::new("SELECT
-    *
-FROM
-    \"DiscordFrontend\".\"Nightly\".\"CachedGuilds\"
+}pub fn cached_emoji_delete_by_guild_id() -> CachedEmojiDeleteByGuildIdStmt
+{ CachedEmojiDeleteByGuildIdStmt(cornucopia_async::private::Stmt::new("DELETE FROM
+    \"DiscordFrontend\".\"Nightly\".\"CachedEmojis\"
 WHERE
-    \"id\" = $1")) } pub struct
-CachedGuildSelectByIdStmt(cornucopia_async::private::Stmt); impl CachedGuildSelectByIdStmt
+    \"guild_id\" = $1
+RETURNING
+    \"id\"")) } pub struct
+CachedEmojiDeleteByGuildIdStmt(cornucopia_async::private::Stmt); impl CachedEmojiDeleteByGuildIdStmt
 { pub fn bind<'a, C:
 GenericClient,T1:
 cornucopia_async::StringSql,>(&'a mut self, client: &'a  C,
-id: &'a T1,) -> CachedGuildSelectByIdQuery<'a,C,
-CachedGuildSelectById, 1>
+guild_id: &'a T1,) -> StringQuery<'a,C,
+String, 1>
 {
-    CachedGuildSelectByIdQuery
+    StringQuery
     {
-        client, params: [id,], stmt: &mut self.0, extractor:
-        |row| { CachedGuildSelectByIdBorrowed { default_message_notifications: row.get(0),explicit_content_filter: row.get(1),features: row.get(2),icon: row.get(3),id: row.get(4),large: row.get(5),mfa_level: row.get(6),name: row.get(7),owner_id: row.get(8),premium_subscription_count: row.get(9),premium_tier: row.get(10),verification_level: row.get(11),} }, mapper: |it| { <CachedGuildSelectById>::from(it) },
+        client, params: [guild_id,], stmt: &mut self.0, extractor:
+        |row| { row.get(0) }, mapper: |it| { it.into() },
     }
-} }}pub mod cached_guild_upsert
-{ use futures::{{StreamExt, TryStreamExt}};use futures; use cornucopia_async::GenericClient;#[derive( Debug)] pub struct CachedGuildUpsertParams<T1: cornucopia_async::StringSql,T2: cornucopia_async::ArraySql<Item = T1>,T3: cornucopia_async::StringSql,T4: cornucopia_async::StringSql,T5: cornucopia_async::StringSql,T6: cornucopia_async::StringSql,> { pub default_message_notifications: i16,pub explicit_content_filter: i16,pub features: T2,pub icon: Option<T3>,pub large: bool,pub name: T4,pub owner_id: T5,pub id: T6,pub mfa_level: i16,pub premium_subscription_count: Option<i64>,pub premium_tier: i16,pub verification_level: i16,}pub fn cached_guild_upsert() -> CachedGuildUpsertStmt
-{ CachedGuildUpsertStmt(cornucopia_async::private::Stmt::new("INSERT INTO
-    \"DiscordFrontend\".\"Nightly\".\"CachedGuilds\" (\"default_message_notifications\", \"explicit_content_filter\", \"features\", \"icon\", \"large\", \"name\", \"owner_id\", \"id\", \"mfa_level\", \"premium_subscription_count\", \"premium_tier\", \"verification_level\")
-VALUES ($1, $2, $3, $4, $5, $6, $7, $8, $9, $10, $11, $12)
-ON CONFLICT (\"id\") DO UPDATE
-    SET
-        \"default_message_notifications\" = $1,
-        \"explicit_content_filter\" = $2,
-        \"features\" = $3,
-        \"icon\" = $4,
-        \"large\" = $5,
-        \"mfa_level\" = $9,
-        \"name\" = $6,
-        \"owner_id\" = $7,
-        \"premium_subscription_count\" = $10,
-        \"premium_tier\" = $11,
-        \"verification_level\" = $12")) } pub struct
-CachedGuildUpsertStmt(cornucopia_async::private::Stmt); impl CachedGuildUpsertStmt
+} }}pub mod cached_emoji_delete_by_guild_id_except_ids
+{ use futures::{{StreamExt, TryStreamExt}};use futures; use cornucopia_async::GenericClient;#[derive( Debug)] pub struct CachedEmojiDeleteByGuildIdExceptIdsParams<T1: cornucopia_async::StringSql,T2: cornucopia_async::StringSql,T3: cornucopia_async::ArraySql<Item = T2>,> { pub guild_id: T1,pub ids: T3,}pub struct StringQuery<'a, C: GenericClient, T, const N: usize>
+{
+    client: &'a  C, params:
+    [&'a (dyn postgres_types::ToSql + Sync); N], stmt: &'a mut
+    cornucopia_async::private::Stmt, extractor: fn(&tokio_postgres::Row) -> &'a str,
+    mapper: fn(&'a str) -> T,
+} impl<'a, C, T:'a, const N: usize> StringQuery<'a, C, T, N> where C:
+GenericClient
+{
+    pub fn map<R>(self, mapper: fn(&'a str) -> R) ->
+    StringQuery<'a,C,R,N>
+    {
+        StringQuery
+        {
+            client: self.client, params: self.params, stmt: self.stmt,
+            extractor: self.extractor, mapper,
+        }
+    } pub async fn one(self) -> Result<T, tokio_postgres::Error>
+    {
+        let stmt = self.stmt.prepare(self.client).await?; let row =
+        self.client.query_one(stmt, &self.params).await?;
+        Ok((self.mapper)((self.extractor)(&row)))
+    } pub async fn all(self) -> Result<Vec<T>, tokio_postgres::Error>
+    { self.iter().await?.try_collect().await } pub async fn opt(self) ->
+    Result<Option<T>, tokio_postgres::Error>
+    {
+        let stmt = self.stmt.prepare(self.client).await?;
+        Ok(self.client.query_opt(stmt, &self.params) .await?
+        .map(|row| (self.mapper)((self.extractor)(&row))))
+    } pub async fn iter(self,) -> Result<impl futures::Stream<Item = Result<T,
+    tokio_postgres::Error>> + 'a, tokio_postgres::Error>
+    {
+        let stmt = self.stmt.prepare(self.client).await?; let it =
+        self.client.query_raw(stmt,
+        cornucopia_async::private::slice_iter(&self.params)) .await?
+        .map(move |res|
+        res.map(|row| (self.mapper)((self.extractor)(&row)))) .into_stream();
+        Ok(it)
+    }
+}pub fn cached_emoji_delete_by_guild_id_except_ids() -> CachedEmojiDeleteByGuildIdExceptIdsStmt
+{ CachedEmojiDeleteByGuildIdExceptIdsStmt(cornucopia_async::private::Stmt::new("DELETE FROM
+    \"DiscordFrontend\".\"Nightly\".\"CachedEmojis\"
+WHERE
+    \"guild_id\" = $1 AND
+    NOT (\"id\" = ANY($2))
+RETURNING
+    \"id\"")) } pub struct
+CachedEmojiDeleteByGuildIdExceptIdsStmt(cornucopia_async::private::Stmt); impl CachedEmojiDeleteByGuildIdExceptIdsStmt
+{ pub fn bind<'a, C:
+GenericClient,T1:
+cornucopia_async::StringSql,T2:
+cornucopia_async::StringSql,T3:
+cornucopia_async::ArraySql<Item = T2>,>(&'a mut self, client: &'a  C,
+guild_id: &'a T1,ids: &'a T3,) -> StringQuery<'a,C,
+String, 2>
+{
+    StringQuery
+    {
+        client, params: [guild_id,ids,], stmt: &mut self.0, extractor:
+        |row| { row.get(0) }, mapper: |it| { it.into() },
+    }
+} }impl <'a, C: GenericClient,T1: cornucopia_async::StringSql,T2: cornucopia_async::StringSql,T3: cornucopia_async::ArraySql<Item = T2>,> cornucopia_async::Params<'a,
+CachedEmojiDeleteByGuildIdExceptIdsParams<T1,T2,T3,>, StringQuery<'a, C,
+String, 2>, C> for CachedEmojiDeleteByGuildIdExceptIdsStmt
+{
+    fn
+    params(&'a mut self, client: &'a  C, params: &'a
+    CachedEmojiDeleteByGuildIdExceptIdsParams<T1,T2,T3,>) -> StringQuery<'a, C,
+    String, 2>
+    { self.bind(client, &params.guild_id,&params.ids,) }
+}}pub mod cached_emoji_delete_by_id
+{ use futures::{{StreamExt, TryStreamExt}};use futures; use cornucopia_async::GenericClient;pub fn cached_emoji_delete_by_id() -> CachedEmojiDeleteByIdStmt
+{ CachedEmojiDeleteByIdStmt(cornucopia_async::private::Stmt::new("DELETE FROM
+    \"DiscordFrontend\".\"Nightly\".\"CachedEmojis\"
+WHERE
+    \"id\" = $1")) } pub struct
+CachedEmojiDeleteByIdStmt(cornucopia_async::private::Stmt); impl CachedEmojiDeleteByIdStmt
+{ pub async fn bind<'a, C:
+GenericClient,T1:
+cornucopia_async::StringSql,>(&'a mut self, client: &'a  C,
+id: &'a T1,) -> Result<u64, tokio_postgres::Error>
+{
+    let stmt = self.0.prepare(client).await?;
+    client.execute(stmt, &[id,]).await
+} }}pub mod cached_emoji_select_by_guild_id
+{ use futures::{{StreamExt, TryStreamExt}};use futures; use cornucopia_async::GenericClient;#[derive( Debug, Clone, PartialEq,)] pub struct CachedEmojiSelectByGuildId
+{ pub id : String,pub guild_id : String,pub animated : bool,pub name : String,pub managed : bool,}pub struct CachedEmojiSelectByGuildIdBorrowed<'a> { pub id : &'a str,pub guild_id : &'a str,pub animated : bool,pub name : &'a str,pub managed : bool,}
+impl<'a> From<CachedEmojiSelectByGuildIdBorrowed<'a>> for CachedEmojiSelectByGuildId
+{
+    fn from(CachedEmojiSelectByGuildIdBorrowed { id,guild_id,animated,name,managed,}: CachedEmojiSelectByGuildIdBorrowed<'a>) ->
+    Self { Self { id: id.into(),guild_id: guild_id.into(),animated,name: name.into(),managed,} }
+}pub struct CachedEmojiSelectByGuildIdQuery<'a, C: GenericClient, T, const N: usize>
+{
+    client: &'a  C, params:
+    [&'a (dyn postgres_types::ToSql + Sync); N], stmt: &'a mut
+    cornucopia_async::private::Stmt, extractor: fn(&tokio_postgres::Row) -> CachedEmojiSelectByGuildIdBorrowed,
+    mapper: fn(CachedEmojiSelectByGuildIdBorrowed) -> T,
+} impl<'a, C, T:'a, const N: usize> CachedEmojiSelectByGuildIdQuery<'a, C, T, N> where C:
+GenericClient
+{
+    pub fn map<R>(self, mapper: fn(CachedEmojiSelectByGuildIdBorrowed) -> R) ->
+    CachedEmojiSelectByGuildIdQuery<'a,C,R,N>
+    {
+        CachedEmojiSelectByGuildIdQuery
+        {
+            client: self.client, params: self.params, stmt: self.stmt,
+            extractor: self.extractor, mapper,
+        }
+    } pub async fn one(self) -> Result<T, tokio_postgres::Error>
+    {
+        let stmt = self.stmt.prepare(self.client).await?; let row =
+        self.client.query_one(stmt, &self.params).await?;
+        Ok((self.mapper)((self.extractor)(&row)))
+    } pub async fn all(self) -> Result<Vec<T>, tokio_postgres::Error>
+    { self.iter().await?.try_collect().await } pub async fn opt(self) ->
+    Result<Option<T>, tokio_postgres::Error>
+    {
+        let stmt = self.stmt.prepare(self.client).await?;
+        Ok(self.client.query_opt(stmt, &self.params) .await?
+        .map(|row| (self.mapper)((self.extractor)(&row))))
+    } pub async fn iter(self,) -> Result<impl futures::Stream<Item = Result<T,
+    tokio_postgres::Error>> + 'a, tokio_postgres::Error>
+    {
+        let stmt = self.stmt.prepare(self.client).await?; let it =
+        self.client.query_raw(stmt,
+        cornucopia_async::private::slice_iter(&self.params)) .await?
+        .map(move |res|
+        res.map(|row| (self.mapper)((self.extractor)(&row)))) .into_stream();
+        Ok(it)
+    }
+}pub fn cached_emoji_select_by_guild_id() -> CachedEmojiSelectByGuildIdStmt
+{ CachedEmojiSelectByGuildIdStmt(cornucopia_async::private::Stmt::new("SELECT
+    *
+FROM
+    \"DiscordFrontend\".\"Nightly\".\"CachedEmojis\"
+WHERE
+    \"guild_id\" = $1")) } pub struct
+CachedEmojiSelectByGuildIdStmt(cornucopia_async::private::Stmt); impl CachedEmojiSelectByGuildIdStmt
+{ pub fn bind<'a, C:
+GenericClient,T1:
+cornucopia_async::StringSql,>(&'a mut self, client: &'a  C,
+guild_id: &'a T1,) -> CachedEmojiSelectByGuildIdQuery<'a,C,
+CachedEmojiSelectByGuildId, 1>
+{
+    CachedEmojiSelectByGuildIdQuery
+    {
+        client, params: [guild_id,], stmt: &mut self.0, extractor:
+        |row| { CachedEmojiSelectByGuildIdBorrowed { id: row.get(0),guild_id: row.get(1),animated: row.get(2),name: row.get(3),managed: row.get(4),} }, mapper: |it| { <CachedEmojiSelectByGuildId>::from(it) },
+    }
+} }}pub mod cached_emoji_select_by_id
+{ use futures::{{StreamExt, TryStreamExt}};use futures; use cornucopia_async::GenericClient;#[derive( Debug, Clone, PartialEq,)] pub struct CachedEmojiSelectById
+{ pub id : String,pub guild_id : String,pub animated : bool,pub name : String,pub managed : bool,}pub struct CachedEmojiSelectByIdBorrowed<'a> { pub id : &'a str,pub guild_id : &'a str,pub animated : bool,pub name : &'a str,pub managed : bool,}
+impl<'a> From<CachedEmojiSelectByIdBorrowed<'a>> for CachedEmojiSelectById
+{
+    fn from(CachedEmojiSelectByIdBorrowed { id,guild_id,animated,name,managed,}: CachedEmojiSelectByIdBorrowed<'a>) ->
+    Self { Self { id: id.into(),guild_id: guild_id.into(),animated,name: name.into(),managed,} }
+}pub struct CachedEmojiSelectByIdQuery<'a, C: GenericClient, T, const N: usize>
+{
+    client: &'a  C, params:
+    [&'a (dyn postgres_types::ToSql + Sync); N], stmt: &'a mut
+    cornucopia_async::private::Stmt, extractor: fn(&tokio_postgres::Row) -> CachedEmojiSelectByIdBorrowed,
+    mapper: fn(CachedEmojiSelectByIdBorrowed) -> T,
+} impl<'a, C, T:'a, const N: usize> CachedEmojiSelectByIdQuery<'a, C, T, N> where C:
+GenericClient
+{
+    pub fn map<R>(self, mapper: fn(CachedEmojiSelectByIdBorrowed) -> R) ->
+    CachedEmojiSelectByIdQuery<'a,C,R,N>
+    {
+        CachedEmojiSelectByIdQuery
+        {
+            client: self.client, params: self.params, stmt: self.stmt,
+            extractor: self.extractor, mapper,
+        }
+    } pub async fn one(self) -> Result<T, tokio_postgres::Error>
+    {
+        let stmt = self.stmt.prepare(self.client).await?; let row =
+        self.client.query_one(stmt, &self.params).await?;
+        Ok((self.mapper)((self.extractor)(&row)))
+    } pub async fn all(self) -> Result<Vec<T>, tokio_postgres::Error>
+    { self.iter().await?.try_collect().await } pub async fn opt(self) ->
+    Result<Option<T>, tokio_postgres::Error>
+    {
+        let stmt = self.stmt.prepare(self.client).await?;
+        Ok(self.client.query_opt(stmt, &self.params) .await?
+        .map(|row| (self.mapper)((self.extractor)(&row))))
+    } pub async fn iter(self,) -> Result<impl futures::Stream<Item = Result<T,
+    tokio_postgres::Error>> + 'a, tokio_postgres::Error>
+    {
+        let stmt = self.stmt.prepare(self.client).await?; let it =
+        self.client.query_raw(stmt,
+        cornucopia_async::private::slice_iter(&self.params)) .await?
+        .map(move |res|
+        res.map(|row| (self.mapper)((self.extractor)(&row)))) .into_stream();
+        Ok(it)
+    }
+}pub fn cached_emoji_select_by_id() -> CachedEmojiSelectByIdStmt
+{ CachedEmojiSelectByIdStmt(cornucopia_async::private::Stmt::new("SELECT
+    *
+FROM
+    \"DiscordFrontend\".\"Nightly\".\"CachedEmojis\"
+WHERE
+    \"id\" = $1")) } pub struct
+CachedEmojiSelectByIdStmt(cornucopia_async::private::Stmt); impl CachedEmojiSelectByIdStmt
+{ pub fn bind<'a, C:
+GenericClient,T1:
+cornucopia_async::StringSql,>(&'a mut self, client: &'a  C,
+id: &'a T1,) -> CachedEmojiSelectByIdQuery<'a,C,
+CachedEmojiSelectById, 1>
+{
+    CachedEmojiSelectByIdQuery
+    {
+        client, params: [id,], stmt: &mut self.0, extractor:
+        |row| { CachedEmojiSelectByIdBorrowed { id: row.get(0),guild_id: row.get(1),animated: row.get(2),name: row.get(3),managed: row.get(4),} }, mapper: |it| { <CachedEmojiSelectById>::from(it) },
+    }
+} }}pub mod cached_emoji_upsert
+{ use futures::{{StreamExt, TryStreamExt}};use futures; use cornucopia_async::GenericClient;#[derive( Debug)] pub struct CachedEmojiUpsertParams<T1: cornucopia_async::StringSql,T2: cornucopia_async::StringSql,T3: cornucopia_async::StringSql,> { pub animated: bool,pub name: T1,pub id: T2,pub guild_id: T3,pub managed: bool,}pub fn cached_emoji_upsert() -> CachedEmojiUpsertStmt
+{ CachedEmojiUpsertStmt(cornucopia_async::private::Stmt::new("INSERT INTO \"DiscordFrontend\".\"Nightly\".\"CachedEmojis\" (\"animated\", \"name\", \"id\", \"guild_id\", \"managed\")
+VALUES ($1, $2, $3, $4, $5)
+ON CONFLICT (\"id\") DO UPDATE
+    SET
+        \"guild_id\" = $4,
+        \"animated\" = $1,
+        \"name\" = $2,
+        \"managed\" = $5")) } pub struct
+CachedEmojiUpsertStmt(cornucopia_async::private::Stmt); impl CachedEmojiUpsertStmt
+{ pub async fn bind<'a, C:
+GenericClient,T1:
+cornucopia_async::StringSql,T2:
+cornucopia_async::StringSql,T3:
+cornucopia_async::StringSql,>(&'a mut self, client: &'a  C,
+animated: &'a bool,name: &'a T1,id: &'a T2,guild_id: &'a T3,managed: &'a bool,) -> Result<u64, tokio_postgres::Error>
+{
+    let stmt = self.0.prepare(client).await?;
+    client.execute(stmt, &[animated,name,id,guild_id,managed,]).await
+} }impl <'a, C: GenericClient + Send + Sync, T1: cornucopia_async::StringSql,T2: cornucopia_async::StringSql,T3: cornucopia_async::StringSql,>
+cornucopia_async::Params<'a, CachedEmojiUpsertParams<T1,T2,T3,>, std::pin::Pin<Box<dyn futures::Future<Output = Result<u64,
+tokio_postgres::Error>> + Send + 'a>>, C> for CachedEmojiUpsertStmt
+{
+    fn
+    params(&'a mut self, client: &'a  C, params: &'a
+    CachedEmojiUpsertParams<T1,T2,T3,>) -> std::pin::Pin<Box<dyn futures::Future<Output = Result<u64,
+    tokio_postgres::Error>> + Send + 'a>>
+    { Box::pin(self.bind(client, &params.animated,&params.name,&params.id,&params.guild_id,&params.managed,)) }
+}}pub mod cached_guild_delete_by_id
+{ use futures::{{StreamExt, TryStreamExt}};use futures; use cornucopia_async::GenericClient;pub fn cached_guild_delete_by_id() -> CachedGuildDeleteByIdStmt
+{ CachedGuildDeleteByIdStmt(cornucopia_async::private::Stmt::new("DELETE FROM
+    \"DiscordFrontend\".\"Nightly\".\"CachedGuilds\"
+WHERE
+    \"id\" = $1")) } pub struct
+CachedGuildDeleteByIdStmt(cornucopia_async::private::Stmt); impl CachedGuildDeleteByIdStmt
+{ pub async fn bind<'a, C:
+GenericClient,T1:
+cornucopia_async::StringSql,>(&'a mut self, client: &'a  C,
+id: &'a T1,) -> Result<u64, tokio_postgres::Error>
+{
+    let stmt = self.0.prepare(client).await?;
+    client.execute(stmt, &[id,]).await
+} }}pub mod cached_guild_select_by_id
+{ use futures::{{StreamExt, TryStreamExt}};use futures; use cornucopia_async::GenericClient;#[derive( Debug, Clone, PartialEq,)] pub struct CachedGuildSelectById
+{ pub default_message_notifications : i16,pub explicit_content_filter : i16,pub features : Vec<String>,pub icon : Option<String>,pub id : String,pub large : bool,pub mfa_level : i16,pub name : String,pub owner_id : String,pub premium_subscription_count : Option<i64>,pub premium_tier : i16,pub verification_level : i16,}pub struct CachedGuildSelectByIdBorrowed<'a> { pub default_message_notifications : i16,pub explicit_content_filter : i16,pub features : cornucopia_async::ArrayIterator<'a, &'a str>,pub icon : Option<&'a str>,pub id : &'a str,pub large : bool,pub mfa_level : i16,pub name : &'a str,pub owner_id : &'a str,pub premium_subscription_count : Option<i64>,pub premium_tier : i16,pub verification_level : i16,}
+impl<'a> From<CachedGuildSelectByIdBorrowed<'a>> for CachedGuildSelectById
+{
+    fn from(CachedGuildSelectByIdBorrowed { default_message_notifications,explicit_content_filter,features,icon,id,large,mfa_level,name,owner_id,premium_subscription_count,premium_tier,verification_level,}: CachedGuildSelectByIdBorrowed<'a>) ->
+    Self { Self { default_message_notifications,explicit_content_filter,features: features.map(|v| v.into()).collect(),icon: icon.map(|v| v.into()),id: id.into(),large,mfa_level,name: name.into(),owner_id: owner_id.into(),premium_subscription_count,premium_tier,verification_level,} }
+}pub struct CachedGuildSelectByIdQuery<'a, C: GenericClient, T, const N: usize>
+{
+    client: &'a  C, params:
+    [&'a (dyn postgres_types::ToSql + Sync); N], stmt: &'a mut
+    cornucopia_async::private::Stmt, extractor: fn(&tokio_postgres::Row) -> CachedGuildSelectByIdBorrowed,
+    mapper: fn(CachedGuildSelectByIdBorrowed) -> T,
+} impl<'a, C, T:'a, const N: usize> CachedGuildSelectByIdQuery<'a, C, T, N> where C:
+GenericClient
+{
+    pub fn map<R>(self, mapper: fn(CachedGuildSelectByIdBorrowed) -> R) ->
+    CachedGuildSelectByIdQuery<'a,C,R,N>
+    {
+        CachedGuildSelectByIdQuery
+        {
+            client: self.client, params: self.params, stmt: self.stmt,
+            extractor: self.extractor, mapper,
+        }
+    } pub async fn one(self) -> Result<T, tokio_postgres::Error>
+    {
+        let stmt = self.stmt.prepare(self.client).await?; let row =
+        self.client.query_one(stmt, &self.params).await?;
+        Ok((self.mapper)((self.extractor)(&row)))
+    } pub async fn all(self) -> Result<Vec<T>, tokio_postgres::Error>
+    { self.iter().await?.try_collect().await } pub async fn opt(self) ->
+    Result<Option<T>, tokio_postgres::Error>
+    {
+        let stmt = self.stmt.prepare(self.client).await?;
+        Ok(self.client.query_opt(stmt, &self.params) .await?
+        .map(|row| (self.mapper)((self.extractor)(&row))))
+    } pub async fn iter(self,) -> Result<impl futures::Stream<Item = Result<T,
+    tokio_postgres::Error>> + 'a, tokio_postgres::Error>
+    {
+        let stmt = self.stmt.prepare(self.client).await?; let it =
+        self.client.query_raw(stmt,
+        cornucopia_async::private::slice_iter(&self.params)) .await?
+        .map(move |res|
+        res.map(|row| (self.mapper)((self.extractor)(&row)))) .into_stream();
+        Ok(it)
+    }
+}pub fn cached_guild_select_by_id() -> CachedGuildSelectByIdStmt
+{ CachedGuildSelectByIdStmt(cornucopia_async::private::Stmt::new("SELECT
+    *
+FROM
+    \"DiscordFrontend\".\"Nightly\".\"CachedGuilds\"
+WHERE
+    \"id\" = $1")) } pub struct
+CachedGuildSelectByIdStmt(cornucopia_async::private::Stmt); impl CachedGuildSelectByIdStmt
+{ pub fn bind<'a, C:
+GenericClient,T1:
+cornucopia_async::StringSql,>(&'a mut self, client: &'a  C,
+id: &'a T1,) -> CachedGuildSelectByIdQuery<'a,C,
+CachedGuildSelectById, 1>
+{
+    CachedGuildSelectByIdQuery
+    {
+        client, params: [id,], stmt: &mut self.0, extractor:
+        |row| { CachedGuildSelectByIdBorrowed { default_message_notifications: row.get(0),explicit_content_filter: row.get(1),features: row.get(2),icon: row.get(3),id: row.get(4),large: row.get(5),mfa_level: row.get(6),name: row.get(7),owner_id: row.get(8),premium_subscription_count: row.get(9),premium_tier: row.get(10),verification_level: row.get(11),} }, mapper: |it| { <CachedGuildSelectById>::from(it) },
+    }
+} }}pub mod cached_guild_select_ids
+{ use futures::{{StreamExt, TryStreamExt}};use futures; use cornucopia_async::GenericClient;pub struct StringQuery<'a, C: GenericClient, T, const N: usize>
+{
+    client: &'a  C, params:
+    [&'a (dyn postgres_types::ToSql + Sync); N], stmt: &'a mut
+    cornucopia_async::private::Stmt, extractor: fn(&tokio_postgres::Row) -> &'a str,
+    mapper: fn(&'a str) -> T,
+} impl<'a, C, T:'a, const N: usize> StringQuery<'a, C, T, N> where C:
+GenericClient
+{
+    pub fn map<R>(self, mapper: fn(&'a str) -> R) ->
+    StringQuery<'a,C,R,N>
+    {
+        StringQuery
+        {
+            client: self.client, params: self.params, stmt: self.stmt,
+            extractor: self.extractor, mapper,
+        }
+    } pub async fn one(self) -> Result<T, tokio_postgres::Error>
+    {
+        let stmt = self.stmt.prepare(self.client).await?; let row =
+        self.client.query_one(stmt, &self.params).await?;
+        Ok((self.mapper)((self.extractor)(&row)))
+    } pub async fn all(self) -> Result<Vec<T>, tokio_postgres::Error>
+    { self.iter().await?.try_collect().await } pub async fn opt(self) ->
+    Result<Option<T>, tokio_postgres::Error>
+    {
+        let stmt = self.stmt.prepare(self.client).await?;
+        Ok(self.client.query_opt(stmt, &self.params) .await?
+        .map(|row| (self.mapper)((self.extractor)(&row))))
+    } pub async fn iter(self,) -> Result<impl futures::Stream<Item = Result<T,
+    tokio_postgres::Error>> + 'a, tokio_postgres::Error>
+    {
+        let stmt = self.stmt.prepare(self.client).await?; let it =
+        self.client.query_raw(stmt,
+        cornucopia_async::private::slice_iter(&self.params)) .await?
+        .map(move |res|
+        res.map(|row| (self.mapper)((self.extractor)(&row)))) .into_stream();
+        Ok(it)
+    }
+}pub fn cached_guild_select_ids() -> CachedGuildSelectIdsStmt
+{ CachedGuildSelectIdsStmt(cornucopia_async::private::Stmt::new("SELECT
+    \"id\"
+FROM
+    \"DiscordFrontend\".\"Nightly\".\"CachedGuilds\"")) } pub struct
+CachedGuildSelectIdsStmt(cornucopia_async::private::Stmt); impl CachedGuildSelectIdsStmt
+{ pub fn bind<'a, C:
+GenericClient,>(&'a mut self, client: &'a  C,
+) -> StringQuery<'a,C,
+String, 0>
+{
+    StringQuery
+    {
+        client, params: [], stmt: &mut self.0, extractor:
+        |row| { row.get(0) }, mapper: |it| { it.into() },
+    }
+} }}pub mod cached_guild_upsert
+{ use futures::{{StreamExt, TryStreamExt}};use futures; use cornucopia_async::GenericClient;#[derive( Debug)] pub struct CachedGuildUpsertParams<T1: cornucopia_async::StringSql,T2: cornucopia_async::ArraySql<Item = T1>,T3: cornucopia_async::StringSql,T4: cornucopia_async::StringSql,T5: cornucopia_async::StringSql,T6: cornucopia_async::StringSql,> { pub default_message_notifications: i16,pub explicit_content_filter: i16,pub features: T2,pub icon: Option<T3>,pub large: bool,pub name: T4,pub owner_id: T5,pub id: T6,pub mfa_level: i16,pub premium_subscription_count: Option<i64>,pub premium_tier: i16,pub verification_level: i16,}pub fn cached_guild_upsert() -> CachedGuildUpsertStmt
+{ CachedGuildUpsertStmt(cornucopia_async::private::Stmt::new("INSERT INTO
+    \"DiscordFrontend\".\"Nightly\".\"CachedGuilds\" (\"default_message_notifications\", \"explicit_content_filter\", \"features\", \"icon\", \"large\", \"name\", \"owner_id\", \"id\", \"mfa_level\", \"premium_subscription_count\", \"premium_tier\", \"verification_level\")
+VALUES ($1, $2, $3, $4, $5, $6, $7, $8, $9, $10, $11, $12)
+ON CONFLICT (\"id\") DO UPDATE
+    SET
+        \"default_message_notifications\" = $1,
+        \"explicit_content_filter\" = $2,
+        \"features\" = $3,
+        \"icon\" = $4,
+        \"large\" = $5,
+        \"mfa_level\" = $9,
+        \"name\" = $6,
+        \"owner_id\" = $7,
+        \"premium_subscription_count\" = $10,
+        \"premium_tier\" = $11,
+        \"verification_level\" = $12")) } pub struct
+CachedGuildUpsertStmt(cornucopia_async::private::Stmt); impl CachedGuildUpsertStmt
+{ pub async fn bind<'a, C:
+GenericClient,T1:
+cornucopia_async::StringSql,T2:
+cornucopia_async::ArraySql<Item = T1>,T3:
+cornucopia_async::StringSql,T4:
+cornucopia_async::StringSql,T5:
+cornucopia_async::StringSql,T6:
+cornucopia_async::StringSql,>(&'a mut self, client: &'a  C,
+default_message_notifications: &'a i16,explicit_content_filter: &'a i16,features: &'a T2,icon: &'a Option<T3>,large: &'a bool,name: &'a T4,owner_id: &'a T5,id: &'a T6,mfa_level: &'a i16,premium_subscription_count: &'a Option<i64>,premium_tier: &'a i16,verification_level: &'a i16,) -> Result<u64, tokio_postgres::Error>
+{
+    let stmt = self.0.prepare(client).await?;
+    client.execute(stmt, &[default_message_notifications,explicit_content_filter,features,icon,large,name,owner_id,id,mfa_level,premium_subscription_count,premium_tier,verification_level,]).await
+} }impl <'a, C: GenericClient + Send + Sync, T1: cornucopia_async::StringSql,T2: cornucopia_async::ArraySql<Item = T1>,T3: cornucopia_async::StringSql,T4: cornucopia_async::StringSql,T5: cornucopia_async::StringSql,T6: cornucopia_async::StringSql,>
+cornucopia_async::Params<'a, CachedGuildUpsertParams<T1,T2,T3,T4,T5,T6,>, std::pin::Pin<Box<dyn futures::Future<Output = Result<u64,
+tokio_postgres::Error>> + Send + 'a>>, C> for CachedGuildUpsertStmt
+{
+    fn
+    params(&'a mut self, client: &'a  C, params: &'a
+    CachedGuildUpsertParams<T1,T2,T3,T4,T5,T6,>) -> std::pin::Pin<Box<dyn futures::Future<Output = Result<u64,
+    tokio_postgres::Error>> + Send + 'a>>
+    { Box::pin(self.bind(client, &params.default_message_notifications,&params.explicit_content_filter,&params.features,&params.icon,&params.large,&params.name,&params.owner_id,&params.id,&params.mfa_level,&params.premium_subscription_count,&params.premium_tier,&params.verification_level,)) }
+}}pub mod cached_member_delete_by_guild_id
+{ use futures::{{StreamExt, TryStreamExt}};use futures; use cornucopia_async::GenericClient;pub fn cached_member_delete_by_guild_id() -> CachedMemberDeleteByGuildIdStmt
+{ CachedMemberDeleteByGuildIdStmt(cornucopia_async::private::Stmt::new("DELETE FROM
+    \"DiscordFrontend\".\"Nightly\".\"CachedMembers\"
+WHERE
+    \"guild_id\" = $1")) } pub struct
+CachedMemberDeleteByGuildIdStmt(cornucopia_async::private::Stmt); impl CachedMemberDeleteByGuildIdStmt
+{ pub async fn bind<'a, C:
+GenericClient,T1:
+cornucopia_async::StringSql,>(&'a mut self, client: &'a  C,
+guild_id: &'a T1,) -> Result<u64, tokio_postgres::Error>
+{
+    let stmt = self.0.prepare(client).await?;
+    client.execute(stmt, &[guild_id,]).await
+} }}pub mod cached_member_delete_by_user_id_and_guild_id
+{ use futures::{{StreamExt, TryStreamExt}};use futures; use cornucopia_async::GenericClient;#[derive( Debug)] pub struct CachedMemberDeleteByUserIdAndGuildIdParams<T1: cornucopia_async::StringSql,T2: cornucopia_async::StringSql,> { pub user_id: T1,pub guild_id: T2,}pub fn cached_member_delete_by_user_id_and_guild_id() -> CachedMemberDeleteByUserIdAndGuildIdStmt
+{ CachedMemberDeleteByUserIdAndGuildIdStmt(cornucopia_async::private::Stmt::new("DELETE FROM
+    \"DiscordFrontend\".\"Nightly\".\"CachedMembers\"
+WHERE
+    \"user_id\" = $1 AND
+    \"guild_id\" = $2")) } pub struct
+CachedMemberDeleteByUserIdAndGuildIdStmt(cornucopia_async::private::Stmt); impl CachedMemberDeleteByUserIdAndGuildIdStmt
+{ pub async fn bind<'a, C:
+GenericClient,T1:
+cornucopia_async::StringSql,T2:
+cornucopia_async::StringSql,>(&'a mut self, client: &'a  C,
+user_id: &'a T1,guild_id: &'a T2,) -> Result<u64, tokio_postgres::Error>
+{
+    let stmt = self.0.prepare(client).await?;
+    client.execute(stmt, &[user_id,guild_id,]).await
+} }impl <'a, C: GenericClient + Send + Sync, T1: cornucopia_async::StringSql,T2: cornucopia_async::StringSql,>
+cornucopia_async::Params<'a, CachedMemberDeleteByUserIdAndGuildIdParams<T1,T2,>, std::pin::Pin<Box<dyn futures::Future<Output = Result<u64,
+tokio_postgres::Error>> + Send + 'a>>, C> for CachedMemberDeleteByUserIdAndGuildIdStmt
+{
+    fn
+    params(&'a mut self, client: &'a  C, params: &'a
+    CachedMemberDeleteByUserIdAndGuildIdParams<T1,T2,>) -> std::pin::Pin<Box<dyn futures::Future<Output = Result<u64,
+    tokio_postgres::Error>> + Send + 'a>>
+    { Box::pin(self.bind(client, &params.user_id,&params.guild_id,)) }
+}}pub mod cached_member_remove_role
+{ use futures::{{StreamExt, TryStreamExt}};use futures; use cornucopia_async::GenericClient;#[derive( Debug)] pub struct CachedMemberRemoveRoleParams<T1: cornucopia_async::StringSql,T2: cornucopia_async::StringSql,> { pub role_id: T1,pub guild_id: T2,}pub struct StringQuery<'a, C: GenericClient, T, const N: usize>
+{
+    client: &'a  C, params:
+    [&'a (dyn postgres_types::ToSql + Sync); N], stmt: &'a mut
+    cornucopia_async::private::Stmt, extractor: fn(&tokio_postgres::Row) -> &'a str,
+    mapper: fn(&'a str) -> T,
+} impl<'a, C, T:'a, const N: usize> StringQuery<'a, C, T, N> where C:
+GenericClient
+{
+    pub fn map<R>(self, mapper: fn(&'a str) -> R) ->
+    StringQuery<'a,C,R,N>
+    {
+        StringQuery
+        {
+            client: self.client, params: self.params, stmt: self.stmt,
+            extractor: self.extractor, mapper,
+        }
+    } pub async fn one(self) -> Result<T, tokio_postgres::Error>
+    {
+        let stmt = self.stmt.prepare(self.client).await?; let row =
+        self.client.query_one(stmt, &self.params).await?;
+        Ok((self.mapper)((self.extractor)(&row)))
+    } pub async fn all(self) -> Result<Vec<T>, tokio_postgres::Error>
+    { self.iter().await?.try_collect().await } pub async fn opt(self) ->
+    Result<Option<T>, tokio_postgres::Error>
+    {
+        let stmt = self.stmt.prepare(self.client).await?;
+        Ok(self.client.query_opt(stmt, &self.params) .await?
+        .map(|row| (self.mapper)((self.extractor)(&row))))
+    } pub async fn iter(self,) -> Result<impl futures::Stream<Item = Result<T,
+    tokio_postgres::Error>> + 'a, tokio_postgres::Error>
+    {
+        let stmt = self.stmt.prepare(self.client).await?; let it =
+        self.client.query_raw(stmt,
+        cornucopia_async::private::slice_iter(&self.params)) .await?
+        .map(move |res|
+        res.map(|row| (self.mapper)((self.extractor)(&row)))) .into_stream();
+        Ok(it)
+    }
+}pub fn cached_member_remove_role() -> CachedMemberRemoveRoleStmt
+{ CachedMemberRemoveRoleStmt(cornucopia_async::private::Stmt::new("UPDATE
+    \"DiscordFrontend\".\"Nightly\".\"CachedMembers\"
+SET
+    \"roles\" = ARRAY_REMOVE(\"roles\", $1)
+WHERE
+    \"guild_id\" = $2 AND
+    $1 = ANY(\"roles\")
+RETURNING
+    \"user_id\"")) } pub struct
+CachedMemberRemoveRoleStmt(cornucopia_async::private::Stmt); impl CachedMemberRemoveRoleStmt
+{ pub fn bind<'a, C:
+GenericClient,T1:
+cornucopia_async::StringSql,T2:
+cornucopia_async::StringSql,>(&'a mut self, client: &'a  C,
+role_id: &'a T1,guild_id: &'a T2,) -> StringQuery<'a,C,
+String, 2>
+{
+    StringQuery
+    {
+        client, params: [role_id,guild_id,], stmt: &mut self.0, extractor:
+        |row| { row.get(0) }, mapper: |it| { it.into() },
+    }
+} }impl <'a, C: GenericClient,T1: cornucopia_async::StringSql,T2: cornucopia_async::StringSql,> cornucopia_async::Params<'a,
+CachedMemberRemoveRoleParams<T1,T2,>, StringQuery<'a, C,
+String, 2>, C> for CachedMemberRemoveRoleStmt
+{
+    fn
+    params(&'a mut self, client: &'a  C, params: &'a
+    CachedMemberRemoveRoleParams<T1,T2,>) -> StringQuery<'a, C,
+    String, 2>
+    { self.bind(client, &params.role_id,&params.guild_id,) }
+}}pub mod cached_member_select_by_guild_id
+{ use futures::{{StreamExt, TryStreamExt}};use futures; use cornucopia_async::GenericClient;#[derive( Debug, Clone, PartialEq,)] pub struct CachedMemberSelectByGuildId
+{ pub flags : i64,pub joined_at : Option<time::OffsetDateTime>,pub nick : Option<String>,pub roles : Vec<String>,pub guild_id : String,pub user_id : String,pub avatar : Option<String>,}pub struct CachedMemberSelectByGuildIdBorrowed<'a> { pub flags : i64,pub joined_at : Option<time::OffsetDateTime>,pub nick : Option<&'a str>,pub roles : cornucopia_async::ArrayIterator<'a, &'a str>,pub guild_id : &'a str,pub user_id : &'a str,pub avatar : Option<&'a str>,}
+impl<'a> From<CachedMemberSelectByGuildIdBorrowed<'a>> for CachedMemberSelectByGuildId
+{
+    fn from(CachedMemberSelectByGuildIdBorrowed { flags,joined_at,nick,roles,guild_id,user_id,avatar,}: CachedMemberSelectByGuildIdBorrowed<'a>) ->
+    Self { Self { flags,joined_at,nick: nick.map(|v| v.into()),roles: roles.map(|v| v.into()).collect(),guild_id: guild_id.into(),user_id: user_id.into(),avatar: avatar.map(|v| v.into()),} }
+}pub struct CachedMemberSelectByGuildIdQuery<'a, C: GenericClient, T, const N: usize>
+{
+    client: &'a  C, params:
+    [&'a (dyn postgres_types::ToSql + Sync); N], stmt: &'a mut
+    cornucopia_async::private::Stmt, extractor: fn(&tokio_postgres::Row) -> CachedMemberSelectByGuildIdBorrowed,
+    mapper: fn(CachedMemberSelectByGuildIdBorrowed) -> T,
+} impl<'a, C, T:'a, const N: usize> CachedMemberSelectByGuildIdQuery<'a, C, T, N> where C:
+GenericClient
+{
+    pub fn map<R>(self, mapper: fn(CachedMemberSelectByGuildIdBorrowed) -> R) ->
+    CachedMemberSelectByGuildIdQuery<'a,C,R,N>
+    {
+        CachedMemberSelectByGuildIdQuery
+        {
+            client: self.client, params: self.params, stmt: self.stmt,
+            extractor: self.extractor, mapper,
+        }
+    } pub async fn one(self) -> Result<T, tokio_postgres::Error>
+    {
+        let stmt = self.stmt.prepare(self.client).await?; let row =
+        self.client.query_one(stmt, &self.params).await?;
+        Ok((self.mapper)((self.extractor)(&row)))
+    } pub async fn all(self) -> Result<Vec<T>, tokio_postgres::Error>
+    { self.iter().await?.try_collect().await } pub async fn opt(self) ->
+    Result<Option<T>, tokio_postgres::Error>
+    {
+        let stmt = self.stmt.prepare(self.client).await?;
+        Ok(self.client.query_opt(stmt, &self.params) .await?
+        .map(|row| (self.mapper)((self.extractor)(&row))))
+    } pub async fn iter(self,) -> Result<impl futures::Stream<Item = Result<T,
+    tokio_postgres::Error>> + 'a, tokio_postgres::Error>
+    {
+        let stmt = self.stmt.prepare(self.client).await?; let it =
+        self.client.query_raw(stmt,
+        cornucopia_async::private::slice_iter(&self.params)) .await?
+        .map(move |res|
+        res.map(|row| (self.mapper)((self.extractor)(&row)))) .into_stream();
+        Ok(it)
+    }
+}pub fn cached_member_select_by_guild_id() -> CachedMemberSelectByGuildIdStmt
+{ CachedMemberSelectByGuildIdStmt(cornucopia_async::private::Stmt::new("SELECT
+    *
+FROM
+    \"DiscordFrontend\".\"Nightly\".\"CachedMembers\"
+WHERE
+    \"guild_id\" = $1")) } pub struct
+CachedMemberSelectByGuildIdStmt(cornucopia_async::private::Stmt); impl CachedMemberSelectByGuildIdStmt
+{ pub fn bind<'a, C:
+GenericClient,T1:
+cornucopia_async::StringSql,>(&'a mut self, client: &'a  C,
+guild_id: &'a T1,) -> CachedMemberSelectByGuildIdQuery<'a,C,
+CachedMemberSelectByGuildId, 1>
+{
+    CachedMemberSelectByGuildIdQuery
+    {
+        client, params: [guild_id,], stmt: &mut self.0, extractor:
+        |row| { CachedMemberSelectByGuildIdBorrowed { flags: row.get(0),joined_at: row.get(1),nick: row.get(2),roles: row.get(3),guild_id: row.get(4),user_id: row.get(5),avatar: row.get(6),} }, mapper: |it| { <CachedMemberSelectByGuildId>::from(it) },
+    }
+} }}pub mod cached_member_select_by_user_id_and_guild_id
+{ use futures::{{StreamExt, TryStreamExt}};use futures; use cornucopia_async::GenericClient;#[derive( Debug)] pub struct CachedMemberSelectByUserIdAndGuildIdParams<T1: cornucopia_async::StringSql,T2: cornucopia_async::StringSql,> { pub user_id: T1,pub guild_id: T2,}#[derive( Debug, Clone, PartialEq,)] pub struct CachedMemberSelectByUserIdAndGuildId
+{ pub flags : i64,pub joined_at : Option<time::OffsetDateTime>,pub nick : Option<String>,pub roles : Vec<String>,pub guild_id : String,pub user_id : String,pub avatar : Option<String>,}pub struct CachedMemberSelectByUserIdAndGuildIdBorrowed<'a> { pub flags : i64,pub joined_at : Option<time::OffsetDateTime>,pub nick : Option<&'a str>,pub roles : cornucopia_async::ArrayIterator<'a, &'a str>,pub guild_id : &'a str,pub user_id : &'a str,pub avatar : Option<&'a str>,}
+impl<'a> From<CachedMemberSelectByUserIdAndGuildIdBorrowed<'a>> for CachedMemberSelectByUserIdAndGuildId
+{
+    fn from(CachedMemberSelectByUserIdAndGuildIdBorrowed { flags,joined_at,nick,roles,guild_id,user_id,avatar,}: CachedMemberSelectByUserIdAndGuildIdBorrowed<'a>) ->
+    Self { Self { flags,joined_at,nick: nick.map(|v| v.into()),roles: roles.map(|v| v.into()).collect(),guild_id: guild_id.into(),user_id: user_id.into(),avatar: avatar.map(|v| v.into()),} }
+}pub struct CachedMemberSelectByUserIdAndGuildIdQuery<'a, C: GenericClient, T, const N: usize>
+{
+    client: &'a  C, params:
+    [&'a (dyn postgres_types::ToSql + Sync); N], stmt: &'a mut
+    cornucopia_async::private::Stmt, extractor: fn(&tokio_postgres::Row) -> CachedMemberSelectByUserIdAndGuildIdBorrowed,
+    mapper: fn(CachedMemberSelectByUserIdAndGuildIdBorrowed) -> T,
+} impl<'a, C, T:'a, const N: usize> CachedMemberSelectByUserIdAndGuildIdQuery<'a, C, T, N> where C:
+GenericClient
+{
+    pub fn map<R>(self, mapper: fn(CachedMemberSelectByUserIdAndGuildIdBorrowed) -> R) ->
+    CachedMemberSelectByUserIdAndGuildIdQuery<'a,C,R,N>
+    {
+        CachedMemberSelectByUserIdAndGuildIdQuery
+        {
+            client: self.client, params: self.params, stmt: self.stmt,
+            extractor: self.extractor, mapper,
+        }
+    } pub async fn one(self) -> Result<T, tokio_postgres::Error>
+    {
+        let stmt = self.stmt.prepare(self.client).await?; let row =
+        self.client.query_one(stmt, &self.params).await?;
+        Ok((self.mapper)((self.extractor)(&row)))
+    } pub async fn all(self) -> Result<Vec<T>, tokio_postgres::Error>
+    { self.iter().await?.try_collect().await } pub async fn opt(self) ->
+    Result<Option<T>, tokio_postgres::Error>
+    {
+        let stmt = self.stmt.prepare(self.client).await?;
+        Ok(self.client.query_opt(stmt, &self.params) .await?
+        .map(|row| (self.mapper)((self.extractor)(&row))))
+    } pub async fn iter(self,) -> Result<impl futures::Stream<Item = Result<T,
+    tokio_postgres::Error>> + 'a, tokio_postgres::Error>
+    {
+        let stmt = self.stmt.prepare(self.client).await?; let it =
+        self.client.query_raw(stmt,
+        cornucopia_async::private::slice_iter(&self.params)) .await?
+        .map(move |res|
+        res.map(|row| (self.mapper)((self.extractor)(&row)))) .into_stream();
+        Ok(it)
+    }
+}pub fn cached_member_select_by_user_id_and_guild_id() -> CachedMemberSelectByUserIdAndGuildIdStmt
+{ CachedMemberSelectByUserIdAndGuildIdStmt(cornucopia_async::private::Stmt::new("SELECT
+    *
+FROM
+    \"DiscordFrontend\".\"Nightly\".\"CachedMembers\"
+WHERE
+    user_id = $1 AND
+    guild_id = $2")) } pub struct
+CachedMemberSelectByUserIdAndGuildIdStmt(cornucopia_async::private::Stmt); impl CachedMemberSelectByUserIdAndGuildIdStmt
+{ pub fn bind<'a, C:
+GenericClient,T1:
+cornucopia_async::StringSql,T2:
+cornucopia_async::StringSql,>(&'a mut self, client: &'a  C,
+user_id: &'a T1,guild_id: &'a T2,) -> CachedMemberSelectByUserIdAndGuildIdQuery<'a,C,
+CachedMemberSelectByUserIdAndGuildId, 2>
+{
+    CachedMemberSelectByUserIdAndGuildIdQuery
+    {
+        client, params: [user_id,guild_id,], stmt: &mut self.0, extractor:
+        |row| { CachedMemberSelectByUserIdAndGuildIdBorrowed { flags: row.get(0),joined_at: row.get(1),nick: row.get(2),roles: row.get(3),guild_id: row.get(4),user_id: row.get(5),avatar: row.get(6),} }, mapper: |it| { <CachedMemberSelectByUserIdAndGuildId>::from(it) },
+    }
+} }impl <'a, C: GenericClient,T1: cornucopia_async::StringSql,T2: cornucopia_async::StringSql,> cornucopia_async::Params<'a,
+CachedMemberSelectByUserIdAndGuildIdParams<T1,T2,>, CachedMemberSelectByUserIdAndGuildIdQuery<'a, C,
+CachedMemberSelectByUserIdAndGuildId, 2>, C> for CachedMemberSelectByUserIdAndGuildIdStmt
+{
+    fn
+    params(&'a mut self, client: &'a  C, params: &'a
+    CachedMemberSelectByUserIdAndGuildIdParams<T1,T2,>) -> CachedMemberSelectByUserIdAndGuildIdQuery<'a, C,
+    CachedMemberSelectByUserIdAndGuildId, 2>
+    { self.bind(client, &params.user_id,&params.guild_id,) }
+}}pub mod cached_member_upsert
+{ use futures::{{StreamExt, TryStreamExt}};use futures; use cornucopia_async::GenericClient;#[derive( Debug)] pub struct CachedMemberUpsertParams<T1: cornucopia_async::StringSql,T2: cornucopia_async::StringSql,T3: cornucopia_async::StringSql,T4: cornucopia_async::StringSql,T5: cornucopia_async::StringSql,T6: cornucopia_async::ArraySql<Item = T5>,> { pub avatar: Option<T1>,pub flags: i64,pub joined_at: Option<time::OffsetDateTime>,pub nick: Option<T2>,pub user_id: T3,pub guild_id: T4,pub roles: T6,}pub fn cached_member_upsert() -> CachedMemberUpsertStmt
+{ CachedMemberUpsertStmt(cornucopia_async::private::Stmt::new("INSERT INTO \"DiscordFrontend\".\"Nightly\".\"CachedMembers\" (\"avatar\", \"flags\", \"joined_at\", \"nick\", \"user_id\", \"guild_id\", \"roles\")
+VALUES ($1, $2, $3, $4, $5, $6, $7)
+ON CONFLICT (\"user_id\", \"guild_id\") DO UPDATE
+    SET
+        \"avatar\" = $1,
+        \"flags\" = $2,
+        \"joined_at\" = $3,
+        \"nick\" = $4,
+        \"roles\" = $7")) } pub struct
+CachedMemberUpsertStmt(cornucopia_async::private::Stmt); impl CachedMemberUpsertStmt
+{ pub async fn bind<'a, C:
+GenericClient,T1:
+cornucopia_async::StringSql,T2:
+cornucopia_async::StringSql,T3:
+cornucopia_async::StringSql,T4:
+cornucopia_async::StringSql,T5:
+cornucopia_async::StringSql,T6:
+cornucopia_async::ArraySql<Item = T5>,>(&'a mut self, client: &'a  C,
+avatar: &'a Option<T1>,flags: &'a i64,joined_at: &'a Option<time::OffsetDateTime>,nick: &'a Option<T2>,user_id: &'a T3,guild_id: &'a T4,roles: &'a T6,) -> Result<u64, tokio_postgres::Error>
+{
+    let stmt = self.0.prepare(client).await?;
+    client.execute(stmt, &[avatar,flags,joined_at,nick,user_id,guild_id,roles,]).await
+} }impl <'a, C: GenericClient + Send + Sync, T1: cornucopia_async::StringSql,T2: cornucopia_async::StringSql,T3: cornucopia_async::StringSql,T4: cornucopia_async::StringSql,T5: cornucopia_async::StringSql,T6: cornucopia_async::ArraySql<Item = T5>,>
+cornucopia_async::Params<'a, CachedMemberUpsertParams<T1,T2,T3,T4,T5,T6,>, std::pin::Pin<Box<dyn futures::Future<Output = Result<u64,
+tokio_postgres::Error>> + Send + 'a>>, C> for CachedMemberUpsertStmt
+{
+    fn
+    params(&'a mut self, client: &'a  C, params: &'a
+    CachedMemberUpsertParams<T1,T2,T3,T4,T5,T6,>) -> std::pin::Pin<Box<dyn futures::Future<Output = Result<u64,
+    tokio_postgres::Error>> + Send + 'a>>
+    { Box::pin(self.bind(client, &params.avatar,&params.flags,&params.joined_at,&params.nick,&params.user_id,&params.guild_id,&params.roles,)) }
+}}pub mod cached_message_delete_by_id
+{ use futures::{{StreamExt, TryStreamExt}};use futures; use cornucopia_async::GenericClient;pub fn cached_message_delete_by_id() -> CachedMessageDeleteByIdStmt
+{ CachedMessageDeleteByIdStmt(cornucopia_async::private::Stmt::new("DELETE FROM
+    \"DiscordFrontend\".\"Nightly\".\"CachedMessages\"
+WHERE
+    \"id\" = $1")) } pub struct
+CachedMessageDeleteByIdStmt(cornucopia_async::private::Stmt); impl CachedMessageDeleteByIdStmt
+{ pub async fn bind<'a, C:
+GenericClient,T1:
+cornucopia_async::StringSql,>(&'a mut self, client: &'a  C,
+id: &'a T1,) -> Result<u64, tokio_postgres::Error>
+{
+    let stmt = self.0.prepare(client).await?;
+    client.execute(stmt, &[id,]).await
+} }}pub mod cached_message_mark_deleted
+{ use futures::{{StreamExt, TryStreamExt}};use futures; use cornucopia_async::GenericClient;pub fn cached_message_mark_deleted() -> CachedMessageMarkDeletedStmt
+{ CachedMessageMarkDeletedStmt(cornucopia_async::private::Stmt::new("UPDATE
+    \"DiscordFrontend\".\"Nightly\".\"CachedMessages\"
+SET
+    \"deleted\" = TRUE
+WHERE
+    \"id\" = ANY($1)")) } pub struct
+CachedMessageMarkDeletedStmt(cornucopia_async::private::Stmt); impl CachedMessageMarkDeletedStmt
+{ pub async fn bind<'a, C:
+GenericClient,T1:
+cornucopia_async::StringSql,T2:
+cornucopia_async::ArraySql<Item = T1>,>(&'a mut self, client: &'a  C,
+ids: &'a T2,) -> Result<u64, tokio_postgres::Error>
+{
+    let stmt = self.0.prepare(client).await?;
+    client.execute(stmt, &[ids,]).await
+} }}pub mod cached_message_prune_before
+{ use futures::{{StreamExt, TryStreamExt}};use futures; use cornucopia_async::GenericClient;pub fn cached_message_prune_before() -> CachedMessagePruneBeforeStmt
+{ CachedMessagePruneBeforeStmt(cornucopia_async::private::Stmt::new("DELETE FROM
+    \"DiscordFrontend\".\"Nightly\".\"CachedMessages\"
+WHERE
+    \"timestamp\" < $1")) } pub struct
+CachedMessagePruneBeforeStmt(cornucopia_async::private::Stmt); impl CachedMessagePruneBeforeStmt
+{ pub async fn bind<'a, C:
+GenericClient,>(&'a mut self, client: &'a  C,
+before: &'a time::OffsetDateTime,) -> Result<u64, tokio_postgres::Error>
+{
+    let stmt = self.0.prepare(client).await?;
+    client.execute(stmt, &[before,]).await
+} }}pub mod cached_message_prune_by_channel_id
+{ use futures::{{StreamExt, TryStreamExt}};use futures; use cornucopia_async::GenericClient;#[derive( Debug)] pub struct CachedMessagePruneByChannelIdParams<T1: cornucopia_async::StringSql,> { pub channel_id: T1,pub limit: i64,}pub fn cached_message_prune_by_channel_id() -> CachedMessagePruneByChannelIdStmt
+{ CachedMessagePruneByChannelIdStmt(cornucopia_async::private::Stmt::new("DELETE FROM
+    \"DiscordFrontend\".\"Nightly\".\"CachedMessages\"
+WHERE
+    \"channel_id\" = $1 AND
+    \"id\" NOT IN (
+        SELECT
+            \"id\"
+        FROM
+            \"DiscordFrontend\".\"Nightly\".\"CachedMessages\"
+        WHERE
+            \"channel_id\" = $1
+        ORDER BY
+            \"timestamp\" DESC
+        LIMIT $2
+    )")) } pub struct
+CachedMessagePruneByChannelIdStmt(cornucopia_async::private::Stmt); impl CachedMessagePruneByChannelIdStmt
+{ pub async fn bind<'a, C:
+GenericClient,T1:
+cornucopia_async::StringSql,>(&'a mut self, client: &'a  C,
+channel_id: &'a T1,limit: &'a i64,) -> Result<u64, tokio_postgres::Error>
+{
+    let stmt = self.0.prepare(client).await?;
+    client.execute(stmt, &[channel_id,limit,]).await
+} }impl <'a, C: GenericClient + Send + Sync, T1: cornucopia_async::StringSql,>
+cornucopia_async::Params<'a, CachedMessagePruneByChannelIdParams<T1,>, std::pin::Pin<Box<dyn futures::Future<Output = Result<u64,
+tokio_postgres::Error>> + Send + 'a>>, C> for CachedMessagePruneByChannelIdStmt
+{
+    fn
+    params(&'a mut self, client: &'a  C, params: &'a
+    CachedMessagePruneByChannelIdParams<T1,>) -> std::pin::Pin<Box<dyn futures::Future<Output = Result<u64,
+    tokio_postgres::Error>> + Send + 'a>>
+    { Box::pin(self.bind(client, &params.channel_id,&params.limit,)) }
+}}pub mod cached_message_select_by_id
+{ use futures::{{StreamExt, TryStreamExt}};use futures; use cornucopia_async::GenericClient;#[derive( Debug, Clone, PartialEq,)] pub struct CachedMessageSelectById
+{ pub id : String,pub channel_id : String,pub guild_id : Option<String>,pub author_id : String,pub content : String,pub attachments : Vec<String>,pub embeds : Vec<String>,pub timestamp : time::OffsetDateTime,pub edited_timestamp : Option<time::OffsetDateTime>,pub deleted : bool,}pub struct CachedMessageSelectByIdBorrowed<'a> { pub id : &'a str,pub channel_id : &'a str,pub guild_id : Option<&'a str>,pub author_id : &'a str,pub content : &'a str,pub attachments : cornucopia_async::ArrayIterator<'a, &'a str>,pub embeds : cornucopia_async::ArrayIterator<'a, &'a str>,pub timestamp : time::OffsetDateTime,pub edited_timestamp : Option<time::OffsetDateTime>,pub deleted : bool,}
+impl<'a> From<CachedMessageSelectByIdBorrowed<'a>> for CachedMessageSelectById
+{
+    fn from(CachedMessageSelectByIdBorrowed { id,channel_id,guild_id,author_id,content,attachments,embeds,timestamp,edited_timestamp,deleted,}: CachedMessageSelectByIdBorrowed<'a>) ->
+    Self { Self { id: id.into(),channel_id: channel_id.into(),guild_id: guild_id.map(|v| v.into()),author_id: author_id.into(),content: content.into(),attachments: attachments.map(|v| v.into()).collect(),embeds: embeds.map(|v| v.into()).collect(),timestamp,edited_timestamp,deleted,} }
+}pub struct CachedMessageSelectByIdQuery<'a, C: GenericClient, T, const N: usize>
+{
+    client: &'a  C, params:
+    [&'a (dyn postgres_types::ToSql + Sync); N], stmt: &'a mut
+    cornucopia_async::private::Stmt, extractor: fn(&tokio_postgres::Row) -> CachedMessageSelectByIdBorrowed,
+    mapper: fn(CachedMessageSelectByIdBorrowed) -> T,
+} impl<'a, C, T:'a, const N: usize> CachedMessageSelectByIdQuery<'a, C, T, N> where C:
+GenericClient
+{
+    pub fn map<R>(self, mapper: fn(CachedMessageSelectByIdBorrowed) -> R) ->
+    CachedMessageSelectByIdQuery<'a,C,R,N>
+    {
+        CachedMessageSelectByIdQuery
+        {
+            client: self.client, params: self.params, stmt: self.stmt,
+            extractor: self.extractor, mapper,
+        }
+    } pub async fn one(self) -> Result<T, tokio_postgres::Error>
+    {
+        let stmt = self.stmt.prepare(self.client).await?; let row =
+        self.client.query_one(stmt, &self.params).await?;
+        Ok((self.mapper)((self.extractor)(&row)))
+    } pub async fn all(self) -> Result<Vec<T>, tokio_postgres::Error>
+    { self.iter().await?.try_collect().await } pub async fn opt(self) ->
+    Result<Option<T>, tokio_postgres::Error>
+    {
+        let stmt = self.stmt.prepare(self.client).await?;
+        Ok(self.client.query_opt(stmt, &self.params) .await?
+        .map(|row| (self.mapper)((self.extractor)(&row))))
+    } pub async fn iter(self,) -> Result<impl futures::Stream<Item = Result<T,
+    tokio_postgres::Error>> + 'a, tokio_postgres::Error>
+    {
+        let stmt = self.stmt.prepare(self.client).await?; let it =
+        self.client.query_raw(stmt,
+        cornucopia_async::private::slice_iter(&self.params)) .await?
+        .map(move |res|
+        res.map(|row| (self.mapper)((self.extractor)(&row)))) .into_stream();
+        Ok(it)
+    }
+}pub fn cached_message_select_by_id() -> CachedMessageSelectByIdStmt
+{ CachedMessageSelectByIdStmt(cornucopia_async::private::Stmt::new("SELECT
+    *
+FROM
+    \"DiscordFrontend\".\"Nightly\".\"CachedMessages\"
+WHERE
+    \"id\" = $1")) } pub struct
+CachedMessageSelectByIdStmt(cornucopia_async::private::Stmt); impl CachedMessageSelectByIdStmt
+{ pub fn bind<'a, C:
+GenericClient,T1:
+cornucopia_async::StringSql,>(&'a mut self, client: &'a  C,
+id: &'a T1,) -> CachedMessageSelectByIdQuery<'a,C,
+CachedMessageSelectById, 1>
+{
+    CachedMessageSelectByIdQuery
+    {
+        client, params: [id,], stmt: &mut self.0, extractor:
+        |row| { CachedMessageSelectByIdBorrowed { id: row.get(0),channel_id: row.get(1),guild_id: row.get(2),author_id: row.get(3),content: row.get(4),attachments: row.get(5),embeds: row.get(6),timestamp: row.get(7),edited_timestamp: row.get(8),deleted: row.get(9),} }, mapper: |it| { <CachedMessageSelectById>::from(it) },
+    }
+} }}pub mod cached_message_upsert
+{ use futures::{{StreamExt, TryStreamExt}};use futures; use cornucopia_async::GenericClient;#[derive( Debug)] pub struct CachedMessageUpsertParams<T1: cornucopia_async::StringSql,T2: cornucopia_async::StringSql,T3: cornucopia_async::StringSql,T4: cornucopia_async::StringSql,T5: cornucopia_async::StringSql,T6: cornucopia_async::StringSql,T7: cornucopia_async::ArraySql<Item = T6>,T8: cornucopia_async::StringSql,T9: cornucopia_async::ArraySql<Item = T8>,> { pub id: T1,pub channel_id: T2,pub guild_id: Option<T3>,pub author_id: T4,pub content: T5,pub attachments: T7,pub embeds: T9,pub timestamp: time::OffsetDateTime,pub edited_timestamp: Option<time::OffsetDateTime>,pub deleted: bool,}pub fn cached_message_upsert() -> CachedMessageUpsertStmt
+{ CachedMessageUpsertStmt(cornucopia_async::private::Stmt::new("INSERT INTO \"DiscordFrontend\".\"Nightly\".\"CachedMessages\" (\"id\", \"channel_id\", \"guild_id\", \"author_id\", \"content\", \"attachments\", \"embeds\", \"timestamp\", \"edited_timestamp\", \"deleted\")
+VALUES ($1, $2, $3, $4, $5, $6, $7, $8, $9, $10)
+ON CONFLICT (\"id\") DO UPDATE
+    SET
+        \"content\" = $5,
+        \"attachments\" = $6,
+        \"embeds\" = $7,
+        \"edited_timestamp\" = $9,
+        \"deleted\" = $10")) } pub struct
+CachedMessageUpsertStmt(cornucopia_async::private::Stmt); impl CachedMessageUpsertStmt
+{ pub async fn bind<'a, C:
+GenericClient,T1:
+cornucopia_async::StringSql,T2:
+cornucopia_async::StringSql,T3:
+cornucopia_async::StringSql,T4:
+cornucopia_async::StringSql,T5:
+cornucopia_async::StringSql,T6:
+cornucopia_async::StringSql,T7:
+cornucopia_async::ArraySql<Item = T6>,T8:
+cornucopia_async::StringSql,T9:
+cornucopia_async::ArraySql<Item = T8>,>(&'a mut self, client: &'a  C,
+id: &'a T1,channel_id: &'a T2,guild_id: &'a Option<T3>,author_id: &'a T4,content: &'a T5,attachments: &'a T7,embeds: &'a T9,timestamp: &'a time::OffsetDateTime,edited_timestamp: &'a Option<time::OffsetDateTime>,deleted: &'a bool,) -> Result<u64, tokio_postgres::Error>
+{
+    let stmt = self.0.prepare(client).await?;
+    client.execute(stmt, &[id,channel_id,guild_id,author_id,content,attachments,embeds,timestamp,edited_timestamp,deleted,]).await
+} }impl <'a, C: GenericClient + Send + Sync, T1: cornucopia_async::StringSql,T2: cornucopia_async::StringSql,T3: cornucopia_async::StringSql,T4: cornucopia_async::StringSql,T5: cornucopia_async::StringSql,T6: cornucopia_async::StringSql,T7: cornucopia_async::ArraySql<Item = T6>,T8: cornucopia_async::StringSql,T9: cornucopia_async::ArraySql<Item = T8>,>
+cornucopia_async::Params<'a, CachedMessageUpsertParams<T1,T2,T3,T4,T5,T6,T7,T8,T9,>, std::pin::Pin<Box<dyn futures::Future<Output = Result<u64,
+tokio_postgres::Error>> + Send + 'a>>, C> for CachedMessageUpsertStmt
+{
+    fn
+    params(&'a mut self, client: &'a  C, params: &'a
+    CachedMessageUpsertParams<T1,T2,T3,T4,T5,T6,T7,T8,T9,>) -> std::pin::Pin<Box<dyn futures::Future<Output = Result<u64,
+    tokio_postgres::Error>> + Send + 'a>>
+    { Box::pin(self.bind(client, &params.id,&params.channel_id,&params.guild_id,&params.author_id,&params.content,&params.attachments,&params.embeds,&params.timestamp,&params.edited_timestamp,&params.deleted,)) }
+}}pub mod cached_role_delete_by_guild_id
+{ use futures::{{StreamExt, TryStreamExt}};use futures; use cornucopia_async::GenericClient;pub fn cached_role_delete_by_guild_id() -> CachedRoleDeleteByGuildIdStmt
+{ CachedRoleDeleteByGuildIdStmt(cornucopia_async::private::Stmt::new("DELETE FROM
+    \"DiscordFrontend\".\"Nightly\".\"CachedRoles\"
+WHERE
+    \"guild_id\" = $1")) } pub struct
+CachedRoleDeleteByGuildIdStmt(cornucopia_async::private::Stmt); impl CachedRoleDeleteByGuildIdStmt
+{ pub async fn bind<'a, C:
+GenericClient,T1:
+cornucopia_async::StringSql,>(&'a mut self, client: &'a  C,
+guild_id: &'a T1,) -> Result<u64, tokio_postgres::Error>
+{
+    let stmt = self.0.prepare(client).await?;
+    client.execute(stmt, &[guild_id,]).await
+} }}pub mod cached_role_delete_by_guild_id_except_ids
+{ use futures::{{StreamExt, TryStreamExt}};use futures; use cornucopia_async::GenericClient;#[derive( Debug)] pub struct CachedRoleDeleteByGuildIdExceptIdsParams<T1: cornucopia_async::StringSql,T2: cornucopia_async::StringSql,T3: cornucopia_async::ArraySql<Item = T2>,> { pub guild_id: T1,pub ids: T3,}pub struct StringQuery<'a, C: GenericClient, T, const N: usize>
+{
+    client: &'a  C, params:
+    [&'a (dyn postgres_types::ToSql + Sync); N], stmt: &'a mut
+    cornucopia_async::private::Stmt, extractor: fn(&tokio_postgres::Row) -> &'a str,
+    mapper: fn(&'a str) -> T,
+} impl<'a, C, T:'a, const N: usize> StringQuery<'a, C, T, N> where C:
+GenericClient
+{
+    pub fn map<R>(self, mapper: fn(&'a str) -> R) ->
+    StringQuery<'a,C,R,N>
+    {
+        StringQuery
+        {
+            client: self.client, params: self.params, stmt: self.stmt,
+            extractor: self.extractor, mapper,
+        }
+    } pub async fn one(self) -> Result<T, tokio_postgres::Error>
+    {
+        let stmt = self.stmt.prepare(self.client).await?; let row =
+        self.client.query_one(stmt, &self.params).await?;
+        Ok((self.mapper)((self.extractor)(&row)))
+    } pub async fn all(self) -> Result<Vec<T>, tokio_postgres::Error>
+    { self.iter().await?.try_collect().await } pub async fn opt(self) ->
+    Result<Option<T>, tokio_postgres::Error>
+    {
+        let stmt = self.stmt.prepare(self.client).await?;
+        Ok(self.client.query_opt(stmt, &self.params) .await?
+        .map(|row| (self.mapper)((self.extractor)(&row))))
+    } pub async fn iter(self,) -> Result<impl futures::Stream<Item = Result<T,
+    tokio_postgres::Error>> + 'a, tokio_postgres::Error>
+    {
+        let stmt = self.stmt.prepare(self.client).await?; let it =
+        self.client.query_raw(stmt,
+        cornucopia_async::private::slice_iter(&self.params)) .await?
+        .map(move |res|
+        res.map(|row| (self.mapper)((self.extractor)(&row)))) .into_stream();
+        Ok(it)
+    }
+}pub fn cached_role_delete_by_guild_id_except_ids() -> CachedRoleDeleteByGuildIdExceptIdsStmt
+{ CachedRoleDeleteByGuildIdExceptIdsStmt(cornucopia_async::private::Stmt::new("DELETE FROM
+    \"DiscordFrontend\".\"Nightly\".\"CachedRoles\"
+WHERE
+    \"guild_id\" = $1 AND
+    NOT (\"id\" = ANY($2))
+RETURNING
+    \"id\"")) } pub struct
+CachedRoleDeleteByGuildIdExceptIdsStmt(cornucopia_async::private::Stmt); impl CachedRoleDeleteByGuildIdExceptIdsStmt
+{ pub fn bind<'a, C:
+GenericClient,T1:
+cornucopia_async::StringSql,T2:
+cornucopia_async::StringSql,T3:
+cornucopia_async::ArraySql<Item = T2>,>(&'a mut self, client: &'a  C,
+guild_id: &'a T1,ids: &'a T3,) -> StringQuery<'a,C,
+String, 2>
+{
+    StringQuery
+    {
+        client, params: [guild_id,ids,], stmt: &mut self.0, extractor:
+        |row| { row.get(0) }, mapper: |it| { it.into() },
+    }
+} }impl <'a, C: GenericClient,T1: cornucopia_async::StringSql,T2: cornucopia_async::StringSql,T3: cornucopia_async::ArraySql<Item = T2>,> cornucopia_async::Params<'a,
+CachedRoleDeleteByGuildIdExceptIdsParams<T1,T2,T3,>, StringQuery<'a, C,
+String, 2>, C> for CachedRoleDeleteByGuildIdExceptIdsStmt
+{
+    fn
+    params(&'a mut self, client: &'a  C, params: &'a
+    CachedRoleDeleteByGuildIdExceptIdsParams<T1,T2,T3,>) -> StringQuery<'a, C,
+    String, 2>
+    { self.bind(client, &params.guild_id,&params.ids,) }
+}}pub mod cached_role_delete_by_id_and_guild_id
+{ use futures::{{StreamExt, TryStreamExt}};use futures; use cornucopia_async::GenericClient;#[derive( Debug)] pub struct CachedRoleDeleteByIdAndGuildIdParams<T1: cornucopia_async::StringSql,T2: cornucopia_async::StringSql,> { pub id: T1,pub guild_id: T2,}pub fn cached_role_delete_by_id_and_guild_id() -> CachedRoleDeleteByIdAndGuildIdStmt
+{ CachedRoleDeleteByIdAndGuildIdStmt(cornucopia_async::private::Stmt::new("DELETE FROM
+    \"DiscordFrontend\".\"Nightly\".\"CachedRoles\"
+WHERE
+    \"id\" = $1 AND
+    \"guild_id\" = $2")) } pub struct
+CachedRoleDeleteByIdAndGuildIdStmt(cornucopia_async::private::Stmt); impl CachedRoleDeleteByIdAndGuildIdStmt
+{ pub async fn bind<'a, C:
+GenericClient,T1:
+cornucopia_async::StringSql,T2:
+cornucopia_async::StringSql,>(&'a mut self, client: &'a  C,
+id: &'a T1,guild_id: &'a T2,) -> Result<u64, tokio_postgres::Error>
+{
+    let stmt = self.0.prepare(client).await?;
+    client.execute(stmt, &[id,guild_id,]).await
+} }impl <'a, C: GenericClient + Send + Sync, T1: cornucopia_async::StringSql,T2: cornucopia_async::StringSql,>
+cornucopia_async::Params<'a, CachedRoleDeleteByIdAndGuildIdParams<T1,T2,>, std::pin::Pin<Box<dyn futures::Future<Output = Result<u64,
+tokio_postgres::Error>> + Send + 'a>>, C> for CachedRoleDeleteByIdAndGuildIdStmt
+{
+    fn
+    params(&'a mut self, client: &'a  C, params: &'a
+    CachedRoleDeleteByIdAndGuildIdParams<T1,T2,>) -> std::pin::Pin<Box<dyn futures::Future<Output = Result<u64,
+    tokio_postgres::Error>> + Send + 'a>>
+    { Box::pin(self.bind(client, &params.id,&params.guild_id,)) }
+}}pub mod cached_role_select_by_guild_id
+{ use futures::{{StreamExt, TryStreamExt}};use futures; use cornucopia_async::GenericClient;#[derive( Debug, Clone, PartialEq,)] pub struct CachedRoleSelectByGuildId
+{ pub color : i64,pub flags : i32,pub guild_id : String,pub hoist : bool,pub icon : Option<String>,pub id : String,pub managed : bool,pub mentionable : bool,pub position : i32,}pub struct CachedRoleSelectByGuildIdBorrowed<'a> { pub color : i64,pub flags : i32,pub guild_id : &'a str,pub hoist : bool,pub icon : Option<&'a str>,pub id : &'a str,pub managed : bool,pub mentionable : bool,pub position : i32,}
+impl<'a> From<CachedRoleSelectByGuildIdBorrowed<'a>> for CachedRoleSelectByGuildId
+{
+    fn from(CachedRoleSelectByGuildIdBorrowed { color,flags,guild_id,hoist,icon,id,managed,mentionable,position,}: CachedRoleSelectByGuildIdBorrowed<'a>) ->
+    Self { Self { color,flags,guild_id: guild_id.into(),hoist,icon: icon.map(|v| v.into()),id: id.into(),managed,mentionable,position,} }
+}pub struct CachedRoleSelectByGuildIdQuery<'a, C: GenericClient, T, const N: usize>
+{
+    client: &'a  C, params:
+    [&'a (dyn postgres_types::ToSql + Sync); N], stmt: &'a mut
+    cornucopia_async::private::Stmt, extractor: fn(&tokio_postgres::Row) -> CachedRoleSelectByGuildIdBorrowed,
+    mapper: fn(CachedRoleSelectByGuildIdBorrowed) -> T,
+} impl<'a, C, T:'a, const N: usize> CachedRoleSelectByGuildIdQuery<'a, C, T, N> where C:
+GenericClient
+{
+    pub fn map<R>(self, mapper: fn(CachedRoleSelectByGuildIdBorrowed) -> R) ->
+    CachedRoleSelectByGuildIdQuery<'a,C,R,N>
+    {
+        CachedRoleSelectByGuildIdQuery
+        {
+            client: self.client, params: self.params, stmt: self.stmt,
+            extractor: self.extractor, mapper,
+        }
+    } pub async fn one(self) -> Result<T, tokio_postgres::Error>
+    {
+        let stmt = self.stmt.prepare(self.client).await?; let row =
+        self.client.query_one(stmt, &self.params).await?;
+        Ok((self.mapper)((self.extractor)(&row)))
+    } pub async fn all(self) -> Result<Vec<T>, tokio_postgres::Error>
+    { self.iter().await?.try_collect().await } pub async fn opt(self) ->
+    Result<Option<T>, tokio_postgres::Error>
+    {
+        let stmt = self.stmt.prepare(self.client).await?;
+        Ok(self.client.query_opt(stmt, &self.params) .await?
+        .map(|row| (self.mapper)((self.extractor)(&row))))
+    } pub async fn iter(self,) -> Result<impl futures::Stream<Item = Result<T,
+    tokio_postgres::Error>> + 'a, tokio_postgres::Error>
+    {
+        let stmt = self.stmt.prepare(self.client).await?; let it =
+        self.client.query_raw(stmt,
+        cornucopia_async::private::slice_iter(&self.params)) .await?
+        .map(move |res|
+        res.map(|row| (self.mapper)((self.extractor)(&row)))) .into_stream();
+        Ok(it)
+    }
+}pub fn cached_role_select_by_guild_id() -> CachedRoleSelectByGuildIdStmt
+{ CachedRoleSelectByGuildIdStmt(cornucopia_async::private::Stmt::new("SELECT
+    *
+FROM
+    \"DiscordFrontend\".\"Nightly\".\"CachedRoles\"
+WHERE
+    \"guild_id\" = $1")) } pub struct
+CachedRoleSelectByGuildIdStmt(cornucopia_async::private::Stmt); impl CachedRoleSelectByGuildIdStmt
+{ pub fn bind<'a, C:
+GenericClient,T1:
+cornucopia_async::StringSql,>(&'a mut self, client: &'a  C,
+guild_id: &'a T1,) -> CachedRoleSelectByGuildIdQuery<'a,C,
+CachedRoleSelectByGuildId, 1>
+{
+    CachedRoleSelectByGuildIdQuery
+    {
+        client, params: [guild_id,], stmt: &mut self.0, extractor:
+        |row| { CachedRoleSelectByGuildIdBorrowed { color: row.get(0),flags: row.get(1),guild_id: row.get(2),hoist: row.get(3),icon: row.get(4),id: row.get(5),managed: row.get(6),mentionable: row.get(7),position: row.get(8),} }, mapper: |it| { <CachedRoleSelectByGuildId>::from(it) },
+    }
+} }}pub mod cached_role_select_by_id_and_guild_id
+{ use futures::{{StreamExt, TryStreamExt}};use futures; use cornucopia_async::GenericClient;#[derive( Debug)] pub struct CachedRoleSelectByIdAndGuildIdParams<T1: cornucopia_async::StringSql,T2: cornucopia_async::StringSql,> { pub id: T1,pub guild_id: T2,}#[derive( Debug, Clone, PartialEq,)] pub struct CachedRoleSelectByIdAndGuildId
+{ pub color : i64,pub flags : i32,pub guild_id : String,pub hoist : bool,pub icon : Option<String>,pub id : String,pub managed : bool,pub mentionable : bool,pub position : i32,}pub struct CachedRoleSelectByIdAndGuildIdBorrowed<'a> { pub color : i64,pub flags : i32,pub guild_id : &'a str,pub hoist : bool,pub icon : Option<&'a str>,pub id : &'a str,pub managed : bool,pub mentionable : bool,pub position : i32,}
+impl<'a> From<CachedRoleSelectByIdAndGuildIdBorrowed<'a>> for CachedRoleSelectByIdAndGuildId
+{
+    fn from(CachedRoleSelectByIdAndGuildIdBorrowed { color,flags,guild_id,hoist,icon,id,managed,mentionable,position,}: CachedRoleSelectByIdAndGuildIdBorrowed<'a>) ->
+    Self { Self { color,flags,guild_id: guild_id.into(),hoist,icon: icon.map(|v| v.into()),id: id.into(),managed,mentionable,position,} }
+}pub struct CachedRoleSelectByIdAndGuildIdQuery<'a, C: GenericClient, T, const N: usize>
+{
+    client: &'a  C, params:
+    [&'a (dyn postgres_types::ToSql + Sync); N], stmt: &'a mut
+    cornucopia_async::private::Stmt, extractor: fn(&tokio_postgres::Row) -> CachedRoleSelectByIdAndGuildIdBorrowed,
+    mapper: fn(CachedRoleSelectByIdAndGuildIdBorrowed) -> T,
+} impl<'a, C, T:'a, const N: usize> CachedRoleSelectByIdAndGuildIdQuery<'a, C, T, N> where C:
+GenericClient
+{
+    pub fn map<R>(self, mapper: fn(CachedRoleSelectByIdAndGuildIdBorrowed) -> R) ->
+    CachedRoleSelectByIdAndGuildIdQuery<'a,C,R,N>
+    {
+        CachedRoleSelectByIdAndGuildIdQuery
+        {
+            client: self.client, params: self.params, stmt: self.stmt,
+            extractor: self.extractor, mapper,
+        }
+    } pub async fn one(self) -> Result<T, tokio_postgres::Error>
+    {
+        let stmt = self.stmt.prepare(self.client).await?; let row =
+        self.client.query_one(stmt, &self.params).await?;
+        Ok((self.mapper)((self.extractor)(&row)))
+    } pub async fn all(self) -> Result<Vec<T>, tokio_postgres::Error>
+    { self.iter().await?.try_collect().await } pub async fn opt(self) ->
+    Result<Option<T>, tokio_postgres::Error>
+    {
+        let stmt = self.stmt.prepare(self.client).await?;
+        Ok(self.client.query_opt(stmt, &self.params) .await?
+        .map(|row| (self.mapper)((self.extractor)(&row))))
+    } pub async fn iter(self,) -> Result<impl futures::Stream<Item = Result<T,
+    tokio_postgres::Error>> + 'a, tokio_postgres::Error>
+    {
+        let stmt = self.stmt.prepare(self.client).await?; let it =
+        self.client.query_raw(stmt,
+        cornucopia_async::private::slice_iter(&self.params)) .await?
+        .map(move |res|
+        res.map(|row| (self.mapper)((self.extractor)(&row)))) .into_stream();
+        Ok(it)
+    }
+}pub fn cached_role_select_by_id_and_guild_id() -> CachedRoleSelectByIdAndGuildIdStmt
+{ CachedRoleSelectByIdAndGuildIdStmt(cornucopia_async::private::Stmt::new("SELECT
+    *
+FROM
+    \"DiscordFrontend\".\"Nightly\".\"CachedRoles\"
+WHERE
+    \"id\" = $1 AND
+    \"guild_id\" = $2")) } pub struct
+CachedRoleSelectByIdAndGuildIdStmt(cornucopia_async::private::Stmt); impl CachedRoleSelectByIdAndGuildIdStmt
+{ pub fn bind<'a, C:
+GenericClient,T1:
+cornucopia_async::StringSql,T2:
+cornucopia_async::StringSql,>(&'a mut self, client: &'a  C,
+id: &'a T1,guild_id: &'a T2,) -> CachedRoleSelectByIdAndGuildIdQuery<'a,C,
+CachedRoleSelectByIdAndGuildId, 2>
+{
+    CachedRoleSelectByIdAndGuildIdQuery
+    {
+        client, params: [id,guild_id,], stmt: &mut self.0, extractor:
+        |row| { CachedRoleSelectByIdAndGuildIdBorrowed { color: row.get(0),flags: row.get(1),guild_id: row.get(2),hoist: row.get(3),icon: row.get(4),id: row.get(5),managed: row.get(6),mentionable: row.get(7),position: row.get(8),} }, mapper: |it| { <CachedRoleSelectByIdAndGuildId>::from(it) },
+    }
+} }impl <'a, C: GenericClient,T1: cornucopia_async::StringSql,T2: cornucopia_async::StringSql,> cornucopia_async::Params<'a,
+CachedRoleSelectByIdAndGuildIdParams<T1,T2,>, CachedRoleSelectByIdAndGuildIdQuery<'a, C,
+CachedRoleSelectByIdAndGuildId, 2>, C> for CachedRoleSelectByIdAndGuildIdStmt
+{
+    fn
+    params(&'a mut self, client: &'a  C, params: &'a
+    CachedRoleSelectByIdAndGuildIdParams<T1,T2,>) -> CachedRoleSelectByIdAndGuildIdQuery<'a, C,
+    CachedRoleSelectByIdAndGuildId, 2>
+    { self.bind(client, &params.id,&params.guild_id,) }
+}}pub mod cached_role_upsert
+{ use futures::{{StreamExt, TryStreamExt}};use futures; use cornucopia_async::GenericClient;#[derive( Debug)] pub struct CachedRoleUpsertParams<T1: cornucopia_async::StringSql,T2: cornucopia_async::StringSql,T3: cornucopia_async::StringSql,> { pub color: i64,pub icon: Option<T1>,pub id: T2,pub guild_id: T3,pub flags: i32,pub hoist: bool,pub managed: bool,pub mentionable: bool,pub position: i32,}pub fn cached_role_upsert() -> CachedRoleUpsertStmt
+{ CachedRoleUpsertStmt(cornucopia_async::private::Stmt::new("INSERT INTO \"DiscordFrontend\".\"Nightly\".\"CachedRoles\" (\"color\", \"icon\", \"id\", \"guild_id\", \"flags\", \"hoist\", \"managed\", \"mentionable\", \"position\")
+VALUES ($1, $2, $3, $4, $5, $6, $7, $8, $9)
+ON CONFLICT (\"id\", \"guild_id\") DO UPDATE
+    SET
+        \"color\" = $1,
+        \"icon\" = $2,
+        \"flags\" = $5,
+        \"hoist\" = $6,
+        \"managed\" = $7,
+        \"mentionable\" = $8,
+        \"position\" = $9")) } pub struct
+CachedRoleUpsertStmt(cornucopia_async::private::Stmt); impl CachedRoleUpsertStmt
+{ pub async fn bind<'a, C:
+GenericClient,T1:
+cornucopia_async::StringSql,T2:
+cornucopia_async::StringSql,T3:
+cornucopia_async::StringSql,>(&'a mut self, client: &'a  C,
+color: &'a i64,icon: &'a Option<T1>,id: &'a T2,guild_id: &'a T3,flags: &'a i32,hoist: &'a bool,managed: &'a bool,mentionable: &'a bool,position: &'a i32,) -> Result<u64, tokio_postgres::Error>
+{
+    let stmt = self.0.prepare(client).await?;
+    client.execute(stmt, &[color,icon,id,guild_id,flags,hoist,managed,mentionable,position,]).await
+} }impl <'a, C: GenericClient + Send + Sync, T1: cornucopia_async::StringSql,T2: cornucopia_async::StringSql,T3: cornucopia_async::StringSql,>
+cornucopia_async::Params<'a, CachedRoleUpsertParams<T1,T2,T3,>, std::pin::Pin<Box<dyn futures::Future<Output = Result<u64,
+tokio_postgres::Error>> + Send + 'a>>, C> for CachedRoleUpsertStmt
+{
+    fn
+    params(&'a mut self, client: &'a  C, params: &'a
+    CachedRoleUpsertParams<T1,T2,T3,>) -> std::pin::Pin<Box<dyn futures::Future<Output = Result<u64,
+    tokio_postgres::Error>> + Send + 'a>>
+    { Box::pin(self.bind(client, &params.color,&params.icon,&params.id,&params.guild_id,&params.flags,&params.hoist,&params.managed,&params.mentionable,&params.position,)) }
+}}pub mod cached_thread_delete_by_guild_id
+{ use futures::{{StreamExt, TryStreamExt}};use futures; use cornucopia_async::GenericClient;pub struct StringQuery<'a, C: GenericClient, T, const N: usize>
+{
+    client: &'a  C, params:
+    [&'a (dyn postgres_types::ToSql + Sync); N], stmt: &'a mut
+    cornucopia_async::private::Stmt, extractor: fn(&tokio_postgres::Row) -> &'a str,
+    mapper: fn(&'a str) -> T,
+} impl<'a, C, T:'a, const N: usize> StringQuery<'a, C, T, N> where C:
+GenericClient
+{
+    pub fn map<R>(self, mapper: fn(&'a str) -> R) ->
+    StringQuery<'a,C,R,N>
+    {
+        StringQuery
+        {
+            client: self.client, params: self.params, stmt: self.stmt,
+            extractor: self.extractor, mapper,
+        }
+    } pub async fn one(self) -> Result<T, tokio_postgres::Error>
+    {
+        let stmt = self.stmt.prepare(self.client).await?; let row =
+        self.client.query_one(stmt, &self.params).await?;
+        Ok((self.mapper)((self.extractor)(&row)))
+    } pub async fn all(self) -> Result<Vec<T>, tokio_postgres::Error>
+    { self.iter().await?.try_collect().await } pub async fn opt(self) ->
+    Result<Option<T>, tokio_postgres::Error>
+    {
+        let stmt = self.stmt.prepare(self.client).await?;
+        Ok(self.client.query_opt(stmt, &self.params) .await?
+        .map(|row| (self.mapper)((self.extractor)(&row))))
+    } pub async fn iter(self,) -> Result<impl futures::Stream<Item = Result<T,
+    tokio_postgres::Error>> + 'a, tokio_postgres::Error>
+    {
+        let stmt = self.stmt.prepare(self.client).await?; let it =
+        self.client.query_raw(stmt,
+        cornucopia_async::private::slice_iter(&self.params)) .await?
+        .map(move |res|
+        res.map(|row| (self.mapper)((self.extractor)(&row)))) .into_stream();
+        Ok(it)
+    }
+}pub fn cached_thread_delete_by_guild_id() -> CachedThreadDeleteByGuildIdStmt
+{ CachedThreadDeleteByGuildIdStmt(cornucopia_async::private::Stmt::new("DELETE FROM
+    \"DiscordFrontend\".\"Nightly\".\"CachedThreads\"
+WHERE
+    \"guild_id\" = $1
+RETURNING
+    \"id\"")) } pub struct
+CachedThreadDeleteByGuildIdStmt(cornucopia_async::private::Stmt); impl CachedThreadDeleteByGuildIdStmt
+{ pub fn bind<'a, C:
+GenericClient,T1:
+cornucopia_async::StringSql,>(&'a mut self, client: &'a  C,
+guild_id: &'a T1,) -> StringQuery<'a,C,
+String, 1>
+{
+    StringQuery
+    {
+        client, params: [guild_id,], stmt: &mut self.0, extractor:
+        |row| { row.get(0) }, mapper: |it| { it.into() },
+    }
+} }}pub mod cached_thread_delete_by_guild_id_except_ids
+{ use futures::{{StreamExt, TryStreamExt}};use futures; use cornucopia_async::GenericClient;#[derive( Debug)] pub struct CachedThreadDeleteByGuildIdExceptIdsParams<T1: cornucopia_async::StringSql,T2: cornucopia_async::StringSql,T3: cornucopia_async::ArraySql<Item = T2>,T4: cornucopia_async::StringSql,T5: cornucopia_async::ArraySql<Item = T4>,> { pub guild_id: T1,pub parent_ids: Option<T3>,pub ids: T5,}pub struct StringQuery<'a, C: GenericClient, T, const N: usize>
+{
+    client: &'a  C, params:
+    [&'a (dyn postgres_types::ToSql + Sync); N], stmt: &'a mut
+    cornucopia_async::private::Stmt, extractor: fn(&tokio_postgres::Row) -> &'a str,
+    mapper: fn(&'a str) -> T,
+} impl<'a, C, T:'a, const N: usize> StringQuery<'a, C, T, N> where C:
+GenericClient
+{
+    pub fn map<R>(self, mapper: fn(&'a str) -> R) ->
+    StringQuery<'a,C,R,N>
+    {
+        StringQuery
+        {
+            client: self.client, params: self.params, stmt: self.stmt,
+            extractor: self.extractor, mapper,
+        }
+    } pub async fn one(self) -> Result<T, tokio_postgres::Error>
+    {
+        let stmt = self.stmt.prepare(self.client).await?; let row =
+        self.client.query_one(stmt, &self.params).await?;
+        Ok((self.mapper)((self.extractor)(&row)))
+    } pub async fn all(self) -> Result<Vec<T>, tokio_postgres::Error>
+    { self.iter().await?.try_collect().await } pub async fn opt(self) ->
+    Result<Option<T>, tokio_postgres::Error>
+    {
+        let stmt = self.stmt.prepare(self.client).await?;
+        Ok(self.client.query_opt(stmt, &self.params) .await?
+        .map(|row| (self.mapper)((self.extractor)(&row))))
+    } pub async fn iter(self,) -> Result<impl futures::Stream<Item = Result<T,
+    tokio_postgres::Error>> + 'a, tokio_postgres::Error>
+    {
+        let stmt = self.stmt.prepare(self.client).await?; let it =
+        self.client.query_raw(stmt,
+        cornucopia_async::private::slice_iter(&self.params)) .await?
+        .map(move |res|
+        res.map(|row| (self.mapper)((self.extractor)(&row)))) .into_stream();
+        Ok(it)
+    }
+}pub fn cached_thread_delete_by_guild_id_except_ids() -> CachedThreadDeleteByGuildIdExceptIdsStmt
+{ CachedThreadDeleteByGuildIdExceptIdsStmt(cornucopia_async::private::Stmt::new("DELETE FROM
+    \"DiscordFrontend\".\"Nightly\".\"CachedThreads\"
+WHERE
+    \"guild_id\" = $1 AND
+    ($2 IS NULL OR \"parent_id\" = ANY($2)) AND
+    NOT (\"id\" = ANY($3))
+RETURNING
+    \"id\"")) } pub struct
+CachedThreadDeleteByGuildIdExceptIdsStmt(cornucopia_async::private::Stmt); impl CachedThreadDeleteByGuildIdExceptIdsStmt
+{ pub fn bind<'a, C:
+GenericClient,T1:
+cornucopia_async::StringSql,T2:
+cornucopia_async::StringSql,T3:
+cornucopia_async::ArraySql<Item = T2>,T4:
+cornucopia_async::StringSql,T5:
+cornucopia_async::ArraySql<Item = T4>,>(&'a mut self, client: &'a  C,
+guild_id: &'a T1,parent_ids: &'a Option<T3>,ids: &'a T5,) -> StringQuery<'a,C,
+String, 3>
+{
+    StringQuery
+    {
+        client, params: [guild_id,parent_ids,ids,], stmt: &mut self.0, extractor:
+        |row| { row.get(0) }, mapper: |it| { it.into() },
+    }
+} }impl <'a, C: GenericClient,T1: cornucopia_async::StringSql,T2: cornucopia_async::StringSql,T3: cornucopia_async::ArraySql<Item = T2>,T4: cornucopia_async::StringSql,T5: cornucopia_async::ArraySql<Item = T4>,> cornucopia_async::Params<'a,
+CachedThreadDeleteByGuildIdExceptIdsParams<T1,T2,T3,T4,T5,>, StringQuery<'a, C,
+String, 3>, C> for CachedThreadDeleteByGuildIdExceptIdsStmt
+{
+    fn
+    params(&'a mut self, client: &'a  C, params: &'a
+    CachedThreadDeleteByGuildIdExceptIdsParams<T1,T2,T3,T4,T5,>) -> StringQuery<'a, C,
+    String, 3>
+    { self.bind(client, &params.guild_id,&params.parent_ids,&params.ids,) }
+}}pub mod cached_thread_delete_by_id
+{ use futures::{{StreamExt, TryStreamExt}};use futures; use cornucopia_async::GenericClient;pub fn cached_thread_delete_by_id() -> CachedThreadDeleteByIdStmt
+{ CachedThreadDeleteByIdStmt(cornucopia_async::private::Stmt::new("DELETE FROM
+    \"DiscordFrontend\".\"Nightly\".\"CachedThreads\"
+WHERE
+    \"id\" = $1")) } pub struct
+CachedThreadDeleteByIdStmt(cornucopia_async::private::Stmt); impl CachedThreadDeleteByIdStmt
+{ pub async fn bind<'a, C:
+GenericClient,T1:
+cornucopia_async::StringSql,>(&'a mut self, client: &'a  C,
+id: &'a T1,) -> Result<u64, tokio_postgres::Error>
+{
+    let stmt = self.0.prepare(client).await?;
+    client.execute(stmt, &[id,]).await
+} }}pub mod cached_thread_delete_by_parent_id
+{ use futures::{{StreamExt, TryStreamExt}};use futures; use cornucopia_async::GenericClient;pub struct StringQuery<'a, C: GenericClient, T, const N: usize>
+{
+    client: &'a  C, params:
+    [&'a (dyn postgres_types::ToSql + Sync); N], stmt: &'a mut
+    cornucopia_async::private::Stmt, extractor: fn(&tokio_postgres::Row) -> &'a str,
+    mapper: fn(&'a str) -> T,
+} impl<'a, C, T:'a, const N: usize> StringQuery<'a, C, T, N> where C:
+GenericClient
+{
+    pub fn map<R>(self, mapper: fn(&'a str) -> R) ->
+    StringQuery<'a,C,R,N>
+    {
+        StringQuery
+        {
+            client: self.client, params: self.params, stmt: self.stmt,
+            extractor: self.extractor, mapper,
+        }
+    } pub async fn one(self) -> Result<T, tokio_postgres::Error>
+    {
+        let stmt = self.stmt.prepare(self.client).await?; let row =
+        self.client.query_one(stmt, &self.params).await?;
+        Ok((self.mapper)((self.extractor)(&row)))
+    } pub async fn all(self) -> Result<Vec<T>, tokio_postgres::Error>
+    { self.iter().await?.try_collect().await } pub async fn opt(self) ->
+    Result<Option<T>, tokio_postgres::Error>
+    {
+        let stmt = self.stmt.prepare(self.client).await?;
+        Ok(self.client.query_opt(stmt, &self.params) .await?
+        .map(|row| (self.mapper)((self.extractor)(&row))))
+    } pub async fn iter(self,) -> Result<impl futures::Stream<Item = Result<T,
+    tokio_postgres::Error>> + 'a, tokio_postgres::Error>
+    {
+        let stmt = self.stmt.prepare(self.client).await?; let it =
+        self.client.query_raw(stmt,
+        cornucopia_async::private::slice_iter(&self.params)) .await?
+        .map(move |res|
+        res.map(|row| (self.mapper)((self.extractor)(&row)))) .into_stream();
+        Ok(it)
+    }
+}pub fn cached_thread_delete_by_parent_id() -> CachedThreadDeleteByParentIdStmt
+{ CachedThreadDeleteByParentIdStmt(cornucopia_async::private::Stmt::new("DELETE FROM
+    \"DiscordFrontend\".\"Nightly\".\"CachedThreads\"
+WHERE
+    \"parent_id\" = $1
+RETURNING
+    \"id\"")) } pub struct
+CachedThreadDeleteByParentIdStmt(cornucopia_async::private::Stmt); impl CachedThreadDeleteByParentIdStmt
+{ pub fn bind<'a, C:
+GenericClient,T1:
+cornucopia_async::StringSql,>(&'a mut self, client: &'a  C,
+parent_id: &'a T1,) -> StringQuery<'a,C,
+String, 1>
+{
+    StringQuery
+    {
+        client, params: [parent_id,], stmt: &mut self.0, extractor:
+        |row| { row.get(0) }, mapper: |it| { it.into() },
+    }
+} }}pub mod cached_thread_select_by_guild_id
+{ use futures::{{StreamExt, TryStreamExt}};use futures; use cornucopia_async::GenericClient;#[derive( Debug, Clone, PartialEq,)] pub struct CachedThreadSelectByGuildId
+{ pub id : String,pub guild_id : String,pub kind : i16,pub locked : bool,pub name : Option<String>,pub owner_id : Option<String>,pub parent_id : Option<String>,pub rate_limit_per_user : Option<i32>,}pub struct CachedThreadSelectByGuildIdBorrowed<'a> { pub id : &'a str,pub guild_id : &'a str,pub kind : i16,pub locked : bool,pub name : Option<&'a str>,pub owner_id : Option<&'a str>,pub parent_id : Option<&'a str>,pub rate_limit_per_user : Option<i32>,}
+impl<'a> From<CachedThreadSelectByGuildIdBorrowed<'a>> for CachedThreadSelectByGuildId
+{
+    fn from(CachedThreadSelectByGuildIdBorrowed { id,guild_id,kind,locked,name,owner_id,parent_id,rate_limit_per_user,}: CachedThreadSelectByGuildIdBorrowed<'a>) ->
+    Self { Self { id: id.into(),guild_id: guild_id.into(),kind,locked,name: name.map(|v| v.into()),owner_id: owner_id.map(|v| v.into()),parent_id: parent_id.map(|v| v.into()),rate_limit_per_user,} }
+}pub struct CachedThreadSelectByGuildIdQuery<'a, C: GenericClient, T, const N: usize>
+{
+    client: &'a  C, params:
+    [&'a (dyn postgres_types::ToSql + Sync); N], stmt: &'a mut
+    cornucopia_async::private::Stmt, extractor: fn(&tokio_postgres::Row) -> CachedThreadSelectByGuildIdBorrowed,
+    mapper: fn(CachedThreadSelectByGuildIdBorrowed) -> T,
+} impl<'a, C, T:'a, const N: usize> CachedThreadSelectByGuildIdQuery<'a, C, T, N> where C:
+GenericClient
+{
+    pub fn map<R>(self, mapper: fn(CachedThreadSelectByGuildIdBorrowed) -> R) ->
+    CachedThreadSelectByGuildIdQuery<'a,C,R,N>
+    {
+        CachedThreadSelectByGuildIdQuery
+        {
+            client: self.client, params: self.params, stmt: self.stmt,
+            extractor: self.extractor, mapper,
+        }
+    } pub async fn one(self) -> Result<T, tokio_postgres::Error>
+    {
+        let stmt = self.stmt.prepare(self.client).await?; let row =
+        self.client.query_one(stmt, &self.params).await?;
+        Ok((self.mapper)((self.extractor)(&row)))
+    } pub async fn all(self) -> Result<Vec<T>, tokio_postgres::Error>
+    { self.iter().await?.try_collect().await } pub async fn opt(self) ->
+    Result<Option<T>, tokio_postgres::Error>
+    {
+        let stmt = self.stmt.prepare(self.client).await?;
+        Ok(self.client.query_opt(stmt, &self.params) .await?
+        .map(|row| (self.mapper)((self.extractor)(&row))))
+    } pub async fn iter(self,) -> Result<impl futures::Stream<Item = Result<T,
+    tokio_postgres::Error>> + 'a, tokio_postgres::Error>
+    {
+        let stmt = self.stmt.prepare(self.client).await?; let it =
+        self.client.query_raw(stmt,
+        cornucopia_async::private::slice_iter(&self.params)) .await?
+        .map(move |res|
+        res.map(|row| (self.mapper)((self.extractor)(&row)))) .into_stream();
+        Ok(it)
+    }
+}pub fn cached_thread_select_by_guild_id() -> CachedThreadSelectByGuildIdStmt
+{ CachedThreadSelectByGuildIdStmt(cornucopia_async::private::Stmt::new("SELECT
+    *
+FROM
+    \"DiscordFrontend\".\"Nightly\".\"CachedThreads\"
+WHERE
+    \"guild_id\" = $1")) } pub struct
+CachedThreadSelectByGuildIdStmt(cornucopia_async::private::Stmt); impl CachedThreadSelectByGuildIdStmt
+{ pub fn bind<'a, C:
+GenericClient,T1:
+cornucopia_async::StringSql,>(&'a mut self, client: &'a  C,
+guild_id: &'a T1,) -> CachedThreadSelectByGuildIdQuery<'a,C,
+CachedThreadSelectByGuildId, 1>
+{
+    CachedThreadSelectByGuildIdQuery
+    {
+        client, params: [guild_id,], stmt: &mut self.0, extractor:
+        |row| { CachedThreadSelectByGuildIdBorrowed { id: row.get(0),guild_id: row.get(1),kind: row.get(2),locked: row.get(3),name: row.get(4),owner_id: row.get(5),parent_id: row.get(6),rate_limit_per_user: row.get(7),} }, mapper: |it| { <CachedThreadSelectByGuildId>::from(it) },
+    }
+} }}pub mod cached_thread_select_by_id
+{ use futures::{{StreamExt, TryStreamExt}};use futures; use cornucopia_async::GenericClient;#[derive( Debug, Clone, PartialEq,)] pub struct CachedThreadSelectById
+{ pub id : String,pub guild_id : String,pub kind : i16,pub locked : bool,pub name : Option<String>,pub owner_id : Option<String>,pub parent_id : Option<String>,pub rate_limit_per_user : Option<i32>,}pub struct CachedThreadSelectByIdBorrowed<'a> { pub id : &'a str,pub guild_id : &'a str,pub kind : i16,pub locked : bool,pub name : Option<&'a str>,pub owner_id : Option<&'a str>,pub parent_id : Option<&'a str>,pub rate_limit_per_user : Option<i32>,}
+impl<'a> From<CachedThreadSelectByIdBorrowed<'a>> for CachedThreadSelectById
+{
+    fn from(CachedThreadSelectByIdBorrowed { id,guild_id,kind,locked,name,owner_id,parent_id,rate_limit_per_user,}: CachedThreadSelectByIdBorrowed<'a>) ->
+    Self { Self { id: id.into(),guild_id: guild_id.into(),kind,locked,name: name.map(|v| v.into()),owner_id: owner_id.map(|v| v.into()),parent_id: parent_id.map(|v| v.into()),rate_limit_per_user,} }
+}pub struct CachedThreadSelectByIdQuery<'a, C: GenericClient, T, const N: usize>
+{
+    client: &'a  C, params:
+    [&'a (dyn postgres_types::ToSql + Sync); N], stmt: &'a mut
+    cornucopia_async::private::Stmt, extractor: fn(&tokio_postgres::Row) -> CachedThreadSelectByIdBorrowed,
+    mapper: fn(CachedThreadSelectByIdBorrowed) -> T,
+} impl<'a, C, T:'a, const N: usize> CachedThreadSelectByIdQuery<'a, C, T, N> where C:
+GenericClient
+{
+    pub fn map<R>(self, mapper: fn(CachedThreadSelectByIdBorrowed) -> R) ->
+    CachedThreadSelectByIdQuery<'a,C,R,N>
+    {
+        CachedThreadSelectByIdQuery
+        {
+            client: self.client, params: self.params, stmt: self.stmt,
+            extractor: self.extractor, mapper,
+        }
+    } pub async fn one(self) -> Result<T, tokio_postgres::Error>
+    {
+        let stmt = self.stmt.prepare(self.client).await?; let row =
+        self.client.query_one(stmt, &self.params).await?;
+        Ok((self.mapper)((self.extractor)(&row)))
+    } pub async fn all(self) -> Result<Vec<T>, tokio_postgres::Error>
+    { self.iter().await?.try_collect().await } pub async fn opt(self) ->
+    Result<Option<T>, tokio_postgres::Error>
+    {
+        let stmt = self.stmt.prepare(self.client).await?;
+        Ok(self.client.query_opt(stmt, &self.params) .await?
+        .map(|row| (self.mapper)((self.extractor)(&row))))
+    } pub async fn iter(self,) -> Result<impl futures::Stream<Item = Result<T,
+    tokio_postgres::Error>> + 'a, tokio_postgres::Error>
+    {
+        let stmt = self.stmt.prepare(self.client).await?; let it =
+        self.client.query_raw(stmt,
+        cornucopia_async::private::slice_iter(&self.params)) .await?
+        .map(move |res|
+        res.map(|row| (self.mapper)((self.extractor)(&row)))) .into_stream();
+        Ok(it)
+    }
+}pub fn cached_thread_select_by_id() -> CachedThreadSelectByIdStmt
+{ CachedThreadSelectByIdStmt(cornucopia_async::private::Stmt::new("SELECT
+    *
+FROM
+    \"DiscordFrontend\".\"Nightly\".\"CachedThreads\"
+WHERE
+    \"id\" = $1")) } pub struct
+CachedThreadSelectByIdStmt(cornucopia_async::private::Stmt); impl CachedThreadSelectByIdStmt
+{ pub fn bind<'a, C:
+GenericClient,T1:
+cornucopia_async::StringSql,>(&'a mut self, client: &'a  C,
+id: &'a T1,) -> CachedThreadSelectByIdQuery<'a,C,
+CachedThreadSelectById, 1>
+{
+    CachedThreadSelectByIdQuery
+    {
+        client, params: [id,], stmt: &mut self.0, extractor:
+        |row| { CachedThreadSelectByIdBorrowed { id: row.get(0),guild_id: row.get(1),kind: row.get(2),locked: row.get(3),name: row.get(4),owner_id: row.get(5),parent_id: row.get(6),rate_limit_per_user: row.get(7),} }, mapper: |it| { <CachedThreadSelectById>::from(it) },
+    }
+} }}pub mod cached_thread_select_by_parent_id
+{ use futures::{{StreamExt, TryStreamExt}};use futures; use cornucopia_async::GenericClient;#[derive( Debug, Clone, PartialEq,)] pub struct CachedThreadSelectByParentId
+{ pub id : String,pub guild_id : String,pub kind : i16,pub locked : bool,pub name : Option<String>,pub owner_id : Option<String>,pub parent_id : Option<String>,pub rate_limit_per_user : Option<i32>,}pub struct CachedThreadSelectByParentIdBorrowed<'a> { pub id : &'a str,pub guild_id : &'a str,pub kind : i16,pub locked : bool,pub name : Option<&'a str>,pub owner_id : Option<&'a str>,pub parent_id : Option<&'a str>,pub rate_limit_per_user : Option<i32>,}
+impl<'a> From<CachedThreadSelectByParentIdBorrowed<'a>> for CachedThreadSelectByParentId
+{
+    fn from(CachedThreadSelectByParentIdBorrowed { id,guild_id,kind,locked,name,owner_id,parent_id,rate_limit_per_user,}: CachedThreadSelectByParentIdBorrowed<'a>) ->
+    Self { Self { id: id.into(),guild_id: guild_id.into(),kind,locked,name: name.map(|v| v.into()),owner_id: owner_id.map(|v| v.into()),parent_id: parent_id.map(|v| v.into()),rate_limit_per_user,} }
+}pub struct CachedThreadSelectByParentIdQuery<'a, C: GenericClient, T, const N: usize>
+{
+    client: &'a  C, params:
+    [&'a (dyn postgres_types::ToSql + Sync); N], stmt: &'a mut
+    cornucopia_async::private::Stmt, extractor: fn(&tokio_postgres::Row) -> CachedThreadSelectByParentIdBorrowed,
+    mapper: fn(CachedThreadSelectByParentIdBorrowed) -> T,
+} impl<'a, C, T:'a, const N: usize> CachedThreadSelectByParentIdQuery<'a, C, T, N> where C:
+GenericClient
+{
+    pub fn map<R>(self, mapper: fn(CachedThreadSelectByParentIdBorrowed) -> R) ->
+    CachedThreadSelectByParentIdQuery<'a,C,R,N>
+    {
+        CachedThreadSelectByParentIdQuery
+        {
+            client: self.client, params: self.params, stmt: self.stmt,
+            extractor: self.extractor, mapper,
+        }
+    } pub async fn one(self) -> Result<T, tokio_postgres::Error>
+    {
+        let stmt = self.stmt.prepare(self.client).await?; let row =
+        self.client.query_one(stmt, &self.params).await?;
+        Ok((self.mapper)((self.extractor)(&row)))
+    } pub async fn all(self) -> Result<Vec<T>, tokio_postgres::Error>
+    { self.iter().await?.try_collect().await } pub async fn opt(self) ->
+    Result<Option<T>, tokio_postgres::Error>
+    {
+        let stmt = self.stmt.prepare(self.client).await?;
+        Ok(self.client.query_opt(stmt, &self.params) .await?
+        .map(|row| (self.mapper)((self.extractor)(&row))))
+    } pub async fn iter(self,) -> Result<impl futures::Stream<Item = Result<T,
+    tokio_postgres::Error>> + 'a, tokio_postgres::Error>
+    {
+        let stmt = self.stmt.prepare(self.client).await?; let it =
+        self.client.query_raw(stmt,
+        cornucopia_async::private::slice_iter(&self.params)) .await?
+        .map(move |res|
+        res.map(|row| (self.mapper)((self.extractor)(&row)))) .into_stream();
+        Ok(it)
+    }
+}pub fn cached_thread_select_by_parent_id() -> CachedThreadSelectByParentIdStmt
+{ CachedThreadSelectByParentIdStmt(cornucopia_async::private::Stmt::new("SELECT
+    *
+FROM
+    \"DiscordFrontend\".\"Nightly\".\"CachedThreads\"
+WHERE
+    \"parent_id\" = $1")) } pub struct
+CachedThreadSelectByParentIdStmt(cornucopia_async::private::Stmt); impl CachedThreadSelectByParentIdStmt
+{ pub fn bind<'a, C:
+GenericClient,T1:
+cornucopia_async::StringSql,>(&'a mut self, client: &'a  C,
+parent_id: &'a T1,) -> CachedThreadSelectByParentIdQuery<'a,C,
+CachedThreadSelectByParentId, 1>
+{
+    CachedThreadSelectByParentIdQuery
+    {
+        client, params: [parent_id,], stmt: &mut self.0, extractor:
+        |row| { CachedThreadSelectByParentIdBorrowed { id: row.get(0),guild_id: row.get(1),kind: row.get(2),locked: row.get(3),name: row.get(4),owner_id: row.get(5),parent_id: row.get(6),rate_limit_per_user: row.get(7),} }, mapper: |it| { <CachedThreadSelectByParentId>::from(it) },
+    }
+} }}pub mod cached_thread_upsert
+{ use futures::{{StreamExt, TryStreamExt}};use futures; use cornucopia_async::GenericClient;#[derive( Debug)] pub struct CachedThreadUpsertParams<T1: cornucopia_async::StringSql,T2: cornucopia_async::StringSql,T3: cornucopia_async::StringSql,T4: cornucopia_async::StringSql,T5: cornucopia_async::StringSql,> { pub id: T1,pub guild_id: T2,pub kind: i16,pub locked: bool,pub name: Option<T3>,pub owner_id: Option<T4>,pub parent_id: Option<T5>,pub rate_limit_per_user: Option<i32>,}pub fn cached_thread_upsert() -> CachedThreadUpsertStmt
+{ CachedThreadUpsertStmt(cornucopia_async::private::Stmt::new("INSERT INTO \"DiscordFrontend\".\"Nightly\".\"CachedThreads\" (\"id\", \"guild_id\", \"kind\", \"locked\", \"name\", \"owner_id\", \"parent_id\", \"rate_limit_per_user\")
+VALUES ($1, $2, $3, $4, $5, $6, $7, $8)
+ON CONFLICT (\"id\") DO UPDATE
+    SET
+        \"guild_id\" = $2,
+        \"kind\" = $3,
+        \"locked\" = $4,
+        \"name\" = $5,
+        \"owner_id\" = $6,
+        \"parent_id\" = $7,
+        \"rate_limit_per_user\" = $8")) } pub struct
+CachedThreadUpsertStmt(cornucopia_async::private::Stmt); impl CachedThreadUpsertStmt
+{ pub async fn bind<'a, C:
+GenericClient,T1:
+cornucopia_async::StringSql,T2:
+cornucopia_async::StringSql,T3:
+cornucopia_async::StringSql,T4:
+cornucopia_async::StringSql,T5:
+cornucopia_async::StringSql,>(&'a mut self, client: &'a  C,
+id: &'a T1,guild_id: &'a T2,kind: &'a i16,locked: &'a bool,name: &'a Option<T3>,owner_id: &'a Option<T4>,parent_id: &'a Option<T5>,rate_limit_per_user: &'a Option<i32>,) -> Result<u64, tokio_postgres::Error>
+{
+    let stmt = self.0.prepare(client).await?;
+    client.execute(stmt, &[id,guild_id,kind,locked,name,owner_id,parent_id,rate_limit_per_user,]).await
+} }impl <'a, C: GenericClient + Send + Sync, T1: cornucopia_async::StringSql,T2: cornucopia_async::StringSql,T3: cornucopia_async::StringSql,T4: cornucopia_async::StringSql,T5: cornucopia_async::StringSql,>
+cornucopia_async::Params<'a, CachedThreadUpsertParams<T1,T2,T3,T4,T5,>, std::pin::Pin<Box<dyn futures::Future<Output = Result<u64,
+tokio_postgres::Error>> + Send + 'a>>, C> for CachedThreadUpsertStmt
+{
+    fn
+    params(&'a mut self, client: &'a  C, params: &'a
+    CachedThreadUpsertParams<T1,T2,T3,T4,T5,>) -> std::pin::Pin<Box<dyn futures::Future<Output = Result<u64,
+    tokio_postgres::Error>> + Send + 'a>>
+    { Box::pin(self.bind(client, &params.id,&params.guild_id,&params.kind,&params.locked,&params.name,&params.owner_id,&params.parent_id,&params.rate_limit_per_user,)) }
+}}pub mod cached_user_delete_by_id
+{ use futures::{{StreamExt, TryStreamExt}};use futures; use cornucopia_async::GenericClient;pub fn cached_user_delete_by_id() -> CachedUserDeleteByIdStmt
+{ CachedUserDeleteByIdStmt(cornucopia_async::private::Stmt::new("DELETE FROM
+    \"DiscordFrontend\".\"Nightly\".\"CachedUsers\"
+WHERE
+    \"id\" = $1")) } pub struct
+CachedUserDeleteByIdStmt(cornucopia_async::private::Stmt); impl CachedUserDeleteByIdStmt
+{ pub async fn bind<'a, C:
+GenericClient,T1:
+cornucopia_async::StringSql,>(&'a mut self, client: &'a  C,
+id: &'a T1,) -> Result<u64, tokio_postgres::Error>
+{
+    let stmt = self.0.prepare(client).await?;
+    client.execute(stmt, &[id,]).await
+} }}pub mod cached_user_select_by_id
+{ use futures::{{StreamExt, TryStreamExt}};use futures; use cornucopia_async::GenericClient;#[derive( Debug, Clone, PartialEq,)] pub struct CachedUserSelectById
+{ pub avatar : Option<String>,pub bot : bool,pub id : String,pub discriminator : String,pub global_name : Option<String>,pub name : String,}pub struct CachedUserSelectByIdBorrowed<'a> { pub avatar : Option<&'a str>,pub bot : bool,pub id : &'a str,pub discriminator : &'a str,pub global_name : Option<&'a str>,pub name : &'a str,}
+impl<'a> From<CachedUserSelectByIdBorrowed<'a>> for CachedUserSelectById
+{
+    fn from(CachedUserSelectByIdBorrowed { avatar,bot,id,discriminator,global_name,name,}: CachedUserSelectByIdBorrowed<'a>) ->
+    Self { Self { avatar: avatar.map(|v| v.into()),bot,id: id.into(),discriminator: discriminator.into(),global_name: global_name.map(|v| v.into()),name: name.into(),} }
+}pub struct CachedUserSelectByIdQuery<'a, C: GenericClient, T, const N: usize>
+{
+    client: &'a  C, params:
+    [&'a (dyn postgres_types::ToSql + Sync); N], stmt: &'a mut
+    cornucopia_async::private::Stmt, extractor: fn(&tokio_postgres::Row) -> CachedUserSelectByIdBorrowed,
+    mapper: fn(CachedUserSelectByIdBorrowed) -> T,
+} impl<'a, C, T:'a, const N: usize> CachedUserSelectByIdQuery<'a, C, T, N> where C:
+GenericClient
+{
+    pub fn map<R>(self, mapper: fn(CachedUserSelectByIdBorrowed) -> R) ->
+    CachedUserSelectByIdQuery<'a,C,R,N>
+    {
+        CachedUserSelectByIdQuery
+        {
+            client: self.client, params: self.params, stmt: self.stmt,
+            extractor: self.extractor, mapper,
+        }
+    } pub async fn one(self) -> Result<T, tokio_postgres::Error>
+    {
+        let stmt = self.stmt.prepare(self.client).await?; let row =
+        self.client.query_one(stmt, &self.params).await?;
+        Ok((self.mapper)((self.extractor)(&row)))
+    } pub async fn all(self) -> Result<Vec<T>, tokio_postgres::Error>
+    { self.iter().await?.try_collect().await } pub async fn opt(self) ->
+    Result<Option<T>, tokio_postgres::Error>
+    {
+        let stmt = self.stmt.prepare(self.client).await?;
+        Ok(self.client.query_opt(stmt, &self.params) .await?
+        .map(|row| (self.mapper)((self.extractor)(&row))))
+    } pub async fn iter(self,) -> Result<impl futures::Stream<Item = Result<T,
+    tokio_postgres::Error>> + 'a, tokio_postgres::Error>
+    {
+        let stmt = self.stmt.prepare(self.client).await?; let it =
+        self.client.query_raw(stmt,
+        cornucopia_async::private::slice_iter(&self.params)) .await?
+        .map(move |res|
+        res.map(|row| (self.mapper)((self.extractor)(&row)))) .into_stream();
+        Ok(it)
+    }
+}pub fn cached_user_select_by_id() -> CachedUserSelectByIdStmt
+{ CachedUserSelectByIdStmt(cornucopia_async::private::Stmt::new("SELECT
+    *
+FROM
+    \"DiscordFrontend\".\"Nightly\".\"CachedUsers\"
+WHERE
+    \"id\" = $1")) } pub struct
+CachedUserSelectByIdStmt(cornucopia_async::private::Stmt); impl CachedUserSelectByIdStmt
+{ pub fn bind<'a, C:
+GenericClient,T1:
+cornucopia_async::StringSql,>(&'a mut self, client: &'a  C,
+id: &'a T1,) -> CachedUserSelectByIdQuery<'a,C,
+CachedUserSelectById, 1>
+{
+    CachedUserSelectByIdQuery
+    {
+        client, params: [id,], stmt: &mut self.0, extractor:
+        |row| { CachedUserSelectByIdBorrowed { avatar: row.get(0),bot: row.get(1),id: row.get(2),discriminator: row.get(3),global_name: row.get(4),name: row.get(5),} }, mapper: |it| { <CachedUserSelectById>::from(it) },
+    }
+} }}pub mod cached_user_upsert
+{ use futures::{{StreamExt, TryStreamExt}};use futures; use cornucopia_async::GenericClient;#[derive( Debug)] pub struct CachedUserUpsertParams<T1: cornucopia_async::StringSql,T2: cornucopia_async::StringSql,T3: cornucopia_async::StringSql,T4: cornucopia_async::StringSql,T5: cornucopia_async::StringSql,> { pub avatar: Option<T1>,pub id: T2,pub bot: bool,pub name: T3,pub discriminator: T4,pub global_name: Option<T5>,}pub fn cached_user_upsert() -> CachedUserUpsertStmt
+{ CachedUserUpsertStmt(cornucopia_async::private::Stmt::new("INSERT INTO \"DiscordFrontend\".\"Nightly\".\"CachedUsers\" (\"avatar\", \"id\", \"bot\", \"name\", \"discriminator\", \"global_name\")
+VALUES ($1, $2, $3, $4, $5, $6)
+ON CONFLICT (\"id\") DO UPDATE
+    SET
+        \"avatar\" = $1,
+        \"bot\" = $3,
+        \"name\" = $4,
+        \"discriminator\" = $5,
+        \"global_name\" = $6")) } pub struct
+CachedUserUpsertStmt(cornucopia_async::private::Stmt); impl CachedUserUpsertStmt
+{ pub async fn bind<'a, C:
+GenericClient,T1:
+cornucopia_async::StringSql,T2:
+cornucopia_async::StringSql,T3:
+cornucopia_async::StringSql,T4:
+cornucopia_async::StringSql,T5:
+cornucopia_async::StringSql,>(&'a mut self, client: &'a  C,
+avatar: &'a Option<T1>,id: &'a T2,bot: &'a bool,name: &'a T3,discriminator: &'a T4,global_name: &'a Option<T5>,) -> Result<u64, tokio_postgres::Error>
+{
+    let stmt = self.0.prepare(client).await?;
+    client.execute(stmt, &[avatar,id,bot,name,discriminator,global_name,]).await
+} }impl <'a, C: GenericClient + Send + Sync, T1: cornucopia_async::StringSql,T2: cornucopia_async::StringSql,T3: cornucopia_async::StringSql,T4: cornucopia_async::StringSql,T5: cornucopia_async::StringSql,>
+cornucopia_async::Params<'a, CachedUserUpsertParams<T1,T2,T3,T4,T5,>, std::pin::Pin<Box<dyn futures::Future<Output = Result<u64,
+tokio_postgres::Error>> + Send + 'a>>, C> for CachedUserUpsertStmt
+{
+    fn
+    params(&'a mut self, client: &'a  C, params: &'a
+    CachedUserUpsertParams<T1,T2,T3,T4,T5,>) -> std::pin::Pin<Box<dyn futures::Future<Output = Result<u64,
+    tokio_postgres::Error>> + Send + 'a>>
+    { Box::pin(self.bind(client, &params.avatar,&params.id,&params.bot,&params.name,&params.discriminator,&params.global_name,)) }
+}}pub mod cached_voice_state_delete_by_guild_id
+{ use futures::{{StreamExt, TryStreamExt}};use futures; use cornucopia_async::GenericClient;pub struct StringQuery<'a, C: GenericClient, T, const N: usize>
+{
+    client: &'a  C, params:
+    [&'a (dyn postgres_types::ToSql + Sync); N], stmt: &'a mut
+    cornucopia_async::private::Stmt, extractor: fn(&tokio_postgres::Row) -> &'a str,
+    mapper: fn(&'a str) -> T,
+} impl<'a, C, T:'a, const N: usize> StringQuery<'a, C, T, N> where C:
+GenericClient
+{
+    pub fn map<R>(self, mapper: fn(&'a str) -> R) ->
+    StringQuery<'a,C,R,N>
+    {
+        StringQuery
+        {
+            client: self.client, params: self.params, stmt: self.stmt,
+            extractor: self.extractor, mapper,
+        }
+    } pub async fn one(self) -> Result<T, tokio_postgres::Error>
+    {
+        let stmt = self.stmt.prepare(self.client).await?; let row =
+        self.client.query_one(stmt, &self.params).await?;
+        Ok((self.mapper)((self.extractor)(&row)))
+    } pub async fn all(self) -> Result<Vec<T>, tokio_postgres::Error>
+    { self.iter().await?.try_collect().await } pub async fn opt(self) ->
+    Result<Option<T>, tokio_postgres::Error>
+    {
+        let stmt = self.stmt.prepare(self.client).await?;
+        Ok(self.client.query_opt(stmt, &self.params) .await?
+        .map(|row| (self.mapper)((self.extractor)(&row))))
+    } pub async fn iter(self,) -> Result<impl futures::Stream<Item = Result<T,
+    tokio_postgres::Error>> + 'a, tokio_postgres::Error>
+    {
+        let stmt = self.stmt.prepare(self.client).await?; let it =
+        self.client.query_raw(stmt,
+        cornucopia_async::private::slice_iter(&self.params)) .await?
+        .map(move |res|
+        res.map(|row| (self.mapper)((self.extractor)(&row)))) .into_stream();
+        Ok(it)
+    }
+}pub fn cached_voice_state_delete_by_guild_id() -> CachedVoiceStateDeleteByGuildIdStmt
+{ CachedVoiceStateDeleteByGuildIdStmt(cornucopia_async::private::Stmt::new("DELETE FROM
+    \"DiscordFrontend\".\"Nightly\".\"CachedVoiceStates\"
+WHERE
+    \"guild_id\" = $1
+RETURNING
+    \"user_id\"")) } pub struct
+CachedVoiceStateDeleteByGuildIdStmt(cornucopia_async::private::Stmt); impl CachedVoiceStateDeleteByGuildIdStmt
+{ pub fn bind<'a, C:
+GenericClient,T1:
+cornucopia_async::StringSql,>(&'a mut self, client: &'a  C,
+guild_id: &'a T1,) -> StringQuery<'a,C,
+String, 1>
+{
+    StringQuery
+    {
+        client, params: [guild_id,], stmt: &mut self.0, extractor:
+        |row| { row.get(0) }, mapper: |it| { it.into() },
+    }
+} }}pub mod cached_voice_state_delete_by_guild_id_except_user_ids
+{ use futures::{{StreamExt, TryStreamExt}};use futures; use cornucopia_async::GenericClient;#[derive( Debug)] pub struct CachedVoiceStateDeleteByGuildIdExceptUserIdsParams<T1: cornucopia_async::StringSql,T2: cornucopia_async::StringSql,T3: cornucopia_async::ArraySql<Item = T2>,> { pub guild_id: T1,pub user_ids: T3,}pub struct StringQuery<'a, C: GenericClient, T, const N: usize>
+{
+    client: &'a  C, params:
+    [&'a (dyn postgres_types::ToSql + Sync); N], stmt: &'a mut
+    cornucopia_async::private::Stmt, extractor: fn(&tokio_postgres::Row) -> &'a str,
+    mapper: fn(&'a str) -> T,
+} impl<'a, C, T:'a, const N: usize> StringQuery<'a, C, T, N> where C:
+GenericClient
+{
+    pub fn map<R>(self, mapper: fn(&'a str) -> R) ->
+    StringQuery<'a,C,R,N>
+    {
+        StringQuery
+        {
+            client: self.client, params: self.params, stmt: self.stmt,
+            extractor: self.extractor, mapper,
+        }
+    } pub async fn one(self) -> Result<T, tokio_postgres::Error>
+    {
+        let stmt = self.stmt.prepare(self.client).await?; let row =
+        self.client.query_one(stmt, &self.params).await?;
+        Ok((self.mapper)((self.extractor)(&row)))
+    } pub async fn all(self) -> Result<Vec<T>, tokio_postgres::Error>
+    { self.iter().await?.try_collect().await } pub async fn opt(self) ->
+    Result<Option<T>, tokio_postgres::Error>
+    {
+        let stmt = self.stmt.prepare(self.client).await?;
+        Ok(self.client.query_opt(stmt, &self.params) .await?
+        .map(|row| (self.mapper)((self.extractor)(&row))))
+    } pub async fn iter(self,) -> Result<impl futures::Stream<Item = Result<T,
+    tokio_postgres::Error>> + 'a, tokio_postgres::Error>
+    {
+        let stmt = self.stmt.prepare(self.client).await?; let it =
+        self.client.query_raw(stmt,
+        cornucopia_async::private::slice_iter(&self.params)) .await?
+        .map(move |res|
+        res.map(|row| (self.mapper)((self.extractor)(&row)))) .into_stream();
+        Ok(it)
+    }
+}pub fn cached_voice_state_delete_by_guild_id_except_user_ids() -> CachedVoiceStateDeleteByGuildIdExceptUserIdsStmt
+{ CachedVoiceStateDeleteByGuildIdExceptUserIdsStmt(cornucopia_async::private::Stmt::new("DELETE FROM
+    \"DiscordFrontend\".\"Nightly\".\"CachedVoiceStates\"
+WHERE
+    \"guild_id\" = $1 AND
+    NOT (\"user_id\" = ANY($2))
+RETURNING
+    \"user_id\"")) } pub struct
+CachedVoiceStateDeleteByGuildIdExceptUserIdsStmt(cornucopia_async::private::Stmt); impl CachedVoiceStateDeleteByGuildIdExceptUserIdsStmt
+{ pub fn bind<'a, C:
+GenericClient,T1:
+cornucopia_async::StringSql,T2:
+cornucopia_async::StringSql,T3:
+cornucopia_async::ArraySql<Item = T2>,>(&'a mut self, client: &'a  C,
+guild_id: &'a T1,user_ids: &'a T3,) -> StringQuery<'a,C,
+String, 2>
+{
+    StringQuery
+    {
+        client, params: [guild_id,user_ids,], stmt: &mut self.0, extractor:
+        |row| { row.get(0) }, mapper: |it| { it.into() },
+    }
+} }impl <'a, C: GenericClient,T1: cornucopia_async::StringSql,T2: cornucopia_async::StringSql,T3: cornucopia_async::ArraySql<Item = T2>,> cornucopia_async::Params<'a,
+CachedVoiceStateDeleteByGuildIdExceptUserIdsParams<T1,T2,T3,>, StringQuery<'a, C,
+String, 2>, C> for CachedVoiceStateDeleteByGuildIdExceptUserIdsStmt
+{
+    fn
+    params(&'a mut self, client: &'a  C, params: &'a
+    CachedVoiceStateDeleteByGuildIdExceptUserIdsParams<T1,T2,T3,>) -> StringQuery<'a, C,
+    String, 2>
+    { self.bind(client, &params.guild_id,&params.user_ids,) }
+}}pub mod cached_voice_state_delete_by_user_id_and_guild_id
+{ use futures::{{StreamExt, TryStreamExt}};use futures; use cornucopia_async::GenericClient;#[derive( Debug)] pub struct CachedVoiceStateDeleteByUserIdAndGuildIdParams<T1: cornucopia_async::StringSql,T2: cornucopia_async::StringSql,> { pub user_id: T1,pub guild_id: T2,}pub fn cached_voice_state_delete_by_user_id_and_guild_id() -> CachedVoiceStateDeleteByUserIdAndGuildIdStmt
+{ CachedVoiceStateDeleteByUserIdAndGuildIdStmt(cornucopia_async::private::Stmt::new("DELETE FROM
+    \"DiscordFrontend\".\"Nightly\".\"CachedVoiceStates\"
+WHERE
+    \"user_id\" = $1 AND
+    \"guild_id\" = $2")) } pub struct
+CachedVoiceStateDeleteByUserIdAndGuildIdStmt(cornucopia_async::private::Stmt); impl CachedVoiceStateDeleteByUserIdAndGuildIdStmt
+{ pub async fn bind<'a, C:
+GenericClient,T1:
+cornucopia_async::StringSql,T2:
+cornucopia_async::StringSql,>(&'a mut self, client: &'a  C,
+user_id: &'a T1,guild_id: &'a T2,) -> Result<u64, tokio_postgres::Error>
+{
+    let stmt = self.0.prepare(client).await?;
+    client.execute(stmt, &[user_id,guild_id,]).await
+} }impl <'a, C: GenericClient + Send + Sync, T1: cornucopia_async::StringSql,T2: cornucopia_async::StringSql,>
+cornucopia_async::Params<'a, CachedVoiceStateDeleteByUserIdAndGuildIdParams<T1,T2,>, std::pin::Pin<Box<dyn futures::Future<Output = Result<u64,
+tokio_postgres::Error>> + Send + 'a>>, C> for CachedVoiceStateDeleteByUserIdAndGuildIdStmt
+{
+    fn
+    params(&'a mut self, client: &'a  C, params: &'a
+    CachedVoiceStateDeleteByUserIdAndGuildIdParams<T1,T2,>) -> std::pin::Pin<Box<dyn futures::Future<Output = Result<u64,
+    tokio_postgres::Error>> + Send + 'a>>
+    { Box::pin(self.bind(client, &params.user_id,&params.guild_id,)) }
+}}pub mod cached_voice_state_select_by_user_id_and_guild_id
+{ use futures::{{StreamExt, TryStreamExt}};use futures; use cornucopia_async::GenericClient;#[derive( Debug)] pub struct CachedVoiceStateSelectByUserIdAndGuildIdParams<T1: cornucopia_async::StringSql,T2: cornucopia_async::StringSql,> { pub user_id: T1,pub guild_id: T2,}#[derive( Debug, Clone, PartialEq,)] pub struct CachedVoiceStateSelectByUserIdAndGuildId
+{ pub channel_id : Option<String>,pub guild_id : String,pub user_id : String,}pub struct CachedVoiceStateSelectByUserIdAndGuildIdBorrowed<'a> { pub channel_id : Option<&'a str>,pub guild_id : &'a str,pub user_id : &'a str,}
+impl<'a> From<CachedVoiceStateSelectByUserIdAndGuildIdBorrowed<'a>> for CachedVoiceStateSelectByUserIdAndGuildId
+{
+    fn from(CachedVoiceStateSelectByUserIdAndGuildIdBorrowed { channel_id,guild_id,user_id,}: CachedVoiceStateSelectByUserIdAndGuildIdBorrowed<'a>) ->
+    Self { Self { channel_id: channel_id.map(|v| v.into()),guild_id: guild_id.into(),user_id: user_id.into(),} }
+}pub struct CachedVoiceStateSelectByUserIdAndGuildIdQuery<'a, C: GenericClient, T, const N: usize>
+{
+    client: &'a  C, params:
+    [&'a (dyn postgres_types::ToSql + Sync); N], stmt: &'a mut
+    cornucopia_async::private::Stmt, extractor: fn(&tokio_postgres::Row) -> CachedVoiceStateSelectByUserIdAndGuildIdBorrowed,
+    mapper: fn(CachedVoiceStateSelectByUserIdAndGuildIdBorrowed) -> T,
+} impl<'a, C, T:'a, const N: usize> CachedVoiceStateSelectByUserIdAndGuildIdQuery<'a, C, T, N> where C:
+GenericClient
+{
+    pub fn map<R>(self, mapper: fn(CachedVoiceStateSelectByUserIdAndGuildIdBorrowed) -> R) ->
+    CachedVoiceStateSelectByUserIdAndGuildIdQuery<'a,C,R,N>
+    {
+        CachedVoiceStateSelectByUserIdAndGuildIdQuery
+        {
+            client: self.client, params: self.params, stmt: self.stmt,
+            extractor: self.extractor, mapper,
+        }
+    } pub async fn one(self) -> Result<T, tokio_postgres::Error>
+    {
+        let stmt = self.stmt.prepare(self.client).await?; let row =
+        self.client.query_one(stmt, &self.params).await?;
+        Ok((self.mapper)((self.extractor)(&row)))
+    } pub async fn all(self) -> Result<Vec<T>, tokio_postgres::Error>
+    { self.iter().await?.try_collect().await } pub async fn opt(self) ->
+    Result<Option<T>, tokio_postgres::Error>
+    {
+        let stmt = self.stmt.prepare(self.client).await?;
+        Ok(self.client.query_opt(stmt, &self.params) .await?
+        .map(|row| (self.mapper)((self.extractor)(&row))))
+    } pub async fn iter(self,) -> Result<impl futures::Stream<Item = Result<T,
+    tokio_postgres::Error>> + 'a, tokio_postgres::Error>
+    {
+        let stmt = self.stmt.prepare(self.client).await?; let it =
+        self.client.query_raw(stmt,
+        cornucopia_async::private::slice_iter(&self.params)) .await?
+        .map(move |res|
+        res.map(|row| (self.mapper)((self.extractor)(&row)))) .into_stream();
+        Ok(it)
+    }
+}pub fn cached_voice_state_select_by_user_id_and_guild_id() -> CachedVoiceStateSelectByUserIdAndGuildIdStmt
+{ CachedVoiceStateSelectByUserIdAndGuildIdStmt(cornucopia_async::private::Stmt::new("SELECT
+    *
+FROM
+    \"DiscordFrontend\".\"Nightly\".\"CachedVoiceStates\"
+WHERE
+    \"user_id\" = $1 AND
+    \"guild_id\" = $2")) } pub struct
+CachedVoiceStateSelectByUserIdAndGuildIdStmt(cornucopia_async::private::Stmt); impl CachedVoiceStateSelectByUserIdAndGuildIdStmt
+{ pub fn bind<'a, C:
+GenericClient,T1:
+cornucopia_async::StringSql,T2:
+cornucopia_async::StringSql,>(&'a mut self, client: &'a  C,
+user_id: &'a T1,guild_id: &'a T2,) -> CachedVoiceStateSelectByUserIdAndGuildIdQuery<'a,C,
+CachedVoiceStateSelectByUserIdAndGuildId, 2>
+{
+    CachedVoiceStateSelectByUserIdAndGuildIdQuery
+    {
+        client, params: [user_id,guild_id,], stmt: &mut self.0, extractor:
+        |row| { CachedVoiceStateSelectByUserIdAndGuildIdBorrowed { channel_id: row.get(0),guild_id: row.get(1),user_id: row.get(2),} }, mapper: |it| { <CachedVoiceStateSelectByUserIdAndGuildId>::from(it) },
+    }
+} }impl <'a, C: GenericClient,T1: cornucopia_async::StringSql,T2: cornucopia_async::StringSql,> cornucopia_async::Params<'a,
+CachedVoiceStateSelectByUserIdAndGuildIdParams<T1,T2,>, CachedVoiceStateSelectByUserIdAndGuildIdQuery<'a, C,
+CachedVoiceStateSelectByUserIdAndGuildId, 2>, C> for CachedVoiceStateSelectByUserIdAndGuildIdStmt
+{
+    fn
+    params(&'a mut self, client: &'a  C, params: &'a
+    CachedVoiceStateSelectByUserIdAndGuildIdParams<T1,T2,>) -> CachedVoiceStateSelectByUserIdAndGuildIdQuery<'a, C,
+    CachedVoiceStateSelectByUserIdAndGuildId, 2>
+    { self.bind(client, &params.user_id,&params.guild_id,) }
+}}pub mod cached_voice_state_upsert
+{ use futures::{{StreamExt, TryStreamExt}};use futures; use cornucopia_async::GenericClient;#[derive( Debug)] pub struct CachedVoiceStateUpsertParams<T1: cornucopia_async::StringSql,T2: cornucopia_async::StringSql,T3: cornucopia_async::StringSql,> { pub channel_id: Option<T1>,pub guild_id: T2,pub user_id: T3,}pub fn cached_voice_state_upsert() -> CachedVoiceStateUpsertStmt
+{ CachedVoiceStateUpsertStmt(cornucopia_async::private::Stmt::new("INSERT INTO \"DiscordFrontend\".\"Nightly\".\"CachedVoiceStates\" (\"channel_id\", \"guild_id\", \"user_id\")
+VALUES ($1, $2, $3)
+ON CONFLICT (\"guild_id\", \"user_id\") DO UPDATE
+    SET
+        \"channel_id\" = $1")) } pub struct
+CachedVoiceStateUpsertStmt(cornucopia_async::private::Stmt); impl CachedVoiceStateUpsertStmt
 { pub async fn bind<'a, C:
 GenericClient,T1:
 cornucopia_async::StringSql,T2:
-cornucopia_async::ArraySql<Item = T1>,T3:
+cornucopia_async::StringSql,T3:
+cornucopia_async::StringSql,>(&'a mut self, client: &'a  C,
+channel_id: &'a Option<T1>,guild_id: &'a T2,user_id: &'a T3,) -> Result<u64, tokio_postgres::Error>
+{
+    let stmt = self.0.prepare(client).await?;
+    client.execute(stmt, &[channel_id,guild_id,user_id,]).await
+} }impl <'a, C: GenericClient + Send + Sync, T1: cornucopia_async::StringSql,T2: cornucopia_async::StringSql,T3: cornucopia_async::StringSql,>
+cornucopia_async::Params<'a, CachedVoiceStateUpsertParams<T1,T2,T3,>, std::pin::Pin<Box<dyn futures::Future<Output = Result<u64,
+tokio_postgres::Error>> + Send + 'a>>, C> for CachedVoiceStateUpsertStmt
+{
+    fn
+    params(&'a mut self, client: &'a  C, params: &'a
+    CachedVoiceStateUpsertParams<T1,T2,T3,>) -> std::pin::Pin<Box<dyn futures::Future<Output = Result<u64,
+    tokio_postgres::Error>> + Send + 'a>>
+    { Box::pin(self.bind(client, &params.channel_id,&params.guild_id,&params.user_id,)) }
+}}pub mod command_usage_insert
+{ use futures::{{StreamExt, TryStreamExt}};use futures; use cornucopia_async::GenericClient;#[derive( Debug)] pub struct CommandUsageInsertParams<T1: cornucopia_async::StringSql,T2: cornucopia_async::StringSql,T3: cornucopia_async::StringSql,T4: cornucopia_async::StringSql,T5: cornucopia_async::StringSql,T6: cornucopia_async::StringSql,> { pub command: T1,pub subcommand: Option<T2>,pub guild_id: Option<T3>,pub locale: T4,pub latency_ms: i64,pub outcome: T5,pub error_hash: Option<T6>,pub invoked_at: time::OffsetDateTime,}pub fn command_usage_insert() -> CommandUsageInsertStmt
+{ CommandUsageInsertStmt(cornucopia_async::private::Stmt::new("INSERT INTO \"DiscordFrontend\".\"Nightly\".\"CommandUsages\" (\"command\", \"subcommand\", \"guild_id\", \"locale\", \"latency_ms\", \"outcome\", \"error_hash\", \"invoked_at\")
+VALUES ($1, $2, $3, $4, $5, $6, $7, $8)")) } pub struct
+CommandUsageInsertStmt(cornucopia_async::private::Stmt); impl CommandUsageInsertStmt
+{ pub async fn bind<'a, C:
+GenericClient,T1:
+cornucopia_async::StringSql,T2:
+cornucopia_async::StringSql,T3:
 cornucopia_async::StringSql,T4:
 cornucopia_async::StringSql,T5:
 cornucopia_async::StringSql,T6:
 cornucopia_async::StringSql,>(&'a mut self, client: &'a  C,
-default_message_notifications: &'a i16,explicit_content_filter: &'a i16,features: &'a T2,icon: &'a Option<T3>,large: &'a bool,name: &'a T4,owner_id: &'a T5,id: &'a T6,mfa_level: &'a i16,premium_subscription_count: &'a Option<i64>,premium_tier: &'a i16,verification_level: &'a i16,) -> Result<u64, tokio_postgres::Error>
+command: &'a T1,subcommand: &'a Option<T2>,guild_id: &'a Option<T3>,locale: &'a T4,latency_ms: &'a i64,outcome: &'a T5,error_hash: &'a Option<T6>,invoked_at: &'a time::OffsetDateTime,) -> Result<u64, tokio_postgres::Error>
 {
     let stmt = self.0.prepare(client).await?;
-    client.execute(stmt, &[default_message_notifications,explicit_content_filter,features,icon,large,name,owner_id,id,mfa_level,premium_subscription_count,premium_tier,verification_level,]).await
-} }impl <'a, C: GenericClient + Send + Sync, T1: cornucopia_async::StringSql,T2: cornucopia_async::ArraySql<Item = T1>,T3: cornucopia_async::StringSql,T4: cornucopia_async::StringSql,T5: cornucopia_async::StringSql,T6: cornucopia_async::StringSql,>
-cornucopia_async::Params<'a, CachedGuildUpsertParams<T1,T2,T3,T4,T5,T6,>, std::pin::Pin<Box<dyn futures::Future<Output = Result<u64,
-tokio_postgres::Error>> + Send + 'a>>, C> for CachedGuildUpsertStmt
+    client.execute(stmt, &[command,subcommand,guild_id,locale,latency_ms,outcome,error_hash,invoked_at,]).await
+} }impl <'a, C: GenericClient + Send + Sync, T1: cornucopia_async::StringSql,T2: cornucopia_async::StringSql,T3: cornucopia_async::StringSql,T4: cornucopia_async::StringSql,T5: cornucopia_async::StringSql,T6: cornucopia_async::StringSql,>
+cornucopia_async::Params<'a, CommandUsageInsertParams<T1,T2,T3,T4,T5,T6,>, std::pin::Pin<Box<dyn futures::Future<Output = Result<u64,
+tokio_postgres::Error>> + Send + 'a>>, C> for CommandUsageInsertStmt
 {
     fn
     params(&'a mut self, client: &'a  C, params: &'a
-    CachedGuildUpsertParams<T1,T2,T3,T4,T5,T6,>) -> std::pin::Pin<Box<dyn futures::Future<Output = Result<u64,
+    CommandUsageInsertParams<T1,T2,T3,T4,T5,T6,>) -> std::pin::Pin<Box<dyn futures::Future<Output = Result<u64,
     tokio_postgres::Error>> + Send + 'a>>
-    { Box::pin(self.bind(client, &params.default_message_notifications,&params.explicit_content_filter,&params.features,&params.icon,&params.large,&params.name,&params.owner_id,&params.id,&params.mfa_level,&params.premium_subscription_count,&params.premium_tier,&params.verification_level,)) }
-}}pub mod cached_member_select_by_guild_id
-{ use futures::{{StreamExt, TryStreamExt}};use futures; use cornucopia_async::GenericClient;#[derive( Debug, Clone, PartialEq,)] pub struct CachedMemberSelectByGuildId
-{ pub flags : i64,pub joined_at : Option<time::OffsetDateTime>,pub nick : Option<String>,pub roles : Vec<String>,pub guild_id : String,pub user_id : String,}pub struct CachedMemberSelectByGuildIdBorrowed<'a> { pub flags : i64,pub joined_at : Option<time::OffsetDateTime>,pub nick : Option<&'a str>,pub roles : cornucopia_async::ArrayIterator<'a, &'a str>,pub guild_id : &'a str,pub user_id : &'a str,}
-impl<'a> From<CachedMemberSelectByGuildIdBorrowed<'a>> for CachedMemberSelectByGuildId
+    { Box::pin(self.bind(client, &params.command,&params.subcommand,&params.guild_id,&params.locale,&params.latency_ms,&params.outcome,&params.error_hash,&params.invoked_at,)) }
+}}pub mod command_usage_rollup_select_by_guild_id_since
+{ use futures::{{StreamExt, TryStreamExt}};use futures; use cornucopia_async::GenericClient;#[derive( Debug)] pub struct CommandUsageRollupSelectByGuildIdSinceParams<T1: cornucopia_async::StringSql,> { pub guild_id: T1,pub since: time::OffsetDateTime,}#[derive( Debug, Clone, PartialEq,)] pub struct CommandUsageRollupSelectByGuildIdSince
+{ pub command : String,pub invocations : i64,pub failures : i64,pub rejections : i64,pub total_latency_ms : i64,}pub struct CommandUsageRollupSelectByGuildIdSinceBorrowed<'a> { pub command : &'a str,pub invocations : i64,pub failures : i64,pub rejections : i64,pub total_latency_ms : i64,}
+impl<'a> From<CommandUsageRollupSelectByGuildIdSinceBorrowed<'a>> for CommandUsageRollupSelectByGuildIdSince
 {
-    fn from(CachedMemberSelectByGuildIdBorrowed { flags,joined_at,nick,roles,guild_id,user_id,}: CachedMemberSelectByGuildIdBorrowed<'a>) ->
-    Self { Self { flags,joined_at,nick: nick.map(|v| v.into()),roles: roles.map(|v| v.into()).collect(),guild_id: guild_id.into(),user_id: user_id.into(),} }
-}pub struct CachedMemberSelectByGuildIdQuery<'a, C: GenericClient, T, const N: usize>
+    fn from(CommandUsageRollupSelectByGuildIdSinceBorrowed { command,invocations,failures,rejections,total_latency_ms,}: CommandUsageRollupSelectByGuildIdSinceBorrowed<'a>) ->
+    Self { Self { command: command.into(),invocations,failures,rejections,total_latency_ms,} }
+}pub struct CommandUsageRollupSelectByGuildIdSinceQuery<'a, C: GenericClient, T, const N: usize>
 {
     client: &'a  C, params:
     [&'a (dyn postgres_types::ToSql + Sync); N], stmt: &'a mut
-    cornucopia_async::private::Stmt, extractor: fn(&tokio_postgres::Row) -> CachedMemberSelectByGuildIdBorrowed,
-    mapper: fn(CachedMemberSelectByGuildIdBorrowed) -> T,
-} impl<'a, C, T:'a, const N: usize> CachedMemberSelectByGuildIdQuery<'a, C, T, N> where C:
+    cornucopia_async::private::Stmt, extractor: fn(&tokio_postgres::Row) -> CommandUsageRollupSelectByGuildIdSinceBorrowed,
+    mapper: fn(CommandUsageRollupSelectByGuildIdSinceBorrowed) -> T,
+} impl<'a, C, T:'a, const N: usize> CommandUsageRollupSelectByGuildIdSinceQuery<'a, C, T, N> where C:
 GenericClient
 {
-    pub fn map<R>(self, mapper: fn(CachedMemberSelectByGuildIdBorrowed) -> R) ->
-    CachedMemberSelectByGuildIdQuery<'a,C,R,N>
+    pub fn map<R>(self, mapper: fn(CommandUsageRollupSelectByGuildIdSinceBorrowed) -> R) ->
+    CommandUsageRollupSelectByGuildIdSinceQuery<'a,C,R,N>
     {
-        CachedMemberSelectByGuildIdQuery
+        CommandUsageRollupSelectByGuildIdSinceQuery
         {
             client: self.client, params: self.params, stmt: self.stmt,
             extractor: self.extractor, mapper,
@@ -313,45 +2414,63 @@ GenericClient
         res.map(|row| (self.mapper)((self.extractor)(&row)))) .into_stream();
         Ok(it)
     }
-}pub fn cached_member_select_by_guild_id() -> CachedMemberSelectByGuildIdStmt
-{ CachedMemberSelectByGuildIdStmt(cornucopia_async::private::Stmt::new("SELECT
-    *
+}pub fn command_usage_rollup_select_by_guild_id_since() -> CommandUsageRollupSelectByGuildIdSinceStmt
+{ CommandUsageRollupSelectByGuildIdSinceStmt(cornucopia_async::private::Stmt::new("SELECT
+    \"command\",
+    SUM(\"invocations\")::BIGINT AS \"invocations\",
+    SUM(\"failures\")::BIGINT AS \"failures\",
+    SUM(\"rejections\")::BIGINT AS \"rejections\",
+    SUM(\"total_latency_ms\")::BIGINT AS \"total_latency_ms\"
 FROM
-    \"DiscordFrontend\".\"Nightly\".\"CachedMembers\"
+    \"DiscordFrontend\".\"Nightly\".\"CommandUsageRollups\"
 WHERE
-    \"guild_id\" = $1")) } pub struct
-CachedMemberSelectByGuildIdStmt(cornucopia_async::private::Stmt); impl CachedMemberSelectByGuildIdStmt
+    \"guild_id\" = $1 AND
+    \"day\" >= $2
+GROUP BY
+    \"command\"
+ORDER BY
+    \"invocations\" DESC")) } pub struct
+CommandUsageRollupSelectByGuildIdSinceStmt(cornucopia_async::private::Stmt); impl CommandUsageRollupSelectByGuildIdSinceStmt
 { pub fn bind<'a, C:
 GenericClient,T1:
 cornucopia_async::StringSql,>(&'a mut self, client: &'a  C,
-guild_id: &'a T1,) -> CachedMemberSelectByGuildIdQuery<'a,C,
-CachedMemberSelectByGuildId, 1>
+guild_id: &'a T1,since: &'a time::OffsetDateTime,) -> CommandUsageRollupSelectByGuildIdSinceQuery<'a,C,
+CommandUsageRollupSelectByGuildIdSince, 2>
 {
-    CachedMemberSelectByGuildIdQuery
+    CommandUsageRollupSelectByGuildIdSinceQuery
     {
-        client, params: [guild_id,], stmt: &mut self.0, extractor:
-        |row| { CachedMemberSelectByGuildIdBorrowed { flags: row.get(0),joined_at: row.get(1),nick: row.get(2),roles: row.get(3),guild_id: row.get(4),user_id: row.get(5),} }, mapper: |it| { <CachedMemberSelectByGuildId>::from(it) },
+        client, params: [guild_id,since,], stmt: &mut self.0, extractor:
+        |row| { CommandUsageRollupSelectByGuildIdSinceBorrowed { command: row.get(0),invocations: row.get(1),failures: row.get(2),rejections: row.get(3),total_latency_ms: row.get(4),} }, mapper: |it| { <CommandUsageRollupSelectByGuildIdSince>::from(it) },
     }
-} }}pub mod cached_member_select_by_user_id_and_guild_id
-{ use futures::{{StreamExt, TryStreamExt}};use futures; use cornucopia_async::GenericClient;#[derive( Debug)] pub struct CachedMemberSelectByUserIdAndGuildIdParams<T1: cornucopia_async::StringSql,T2: cornucopia_async::StringSql,> { pub user_id: T1,pub guild_id: T2,}#[derive( Debug, Clone, PartialEq,)] pub struct CachedMemberSelectByUserIdAndGuildId
-{ pub flags : i64,pub joined_at : Option<time::OffsetDateTime>,pub nick : Option<String>,pub roles : Vec<String>,pub guild_id : String,pub user_id : String,}pub struct CachedMemberSelectByUserIdAndGuildIdBorrowed<'a> { pub flags : i64,pub joined_at : Option<time::OffsetDateTime>,pub nick : Option<&'a str>,pub roles : cornucopia_async::ArrayIterator<'a, &'a str>,pub guild_id : &'a str,pub user_id : &'a str,}
-impl<'a> From<CachedMemberSelectByUserIdAndGuildIdBorrowed<'a>> for CachedMemberSelectByUserIdAndGuildId
+} }impl <'a, C: GenericClient,T1: cornucopia_async::StringSql,> cornucopia_async::Params<'a,
+CommandUsageRollupSelectByGuildIdSinceParams<T1,>, CommandUsageRollupSelectByGuildIdSinceQuery<'a, C,
+CommandUsageRollupSelectByGuildIdSince, 2>, C> for CommandUsageRollupSelectByGuildIdSinceStmt
 {
-    fn from(CachedMemberSelectByUserIdAndGuildIdBorrowed { flags,joined_at,nick,roles,guild_id,user_id,}: CachedMemberSelectByUserIdAndGuildIdBorrowed<'a>) ->
-    Self { Self { flags,joined_at,nick: nick.map(|v| v.into()),roles: roles.map(|v| v.into()).collect(),guild_id: guild_id.into(),user_id: user_id.into(),} }
-}pub struct CachedMemberSelectByUserIdAndGuildIdQuery<'a, C: GenericClient, T, const N: usize>
+    fn
+    params(&'a mut self, client: &'a  C, params: &'a
+    CommandUsageRollupSelectByGuildIdSinceParams<T1,>) -> CommandUsageRollupSelectByGuildIdSinceQuery<'a, C,
+    CommandUsageRollupSelectByGuildIdSince, 2>
+    { self.bind(client, &params.guild_id,&params.since,) }
+}}pub mod command_usage_rollup_select_since
+{ use futures::{{StreamExt, TryStreamExt}};use futures; use cornucopia_async::GenericClient;#[derive( Debug, Clone, PartialEq,)] pub struct CommandUsageRollupSelectSince
+{ pub command : String,pub invocations : i64,pub failures : i64,pub rejections : i64,pub total_latency_ms : i64,}pub struct CommandUsageRollupSelectSinceBorrowed<'a> { pub command : &'a str,pub invocations : i64,pub failures : i64,pub rejections : i64,pub total_latency_ms : i64,}
+impl<'a> From<CommandUsageRollupSelectSinceBorrowed<'a>> for CommandUsageRollupSelectSince
+{
+    fn from(CommandUsageRollupSelectSinceBorrowed { command,invocations,failures,rejections,total_latency_ms,}: CommandUsageRollupSelectSinceBorrowed<'a>) ->
+    Self { Self { command: command.into(),invocations,failures,rejections,total_latency_ms,} }
+}pub struct CommandUsageRollupSelectSinceQuery<'a, C: GenericClient, T, const N: usize>
 {
     client: &'a  C, params:
     [&'a (dyn postgres_types::ToSql + Sync); N], stmt: &'a mut
-    cornucopia_async::private::Stmt, extractor: fn(&tokio_postgres::Row) -> CachedMemberSelectByUserIdAndGuildIdBorrowed,
-    mapper: fn(CachedMemberSelectByUserIdAndGuildIdBorrowed) -> T,
-} impl<'a, C, T:'a, const N: usize> CachedMemberSelectByUserIdAndGuildIdQuery<'a, C, T, N> where C:
+    cornucopia_async::private::Stmt, extractor: fn(&tokio_postgres::Row) -> CommandUsageRollupSelectSinceBorrowed,
+    mapper: fn(CommandUsageRollupSelectSinceBorrowed) -> T,
+} impl<'a, C, T:'a, const N: usize> CommandUsageRollupSelectSinceQuery<'a, C, T, N> where C:
 GenericClient
 {
-    pub fn map<R>(self, mapper: fn(CachedMemberSelectByUserIdAndGuildIdBorrowed) -> R) ->
-    CachedMemberSelectByUserIdAndGuildIdQuery<'a,C,R,N>
+    pub fn map<R>(self, mapper: fn(CommandUsageRollupSelectSinceBorrowed) -> R) ->
+    CommandUsageRollupSelectSinceQuery<'a,C,R,N>
     {
-        CachedMemberSelectByUserIdAndGuildIdQuery
+        CommandUsageRollupSelectSinceQuery
         {
             client: self.client, params: self.params, stmt: self.stmt,
             extractor: self.extractor, mapper,
@@ -378,87 +2497,99 @@ GenericClient
         res.map(|row| (self.mapper)((self.extractor)(&row)))) .into_stream();
         Ok(it)
     }
-}pub fn cached_member_select_by_user_id_and_guild_id() -> CachedMemberSelectByUserIdAndGuildIdStmt
-{ CachedMemberSelectByUserIdAndGuildIdStmt(cornucopia_async::private::Stmt::new("SELECT
-    *
+}pub fn command_usage_rollup_select_since() -> CommandUsageRollupSelectSinceStmt
+{ CommandUsageRollupSelectSinceStmt(cornucopia_async::private::Stmt::new("SELECT
+    \"command\",
+    SUM(\"invocations\")::BIGINT AS \"invocations\",
+    SUM(\"failures\")::BIGINT AS \"failures\",
+    SUM(\"rejections\")::BIGINT AS \"rejections\",
+    SUM(\"total_latency_ms\")::BIGINT AS \"total_latency_ms\"
 FROM
-    \"DiscordFrontend\".\"Nightly\".\"CachedMembers\"
+    \"DiscordFrontend\".\"Nightly\".\"CommandUsageRollups\"
 WHERE
-    user_id = $1 AND
-    guild_id = $2")) } pub struct
-CachedMemberSelectByUserIdAndGuildIdStmt(cornucopia_async::private::Stmt); impl CachedMemberSelectByUserIdAndGuildIdStmt
+    \"day\" >= $1
+GROUP BY
+    \"command\"
+ORDER BY
+    \"invocations\" DESC")) } pub struct
+CommandUsageRollupSelectSinceStmt(cornucopia_async::private::Stmt); impl CommandUsageRollupSelectSinceStmt
 { pub fn bind<'a, C:
+GenericClient,>(&'a mut self, client: &'a  C,
+since: &'a time::OffsetDateTime,) -> CommandUsageRollupSelectSinceQuery<'a,C,
+CommandUsageRollupSelectSince, 1>
+{
+    CommandUsageRollupSelectSinceQuery
+    {
+        client, params: [since,], stmt: &mut self.0, extractor:
+        |row| { CommandUsageRollupSelectSinceBorrowed { command: row.get(0),invocations: row.get(1),failures: row.get(2),rejections: row.get(3),total_latency_ms: row.get(4),} }, mapper: |it| { <CommandUsageRollupSelectSince>::from(it) },
+    }
+} }}pub mod command_usage_rollup_upsert
+{ use futures::{{StreamExt, TryStreamExt}};use futures; use cornucopia_async::GenericClient;#[derive( Debug)] pub struct CommandUsageRollupUpsertParams<T1: cornucopia_async::StringSql,T2: cornucopia_async::StringSql,> { pub invoked_at: time::OffsetDateTime,pub command: T1,pub guild_id: T2,pub failures: i64,pub rejections: i64,pub latency_ms: i64,}pub fn command_usage_rollup_upsert() -> CommandUsageRollupUpsertStmt
+{ CommandUsageRollupUpsertStmt(cornucopia_async::private::Stmt::new("INSERT INTO \"DiscordFrontend\".\"Nightly\".\"CommandUsageRollups\" (\"day\", \"command\", \"guild_id\", \"invocations\", \"failures\", \"rejections\", \"total_latency_ms\")
+VALUES (date_trunc('day', $1, 'UTC'), $2, $3, 1, $4, $5, $6)
+ON CONFLICT (\"day\", \"command\", \"guild_id\") DO UPDATE
+SET
+    \"invocations\" = \"CommandUsageRollups\".\"invocations\" + 1,
+    \"failures\" = \"CommandUsageRollups\".\"failures\" + excluded.\"failures\",
+    \"rejections\" = \"CommandUsageRollups\".\"rejections\" + excluded.\"rejections\",
+    \"total_latency_ms\" = \"CommandUsageRollups\".\"total_latency_ms\" + excluded.\"total_latency_ms\"")) } pub struct
+CommandUsageRollupUpsertStmt(cornucopia_async::private::Stmt); impl CommandUsageRollupUpsertStmt
+{ pub async fn bind<'a, C:
 GenericClient,T1:
 cornucopia_async::StringSql,T2:
 cornucopia_async::StringSql,>(&'a mut self, client: &'a  C,
-user_id: &'a T1,guild_id: &'a T2,) -> CachedMemberSelectByUserIdAndGuildIdQuery<'a,C,
-CachedMemberSelectByUserIdAndGuildId, 2>
+invoked_at: &'a time::OffsetDateTime,command: &'a T1,guild_id: &'a T2,failures: &'a i64,rejections: &'a i64,latency_ms: &'a i64,) -> Result<u64, tokio_postgres::Error>
 {
-    CachedMemberSelectByUserIdAndGuildIdQuery
-    {
-        client, params: [user_id,guild_id,], stmt: &mut self.0, extractor:
-        |row| { CachedMemberSelectByUserIdAndGuildIdBorrowed { flags: row.get(0),joined_at: row.get(1),nick: row.get(2),roles: row.get(3),guild_id: row.get(4),user_id: row.get(5),} }, mapper: |it| { <CachedMemberSelectByUserIdAndGuildId>::from(it) },
-    }
-} }impl <'a, C: GenericClient,T1: cornucopia_async::StringSql,T2: cornucopia_async::StringSql,> cornucopia_async::Params<'a,
-CachedMemberSelectByUserIdAndGuildIdParams<T1,T2,>, CachedMemberSelectByUserIdAndGuildIdQuery<'a, C,
-CachedMemberSelectByUserIdAndGuildId, 2>, C> for CachedMemberSelectByUserIdAndGuildIdStmt
+    let stmt = self.0.prepare(client).await?;
+    client.execute(stmt, &[invoked_at,command,guild_id,failures,rejections,latency_ms,]).await
+} }impl <'a, C: GenericClient + Send + Sync, T1: cornucopia_async::StringSql,T2: cornucopia_async::StringSql,>
+cornucopia_async::Params<'a, CommandUsageRollupUpsertParams<T1,T2,>, std::pin::Pin<Box<dyn futures::Future<Output = Result<u64,
+tokio_postgres::Error>> + Send + 'a>>, C> for CommandUsageRollupUpsertStmt
 {
     fn
     params(&'a mut self, client: &'a  C, params: &'a
-    CachedMemberSelectByUserIdAndGuildIdParams<T1,T2,>) -> CachedMemberSelectByUserIdAndGuildIdQuery<'a, C,
-    CachedMemberSelectByUserIdAndGuildId, 2>
-    { self.bind(client, &params.user_id,&params.guild_id,) }
-}}pub mod cached_member_upsert
-{ use futures::{{StreamExt, TryStreamExt}};use futures; use cornucopia_async::GenericClient;#[derive( Debug)] pub struct CachedMemberUpsertParams<T1: cornucopia_async::StringSql,T2: cornucopia_async::StringSql,T3: cornucopia_async::StringSql,T4: cornucopia_async::StringSql,T5: cornucopia_async::ArraySql<Item = T4>,> { pub flags: i64,pub joined_at: Option<time::OffsetDateTime>,pub nick: Option<T1>,pub user_id: T2,pub guild_id: T3,pub roles: T5,}pub fn cached_member_upsert() -> CachedMemberUpsertStmt
-{ CachedMemberUpsertStmt(cornucopia_async::private::Stmt::new("INSERT INTO \"DiscordFrontend\".\"Nightly\".\"CachedMembers\" (\"flags\", \"joined_at\", \"nick\", \"user_id\", \"guild_id\", \"roles\")
-VALUES ($1, $2, $3, $4, $5, $6)
-ON CONFLICT (\"user_id\", \"guild_id\") DO UPDATE
-    SET
-        \"flags\" = $1,
-        \"joined_at\" = $2,
-        \"nick\" = $3,
-        \"roles\" = $6")) } pub struct
-CachedMemberUpsertStmt(cornucopia_async::private::Stmt); impl CachedMemberUpsertStmt
+    CommandUsageRollupUpsertParams<T1,T2,>) -> std::pin::Pin<Box<dyn futures::Future<Output = Result<u64,
+    tokio_postgres::Error>> + Send + 'a>>
+    { Box::pin(self.bind(client, &params.invoked_at,&params.command,&params.guild_id,&params.failures,&params.rejections,&params.latency_ms,)) }
+}}pub mod infraction_audit_log_insert
+{ use futures::{{StreamExt, TryStreamExt}};use futures; use cornucopia_async::GenericClient;#[derive( Debug)] pub struct InfractionAuditLogInsertParams<T1: cornucopia_async::StringSql,T2: cornucopia_async::StringSql,T3: cornucopia_async::StringSql,T4: cornucopia_async::StringSql,T5: cornucopia_async::StringSql,> { pub guild_id: T1,pub case_number: i64,pub actor_id: T2,pub action: T3,pub old_reason: Option<T4>,pub new_reason: Option<T5>,pub timestamp: time::OffsetDateTime,}pub fn infraction_audit_log_insert() -> InfractionAuditLogInsertStmt
+{ InfractionAuditLogInsertStmt(cornucopia_async::private::Stmt::new("INSERT INTO \"DiscordFrontend\".\"Nightly\".\"InfractionAuditLogs\" (\"guild_id\", \"case_number\", \"actor_id\", \"action\", \"old_reason\", \"new_reason\", \"timestamp\")
+VALUES ($1, $2, $3, $4, $5, $6, $7)")) } pub struct
+InfractionAuditLogInsertStmt(cornucopia_async::private::Stmt); impl InfractionAuditLogInsertStmt
 { pub async fn bind<'a, C:
 GenericClient,T1:
 cornucopia_async::StringSql,T2:
 cornucopia_async::StringSql,T3:
 cornucopia_async::StringSql,T4:
 cornucopia_async::StringSql,T5:
-cornucopia_async::ArraySql<Item = T4>,>(&'a mut self, client: &'a  C,
-flags: &'a i64,joined_at: &'a Option<time::OffsetDateTime>,nick: &'a Option<T1>,user_id: &'a T2,guild_id: &'a T3,roles: &'a T5,) -> Result<u64, tokio_postgres::Error>
+cornucopia_async::StringSql,>(&'a mut self, client: &'a  C,
+guild_id: &'a T1,case_number: &'a i64,actor_id: &'a T2,action: &'a T3,old_reason: &'a Option<T4>,new_reason: &'a Option<T5>,timestamp: &'a time::OffsetDateTime,) -> Result<u64, tokio_postgres::Error>
 {
     let stmt = self.0.prepare(client).await?;
-    client.execute(stmt, &[flags,joined_at,nick,user_id,guild_id,roles,]).await
-} }impl <'a, C: GenericClient + Send + Sync, T1: cornucopia_async::StringSql,T2: cornucopia_async::StringSql,T3: cornucopia_async::StringSql,T4: cornucopia_async::StringSql,T5: cornucopia_async::ArraySql<Item = T4>,>
-cornucopia_async::Params<'a, CachedMemberUpsertParams<T1,T2,T3,T4,T5,>, std::pin::Pin<Box<dyn futures::Future<Output = Result<u64,
-tokio_postgres::Error>> + Send + 'a>>, C> for CachedMemberUpsertStmt
+    client.execute(stmt, &[guild_id,case_number,actor_id,action,old_reason,new_reason,timestamp,]).await
+} }impl <'a, C: GenericClient + Send + Sync, T1: cornucopia_async::StringSql,T2: cornucopia_async::StringSql,T3: cornucopia_async::StringSql,T4: cornucopia_async::StringSql,T5: cornucopia_async::StringSql,>
+cornucopia_async::Params<'a, InfractionAuditLogInsertParams<T1,T2,T3,T4,T5,>, std::pin::Pin<Box<dyn futures::Future<Output = Result<u64,
+tokio_postgres::Error>> + Send + 'a>>, C> for InfractionAuditLogInsertStmt
 {
     fn
     params(&'a mut self, client: &'a  C, params: &'a
-    CachedMemberUpsertParams<T1,T2,T3,T4,T5,>) -> std::pin::Pin<Box<dyn futures::Future<Output = Result<u64,
+    InfractionAuditLogInsertParams<T1,T2,T3,T4,T5,>) -> std::pin::Pin<Box<dyn futures::Future<Output = Result<u64,
     tokio_postgres::Error>> + Send + 'a>>
-    { Box::pin(self.bind(client, &params.flags,&params.joined_at,&params.nick,&params.user_id,&params.guild_id,&params.roles,)) }
-}}pub mod cached_role_select_by_guild_id
-{ use futures::{{StreamExt, TryStreamExt}};use futures; use cornucopia_async::GenericClient;#[derive( Debug, Clone, PartialEq,)] pub struct CachedRoleSelectByGuildId
-{ pub color : i64,pub flags : i32,pub guild_id : String,pub hoist : bool,pub icon : Option<String>,pub id : String,pub managed : bool,pub mentionable : bool,pub position : i32,}pub struct CachedRoleSelectByGuildIdBorrowed<'a> { pub color : i64,pub flags : i32,pub guild_id : &'a str,pub hoist : bool,pub icon : Option<&'a str>,pub id : &'a str,pub managed : bool,pub mentionable : bool,pub position : i32,}
-impl<'a> From<CachedRoleSelectByGuildIdBorrowed<'a>> for CachedRoleSelectByGuildId
-{
-    fn from(CachedRoleSelectByGuildIdBorrowed { color,flags,guild_id,hoist,icon,id,managed,mentionable,position,}: CachedRoleSelectByGuildIdBorrowed<'a>) ->
-    Self { Self { color,flags,guild_id: guild_id.into(),hoist,icon: icon.map(|v| v.into()),id: id.into(),managed,mentionable,position,} }
-}pub struct CachedRoleSelectByGuildIdQuery<'a, C: GenericClient, T, const N: usize>
+    { Box::pin(self.bind(client, &params.guild_id,&params.case_number,&params.actor_id,&params.action,&params.old_reason,&params.new_reason,&params.timestamp,)) }
+}}pub mod infraction_count_by_user_id_and_guild_id
+{ use futures::{{StreamExt, TryStreamExt}};use futures; use cornucopia_async::GenericClient;#[derive( Debug)] pub struct InfractionCountByUserIdAndGuildIdParams<T1: cornucopia_async::StringSql,T2: cornucopia_async::StringSql,> { pub user_id: T1,pub guild_id: T2,}pub struct I64Query<'a, C: GenericClient, T, const N: usize>
 {
     client: &'a  C, params:
     [&'a (dyn postgres_types::ToSql + Sync); N], stmt: &'a mut
-    cornucopia_async::private::Stmt, extractor: fn(&tokio_postgres::Row) -> CachedRoleSelectByGuildIdBorrowed,
-    mapper: fn(CachedRoleSelectByGuildIdBorrowed) -> T,
-} impl<'a, C, T:'a, const N: usize> CachedRoleSelectByGuildIdQuery<'a, C, T, N> where C:
+    cornucopia_async::private::Stmt, extractor: fn(&tokio_postgres::Row) -> i64,
+    mapper: fn(i64) -> T,
+} impl<'a, C, T:'a, const N: usize> I64Query<'a, C, T, N> where C:
 GenericClient
 {
-    pub fn map<R>(self, mapper: fn(CachedRoleSelectByGuildIdBorrowed) -> R) ->
-    CachedRoleSelectByGuildIdQuery<'a,C,R,N>
+    pub fn map<R>(self, mapper: fn(i64) -> R) ->
+    I64Query<'a,C,R,N>
     {
-        CachedRoleSelectByGuildIdQuery
+        I64Query
         {
             client: self.client, params: self.params, stmt: self.stmt,
             extractor: self.extractor, mapper,
@@ -485,45 +2616,80 @@ GenericClient
         res.map(|row| (self.mapper)((self.extractor)(&row)))) .into_stream();
         Ok(it)
     }
-}pub fn cached_role_select_by_guild_id() -> CachedRoleSelectByGuildIdStmt
-{ CachedRoleSelectByGuildIdStmt(cornucopia_async::private::Stmt::new("SELECT
-    *
+}pub fn infraction_count_by_user_id_and_guild_id() -> InfractionCountByUserIdAndGuildIdStmt
+{ InfractionCountByUserIdAndGuildIdStmt(cornucopia_async::private::Stmt::new("SELECT
+    COUNT(*)
 FROM
-    \"DiscordFrontend\".\"Nightly\".\"CachedRoles\"
+    \"DiscordFrontend\".\"Nightly\".\"Infractions\"
 WHERE
-    \"guild_id\" = $1")) } pub struct
-CachedRoleSelectByGuildIdStmt(cornucopia_async::private::Stmt); impl CachedRoleSelectByGuildIdStmt
+    \"user_id\" = $1 AND
+    \"guild_id\" = $2")) } pub struct
+InfractionCountByUserIdAndGuildIdStmt(cornucopia_async::private::Stmt); impl InfractionCountByUserIdAndGuildIdStmt
 { pub fn bind<'a, C:
 GenericClient,T1:
+cornucopia_async::StringSql,T2:
 cornucopia_async::StringSql,>(&'a mut self, client: &'a  C,
-guild_id: &'a T1,) -> CachedRoleSelectByGuildIdQuery<'a,C,
-CachedRoleSelectByGuildId, 1>
+user_id: &'a T1,guild_id: &'a T2,) -> I64Query<'a,C,
+i64, 2>
 {
-    CachedRoleSelectByGuildIdQuery
+    I64Query
     {
-        client, params: [guild_id,], stmt: &mut self.0, extractor:
-        |row| { CachedRoleSelectByGuildIdBorrowed { color: row.get(0),flags: row.get(1),guild_id: row.get(2),hoist: row.get(3),icon: row.get(4),id: row.get(5),managed: row.get(6),mentionable: row.get(7),position: row.get(8),} }, mapper: |it| { <CachedRoleSelectByGuildId>::from(it) },
+        client, params: [user_id,guild_id,], stmt: &mut self.0, extractor:
+        |row| { row.get(0) }, mapper: |it| { it },
     }
-} }}pub mod cached_role_select_by_id_and_guild_id
-{ use futures::{{StreamExt, TryStreamExt}};use futures; use cornucopia_async::GenericClient;#[derive( Debug)] pub struct CachedRoleSelectByIdAndGuildIdParams<T1: cornucopia_async::StringSql,T2: cornucopia_async::StringSql,> { pub id: T1,pub guild_id: T2,}#[derive( Debug, Clone, PartialEq,)] pub struct CachedRoleSelectByIdAndGuildId
-{ pub color : i64,pub flags : i32,pub guild_id : String,pub hoist : bool,pub icon : Option<String>,pub id : String,pub managed : bool,pub mentionable : bool,pub position : i32,}pub struct CachedRoleSelectByIdAndGuildIdBorrowed<'a> { pub color : i64,pub flags : i32,pub guild_id : &'a str,pub hoist : bool,pub icon : Option<&'a str>,pub id : &'a str,pub managed : bool,pub mentionable : bool,pub position : i32,}
-impl<'a> From<CachedRoleSelectByIdAndGuildIdBorrowed<'a>> for CachedRoleSelectByIdAndGuildId
+} }impl <'a, C: GenericClient,T1: cornucopia_async::StringSql,T2: cornucopia_async::StringSql,> cornucopia_async::Params<'a,
+InfractionCountByUserIdAndGuildIdParams<T1,T2,>, I64Query<'a, C,
+i64, 2>, C> for InfractionCountByUserIdAndGuildIdStmt
 {
-    fn from(CachedRoleSelectByIdAndGuildIdBorrowed { color,flags,guild_id,hoist,icon,id,managed,mentionable,position,}: CachedRoleSelectByIdAndGuildIdBorrowed<'a>) ->
-    Self { Self { color,flags,guild_id: guild_id.into(),hoist,icon: icon.map(|v| v.into()),id: id.into(),managed,mentionable,position,} }
-}pub struct CachedRoleSelectByIdAndGuildIdQuery<'a, C: GenericClient, T, const N: usize>
+    fn
+    params(&'a mut self, client: &'a  C, params: &'a
+    InfractionCountByUserIdAndGuildIdParams<T1,T2,>) -> I64Query<'a, C,
+    i64, 2>
+    { self.bind(client, &params.user_id,&params.guild_id,) }
+}}pub mod infraction_delete
+{ use futures::{{StreamExt, TryStreamExt}};use futures; use cornucopia_async::GenericClient;#[derive( Debug)] pub struct InfractionDeleteParams<T1: cornucopia_async::StringSql,> { pub case_number: i64,pub guild_id: T1,}pub fn infraction_delete() -> InfractionDeleteStmt
+{ InfractionDeleteStmt(cornucopia_async::private::Stmt::new("DELETE FROM
+    \"DiscordFrontend\".\"Nightly\".\"Infractions\"
+WHERE
+    \"case_number\" = $1 AND
+    \"guild_id\" = $2")) } pub struct
+InfractionDeleteStmt(cornucopia_async::private::Stmt); impl InfractionDeleteStmt
+{ pub async fn bind<'a, C:
+GenericClient,T1:
+cornucopia_async::StringSql,>(&'a mut self, client: &'a  C,
+case_number: &'a i64,guild_id: &'a T1,) -> Result<u64, tokio_postgres::Error>
+{
+    let stmt = self.0.prepare(client).await?;
+    client.execute(stmt, &[case_number,guild_id,]).await
+} }impl <'a, C: GenericClient + Send + Sync, T1: cornucopia_async::StringSql,>
+cornucopia_async::Params<'a, InfractionDeleteParams<T1,>, std::pin::Pin<Box<dyn futures::Future<Output = Result<u64,
+tokio_postgres::Error>> + Send + 'a>>, C> for InfractionDeleteStmt
+{
+    fn
+    params(&'a mut self, client: &'a  C, params: &'a
+    InfractionDeleteParams<T1,>) -> std::pin::Pin<Box<dyn futures::Future<Output = Result<u64,
+    tokio_postgres::Error>> + Send + 'a>>
+    { Box::pin(self.bind(client, &params.case_number,&params.guild_id,)) }
+}}pub mod infraction_select_by_case_number_and_guild_id
+{ use futures::{{StreamExt, TryStreamExt}};use futures; use cornucopia_async::GenericClient;#[derive( Debug)] pub struct InfractionSelectByCaseNumberAndGuildIdParams<T1: cornucopia_async::StringSql,> { pub case_number: i64,pub guild_id: T1,}#[derive( Debug, Clone, PartialEq,)] pub struct InfractionSelectByCaseNumberAndGuildId
+{ pub guild_id : String,pub case_number : i64,pub user_id : String,pub moderator_id : String,pub kind : String,pub reason : String,pub created_at : time::OffsetDateTime,pub pardoned : bool,}pub struct InfractionSelectByCaseNumberAndGuildIdBorrowed<'a> { pub guild_id : &'a str,pub case_number : i64,pub user_id : &'a str,pub moderator_id : &'a str,pub kind : &'a str,pub reason : &'a str,pub created_at : time::OffsetDateTime,pub pardoned : bool,}
+impl<'a> From<InfractionSelectByCaseNumberAndGuildIdBorrowed<'a>> for InfractionSelectByCaseNumberAndGuildId
+{
+    fn from(InfractionSelectByCaseNumberAndGuildIdBorrowed { guild_id,case_number,user_id,moderator_id,kind,reason,created_at,pardoned,}: InfractionSelectByCaseNumberAndGuildIdBorrowed<'a>) ->
+    Self { Self { guild_id: guild_id.into(),case_number,user_id: user_id.into(),moderator_id: moderator_id.into(),kind: kind.into(),reason: reason.into(),created_at,pardoned,} }
+}pub struct InfractionSelectByCaseNumberAndGuildIdQuery<'a, C: GenericClient, T, const N: usize>
 {
     client: &'a  C, params:
     [&'a (dyn postgres_types::ToSql + Sync); N], stmt: &'a mut
-    cornucopia_async::private::Stmt, extractor: fn(&tokio_postgres::Row) -> CachedRoleSelectByIdAndGuildIdBorrowed,
-    mapper: fn(CachedRoleSelectByIdAndGuildIdBorrowed) -> T,
-} impl<'a, C, T:'a, const N: usize> CachedRoleSelectByIdAndGuildIdQuery<'a, C, T, N> where C:
+    cornucopia_async::private::Stmt, extractor: fn(&tokio_postgres::Row) -> InfractionSelectByCaseNumberAndGuildIdBorrowed,
+    mapper: fn(InfractionSelectByCaseNumberAndGuildIdBorrowed) -> T,
+} impl<'a, C, T:'a, const N: usize> InfractionSelectByCaseNumberAndGuildIdQuery<'a, C, T, N> where C:
 GenericClient
 {
-    pub fn map<R>(self, mapper: fn(CachedRoleSelectByIdAndGuildIdBorrowed) -> R) ->
-    CachedRoleSelectByIdAndGuildIdQuery<'a,C,R,N>
+    pub fn map<R>(self, mapper: fn(InfractionSelectByCaseNumberAndGuildIdBorrowed) -> R) ->
+    InfractionSelectByCaseNumberAndGuildIdQuery<'a,C,R,N>
     {
-        CachedRoleSelectByIdAndGuildIdQuery
+        InfractionSelectByCaseNumberAndGuildIdQuery
         {
             client: self.client, params: self.params, stmt: self.stmt,
             extractor: self.extractor, mapper,
@@ -550,88 +2716,55 @@ GenericClient
         res.map(|row| (self.mapper)((self.extractor)(&row)))) .into_stream();
         Ok(it)
     }
-}pub fn cached_role_select_by_id_and_guild_id() -> CachedRoleSelectByIdAndGuildIdStmt
-{ CachedRoleSelectByIdAndGuildIdStmt(cornucopia_async::private::Stmt::new("SELECT
+}pub fn infraction_select_by_case_number_and_guild_id() -> InfractionSelectByCaseNumberAndGuildIdStmt
+{ InfractionSelectByCaseNumberAndGuildIdStmt(cornucopia_async::private::Stmt::new("SELECT
     *
 FROM
-    \"DiscordFrontend\".\"Nightly\".\"CachedRoles\"
+    \"DiscordFrontend\".\"Nightly\".\"Infractions\"
 WHERE
-    \"id\" = $1 AND
+    \"case_number\" = $1 AND
     \"guild_id\" = $2")) } pub struct
-CachedRoleSelectByIdAndGuildIdStmt(cornucopia_async::private::Stmt); impl CachedRoleSelectByIdAndGuildIdStmt
+InfractionSelectByCaseNumberAndGuildIdStmt(cornucopia_async::private::Stmt); impl InfractionSelectByCaseNumberAndGuildIdStmt
 { pub fn bind<'a, C:
 GenericClient,T1:
-cornucopia_async::StringSql,T2:
 cornucopia_async::StringSql,>(&'a mut self, client: &'a  C,
-id: &'a T1,guild_id: &'a T2,) -> CachedRoleSelectByIdAndGuildIdQuery<'a,C,
-CachedRoleSelectByIdAndGuildId, 2>
+case_number: &'a i64,guild_id: &'a T1,) -> InfractionSelectByCaseNumberAndGuildIdQuery<'a,C,
+InfractionSelectByCaseNumberAndGuildId, 2>
 {
-    CachedRoleSelectByIdAndGuildIdQuery
+    InfractionSelectByCaseNumberAndGuildIdQuery
     {
-        client, params: [id,guild_id,], stmt: &mut self.0, extractor:
-        |row| { CachedRoleSelectByIdAndGuildIdBorrowed { color: row.get(0),flags: row.get(1),guild_id: row.get(2),hoist: row.get(3),icon: row.get(4),id: row.get(5),managed: row.get(6),mentionable: row.get(7),position: row.get(8),} }, mapper: |it| { <CachedRoleSelectByIdAndGuildId>::from(it) },
+        client, params: [case_number,guild_id,], stmt: &mut self.0, extractor:
+        |row| { InfractionSelectByCaseNumberAndGuildIdBorrowed { guild_id: row.get(0),case_number: row.get(1),user_id: row.get(2),moderator_id: row.get(3),kind: row.get(4),reason: row.get(5),created_at: row.get(6),pardoned: row.get(7),} }, mapper: |it| { <InfractionSelectByCaseNumberAndGuildId>::from(it) },
     }
-} }impl <'a, C: GenericClient,T1: cornucopia_async::StringSql,T2: cornucopia_async::StringSql,> cornucopia_async::Params<'a,
-CachedRoleSelectByIdAndGuildIdParams<T1,T2,>, CachedRoleSelectByIdAndGuildIdQuery<'a, C,
-CachedRoleSelectByIdAndGuildId, 2>, C> for CachedRoleSelectByIdAndGuildIdStmt
-{
-    fn
-    params(&'a mut self, client: &'a  C, params: &'a
-    CachedRoleSelectByIdAndGuildIdParams<T1,T2,>) -> CachedRoleSelectByIdAndGuildIdQuery<'a, C,
-    CachedRoleSelectByIdAndGuildId, 2>
-    { self.bind(client, &params.id,&params.guild_id,) }
-}}pub mod cached_role_upsert
-{ use futures::{{StreamExt, TryStreamExt}};use futures; use cornucopia_async::GenericClient;#[derive( Debug)] pub struct CachedRoleUpsertParams<T1: cornucopia_async::StringSql,T2: cornucopia_async::StringSql,T3: cornucopia_async::StringSql,> { pub color: i64,pub icon: Option<T1>,pub id: T2,pub guild_id: T3,pub flags: i32,pub hoist: bool,pub managed: bool,pub mentionable: bool,pub position: i32,}pub fn cached_role_upsert() -> CachedRoleUpsertStmt
-{ CachedRoleUpsertStmt(cornucopia_async::private::Stmt::new("INSERT INTO \"DiscordFrontend\".\"Nightly\".\"CachedRoles\" (\"color\", \"icon\", \"id\", \"guild_id\", \"flags\", \"hoist\", \"managed\", \"mentionable\", \"position\")
-VALUES ($1, $2, $3, $4, $5, $6, $7, $8, $9)
-ON CONFLICT (\"id\", \"guild_id\") DO UPDATE
-    SET
-        \"color\" = $1,
-        \"icon\" = $2,
-        \"flags\" = $5,
-        \"hoist\" = $6,
-        \"managed\" = $7,
-        \"mentionable\" = $8,
-        \"position\" = $9")) } pub struct
-CachedRoleUpsertStmt(cornucopia_async::private::Stmt); impl CachedRoleUpsertStmt
-{ pub async fn bind<'a, C:
-GenericClient,T1:
-cornucopia_async::StringSql,T2:
-cornucopia_async::StringSql,T3:
-cornucopia_async::StringSql,>(&'a mut self, client: &'a  C,
-color: &'a i64,icon: &'a Option<T1>,id: &'a T2,guild_id: &'a T3,flags: &'a i32,hoist: &'a bool,managed: &'a bool,mentionable: &'a bool,position: &'a i32,) -> Result<u64, tokio_postgres::Error>
-{
-    let stmt = self.0.prepare(client).await?;
-    client.execute(stmt, &[color,icon,id,guild_id,flags,hoist,managed,mentionable,position,]).await
-} }impl <'a, C: GenericClient + Send + Sync, T1: cornucopia_async::StringSql,T2: cornucopia_async::StringSql,T3: cornucopia_async::StringSql,>
-cornucopia_async::Params<'a, CachedRoleUpsertParams<T1,T2,T3,>, std::pin::Pin<Box<dyn futures::Future<Output = Result<u64,
-tokio_postgres::Error>> + Send + 'a>>, C> for CachedRoleUpsertStmt
+} }impl <'a, C: GenericClient,T1: cornucopia_async::StringSql,> cornucopia_async::Params<'a,
+InfractionSelectByCaseNumberAndGuildIdParams<T1,>, InfractionSelectByCaseNumberAndGuildIdQuery<'a, C,
+InfractionSelectByCaseNumberAndGuildId, 2>, C> for InfractionSelectByCaseNumberAndGuildIdStmt
 {
     fn
     params(&'a mut self, client: &'a  C, params: &'a
-    CachedRoleUpsertParams<T1,T2,T3,>) -> std::pin::Pin<Box<dyn futures::Future<Output = Result<u64,
-    tokio_postgres::Error>> + Send + 'a>>
-    { Box::pin(self.bind(client, &params.color,&params.icon,&params.id,&params.guild_id,&params.flags,&params.hoist,&params.managed,&params.mentionable,&params.position,)) }
-}}pub mod cached_user_select_by_id
-{ use futures::{{StreamExt, TryStreamExt}};use futures; use cornucopia_async::GenericClient;#[derive( Debug, Clone, PartialEq,)] pub struct CachedUserSelectById
-{ pub avatar : Option<String>,pub bot : bool,pub id : String,pub discriminator : String,pub global_name : Option<String>,pub name : String,}pub struct CachedUserSelectByIdBorrowed<'a> { pub avatar : Option<&'a str>,pub bot : bool,pub id : &'a str,pub discriminator : &'a str,pub global_name : Option<&'a str>,pub name : &'a str,}
-impl<'a> From<CachedUserSelectByIdBorrowed<'a>> for CachedUserSelectById
+    InfractionSelectByCaseNumberAndGuildIdParams<T1,>) -> InfractionSelectByCaseNumberAndGuildIdQuery<'a, C,
+    InfractionSelectByCaseNumberAndGuildId, 2>
+    { self.bind(client, &params.case_number,&params.guild_id,) }
+}}pub mod infraction_select_by_user_id_and_guild_id
+{ use futures::{{StreamExt, TryStreamExt}};use futures; use cornucopia_async::GenericClient;#[derive( Debug)] pub struct InfractionSelectByUserIdAndGuildIdParams<T1: cornucopia_async::StringSql,T2: cornucopia_async::StringSql,> { pub user_id: T1,pub guild_id: T2,pub limit: i64,pub offset: i64,}#[derive( Debug, Clone, PartialEq,)] pub struct InfractionSelectByUserIdAndGuildId
+{ pub guild_id : String,pub case_number : i64,pub user_id : String,pub moderator_id : String,pub kind : String,pub reason : String,pub created_at : time::OffsetDateTime,pub pardoned : bool,}pub struct InfractionSelectByUserIdAndGuildIdBorrowed<'a> { pub guild_id : &'a str,pub case_number : i64,pub user_id : &'a str,pub moderator_id : &'a str,pub kind : &'a str,pub reason : &'a str,pub created_at : time::OffsetDateTime,pub pardoned : bool,}
+impl<'a> From<InfractionSelectByUserIdAndGuildIdBorrowed<'a>> for InfractionSelectByUserIdAndGuildId
 {
-    fn from(CachedUserSelectByIdBorrowed { avatar,bot,id,discriminator,global_name,name,}: CachedUserSelectByIdBorrowed<'a>) ->
-    Self { Self { avatar: avatar.map(|v| v.into()),bot,id: id.into(),discriminator: discriminator.into(),global_name: global_name.map(|v| v.into()),name: name.into(),} }
-}pub struct CachedUserSelectByIdQuery<'a, C: GenericClient, T, const N: usize>
+    fn from(InfractionSelectByUserIdAndGuildIdBorrowed { guild_id,case_number,user_id,moderator_id,kind,reason,created_at,pardoned,}: InfractionSelectByUserIdAndGuildIdBorrowed<'a>) ->
+    Self { Self { guild_id: guild_id.into(),case_number,user_id: user_id.into(),moderator_id: moderator_id.into(),kind: kind.into(),reason: reason.into(),created_at,pardoned,} }
+}pub struct InfractionSelectByUserIdAndGuildIdQuery<'a, C: GenericClient, T, const N: usize>
 {
     client: &'a  C, params:
     [&'a (dyn postgres_types::ToSql + Sync); N], stmt: &'a mut
-    cornucopia_async::private::Stmt, extractor: fn(&tokio_postgres::Row) -> CachedUserSelectByIdBorrowed,
-    mapper: fn(CachedUserSelectByIdBorrowed) -> T,
-} impl<'a, C, T:'a, const N: usize> CachedUserSelectByIdQuery<'a, C, T, N> where C:
+    cornucopia_async::private::Stmt, extractor: fn(&tokio_postgres::Row) -> InfractionSelectByUserIdAndGuildIdBorrowed,
+    mapper: fn(InfractionSelectByUserIdAndGuildIdBorrowed) -> T,
+} impl<'a, C, T:'a, const N: usize> InfractionSelectByUserIdAndGuildIdQuery<'a, C, T, N> where C:
 GenericClient
 {
-    pub fn map<R>(self, mapper: fn(CachedUserSelectByIdBorrowed) -> R) ->
-    CachedUserSelectByIdQuery<'a,C,R,N>
+    pub fn map<R>(self, mapper: fn(InfractionSelectByUserIdAndGuildIdBorrowed) -> R) ->
+    InfractionSelectByUserIdAndGuildIdQuery<'a,C,R,N>
     {
-        CachedUserSelectByIdQuery
+        InfractionSelectByUserIdAndGuildIdQuery
         {
             client: self.client, params: self.params, stmt: self.stmt,
             extractor: self.extractor, mapper,
@@ -658,55 +2791,91 @@ GenericClient
         res.map(|row| (self.mapper)((self.extractor)(&row)))) .into_stream();
         Ok(it)
     }
-}pub fn cached_user_select_by_id() -> CachedUserSelectByIdStmt
-{ CachedUserSelectByIdStmt(cornucopia_async::private::Stmt::new("SELECT
+}pub fn infraction_select_by_user_id_and_guild_id() -> InfractionSelectByUserIdAndGuildIdStmt
+{ InfractionSelectByUserIdAndGuildIdStmt(cornucopia_async::private::Stmt::new("SELECT
     *
 FROM
-    \"DiscordFrontend\".\"Nightly\".\"CachedUsers\"
+    \"DiscordFrontend\".\"Nightly\".\"Infractions\"
 WHERE
-    \"id\" = $1")) } pub struct
-CachedUserSelectByIdStmt(cornucopia_async::private::Stmt); impl CachedUserSelectByIdStmt
+    \"user_id\" = $1 AND
+    \"guild_id\" = $2
+ORDER BY
+    \"case_number\" DESC
+LIMIT $3
+OFFSET $4")) } pub struct
+InfractionSelectByUserIdAndGuildIdStmt(cornucopia_async::private::Stmt); impl InfractionSelectByUserIdAndGuildIdStmt
 { pub fn bind<'a, C:
 GenericClient,T1:
+cornucopia_async::StringSql,T2:
 cornucopia_async::StringSql,>(&'a mut self, client: &'a  C,
-id: &'a T1,) -> CachedUserSelectByIdQuery<'a,C,
-CachedUserSelectById, 1>
+user_id: &'a T1,guild_id: &'a T2,limit: &'a i64,offset: &'a i64,) -> InfractionSelectByUserIdAndGuildIdQuery<'a,C,
+InfractionSelectByUserIdAndGuildId, 4>
 {
-    CachedUserSelectByIdQuery
+    InfractionSelectByUserIdAndGuildIdQuery
     {
-        client, params: [id,], stmt: &mut self.0, extractor:
-        |row| { CachedUserSelectByIdBorrowed { avatar: row.get(0),bot: row.get(1),id: row.get(2),discriminator: row.get(3),global_name: row.get(4),name: row.get(5),} }, mapper: |it| { <CachedUserSelectById>::from(it) },
+        client, params: [user_id,guild_id,limit,offset,], stmt: &mut self.0, extractor:
+        |row| { InfractionSelectByUserIdAndGuildIdBorrowed { guild_id: row.get(0),case_number: row.get(1),user_id: row.get(2),moderator_id: row.get(3),kind: row.get(4),reason: row.get(5),created_at: row.get(6),pardoned: row.get(7),} }, mapper: |it| { <InfractionSelectByUserIdAndGuildId>::from(it) },
     }
-} }}pub mod cached_user_upsert
-{ use futures::{{StreamExt, TryStreamExt}};use futures; use cornucopia_async::GenericClient;#[derive( Debug)] pub struct CachedUserUpsertParams<T1: cornucopia_async::StringSql,T2: cornucopia_async::StringSql,T3: cornucopia_async::StringSql,T4: cornucopia_async::StringSql,T5: cornucopia_async::StringSql,> { pub avatar: Option<T1>,pub id: T2,pub bot: bool,pub name: T3,pub discriminator: T4,pub global_name: Option<T5>,}pub fn cached_user_upsert() -> CachedUserUpsertStmt
-{ CachedUserUpsertStmt(cornucopia_async::private::Stmt::new("INSERT INTO \"DiscordFrontend\".\"Nightly\".\"CachedUsers\" (\"avatar\", \"id\", \"bot\", \"name\", \"discriminator\", \"global_name\")
-VALUES ($1, $2, $3, $4, $5, $6)
-ON CONFLICT (\"id\") DO UPDATE
-    SET
-        \"avatar\" = $1,
-        \"bot\" = $3,
-        \"name\" = $4,
-        \"discriminator\" = $5,
-        \"global_name\" = $6")) } pub struct
-CachedUserUpsertStmt(cornucopia_async::private::Stmt); impl CachedUserUpsertStmt
+} }impl <'a, C: GenericClient,T1: cornucopia_async::StringSql,T2: cornucopia_async::StringSql,> cornucopia_async::Params<'a,
+InfractionSelectByUserIdAndGuildIdParams<T1,T2,>, InfractionSelectByUserIdAndGuildIdQuery<'a, C,
+InfractionSelectByUserIdAndGuildId, 4>, C> for InfractionSelectByUserIdAndGuildIdStmt
+{
+    fn
+    params(&'a mut self, client: &'a  C, params: &'a
+    InfractionSelectByUserIdAndGuildIdParams<T1,T2,>) -> InfractionSelectByUserIdAndGuildIdQuery<'a, C,
+    InfractionSelectByUserIdAndGuildId, 4>
+    { self.bind(client, &params.user_id,&params.guild_id,&params.limit,&params.offset,) }
+}}pub mod infraction_update_pardoned
+{ use futures::{{StreamExt, TryStreamExt}};use futures; use cornucopia_async::GenericClient;#[derive( Debug)] pub struct InfractionUpdatePardonedParams<T1: cornucopia_async::StringSql,> { pub pardoned: bool,pub case_number: i64,pub guild_id: T1,}pub fn infraction_update_pardoned() -> InfractionUpdatePardonedStmt
+{ InfractionUpdatePardonedStmt(cornucopia_async::private::Stmt::new("UPDATE
+    \"DiscordFrontend\".\"Nightly\".\"Infractions\"
+SET
+    \"pardoned\" = $1
+WHERE
+    \"case_number\" = $2 AND
+    \"guild_id\" = $3")) } pub struct
+InfractionUpdatePardonedStmt(cornucopia_async::private::Stmt); impl InfractionUpdatePardonedStmt
+{ pub async fn bind<'a, C:
+GenericClient,T1:
+cornucopia_async::StringSql,>(&'a mut self, client: &'a  C,
+pardoned: &'a bool,case_number: &'a i64,guild_id: &'a T1,) -> Result<u64, tokio_postgres::Error>
+{
+    let stmt = self.0.prepare(client).await?;
+    client.execute(stmt, &[pardoned,case_number,guild_id,]).await
+} }impl <'a, C: GenericClient + Send + Sync, T1: cornucopia_async::StringSql,>
+cornucopia_async::Params<'a, InfractionUpdatePardonedParams<T1,>, std::pin::Pin<Box<dyn futures::Future<Output = Result<u64,
+tokio_postgres::Error>> + Send + 'a>>, C> for InfractionUpdatePardonedStmt
+{
+    fn
+    params(&'a mut self, client: &'a  C, params: &'a
+    InfractionUpdatePardonedParams<T1,>) -> std::pin::Pin<Box<dyn futures::Future<Output = Result<u64,
+    tokio_postgres::Error>> + Send + 'a>>
+    { Box::pin(self.bind(client, &params.pardoned,&params.case_number,&params.guild_id,)) }
+}}pub mod infraction_update_reason
+{ use futures::{{StreamExt, TryStreamExt}};use futures; use cornucopia_async::GenericClient;#[derive( Debug)] pub struct InfractionUpdateReasonParams<T1: cornucopia_async::StringSql,T2: cornucopia_async::StringSql,> { pub reason: T1,pub case_number: i64,pub guild_id: T2,}pub fn infraction_update_reason() -> InfractionUpdateReasonStmt
+{ InfractionUpdateReasonStmt(cornucopia_async::private::Stmt::new("UPDATE
+    \"DiscordFrontend\".\"Nightly\".\"Infractions\"
+SET
+    \"reason\" = $1
+WHERE
+    \"case_number\" = $2 AND
+    \"guild_id\" = $3")) } pub struct
+InfractionUpdateReasonStmt(cornucopia_async::private::Stmt); impl InfractionUpdateReasonStmt
 { pub async fn bind<'a, C:
 GenericClient,T1:
 cornucopia_async::StringSql,T2:
-cornucopia_async::StringSql,T3:
-cornucopia_async::StringSql,T4:
-cornucopia_async::StringSql,T5:
 cornucopia_async::StringSql,>(&'a mut self, client: &'a  C,
-avatar: &'a Option<T1>,id: &'a T2,bot: &'a bool,name: &'a T3,discriminator: &'a T4,global_name: &'a Option<T5>,) -> Result<u64, tokio_postgres::Error>
+reason: &'a T1,case_number: &'a i64,guild_id: &'a T2,) -> Result<u64, tokio_postgres::Error>
 {
     let stmt = self.0.prepare(client).await?;
-    client.execute(stmt, &[avatar,id,bot,name,discriminator,global_name,]).await
-} }impl <'a, C: GenericClient + Send + Sync, T1: cornucopia_async::StringSql,T2: cornucopia_async::StringSql,T3: cornucopia_async::StringSql,T4: cornucopia_async::StringSql,T5: cornucopia_async::StringSql,>
-cornucopia_async::Params<'a, CachedUserUpsertParams<T1,T2,T3,T4,T5,>, std::pin::Pin<Box<dyn futures::Future<Output = Result<u64,
-tokio_postgres::Error>> + Send + 'a>>, C> for CachedUserUpsertStmt
+    client.execute(stmt, &[reason,case_number,guild_id,]).await
+} }impl <'a, C: GenericClient + Send + Sync, T1: cornucopia_async::StringSql,T2: cornucopia_async::StringSql,>
+cornucopia_async::Params<'a, InfractionUpdateReasonParams<T1,T2,>, std::pin::Pin<Box<dyn futures::Future<Output = Result<u64,
+tokio_postgres::Error>> + Send + 'a>>, C> for InfractionUpdateReasonStmt
 {
     fn
     params(&'a mut self, client: &'a  C, params: &'a
-    CachedUserUpsertParams<T1,T2,T3,T4,T5,>) -> std::pin::Pin<Box<dyn futures::Future<Output = Result<u64,
+    InfractionUpdateReasonParams<T1,T2,>) -> std::pin::Pin<Box<dyn futures::Future<Output = Result<u64,
     tokio_postgres::Error>> + Send + 'a>>
-    { Box::pin(self.bind(client, &params.avatar,&params.id,&params.bot,&params.name,&params.discriminator,&params.global_name,)) }
+    { Box::pin(self.bind(client, &params.reason,&params.case_number,&params.guild_id,)) }
 }}}
\ No newline at end of file
@@ -0,0 +1,8 @@
+--! appearance_select_by_guild_id : (appearance_nickname, appearance_colour)
+SELECT
+    "appearance_nickname",
+    "appearance_colour"
+FROM
+    "Nightly"."GuildConfigurations"
+WHERE
+    "guild_id" = :guild_id;
@@ -0,0 +1,3 @@
+--! configuration_diagnostic_insert (guild_id, source, message, timestamp)
+INSERT INTO "Nightly"."GuildConfigurationDiagnostics" ("guild_id", "source", "message", "timestamp")
+VALUES (:guild_id, :source, :message, :timestamp);
@@ -0,0 +1,8 @@
+--! dashboard_admins_and_enabled_plugins_select_by_guild_id : (dashboard_admins, enabled_plugins)
+SELECT
+    "dashboard_admins",
+    "enabled_plugins"
+FROM
+    "Nightly"."GuildConfigurations"
+WHERE
+    "guild_id" = :guild_id;
@@ -0,0 +1,8 @@
+--! enabled_plugins_append
+UPDATE
+    "Nightly"."GuildConfigurations"
+SET
+    "enabled_plugins" = array_append("enabled_plugins", :plugin)
+WHERE
+    "guild_id" = :guild_id AND
+    NOT "enabled_plugins" @> array[ :plugin ];
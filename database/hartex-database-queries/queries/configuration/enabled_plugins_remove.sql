@@ -0,0 +1,8 @@
+--! enabled_plugins_remove
+UPDATE
+    "Nightly"."GuildConfigurations"
+SET
+    "enabled_plugins" = array_remove("enabled_plugins", :plugin)
+WHERE
+    "guild_id" = :guild_id AND
+    "enabled_plugins" @> array[ :plugin ];
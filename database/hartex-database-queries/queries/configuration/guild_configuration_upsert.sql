@@ -0,0 +1,11 @@
+--! guild_configuration_upsert (guild_id, dashboard_admins, dashboard_editors, dashboard_viewers, appearance_nickname, appearance_colour, enabled_plugins)
+INSERT INTO "Nightly"."GuildConfigurations" ("guild_id", "dashboard_admins", "dashboard_editors", "dashboard_viewers", "appearance_nickname", "appearance_colour", "enabled_plugins")
+VALUES (:guild_id, :dashboard_admins, :dashboard_editors, :dashboard_viewers, :appearance_nickname, :appearance_colour, :enabled_plugins)
+ON CONFLICT ("guild_id") DO UPDATE
+    SET
+        "dashboard_admins" = :dashboard_admins,
+        "dashboard_editors" = :dashboard_editors,
+        "dashboard_viewers" = :dashboard_viewers,
+        "appearance_nickname" = :appearance_nickname,
+        "appearance_colour" = :appearance_colour,
+        "enabled_plugins" = :enabled_plugins;
@@ -0,0 +1,3 @@
+--! modlog_logger_insert (guild_id, channel_id, events, format)
+INSERT INTO "Nightly"."GuildModlogLoggers" ("guild_id", "channel_id", "events", "format")
+VALUES (:guild_id, :channel_id, :events, :format);
@@ -0,0 +1,5 @@
+--! modlog_loggers_delete_by_guild_id (guild_id)
+DELETE FROM
+    "Nightly"."GuildModlogLoggers"
+WHERE
+    "guild_id" = :guild_id;
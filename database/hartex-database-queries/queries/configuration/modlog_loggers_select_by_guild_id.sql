@@ -0,0 +1,9 @@
+--! modlog_loggers_select_by_guild_id : (channel_id, events, format)
+SELECT
+    "channel_id",
+    "events",
+    "format"
+FROM
+    "Nightly"."GuildModlogLoggers"
+WHERE
+    "guild_id" = :guild_id;
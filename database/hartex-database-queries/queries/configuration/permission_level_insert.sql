@@ -0,0 +1,3 @@
+--! permission_level_insert (guild_id, kind, target_id, level)
+INSERT INTO "Nightly"."GuildPermissionLevels" ("guild_id", "kind", "target_id", "level")
+VALUES (:guild_id, :kind, :target_id, :level);
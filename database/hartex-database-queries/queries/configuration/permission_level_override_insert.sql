@@ -0,0 +1,3 @@
+--! permission_level_override_insert (guild_id, kind, name, level)
+INSERT INTO "Nightly"."GuildPermissionLevelOverrides" ("guild_id", "kind", "name", "level")
+VALUES (:guild_id, :kind, :name, :level);
@@ -0,0 +1,11 @@
+--! permission_level_override_select_by_guild_id_and_names : (kind, level)
+SELECT
+    "kind",
+    "level"
+FROM
+    "Nightly"."GuildPermissionLevelOverrides"
+WHERE
+    "guild_id" = :guild_id AND (
+        ("kind" = 'plugin' AND "name" = :plugin) OR
+        ("kind" = 'command' AND "name" = :command)
+    );
@@ -0,0 +1,5 @@
+--! permission_level_overrides_delete_by_guild_id (guild_id)
+DELETE FROM
+    "Nightly"."GuildPermissionLevelOverrides"
+WHERE
+    "guild_id" = :guild_id;
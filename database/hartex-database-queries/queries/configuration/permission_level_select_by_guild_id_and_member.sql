@@ -0,0 +1,10 @@
+--! permission_level_select_by_guild_id_and_member
+SELECT
+    COALESCE(MAX("level"), 0::SMALLINT)
+FROM
+    "Nightly"."GuildPermissionLevels"
+WHERE
+    "guild_id" = :guild_id AND (
+        ("kind" = 'role' AND "target_id" = ANY(:role_ids)) OR
+        ("kind" = 'user' AND "target_id" = :user_id)
+    );
@@ -0,0 +1,5 @@
+--! permission_levels_delete_by_guild_id (guild_id)
+DELETE FROM
+    "Nightly"."GuildPermissionLevels"
+WHERE
+    "guild_id" = :guild_id;
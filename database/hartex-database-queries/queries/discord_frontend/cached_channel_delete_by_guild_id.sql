@@ -0,0 +1,7 @@
+--! cached_channel_delete_by_guild_id (guild_id)
+DELETE FROM
+    "DiscordFrontend"."Nightly"."CachedChannels"
+WHERE
+    "guild_id" = :guild_id
+RETURNING
+    "id";
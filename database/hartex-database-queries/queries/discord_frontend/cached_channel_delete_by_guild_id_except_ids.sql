@@ -0,0 +1,8 @@
+--! cached_channel_delete_by_guild_id_except_ids (guild_id, ids)
+DELETE FROM
+    "DiscordFrontend"."Nightly"."CachedChannels"
+WHERE
+    "guild_id" = :guild_id AND
+    NOT ("id" = ANY(:ids))
+RETURNING
+    "id";
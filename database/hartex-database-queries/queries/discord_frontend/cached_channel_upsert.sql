@@ -0,0 +1,14 @@
+--! cached_channel_upsert (id, guild_id, kind, name?, nsfw?, parent_id?, permission_overwrites?, position?, rate_limit_per_user?, topic?)
+INSERT INTO "DiscordFrontend"."Nightly"."CachedChannels" ("id", "guild_id", "kind", "name", "nsfw", "parent_id", "permission_overwrites", "position", "rate_limit_per_user", "topic")
+VALUES (:id, :guild_id, :kind, :name, :nsfw, :parent_id, :permission_overwrites, :position, :rate_limit_per_user, :topic)
+ON CONFLICT ("id") DO UPDATE
+    SET
+        "guild_id" = :guild_id,
+        "kind" = :kind,
+        "name" = :name,
+        "nsfw" = :nsfw,
+        "parent_id" = :parent_id,
+        "permission_overwrites" = :permission_overwrites,
+        "position" = :position,
+        "rate_limit_per_user" = :rate_limit_per_user,
+        "topic" = :topic;
@@ -0,0 +1,5 @@
+--! cached_guild_select_ids
+SELECT
+    "id"
+FROM
+    "DiscordFrontend"."Nightly"."CachedGuilds";
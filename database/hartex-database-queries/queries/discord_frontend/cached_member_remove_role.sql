@@ -0,0 +1,10 @@
+--! cached_member_remove_role (role_id, guild_id)
+UPDATE
+    "DiscordFrontend"."Nightly"."CachedMembers"
+SET
+    "roles" = ARRAY_REMOVE("roles", :role_id)
+WHERE
+    "guild_id" = :guild_id AND
+    :role_id = ANY("roles")
+RETURNING
+    "user_id";
@@ -1,4 +1,4 @@
---! cached_member_select_by_guild_id (guild_id) : (flags, joined_at?, nick?, user_id, guild_id, roles)
+--! cached_member_select_by_guild_id (guild_id) : (flags, joined_at?, nick?, user_id, guild_id, roles, avatar?)
 SELECT
     *
 FROM
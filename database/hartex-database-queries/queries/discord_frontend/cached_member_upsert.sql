@@ -1,8 +1,9 @@
---! cached_member_upsert (flags, joined_at?, nick?, user_id, guild_id, roles)
-INSERT INTO "DiscordFrontend"."Nightly"."CachedMembers" ("flags", "joined_at", "nick", "user_id", "guild_id", "roles")
-VALUES (:flags, :joined_at, :nick, :user_id, :guild_id, :roles)
+--! cached_member_upsert (avatar?, flags, joined_at?, nick?, user_id, guild_id, roles)
+INSERT INTO "DiscordFrontend"."Nightly"."CachedMembers" ("avatar", "flags", "joined_at", "nick", "user_id", "guild_id", "roles")
+VALUES (:avatar, :flags, :joined_at, :nick, :user_id, :guild_id, :roles)
 ON CONFLICT ("user_id", "guild_id") DO UPDATE
     SET
+        "avatar" = :avatar,
         "flags" = :flags,
         "joined_at" = :joined_at,
         "nick" = :nick,
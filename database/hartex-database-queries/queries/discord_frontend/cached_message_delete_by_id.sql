@@ -0,0 +1,5 @@
+--! cached_message_delete_by_id (id)
+DELETE FROM
+    "DiscordFrontend"."Nightly"."CachedMessages"
+WHERE
+    "id" = :id;
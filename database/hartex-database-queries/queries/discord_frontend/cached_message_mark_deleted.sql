@@ -0,0 +1,7 @@
+--! cached_message_mark_deleted (ids)
+UPDATE
+    "DiscordFrontend"."Nightly"."CachedMessages"
+SET
+    "deleted" = TRUE
+WHERE
+    "id" = ANY(:ids);
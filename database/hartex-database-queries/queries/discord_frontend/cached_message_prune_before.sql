@@ -0,0 +1,5 @@
+--! cached_message_prune_before (before)
+DELETE FROM
+    "DiscordFrontend"."Nightly"."CachedMessages"
+WHERE
+    "timestamp" < :before;
@@ -0,0 +1,16 @@
+--! cached_message_prune_by_channel_id (channel_id, limit)
+DELETE FROM
+    "DiscordFrontend"."Nightly"."CachedMessages"
+WHERE
+    "channel_id" = :channel_id AND
+    "id" NOT IN (
+        SELECT
+            "id"
+        FROM
+            "DiscordFrontend"."Nightly"."CachedMessages"
+        WHERE
+            "channel_id" = :channel_id
+        ORDER BY
+            "timestamp" DESC
+        LIMIT :limit
+    );
@@ -0,0 +1,7 @@
+--! cached_message_select_by_id : (id, channel_id, guild_id?, author_id, content, attachments, embeds, timestamp, edited_timestamp?, deleted)
+SELECT
+    *
+FROM
+    "DiscordFrontend"."Nightly"."CachedMessages"
+WHERE
+    "id" = :id;
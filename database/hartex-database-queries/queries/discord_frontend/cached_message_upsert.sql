@@ -0,0 +1,10 @@
+--! cached_message_upsert (id, channel_id, guild_id?, author_id, content, attachments, embeds, timestamp, edited_timestamp?, deleted)
+INSERT INTO "DiscordFrontend"."Nightly"."CachedMessages" ("id", "channel_id", "guild_id", "author_id", "content", "attachments", "embeds", "timestamp", "edited_timestamp", "deleted")
+VALUES (:id, :channel_id, :guild_id, :author_id, :content, :attachments, :embeds, :timestamp, :edited_timestamp, :deleted)
+ON CONFLICT ("id") DO UPDATE
+    SET
+        "content" = :content,
+        "attachments" = :attachments,
+        "embeds" = :embeds,
+        "edited_timestamp" = :edited_timestamp,
+        "deleted" = :deleted;
@@ -0,0 +1,7 @@
+--! cached_thread_delete_by_guild_id (guild_id)
+DELETE FROM
+    "DiscordFrontend"."Nightly"."CachedThreads"
+WHERE
+    "guild_id" = :guild_id
+RETURNING
+    "id";
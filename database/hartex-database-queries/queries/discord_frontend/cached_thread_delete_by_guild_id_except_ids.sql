@@ -0,0 +1,9 @@
+--! cached_thread_delete_by_guild_id_except_ids (guild_id, parent_ids?, ids)
+DELETE FROM
+    "DiscordFrontend"."Nightly"."CachedThreads"
+WHERE
+    "guild_id" = :guild_id AND
+    (:parent_ids IS NULL OR "parent_id" = ANY(:parent_ids)) AND
+    NOT ("id" = ANY(:ids))
+RETURNING
+    "id";
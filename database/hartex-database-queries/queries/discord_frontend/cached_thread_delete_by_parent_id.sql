@@ -0,0 +1,7 @@
+--! cached_thread_delete_by_parent_id (parent_id)
+DELETE FROM
+    "DiscordFrontend"."Nightly"."CachedThreads"
+WHERE
+    "parent_id" = :parent_id
+RETURNING
+    "id";
@@ -0,0 +1,7 @@
+--! cached_thread_select_by_guild_id : (id, guild_id, kind, locked, name?, owner_id?, parent_id?, rate_limit_per_user?)
+SELECT
+    *
+FROM
+    "DiscordFrontend"."Nightly"."CachedThreads"
+WHERE
+    "guild_id" = :guild_id;
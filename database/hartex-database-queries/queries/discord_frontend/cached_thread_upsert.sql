@@ -0,0 +1,12 @@
+--! cached_thread_upsert (id, guild_id, kind, locked, name?, owner_id?, parent_id?, rate_limit_per_user?)
+INSERT INTO "DiscordFrontend"."Nightly"."CachedThreads" ("id", "guild_id", "kind", "locked", "name", "owner_id", "parent_id", "rate_limit_per_user")
+VALUES (:id, :guild_id, :kind, :locked, :name, :owner_id, :parent_id, :rate_limit_per_user)
+ON CONFLICT ("id") DO UPDATE
+    SET
+        "guild_id" = :guild_id,
+        "kind" = :kind,
+        "locked" = :locked,
+        "name" = :name,
+        "owner_id" = :owner_id,
+        "parent_id" = :parent_id,
+        "rate_limit_per_user" = :rate_limit_per_user;
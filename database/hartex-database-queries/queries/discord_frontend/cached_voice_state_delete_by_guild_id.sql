@@ -0,0 +1,7 @@
+--! cached_voice_state_delete_by_guild_id (guild_id)
+DELETE FROM
+    "DiscordFrontend"."Nightly"."CachedVoiceStates"
+WHERE
+    "guild_id" = :guild_id
+RETURNING
+    "user_id";
@@ -0,0 +1,8 @@
+--! cached_voice_state_delete_by_guild_id_except_user_ids (guild_id, user_ids)
+DELETE FROM
+    "DiscordFrontend"."Nightly"."CachedVoiceStates"
+WHERE
+    "guild_id" = :guild_id AND
+    NOT ("user_id" = ANY(:user_ids))
+RETURNING
+    "user_id";
@@ -0,0 +1,6 @@
+--! cached_voice_state_delete_by_user_id_and_guild_id (user_id, guild_id)
+DELETE FROM
+    "DiscordFrontend"."Nightly"."CachedVoiceStates"
+WHERE
+    "user_id" = :user_id AND
+    "guild_id" = :guild_id;
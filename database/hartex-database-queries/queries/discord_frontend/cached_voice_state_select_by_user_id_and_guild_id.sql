@@ -0,0 +1,8 @@
+--! cached_voice_state_select_by_user_id_and_guild_id : (channel_id?, guild_id, user_id)
+SELECT
+    *
+FROM
+    "DiscordFrontend"."Nightly"."CachedVoiceStates"
+WHERE
+    "user_id" = :user_id AND
+    "guild_id" = :guild_id;
@@ -0,0 +1,6 @@
+--! cached_voice_state_upsert (channel_id?, guild_id, user_id)
+INSERT INTO "DiscordFrontend"."Nightly"."CachedVoiceStates" ("channel_id", "guild_id", "user_id")
+VALUES (:channel_id, :guild_id, :user_id)
+ON CONFLICT ("guild_id", "user_id") DO UPDATE
+    SET
+        "channel_id" = :channel_id;
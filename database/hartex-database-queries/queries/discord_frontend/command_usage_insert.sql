@@ -0,0 +1,3 @@
+--! command_usage_insert (command, subcommand?, guild_id?, locale, latency_ms, outcome, error_hash?, invoked_at)
+INSERT INTO "DiscordFrontend"."Nightly"."CommandUsages" ("command", "subcommand", "guild_id", "locale", "latency_ms", "outcome", "error_hash", "invoked_at")
+VALUES (:command, :subcommand, :guild_id, :locale, :latency_ms, :outcome, :error_hash, :invoked_at);
@@ -0,0 +1,16 @@
+--! command_usage_rollup_select_by_guild_id_since
+SELECT
+    "command",
+    SUM("invocations")::BIGINT AS "invocations",
+    SUM("failures")::BIGINT AS "failures",
+    SUM("rejections")::BIGINT AS "rejections",
+    SUM("total_latency_ms")::BIGINT AS "total_latency_ms"
+FROM
+    "DiscordFrontend"."Nightly"."CommandUsageRollups"
+WHERE
+    "guild_id" = :guild_id AND
+    "day" >= :since
+GROUP BY
+    "command"
+ORDER BY
+    "invocations" DESC;
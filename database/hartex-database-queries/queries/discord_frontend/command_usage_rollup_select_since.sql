@@ -0,0 +1,15 @@
+--! command_usage_rollup_select_since
+SELECT
+    "command",
+    SUM("invocations")::BIGINT AS "invocations",
+    SUM("failures")::BIGINT AS "failures",
+    SUM("rejections")::BIGINT AS "rejections",
+    SUM("total_latency_ms")::BIGINT AS "total_latency_ms"
+FROM
+    "DiscordFrontend"."Nightly"."CommandUsageRollups"
+WHERE
+    "day" >= :since
+GROUP BY
+    "command"
+ORDER BY
+    "invocations" DESC;
@@ -0,0 +1,9 @@
+--! command_usage_rollup_upsert
+INSERT INTO "DiscordFrontend"."Nightly"."CommandUsageRollups" ("day", "command", "guild_id", "invocations", "failures", "rejections", "total_latency_ms")
+VALUES (date_trunc('day', :invoked_at, 'UTC'), :command, :guild_id, 1, :failures, :rejections, :latency_ms)
+ON CONFLICT ("day", "command", "guild_id") DO UPDATE
+SET
+    "invocations" = "CommandUsageRollups"."invocations" + 1,
+    "failures" = "CommandUsageRollups"."failures" + excluded."failures",
+    "rejections" = "CommandUsageRollups"."rejections" + excluded."rejections",
+    "total_latency_ms" = "CommandUsageRollups"."total_latency_ms" + excluded."total_latency_ms";
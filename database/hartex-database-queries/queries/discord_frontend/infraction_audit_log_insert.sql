@@ -0,0 +1,3 @@
+--! infraction_audit_log_insert (guild_id, case_number, actor_id, action, old_reason?, new_reason?, timestamp)
+INSERT INTO "DiscordFrontend"."Nightly"."InfractionAuditLogs" ("guild_id", "case_number", "actor_id", "action", "old_reason", "new_reason", "timestamp")
+VALUES (:guild_id, :case_number, :actor_id, :action, :old_reason, :new_reason, :timestamp);
@@ -0,0 +1,8 @@
+--! infraction_count_by_user_id_and_guild_id
+SELECT
+    COUNT(*)
+FROM
+    "DiscordFrontend"."Nightly"."Infractions"
+WHERE
+    "user_id" = :user_id AND
+    "guild_id" = :guild_id;
@@ -0,0 +1,6 @@
+--! infraction_delete
+DELETE FROM
+    "DiscordFrontend"."Nightly"."Infractions"
+WHERE
+    "case_number" = :case_number AND
+    "guild_id" = :guild_id;
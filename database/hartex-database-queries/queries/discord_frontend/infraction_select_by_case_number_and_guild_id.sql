@@ -0,0 +1,8 @@
+--! infraction_select_by_case_number_and_guild_id : (guild_id, case_number, user_id, moderator_id, kind, reason, created_at, pardoned)
+SELECT
+    *
+FROM
+    "DiscordFrontend"."Nightly"."Infractions"
+WHERE
+    "case_number" = :case_number AND
+    "guild_id" = :guild_id;
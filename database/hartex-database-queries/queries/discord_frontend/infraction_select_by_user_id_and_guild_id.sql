@@ -0,0 +1,12 @@
+--! infraction_select_by_user_id_and_guild_id : (guild_id, case_number, user_id, moderator_id, kind, reason, created_at, pardoned)
+SELECT
+    *
+FROM
+    "DiscordFrontend"."Nightly"."Infractions"
+WHERE
+    "user_id" = :user_id AND
+    "guild_id" = :guild_id
+ORDER BY
+    "case_number" DESC
+LIMIT :limit
+OFFSET :offset;
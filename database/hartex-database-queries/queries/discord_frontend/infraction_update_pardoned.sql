@@ -0,0 +1,8 @@
+--! infraction_update_pardoned
+UPDATE
+    "DiscordFrontend"."Nightly"."Infractions"
+SET
+    "pardoned" = :pardoned
+WHERE
+    "case_number" = :case_number AND
+    "guild_id" = :guild_id;
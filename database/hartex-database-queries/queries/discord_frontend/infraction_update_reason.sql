@@ -0,0 +1,8 @@
+--! infraction_update_reason
+UPDATE
+    "DiscordFrontend"."Nightly"."Infractions"
+SET
+    "reason" = :reason
+WHERE
+    "case_number" = :case_number AND
+    "guild_id" = :guild_id;
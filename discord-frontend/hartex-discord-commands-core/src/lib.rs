@@ -36,4 +36,8 @@ extern crate hartex_discord_commands_macros;
 #[cfg(feature = "derive")]
 pub use hartex_discord_commands_macros::*;
 
+pub mod middleware;
+pub mod options;
+pub mod routing;
+pub mod spec;
 pub mod traits;
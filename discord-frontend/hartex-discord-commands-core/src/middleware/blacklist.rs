@@ -0,0 +1,80 @@
+/*
+ * SPDX-License-Identifier: AGPL-3.0-only
+ *
+ * This file is part of HarTex.
+ *
+ * HarTex
+ * Copyright (c) 2021-2024 HarTex Project Developers
+ *
+ * HarTex is free software; you can redistribute it and/or modify
+ * it under the terms of the GNU Affero General Public License as published by
+ * the Free Software Foundation; either version 3 of the License, or
+ * (at your option) any later version.
+ *
+ * HarTex is distributed in the hope that it will be useful,
+ * but WITHOUT ANY WARRANTY; without even the implied warranty of
+ * MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+ * GNU Affero General Public License for more details.
+ *
+ * You should have received a copy of the GNU Affero General Public License along
+ * with HarTex. If not, see <https://www.gnu.org/licenses/>.
+ */
+
+//! # Blacklist Middleware
+//!
+//! Rejects invocations of commands by blacklisted users or in blacklisted guilds.
+
+use std::collections::HashSet;
+
+use async_trait::async_trait;
+use hartex_discord_core::discord::model::id::marker::GuildMarker;
+use hartex_discord_core::discord::model::id::marker::UserMarker;
+use hartex_discord_core::discord::model::id::Id;
+use hartex_localization_core::Localizer;
+
+use crate::middleware::Invocation;
+use crate::middleware::Middleware;
+use crate::middleware::MiddlewareOutcome;
+
+/// Rejects invocations of commands by blacklisted users or in blacklisted guilds.
+pub struct Blacklist {
+    guilds: HashSet<Id<GuildMarker>>,
+    users: HashSet<Id<UserMarker>>,
+}
+
+impl Blacklist {
+    /// Creates the middleware from the blacklisted guilds and users.
+    #[must_use]
+    pub fn new(guilds: HashSet<Id<GuildMarker>>, users: HashSet<Id<UserMarker>>) -> Self {
+        Self { guilds, users }
+    }
+}
+
+#[async_trait]
+impl Middleware for Blacklist {
+    async fn before(
+        &self,
+        invocation: &Invocation<'_>,
+        localizer: &Localizer<'_>,
+    ) -> miette::Result<MiddlewareOutcome> {
+        if self.permits(invocation).await? {
+            return Ok(MiddlewareOutcome::Continue);
+        }
+
+        Ok(MiddlewareOutcome::Respond(
+            localizer.error_error_blacklisted()?,
+        ))
+    }
+
+    async fn permits(&self, invocation: &Invocation<'_>) -> miette::Result<bool> {
+        let interaction = invocation.interaction;
+        let blacklisted = interaction
+            .guild_id
+            .is_some_and(|guild_id| self.guilds.contains(&guild_id))
+            || interaction
+                .author_id()
+                .is_some_and(|user_id| self.users.contains(&user_id));
+
+        Ok(!blacklisted)
+    }
+}
@@ -0,0 +1,114 @@
+/*
+ * SPDX-License-Identifier: AGPL-3.0-only
+ *
+ * This file is part of HarTex.
+ *
+ * HarTex
+ * Copyright (c) 2021-2024 HarTex Project Developers
+ *
+ * HarTex is free software; you can redistribute it and/or modify
+ * it under the terms of the GNU Affero General Public License as published by
+ * the Free Software Foundation; either version 3 of the License, or
+ * (at your option) any later version.
+ *
+ * HarTex is distributed in the hope that it will be useful,
+ * but WITHOUT ANY WARRANTY; without even the implied warranty of
+ * MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+ * GNU Affero General Public License for more details.
+ *
+ * You should have received a copy of the GNU Affero General Public License along
+ * with HarTex. If not, see <https://www.gnu.org/licenses/>.
+ */
+
+//! # Cooldown Middleware
+//!
+//! Rejects invocations of a command by a user that is still on cooldown for the command.
+
+use std::collections::HashMap;
+use std::sync::Mutex;
+use std::time::Duration;
+use std::time::Instant;
+
+use async_trait::async_trait;
+use hartex_discord_core::discord::model::id::marker::UserMarker;
+use hartex_discord_core::discord::model::id::Id;
+use hartex_localization_core::Localizer;
+use once_cell::sync::Lazy;
+
+use crate::middleware::Invocation;
+use crate::middleware::Middleware;
+use crate::middleware::MiddlewareOutcome;
+
+/// The instants until which users are on cooldown for each command.
+static COOLDOWNS: Lazy<Mutex<HashMap<(String, Id<UserMarker>), Instant>>> =
+    Lazy::new(|| Mutex::new(HashMap::new()));
+
+/// Rejects invocations of a command by a user within a duration of their last invocation that
+/// passed every middleware.
+pub struct Cooldown {
+    duration: Duration,
+}
+
+impl Cooldown {
+    /// Creates a cooldown of a certain number of seconds.
+    #[must_use]
+    pub const fn from_secs(seconds: u64) -> Self {
+        Self {
+            duration: Duration::from_secs(seconds),
+        }
+    }
+}
+
+#[async_trait]
+impl Middleware for Cooldown {
+    async fn before(
+        &self,
+        invocation: &Invocation<'_>,
+        localizer: &Localizer<'_>,
+    ) -> miette::Result<MiddlewareOutcome> {
+        let Some(user_id) = invocation.interaction.author_id() else {
+            return Ok(MiddlewareOutcome::Continue);
+        };
+
+        let now = Instant::now();
+        let remaining = {
+            let mut cooldowns = COOLDOWNS.lock().unwrap();
+            cooldowns.retain(|_, until| *until > now);
+
+            cooldowns
+                .get(&(invocation.command.name(), user_id))
+                .map(|until| *until - now)
+        };
+
+        let Some(remaining) = remaining else {
+            return Ok(MiddlewareOutcome::Continue);
+        };
+
+        Ok(MiddlewareOutcome::Respond(localizer.error_error_cooldown(
+            remaining.as_secs().max(1).to_string(),
+        )?))
+    }
+
+    async fn after(
+        &self,
+        invocation: &Invocation<'_>,
+        _: &miette::Result<()>,
+    ) -> miette::Result<()> {
+        // the cooldown only starts once the invocation passed every middleware, such that an
+        // invocation rejected by a later middleware does not put the user on cooldown
+        if invocation.short_circuited {
+            return Ok(());
+        }
+
+        let Some(user_id) = invocation.interaction.author_id() else {
+            return Ok(());
+        };
+
+        COOLDOWNS.lock().unwrap().insert(
+            (invocation.command.name(), user_id),
+            invocation.started + self.duration,
+        );
+
+        Ok(())
+    }
+}
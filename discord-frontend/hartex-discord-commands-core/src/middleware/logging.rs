@@ -0,0 +1,67 @@
+/*
+ * SPDX-License-Identifier: AGPL-3.0-only
+ *
+ * This file is part of HarTex.
+ *
+ * HarTex
+ * Copyright (c) 2021-2024 HarTex Project Developers
+ *
+ * HarTex is free software; you can redistribute it and/or modify
+ * it under the terms of the GNU Affero General Public License as published by
+ * the Free Software Foundation; either version 3 of the License, or
+ * (at your option) any later version.
+ *
+ * HarTex is distributed in the hope that it will be useful,
+ * but WITHOUT ANY WARRANTY; without even the implied warranty of
+ * MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+ * GNU Affero General Public License for more details.
+ *
+ * You should have received a copy of the GNU Affero General Public License along
+ * with HarTex. If not, see <https://www.gnu.org/licenses/>.
+ */
+
+//! # Logging Middleware
+//!
+//! Logs invocations of commands and their results.
+
+use async_trait::async_trait;
+use hartex_localization_core::Localizer;
+use hartex_log::log;
+
+use crate::middleware::Invocation;
+use crate::middleware::Middleware;
+use crate::middleware::MiddlewareOutcome;
+
+/// Logs invocations of commands and their results.
+pub struct Logging;
+
+#[async_trait]
+impl Middleware for Logging {
+    async fn before(
+        &self,
+        invocation: &Invocation<'_>,
+        _: &Localizer<'_>,
+    ) -> miette::Result<MiddlewareOutcome> {
+        log::trace!(
+            "command {} invoked by user {:?} in guild {:?}",
+            invocation.command.name(),
+            invocation.interaction.author_id(),
+            invocation.interaction.guild_id
+        );
+
+        Ok(MiddlewareOutcome::Continue)
+    }
+
+    async fn after(
+        &self,
+        invocation: &Invocation<'_>,
+        result: &miette::Result<()>,
+    ) -> miette::Result<()> {
+        match result {
+            Ok(()) => log::trace!("command {} completed", invocation.command.name()),
+            Err(error) => log::warn!("command {} failed: {error:?}", invocation.command.name()),
+        }
+
+        Ok(())
+    }
+}
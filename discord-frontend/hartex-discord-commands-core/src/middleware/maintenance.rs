@@ -0,0 +1,90 @@
+/*
+ * SPDX-License-Identifier: AGPL-3.0-only
+ *
+ * This file is part of HarTex.
+ *
+ * HarTex
+ * Copyright (c) 2021-2024 HarTex Project Developers
+ *
+ * HarTex is free software; you can redistribute it and/or modify
+ * it under the terms of the GNU Affero General Public License as published by
+ * the Free Software Foundation; either version 3 of the License, or
+ * (at your option) any later version.
+ *
+ * HarTex is distributed in the hope that it will be useful,
+ * but WITHOUT ANY WARRANTY; without even the implied warranty of
+ * MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+ * GNU Affero General Public License for more details.
+ *
+ * You should have received a copy of the GNU Affero General Public License along
+ * with HarTex. If not, see <https://www.gnu.org/licenses/>.
+ */
+
+//! # Maintenance Middleware
+//!
+//! Rejects invocations of commands while the bot is in maintenance mode, except for exempt users.
+
+use std::collections::HashSet;
+use std::sync::atomic::AtomicBool;
+use std::sync::atomic::Ordering;
+
+use async_trait::async_trait;
+use hartex_discord_core::discord::model::id::marker::UserMarker;
+use hartex_discord_core::discord::model::id::Id;
+use hartex_localization_core::Localizer;
+
+use crate::middleware::Invocation;
+use crate::middleware::Middleware;
+use crate::middleware::MiddlewareOutcome;
+
+/// Whether the bot is in maintenance mode.
+static ENABLED: AtomicBool = AtomicBool::new(false);
+
+/// Returns whether the bot is in maintenance mode.
+#[must_use]
+pub fn enabled() -> bool {
+    ENABLED.load(Ordering::Relaxed)
+}
+
+/// Sets whether the bot is in maintenance mode.
+pub fn set_enabled(enabled: bool) {
+    ENABLED.store(enabled, Ordering::Relaxed);
+}
+
+/// Rejects invocations of commands while the bot is in maintenance mode.
+pub struct Maintenance {
+    exempt_users: HashSet<Id<UserMarker>>,
+}
+
+impl Maintenance {
+    /// Creates the middleware, exempting the given users from maintenance mode.
+    #[must_use]
+    pub fn new(exempt_users: HashSet<Id<UserMarker>>) -> Self {
+        Self { exempt_users }
+    }
+}
+
+#[async_trait]
+impl Middleware for Maintenance {
+    async fn before(
+        &self,
+        invocation: &Invocation<'_>,
+        localizer: &Localizer<'_>,
+    ) -> miette::Result<MiddlewareOutcome> {
+        if self.permits(invocation).await? {
+            return Ok(MiddlewareOutcome::Continue);
+        }
+
+        Ok(MiddlewareOutcome::Respond(
+            localizer.error_error_maintenance()?,
+        ))
+    }
+
+    async fn permits(&self, invocation: &Invocation<'_>) -> miette::Result<bool> {
+        Ok(!enabled()
+            || invocation
+                .interaction
+                .author_id()
+                .is_some_and(|user_id| self.exempt_users.contains(&user_id)))
+    }
+}
@@ -0,0 +1,83 @@
+/*
+ * SPDX-License-Identifier: AGPL-3.0-only
+ *
+ * This file is part of HarTex.
+ *
+ * HarTex
+ * Copyright (c) 2021-2024 HarTex Project Developers
+ *
+ * HarTex is free software; you can redistribute it and/or modify
+ * it under the terms of the GNU Affero General Public License as published by
+ * the Free Software Foundation; either version 3 of the License, or
+ * (at your option) any later version.
+ *
+ * HarTex is distributed in the hope that it will be useful,
+ * but WITHOUT ANY WARRANTY; without even the implied warranty of
+ * MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+ * GNU Affero General Public License for more details.
+ *
+ * You should have received a copy of the GNU Affero General Public License along
+ * with HarTex. If not, see <https://www.gnu.org/licenses/>.
+ */
+
+//! # Metrics Middleware
+//!
+//! Records the number of invocations, the number of failures and the total execution time of
+//! each command.
+
+use std::collections::HashMap;
+use std::sync::Mutex;
+use std::time::Duration;
+
+use async_trait::async_trait;
+use once_cell::sync::Lazy;
+
+use crate::middleware::Invocation;
+use crate::middleware::Middleware;
+
+/// The metrics recorded for each command.
+static METRICS: Lazy<Mutex<HashMap<String, CommandMetrics>>> =
+    Lazy::new(|| Mutex::new(HashMap::new()));
+
+/// The metrics recorded for a command.
+#[derive(Clone, Debug, Default)]
+pub struct CommandMetrics {
+    /// The number of times the command has been invoked.
+    pub invocations: u64,
+    /// The number of invocations that have failed.
+    pub failures: u64,
+    /// The total time spent on the invocations.
+    pub total_duration: Duration,
+}
+
+/// Returns the metrics recorded for each command.
+///
+/// # Panics
+///
+/// Panics when the metrics lock is poisoned.
+#[must_use]
+pub fn snapshot() -> HashMap<String, CommandMetrics> {
+    METRICS.lock().unwrap().clone()
+}
+
+/// Records metrics of command invocations.
+pub struct Metrics;
+
+#[async_trait]
+impl Middleware for Metrics {
+    async fn after(
+        &self,
+        invocation: &Invocation<'_>,
+        result: &miette::Result<()>,
+    ) -> miette::Result<()> {
+        let mut metrics = METRICS.lock().unwrap();
+        let entry = metrics.entry(invocation.command.name()).or_default();
+        entry.invocations += 1;
+        entry.total_duration += invocation.started.elapsed();
+        if result.is_err() {
+            entry.failures += 1;
+        }
+
+        Ok(())
+    }
+}
@@ -0,0 +1,322 @@
+/*
+ * SPDX-License-Identifier: AGPL-3.0-only
+ *
+ * This file is part of HarTex.
+ *
+ * HarTex
+ * Copyright (c) 2021-2024 HarTex Project Developers
+ *
+ * HarTex is free software; you can redistribute it and/or modify
+ * it under the terms of the GNU Affero General Public License as published by
+ * the Free Software Foundation; either version 3 of the License, or
+ * (at your option) any later version.
+ *
+ * HarTex is distributed in the hope that it will be useful,
+ * but WITHOUT ANY WARRANTY; without even the implied warranty of
+ * MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+ * GNU Affero General Public License for more details.
+ *
+ * You should have received a copy of the GNU Affero General Public License along
+ * with HarTex. If not, see <https://www.gnu.org/licenses/>.
+ */
+
+//! # Command Middleware
+//!
+//! Middlewares run around the execution of commands and the handling of their component
+//! interactions. Each middleware may inspect an invocation before the command is executed,
+//! short-circuiting the invocation with a response if necessary, and observe the result of the
+//! invocation afterwards.
+//!
+//! A [`MiddlewareChain`] holds the middlewares applied to every command. Plugins and commands may
+//! declare additional middlewares, which run after the ones in the chain, in that order.
+
+use std::time::Instant;
+
+use async_trait::async_trait;
+use hartex_discord_core::discord::http::client::InteractionClient;
+use hartex_discord_core::discord::model::application::interaction::Interaction;
+use hartex_discord_core::discord::model::http::interaction::InteractionResponse;
+use hartex_discord_core::discord::model::http::interaction::InteractionResponseType;
+use hartex_discord_core::discord::util::builder::InteractionResponseDataBuilder;
+use hartex_localization_core::Localizer;
+use hartex_log::log;
+use miette::IntoDiagnostic;
+
+use crate::traits::Command;
+
+pub mod blacklist;
+pub mod cooldown;
+pub mod logging;
+pub mod maintenance;
+pub mod metrics;
+pub mod permissions;
+pub mod plugin;
+
+/// An invocation of a command.
+#[derive(Clone, Copy)]
+pub struct Invocation<'a> {
+    /// The command being invoked.
+    pub command: &'a (dyn Command + Send + Sync),
+    /// The interaction invoking the command.
+    pub interaction: &'a Interaction,
+    /// The instant at which the invocation started running through the middlewares.
+    pub started: Instant,
+    /// Whether the invocation has been short-circuited by a middleware.
+    ///
+    /// This is always `false` before the command is executed.
+    pub short_circuited: bool,
+}
+
+/// The outcome of a middleware inspecting an invocation.
+pub enum MiddlewareOutcome {
+    /// Continue with the next middleware, or the execution of the command.
+    Continue,
+    /// Short-circuit the invocation, responding with the given content.
+    Respond(String),
+}
+
+/// The middleware trait, contains callbacks that are run before and after a command is executed.
+#[async_trait]
+pub trait Middleware {
+    /// Inspects an invocation before the command is executed.
+    async fn before(
+        &self,
+        _: &Invocation<'_>,
+        _: &Localizer<'_>,
+    ) -> miette::Result<MiddlewareOutcome> {
+        Ok(MiddlewareOutcome::Continue)
+    }
+
+    /// Observes the result of an invocation.
+    ///
+    /// This is called for every middleware whose [`Middleware::before`] callback has been called,
+    /// in reverse order, including when the invocation has been short-circuited.
+    async fn after(&self, _: &Invocation<'_>, _: &miette::Result<()>) -> miette::Result<()> {
+        Ok(())
+    }
+
+    /// Determines whether an invocation would pass this middleware, without side effects.
+    ///
+    /// This is used for determining the commands available to a user without invoking them.
+    /// Middlewares restricting access to commands should override this consistently with
+    /// [`Middleware::before`].
+    async fn permits(&self, _: &Invocation<'_>) -> miette::Result<bool> {
+        Ok(true)
+    }
+}
+
+/// A chain of middlewares applied to every command.
+#[derive(Default)]
+pub struct MiddlewareChain {
+    middlewares: Vec<Box<dyn Middleware + Send + Sync>>,
+}
+
+impl MiddlewareChain {
+    /// Creates an empty middleware chain.
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Appends a middleware to the chain.
+    #[must_use]
+    pub fn with<T: Middleware + Send + Sync + 'static>(mut self, middleware: T) -> Self {
+        self.middlewares.push(Box::new(middleware));
+        self
+    }
+
+    /// Runs a command through the middlewares of the chain, followed by the middlewares of its
+    /// plugin and the command itself.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error when a middleware fails to inspect the invocation, when the
+    /// short-circuiting response fails to be sent, or when the command fails to execute.
+    pub async fn run(
+        &self,
+        command: &(dyn Command + Send + Sync),
+        interaction: Interaction,
+        interaction_client: &InteractionClient<'_>,
+        localizer: Localizer<'_>,
+    ) -> miette::Result<()> {
+        self.run_handler(
+            command,
+            Handler::Execute,
+            interaction,
+            interaction_client,
+            localizer,
+        )
+        .await
+    }
+
+    /// Runs a component interaction of a command through the same middlewares as the command
+    /// itself, followed by the component handler of the command.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error when a middleware fails to inspect the invocation, when the
+    /// short-circuiting response fails to be sent, or when the component interaction fails to be
+    /// handled.
+    pub async fn run_component(
+        &self,
+        command: &(dyn Command + Send + Sync),
+        interaction: Interaction,
+        interaction_client: &InteractionClient<'_>,
+        localizer: Localizer<'_>,
+    ) -> miette::Result<()> {
+        self.run_handler(
+            command,
+            Handler::HandleComponent,
+            interaction,
+            interaction_client,
+            localizer,
+        )
+        .await
+    }
+
+    /// Runs an interaction through the middlewares, followed by the given handler of the command.
+    async fn run_handler(
+        &self,
+        command: &(dyn Command + Send + Sync),
+        handler: Handler,
+        interaction: Interaction,
+        interaction_client: &InteractionClient<'_>,
+        localizer: Localizer<'_>,
+    ) -> miette::Result<()> {
+        let scoped = command
+            .plugin()
+            .middlewares()
+            .into_iter()
+            .chain(command.middlewares())
+            .collect::<Vec<_>>();
+        let middlewares = self
+            .middlewares
+            .iter()
+            .chain(scoped.iter())
+            .collect::<Vec<_>>();
+
+        let invocation = Invocation {
+            command,
+            interaction: &interaction,
+            started: Instant::now(),
+            short_circuited: false,
+        };
+
+        let mut entered = 0;
+        let mut response = None;
+        for middleware in &middlewares {
+            entered += 1;
+
+            match middleware.before(&invocation, &localizer).await {
+                Ok(MiddlewareOutcome::Continue) => {}
+                Ok(MiddlewareOutcome::Respond(content)) => {
+                    response = Some(Ok(content));
+                    break;
+                }
+                Err(error) => {
+                    response = Some(Err(error));
+                    break;
+                }
+            }
+        }
+
+        let invocation = Invocation {
+            short_circuited: response.is_some(),
+            ..invocation
+        };
+        let result = match response {
+            Some(Ok(content)) => respond(interaction_client, &interaction, content).await,
+            Some(Err(error)) => Err(error),
+            None => match handler {
+                Handler::Execute => {
+                    command
+                        .execute(interaction.clone(), interaction_client, localizer)
+                        .await
+                }
+                Handler::HandleComponent => {
+                    command
+                        .handle_component(interaction.clone(), interaction_client, localizer)
+                        .await
+                }
+            },
+        };
+
+        for middleware in middlewares[..entered].iter().rev() {
+            if let Err(error) = middleware.after(&invocation, &result).await {
+                log::error!(
+                    "middleware failed to observe invocation of command {}: {error:?}",
+                    command.name()
+                );
+            }
+        }
+
+        result
+    }
+
+    /// Determines whether an invocation of a command would pass the middlewares of the chain,
+    /// followed by the middlewares of its plugin and the command itself, without invoking it.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error when a middleware fails to inspect the invocation.
+    pub async fn permits(
+        &self,
+        command: &(dyn Command + Send + Sync),
+        interaction: &Interaction,
+    ) -> miette::Result<bool> {
+        let scoped = command
+            .plugin()
+            .middlewares()
+            .into_iter()
+            .chain(command.middlewares())
+            .collect::<Vec<_>>();
+
+        let invocation = Invocation {
+            command,
+            interaction,
+            started: Instant::now(),
+            short_circuited: false,
+        };
+
+        for middleware in self.middlewares.iter().chain(scoped.iter()) {
+            if !middleware.permits(&invocation).await? {
+                return Ok(false);
+            }
+        }
+
+        Ok(true)
+    }
+}
+
+/// The handler of a command run after the middlewares.
+enum Handler {
+    /// Executes the command.
+    Execute,
+    /// Handles a component interaction of the command.
+    HandleComponent,
+}
+
+/// Responds to an interaction with the given content.
+async fn respond(
+    interaction_client: &InteractionClient<'_>,
+    interaction: &Interaction,
+    content: String,
+) -> miette::Result<()> {
+    interaction_client
+        .create_response(
+            interaction.id,
+            &interaction.token,
+            &InteractionResponse {
+                kind: InteractionResponseType::ChannelMessageWithSource,
+                data: Some(
+                    InteractionResponseDataBuilder::new()
+                        .content(content)
+                        .build(),
+                ),
+            },
+        )
+        .await
+        .into_diagnostic()?;
+
+    Ok(())
+}
@@ -0,0 +1,165 @@
+/*
+ * SPDX-License-Identifier: AGPL-3.0-only
+ *
+ * This file is part of HarTex.
+ *
+ * HarTex
+ * Copyright (c) 2021-2024 HarTex Project Developers
+ *
+ * HarTex is free software; you can redistribute it and/or modify
+ * it under the terms of the GNU Affero General Public License as published by
+ * the Free Software Foundation; either version 3 of the License, or
+ * (at your option) any later version.
+ *
+ * HarTex is distributed in the hope that it will be useful,
+ * but WITHOUT ANY WARRANTY; without even the implied warranty of
+ * MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+ * GNU Affero General Public License for more details.
+ *
+ * You should have received a copy of the GNU Affero General Public License along
+ * with HarTex. If not, see <https://www.gnu.org/licenses/>.
+ */
+
+//! # Permission Middlewares
+//!
+//! Rejects invocations by members lacking the Discord permissions or the permission level
+//! required by the command, or by users who are not administrators of the dashboard.
+
+use async_trait::async_trait;
+use hartex_discord_configuration_provider::ConfigurationProvider;
+use hartex_localization_core::Localizer;
+
+use crate::middleware::Invocation;
+use crate::middleware::Middleware;
+use crate::middleware::MiddlewareOutcome;
+
+/// Rejects invocations by members lacking the Discord permissions required by the command.
+pub struct RequiredPermissions;
+
+#[async_trait]
+impl Middleware for RequiredPermissions {
+    async fn before(
+        &self,
+        invocation: &Invocation<'_>,
+        localizer: &Localizer<'_>,
+    ) -> miette::Result<MiddlewareOutcome> {
+        if self.permits(invocation).await? {
+            return Ok(MiddlewareOutcome::Continue);
+        }
+
+        Ok(MiddlewareOutcome::Respond(
+            localizer.error_error_insufficient_permissions()?,
+        ))
+    }
+
+    async fn permits(&self, invocation: &Invocation<'_>) -> miette::Result<bool> {
+        let member_permissions = invocation
+            .interaction
+            .member
+            .as_ref()
+            .and_then(|member| member.permissions)
+            .unwrap_or_default();
+
+        Ok(member_permissions.contains(invocation.command.required_permissions()))
+    }
+}
+
+/// Rejects invocations by members whose permission level is below the one required by the
+/// command, as configured by the guild or otherwise declared by the command.
+pub struct PermissionLevel;
+
+#[async_trait]
+impl Middleware for PermissionLevel {
+    async fn before(
+        &self,
+        invocation: &Invocation<'_>,
+        localizer: &Localizer<'_>,
+    ) -> miette::Result<MiddlewareOutcome> {
+        let Some((level, required_level)) = insufficient_level(invocation).await? else {
+            return Ok(MiddlewareOutcome::Continue);
+        };
+
+        Ok(MiddlewareOutcome::Respond(
+            localizer.error_error_insufficient_permission_level(
+                level.to_string(),
+                required_level.to_string(),
+            )?,
+        ))
+    }
+
+    async fn permits(&self, invocation: &Invocation<'_>) -> miette::Result<bool> {
+        Ok(insufficient_level(invocation).await?.is_none())
+    }
+}
+
+/// Returns the permission level of the invoking member along with the level required by the
+/// command, if the former is below the latter.
+async fn insufficient_level(invocation: &Invocation<'_>) -> miette::Result<Option<(u8, u8)>> {
+    let Some(guild_id) = invocation.interaction.guild_id else {
+        return Ok(None);
+    };
+
+    let command = invocation.command;
+    let required_level = ConfigurationProvider::minimum_permission_level_cached(
+        guild_id,
+        command.plugin().name(),
+        command.name(),
+    )
+    .await?
+    .unwrap_or_else(|| command.minimum_permission_level());
+    if required_level == 0 {
+        return Ok(None);
+    }
+
+    let Some(user_id) = invocation.interaction.author_id() else {
+        return Ok(None);
+    };
+    let role_ids = invocation
+        .interaction
+        .member
+        .as_ref()
+        .map(|member| member.roles.clone())
+        .unwrap_or_default();
+    let level =
+        ConfigurationProvider::permission_level_cached(guild_id, user_id, &role_ids).await?;
+    if level >= required_level {
+        return Ok(None);
+    }
+
+    Ok(Some((level, required_level)))
+}
+
+/// Rejects invocations by users who are not administrators of the dashboard of the guild.
+///
+/// This is applied to commands that change the configuration of the guild.
+pub struct DashboardAdmin;
+
+#[async_trait]
+impl Middleware for DashboardAdmin {
+    async fn before(
+        &self,
+        invocation: &Invocation<'_>,
+        localizer: &Localizer<'_>,
+    ) -> miette::Result<MiddlewareOutcome> {
+        if self.permits(invocation).await? {
+            return Ok(MiddlewareOutcome::Continue);
+        }
+
+        Ok(MiddlewareOutcome::Respond(
+            localizer.error_error_not_dashboard_admin()?,
+        ))
+    }
+
+    async fn permits(&self, invocation: &Invocation<'_>) -> miette::Result<bool> {
+        let (Some(guild_id), Some(user_id)) = (
+            invocation.interaction.guild_id,
+            invocation.interaction.author_id(),
+        ) else {
+            return Ok(false);
+        };
+
+        Ok(ConfigurationProvider::dashboard_admins(guild_id)
+            .await?
+            .contains(&user_id))
+    }
+}
@@ -0,0 +1,60 @@
+/*
+ * SPDX-License-Identifier: AGPL-3.0-only
+ *
+ * This file is part of HarTex.
+ *
+ * HarTex
+ * Copyright (c) 2021-2024 HarTex Project Developers
+ *
+ * HarTex is free software; you can redistribute it and/or modify
+ * it under the terms of the GNU Affero General Public License as published by
+ * the Free Software Foundation; either version 3 of the License, or
+ * (at your option) any later version.
+ *
+ * HarTex is distributed in the hope that it will be useful,
+ * but WITHOUT ANY WARRANTY; without even the implied warranty of
+ * MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+ * GNU Affero General Public License for more details.
+ *
+ * You should have received a copy of the GNU Affero General Public License along
+ * with HarTex. If not, see <https://www.gnu.org/licenses/>.
+ */
+
+//! # Plugin Middleware
+//!
+//! Rejects invocations of commands belonging to plugins that are not enabled in the guild.
+
+use async_trait::async_trait;
+use hartex_localization_core::Localizer;
+
+use crate::middleware::Invocation;
+use crate::middleware::Middleware;
+use crate::middleware::MiddlewareOutcome;
+
+/// Rejects invocations of commands belonging to disabled plugins.
+pub struct PluginEnabled;
+
+#[async_trait]
+impl Middleware for PluginEnabled {
+    async fn before(
+        &self,
+        invocation: &Invocation<'_>,
+        localizer: &Localizer<'_>,
+    ) -> miette::Result<MiddlewareOutcome> {
+        if self.permits(invocation).await? {
+            return Ok(MiddlewareOutcome::Continue);
+        }
+
+        Ok(MiddlewareOutcome::Respond(
+            localizer.error_error_plugin_disabled(invocation.command.plugin().name())?,
+        ))
+    }
+
+    async fn permits(&self, invocation: &Invocation<'_>) -> miette::Result<bool> {
+        let Some(guild_id) = invocation.interaction.guild_id else {
+            return Ok(true);
+        };
+
+        invocation.command.plugin().enabled(guild_id).await
+    }
+}
@@ -0,0 +1,294 @@
+/*
+ * SPDX-License-Identifier: AGPL-3.0-only
+ *
+ * This file is part of HarTex.
+ *
+ * HarTex
+ * Copyright (c) 2021-2024 HarTex Project Developers
+ *
+ * HarTex is free software; you can redistribute it and/or modify
+ * it under the terms of the GNU Affero General Public License as published by
+ * the Free Software Foundation; either version 3 of the License, or
+ * (at your option) any later version.
+ *
+ * HarTex is distributed in the hope that it will be useful,
+ * but WITHOUT ANY WARRANTY; without even the implied warranty of
+ * MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+ * GNU Affero General Public License for more details.
+ *
+ * You should have received a copy of the GNU Affero General Public License along
+ * with HarTex. If not, see <https://www.gnu.org/licenses/>.
+ */
+
+//! # Command Options
+//!
+//! Typed extraction of command options from interaction data. Structs deriving `CommandOptions`
+//! are parsed from the options of a command or a subcommand, and produce a `CommandOptionsError`
+//! when an option is missing, mistyped, unresolved or out of range.
+
+use std::error::Error;
+use std::fmt;
+use std::fmt::Display;
+use std::fmt::Formatter;
+
+use hartex_discord_core::discord::model::application::interaction::application_command::CommandData;
+use hartex_discord_core::discord::model::application::interaction::application_command::CommandDataOption;
+use hartex_discord_core::discord::model::application::interaction::application_command::CommandOptionValue;
+use hartex_discord_core::discord::model::application::interaction::Interaction;
+use hartex_discord_core::discord::model::application::interaction::InteractionChannel;
+use hartex_discord_core::discord::model::application::interaction::InteractionData;
+use hartex_discord_core::discord::model::application::interaction::InteractionDataResolved;
+use hartex_discord_core::discord::model::channel::Attachment;
+use hartex_discord_core::discord::model::guild::Role;
+use hartex_discord_core::discord::model::id::marker::AttachmentMarker;
+use hartex_discord_core::discord::model::id::marker::ChannelMarker;
+use hartex_discord_core::discord::model::id::marker::GenericMarker;
+use hartex_discord_core::discord::model::id::marker::RoleMarker;
+use hartex_discord_core::discord::model::id::marker::UserMarker;
+use hartex_discord_core::discord::model::id::Id;
+use hartex_discord_core::discord::model::user::User;
+
+/// An error encountered when extracting command options.
+#[allow(clippy::module_name_repetitions)]
+#[derive(Debug)]
+pub enum CommandOptionsError {
+    /// A required option is missing.
+    Missing { name: String },
+    /// An option has a different type from the expected one.
+    MismatchedType {
+        name: String,
+        expected: &'static str,
+    },
+    /// The options were expected to be within a subcommand or subcommand group.
+    NotSubcommand { name: String },
+    /// An option value is outside of its allowed range.
+    OutOfRange {
+        name: String,
+        value: String,
+        min: Option<String>,
+        max: Option<String>,
+    },
+    /// An option refers to an entity absent from the resolved data of the interaction.
+    Unresolved { name: String },
+}
+
+impl Display for CommandOptionsError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Missing { name } => write!(f, "missing required option `{name}`"),
+            Self::MismatchedType { name, expected } => {
+                write!(f, "option `{name}` is not of the expected type: {expected}")
+            }
+            Self::NotSubcommand { name } => {
+                write!(f, "option `{name}` is not a subcommand or subcommand group")
+            }
+            Self::OutOfRange {
+                name,
+                value,
+                min,
+                max,
+            } => {
+                write!(f, "option `{name}` has value {value} outside of the range ")?;
+                write!(
+                    f,
+                    "{}..={}",
+                    min.as_deref().unwrap_or_default(),
+                    max.as_deref().unwrap_or_default()
+                )
+            }
+            Self::Unresolved { name } => {
+                write!(f, "option `{name}` is missing from the resolved data")
+            }
+        }
+    }
+}
+
+impl Error for CommandOptionsError {}
+
+/// A struct that can be extracted from command options.
+///
+/// This is usually implemented with `#[derive(CommandOptions)]`.
+pub trait CommandOptions: Sized {
+    /// Extracts the struct from a collection of options and the resolved data of the interaction.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error when an option is missing, mistyped, unresolved or out of range.
+    fn from_options(
+        options: &[CommandDataOption],
+        resolved: Option<&InteractionDataResolved>,
+    ) -> Result<Self, CommandOptionsError>;
+
+    /// Extracts the struct from the top-level options of a command.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error when an option is missing, mistyped, unresolved or out of range.
+    fn from_data(data: &CommandData) -> Result<Self, CommandOptionsError> {
+        Self::from_options(&data.options, data.resolved.as_ref())
+    }
+
+    /// Extracts the struct from the options of a subcommand or subcommand group.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error when the option is not a subcommand or subcommand group, or when an
+    /// option is missing, mistyped, unresolved or out of range.
+    fn from_subcommand(
+        option: &CommandDataOption,
+        resolved: Option<&InteractionDataResolved>,
+    ) -> Result<Self, CommandOptionsError> {
+        match &option.value {
+            CommandOptionValue::SubCommand(options)
+            | CommandOptionValue::SubCommandGroup(options) => Self::from_options(options, resolved),
+            _ => Err(CommandOptionsError::NotSubcommand {
+                name: option.name.clone(),
+            }),
+        }
+    }
+}
+
+/// A type that can be extracted from a single command option value.
+pub trait FromOptionValue: Sized {
+    /// Extracts the type from the value of the option with the given name.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error when the value is of a different type, or when the entity it refers to is
+    /// absent from the resolved data.
+    fn from_option_value(
+        name: &str,
+        value: &CommandOptionValue,
+        resolved: Option<&InteractionDataResolved>,
+    ) -> Result<Self, CommandOptionsError>;
+}
+
+macro_rules! impl_from_option_value {
+    ($ty:ty, $variant:ident, $expected:literal) => {
+        impl FromOptionValue for $ty {
+            fn from_option_value(
+                name: &str,
+                value: &CommandOptionValue,
+                _: Option<&InteractionDataResolved>,
+            ) -> Result<Self, CommandOptionsError> {
+                let CommandOptionValue::$variant(value) = value else {
+                    return Err(CommandOptionsError::MismatchedType {
+                        name: name.to_string(),
+                        expected: $expected,
+                    });
+                };
+
+                Ok(value.clone())
+            }
+        }
+    };
+}
+
+impl_from_option_value!(bool, Boolean, "boolean");
+impl_from_option_value!(f64, Number, "number");
+impl_from_option_value!(i64, Integer, "integer");
+impl_from_option_value!(String, String, "string");
+impl_from_option_value!(Id<AttachmentMarker>, Attachment, "attachment");
+impl_from_option_value!(Id<ChannelMarker>, Channel, "channel");
+impl_from_option_value!(Id<GenericMarker>, Mentionable, "mentionable");
+impl_from_option_value!(Id<RoleMarker>, Role, "role");
+impl_from_option_value!(Id<UserMarker>, User, "user");
+
+macro_rules! impl_from_resolved_option_value {
+    ($ty:ty, $variant:ident, $field:ident, $expected:literal) => {
+        impl FromOptionValue for $ty {
+            fn from_option_value(
+                name: &str,
+                value: &CommandOptionValue,
+                resolved: Option<&InteractionDataResolved>,
+            ) -> Result<Self, CommandOptionsError> {
+                let CommandOptionValue::$variant(id) = value else {
+                    return Err(CommandOptionsError::MismatchedType {
+                        name: name.to_string(),
+                        expected: $expected,
+                    });
+                };
+
+                resolved
+                    .and_then(|resolved| resolved.$field.get(id))
+                    .cloned()
+                    .ok_or_else(|| CommandOptionsError::Unresolved {
+                        name: name.to_string(),
+                    })
+            }
+        }
+    };
+}
+
+impl_from_resolved_option_value!(Attachment, Attachment, attachments, "attachment");
+impl_from_resolved_option_value!(InteractionChannel, Channel, channels, "channel");
+impl_from_resolved_option_value!(Role, Role, roles, "role");
+impl_from_resolved_option_value!(User, User, users, "user");
+
+/// Returns the resolved data of an application command interaction, if any.
+#[must_use]
+pub fn resolved(interaction: &Interaction) -> Option<&InteractionDataResolved> {
+    let Some(InteractionData::ApplicationCommand(data)) = &interaction.data else {
+        return None;
+    };
+
+    data.resolved.as_ref()
+}
+
+/// Extracts an optional option by its name.
+///
+/// # Errors
+///
+/// Returns an error when the option is present but mistyped or unresolved.
+pub fn optional<T: FromOptionValue>(
+    options: &[CommandDataOption],
+    resolved: Option<&InteractionDataResolved>,
+    name: &str,
+) -> Result<Option<T>, CommandOptionsError> {
+    options
+        .iter()
+        .find(|option| option.name == name)
+        .map(|option| T::from_option_value(name, &option.value, resolved))
+        .transpose()
+}
+
+/// Extracts a required option by its name.
+///
+/// # Errors
+///
+/// Returns an error when the option is missing, mistyped or unresolved.
+pub fn required<T: FromOptionValue>(
+    options: &[CommandDataOption],
+    resolved: Option<&InteractionDataResolved>,
+    name: &str,
+) -> Result<T, CommandOptionsError> {
+    optional(options, resolved, name)?.ok_or_else(|| CommandOptionsError::Missing {
+        name: name.to_string(),
+    })
+}
+
+/// Checks that the value of an option is within its allowed range.
+///
+/// # Errors
+///
+/// Returns an error when the value is below the minimum or above the maximum.
+pub fn check_range<T: Display + PartialOrd>(
+    name: &str,
+    value: &T,
+    min: Option<T>,
+    max: Option<T>,
+) -> Result<(), CommandOptionsError> {
+    let below = min.as_ref().is_some_and(|min| value < min);
+    let above = max.as_ref().is_some_and(|max| value > max);
+
+    if below || above {
+        return Err(CommandOptionsError::OutOfRange {
+            name: name.to_string(),
+            value: value.to_string(),
+            min: min.map(|min| min.to_string()),
+            max: max.map(|max| max.to_string()),
+        });
+    }
+
+    Ok(())
+}
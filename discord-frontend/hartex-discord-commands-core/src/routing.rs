@@ -0,0 +1,97 @@
+/*
+ * SPDX-License-Identifier: AGPL-3.0-only
+ *
+ * This file is part of HarTex.
+ *
+ * HarTex
+ * Copyright (c) 2021-2024 HarTex Project Developers
+ *
+ * HarTex is free software; you can redistribute it and/or modify
+ * it under the terms of the GNU Affero General Public License as published by
+ * the Free Software Foundation; either version 3 of the License, or
+ * (at your option) any later version.
+ *
+ * HarTex is distributed in the hope that it will be useful,
+ * but WITHOUT ANY WARRANTY; without even the implied warranty of
+ * MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+ * GNU Affero General Public License for more details.
+ *
+ * You should have received a copy of the GNU Affero General Public License along
+ * with HarTex. If not, see <https://www.gnu.org/licenses/>.
+ */
+
+//! # Subcommand Routing
+//!
+//! Routes invocations of commands and subcommand groups to the subcommand that was invoked,
+//! checking the permissions required by the subcommand before executing it.
+
+use hartex_discord_core::discord::http::client::InteractionClient;
+use hartex_discord_core::discord::model::application::interaction::application_command::CommandDataOption;
+use hartex_discord_core::discord::model::application::interaction::application_command::CommandOptionValue;
+use hartex_discord_core::discord::model::application::interaction::Interaction;
+use hartex_discord_core::discord::model::http::interaction::InteractionResponse;
+use hartex_discord_core::discord::model::http::interaction::InteractionResponseType;
+use hartex_discord_core::discord::util::builder::InteractionResponseDataBuilder;
+use hartex_localization_core::Localizer;
+use miette::IntoDiagnostic;
+
+use crate::traits::Subcommand;
+
+/// Routes an invocation to the invoked subcommand or subcommand group among the options.
+///
+/// # Errors
+///
+/// Returns an error when no subcommand is invoked, when the invoked subcommand is unknown, or
+/// when the subcommand fails to execute.
+pub async fn route(
+    subcommands: Vec<Box<dyn Subcommand + Send + Sync>>,
+    interaction: Interaction,
+    interaction_client: &InteractionClient<'_>,
+    options: &[CommandDataOption],
+    localizer: Localizer<'_>,
+) -> miette::Result<()> {
+    let Some(option) = options.iter().find(|option| {
+        matches!(
+            option.value,
+            CommandOptionValue::SubCommand(_) | CommandOptionValue::SubCommandGroup(_)
+        )
+    }) else {
+        miette::bail!("no subcommand was invoked");
+    };
+
+    let Some(subcommand) = subcommands
+        .iter()
+        .find(|subcommand| subcommand.name() == option.name)
+    else {
+        miette::bail!("unknown subcommand `{}`", option.name);
+    };
+
+    let member_permissions = interaction
+        .member
+        .as_ref()
+        .and_then(|member| member.permissions)
+        .unwrap_or_default();
+    if !member_permissions.contains(subcommand.required_permissions()) {
+        interaction_client
+            .create_response(
+                interaction.id,
+                &interaction.token,
+                &InteractionResponse {
+                    kind: InteractionResponseType::ChannelMessageWithSource,
+                    data: Some(
+                        InteractionResponseDataBuilder::new()
+                            .content(localizer.error_error_insufficient_permissions()?)
+                            .build(),
+                    ),
+                },
+            )
+            .await
+            .into_diagnostic()?;
+
+        return Ok(());
+    }
+
+    subcommand
+        .execute(interaction, interaction_client, option.clone(), localizer)
+        .await
+}
@@ -0,0 +1,128 @@
+/*
+ * SPDX-License-Identifier: AGPL-3.0-only
+ *
+ * This file is part of HarTex.
+ *
+ * HarTex
+ * Copyright (c) 2021-2024 HarTex Project Developers
+ *
+ * HarTex is free software; you can redistribute it and/or modify
+ * it under the terms of the GNU Affero General Public License as published by
+ * the Free Software Foundation; either version 3 of the License, or
+ * (at your option) any later version.
+ *
+ * HarTex is distributed in the hope that it will be useful,
+ * but WITHOUT ANY WARRANTY; without even the implied warranty of
+ * MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+ * GNU Affero General Public License for more details.
+ *
+ * You should have received a copy of the GNU Affero General Public License along
+ * with HarTex. If not, see <https://www.gnu.org/licenses/>.
+ */
+
+//! # Command Specifications
+//!
+//! Registration specifications of commands, as declared with the `command` macro. These serialize
+//! to the same JSON as the specifications in `hartex-discord-commands-spec`.
+//!
+//! The specifications declare no localizations; these are the `command-*` Fluent messages, which
+//! the commands manager fills in when registering the commands.
+
+use hartex_discord_core::discord::model::application::command::CommandOptionType;
+use hartex_discord_core::discord::model::application::command::CommandOptionValue;
+use hartex_discord_core::discord::model::application::command::CommandType;
+use hartex_discord_core::discord::model::channel::ChannelType;
+use hartex_discord_core::discord::model::guild::Permissions;
+use serde::Serialize;
+use serde::Serializer;
+
+/// The registration specification of a command.
+#[allow(clippy::module_name_repetitions)]
+#[derive(Clone, Debug, Serialize)]
+pub struct CommandSpec {
+    pub name: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub description: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub contexts: Option<Vec<CommandContext>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub default_member_permissions: Option<Permissions>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub dm_permission: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub nsfw: Option<bool>,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub options: Vec<CommandOptionSpec>,
+    #[serde(rename = "type")]
+    pub kind: CommandType,
+}
+
+/// The registration specification of a command option.
+#[allow(clippy::module_name_repetitions)]
+#[derive(Clone, Debug, Serialize)]
+pub struct CommandOptionSpec {
+    pub name: String,
+    pub description: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub autocomplete: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub channel_types: Option<Vec<ChannelType>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub choices: Option<Vec<CommandOptionChoiceSpec>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub max_length: Option<u16>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub min_length: Option<u16>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub max_value: Option<CommandOptionValue>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub min_value: Option<CommandOptionValue>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub options: Option<Vec<CommandOptionSpec>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub required: Option<bool>,
+    #[serde(rename = "type")]
+    pub kind: CommandOptionType,
+}
+
+/// The registration specification of a command option choice.
+#[allow(clippy::module_name_repetitions)]
+#[derive(Clone, Debug, Serialize)]
+pub struct CommandOptionChoiceSpec {
+    pub name: String,
+    pub value: CommandOptionChoiceValue,
+}
+
+/// The value of a command option choice.
+#[derive(Clone, Debug, Serialize)]
+#[serde(untagged)]
+pub enum CommandOptionChoiceValue {
+    String(String),
+    Integer(i64),
+    Number(f64),
+}
+
+/// The interaction contexts a command can be used in.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum CommandContext {
+    /// The command can be used in guilds.
+    Guild,
+    /// The command can be used in direct messages with the bot.
+    BotDm,
+    /// The command can be used in group direct messages and direct messages other than with the
+    /// bot.
+    PrivateChannel,
+}
+
+impl Serialize for CommandContext {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        serializer.serialize_u8(match self {
+            Self::Guild => 0,
+            Self::BotDm => 1,
+            Self::PrivateChannel => 2,
+        })
+    }
+}
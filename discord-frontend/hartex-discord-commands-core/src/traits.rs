@@ -20,15 +20,25 @@
  * with HarTex. If not, see <https://www.gnu.org/licenses/>.
  */
 
+use std::sync::Arc;
+
 use async_trait::async_trait;
 use hartex_discord_configuration_provider::ConfigurationProvider;
+use hartex_discord_configuration_provider::PluginConfiguration;
 use hartex_discord_core::discord::http::client::InteractionClient;
+use hartex_discord_core::discord::model::application::interaction::application_command::CommandDataOption;
+use hartex_discord_core::discord::model::application::interaction::application_command::CommandOptionValue;
 use hartex_discord_core::discord::model::application::interaction::Interaction;
+use hartex_discord_core::discord::model::application::interaction::InteractionData;
 use hartex_discord_core::discord::model::guild::Permissions;
 use hartex_discord_core::discord::model::id::marker::GuildMarker;
 use hartex_discord_core::discord::model::id::Id;
 use hartex_localization_core::Localizer;
 
+use crate::middleware::Middleware;
+use crate::spec::CommandOptionSpec;
+use crate::spec::CommandSpec;
+
 /// The command metadata trait, specifying the various information about a command.
 pub trait CommandMetadata {
     /// The minimum permission level required for this command to be run.
@@ -36,11 +46,30 @@ pub trait CommandMetadata {
         Permissions::empty()
     }
 
+    /// The minimum permission level required for this command to be run, unless overridden by
+    /// the configuration of the guild.
+    fn minimum_permission_level(&self) -> u8 {
+        0
+    }
+
+    /// The middlewares specific to the command, run after those of its plugin.
+    fn middlewares(&self) -> Vec<Box<dyn Middleware + Send + Sync>> {
+        Vec::new()
+    }
+
     /// The name of the command.
     fn name(&self) -> String;
 
     /// The plugin the command belongs to.
     fn plugin(&self) -> Box<dyn Plugin + Send + Sync>;
+
+    /// The registration specification of the command.
+    fn spec(&self) -> CommandSpec;
+
+    /// The subcommands and subcommand groups of the command.
+    fn subcommands(&self) -> Vec<Box<dyn Subcommand + Send + Sync>> {
+        Vec::new()
+    }
 }
 
 /// The command trait, contains callbacks that are to be run before or when an interaction command
@@ -48,12 +77,90 @@ pub trait CommandMetadata {
 #[async_trait]
 pub trait Command: CommandMetadata {
     /// Executes the command.
+    ///
+    /// By default, this routes the invocation to the invoked subcommand or subcommand group.
+    async fn execute(
+        &self,
+        interaction: Interaction,
+        interaction_client: &InteractionClient<'_>,
+        localizer: Localizer<'_>,
+    ) -> miette::Result<()> {
+        let Some(InteractionData::ApplicationCommand(command)) = interaction.data.clone() else {
+            miette::bail!("interaction is not an application command");
+        };
+
+        crate::routing::route(
+            self.subcommands(),
+            interaction,
+            interaction_client,
+            &command.options,
+            localizer,
+        )
+        .await
+    }
+
+    /// Handles a message component interaction on a message sent by the command.
+    ///
+    /// Custom ids of the components are prefixed with the name of the command and a colon, by
+    /// which the interaction is routed to the command.
+    async fn handle_component(
+        &self,
+        _: Interaction,
+        _: &InteractionClient<'_>,
+        _: Localizer<'_>,
+    ) -> miette::Result<()> {
+        miette::bail!("command `{}` has no component handler", self.name());
+    }
+}
+
+/// The subcommand metadata trait, specifying the various information about a subcommand or a
+/// subcommand group.
+pub trait SubcommandMetadata {
+    /// The minimum permission level required for this subcommand to be run, in addition to that
+    /// of the command it belongs to.
+    fn required_permissions(&self) -> Permissions {
+        Permissions::empty()
+    }
+
+    /// The name of the subcommand.
+    fn name(&self) -> String;
+
+    /// The registration specification of the subcommand.
+    fn spec(&self) -> CommandOptionSpec;
+
+    /// The subcommands of the subcommand group.
+    fn subcommands(&self) -> Vec<Box<dyn Subcommand + Send + Sync>> {
+        Vec::new()
+    }
+}
+
+/// The subcommand trait, contains callbacks that are to be run when a subcommand or a subcommand
+/// group is invoked.
+#[async_trait]
+pub trait Subcommand: SubcommandMetadata {
+    /// Executes the subcommand.
+    ///
+    /// By default, this routes the invocation to the invoked subcommand of the subcommand group.
     async fn execute(
         &self,
         interaction: Interaction,
         interaction_client: &InteractionClient<'_>,
+        option: CommandDataOption,
         localizer: Localizer<'_>,
-    ) -> miette::Result<()>;
+    ) -> miette::Result<()> {
+        let CommandOptionValue::SubCommandGroup(options) = option.value else {
+            miette::bail!("subcommand `{}` has no handler", option.name);
+        };
+
+        crate::routing::route(
+            self.subcommands(),
+            interaction,
+            interaction_client,
+            &options,
+            localizer,
+        )
+        .await
+    }
 }
 
 /// The plugin metadata data specifying information about a plugin.
@@ -63,11 +170,64 @@ pub trait PluginMetadata {
 }
 
 /// The plugin trait, contains callbacks that are to be run before or when an interaction command is
-/// handled.
+/// handled, as well as lifecycle hooks of the plugin.
 #[async_trait]
 pub trait Plugin: PluginMetadata {
     /// Whether a given plugin is enabled.
     async fn enabled(&self, guild_id: Id<GuildMarker>) -> miette::Result<bool> {
-        ConfigurationProvider::plugin_enabled(guild_id, self.name()).await
+        ConfigurationProvider::plugin_enabled_cached(guild_id, self.name()).await
+    }
+
+    /// Whether the plugin can be enabled or disabled by the guild.
+    ///
+    /// Plugins that are always enabled should return `false`.
+    fn toggleable(&self) -> bool {
+        true
+    }
+
+    /// The middlewares applied to every command of the plugin.
+    fn middlewares(&self) -> Vec<Box<dyn Middleware + Send + Sync>> {
+        Vec::new()
+    }
+
+    /// Called when the plugin is enabled in a guild.
+    async fn on_enable(&self, _: Id<GuildMarker>) -> miette::Result<()> {
+        Ok(())
+    }
+
+    /// Called when the plugin is disabled in a guild.
+    async fn on_disable(&self, _: Id<GuildMarker>) -> miette::Result<()> {
+        Ok(())
+    }
+
+    /// Called when the configuration of a guild is changed, after the cached configuration of the
+    /// guild is invalidated.
+    async fn on_configuration_change(&self, _: Id<GuildMarker>) -> miette::Result<()> {
+        Ok(())
+    }
+
+    /// Called when the bot joins a guild.
+    async fn on_guild_join(&self, _: Id<GuildMarker>) -> miette::Result<()> {
+        Ok(())
+    }
+
+    /// Called when the bot leaves a guild.
+    async fn on_guild_leave(&self, _: Id<GuildMarker>) -> miette::Result<()> {
+        Ok(())
+    }
+}
+
+/// The configured plugin trait, providing typed access to the configuration section of a plugin.
+#[async_trait]
+pub trait ConfiguredPlugin: Plugin {
+    /// The type of the configuration section of the plugin.
+    type Configuration: PluginConfiguration + Send + Sync + 'static;
+
+    /// Returns the configuration section of the plugin for a certain guild.
+    async fn configuration(
+        &self,
+        guild_id: Id<GuildMarker>,
+    ) -> miette::Result<Arc<Self::Configuration>> {
+        ConfigurationProvider::plugin_configuration(guild_id).await
     }
 }
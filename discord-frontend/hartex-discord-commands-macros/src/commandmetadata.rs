@@ -21,103 +21,514 @@
  */
 
 use proc_macro2::TokenStream as TokenStream2;
+use syn::bracketed;
+use syn::parenthesized;
 use syn::parse::Parse;
 use syn::parse::ParseStream;
+use syn::punctuated::Punctuated;
 use syn::spanned::Spanned;
+use syn::token;
 use syn::Expr;
+use syn::ExprLit;
+use syn::ExprPath;
 use syn::Ident;
 use syn::ItemStruct;
 use syn::Lit;
+use syn::LitStr;
 use syn::Token;
 
-/// Represents input to the `metadata` derive macro.
-#[allow(dead_code)]
+/// The keys accepted by the `command` macro.
+const COMMAND_KEYS: [&str; 13] = [
+    "contexts",
+    "default_member_permissions",
+    "description",
+    "dm_permission",
+    "kind",
+    "middlewares",
+    "minimum_permission_level",
+    "name",
+    "nsfw",
+    "options",
+    "plugin",
+    "required_permissions",
+    "subcommands",
+];
+
+/// The keys accepted by command options.
+const OPTION_KEYS: [&str; 11] = [
+    "autocomplete",
+    "channel_types",
+    "choices",
+    "description",
+    "max_length",
+    "max_value",
+    "min_length",
+    "min_value",
+    "name",
+    "options",
+    "required",
+];
+
+/// The keys accepted by command option choices.
+const CHOICE_KEYS: [&str; 2] = ["name", "value"];
+
+/// The command option kinds, and their corresponding `CommandOptionType` variants.
+const OPTION_KINDS: [(&str, &str); 11] = [
+    ("attachment", "Attachment"),
+    ("boolean", "Boolean"),
+    ("channel", "Channel"),
+    ("integer", "Integer"),
+    ("mentionable", "Mentionable"),
+    ("number", "Number"),
+    ("role", "Role"),
+    ("string", "String"),
+    ("subcommand", "SubCommand"),
+    ("subcommand_group", "SubCommandGroup"),
+    ("user", "User"),
+];
+
+/// Represents input to the `command` attribute macro.
 pub struct CommandMetadataMacroInput {
-    pub(self) name_ident: Ident,
-    pub(self) equal3: Token![=],
-    pub(self) name_lit: Lit,
-    pub(self) comma1: Token![,],
-    pub(self) plugin_ident: Ident,
-    pub(self) equal1: Token![=],
-    pub(self) plugin_actual_ident: Ident,
-    pub(self) comma3: Option<Token![,]>,
-    pub(self) minimum_permission_level_ident: Option<Ident>,
-    pub(self) equal_2: Option<Token![=]>,
-    pub(self) minimum_permission_level: Option<Expr>,
-    pub(self) comma4: Option<Token![,]>,
+    pub(crate) arguments: Punctuated<Argument, Token![,]>,
 }
 
 impl Parse for CommandMetadataMacroInput {
     fn parse(input: ParseStream) -> syn::Result<Self> {
-        let mut result = Self {
-            name_ident: input.parse()?,
-            equal3: input.parse()?,
-            name_lit: input.parse()?,
-            comma1: input.parse()?,
-            plugin_ident: input.parse()?,
-            equal1: input.parse()?,
-            plugin_actual_ident: input.parse()?,
-            comma3: None,
-            minimum_permission_level_ident: None,
-            equal_2: None,
-            minimum_permission_level: None,
-            comma4: None,
-        };
+        Ok(Self {
+            arguments: Punctuated::parse_terminated(input)?,
+        })
+    }
+}
 
-        let Some(comma_3) = input.parse().ok() else {
-            return Ok(result);
-        };
+/// A `key = value` argument.
+#[allow(dead_code)]
+pub struct Argument {
+    pub(self) key: Ident,
+    pub(self) equal: Token![=],
+    pub(self) value: ArgumentValue,
+}
 
-        result.comma3.replace(comma_3);
-        result.minimum_permission_level_ident = input.parse().ok();
-        result.equal_2 = input.parse().ok();
-        result.comma4 = input.parse().ok();
+impl Parse for Argument {
+    fn parse(input: ParseStream) -> syn::Result<Self> {
+        Ok(Self {
+            key: input.parse()?,
+            equal: input.parse()?,
+            value: input.parse()?,
+        })
+    }
+}
 
-        Ok(result)
+/// The value of an argument.
+pub enum ArgumentValue {
+    /// An expression, such as a literal or a path.
+    Expr(Expr),
+    /// A bracketed list.
+    List(Punctuated<ListElement, Token![,]>),
+}
+
+impl Parse for ArgumentValue {
+    fn parse(input: ParseStream) -> syn::Result<Self> {
+        if input.peek(token::Bracket) {
+            let content;
+            bracketed!(content in input);
+
+            return Ok(Self::List(Punctuated::parse_terminated(&content)?));
+        }
+
+        Ok(Self::Expr(input.parse()?))
     }
 }
 
-/// Returns the token stream for generating the `CommandMetadata` trait implementation
-#[allow(clippy::too_many_lines)]
-pub fn implement_metadata(
-    parameters: &CommandMetadataMacroInput,
-    struct_item: &ItemStruct,
-) -> Option<TokenStream2> {
-    if parameters.name_ident != "name" {
-        parameters
-            .name_ident
-            .span()
-            .unwrap()
-            .error("expected `name`")
-            .emit();
+/// An element of a bracketed list.
+pub enum ListElement {
+    /// A `kind(key = value, ...)` call, used for options and choices.
+    Call(Ident, Punctuated<Argument, Token![,]>),
+    /// An expression.
+    Expr(Expr),
+}
 
-        return None;
+impl Parse for ListElement {
+    fn parse(input: ParseStream) -> syn::Result<Self> {
+        if input.peek(Ident) && input.peek2(token::Paren) {
+            let ident = input.parse()?;
+            let content;
+            parenthesized!(content in input);
+
+            return Ok(Self::Call(ident, Punctuated::parse_terminated(&content)?));
+        }
+
+        Ok(Self::Expr(input.parse()?))
+    }
+}
+
+/// Emits an error at the span of an item.
+pub(crate) fn error<T: Spanned>(item: &T, message: &str) {
+    item.span().unwrap().error(message).emit();
+}
+
+/// Checks that every argument has an accepted key and appears at most once.
+pub(crate) fn check_keys(
+    arguments: &Punctuated<Argument, Token![,]>,
+    accepted: &[&str],
+) -> Option<()> {
+    let mut seen = Vec::new();
+    for argument in arguments {
+        let key = argument.key.to_string();
+        if !accepted.contains(&key.as_str()) {
+            argument
+                .key
+                .span()
+                .unwrap()
+                .error(format!("unexpected key `{key}`"))
+                .help(format!("expected one of: {}", accepted.join(", ")))
+                .emit();
+
+            return None;
+        }
+
+        if seen.contains(&key) {
+            error(&argument.key, "duplicate key");
+
+            return None;
+        }
+
+        seen.push(key);
+    }
+
+    Some(())
+}
+
+/// Finds the value of an argument by its key.
+pub(crate) fn find<'a>(
+    arguments: &'a Punctuated<Argument, Token![,]>,
+    key: &str,
+) -> Option<&'a ArgumentValue> {
+    arguments
+        .iter()
+        .find(|argument| argument.key == key)
+        .map(|argument| &argument.value)
+}
+
+/// Expects an argument value to be an expression.
+pub(crate) fn expect_expr(value: &ArgumentValue) -> Option<&Expr> {
+    match value {
+        ArgumentValue::Expr(expr) => Some(expr),
+        ArgumentValue::List(list) => {
+            error(list, "expected expression, found list");
+
+            None
+        }
+    }
+}
+
+/// Expects an argument value to be a list.
+pub(crate) fn expect_list(value: &ArgumentValue) -> Option<&Punctuated<ListElement, Token![,]>> {
+    match value {
+        ArgumentValue::List(list) => Some(list),
+        ArgumentValue::Expr(expr) => {
+            error(expr, "expected list");
+
+            None
+        }
+    }
+}
+
+/// Expects an argument value to be a string literal.
+pub(crate) fn expect_lit_str(value: &ArgumentValue) -> Option<LitStr> {
+    if let Expr::Lit(ExprLit {
+        lit: Lit::Str(lit_str),
+        ..
+    }) = expect_expr(value)?
+    {
+        return Some(lit_str.clone());
+    }
+
+    error(value_span(value), "expected string");
+
+    None
+}
+
+/// Returns a spanned item of an argument value.
+pub(crate) fn value_span(value: &ArgumentValue) -> &dyn Spanned {
+    match value {
+        ArgumentValue::Expr(expr) => expr,
+        ArgumentValue::List(list) => list,
+    }
+}
+
+/// Returns an optional expression for an optional argument.
+pub(crate) fn optional_expr(value: Option<&ArgumentValue>) -> Option<TokenStream2> {
+    let Some(value) = value else {
+        return Some(quote::quote! { None });
+    };
+
+    let expr = expect_expr(value)?;
+    Some(quote::quote! { Some(#expr) })
+}
+
+/// Returns an optional string expression for an optional argument.
+pub(crate) fn optional_string(value: Option<&ArgumentValue>) -> Option<TokenStream2> {
+    let Some(value) = value else {
+        return Some(quote::quote! { None });
+    };
+
+    let lit_str = expect_lit_str(value)?;
+    Some(quote::quote! { Some(String::from(#lit_str)) })
+}
+
+/// Returns a spanned item of a list element.
+pub(crate) fn element_span(element: &ListElement) -> &dyn Spanned {
+    match element {
+        ListElement::Call(ident, _) => ident,
+        ListElement::Expr(expr) => expr,
     }
+}
+
+/// Prefixes a bare identifier with a path, leaving other expressions untouched.
+fn prefixed(expr: &Expr, prefix: &TokenStream2) -> TokenStream2 {
+    if let Expr::Path(ExprPath { path, .. }) = expr {
+        if let Some(ident) = path.get_ident() {
+            return quote::quote! { #prefix::#ident };
+        }
+    }
+
+    quote::quote! { #expr }
+}
+
+/// Returns the `subcommands` function for a list of subcommands.
+pub(crate) fn subcommands_function(value: &ArgumentValue) -> Option<TokenStream2> {
+    let subcommands = expect_list(value)?
+        .iter()
+        .map(|element| {
+            let ListElement::Expr(expr) = element else {
+                error(element_span(element), "expected subcommand");
+
+                return None;
+            };
+
+            Some(quote::quote! { Box::new(#expr) })
+        })
+        .collect::<Option<Vec<_>>>()?;
 
-    if parameters.plugin_ident != "plugin" {
-        parameters
-            .plugin_ident
-            .span()
+    Some(quote::quote! {
+        fn subcommands(&self) -> Vec<Box<dyn _commands_core::traits::Subcommand + Send + Sync>> {
+            vec![#(#subcommands),*]
+        }
+    })
+}
+
+/// Returns the expression for a list of command options.
+pub(crate) fn options(value: &ArgumentValue) -> Option<Vec<TokenStream2>> {
+    expect_list(value)?
+        .iter()
+        .map(|element| {
+            let ListElement::Call(kind, arguments) = element else {
+                error(element_span(element), "expected `kind(...)`");
+
+                return None;
+            };
+
+            option(kind, arguments)
+        })
+        .collect()
+}
+
+/// Returns the expression for a command option.
+fn option(kind: &Ident, arguments: &Punctuated<Argument, Token![,]>) -> Option<TokenStream2> {
+    let Some((_, variant)) = OPTION_KINDS.iter().find(|(name, _)| kind == name) else {
+        kind.span()
             .unwrap()
-            .error("expected `plugin`")
+            .error("unknown option kind")
+            .help(format!(
+                "expected one of: {}",
+                OPTION_KINDS.map(|(name, _)| name).join(", ")
+            ))
             .emit();
 
         return None;
-    }
+    };
+    let variant = Ident::new(variant, kind.span());
+
+    check_keys(arguments, &OPTION_KEYS)?;
+
+    let Some(name) = find(arguments, "name") else {
+        error(kind, "missing `name`");
+
+        return None;
+    };
+    let name = expect_lit_str(name)?;
+    let Some(description) = find(arguments, "description") else {
+        error(kind, "missing `description`");
+
+        return None;
+    };
+    let description = expect_lit_str(description)?;
+
+    let autocomplete = optional_expr(find(arguments, "autocomplete"))?;
+    let max_length = optional_expr(find(arguments, "max_length"))?;
+    let min_length = optional_expr(find(arguments, "min_length"))?;
+    let required = optional_expr(find(arguments, "required"))?;
+
+    let value_variant = if kind == "number" {
+        quote::quote! { Number }
+    } else {
+        quote::quote! { Integer }
+    };
+    let value = |value: Option<&ArgumentValue>| {
+        let Some(value) = value else {
+            return Some(quote::quote! { None });
+        };
+
+        let expr = expect_expr(value)?;
+        let expr = if kind == "number" {
+            quote::quote! { f64::from(#expr) }
+        } else {
+            quote::quote! { #expr }
+        };
+
+        Some(quote::quote! {
+            Some(hartex_discord_core::discord::model::application::command::CommandOptionValue::#value_variant(#expr))
+        })
+    };
+    let max_value = value(find(arguments, "max_value"))?;
+    let min_value = value(find(arguments, "min_value"))?;
+
+    let channel_types = match find(arguments, "channel_types") {
+        Some(value) => {
+            let prefix =
+                quote::quote! { hartex_discord_core::discord::model::channel::ChannelType };
+            let channel_types = expect_list(value)?
+                .iter()
+                .map(|element| {
+                    let ListElement::Expr(expr) = element else {
+                        error(element_span(element), "expected channel type");
+
+                        return None;
+                    };
+
+                    Some(prefixed(expr, &prefix))
+                })
+                .collect::<Option<Vec<_>>>()?;
+
+            quote::quote! { Some(vec![#(#channel_types),*]) }
+        }
+        None => quote::quote! { None },
+    };
+
+    let choices = match find(arguments, "choices") {
+        Some(value) => {
+            let choices = expect_list(value)?
+                .iter()
+                .map(|element| {
+                    let ListElement::Call(ident, arguments) = element else {
+                        error(element_span(element), "expected `choice(...)`");
+
+                        return None;
+                    };
+
+                    if ident != "choice" {
+                        error(ident, "expected `choice`");
+
+                        return None;
+                    }
+
+                    choice(ident, arguments)
+                })
+                .collect::<Option<Vec<_>>>()?;
+
+            quote::quote! { Some(vec![#(#choices),*]) }
+        }
+        None => quote::quote! { None },
+    };
+
+    let options = match find(arguments, "options") {
+        Some(value) => {
+            let options = options(value)?;
+            quote::quote! { Some(vec![#(#options),*]) }
+        }
+        None => quote::quote! { None },
+    };
+
+    Some(quote::quote! {
+        _commands_core::spec::CommandOptionSpec {
+            name: String::from(#name),
+            description: String::from(#description),
+            autocomplete: #autocomplete,
+            channel_types: #channel_types,
+            choices: #choices,
+            max_length: #max_length,
+            min_length: #min_length,
+            max_value: #max_value,
+            min_value: #min_value,
+            options: #options,
+            required: #required,
+            kind: hartex_discord_core::discord::model::application::command::CommandOptionType::#variant,
+        }
+    })
+}
+
+/// Returns the expression for a command option choice.
+fn choice(ident: &Ident, arguments: &Punctuated<Argument, Token![,]>) -> Option<TokenStream2> {
+    check_keys(arguments, &CHOICE_KEYS)?;
+
+    let Some(name) = find(arguments, "name") else {
+        error(ident, "missing `name`");
+
+        return None;
+    };
+    let name = expect_lit_str(name)?;
+
+    let Some(value) = find(arguments, "value") else {
+        error(ident, "missing `value`");
+
+        return None;
+    };
+    let value = match expect_expr(value)? {
+        Expr::Lit(ExprLit {
+            lit: Lit::Str(lit_str),
+            ..
+        }) => quote::quote! { String(String::from(#lit_str)) },
+        Expr::Lit(ExprLit {
+            lit: Lit::Int(lit_int),
+            ..
+        }) => quote::quote! { Integer(#lit_int) },
+        Expr::Lit(ExprLit {
+            lit: Lit::Float(lit_float),
+            ..
+        }) => quote::quote! { Number(#lit_float) },
+        expr => {
+            error(expr, "expected string, integer or float literal");
+
+            return None;
+        }
+    };
+
+    Some(quote::quote! {
+        _commands_core::spec::CommandOptionChoiceSpec {
+            name: String::from(#name),
+            value: _commands_core::spec::CommandOptionChoiceValue::#value,
+        }
+    })
+}
+
+/// Returns the token stream for generating the `CommandMetadata` trait implementation
+#[allow(clippy::too_many_lines)]
+pub fn implement_metadata(
+    parameters: &CommandMetadataMacroInput,
+    struct_item: &ItemStruct,
+) -> Option<TokenStream2> {
+    let arguments = &parameters.arguments;
+    check_keys(arguments, &COMMAND_KEYS)?;
 
     let mut functions = TokenStream2::new();
 
     // name = ?
-    let Lit::Str(name) = parameters.name_lit.clone() else {
-        parameters
-            .name_lit
-            .span()
-            .unwrap()
-            .error("expected string")
-            .emit();
+    let Some(name) = find(arguments, "name") else {
+        error(&struct_item.ident, "missing `name`");
 
         return None;
     };
+    let name = expect_lit_str(name)?;
     let expanded = quote::quote! {
         fn name(&self) -> String {
             String::from(#name)
@@ -126,40 +537,55 @@ pub fn implement_metadata(
     functions.extend(expanded);
 
     // plugin = ?
-    let plugin_ident = parameters.plugin_actual_ident.clone();
+    let Some(plugin) = find(arguments, "plugin") else {
+        error(&struct_item.ident, "missing `plugin`");
+
+        return None;
+    };
+    let plugin = expect_expr(plugin)?;
     let expanded = quote::quote! {
         fn plugin(&self) -> Box<dyn _commands_core::traits::Plugin + Send + Sync> {
-            Box::new(#plugin_ident)
+            Box::new(#plugin)
         }
     };
     functions.extend(expanded);
 
-    // minimum_permission_level = ?
-    if let Some(minimum_permission_level_ident) = parameters.minimum_permission_level_ident.clone()
-        && minimum_permission_level_ident == "required_permissions"
-    {
-        let Some(_) = parameters.equal_2 else {
-            parameters
-                .equal_2
-                .span()
-                .unwrap()
-                .error("expected equal")
-                .emit();
+    // middlewares = [?]
+    if let Some(middlewares) = find(arguments, "middlewares") {
+        let middlewares = expect_list(middlewares)?
+            .iter()
+            .map(|element| {
+                let ListElement::Expr(expr) = element else {
+                    error(element_span(element), "expected middleware");
 
-            return None;
-        };
+                    return None;
+                };
 
-        let Some(Expr::Binary(expr)) = parameters.minimum_permission_level.clone() else {
-            parameters
-                .minimum_permission_level
-                .span()
-                .unwrap()
-                .error("expected expression")
-                .emit();
+                Some(quote::quote! { Box::new(#expr) })
+            })
+            .collect::<Option<Vec<_>>>()?;
+        let expanded = quote::quote! {
+            fn middlewares(&self) -> Vec<Box<dyn _commands_core::middleware::Middleware + Send + Sync>> {
+                vec![#(#middlewares),*]
+            }
+        };
+        functions.extend(expanded);
+    }
 
-            return None;
+    // minimum_permission_level = ?
+    if let Some(minimum_permission_level) = find(arguments, "minimum_permission_level") {
+        let expr = expect_expr(minimum_permission_level)?;
+        let expanded = quote::quote! {
+            fn minimum_permission_level(&self) -> u8 {
+                #expr
+            }
         };
+        functions.extend(expanded);
+    }
 
+    // required_permissions = ?
+    if let Some(required_permissions) = find(arguments, "required_permissions") {
+        let expr = expect_expr(required_permissions)?;
         let expanded = quote::quote! {
             fn required_permissions(&self) -> Permissions {
                 #expr
@@ -168,6 +594,87 @@ pub fn implement_metadata(
         functions.extend(expanded);
     }
 
+    // the registration specification
+    let description = optional_string(find(arguments, "description"))?;
+    let default_member_permissions = optional_expr(find(arguments, "default_member_permissions"))?;
+    let dm_permission = optional_expr(find(arguments, "dm_permission"))?;
+    let nsfw = optional_expr(find(arguments, "nsfw"))?;
+
+    let contexts = match find(arguments, "contexts") {
+        Some(value) => {
+            let prefix = quote::quote! { _commands_core::spec::CommandContext };
+            let contexts = expect_list(value)?
+                .iter()
+                .map(|element| {
+                    let ListElement::Expr(expr) = element else {
+                        error(element_span(element), "expected context");
+
+                        return None;
+                    };
+
+                    Some(prefixed(expr, &prefix))
+                })
+                .collect::<Option<Vec<_>>>()?;
+
+            quote::quote! { Some(vec![#(#contexts),*]) }
+        }
+        None => quote::quote! { None },
+    };
+
+    let kind = match find(arguments, "kind") {
+        Some(value) => prefixed(
+            expect_expr(value)?,
+            &quote::quote! { hartex_discord_core::discord::model::application::command::CommandType },
+        ),
+        None => quote::quote! {
+            hartex_discord_core::discord::model::application::command::CommandType::ChatInput
+        },
+    };
+
+    let options = match find(arguments, "options") {
+        Some(value) => options(value)?,
+        None => Vec::new(),
+    };
+
+    // subcommands = ?
+    if let Some(subcommands) = find(arguments, "subcommands") {
+        if let Some(options) = find(arguments, "options") {
+            error(
+                value_span(options),
+                "`options` cannot be declared together with `subcommands`",
+            );
+
+            return None;
+        }
+
+        functions.extend(subcommands_function(subcommands)?);
+    }
+
+    let expanded = quote::quote! {
+        fn spec(&self) -> _commands_core::spec::CommandSpec {
+            _commands_core::spec::CommandSpec {
+                name: String::from(#name),
+                description: #description,
+                contexts: #contexts,
+                default_member_permissions: #default_member_permissions,
+                dm_permission: #dm_permission,
+                nsfw: #nsfw,
+                options: vec![#(#options),*]
+                    .into_iter()
+                    .chain(
+                        _commands_core::traits::CommandMetadata::subcommands(self)
+                            .iter()
+                            .map(|subcommand| {
+                                _commands_core::traits::SubcommandMetadata::spec(subcommand.as_ref())
+                            }),
+                    )
+                    .collect(),
+                kind: #kind,
+            }
+        }
+    };
+    functions.extend(expanded);
+
     let core_use = quote::quote! {
         extern crate hartex_discord_commands_core as _commands_core;
 
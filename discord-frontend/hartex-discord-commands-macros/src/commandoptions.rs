@@ -0,0 +1,200 @@
+/*
+ * SPDX-License-Identifier: AGPL-3.0-only
+ *
+ * This file is part of HarTex.
+ *
+ * HarTex
+ * Copyright (c) 2021-2024 HarTex Project Developers
+ *
+ * HarTex is free software; you can redistribute it and/or modify
+ * it under the terms of the GNU Affero General Public License as published by
+ * the Free Software Foundation; either version 3 of the License, or
+ * (at your option) any later version.
+ *
+ * HarTex is distributed in the hope that it will be useful,
+ * but WITHOUT ANY WARRANTY; without even the implied warranty of
+ * MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+ * GNU Affero General Public License for more details.
+ *
+ * You should have received a copy of the GNU Affero General Public License along
+ * with HarTex. If not, see <https://www.gnu.org/licenses/>.
+ */
+
+use proc_macro2::TokenStream as TokenStream2;
+use syn::spanned::Spanned;
+use syn::Data;
+use syn::DeriveInput;
+use syn::Expr;
+use syn::Fields;
+use syn::GenericArgument;
+use syn::LitStr;
+use syn::PathArguments;
+use syn::Type;
+use syn::TypePath;
+
+/// The attributes of a field.
+struct FieldAttributes {
+    name: Option<LitStr>,
+    min: Option<Expr>,
+    max: Option<Expr>,
+}
+
+/// Parses the `option` attributes of a field.
+fn field_attributes(field: &syn::Field) -> Option<FieldAttributes> {
+    let mut attributes = FieldAttributes {
+        name: None,
+        min: None,
+        max: None,
+    };
+
+    for attribute in field
+        .attrs
+        .iter()
+        .filter(|attribute| attribute.path().is_ident("option"))
+    {
+        let result = attribute.parse_nested_meta(|meta| {
+            if meta.path.is_ident("name") {
+                attributes.name = Some(meta.value()?.parse()?);
+            } else if meta.path.is_ident("min") {
+                attributes.min = Some(meta.value()?.parse()?);
+            } else if meta.path.is_ident("max") {
+                attributes.max = Some(meta.value()?.parse()?);
+            } else {
+                return Err(meta.error("expected `name`, `min` or `max`"));
+            }
+
+            Ok(())
+        });
+
+        if let Err(error) = result {
+            error.span().unwrap().error(error.to_string()).emit();
+
+            return None;
+        }
+    }
+
+    Some(attributes)
+}
+
+/// Returns the inner type if the type is an `Option`.
+fn option_inner(ty: &Type) -> Option<&Type> {
+    let Type::Path(TypePath { qself: None, path }) = ty else {
+        return None;
+    };
+
+    let segment = path.segments.last()?;
+    if segment.ident != "Option" {
+        return None;
+    }
+
+    let PathArguments::AngleBracketed(arguments) = &segment.arguments else {
+        return None;
+    };
+
+    match arguments.args.first()? {
+        GenericArgument::Type(inner) if arguments.args.len() == 1 => Some(inner),
+        _ => None,
+    }
+}
+
+/// Returns the token stream for generating the `CommandOptions` trait implementation
+pub fn implement_command_options(input: &DeriveInput) -> Option<TokenStream2> {
+    let Data::Struct(data) = &input.data else {
+        input
+            .ident
+            .span()
+            .unwrap()
+            .error("`CommandOptions` can only be derived for structs")
+            .emit();
+
+        return None;
+    };
+
+    let Fields::Named(fields) = &data.fields else {
+        data.fields
+            .span()
+            .unwrap()
+            .error("`CommandOptions` can only be derived for structs with named fields")
+            .emit();
+
+        return None;
+    };
+
+    let mut extractions = Vec::new();
+    let mut idents = Vec::new();
+    for field in &fields.named {
+        let ident = field.ident.clone().unwrap();
+        let attributes = field_attributes(field)?;
+        let name = attributes.name.unwrap_or_else(|| {
+            let name = ident.to_string();
+            LitStr::new(name.trim_start_matches("r#"), ident.span())
+        });
+
+        let (extraction, value_ty) = match option_inner(&field.ty) {
+            Some(inner) => (
+                quote::quote! {
+                    let #ident = _commands_core::options::optional::<#inner>(options, resolved, #name)?;
+                },
+                inner,
+            ),
+            None => {
+                let ty = &field.ty;
+                (
+                    quote::quote! {
+                        let #ident = _commands_core::options::required::<#ty>(options, resolved, #name)?;
+                    },
+                    ty,
+                )
+            }
+        };
+        extractions.push(extraction);
+
+        if attributes.min.is_some() || attributes.max.is_some() {
+            let min = attributes.min.map_or_else(
+                || quote::quote! { None },
+                |min| quote::quote! { Some(#min) },
+            );
+            let max = attributes.max.map_or_else(
+                || quote::quote! { None },
+                |max| quote::quote! { Some(#max) },
+            );
+
+            let check = if option_inner(&field.ty).is_some() {
+                quote::quote! {
+                    if let Some(value) = &#ident {
+                        _commands_core::options::check_range::<#value_ty>(#name, value, #min, #max)?;
+                    }
+                }
+            } else {
+                quote::quote! {
+                    _commands_core::options::check_range::<#value_ty>(#name, &#ident, #min, #max)?;
+                }
+            };
+            extractions.push(check);
+        }
+
+        idents.push(ident);
+    }
+
+    let ident = &input.ident;
+    let (impl_generics, ty_generics, where_clause) = input.generics.split_for_impl();
+    Some(quote::quote! {
+        const _: () = {
+            extern crate hartex_discord_commands_core as _commands_core;
+
+            #[automatically_derived]
+            impl #impl_generics _commands_core::options::CommandOptions for #ident #ty_generics #where_clause {
+                fn from_options(
+                    options: &[hartex_discord_core::discord::model::application::interaction::application_command::CommandDataOption],
+                    resolved: Option<&hartex_discord_core::discord::model::application::interaction::InteractionDataResolved>,
+                ) -> Result<Self, _commands_core::options::CommandOptionsError> {
+                    #(#extractions)*
+
+                    Ok(Self {
+                        #(#idents),*
+                    })
+                }
+            }
+        };
+    })
+}
@@ -35,10 +35,13 @@ extern crate proc_macro;
 
 use proc_macro::TokenStream;
 use syn::parse_macro_input;
+use syn::DeriveInput;
 use syn::ItemStruct;
 
 mod commandmetadata;
+mod commandoptions;
 mod pluginmetadata;
+mod subcommandmetadata;
 
 /// Macro to implement the `CommandMetadata` trait.
 #[proc_macro_attribute]
@@ -50,6 +53,15 @@ pub fn command(tokens: TokenStream, item: TokenStream) -> TokenStream {
         .into()
 }
 
+/// Macro to implement the `CommandOptions` trait.
+#[proc_macro_derive(CommandOptions, attributes(option))]
+pub fn command_options(tokens: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(tokens as DeriveInput);
+    commandoptions::implement_command_options(&input)
+        .unwrap_or_default()
+        .into()
+}
+
 /// Macro to implement the `PluginMetadata` trait.
 #[proc_macro_attribute]
 pub fn plugin(tokens: TokenStream, item: TokenStream) -> TokenStream {
@@ -59,3 +71,23 @@ pub fn plugin(tokens: TokenStream, item: TokenStream) -> TokenStream {
         .unwrap_or_default()
         .into()
 }
+
+/// Macro to implement the `SubcommandMetadata` trait for a subcommand.
+#[proc_macro_attribute]
+pub fn subcommand(tokens: TokenStream, item: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(tokens as commandmetadata::CommandMetadataMacroInput);
+    let struct_decl = parse_macro_input!(item as ItemStruct);
+    subcommandmetadata::implement_metadata(&input, &struct_decl, false)
+        .unwrap_or_default()
+        .into()
+}
+
+/// Macro to implement the `SubcommandMetadata` trait for a subcommand group.
+#[proc_macro_attribute]
+pub fn subcommand_group(tokens: TokenStream, item: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(tokens as commandmetadata::CommandMetadataMacroInput);
+    let struct_decl = parse_macro_input!(item as ItemStruct);
+    subcommandmetadata::implement_metadata(&input, &struct_decl, true)
+        .unwrap_or_default()
+        .into()
+}
@@ -0,0 +1,161 @@
+/*
+ * SPDX-License-Identifier: AGPL-3.0-only
+ *
+ * This file is part of HarTex.
+ *
+ * HarTex
+ * Copyright (c) 2021-2024 HarTex Project Developers
+ *
+ * HarTex is free software; you can redistribute it and/or modify
+ * it under the terms of the GNU Affero General Public License as published by
+ * the Free Software Foundation; either version 3 of the License, or
+ * (at your option) any later version.
+ *
+ * HarTex is distributed in the hope that it will be useful,
+ * but WITHOUT ANY WARRANTY; without even the implied warranty of
+ * MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+ * GNU Affero General Public License for more details.
+ *
+ * You should have received a copy of the GNU Affero General Public License along
+ * with HarTex. If not, see <https://www.gnu.org/licenses/>.
+ */
+
+use proc_macro2::TokenStream as TokenStream2;
+use syn::ItemStruct;
+
+use crate::commandmetadata::check_keys;
+use crate::commandmetadata::error;
+use crate::commandmetadata::expect_expr;
+use crate::commandmetadata::expect_lit_str;
+use crate::commandmetadata::find;
+use crate::commandmetadata::options;
+use crate::commandmetadata::subcommands_function;
+use crate::commandmetadata::CommandMetadataMacroInput;
+
+/// The keys accepted by the `subcommand` macro.
+const SUBCOMMAND_KEYS: [&str; 4] = ["description", "name", "options", "required_permissions"];
+
+/// The keys accepted by the `subcommand_group` macro.
+const SUBCOMMAND_GROUP_KEYS: [&str; 4] =
+    ["description", "name", "required_permissions", "subcommands"];
+
+/// Returns the token stream for generating the `SubcommandMetadata` trait implementation
+///
+/// Subcommand groups declare their subcommands instead of options.
+pub fn implement_metadata(
+    parameters: &CommandMetadataMacroInput,
+    struct_item: &ItemStruct,
+    group: bool,
+) -> Option<TokenStream2> {
+    let arguments = &parameters.arguments;
+    check_keys(
+        arguments,
+        if group {
+            &SUBCOMMAND_GROUP_KEYS
+        } else {
+            &SUBCOMMAND_KEYS
+        },
+    )?;
+
+    let mut functions = TokenStream2::new();
+
+    // name = ?
+    let Some(name) = find(arguments, "name") else {
+        error(&struct_item.ident, "missing `name`");
+
+        return None;
+    };
+    let name = expect_lit_str(name)?;
+    let expanded = quote::quote! {
+        fn name(&self) -> String {
+            String::from(#name)
+        }
+    };
+    functions.extend(expanded);
+
+    // required_permissions = ?
+    if let Some(required_permissions) = find(arguments, "required_permissions") {
+        let expr = expect_expr(required_permissions)?;
+        let expanded = quote::quote! {
+            fn required_permissions(&self) -> hartex_discord_core::discord::model::guild::Permissions {
+                #expr
+            }
+        };
+        functions.extend(expanded);
+    }
+
+    // the registration specification
+    let Some(description) = find(arguments, "description") else {
+        error(&struct_item.ident, "missing `description`");
+
+        return None;
+    };
+    let description = expect_lit_str(description)?;
+
+    let (options, kind) = if group {
+        let Some(subcommands) = find(arguments, "subcommands") else {
+            error(&struct_item.ident, "missing `subcommands`");
+
+            return None;
+        };
+        functions.extend(subcommands_function(subcommands)?);
+
+        (
+            quote::quote! {
+                Some(
+                    _commands_core::traits::SubcommandMetadata::subcommands(self)
+                        .iter()
+                        .map(|subcommand| {
+                            _commands_core::traits::SubcommandMetadata::spec(subcommand.as_ref())
+                        })
+                        .collect(),
+                )
+            },
+            quote::quote! { SubCommandGroup },
+        )
+    } else {
+        let options = match find(arguments, "options") {
+            Some(value) => {
+                let options = options(value)?;
+                quote::quote! { Some(vec![#(#options),*]) }
+            }
+            None => quote::quote! { None },
+        };
+
+        (options, quote::quote! { SubCommand })
+    };
+
+    let expanded = quote::quote! {
+        fn spec(&self) -> _commands_core::spec::CommandOptionSpec {
+            _commands_core::spec::CommandOptionSpec {
+                name: String::from(#name),
+                description: String::from(#description),
+                autocomplete: None,
+                channel_types: None,
+                choices: None,
+                max_length: None,
+                min_length: None,
+                max_value: None,
+                min_value: None,
+                options: #options,
+                required: None,
+                kind: hartex_discord_core::discord::model::application::command::CommandOptionType::#kind,
+            }
+        }
+    };
+    functions.extend(expanded);
+
+    let ident = struct_item.ident.clone();
+    Some(quote::quote! {
+        #struct_item
+
+        const _: () = {
+            extern crate hartex_discord_commands_core as _commands_core;
+
+            #[automatically_derived]
+            impl _commands_core::traits::SubcommandMetadata for #ident {
+                #functions
+            }
+        };
+    })
+}
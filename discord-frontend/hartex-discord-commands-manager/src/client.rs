@@ -0,0 +1,354 @@
+/*
+ * SPDX-License-Identifier: AGPL-3.0-only
+ *
+ * This file is part of HarTex.
+ *
+ * HarTex
+ * Copyright (c) 2021-2024 HarTex Project Developers
+ *
+ * HarTex is free software; you can redistribute it and/or modify
+ * it under the terms of the GNU Affero General Public License as published by
+ * the Free Software Foundation; either version 3 of the License, or
+ * (at your option) any later version.
+ *
+ * HarTex is distributed in the hope that it will be useful,
+ * but WITHOUT ANY WARRANTY; without even the implied warranty of
+ * MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+ * GNU Affero General Public License for more details.
+ *
+ * You should have received a copy of the GNU Affero General Public License along
+ * with HarTex. If not, see <https://www.gnu.org/licenses/>.
+ */
+
+//! # Discord API Client
+//!
+//! The HTTP client through which every subcommand of the commands manager makes requests to the
+//! Discord API.
+
+use std::env;
+use std::io::Read;
+use std::time::Duration;
+
+use clap::ArgMatches;
+use hartex_discord_core::tokio::io::AsyncRead;
+use hartex_discord_core::tokio::io::AsyncWrite;
+use hartex_discord_core::tokio::net::TcpStream;
+use hartex_discord_core::tokio::task::spawn;
+use hartex_discord_core::tokio::time::sleep;
+use hartex_discord_utils::hyper::tls_stream_to;
+use hartex_log::log;
+use http_body_util::BodyExt;
+use http_body_util::Full;
+use hyper::body::Buf;
+use hyper::body::Bytes;
+use hyper::client::conn::http1::handshake;
+use hyper::client::conn::http1::SendRequest;
+use hyper::header::ACCEPT;
+use hyper::header::AUTHORIZATION;
+use hyper::header::CONTENT_LENGTH;
+use hyper::header::CONTENT_TYPE;
+use hyper::header::HOST;
+use hyper::header::RETRY_AFTER;
+use hyper::header::USER_AGENT;
+use hyper::HeaderMap;
+use hyper::Method;
+use hyper::Request;
+use hyper::StatusCode;
+use hyper::Uri;
+use hyper_util::rt::TokioIo;
+use miette::IntoDiagnostic;
+use miette::MietteDiagnostic;
+use miette::Report;
+use serde::Deserialize;
+use serde_json::Value;
+
+/// The base URL of the Discord API used unless another is configured.
+const DEFAULT_BASE_URL: &str = "https://discord.com/api/v10";
+
+/// The maximum number of times a rate limited request is retried.
+const MAX_RETRIES: u32 = 5;
+
+/// The user agent of the requests.
+const USER_AGENT_VALUE: &str =
+    "DiscordBot (https://github.com/TeamHarTex/HarTex, v0.6.0) CommandsManager";
+
+/// A client of the Discord API.
+pub struct Client {
+    application_id: String,
+    base_path: String,
+    host: String,
+    port: u16,
+    sender: Option<SendRequest<Full<Bytes>>>,
+    tls: bool,
+    token: String,
+    /// The time to wait before the next request, when the rate limit bucket of the previous
+    /// request has been exhausted.
+    wait: Option<Duration>,
+}
+
+/// An error response of the Discord API.
+#[derive(Deserialize)]
+struct ErrorResponse {
+    code: u64,
+    #[serde(default)]
+    errors: Option<Value>,
+    message: String,
+}
+
+/// A rate limited response of the Discord API.
+#[derive(Deserialize)]
+struct RateLimitResponse {
+    retry_after: f64,
+}
+
+impl Client {
+    /// Create a client from the environment variables and the command line.
+    ///
+    /// The base URL of the API is that specified with `--api-url`, or with the `DISCORD_API_URL`
+    /// environment variable, defaulting to the Discord API. URLs with the `http` scheme are
+    /// connected to without TLS, such that a local mock server can be targeted.
+    pub fn from_env(matches: &ArgMatches) -> miette::Result<Self> {
+        let application_id = env::var("APPLICATION_ID").into_diagnostic()?;
+
+        let mut token = env::var("BOT_TOKEN").into_diagnostic()?;
+        if !token.starts_with("Bot ") {
+            token.insert_str(0, "Bot ");
+        }
+
+        let base_url = matches
+            .get_one::<String>("api-url")
+            .cloned()
+            .or_else(|| env::var("DISCORD_API_URL").ok())
+            .unwrap_or_else(|| DEFAULT_BASE_URL.to_string());
+        let uri = base_url.parse::<Uri>().into_diagnostic()?;
+
+        let tls = match uri.scheme_str() {
+            Some("https") => true,
+            Some("http") => false,
+            _ => miette::bail!("the base url {base_url} must have the http or https scheme"),
+        };
+        let Some(host) = uri.host() else {
+            miette::bail!("the base url {base_url} must have a host");
+        };
+
+        Ok(Self {
+            application_id,
+            base_path: uri.path().trim_end_matches('/').to_string(),
+            host: host.to_string(),
+            port: uri.port_u16().unwrap_or(if tls { 443 } else { 80 }),
+            sender: None,
+            tls,
+            token,
+            wait: None,
+        })
+    }
+
+    /// The ID of the application whose commands are managed.
+    pub fn application_id(&self) -> &str {
+        &self.application_id
+    }
+
+    /// Send a request to a path relative to the base URL, returning the response body.
+    ///
+    /// Rate limited requests are retried after the time indicated by Discord. A response with an
+    /// unsuccessful status is returned as an error describing the error body.
+    pub async fn request(
+        &mut self,
+        method: Method,
+        path: &str,
+        body: Option<String>,
+    ) -> miette::Result<String> {
+        let authorization = self.token.clone();
+        self.request_as(&authorization, method, path, body).await
+    }
+
+    /// Send a request with an authorization other than the bot token, such as an OAuth2 bearer
+    /// token, returning the response body.
+    ///
+    /// Requests are otherwise sent like with [`request`](Self::request).
+    pub async fn request_as(
+        &mut self,
+        authorization: &str,
+        method: Method,
+        path: &str,
+        body: Option<String>,
+    ) -> miette::Result<String> {
+        let bytes = body.map(Bytes::from).unwrap_or_default();
+        let uri = format!("{}{path}", self.base_path);
+        let default_port = if self.tls { 443 } else { 80 };
+        let host = if self.port == default_port {
+            self.host.clone()
+        } else {
+            format!("{}:{}", self.host, self.port)
+        };
+
+        let mut retries = 0;
+        loop {
+            if let Some(wait) = self.wait.take() {
+                log::info!("rate limit bucket exhausted, waiting for {wait:?}");
+                sleep(wait).await;
+            }
+
+            let mut builder = Request::builder()
+                .uri(&uri)
+                .method(method.clone())
+                .header(HOST, &host)
+                .header(ACCEPT, "application/json")
+                .header(AUTHORIZATION, authorization)
+                .header(CONTENT_LENGTH, bytes.len())
+                .header(USER_AGENT, USER_AGENT_VALUE);
+            if !bytes.is_empty() {
+                builder = builder.header(CONTENT_TYPE, "application/json");
+            }
+            let request = builder
+                .body(Full::<Bytes>::new(bytes.clone()))
+                .into_diagnostic()?;
+
+            log::trace!("sending request {method} {uri} with body {bytes:?}");
+            let sender = self.sender().await?;
+            let response = sender.send_request(request).await.into_diagnostic()?;
+            let status = response.status();
+            let headers = response.headers().clone();
+            log::info!("received response with status {status}");
+
+            let mut body = String::new();
+            response
+                .into_body()
+                .collect()
+                .await
+                .into_diagnostic()?
+                .aggregate()
+                .reader()
+                .read_to_string(&mut body)
+                .into_diagnostic()?;
+
+            if header(&headers, "x-ratelimit-remaining").as_deref() == Some("0") {
+                self.wait = header(&headers, "x-ratelimit-reset-after")
+                    .and_then(|reset_after| reset_after.parse::<f64>().ok())
+                    .map(Duration::from_secs_f64);
+            }
+
+            if status == StatusCode::TOO_MANY_REQUESTS && retries < MAX_RETRIES {
+                let retry_after = serde_json::from_str::<RateLimitResponse>(&body)
+                    .ok()
+                    .map(|response| response.retry_after)
+                    .or_else(|| header(&headers, RETRY_AFTER.as_str())?.parse::<f64>().ok())
+                    .unwrap_or(1.0);
+
+                retries += 1;
+                log::warn!(
+                    "request {method} {uri} was rate limited, retrying after {retry_after}s ({retries}/{MAX_RETRIES})"
+                );
+                sleep(Duration::from_secs_f64(retry_after)).await;
+
+                continue;
+            }
+
+            if !status.is_success() {
+                return Err(error(&method, &uri, status, &body));
+            }
+
+            return Ok(body);
+        }
+    }
+
+    /// Returns a sender on an open connection, connecting if there is none.
+    async fn sender(&mut self) -> miette::Result<&mut SendRequest<Full<Bytes>>> {
+        if let Some(sender) = &mut self.sender
+            && sender.ready().await.is_ok()
+        {
+            return Ok(self.sender.as_mut().unwrap());
+        }
+
+        log::trace!("making tcp connection to {}:{}", self.host, self.port);
+        let sender = if self.tls {
+            handshake_spawned(tls_stream_to(&self.host, self.port).await?).await?
+        } else {
+            let stream = TcpStream::connect((self.host.as_str(), self.port))
+                .await
+                .into_diagnostic()?;
+            handshake_spawned(stream).await?
+        };
+
+        Ok(self.sender.insert(sender))
+    }
+}
+
+/// Performs the HTTP handshake over a stream, driving the connection in the background.
+async fn handshake_spawned<T>(stream: T) -> miette::Result<SendRequest<Full<Bytes>>>
+where
+    T: AsyncRead + AsyncWrite + Send + Unpin + 'static,
+{
+    let (sender, connection) = handshake(TokioIo::new(stream)).await.into_diagnostic()?;
+    spawn(async move {
+        if let Err(err) = connection.await {
+            log::error!("TCP connection failed: {:?}", err);
+        }
+    });
+
+    Ok(sender)
+}
+
+/// Describes an unsuccessful response, including the errors of the fields of the request body
+/// reported by Discord if any.
+fn error(method: &Method, uri: &str, status: StatusCode, body: &str) -> Report {
+    let Ok(response) = serde_json::from_str::<ErrorResponse>(body) else {
+        return Report::msg(format!(
+            "{method} {uri} failed with status {status}: {body}"
+        ));
+    };
+
+    let mut diagnostic = MietteDiagnostic::new(format!(
+        "{method} {uri} failed with status {status}: {} (error code {})",
+        response.message, response.code
+    ));
+
+    let mut errors = Vec::new();
+    if let Some(value) = &response.errors {
+        field_errors(value, "", &mut errors);
+    }
+    if !errors.is_empty() {
+        diagnostic = diagnostic.with_help(errors.join("\n"));
+    }
+
+    Report::new(diagnostic)
+}
+
+/// Flattens the nested errors of the fields of a request body into lines of the form
+/// `path.to.field: message (code)`.
+fn field_errors(value: &Value, path: &str, errors: &mut Vec<String>) {
+    let Some(object) = value.as_object() else {
+        return;
+    };
+
+    if let Some(field_errors) = object.get("_errors").and_then(Value::as_array) {
+        for error in field_errors {
+            let message = error
+                .get("message")
+                .and_then(Value::as_str)
+                .unwrap_or_default();
+            let code = error
+                .get("code")
+                .and_then(Value::as_str)
+                .unwrap_or_default();
+            errors.push(format!("{path}: {message} ({code})"));
+        }
+    }
+
+    for (key, value) in object.iter().filter(|(key, _)| *key != "_errors") {
+        let path = if path.is_empty() {
+            key.clone()
+        } else {
+            format!("{path}.{key}")
+        };
+        field_errors(value, &path, errors);
+    }
+}
+
+/// Returns the value of a header as a string, if present and valid.
+fn header(headers: &HeaderMap, name: &str) -> Option<String> {
+    headers
+        .get(name)
+        .and_then(|value| value.to_str().ok())
+        .map(ToString::to_string)
+}
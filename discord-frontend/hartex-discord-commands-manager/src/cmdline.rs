@@ -29,6 +29,12 @@ use crate::commands;
 /// Handle the command line with argument matches.
 pub async fn handle(matches: ArgMatches) -> miette::Result<()> {
     match matches.subcommand() {
+        Some(("diff", subcommand_matches)) => {
+            commands::diff::diff_command(subcommand_matches.clone()).await
+        }
+        Some(("export", subcommand_matches)) => {
+            commands::export::export_command(subcommand_matches.clone()).await
+        }
         Some(("list-from-discord", subcommand_matches)) => {
             commands::list_from_discord::list_from_discord_command(subcommand_matches.clone()).await
         }
@@ -36,12 +42,28 @@ pub async fn handle(matches: ArgMatches) -> miette::Result<()> {
         Some(("patch", subcommand_matches)) => {
             commands::patch::patch_command(subcommand_matches.clone()).await
         }
+        Some(("permissions", subcommand_matches)) => match subcommand_matches.subcommand() {
+            Some(("list", permissions_matches)) => {
+                commands::permissions::permissions_list_command(permissions_matches.clone()).await
+            }
+            Some(("set", permissions_matches)) => {
+                commands::permissions::permissions_set_command(permissions_matches.clone()).await
+            }
+            _ => Ok(()),
+        },
+        Some(("promote", subcommand_matches)) => {
+            commands::promote::promote_command(subcommand_matches.clone()).await
+        }
         Some(("register", subcommand_matches)) => {
             commands::register::register_command(subcommand_matches.clone()).await
         }
+        Some(("sync", subcommand_matches)) => {
+            commands::sync::sync_command(subcommand_matches.clone()).await
+        }
         Some(("unregister", subcommand_matches)) => {
             commands::unregister::unregister_command(subcommand_matches.clone()).await
         }
+        Some(("validate", _)) => commands::validate::validate_command(),
         _ => Ok(()),
     }
 }
@@ -0,0 +1,371 @@
+/*
+ * SPDX-License-Identifier: AGPL-3.0-only
+ *
+ * This file is part of HarTex.
+ *
+ * HarTex
+ * Copyright (c) 2021-2024 HarTex Project Developers
+ *
+ * HarTex is free software; you can redistribute it and/or modify
+ * it under the terms of the GNU Affero General Public License as published by
+ * the Free Software Foundation; either version 3 of the License, or
+ * (at your option) any later version.
+ *
+ * HarTex is distributed in the hope that it will be useful,
+ * but WITHOUT ANY WARRANTY; without even the implied warranty of
+ * MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+ * GNU Affero General Public License for more details.
+ *
+ * You should have received a copy of the GNU Affero General Public License along
+ * with HarTex. If not, see <https://www.gnu.org/licenses/>.
+ */
+
+use std::collections::HashMap;
+
+use clap::ArgMatches;
+use hartex_discord_core::dotenvy;
+use hartex_log::log;
+use miette::IntoDiagnostic;
+use owo_colors::OwoColorize;
+use serde::Serialize;
+use serde_json::Map;
+use serde_json::Value;
+
+use crate::client::Client;
+use crate::model::command::CommandManagerCommand;
+use crate::model::TypeEnumExt;
+
+/// The difference between the specification of a command and the command registered with
+/// Discord.
+#[derive(Serialize)]
+struct CommandDiff {
+    name: String,
+    #[serde(rename = "type")]
+    kind: &'static str,
+    status: CommandStatus,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    changes: Vec<FieldDiff>,
+}
+
+/// The status of a command in the filesystem relative to Discord.
+#[derive(PartialEq, Serialize)]
+#[serde(rename_all = "snake_case")]
+enum CommandStatus {
+    /// The command is specified and registered identically.
+    Unchanged,
+    /// The command is specified and registered, with differences.
+    Changed,
+    /// The command is specified but not registered.
+    OnlyInFilesystem,
+    /// The command is registered but not specified.
+    OnlyOnDiscord,
+}
+
+/// The difference of a single field, identified by its path.
+///
+/// Options and choices are identified by their names in the path, such that the path of the
+/// description of the `verbose` option of the `server` subcommand is
+/// `options.server.options.verbose.description`.
+#[derive(Serialize)]
+pub(super) struct FieldDiff {
+    path: String,
+    filesystem: Option<Value>,
+    discord: Option<Value>,
+}
+
+/// Show the differences between the command specifications in the filesystem and the commands
+/// registered with Discord.
+#[allow(clippy::module_name_repetitions)]
+pub async fn diff_command(matches: ArgMatches) -> miette::Result<()> {
+    log::trace!("loading environment variables");
+    dotenvy::dotenv().into_diagnostic()?;
+
+    log::trace!("reading specification directory");
+    log::warn!(
+        "an error will occur if this command is not ran within the discord-frontend directory"
+    );
+    let specs = super::sync::read_specs()?;
+
+    let mut client = Client::from_env(&matches)?;
+    let commands_uri = super::commands_uri(client.application_id(), &matches);
+
+    log::trace!("fetching registered commands");
+    let registered = super::sync::fetch(&mut client, &commands_uri).await?;
+
+    let diffs = diff(&specs, &registered)?;
+    let differs = diffs
+        .iter()
+        .any(|diff| diff.status != CommandStatus::Unchanged);
+
+    if matches.get_one::<String>("format").map(String::as_str) == Some("json") {
+        println!(
+            "{}",
+            serde_json::to_string_pretty(&diffs).into_diagnostic()?
+        );
+    } else {
+        print_diffs(&diffs);
+    }
+
+    if differs && matches.get_flag("exit-code") {
+        miette::bail!("the command specifications differ from the registered commands");
+    }
+
+    Ok(())
+}
+
+/// Computes the differences between the specifications and the registered commands.
+///
+/// Commands are matched by their names and types, like when synchronizing.
+fn diff(
+    specs: &[CommandManagerCommand],
+    registered: &[CommandManagerCommand],
+) -> miette::Result<Vec<CommandDiff>> {
+    let mut registered = registered
+        .iter()
+        .map(|command| ((command.name.as_str(), command.kind.name()), command))
+        .collect::<HashMap<_, _>>();
+
+    let mut diffs = Vec::new();
+    for spec in specs {
+        let kind = spec.kind.name();
+        let Some(existing) = registered.remove(&(spec.name.as_str(), kind)) else {
+            diffs.push(CommandDiff {
+                name: spec.name.clone(),
+                kind,
+                status: CommandStatus::OnlyInFilesystem,
+                changes: Vec::new(),
+            });
+            continue;
+        };
+
+        let changes = changes(spec, existing)?;
+        diffs.push(CommandDiff {
+            name: spec.name.clone(),
+            kind,
+            status: if changes.is_empty() {
+                CommandStatus::Unchanged
+            } else {
+                CommandStatus::Changed
+            },
+            changes,
+        });
+    }
+
+    let mut stale = registered.into_values().collect::<Vec<_>>();
+    stale.sort_by(|left, right| left.name.cmp(&right.name));
+    diffs.extend(stale.into_iter().map(|command| CommandDiff {
+        name: command.name.clone(),
+        kind: command.kind.name(),
+        status: CommandStatus::OnlyOnDiscord,
+        changes: Vec::new(),
+    }));
+
+    Ok(diffs)
+}
+
+/// Computes the differences between the specification of a command and the command registered
+/// with Discord.
+///
+/// Fields left unspecified are treated as their defaults on Discord, such that a specification
+/// omitting them is not considered changed. The deprecated `default_permission` field is not
+/// compared, and the contexts of the command are only compared when specified.
+pub(super) fn changes(
+    spec: &CommandManagerCommand,
+    registered: &CommandManagerCommand,
+) -> miette::Result<Vec<FieldDiff>> {
+    let filesystem = normalize(spec)?;
+    let mut discord = normalize(registered)?;
+
+    if filesystem.get("contexts").is_none()
+        && let Some(object) = discord.as_object_mut()
+    {
+        object.remove("contexts");
+    }
+
+    let mut changes = Vec::new();
+    diff_values("", Some(&filesystem), Some(&discord), &mut changes);
+
+    Ok(changes)
+}
+
+/// Converts a command to JSON with the fields left unspecified set to their defaults on Discord,
+/// and empty fields removed.
+fn normalize(command: &CommandManagerCommand) -> miette::Result<Value> {
+    let mut value = serde_json::to_value(command).into_diagnostic()?;
+
+    if let Some(object) = value.as_object_mut() {
+        object.remove("default_permissions");
+        object
+            .entry("description")
+            .or_insert_with(|| Value::String(String::new()));
+        object.entry("dm_permission").or_insert(Value::Bool(true));
+        object.entry("nsfw").or_insert(Value::Bool(false));
+
+        if let Some(options) = object.get_mut("options") {
+            normalize_options(options);
+        }
+    }
+
+    remove_empty(&mut value);
+
+    Ok(value)
+}
+
+fn normalize_options(options: &mut Value) {
+    let Some(options) = options.as_array_mut() else {
+        return;
+    };
+
+    for option in options.iter_mut().filter_map(Value::as_object_mut) {
+        option.entry("autocomplete").or_insert(Value::Bool(false));
+        option.entry("required").or_insert(Value::Bool(false));
+
+        if let Some(options) = option.get_mut("options") {
+            normalize_options(options);
+        }
+    }
+}
+
+fn remove_empty(value: &mut Value) {
+    match value {
+        Value::Object(object) => object.retain(|_, value| {
+            remove_empty(value);
+
+            !(value.is_null()
+                || value.as_object().is_some_and(Map::is_empty)
+                || value.as_array().is_some_and(Vec::is_empty))
+        }),
+        Value::Array(array) => array.iter_mut().for_each(remove_empty),
+        _ => {}
+    }
+}
+
+/// Computes the differences between two values recursively.
+///
+/// Arrays of named objects, being options and choices, are compared by the names of their
+/// elements, with a difference of the array itself reported if the common elements are ordered
+/// differently.
+fn diff_values(
+    path: &str,
+    filesystem: Option<&Value>,
+    discord: Option<&Value>,
+    changes: &mut Vec<FieldDiff>,
+) {
+    match (filesystem, discord) {
+        (Some(Value::Object(filesystem)), Some(Value::Object(discord))) => {
+            let mut keys = filesystem.keys().chain(discord.keys()).collect::<Vec<_>>();
+            keys.sort();
+            keys.dedup();
+
+            for key in keys {
+                diff_values(
+                    &join(path, key),
+                    filesystem.get(key),
+                    discord.get(key),
+                    changes,
+                );
+            }
+        }
+        (Some(Value::Array(filesystem)), Some(Value::Array(discord)))
+            if filesystem
+                .iter()
+                .chain(discord)
+                .all(|value| name(value).is_some()) =>
+        {
+            let filesystem_names = filesystem.iter().filter_map(name).collect::<Vec<_>>();
+            let discord_names = discord.iter().filter_map(name).collect::<Vec<_>>();
+
+            let common = |names: &[&str], others: &[&str]| {
+                names
+                    .iter()
+                    .filter(|name| others.contains(name))
+                    .map(|name| Value::String((*name).to_string()))
+                    .collect::<Vec<_>>()
+            };
+            let filesystem_order = common(&filesystem_names, &discord_names);
+            let discord_order = common(&discord_names, &filesystem_names);
+            if filesystem_order != discord_order {
+                changes.push(FieldDiff {
+                    path: path.to_string(),
+                    filesystem: Some(Value::Array(filesystem_order)),
+                    discord: Some(Value::Array(discord_order)),
+                });
+            }
+
+            let mut names = filesystem_names.clone();
+            names.extend(
+                discord_names
+                    .iter()
+                    .copied()
+                    .filter(|name| !filesystem_names.contains(name)),
+            );
+
+            for element in names {
+                diff_values(
+                    &join(path, element),
+                    filesystem.iter().find(|value| name(value) == Some(element)),
+                    discord.iter().find(|value| name(value) == Some(element)),
+                    changes,
+                );
+            }
+        }
+        (filesystem, discord) if filesystem != discord => changes.push(FieldDiff {
+            path: path.to_string(),
+            filesystem: filesystem.cloned(),
+            discord: discord.cloned(),
+        }),
+        _ => {}
+    }
+}
+
+fn join(path: &str, segment: &str) -> String {
+    if path.is_empty() {
+        segment.to_string()
+    } else {
+        format!("{path}.{segment}")
+    }
+}
+
+fn name(value: &Value) -> Option<&str> {
+    value.get("name").and_then(Value::as_str)
+}
+
+fn print_diffs(diffs: &[CommandDiff]) {
+    for diff in diffs {
+        match diff.status {
+            CommandStatus::Unchanged => println!(
+                "{}",
+                format!("= {} ({})", diff.name, diff.kind).truecolor(107, 107, 107)
+            ),
+            CommandStatus::Changed => {
+                println!("{} {} ({})", "~".yellow(), diff.name.bold(), diff.kind);
+
+                for change in &diff.changes {
+                    println!(
+                        "    {}: {} {} {}",
+                        change.path.bold(),
+                        display(change.discord.as_ref()).red(),
+                        "->".truecolor(107, 107, 107),
+                        display(change.filesystem.as_ref()).green()
+                    );
+                }
+            }
+            CommandStatus::OnlyInFilesystem => println!(
+                "{} {} ({}) only in filesystem",
+                "+".green(),
+                diff.name.bold(),
+                diff.kind
+            ),
+            CommandStatus::OnlyOnDiscord => println!(
+                "{} {} ({}) only on Discord",
+                "-".red(),
+                diff.name.bold(),
+                diff.kind
+            ),
+        }
+    }
+}
+
+fn display(value: Option<&Value>) -> String {
+    value.map_or_else(|| String::from("<absent>"), ToString::to_string)
+}
@@ -0,0 +1,106 @@
+/*
+ * SPDX-License-Identifier: AGPL-3.0-only
+ *
+ * This file is part of HarTex.
+ *
+ * HarTex
+ * Copyright (c) 2021-2024 HarTex Project Developers
+ *
+ * HarTex is free software; you can redistribute it and/or modify
+ * it under the terms of the GNU Affero General Public License as published by
+ * the Free Software Foundation; either version 3 of the License, or
+ * (at your option) any later version.
+ *
+ * HarTex is distributed in the hope that it will be useful,
+ * but WITHOUT ANY WARRANTY; without even the implied warranty of
+ * MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+ * GNU Affero General Public License for more details.
+ *
+ * You should have received a copy of the GNU Affero General Public License along
+ * with HarTex. If not, see <https://www.gnu.org/licenses/>.
+ */
+
+use std::collections::HashMap;
+use std::fs;
+use std::path::PathBuf;
+
+use clap::ArgMatches;
+use hartex_discord_core::dotenvy;
+use hartex_log::log;
+use miette::IntoDiagnostic;
+use walkdir::WalkDir;
+
+use crate::client::Client;
+
+/// Export the commands registered with Discord to JSON files in the layout of the specification
+/// directory.
+///
+/// A command is written to the path of the specification of the same name relative to the output
+/// directory if there is one, or to the root of the output directory otherwise.
+#[allow(clippy::module_name_repetitions)]
+pub async fn export_command(matches: ArgMatches) -> miette::Result<()> {
+    log::trace!("loading environment variables");
+    dotenvy::dotenv().into_diagnostic()?;
+
+    log::trace!("reading specification directory");
+    log::warn!(
+        "an error will occur if this command is not ran within the discord-frontend directory"
+    );
+    let layout = spec_layout()?;
+
+    let mut client = Client::from_env(&matches)?;
+    let commands_uri = super::commands_uri(client.application_id(), &matches);
+
+    log::trace!("fetching registered commands");
+    let registered = super::sync::fetch(&mut client, &commands_uri).await?;
+
+    let output = PathBuf::from(matches.get_one::<String>("output").unwrap());
+    for command in registered {
+        let path = output.join(
+            layout
+                .get(&command.name)
+                .cloned()
+                .unwrap_or_else(|| PathBuf::from(format!("{}.json", command.name))),
+        );
+        if let Some(parent) = path.parent() {
+            fs::create_dir_all(parent).into_diagnostic()?;
+        }
+
+        // converting to a value first sorts the localizations by locale
+        let value = serde_json::to_value(&command).into_diagnostic()?;
+        let mut json = serde_json::to_string_pretty(&value).into_diagnostic()?;
+        json.push('\n');
+        fs::write(&path, json).into_diagnostic()?;
+
+        log::info!("exported command {} to {}", command.name, path.display());
+    }
+
+    Ok(())
+}
+
+/// Returns the paths of the specifications relative to the specification directory, by the names
+/// of their commands.
+fn spec_layout() -> miette::Result<HashMap<String, PathBuf>> {
+    let root = PathBuf::from("hartex-discord-commands-spec");
+
+    let mut layout = HashMap::new();
+    for result in WalkDir::new(&root).same_file_system(true) {
+        let entry = result.into_diagnostic()?;
+        if entry.metadata().into_diagnostic()?.is_dir() {
+            continue;
+        }
+
+        if entry.path().extension().and_then(|ext| ext.to_str()) != Some("json") {
+            continue;
+        }
+
+        let Some(name) = entry.path().file_stem().and_then(|stem| stem.to_str()) else {
+            continue;
+        };
+        let relative = entry.path().strip_prefix(&root).into_diagnostic()?;
+
+        layout.insert(name.to_string(), relative.to_path_buf());
+    }
+
+    Ok(layout)
+}
@@ -20,15 +20,40 @@
  * with HarTex. If not, see <https://www.gnu.org/licenses/>.
  */
 
+use clap::ArgMatches;
+
 /// # Commands
 ///
+/// - diff
+/// - export
 /// - patch
 /// - list from discord
 /// - list from filesystem
+/// - permissions
+/// - promote
 /// - register
+/// - sync
 /// - unregister
+/// - validate
+pub mod diff;
+pub mod export;
 pub mod list_from_discord;
 pub mod list_from_fs;
 pub mod patch;
+pub mod permissions;
+pub mod promote;
 pub mod register;
+pub mod sync;
 pub mod unregister;
+pub mod validate;
+
+/// The URI of the commands of the application relative to the base URL of the API, which are the
+/// commands of the guild specified with `--guild` if any, or the global commands otherwise.
+pub fn commands_uri(application_id: &str, matches: &ArgMatches) -> String {
+    match matches.get_one::<String>("guild") {
+        Some(guild_id) => {
+            format!("/applications/{application_id}/guilds/{guild_id}/commands")
+        }
+        None => format!("/applications/{application_id}/commands"),
+    }
+}
@@ -20,34 +20,21 @@
  * with HarTex. If not, see <https://www.gnu.org/licenses/>.
  */
 
-use std::env;
 use std::fs::File;
 use std::io::Read;
 
 use clap::ArgMatches;
 use hartex_discord_core::dotenvy;
-use hartex_discord_core::tokio::task::spawn;
-use hartex_discord_utils::hyper::tls_stream;
 use hartex_log::log;
-use http_body_util::BodyExt;
-use http_body_util::Full;
-use hyper::body::Buf;
-use hyper::body::Bytes;
-use hyper::client::conn::http1::handshake;
-use hyper::header::ACCEPT;
-use hyper::header::AUTHORIZATION;
-use hyper::header::CONTENT_LENGTH;
-use hyper::header::CONTENT_TYPE;
-use hyper::header::HOST;
-use hyper::header::USER_AGENT;
 use hyper::Method;
-use hyper::Request;
-use hyper_util::rt::TokioIo;
 use miette::IntoDiagnostic;
 use miette::Report;
 use minify::json::minify;
 use walkdir::WalkDir;
 
+use crate::client::Client;
+use crate::localization;
+
 /// Patch a command.
 #[allow(clippy::module_name_repetitions)]
 pub async fn patch_command(matches: ArgMatches) -> miette::Result<()> {
@@ -95,56 +82,21 @@ pub async fn patch_command(matches: ArgMatches) -> miette::Result<()> {
     let mut json = String::new();
     file.read_to_string(&mut json).into_diagnostic()?;
 
-    log::trace!("making tcp connection");
-    let stream = tls_stream().await?;
-    let (mut sender, connection) = handshake(TokioIo::new(stream)).await.into_diagnostic()?;
-
-    spawn(async move {
-        if let Err(err) = connection.await {
-            log::error!("TCP connection failed: {:?}", err);
-        }
-    });
-
-    let application_id = env::var("APPLICATION_ID").into_diagnostic()?;
+    log::trace!("filling localizations from fluent messages");
+    let json = localization::localize_json(&json)?;
 
-    let mut token = env::var("BOT_TOKEN").into_diagnostic()?;
-    if !token.starts_with("Bot ") {
-        token.insert_str(0, "Bot ");
-    }
+    let mut client = Client::from_env(&matches)?;
+    let uri = format!(
+        "{}/{command_id}",
+        super::commands_uri(client.application_id(), &matches)
+    );
 
-    let bytes = Bytes::from(minify(&json));
-
-    log::trace!("sending request with body {:?}", bytes.clone());
-    let request = Request::builder()
-        .uri(format!(
-            "/api/v10/applications/{application_id}/commands/{command_id}"
-        ))
-        .method(Method::PATCH)
-        .header(HOST, "discord.com")
-        .header(ACCEPT, "application/json")
-        .header(AUTHORIZATION, token)
-        .header(CONTENT_TYPE, "application/json")
-        .header(CONTENT_LENGTH, bytes.len())
-        .header(
-            USER_AGENT,
-            "DiscordBot (https://github.com/TeamHarTex/HarTex, v0.6.0) CommandsManager",
-        )
-        .body(Full::<Bytes>::new(bytes))
-        .into_diagnostic()?;
-    let result = sender.send_request(request).await.into_diagnostic()?;
-
-    log::info!("received response with status {}", result.status());
-    let body = result
-        .into_body()
-        .collect()
-        .await
-        .into_diagnostic()?
-        .aggregate();
-    let mut string = String::new();
-    body.reader()
-        .read_to_string(&mut string)
-        .into_diagnostic()?;
-    log::info!("response body: {string:?}");
+    log::trace!("patching command");
+    let body = client
+        .request(Method::PATCH, &uri, Some(minify(&json)))
+        .await?;
+    log::info!("patched command {command_id}");
+    log::trace!("response body: {body:?}");
 
     Ok(())
 }
@@ -0,0 +1,368 @@
+/*
+ * SPDX-License-Identifier: AGPL-3.0-only
+ *
+ * This file is part of HarTex.
+ *
+ * HarTex
+ * Copyright (c) 2021-2024 HarTex Project Developers
+ *
+ * HarTex is free software; you can redistribute it and/or modify
+ * it under the terms of the GNU Affero General Public License as published by
+ * the Free Software Foundation; either version 3 of the License, or
+ * (at your option) any later version.
+ *
+ * HarTex is distributed in the hope that it will be useful,
+ * but WITHOUT ANY WARRANTY; without even the implied warranty of
+ * MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+ * GNU Affero General Public License for more details.
+ *
+ * You should have received a copy of the GNU Affero General Public License along
+ * with HarTex. If not, see <https://www.gnu.org/licenses/>.
+ */
+
+use std::collections::HashMap;
+use std::env;
+use std::fmt;
+use std::fmt::Display;
+use std::fmt::Formatter;
+use std::fs;
+
+use clap::ArgMatches;
+use hartex_discord_core::discord::model::application::command::permissions::CommandPermission;
+use hartex_discord_core::discord::model::application::command::permissions::CommandPermissionType;
+use hartex_discord_core::discord::model::application::command::permissions::GuildCommandPermissions;
+use hartex_discord_core::dotenvy;
+use hartex_log::log;
+use hyper::Method;
+use miette::IntoDiagnostic;
+use miette::Report;
+use owo_colors::OwoColorize;
+use serde_json::json;
+
+use super::sync::PlanAction;
+use crate::client::Client;
+use crate::model::permissions::CommandManagerPermissionOverride;
+use crate::model::permissions::CommandManagerPermissionsFile;
+
+/// The name under which the overrides applying to every command of the application are declared
+/// and listed.
+const ALL_COMMANDS: &str = "*";
+
+/// The names of the commands the permission overrides of a guild may apply to, by their IDs.
+///
+/// Commands of the guild take precedence over global commands of the same name, as they are the
+/// ones shown to the members of the guild.
+struct CommandNames {
+    global: HashMap<String, String>,
+    guild: HashMap<String, String>,
+}
+
+impl CommandNames {
+    /// The ID of the command with the given name, if registered.
+    ///
+    /// Commands of different types sharing a name cannot be told apart by their names and are
+    /// reported as an error.
+    fn id_of(&self, name: &str) -> miette::Result<Option<String>> {
+        for names in [&self.guild, &self.global] {
+            let mut ids = names
+                .iter()
+                .filter(|(_, command)| *command == name)
+                .map(|(id, _)| id.clone());
+
+            let Some(id) = ids.next() else {
+                continue;
+            };
+            if ids.next().is_some() {
+                miette::bail!(
+                    "command name {name} is ambiguous between several registered commands"
+                );
+            }
+
+            return Ok(Some(id));
+        }
+
+        Ok(None)
+    }
+
+    /// The name of the command with the given ID, if registered.
+    fn name_of(&self, id: &str) -> Option<&String> {
+        self.guild.get(id).or_else(|| self.global.get(id))
+    }
+}
+
+/// An action to be taken to bring the permission overrides of a command in sync with those
+/// declared.
+pub(super) enum PermissionsAction {
+    /// The command has overrides declared that differ from those set.
+    Set {
+        command: String,
+        id: String,
+        permissions: Vec<CommandPermission>,
+    },
+    /// The command has overrides set but none declared.
+    Clear { command: String, id: String },
+    /// The command has overrides declared identical to those set.
+    Unchanged(String),
+}
+
+impl PlanAction for PermissionsAction {
+    fn is_unchanged(&self) -> bool {
+        matches!(self, Self::Unchanged(_))
+    }
+}
+
+impl Display for PermissionsAction {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Set {
+                command,
+                permissions,
+                ..
+            } => {
+                write!(f, "{} {}", "~ set".yellow(), command.bold())?;
+                for permission in permissions {
+                    write!(f, "\n        {}", describe(permission))?;
+                }
+
+                Ok(())
+            }
+            Self::Clear { command, .. } => write!(f, "{} {}", "- clear".red(), command.bold()),
+            Self::Unchanged(command) => write!(
+                f,
+                "{}",
+                format!("= unchanged {command}").truecolor(107, 107, 107)
+            ),
+        }
+    }
+}
+
+/// List the command permission overrides of the guild specified with `--guild`.
+#[allow(clippy::module_name_repetitions)]
+pub async fn permissions_list_command(matches: ArgMatches) -> miette::Result<()> {
+    log::trace!("loading environment variables");
+    dotenvy::dotenv().into_diagnostic()?;
+
+    require_guild(&matches)?;
+
+    let mut client = Client::from_env(&matches)?;
+    let names = command_names(&mut client, &matches).await?;
+
+    log::trace!("fetching command permissions");
+    let mut overrides = fetch(&mut client, &matches)
+        .await?
+        .into_iter()
+        .map(|(id, permissions)| (name_of(&client, &names, &id), permissions))
+        .collect::<Vec<_>>();
+    overrides.sort_by(|(left, _), (right, _)| left.cmp(right));
+
+    if overrides.is_empty() {
+        println!(
+            "{}",
+            "No command permission overrides.".truecolor(107, 107, 107)
+        );
+    }
+
+    for (command, permissions) in overrides {
+        println!("{}", command.bold());
+        for permission in permissions {
+            println!("    {}", describe(&permission));
+        }
+    }
+
+    Ok(())
+}
+
+/// Set the command permission overrides of the guild specified with `--guild` to those declared in
+/// a permissions file.
+///
+/// Overrides set for commands absent from the file are cleared. As Discord only allows the
+/// overrides to be edited on behalf of a user, the OAuth2 bearer token of a user with the
+/// `applications.commands.permissions.update` scope and permissions to manage the guild and its
+/// roles must be provided with the `COMMAND_PERMISSIONS_TOKEN` environment variable.
+#[allow(clippy::module_name_repetitions)]
+pub async fn permissions_set_command(matches: ArgMatches) -> miette::Result<()> {
+    log::trace!("loading environment variables");
+    dotenvy::dotenv().into_diagnostic()?;
+
+    require_guild(&matches)?;
+
+    let path = matches.get_one::<String>("file").unwrap();
+    log::trace!("reading permissions file {path}");
+    let file = serde_json::from_str::<CommandManagerPermissionsFile>(
+        &fs::read_to_string(path).into_diagnostic()?,
+    )
+    .map_err(|error| Report::msg(format!("deserialization failed for file {path}: {error}")))?;
+
+    let mut client = Client::from_env(&matches)?;
+    let names = command_names(&mut client, &matches).await?;
+
+    log::trace!("fetching command permissions");
+    let current = fetch(&mut client, &matches).await?;
+
+    let plan = plan(&client, &file, &names, current)?;
+    if !super::sync::confirm_plan(&plan, &matches)? {
+        return Ok(());
+    }
+
+    let mut authorization = env::var("COMMAND_PERMISSIONS_TOKEN").map_err(|_| {
+        Report::msg(
+            "setting command permissions requires the OAuth2 bearer token of a user in the COMMAND_PERMISSIONS_TOKEN environment variable",
+        )
+    })?;
+    if !authorization.starts_with("Bearer ") {
+        authorization.insert_str(0, "Bearer ");
+    }
+
+    let commands_uri = super::commands_uri(client.application_id(), &matches);
+    for action in plan {
+        let (command, id, permissions) = match action {
+            PermissionsAction::Set {
+                command,
+                id,
+                permissions,
+            } => (command, id, permissions),
+            PermissionsAction::Clear { command, id } => (command, id, Vec::new()),
+            PermissionsAction::Unchanged(_) => continue,
+        };
+
+        log::trace!("setting permissions of command {command}");
+        let body = json!({ "permissions": permissions }).to_string();
+        client
+            .request_as(
+                &authorization,
+                Method::PUT,
+                &format!("{commands_uri}/{id}/permissions"),
+                Some(body),
+            )
+            .await?;
+    }
+
+    println!("{}", "Command permissions synchronized.".green());
+
+    Ok(())
+}
+
+/// Computes the actions to be taken to bring the permission overrides set in sync with those
+/// declared.
+fn plan(
+    client: &Client,
+    file: &CommandManagerPermissionsFile,
+    names: &CommandNames,
+    mut current: HashMap<String, Vec<CommandPermission>>,
+) -> miette::Result<Vec<PermissionsAction>> {
+    let mut plan = Vec::new();
+    for (command, overrides) in file {
+        let id = if command == ALL_COMMANDS {
+            client.application_id().to_string()
+        } else {
+            names
+                .id_of(command)?
+                .ok_or_else(|| Report::msg(format!("command {command} is not registered")))?
+        };
+
+        let permissions = overrides
+            .iter()
+            .map(CommandManagerPermissionOverride::to_permission)
+            .collect::<miette::Result<Vec<_>>>()?;
+        let existing = current.remove(&id).unwrap_or_default();
+
+        if same_permissions(&permissions, &existing) {
+            plan.push(PermissionsAction::Unchanged(command.clone()));
+        } else {
+            plan.push(PermissionsAction::Set {
+                command: command.clone(),
+                id,
+                permissions,
+            });
+        }
+    }
+
+    let mut stale = current
+        .into_iter()
+        .filter(|(_, permissions)| !permissions.is_empty())
+        .map(|(id, _)| (name_of(client, names, &id), id))
+        .collect::<Vec<_>>();
+    stale.sort();
+    plan.extend(
+        stale
+            .into_iter()
+            .map(|(command, id)| PermissionsAction::Clear { command, id }),
+    );
+
+    Ok(plan)
+}
+
+/// Fetches the permission overrides set in the guild, by the IDs of the commands they apply to.
+async fn fetch(
+    client: &mut Client,
+    matches: &ArgMatches,
+) -> miette::Result<HashMap<String, Vec<CommandPermission>>> {
+    let uri = format!(
+        "{}/permissions",
+        super::commands_uri(client.application_id(), matches)
+    );
+    let body = client.request(Method::GET, &uri, None).await?;
+
+    Ok(serde_json::from_str::<Vec<GuildCommandPermissions>>(&body)
+        .into_diagnostic()?
+        .into_iter()
+        .map(|permissions| (permissions.id.to_string(), permissions.permissions))
+        .collect())
+}
+
+/// Fetches the names of the global commands and the commands of the guild.
+async fn command_names(client: &mut Client, matches: &ArgMatches) -> miette::Result<CommandNames> {
+    let global_uri = format!("/applications/{}/commands", client.application_id());
+    let guild_uri = super::commands_uri(client.application_id(), matches);
+
+    Ok(CommandNames {
+        global: names_by_id(client, &global_uri).await?,
+        guild: names_by_id(client, &guild_uri).await?,
+    })
+}
+
+async fn names_by_id(client: &mut Client, uri: &str) -> miette::Result<HashMap<String, String>> {
+    Ok(super::sync::fetch(client, uri)
+        .await?
+        .into_iter()
+        .filter_map(|command| Some((command.id?, command.name)))
+        .collect())
+}
+
+fn name_of(client: &Client, names: &CommandNames, id: &str) -> String {
+    if id == client.application_id() {
+        return ALL_COMMANDS.to_string();
+    }
+
+    names.name_of(id).cloned().unwrap_or_else(|| id.to_string())
+}
+
+fn require_guild(matches: &ArgMatches) -> miette::Result<()> {
+    if matches.get_one::<String>("guild").is_none() {
+        return Err(Report::msg(
+            "the guild of the command permissions must be specified with --guild",
+        ));
+    }
+
+    Ok(())
+}
+
+/// Whether two lists of permission overrides contain the same overrides, regardless of order.
+fn same_permissions(left: &[CommandPermission], right: &[CommandPermission]) -> bool {
+    left.len() == right.len() && left.iter().all(|permission| right.contains(permission))
+}
+
+fn describe(permission: &CommandPermission) -> String {
+    let (kind, id) = match permission.id {
+        CommandPermissionType::Channel(id) => ("channel", id.get()),
+        CommandPermissionType::Role(id) => ("role", id.get()),
+        CommandPermissionType::User(id) => ("user", id.get()),
+    };
+    let state = if permission.permission {
+        "allowed".green().to_string()
+    } else {
+        "denied".red().to_string()
+    };
+
+    format!("{kind} {id}: {state}")
+}
@@ -0,0 +1,73 @@
+/*
+ * SPDX-License-Identifier: AGPL-3.0-only
+ *
+ * This file is part of HarTex.
+ *
+ * HarTex
+ * Copyright (c) 2021-2024 HarTex Project Developers
+ *
+ * HarTex is free software; you can redistribute it and/or modify
+ * it under the terms of the GNU Affero General Public License as published by
+ * the Free Software Foundation; either version 3 of the License, or
+ * (at your option) any later version.
+ *
+ * HarTex is distributed in the hope that it will be useful,
+ * but WITHOUT ANY WARRANTY; without even the implied warranty of
+ * MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+ * GNU Affero General Public License for more details.
+ *
+ * You should have received a copy of the GNU Affero General Public License along
+ * with HarTex. If not, see <https://www.gnu.org/licenses/>.
+ */
+
+use clap::ArgMatches;
+use hartex_discord_core::dotenvy;
+use hartex_log::log;
+use hyper::Method;
+use miette::IntoDiagnostic;
+use miette::Report;
+use owo_colors::OwoColorize;
+
+use crate::client::Client;
+
+/// Promote the commands of a guild to global commands.
+///
+/// The global commands are overwritten with the commands registered in the guild specified with
+/// `--guild`, after the resulting plan is confirmed.
+#[allow(clippy::module_name_repetitions)]
+pub async fn promote_command(matches: ArgMatches) -> miette::Result<()> {
+    log::trace!("loading environment variables");
+    dotenvy::dotenv().into_diagnostic()?;
+
+    if matches.get_one::<String>("guild").is_none() {
+        return Err(Report::msg(
+            "the guild to promote the commands of must be specified with --guild",
+        ));
+    }
+
+    let mut client = Client::from_env(&matches)?;
+
+    let guild_uri = super::commands_uri(client.application_id(), &matches);
+    let global_uri = format!("/applications/{}/commands", client.application_id());
+
+    log::trace!("fetching guild commands");
+    let guild_commands = super::sync::fetch(&mut client, &guild_uri).await?;
+    log::trace!("fetching global commands");
+    let global_commands = super::sync::fetch(&mut client, &global_uri).await?;
+
+    let bulk_body = serde_json::to_string(&guild_commands).into_diagnostic()?;
+    let plan = super::sync::plan(guild_commands, global_commands)?;
+
+    if !super::sync::confirm_plan(&plan, &matches)? {
+        return Ok(());
+    }
+
+    log::trace!("overwriting global commands");
+    client
+        .request(Method::PUT, &global_uri, Some(bulk_body))
+        .await?;
+
+    println!("{}", "Commands promoted.".green());
+
+    Ok(())
+}
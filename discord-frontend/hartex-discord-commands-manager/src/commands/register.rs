@@ -20,31 +20,20 @@
  * with HarTex. If not, see <https://www.gnu.org/licenses/>.
  */
 
-use std::env;
 use std::fs::File;
 use std::io::Read;
 
 use clap::ArgMatches;
 use hartex_discord_core::dotenvy;
-use hartex_discord_core::tokio::task::spawn;
-use hartex_discord_utils::hyper::tls_stream;
 use hartex_log::log;
-use http_body_util::Full;
-use hyper::body::Bytes;
-use hyper::client::conn::http1::handshake;
-use hyper::header::ACCEPT;
-use hyper::header::AUTHORIZATION;
-use hyper::header::CONTENT_LENGTH;
-use hyper::header::CONTENT_TYPE;
-use hyper::header::HOST;
-use hyper::header::USER_AGENT;
 use hyper::Method;
-use hyper::Request;
-use hyper_util::rt::TokioIo;
 use miette::IntoDiagnostic;
 use miette::Report;
 use walkdir::WalkDir;
 
+use crate::client::Client;
+use crate::localization;
+
 /// Register a command.
 #[allow(clippy::module_name_repetitions)]
 pub async fn register_command(matches: ArgMatches) -> miette::Result<()> {
@@ -91,43 +80,15 @@ pub async fn register_command(matches: ArgMatches) -> miette::Result<()> {
     let mut json = String::new();
     file.read_to_string(&mut json).into_diagnostic()?;
 
-    log::trace!("making tcp connection");
-    let stream = tls_stream().await?;
-    let (mut sender, connection) = handshake(TokioIo::new(stream)).await.into_diagnostic()?;
-
-    spawn(async move {
-        if let Err(err) = connection.await {
-            log::error!("TCP connection failed: {:?}", err);
-        }
-    });
+    log::trace!("filling localizations from fluent messages");
+    let json = localization::localize_json(&json)?;
 
-    let application_id = env::var("APPLICATION_ID").into_diagnostic()?;
-
-    let mut token = env::var("BOT_TOKEN").into_diagnostic()?;
-    if !token.starts_with("Bot ") {
-        token.insert_str(0, "Bot ");
-    }
+    let mut client = Client::from_env(&matches)?;
+    let uri = super::commands_uri(client.application_id(), &matches);
 
-    let bytes = Bytes::from(json);
-
-    log::trace!("sending request with body {:?}", bytes.clone());
-    let request = Request::builder()
-        .uri(format!("/api/v10/applications/{application_id}/commands"))
-        .method(Method::POST)
-        .header(HOST, "discord.com")
-        .header(ACCEPT, "application/json")
-        .header(AUTHORIZATION, token)
-        .header(CONTENT_TYPE, "application/json")
-        .header(CONTENT_LENGTH, bytes.len())
-        .header(
-            USER_AGENT,
-            "DiscordBot (https://github.com/TeamHarTex/HarTex, v0.6.0) CommandsManager",
-        )
-        .body(Full::<Bytes>::new(bytes))
-        .into_diagnostic()?;
-
-    let result = sender.send_request(request).await.into_diagnostic()?;
-    log::info!("received response with status {}", result.status());
+    log::trace!("registering command");
+    client.request(Method::POST, &uri, Some(json)).await?;
+    log::info!("registered command {command}");
 
     Ok(())
 }
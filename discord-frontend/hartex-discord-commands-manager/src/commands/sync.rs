@@ -0,0 +1,303 @@
+/*
+ * SPDX-License-Identifier: AGPL-3.0-only
+ *
+ * This file is part of HarTex.
+ *
+ * HarTex
+ * Copyright (c) 2021-2024 HarTex Project Developers
+ *
+ * HarTex is free software; you can redistribute it and/or modify
+ * it under the terms of the GNU Affero General Public License as published by
+ * the Free Software Foundation; either version 3 of the License, or
+ * (at your option) any later version.
+ *
+ * HarTex is distributed in the hope that it will be useful,
+ * but WITHOUT ANY WARRANTY; without even the implied warranty of
+ * MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+ * GNU Affero General Public License for more details.
+ *
+ * You should have received a copy of the GNU Affero General Public License along
+ * with HarTex. If not, see <https://www.gnu.org/licenses/>.
+ */
+
+use std::collections::HashMap;
+use std::fmt;
+use std::fmt::Display;
+use std::fmt::Formatter;
+use std::fs::File;
+use std::io;
+use std::io::Read;
+use std::io::Write;
+
+use clap::ArgMatches;
+use hartex_discord_core::dotenvy;
+use hartex_localization_loader::LocalizationBundleHolder;
+use hartex_log::log;
+use hyper::Method;
+use miette::IntoDiagnostic;
+use miette::Report;
+use owo_colors::OwoColorize;
+use serde_json::Value;
+use walkdir::WalkDir;
+
+use crate::client::Client;
+use crate::localization;
+use crate::model::command::CommandManagerCommand;
+use crate::model::TypeEnumExt;
+
+/// An action to be taken to bring a command registered with Discord in sync with its
+/// specification.
+pub(super) enum SyncAction {
+    /// The command is specified but not registered.
+    Create(CommandManagerCommand),
+    /// The command is registered but differs from its specification.
+    Update {
+        id: String,
+        command: CommandManagerCommand,
+    },
+    /// The command is registered but no longer specified.
+    Delete { id: String, name: String },
+    /// The command is registered and matches its specification.
+    Unchanged(String),
+}
+
+/// An action of a plan to be confirmed before it is applied.
+pub(super) trait PlanAction: Display {
+    /// Whether the action leaves what it applies to unchanged.
+    fn is_unchanged(&self) -> bool;
+}
+
+impl PlanAction for SyncAction {
+    fn is_unchanged(&self) -> bool {
+        matches!(self, Self::Unchanged(_))
+    }
+}
+
+impl Display for SyncAction {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Create(command) => write!(f, "{} {}", "+ create".green(), command.name.bold()),
+            Self::Update { id, command } => {
+                write!(f, "{} {} ({id})", "~ update".yellow(), command.name.bold())
+            }
+            Self::Delete { id, name } => {
+                write!(f, "{} {} ({id})", "- delete".red(), name.bold())
+            }
+            Self::Unchanged(name) => write!(
+                f,
+                "{}",
+                format!("= unchanged {name}").truecolor(107, 107, 107)
+            ),
+        }
+    }
+}
+
+/// Synchronize the commands registered with Discord with the specifications in the filesystem.
+#[allow(clippy::module_name_repetitions)]
+pub async fn sync_command(matches: ArgMatches) -> miette::Result<()> {
+    log::trace!("loading environment variables");
+    dotenvy::dotenv().into_diagnostic()?;
+
+    log::trace!("reading specification directory");
+    log::warn!(
+        "an error will occur if this command is not ran within the discord-frontend directory"
+    );
+    let specs = read_specs()?;
+
+    let mut client = Client::from_env(&matches)?;
+    let commands_uri = super::commands_uri(client.application_id(), &matches);
+
+    log::trace!("fetching registered commands");
+    let registered = fetch(&mut client, &commands_uri).await?;
+
+    let bulk_body = serde_json::to_string(&specs).into_diagnostic()?;
+    let plan = plan(specs, registered)?;
+
+    if !confirm_plan(&plan, &matches)? {
+        return Ok(());
+    }
+
+    if matches.get_flag("bulk") {
+        log::trace!("overwriting registered commands");
+        client
+            .request(Method::PUT, &commands_uri, Some(bulk_body))
+            .await?;
+
+        println!("{}", "Commands synchronized.".green());
+        return Ok(());
+    }
+
+    for action in plan {
+        match action {
+            SyncAction::Create(command) => {
+                log::trace!("creating command {}", command.name);
+                let body = serde_json::to_string(&command).into_diagnostic()?;
+                client
+                    .request(Method::POST, &commands_uri, Some(body))
+                    .await?;
+            }
+            SyncAction::Update { id, command } => {
+                log::trace!("updating command {}", command.name);
+                let body = serde_json::to_string(&command).into_diagnostic()?;
+                client
+                    .request(Method::PATCH, &format!("{commands_uri}/{id}"), Some(body))
+                    .await?;
+            }
+            SyncAction::Delete { id, name } => {
+                log::trace!("deleting command {name}");
+                client
+                    .request(Method::DELETE, &format!("{commands_uri}/{id}"), None)
+                    .await?;
+            }
+            SyncAction::Unchanged(_) => (),
+        }
+    }
+
+    println!("{}", "Commands synchronized.".green());
+
+    Ok(())
+}
+
+/// Reads every command specification in the filesystem.
+///
+/// Unlike listing, a specification that fails to deserialize is an error, as omitting it would
+/// cause the corresponding command to be deleted. The localizations of the specifications are
+/// filled from the Fluent messages.
+pub(super) fn read_specs() -> miette::Result<Vec<CommandManagerCommand>> {
+    let holder = LocalizationBundleHolder::load_localizations()?;
+
+    let mut specs = Vec::new();
+    for result in WalkDir::new("hartex-discord-commands-spec")
+        .same_file_system(true)
+        .sort_by_file_name()
+    {
+        let entry = result.into_diagnostic()?;
+        if entry.metadata().into_diagnostic()?.is_dir() {
+            continue;
+        }
+
+        if entry.path().extension().and_then(|ext| ext.to_str()) != Some("json") {
+            continue;
+        }
+
+        let mut buffer = String::new();
+        File::open(entry.path())
+            .into_diagnostic()?
+            .read_to_string(&mut buffer)
+            .into_diagnostic()?;
+
+        let mut spec = serde_json::from_str::<Value>(&buffer).map_err(|error| {
+            Report::msg(format!(
+                "deserialization failed for file {}: {error}",
+                entry.path().display()
+            ))
+        })?;
+        localization::localize(&mut spec, &holder)?;
+
+        let command = serde_json::from_value::<CommandManagerCommand>(spec).map_err(|error| {
+            Report::msg(format!(
+                "deserialization failed for file {}: {error}",
+                entry.path().display()
+            ))
+        })?;
+        specs.push(command);
+    }
+
+    Ok(specs)
+}
+
+/// Computes the actions to be taken to bring the registered commands in sync with their
+/// specifications.
+///
+/// Commands are matched by their names and types, and are unchanged if they do not differ like
+/// when diffing them.
+pub(super) fn plan(
+    specs: Vec<CommandManagerCommand>,
+    registered: Vec<CommandManagerCommand>,
+) -> miette::Result<Vec<SyncAction>> {
+    let mut registered = registered
+        .into_iter()
+        .map(|command| ((command.name.clone(), command.kind.name()), command))
+        .collect::<HashMap<_, _>>();
+
+    let mut plan = Vec::new();
+    for command in specs {
+        let action = match registered.remove(&(command.name.clone(), command.kind.name())) {
+            Some(existing) if super::diff::changes(&command, &existing)?.is_empty() => {
+                SyncAction::Unchanged(command.name)
+            }
+            Some(existing) => SyncAction::Update {
+                id: existing.id.unwrap_or_default(),
+                command,
+            },
+            None => SyncAction::Create(command),
+        };
+        plan.push(action);
+    }
+
+    let mut stale = registered.into_values().collect::<Vec<_>>();
+    stale.sort_by(|left, right| left.name.cmp(&right.name));
+    plan.extend(stale.into_iter().map(|command| SyncAction::Delete {
+        id: command.id.unwrap_or_default(),
+        name: command.name,
+    }));
+
+    Ok(plan)
+}
+
+/// Prints a plan, returning whether it is to be applied.
+///
+/// A plan is not applied if it contains no changes, if `--dry-run` is specified, or if it is not
+/// confirmed on the terminal unless `--yes` is specified.
+pub(super) fn confirm_plan<A: PlanAction>(
+    plan: &[A],
+    matches: &ArgMatches,
+) -> miette::Result<bool> {
+    println!("{}", "Sync Plan:".bold());
+    for action in plan {
+        println!("    {action}");
+    }
+
+    if plan.iter().all(PlanAction::is_unchanged) {
+        println!("{}", "Already in sync.".green());
+        return Ok(false);
+    }
+
+    if matches.get_flag("dry-run") {
+        return Ok(false);
+    }
+
+    if !matches.get_flag("yes") && !confirm()? {
+        println!("{}", "Aborted.".yellow());
+        return Ok(false);
+    }
+
+    Ok(true)
+}
+
+/// Asks for confirmation on the terminal before applying the plan.
+fn confirm() -> miette::Result<bool> {
+    print!("Apply this plan? [y/N] ");
+    io::stdout().flush().into_diagnostic()?;
+
+    let mut answer = String::new();
+    io::stdin().read_line(&mut answer).into_diagnostic()?;
+
+    Ok(matches!(answer.trim(), "y" | "Y" | "yes"))
+}
+
+/// Fetches the commands registered at a URI, including their localizations.
+pub(super) async fn fetch(
+    client: &mut Client,
+    commands_uri: &str,
+) -> miette::Result<Vec<CommandManagerCommand>> {
+    let body = client
+        .request(
+            Method::GET,
+            &format!("{commands_uri}?with_localizations=true"),
+            None,
+        )
+        .await?;
+
+    serde_json::from_str(&body).into_diagnostic()
+}
@@ -20,26 +20,14 @@
  * with HarTex. If not, see <https://www.gnu.org/licenses/>.
  */
 
-use std::env;
-
 use clap::ArgMatches;
 use hartex_discord_core::dotenvy;
-use hartex_discord_core::tokio::task::spawn;
-use hartex_discord_utils::hyper::tls_stream;
 use hartex_log::log;
-use http_body_util::Empty;
-use hyper::body::Bytes;
-use hyper::client::conn::http1::handshake;
-use hyper::header::ACCEPT;
-use hyper::header::AUTHORIZATION;
-use hyper::header::CONTENT_LENGTH;
-use hyper::header::HOST;
-use hyper::header::USER_AGENT;
 use hyper::Method;
-use hyper::Request;
-use hyper_util::rt::TokioIo;
 use miette::IntoDiagnostic;
 
+use crate::client::Client;
+
 /// Unregistering a command.
 #[allow(clippy::module_name_repetitions)]
 pub async fn unregister_command(matches: ArgMatches) -> miette::Result<()> {
@@ -48,42 +36,15 @@ pub async fn unregister_command(matches: ArgMatches) -> miette::Result<()> {
 
     let command_id = matches.get_one::<String>("command-id").unwrap().clone();
 
-    log::trace!("making tcp connection");
-    let stream = tls_stream().await?;
-    let (mut sender, connection) = handshake(TokioIo::new(stream)).await.into_diagnostic()?;
-
-    spawn(async move {
-        if let Err(err) = connection.await {
-            log::error!("TCP connection failed: {:?}", err);
-        }
-    });
-
-    let application_id = env::var("APPLICATION_ID").into_diagnostic()?;
-
-    let mut token = env::var("BOT_TOKEN").into_diagnostic()?;
-    if !token.starts_with("Bot ") {
-        token.insert_str(0, "Bot ");
-    }
-
-    log::trace!("sending request");
-    let request = Request::builder()
-        .uri(format!(
-            "/api/v10/applications/{application_id}/commands/{command_id}"
-        ))
-        .method(Method::DELETE)
-        .header(HOST, "discord.com")
-        .header(ACCEPT, "application/json")
-        .header(AUTHORIZATION, token)
-        .header(CONTENT_LENGTH, 0)
-        .header(
-            USER_AGENT,
-            "DiscordBot (https://github.com/TeamHarTex/HarTex, v0.6.0) CommandsManager",
-        )
-        .body(Empty::<Bytes>::new())
-        .into_diagnostic()?;
+    let mut client = Client::from_env(&matches)?;
+    let uri = format!(
+        "{}/{command_id}",
+        super::commands_uri(client.application_id(), &matches)
+    );
 
-    let result = sender.send_request(request).await.into_diagnostic()?;
-    log::info!("received response with status {}", result.status());
+    log::trace!("unregistering command");
+    client.request(Method::DELETE, &uri, None).await?;
+    log::info!("unregistered command {command_id}");
 
     Ok(())
 }
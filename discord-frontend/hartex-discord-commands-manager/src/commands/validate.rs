@@ -0,0 +1,975 @@
+/*
+ * SPDX-License-Identifier: AGPL-3.0-only
+ *
+ * This file is part of HarTex.
+ *
+ * HarTex
+ * Copyright (c) 2021-2024 HarTex Project Developers
+ *
+ * HarTex is free software; you can redistribute it and/or modify
+ * it under the terms of the GNU Affero General Public License as published by
+ * the Free Software Foundation; either version 3 of the License, or
+ * (at your option) any later version.
+ *
+ * HarTex is distributed in the hope that it will be useful,
+ * but WITHOUT ANY WARRANTY; without even the implied warranty of
+ * MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+ * GNU Affero General Public License for more details.
+ *
+ * You should have received a copy of the GNU Affero General Public License along
+ * with HarTex. If not, see <https://www.gnu.org/licenses/>.
+ */
+
+use std::collections::HashMap;
+use std::collections::HashSet;
+use std::fs;
+
+use hartex_localization_loader::LocalizationBundleHolder;
+use hartex_log::log;
+use miette::IntoDiagnostic;
+use miette::LabeledSpan;
+use miette::NamedSource;
+use miette::Report;
+use miette::Severity;
+use miette::SourceSpan;
+use serde_json::Map;
+use serde_json::Value;
+use walkdir::WalkDir;
+
+use crate::localization;
+
+/// The path to the JSON schema of command specifications, relative to the discord-frontend
+/// directory.
+const SCHEMA_PATH: &str = "../schemas/cmdmgr-spec-schema-v1.json";
+
+/// The path to the locales of the localization infrastructure, relative to the discord-frontend
+/// directory.
+const LOCALES_PATH: &str = "../localization/locales";
+
+/// The locale of the names and descriptions of the specifications themselves.
+const DEFAULT_LOCALE: &str = "en-GB";
+
+/// The locales supported by Discord.
+const DISCORD_LOCALES: [&str; 32] = [
+    "id", "da", "de", "en-GB", "en-US", "es-ES", "es-419", "fr", "hr", "it", "lt", "hu", "nl",
+    "no", "pl", "pt-BR", "ro", "fi", "sv-SE", "vi", "tr", "cs", "el", "bg", "ru", "uk", "hi", "th",
+    "zh-CN", "ja", "zh-TW", "ko",
+];
+
+/// The maximum number of options, and of choices of an option.
+const MAX_OPTIONS: usize = 25;
+
+/// Validate the command specifications in the filesystem.
+///
+/// The specifications are validated with their localizations filled from the Fluent messages, as
+/// they would be registered.
+#[allow(clippy::module_name_repetitions)]
+pub fn validate_command() -> miette::Result<()> {
+    log::trace!("reading specification schema");
+    log::warn!(
+        "an error will occur if this command is not ran within the discord-frontend directory"
+    );
+    let schema = serde_json::from_str::<Value>(&fs::read_to_string(SCHEMA_PATH).into_diagnostic()?)
+        .into_diagnostic()?;
+    let schema = jsonschema::validator_for(&schema)
+        .map_err(|error| Report::msg(format!("invalid specification schema: {error}")))?;
+
+    log::trace!("reading locales");
+    let mut locales = Vec::new();
+    for entry in fs::read_dir(LOCALES_PATH).into_diagnostic()? {
+        let entry = entry.into_diagnostic()?;
+        if entry.file_type().into_diagnostic()?.is_dir()
+            && let Some(name) = entry.file_name().to_str()
+            && name != DEFAULT_LOCALE
+        {
+            locales.push(name.to_string());
+        }
+    }
+    locales.sort();
+
+    log::trace!("reading fluent messages");
+    let holder = LocalizationBundleHolder::load_localizations()?;
+
+    log::trace!("reading specification directory");
+    let mut errors = 0;
+    let mut warnings = 0;
+    for result in WalkDir::new("hartex-discord-commands-spec")
+        .same_file_system(true)
+        .sort_by_file_name()
+    {
+        let entry = result.into_diagnostic()?;
+        if entry.metadata().into_diagnostic()?.is_dir() {
+            continue;
+        }
+
+        if entry.path().extension().and_then(|ext| ext.to_str()) != Some("json") {
+            continue;
+        }
+
+        let path = entry.path().display().to_string();
+        let source = fs::read_to_string(entry.path()).into_diagnostic()?;
+
+        let validator = match serde_json::from_str::<Value>(&source) {
+            Ok(mut spec) => {
+                // the spans are only indexed once the source is known to be valid json
+                let mut validator =
+                    Validator::new(&path, &source, &locales, SpanIndex::new(&source));
+
+                localization::localize(&mut spec, &holder)?;
+
+                for error in schema.iter_errors(&spec) {
+                    validator.error(&error.instance_path.to_string(), error.to_string());
+                }
+
+                validator.validate_command(&spec);
+                validator
+            }
+            Err(error) => {
+                let mut validator =
+                    Validator::new(&path, &source, &locales, SpanIndex::empty(&source));
+                validator.syntax_error(&error);
+                validator
+            }
+        };
+
+        for diagnostic in validator.diagnostics {
+            if diagnostic.severity() == Some(Severity::Warning) {
+                warnings += 1;
+            } else {
+                errors += 1;
+            }
+
+            println!("{diagnostic:?}");
+        }
+    }
+
+    if errors > 0 {
+        miette::bail!(
+            "command specifications failed validation with {errors} error(s) and {warnings} warning(s)"
+        );
+    }
+
+    log::info!("command specifications are valid with {warnings} warning(s)");
+
+    Ok(())
+}
+
+/// The context in which a list of options is declared.
+#[derive(Clone, Copy, PartialEq)]
+enum OptionContext {
+    /// The options of a command.
+    Command,
+    /// The options of a subcommand group.
+    SubcommandGroup,
+    /// The options of a subcommand.
+    Subcommand,
+}
+
+/// The validator of a single command specification.
+struct Validator<'a> {
+    path: &'a str,
+    source: &'a str,
+    locales: &'a [String],
+    spans: SpanIndex,
+    diagnostics: Vec<Report>,
+}
+
+impl<'a> Validator<'a> {
+    fn new(path: &'a str, source: &'a str, locales: &'a [String], spans: SpanIndex) -> Self {
+        Self {
+            path,
+            source,
+            locales,
+            spans,
+            diagnostics: Vec::new(),
+        }
+    }
+
+    fn report(&mut self, severity: Severity, span: SourceSpan, message: String) {
+        let report = miette::miette!(
+            severity = severity,
+            labels = vec![LabeledSpan::underline(span)],
+            "{message}"
+        )
+        .with_source_code(NamedSource::new(self.path, self.source.to_string()));
+
+        self.diagnostics.push(report);
+    }
+
+    fn error(&mut self, pointer: &str, message: String) {
+        let span = self.spans.value(pointer);
+        self.report(Severity::Error, span, message);
+    }
+
+    fn warning(&mut self, pointer: &str, message: String) {
+        let span = self.spans.value(pointer);
+        self.report(Severity::Warning, span, message);
+    }
+
+    fn syntax_error(&mut self, error: &serde_json::Error) {
+        let offset = self
+            .source
+            .split_inclusive('\n')
+            .take(error.line().saturating_sub(1))
+            .map(str::len)
+            .sum::<usize>()
+            + error.column().saturating_sub(1);
+
+        self.report(
+            Severity::Error,
+            SourceSpan::from(offset.min(self.source.len())..offset.min(self.source.len())),
+            format!("invalid json: {error}"),
+        );
+    }
+
+    fn validate_command(&mut self, spec: &Value) {
+        let chat_input = spec.get("type").and_then(Value::as_u64).unwrap_or(1) == 1;
+
+        self.validate_name("", spec, chat_input);
+        if chat_input {
+            self.validate_description("", spec);
+        }
+
+        match spec.get("options").and_then(Value::as_array) {
+            Some(options) if !chat_input && !options.is_empty() => self.error(
+                "/options",
+                String::from("only chat input commands may have options"),
+            ),
+            Some(options) => self.validate_options("/options", options, OptionContext::Command),
+            None => {}
+        }
+    }
+
+    fn validate_options(&mut self, pointer: &str, options: &[Value], context: OptionContext) {
+        if options.len() > MAX_OPTIONS {
+            self.error(
+                pointer,
+                format!(
+                    "at most {MAX_OPTIONS} options may be specified, found {}",
+                    options.len()
+                ),
+            );
+        }
+
+        let kinds = options
+            .iter()
+            .map(|option| {
+                option
+                    .get("type")
+                    .and_then(Value::as_u64)
+                    .unwrap_or_default()
+            })
+            .collect::<Vec<_>>();
+        let subcommands = kinds.iter().filter(|kind| matches!(kind, 1 | 2)).count();
+        match context {
+            OptionContext::Command if subcommands != 0 && subcommands != options.len() => self
+                .error(
+                    pointer,
+                    String::from(
+                        "subcommands and subcommand groups cannot be mixed with other options",
+                    ),
+                ),
+            OptionContext::SubcommandGroup if kinds.iter().any(|kind| *kind != 1) => self.error(
+                pointer,
+                String::from("subcommand groups may only contain subcommands"),
+            ),
+            OptionContext::Subcommand if subcommands != 0 => self.error(
+                pointer,
+                String::from("subcommands cannot contain subcommands or subcommand groups"),
+            ),
+            _ => {}
+        }
+
+        let mut names = HashSet::new();
+        let mut optional = false;
+        for (index, option) in options.iter().enumerate() {
+            let option_pointer = format!("{pointer}/{index}");
+
+            if let Some(name) = option.get("name").and_then(Value::as_str)
+                && !names.insert(name)
+            {
+                self.error(
+                    &format!("{option_pointer}/name"),
+                    format!("option name `{name}` is already used by another option"),
+                );
+            }
+
+            self.validate_name(&option_pointer, option, true);
+            self.validate_description(&option_pointer, option);
+
+            if matches!(kinds[index], 1 | 2) {
+                let context = if kinds[index] == 1 {
+                    OptionContext::Subcommand
+                } else {
+                    OptionContext::SubcommandGroup
+                };
+
+                if let Some(options) = option.get("options").and_then(Value::as_array) {
+                    self.validate_options(&format!("{option_pointer}/options"), options, context);
+                }
+
+                continue;
+            }
+
+            if option
+                .get("required")
+                .and_then(Value::as_bool)
+                .unwrap_or(false)
+            {
+                if optional {
+                    self.error(
+                        &format!("{option_pointer}/required"),
+                        String::from("required options must be placed before optional options"),
+                    );
+                }
+            } else {
+                optional = true;
+            }
+
+            if let Some(choices) = option.get("choices").and_then(Value::as_array) {
+                self.validate_choices(&format!("{option_pointer}/choices"), option, choices);
+            }
+        }
+    }
+
+    fn validate_choices(&mut self, pointer: &str, option: &Value, choices: &[Value]) {
+        if choices.len() > MAX_OPTIONS {
+            self.error(
+                pointer,
+                format!(
+                    "at most {MAX_OPTIONS} choices may be specified, found {}",
+                    choices.len()
+                ),
+            );
+        }
+
+        if option
+            .get("autocomplete")
+            .and_then(Value::as_bool)
+            .unwrap_or(false)
+        {
+            self.error(
+                pointer,
+                String::from("options with autocomplete enabled cannot specify choices"),
+            );
+        }
+
+        for (index, choice) in choices.iter().enumerate() {
+            let choice_pointer = format!("{pointer}/{index}");
+
+            if let Some(name) = choice.get("name").and_then(Value::as_str) {
+                self.validate_length(&format!("{choice_pointer}/name"), "choice name", name, 100);
+            }
+
+            if let Some(value) = choice.get("value").and_then(Value::as_str) {
+                self.validate_length(
+                    &format!("{choice_pointer}/value"),
+                    "choice value",
+                    value,
+                    100,
+                );
+            }
+
+            if let Some(localizations) = choice.get("name_localizations").and_then(Value::as_object)
+            {
+                for (locale, name) in
+                    self.localizations(&choice_pointer, "name_localizations", localizations)
+                {
+                    self.validate_length(
+                        &format!("{choice_pointer}/name_localizations/{locale}"),
+                        "choice name",
+                        name,
+                        100,
+                    );
+                }
+            }
+        }
+    }
+
+    fn validate_name(&mut self, pointer: &str, spec: &Value, lowercase: bool) {
+        if let Some(name) = spec.get("name").and_then(Value::as_str) {
+            self.validate_name_value(&format!("{pointer}/name"), name, lowercase);
+        }
+
+        let localizations = spec.get("name_localizations").and_then(Value::as_object);
+        if let Some(localizations) = localizations {
+            for (locale, name) in self.localizations(pointer, "name_localizations", localizations) {
+                self.validate_name_value(
+                    &format!("{pointer}/name_localizations/{locale}"),
+                    name,
+                    lowercase,
+                );
+            }
+        }
+
+        self.validate_completeness(pointer, "name_localizations", localizations);
+    }
+
+    fn validate_name_value(&mut self, pointer: &str, name: &str, lowercase: bool) {
+        self.validate_length(pointer, "name", name, 32);
+
+        if !name
+            .chars()
+            .all(|char| char.is_alphanumeric() || char == '-' || char == '_')
+        {
+            self.error(
+                pointer,
+                format!("name `{name}` may only contain letters, numbers, hyphens and underscores"),
+            );
+        } else if lowercase && name.to_lowercase() != name {
+            self.error(pointer, format!("name `{name}` must be lowercase"));
+        }
+    }
+
+    fn validate_description(&mut self, pointer: &str, spec: &Value) {
+        if let Some(description) = spec.get("description").and_then(Value::as_str) {
+            self.validate_length(
+                &format!("{pointer}/description"),
+                "description",
+                description,
+                100,
+            );
+        }
+
+        let localizations = spec
+            .get("description_localizations")
+            .and_then(Value::as_object);
+        if let Some(localizations) = localizations {
+            for (locale, description) in
+                self.localizations(pointer, "description_localizations", localizations)
+            {
+                self.validate_length(
+                    &format!("{pointer}/description_localizations/{locale}"),
+                    "description",
+                    description,
+                    100,
+                );
+            }
+        }
+
+        self.validate_completeness(pointer, "description_localizations", localizations);
+    }
+
+    fn validate_length(&mut self, pointer: &str, what: &str, value: &str, max: usize) {
+        let length = value.chars().count();
+        if length == 0 || length > max {
+            self.error(
+                pointer,
+                format!("{what} must be between 1 and {max} characters long, found {length}"),
+            );
+        }
+    }
+
+    fn validate_completeness(
+        &mut self,
+        pointer: &str,
+        field: &str,
+        localizations: Option<&Map<String, Value>>,
+    ) {
+        let missing = self
+            .locales
+            .iter()
+            .filter(|locale| {
+                localizations.is_none_or(|localizations| !localizations.contains_key(*locale))
+            })
+            .cloned()
+            .collect::<Vec<_>>();
+        if missing.is_empty() {
+            return;
+        }
+
+        let pointer = if localizations.is_some() {
+            format!("{pointer}/{field}")
+        } else {
+            pointer.to_string()
+        };
+        self.warning(
+            &pointer,
+            format!("missing {field} for locale(s): {}", missing.join(", ")),
+        );
+    }
+
+    /// Reports localization keys that are not locales supported by Discord, returning the string
+    /// localizations of the supported locales.
+    fn localizations<'v>(
+        &mut self,
+        pointer: &str,
+        field: &str,
+        localizations: &'v Map<String, Value>,
+    ) -> Vec<(&'v str, &'v str)> {
+        let mut valid = Vec::new();
+        for (locale, value) in localizations {
+            if !DISCORD_LOCALES.contains(&locale.as_str()) {
+                let span = self.spans.key(&format!("{pointer}/{field}/{locale}"));
+                self.report(
+                    Severity::Error,
+                    span,
+                    format!("`{locale}` is not a locale supported by Discord"),
+                );
+
+                continue;
+            }
+
+            if let Some(value) = value.as_str() {
+                valid.push((locale.as_str(), value));
+            }
+        }
+
+        valid
+    }
+}
+
+/// An index of the spans of the keys and values in a JSON document, by their JSON pointers.
+struct SpanIndex {
+    length: usize,
+    keys: HashMap<String, SourceSpan>,
+    values: HashMap<String, SourceSpan>,
+}
+
+impl SpanIndex {
+    /// Indexes the spans of a JSON document.
+    ///
+    /// The document is expected to be valid JSON; malformed documents are indexed on a best-effort
+    /// basis.
+    fn new(source: &str) -> Self {
+        let mut index = Self::empty(source);
+
+        let mut position = 0;
+        index.index_value(source.as_bytes(), &mut position, String::new());
+
+        index
+    }
+
+    /// An index without any spans, resolving every pointer to the entire document.
+    fn empty(source: &str) -> Self {
+        Self {
+            length: source.len(),
+            keys: HashMap::new(),
+            values: HashMap::new(),
+        }
+    }
+
+    /// The span of the key of the member with the given pointer, or that of its value if it is not
+    /// an object member.
+    fn key(&self, pointer: &str) -> SourceSpan {
+        self.keys
+            .get(pointer)
+            .copied()
+            .unwrap_or_else(|| self.value(pointer))
+    }
+
+    /// The span of the value with the given pointer, or that of its closest indexed ancestor.
+    fn value(&self, pointer: &str) -> SourceSpan {
+        let mut pointer = pointer;
+        loop {
+            if let Some(span) = self.values.get(pointer) {
+                return *span;
+            }
+
+            match pointer.rfind('/') {
+                Some(index) => pointer = &pointer[..index],
+                None => return SourceSpan::from(0..self.length),
+            }
+        }
+    }
+
+    fn index_value(&mut self, bytes: &[u8], position: &mut usize, pointer: String) {
+        skip_whitespace(bytes, position);
+        let start = *position;
+
+        match bytes.get(start) {
+            Some(b'{') => {
+                *position += 1;
+                loop {
+                    skip_whitespace(bytes, position);
+                    match bytes.get(*position) {
+                        Some(b'"') => {}
+                        Some(b',') => {
+                            *position += 1;
+                            continue;
+                        }
+                        _ => break,
+                    }
+
+                    let key_start = *position;
+                    skip_string(bytes, position);
+                    if *position >= bytes.len() {
+                        break;
+                    }
+
+                    let key = serde_json::from_slice::<String>(&bytes[key_start..*position])
+                        .unwrap_or_default();
+                    let member = format!("{pointer}/{}", key.replace('~', "~0").replace('/', "~1"));
+                    self.keys
+                        .insert(member.clone(), SourceSpan::from(key_start..*position));
+
+                    skip_whitespace(bytes, position);
+                    if bytes.get(*position) == Some(&b':') {
+                        *position += 1;
+                    }
+
+                    self.index_value(bytes, position, member);
+                }
+                *position += 1;
+            }
+            Some(b'[') => {
+                *position += 1;
+                let mut index = 0;
+                loop {
+                    skip_whitespace(bytes, position);
+                    match bytes.get(*position) {
+                        Some(b']') | None => break,
+                        Some(b',') => {
+                            *position += 1;
+                            continue;
+                        }
+                        _ => {}
+                    }
+
+                    let element_start = *position;
+                    self.index_value(bytes, position, format!("{pointer}/{index}"));
+                    if *position == element_start {
+                        // not the start of a value, such as a mismatched closing brace
+                        *position += 1;
+                    }
+                    index += 1;
+                }
+                *position += 1;
+            }
+            Some(b'"') => skip_string(bytes, position),
+            Some(b'}' | b']') => return,
+            Some(_) => {
+                while let Some(byte) = bytes.get(*position)
+                    && !matches!(byte, b',' | b'}' | b']')
+                    && !byte.is_ascii_whitespace()
+                {
+                    *position += 1;
+                }
+            }
+            None => return,
+        }
+
+        *position = (*position).min(bytes.len());
+        self.values
+            .insert(pointer, SourceSpan::from(start..*position));
+    }
+}
+
+fn skip_whitespace(bytes: &[u8], position: &mut usize) {
+    while bytes.get(*position).is_some_and(u8::is_ascii_whitespace) {
+        *position += 1;
+    }
+}
+
+/// Skips a string, or the rest of the document if the string is unterminated.
+fn skip_string(bytes: &[u8], position: &mut usize) {
+    *position += 1;
+    while let Some(byte) = bytes.get(*position) {
+        *position += 1;
+        match byte {
+            b'\\' => *position += 1,
+            b'"' => break,
+            _ => {}
+        }
+    }
+
+    // an escape at the end of the document would otherwise skip past it
+    *position = (*position).min(bytes.len());
+}
+
+#[cfg(test)]
+mod tests {
+    use miette::Severity;
+    use miette::SourceSpan;
+    use serde_json::json;
+    use serde_json::Value;
+
+    use super::SpanIndex;
+    use super::Validator;
+
+    /// Validates a specification, returning the severities and messages of the diagnostics.
+    fn validate(spec: &Value, locales: &[&str]) -> Vec<(Severity, String)> {
+        let source = serde_json::to_string_pretty(spec).unwrap();
+        let locales = locales.iter().map(ToString::to_string).collect::<Vec<_>>();
+
+        let mut validator = Validator::new("spec.json", &source, &locales, SpanIndex::new(&source));
+        validator.validate_command(spec);
+
+        validator
+            .diagnostics
+            .iter()
+            .map(|report| {
+                (
+                    report.severity().unwrap_or(Severity::Error),
+                    report.to_string(),
+                )
+            })
+            .collect()
+    }
+
+    fn errors(spec: &Value) -> Vec<String> {
+        validate(spec, &[])
+            .into_iter()
+            .filter(|(severity, _)| *severity == Severity::Error)
+            .map(|(_, message)| message)
+            .collect()
+    }
+
+    fn command(options: &Value) -> Value {
+        json!({
+            "name": "command",
+            "description": "A command.",
+            "options": options,
+        })
+    }
+
+    fn option(name: &str, kind: u64) -> Value {
+        json!({
+            "type": kind,
+            "name": name,
+            "description": "An option.",
+        })
+    }
+
+    #[test]
+    fn span_index_indexes_keys_and_values() {
+        let source = r#"{"name": "command", "options": [{"name": "user"}, 2]}"#;
+        let index = SpanIndex::new(source);
+
+        assert_eq!(index.key("/name"), SourceSpan::from(1..7));
+        assert_eq!(index.value("/name"), SourceSpan::from(9..18));
+        assert_eq!(index.value("/options/0/name"), SourceSpan::from(41..47));
+        assert_eq!(index.value("/options/1"), SourceSpan::from(50..51));
+        assert_eq!(index.value(""), SourceSpan::from(0..source.len()));
+    }
+
+    #[test]
+    fn span_index_falls_back_to_closest_ancestor() {
+        let source = r#"{"options": [{"name": "user"}]}"#;
+        let index = SpanIndex::new(source);
+
+        assert_eq!(
+            index.value("/options/0/description"),
+            index.value("/options/0")
+        );
+        assert_eq!(index.key("/options/0"), index.value("/options/0"));
+        assert_eq!(
+            SpanIndex::empty(source).value("/options"),
+            SourceSpan::from(0..source.len())
+        );
+    }
+
+    #[test]
+    fn span_index_terminates_on_mismatched_brackets() {
+        for source in ["[1}", "[}", "{\"a\": [1}}", "]", "{\"a\" 1 x}"] {
+            let index = SpanIndex::new(source);
+
+            assert!(index.value("/0").len() <= source.len());
+        }
+    }
+
+    #[test]
+    fn span_index_terminates_on_unterminated_strings() {
+        for source in ["{\"a\\", "{\"a", "[\"a\\", "\"\\"] {
+            let index = SpanIndex::new(source);
+
+            assert!(index.key("/a").offset() + index.key("/a").len() <= source.len());
+        }
+    }
+
+    #[test]
+    fn valid_command_has_no_diagnostics() {
+        let spec = command(&json!([option("user", 6)]));
+
+        assert!(validate(&spec, &[]).is_empty());
+    }
+
+    #[test]
+    fn name_length_is_validated() {
+        let spec = json!({ "name": "a".repeat(33), "description": "A command." });
+
+        assert_eq!(
+            errors(&spec),
+            ["name must be between 1 and 32 characters long, found 33"]
+        );
+        assert_eq!(
+            errors(&json!({ "name": "", "description": "A command." })),
+            ["name must be between 1 and 32 characters long, found 0"]
+        );
+    }
+
+    #[test]
+    fn name_characters_are_validated() {
+        let spec = json!({ "name": "no spaces", "description": "A command." });
+
+        assert_eq!(
+            errors(&spec),
+            ["name `no spaces` may only contain letters, numbers, hyphens and underscores"]
+        );
+    }
+
+    #[test]
+    fn chat_input_names_must_be_lowercase() {
+        let spec = json!({ "name": "Command", "description": "A command." });
+        assert_eq!(errors(&spec), ["name `Command` must be lowercase"]);
+
+        let spec = json!({ "type": 2, "name": "Command" });
+        assert!(errors(&spec).is_empty());
+    }
+
+    #[test]
+    fn description_length_is_validated() {
+        let spec = json!({ "name": "command", "description": "a".repeat(101) });
+
+        assert_eq!(
+            errors(&spec),
+            ["description must be between 1 and 100 characters long, found 101"]
+        );
+    }
+
+    #[test]
+    fn only_chat_input_commands_may_have_options() {
+        let spec = json!({ "type": 3, "name": "message", "options": [option("user", 6)] });
+
+        assert_eq!(errors(&spec), ["only chat input commands may have options"]);
+    }
+
+    #[test]
+    fn option_count_is_limited() {
+        let options = (0..26)
+            .map(|index| option(&format!("option{index}"), 3))
+            .collect::<Vec<_>>();
+
+        assert_eq!(
+            errors(&command(&json!(options))),
+            ["at most 25 options may be specified, found 26"]
+        );
+    }
+
+    #[test]
+    fn subcommands_cannot_be_mixed_with_other_options() {
+        let spec = command(&json!([option("list", 1), option("user", 6)]));
+
+        assert_eq!(
+            errors(&spec),
+            ["subcommands and subcommand groups cannot be mixed with other options"]
+        );
+    }
+
+    #[test]
+    fn subcommand_groups_may_only_contain_subcommands() {
+        let mut group = option("group", 2);
+        group["options"] = json!([option("user", 6)]);
+
+        assert_eq!(
+            errors(&command(&json!([group]))),
+            ["subcommand groups may only contain subcommands"]
+        );
+    }
+
+    #[test]
+    fn subcommands_cannot_be_nested() {
+        let mut subcommand = option("list", 1);
+        subcommand["options"] = json!([option("all", 1)]);
+
+        assert_eq!(
+            errors(&command(&json!([subcommand]))),
+            ["subcommands cannot contain subcommands or subcommand groups"]
+        );
+    }
+
+    #[test]
+    fn option_names_must_be_unique() {
+        let spec = command(&json!([option("user", 6), option("user", 3)]));
+
+        assert_eq!(
+            errors(&spec),
+            ["option name `user` is already used by another option"]
+        );
+    }
+
+    #[test]
+    fn required_options_precede_optional_options() {
+        let mut required = option("reason", 3);
+        required["required"] = json!(true);
+        let spec = command(&json!([option("user", 6), required]));
+
+        assert_eq!(
+            errors(&spec),
+            ["required options must be placed before optional options"]
+        );
+    }
+
+    #[test]
+    fn choice_count_is_limited() {
+        let mut choices = option("choice", 3);
+        choices["choices"] = (0..26)
+            .map(|index| json!({ "name": format!("choice {index}"), "value": "value" }))
+            .collect();
+
+        assert_eq!(
+            errors(&command(&json!([choices]))),
+            ["at most 25 choices may be specified, found 26"]
+        );
+    }
+
+    #[test]
+    fn choices_conflict_with_autocomplete() {
+        let mut choices = option("choice", 3);
+        choices["autocomplete"] = json!(true);
+        choices["choices"] = json!([{ "name": "choice", "value": "value" }]);
+
+        assert_eq!(
+            errors(&command(&json!([choices]))),
+            ["options with autocomplete enabled cannot specify choices"]
+        );
+    }
+
+    #[test]
+    fn choice_lengths_are_validated() {
+        let mut choices = option("choice", 3);
+        choices["choices"] = json!([{ "name": "", "value": "a".repeat(101) }]);
+
+        assert_eq!(
+            errors(&command(&json!([choices]))),
+            [
+                "choice name must be between 1 and 100 characters long, found 0",
+                "choice value must be between 1 and 100 characters long, found 101",
+            ]
+        );
+    }
+
+    #[test]
+    fn localization_locales_must_be_supported_by_discord() {
+        let spec = json!({
+            "name": "command",
+            "name_localizations": { "ja": "command", "xx": "command" },
+            "description": "A command.",
+        });
+
+        assert_eq!(errors(&spec), ["`xx` is not a locale supported by Discord"]);
+    }
+
+    #[test]
+    fn missing_localizations_are_warned_about() {
+        let spec = json!({
+            "name": "command",
+            "name_localizations": { "ja": "command" },
+            "description": "A command.",
+        });
+
+        assert_eq!(
+            validate(&spec, &["ja", "zh-CN"]),
+            [
+                (
+                    Severity::Warning,
+                    String::from("missing name_localizations for locale(s): zh-CN")
+                ),
+                (
+                    Severity::Warning,
+                    String::from("missing description_localizations for locale(s): ja, zh-CN")
+                ),
+            ]
+        );
+    }
+}
@@ -0,0 +1,144 @@
+/*
+ * SPDX-License-Identifier: AGPL-3.0-only
+ *
+ * This file is part of HarTex.
+ *
+ * HarTex
+ * Copyright (c) 2021-2024 HarTex Project Developers
+ *
+ * HarTex is free software; you can redistribute it and/or modify
+ * it under the terms of the GNU Affero General Public License as published by
+ * the Free Software Foundation; either version 3 of the License, or
+ * (at your option) any later version.
+ *
+ * HarTex is distributed in the hope that it will be useful,
+ * but WITHOUT ANY WARRANTY; without even the implied warranty of
+ * MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+ * GNU Affero General Public License for more details.
+ *
+ * You should have received a copy of the GNU Affero General Public License along
+ * with HarTex. If not, see <https://www.gnu.org/licenses/>.
+ */
+
+//! # Command Localizations
+//!
+//! The names and descriptions of commands, options and choices are localized with dedicated
+//! Fluent messages in the localization infrastructure, which take precedence over the
+//! localizations declared in the specifications. The messages are named after the path to what
+//! they localize:
+//!
+//! - `command-{command}-name` and `command-{command}-description` for commands;
+//! - `command-{command}-{option}-name` and `command-{command}-{option}-description` for options,
+//!   with the names of the options of subcommands and subcommand groups appended in turn;
+//! - `command-{command}-{option}-choice-{value}-name` for choices, where characters of the value
+//!   not allowed in message names are replaced with hyphens.
+
+use hartex_localization_loader::LocalizationBundleHolder;
+use miette::IntoDiagnostic;
+use serde_json::Map;
+use serde_json::Value;
+
+/// The language of the names and descriptions declared in the specifications.
+const DEFAULT_LANGUAGE: &str = "en-GB";
+
+/// Fill the localizations of a command specification in JSON from the Fluent messages.
+pub fn localize_json(json: &str) -> miette::Result<String> {
+    let holder = LocalizationBundleHolder::load_localizations()?;
+
+    let mut spec = serde_json::from_str::<Value>(json).into_diagnostic()?;
+    localize(&mut spec, &holder)?;
+
+    serde_json::to_string(&spec).into_diagnostic()
+}
+
+/// Fill the localizations of a command specification from the Fluent messages.
+pub fn localize(spec: &mut Value, holder: &LocalizationBundleHolder) -> miette::Result<()> {
+    let Some(name) = spec.get("name").and_then(Value::as_str) else {
+        return Ok(());
+    };
+
+    let mut languages = holder
+        .languages()
+        .filter(|language| *language != DEFAULT_LANGUAGE)
+        .collect::<Vec<_>>();
+    languages.sort_unstable();
+
+    let message = format!("command-{name}");
+    localize_entry(spec, &message, &["name", "description"], &languages, holder)
+}
+
+fn localize_entry(
+    entry: &mut Value,
+    message: &str,
+    fields: &[&str],
+    languages: &[&str],
+    holder: &LocalizationBundleHolder,
+) -> miette::Result<()> {
+    let Some(object) = entry.as_object_mut() else {
+        return Ok(());
+    };
+
+    for field in fields {
+        for language in languages {
+            let Some(localized) = holder.format_message(language, &format!("{message}-{field}"))?
+            else {
+                continue;
+            };
+
+            let localizations = object
+                .entry(format!("{field}_localizations"))
+                .or_insert_with(|| Value::Object(Map::new()));
+            if !localizations.is_object() {
+                *localizations = Value::Object(Map::new());
+            }
+
+            if let Some(localizations) = localizations.as_object_mut() {
+                localizations.insert((*language).to_string(), Value::String(localized));
+            }
+        }
+    }
+
+    if let Some(options) = object.get_mut("options").and_then(Value::as_array_mut) {
+        for option in options {
+            let Some(name) = option.get("name").and_then(Value::as_str) else {
+                continue;
+            };
+
+            let message = format!("{message}-{name}");
+            localize_entry(
+                option,
+                &message,
+                &["name", "description"],
+                languages,
+                holder,
+            )?;
+        }
+    }
+
+    if let Some(choices) = object.get_mut("choices").and_then(Value::as_array_mut) {
+        for choice in choices {
+            let value = match choice.get("value") {
+                Some(Value::String(value)) => value.clone(),
+                Some(value) => value.to_string(),
+                None => continue,
+            };
+
+            let value = value
+                .to_lowercase()
+                .chars()
+                .map(|char| {
+                    if char.is_ascii_alphanumeric() || char == '_' {
+                        char
+                    } else {
+                        '-'
+                    }
+                })
+                .collect::<String>();
+
+            let message = format!("{message}-choice-{value}");
+            localize_entry(choice, &message, &["name"], languages, holder)?;
+        }
+    }
+
+    Ok(())
+}
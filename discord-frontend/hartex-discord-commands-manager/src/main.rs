@@ -37,8 +37,10 @@ use clap::ArgAction;
 use clap::Command;
 use hartex_discord_core::tokio;
 
+mod client;
 mod cmdline;
 mod commands;
+mod localization;
 mod model;
 
 /// Maneger entry point.
@@ -47,6 +49,53 @@ pub async fn main() -> miette::Result<()> {
     hartex_log::initialize();
 
     let command = Command::new("cmdmgr")
+        .arg(
+            Arg::new("api-url")
+                .long("api-url")
+                .help("The base URL of the Discord API, overriding the DISCORD_API_URL environment variable.")
+                .global(true)
+                .action(ArgAction::Set),
+        )
+        .arg(
+            Arg::new("guild")
+                .long("guild")
+                .short('g')
+                .help("Targets the commands of a guild instead of the global commands.")
+                .global(true)
+                .action(ArgAction::Set),
+        )
+        .subcommand(
+            Command::new("diff")
+                .about("Shows the differences between the commands declared in the filesystem and those registered with Discord.")
+                .arg(
+                    Arg::new("exit-code")
+                        .long("exit-code")
+                        .help("Exits with an error if there are differences.")
+                        .num_args(0)
+                        .action(ArgAction::SetTrue),
+                )
+                .arg(
+                    Arg::new("format")
+                        .long("format")
+                        .short('f')
+                        .help("The format of the output.")
+                        .value_parser(["text", "json"])
+                        .default_value("text")
+                        .action(ArgAction::Set),
+                ),
+        )
+        .subcommand(
+            Command::new("export")
+                .about("Exports the commands registered with Discord to JSON files in the layout of the filesystem.")
+                .arg(
+                    Arg::new("output")
+                        .long("output")
+                        .short('o')
+                        .help("The directory to export the commands to.")
+                        .default_value("hartex-discord-commands-export")
+                        .action(ArgAction::Set),
+                ),
+        )
         .subcommand(
             Command::new("list-from-discord")
                 .about("Lists commands registered with Discord.")
@@ -67,15 +116,96 @@ pub async fn main() -> miette::Result<()> {
                 .arg(Arg::new("command").required(true).action(ArgAction::Set))
                 .arg(Arg::new("command-id").required(true).action(ArgAction::Set)),
         )
+        .subcommand(
+            Command::new("permissions")
+                .about("Manages the command permission overrides of the guild specified with --guild.")
+                .subcommand_required(true)
+                .subcommand(
+                    Command::new("list").about("Lists the command permission overrides."),
+                )
+                .subcommand(
+                    Command::new("set")
+                        .about("Sets the command permission overrides to those declared in a file.")
+                        .arg(Arg::new("file").required(true).action(ArgAction::Set))
+                        .arg(
+                            Arg::new("dry-run")
+                                .long("dry-run")
+                                .short('n')
+                                .help("Prints the plan without applying it.")
+                                .num_args(0)
+                                .action(ArgAction::SetTrue),
+                        )
+                        .arg(
+                            Arg::new("yes")
+                                .long("yes")
+                                .short('y')
+                                .help("Applies the plan without asking for confirmation.")
+                                .num_args(0)
+                                .action(ArgAction::SetTrue),
+                        ),
+                ),
+        )
+        .subcommand(
+            Command::new("promote")
+                .about("Promotes the commands of the guild specified with --guild to global commands.")
+                .arg(
+                    Arg::new("dry-run")
+                        .long("dry-run")
+                        .short('n')
+                        .help("Prints the plan without applying it.")
+                        .num_args(0)
+                        .action(ArgAction::SetTrue),
+                )
+                .arg(
+                    Arg::new("yes")
+                        .long("yes")
+                        .short('y')
+                        .help("Applies the plan without asking for confirmation.")
+                        .num_args(0)
+                        .action(ArgAction::SetTrue),
+                ),
+        )
         .subcommand(
             Command::new("register")
                 .about("Registers a command with Discord.")
                 .arg(Arg::new("command").required(true).action(ArgAction::Set)),
         )
+        .subcommand(
+            Command::new("sync")
+                .about("Synchronizes the commands registered with Discord with those declared in the filesystem.")
+                .arg(
+                    Arg::new("bulk")
+                        .long("bulk")
+                        .short('b')
+                        .help("Applies the plan with a single bulk overwrite.")
+                        .num_args(0)
+                        .action(ArgAction::SetTrue),
+                )
+                .arg(
+                    Arg::new("dry-run")
+                        .long("dry-run")
+                        .short('n')
+                        .help("Prints the plan without applying it.")
+                        .num_args(0)
+                        .action(ArgAction::SetTrue),
+                )
+                .arg(
+                    Arg::new("yes")
+                        .long("yes")
+                        .short('y')
+                        .help("Applies the plan without asking for confirmation.")
+                        .num_args(0)
+                        .action(ArgAction::SetTrue),
+                ),
+        )
         .subcommand(
             Command::new("unregister")
                 .about("Unregisters a command from Discord")
                 .arg(Arg::new("command-id").required(true).action(ArgAction::Set)),
+        )
+        .subcommand(
+            Command::new("validate")
+                .about("Validates the command specifications against the specification schema and the rules of Discord."),
         );
 
     let matches = command.get_matches();
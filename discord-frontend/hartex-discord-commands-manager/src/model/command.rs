@@ -40,12 +40,17 @@ use super::TypeEnumExt;
 #[allow(clippy::module_name_repetitions)]
 #[derive(Debug, Deserialize, Serialize)]
 pub struct CommandManagerCommand {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub contexts: Option<Vec<u8>>,
     pub default_member_permissions: Option<Permissions>,
     #[deprecated(note = "use default_member_permissions instead")]
     #[serde(skip_serializing_if = "Option::is_none")]
     pub default_permissions: Option<bool>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub dm_permission: Option<bool>,
+    /// The id of the command, only present for commands registered with Discord.
+    #[serde(default, skip_serializing)]
+    pub id: Option<String>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub description: Option<String>,
     #[serde(skip_serializing_if = "Option::is_none")]
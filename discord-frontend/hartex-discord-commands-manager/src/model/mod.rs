@@ -32,6 +32,7 @@ use owo_colors::OwoColorize;
 
 pub mod command;
 pub mod option;
+pub mod permissions;
 
 /// Display extensions.
 pub trait DisplayExt {
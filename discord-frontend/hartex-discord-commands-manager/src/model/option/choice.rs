@@ -73,7 +73,7 @@ impl CommandManagerCommandOptionChoice {
 
 /// Command option choice value.
 #[allow(clippy::module_name_repetitions)]
-#[derive(Debug, Deserialize, Serialize)]
+#[derive(Debug, Deserialize, PartialEq, Serialize)]
 #[serde(untagged)]
 pub enum CommandManagerCommandOptionChoiceValue {
     String(String),
@@ -0,0 +1,88 @@
+/*
+ * SPDX-License-Identifier: AGPL-3.0-only
+ *
+ * This file is part of HarTex.
+ *
+ * HarTex
+ * Copyright (c) 2021-2024 HarTex Project Developers
+ *
+ * HarTex is free software; you can redistribute it and/or modify
+ * it under the terms of the GNU Affero General Public License as published by
+ * the Free Software Foundation; either version 3 of the License, or
+ * (at your option) any later version.
+ *
+ * HarTex is distributed in the hope that it will be useful,
+ * but WITHOUT ANY WARRANTY; without even the implied warranty of
+ * MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+ * GNU Affero General Public License for more details.
+ *
+ * You should have received a copy of the GNU Affero General Public License along
+ * with HarTex. If not, see <https://www.gnu.org/licenses/>.
+ */
+
+use std::collections::BTreeMap;
+
+use hartex_discord_core::discord::model::application::command::permissions::CommandPermission;
+use hartex_discord_core::discord::model::application::command::permissions::CommandPermissionType;
+use miette::IntoDiagnostic;
+use serde::Deserialize;
+
+/// Command permission overrides of a guild as declared in a permissions file, by the names of the
+/// commands they apply to.
+///
+/// The overrides declared for `*` apply to every command of the application without overrides of
+/// its own. For example, the following denies every command in a channel but allows the `plugins`
+/// command for a role:
+///
+/// ```json
+/// {
+///   "*": [{ "type": "channel", "id": "1000000000000000001", "permission": false }],
+///   "plugins": [{ "type": "role", "id": "1000000000000000002", "permission": true }]
+/// }
+/// ```
+#[allow(clippy::module_name_repetitions)]
+pub type CommandManagerPermissionsFile = BTreeMap<String, Vec<CommandManagerPermissionOverride>>;
+
+/// Command permission override.
+///
+/// Refer to the corresponding API documentation on discord official website.
+#[allow(clippy::module_name_repetitions)]
+#[derive(Debug, Deserialize)]
+pub struct CommandManagerPermissionOverride {
+    pub id: String,
+    #[serde(rename = "type")]
+    pub kind: CommandManagerPermissionOverrideKind,
+    pub permission: bool,
+}
+
+/// The kind of the entity a command permission override applies to.
+#[allow(clippy::module_name_repetitions)]
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum CommandManagerPermissionOverrideKind {
+    Channel,
+    Role,
+    User,
+}
+
+impl CommandManagerPermissionOverride {
+    /// Converts the override to a command permission to be sent to Discord.
+    pub fn to_permission(&self) -> miette::Result<CommandPermission> {
+        let id = match self.kind {
+            CommandManagerPermissionOverrideKind::Channel => {
+                CommandPermissionType::Channel(self.id.parse().into_diagnostic()?)
+            }
+            CommandManagerPermissionOverrideKind::Role => {
+                CommandPermissionType::Role(self.id.parse().into_diagnostic()?)
+            }
+            CommandManagerPermissionOverrideKind::User => {
+                CommandPermissionType::User(self.id.parse().into_diagnostic()?)
+            }
+        };
+
+        Ok(CommandPermission {
+            id,
+            permission: self.permission,
+        })
+    }
+}
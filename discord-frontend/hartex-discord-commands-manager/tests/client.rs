@@ -0,0 +1,198 @@
+/*
+ * SPDX-License-Identifier: AGPL-3.0-only
+ *
+ * This file is part of HarTex.
+ *
+ * HarTex
+ * Copyright (c) 2021-2024 HarTex Project Developers
+ *
+ * HarTex is free software; you can redistribute it and/or modify
+ * it under the terms of the GNU Affero General Public License as published by
+ * the Free Software Foundation; either version 3 of the License, or
+ * (at your option) any later version.
+ *
+ * HarTex is distributed in the hope that it will be useful,
+ * but WITHOUT ANY WARRANTY; without even the implied warranty of
+ * MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+ * GNU Affero General Public License for more details.
+ *
+ * You should have received a copy of the GNU Affero General Public License along
+ * with HarTex. If not, see <https://www.gnu.org/licenses/>.
+ */
+
+use std::env;
+use std::fs;
+use std::io::BufRead;
+use std::io::BufReader;
+use std::io::Read;
+use std::io::Write;
+use std::net::TcpListener;
+use std::path::PathBuf;
+use std::process::Command;
+use std::process::Output;
+use std::sync::Arc;
+use std::sync::Mutex;
+use std::thread;
+
+/// A request received by the mock server.
+struct MockRequest {
+    authorization: Option<String>,
+    method: String,
+    path: String,
+}
+
+/// Serves the given responses in turn over HTTP, returning the base URL of the server and the
+/// requests it received.
+fn mock_server(responses: Vec<(u16, &'static str)>) -> (String, Arc<Mutex<Vec<MockRequest>>>) {
+    let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+    let url = format!("http://{}/api/v10", listener.local_addr().unwrap());
+    let requests = Arc::new(Mutex::new(Vec::new()));
+
+    let received = requests.clone();
+    thread::spawn(move || {
+        let mut responses = responses.into_iter();
+        for stream in listener.incoming() {
+            let Ok(mut stream) = stream else {
+                continue;
+            };
+            let mut reader = BufReader::new(stream.try_clone().unwrap());
+
+            // connections are kept alive, so serve requests until the client closes it
+            loop {
+                let mut request_line = String::new();
+                if reader.read_line(&mut request_line).unwrap_or(0) == 0 {
+                    break;
+                }
+
+                let mut parts = request_line.split_whitespace();
+                let mut request = MockRequest {
+                    method: parts.next().unwrap_or_default().to_string(),
+                    path: parts.next().unwrap_or_default().to_string(),
+                    authorization: None,
+                };
+
+                let mut content_length = 0;
+                loop {
+                    let mut line = String::new();
+                    reader.read_line(&mut line).unwrap();
+                    let line = line.trim_end();
+                    if line.is_empty() {
+                        break;
+                    }
+
+                    if let Some((name, value)) = line.split_once(':') {
+                        match name.to_ascii_lowercase().as_str() {
+                            "authorization" => {
+                                request.authorization = Some(value.trim().to_string());
+                            }
+                            "content-length" => content_length = value.trim().parse().unwrap(),
+                            _ => {}
+                        }
+                    }
+                }
+
+                let mut body = vec![0; content_length];
+                reader.read_exact(&mut body).unwrap();
+                received.lock().unwrap().push(request);
+
+                let (status, body) = responses.next().unwrap_or((500, "{}"));
+                write!(
+                    stream,
+                    "HTTP/1.1 {status} Mock\r\ncontent-type: application/json\r\ncontent-length: {}\r\n\r\n{body}",
+                    body.len()
+                )
+                .unwrap();
+            }
+        }
+    });
+
+    (url, requests)
+}
+
+/// Runs the commands manager against the given base URL.
+fn cmdmgr(url: &str, args: &[&str]) -> Output {
+    // the commands manager requires an environment file to be present
+    let directory =
+        env::temp_dir().join(format!("cmdmgr-{}-{}", std::process::id(), args.join("-")));
+    fs::create_dir_all(&directory).unwrap();
+    fs::write(directory.join(".env"), "").unwrap();
+
+    Command::new(PathBuf::from(env!(
+        "CARGO_BIN_EXE_hartex_discord_commands_manager"
+    )))
+    .args(args)
+    .current_dir(&directory)
+    .env("APPLICATION_ID", "1234")
+    .env("BOT_TOKEN", "token")
+    .env("DISCORD_API_URL", url)
+    .env("NO_COLOR", "1")
+    .output()
+    .unwrap()
+}
+
+/// The standard error of the commands manager, with whitespace and line wrapping normalized.
+fn stderr(output: &Output) -> String {
+    String::from_utf8_lossy(&output.stderr)
+        .split_whitespace()
+        .filter(|word| {
+            !word
+                .chars()
+                .all(|char| matches!(char, '│' | '×' | '╰' | '─' | '▶'))
+        })
+        .collect::<Vec<_>>()
+        .join(" ")
+}
+
+#[test]
+fn rate_limited_requests_are_retried() {
+    let (url, requests) = mock_server(vec![
+        (
+            429,
+            r#"{"message": "You are being rate limited.", "retry_after": 0.05, "global": false}"#,
+        ),
+        (
+            200,
+            r#"[{"id": "1", "type": 1, "name": "about", "description": "About HarTex"}]"#,
+        ),
+    ]);
+
+    let output = cmdmgr(&url, &["list-from-discord"]);
+    assert!(output.status.success(), "{}", stderr(&output));
+    assert!(String::from_utf8_lossy(&output.stdout).contains("about"));
+
+    let requests = requests.lock().unwrap();
+    assert_eq!(requests.len(), 2);
+    for request in requests.iter() {
+        assert_eq!(request.method, "GET");
+        assert_eq!(request.path, "/api/v10/applications/1234/commands");
+        assert_eq!(request.authorization.as_deref(), Some("Bot token"));
+    }
+}
+
+#[test]
+fn error_responses_fail_with_their_message() {
+    let (url, requests) = mock_server(vec![(
+        400,
+        r#"{"code": 50035, "message": "Invalid Form Body", "errors": {"name": {"_errors": [{"code": "BASE_TYPE_REQUIRED", "message": "This field is required"}]}}}"#,
+    )]);
+
+    let output = cmdmgr(&url, &["list-from-discord", "--guild", "5678"]);
+    assert!(!output.status.success());
+
+    let stderr = stderr(&output);
+    assert!(
+        stderr.contains("Invalid Form Body (error code 50035)"),
+        "{stderr}"
+    );
+    assert!(
+        stderr.contains("name: This field is required (BASE_TYPE_REQUIRED)"),
+        "{stderr}"
+    );
+
+    let requests = requests.lock().unwrap();
+    assert_eq!(requests.len(), 1);
+    assert_eq!(
+        requests[0].path,
+        "/api/v10/applications/1234/guilds/5678/commands"
+    );
+}
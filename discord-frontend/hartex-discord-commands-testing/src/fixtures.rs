@@ -0,0 +1,227 @@
+/*
+ * SPDX-License-Identifier: AGPL-3.0-only
+ *
+ * This file is part of HarTex.
+ *
+ * HarTex
+ * Copyright (c) 2021-2024 HarTex Project Developers
+ *
+ * HarTex is free software; you can redistribute it and/or modify
+ * it under the terms of the GNU Affero General Public License as published by
+ * the Free Software Foundation; either version 3 of the License, or
+ * (at your option) any later version.
+ *
+ * HarTex is distributed in the hope that it will be useful,
+ * but WITHOUT ANY WARRANTY; without even the implied warranty of
+ * MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+ * GNU Affero General Public License for more details.
+ *
+ * You should have received a copy of the GNU Affero General Public License along
+ * with HarTex. If not, see <https://www.gnu.org/licenses/>.
+ */
+
+//! # Interaction Fixtures
+//!
+//! Constructs application command interactions for tests, either from a builder or from JSON
+//! fixture files in the format of Discord gateway payloads.
+
+use std::fs;
+use std::path::Path;
+use std::sync::atomic::AtomicU64;
+use std::sync::atomic::Ordering;
+
+use hartex_discord_core::discord::model::application::command::CommandOptionType;
+use hartex_discord_core::discord::model::application::interaction::Interaction;
+use hartex_discord_core::discord::model::guild::Permissions;
+use serde_json::json;
+use serde_json::Value;
+
+/// The id of the application in fixtures.
+pub const APPLICATION_ID: u64 = 1000;
+/// The id of the channel in fixtures, unless overridden.
+pub const CHANNEL_ID: u64 = 2000;
+/// The id of the guild in fixtures, unless overridden.
+pub const GUILD_ID: u64 = 3000;
+/// The id of the invoking user in fixtures, unless overridden.
+pub const USER_ID: u64 = 4000;
+
+/// The next interaction id, such that requests concerning different interactions can be told
+/// apart.
+static NEXT_INTERACTION_ID: AtomicU64 = AtomicU64::new(5000);
+
+/// Loads an interaction from a JSON fixture file.
+///
+/// # Panics
+///
+/// Panics when the file cannot be read, or does not contain a valid interaction.
+pub fn load(path: impl AsRef<Path>) -> Interaction {
+    let path = path.as_ref();
+    let json = fs::read_to_string(path)
+        .unwrap_or_else(|error| panic!("failed to read {}: {error}", path.display()));
+
+    serde_json::from_str(&json)
+        .unwrap_or_else(|error| panic!("invalid interaction in {}: {error}", path.display()))
+}
+
+/// Constructs a command option.
+#[must_use]
+pub fn option(name: &str, kind: CommandOptionType, value: impl Into<Value>) -> Value {
+    json!({
+        "name": name,
+        "type": u8::from(kind),
+        "value": value.into(),
+    })
+}
+
+/// A builder for application command interactions, invoked in a guild by default.
+pub struct InteractionFixture {
+    interaction: Value,
+}
+
+impl InteractionFixture {
+    /// Creates an invocation of a command.
+    #[must_use]
+    pub fn command(name: &str) -> Self {
+        let id = NEXT_INTERACTION_ID.fetch_add(1, Ordering::Relaxed);
+
+        Self {
+            interaction: json!({
+                "id": id.to_string(),
+                "application_id": APPLICATION_ID.to_string(),
+                "type": 2,
+                "token": format!("token-{id}"),
+                "version": 1,
+                "app_permissions": Permissions::all().bits().to_string(),
+                "channel_id": CHANNEL_ID.to_string(),
+                "entitlements": [],
+                "guild_id": GUILD_ID.to_string(),
+                "guild_locale": "en-GB",
+                "locale": "en-GB",
+                "member": {
+                    "avatar": null,
+                    "deaf": false,
+                    "flags": 0,
+                    "joined_at": "2024-01-01T00:00:00.000000+00:00",
+                    "mute": false,
+                    "nick": null,
+                    "pending": false,
+                    "permissions": Permissions::all().bits().to_string(),
+                    "roles": [],
+                    "user": {
+                        "avatar": null,
+                        "discriminator": "0",
+                        "global_name": "Tester",
+                        "id": USER_ID.to_string(),
+                        "username": "tester",
+                    },
+                },
+                "data": {
+                    "id": "1",
+                    "name": name,
+                    "type": 1,
+                    "options": [],
+                },
+            }),
+        }
+    }
+
+    /// Sets the guild the command is invoked in.
+    #[must_use]
+    pub fn guild_id(mut self, guild_id: u64) -> Self {
+        self.interaction["guild_id"] = json!(guild_id.to_string());
+        self
+    }
+
+    /// Makes the command invoked in a direct message with the bot rather than in a guild.
+    #[must_use]
+    pub fn direct_message(mut self) -> Self {
+        let object = self.interaction.as_object_mut().unwrap();
+        object.remove("guild_id");
+        object.remove("guild_locale");
+
+        let member = object.remove("member").unwrap();
+        object.insert(String::from("user"), member["user"].clone());
+        self
+    }
+
+    /// Sets the user invoking the command.
+    #[must_use]
+    pub fn user_id(mut self, user_id: u64) -> Self {
+        self.interaction["member"]["user"]["id"] = json!(user_id.to_string());
+        self
+    }
+
+    /// Sets the locale of the invoking user.
+    #[must_use]
+    pub fn locale(mut self, locale: &str) -> Self {
+        self.interaction["locale"] = json!(locale);
+        self
+    }
+
+    /// Sets the permissions of the invoking member.
+    #[must_use]
+    pub fn permissions(mut self, permissions: Permissions) -> Self {
+        self.interaction["member"]["permissions"] = json!(permissions.bits().to_string());
+        self
+    }
+
+    /// Sets the roles of the invoking member.
+    #[must_use]
+    pub fn roles(mut self, roles: &[u64]) -> Self {
+        self.interaction["member"]["roles"] = roles.iter().map(ToString::to_string).collect();
+        self
+    }
+
+    /// Adds an option to the invocation.
+    #[must_use]
+    pub fn option(mut self, option: Value) -> Self {
+        self.options().push(option);
+        self
+    }
+
+    /// Invokes a subcommand with the given options.
+    #[must_use]
+    pub fn subcommand(self, name: &str, options: Vec<Value>) -> Self {
+        self.option(json!({
+            "name": name,
+            "type": u8::from(CommandOptionType::SubCommand),
+            "options": options,
+        }))
+    }
+
+    /// Invokes a subcommand of a subcommand group with the given options.
+    #[must_use]
+    pub fn subcommand_group(self, group: &str, name: &str, options: Vec<Value>) -> Self {
+        self.option(json!({
+            "name": group,
+            "type": u8::from(CommandOptionType::SubCommandGroup),
+            "options": [{
+                "name": name,
+                "type": u8::from(CommandOptionType::SubCommand),
+                "options": options,
+            }],
+        }))
+    }
+
+    /// Sets the data resolved from the options, such as users and roles.
+    #[must_use]
+    pub fn resolved(mut self, resolved: Value) -> Self {
+        self.interaction["data"]["resolved"] = resolved;
+        self
+    }
+
+    /// Builds the interaction.
+    ///
+    /// # Panics
+    ///
+    /// Panics when the constructed interaction is invalid.
+    #[must_use]
+    pub fn build(self) -> Interaction {
+        serde_json::from_value(self.interaction).unwrap()
+    }
+
+    /// The options of the invocation.
+    fn options(&mut self) -> &mut Vec<Value> {
+        self.interaction["data"]["options"].as_array_mut().unwrap()
+    }
+}
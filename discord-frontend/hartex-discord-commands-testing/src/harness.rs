@@ -0,0 +1,133 @@
+/*
+ * SPDX-License-Identifier: AGPL-3.0-only
+ *
+ * This file is part of HarTex.
+ *
+ * HarTex
+ * Copyright (c) 2021-2024 HarTex Project Developers
+ *
+ * HarTex is free software; you can redistribute it and/or modify
+ * it under the terms of the GNU Affero General Public License as published by
+ * the Free Software Foundation; either version 3 of the License, or
+ * (at your option) any later version.
+ *
+ * HarTex is distributed in the hope that it will be useful,
+ * but WITHOUT ANY WARRANTY; without even the implied warranty of
+ * MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+ * GNU Affero General Public License for more details.
+ *
+ * You should have received a copy of the GNU Affero General Public License along
+ * with HarTex. If not, see <https://www.gnu.org/licenses/>.
+ */
+
+//! # Command Harness
+//!
+//! Executes commands against the mock Discord HTTP server, returning the requests they send.
+
+use std::env;
+use std::sync::Once;
+
+use hartex_discord_commands_core::middleware::MiddlewareChain;
+use hartex_discord_commands_core::traits::Command;
+use hartex_discord_configuration_provider::memory as configuration;
+use hartex_discord_core::discord::model::application::interaction::Interaction;
+use hartex_discord_entitycache_repositories::memory as cache;
+use hartex_discord_utils::CLIENT;
+use hartex_localization_core::Localizer;
+use hartex_localization_core::LOCALIZATION_HOLDER;
+use once_cell::sync::Lazy;
+
+use crate::http::CapturedRequest;
+use crate::http::MOCK_DISCORD;
+
+/// Guards the preparation of the environment.
+static PREPARE: Once = Once::new();
+
+/// Prepares the environment for commands to be executed offline.
+///
+/// This starts the mock Discord HTTP server, installs the in-memory entity cache and configuration
+/// backends, and points the Discord HTTP client, the API backend domain and the localization
+/// files at their test counterparts. It is called by [`execute`],
+/// and must otherwise be called before any of them is used.
+pub fn prepare() {
+    PREPARE.call_once(|| {
+        let address = MOCK_DISCORD.address().to_string();
+
+        env::set_var("API_DOMAIN", &address);
+        env::set_var("DISCORD_HTTP_PROXY", &address);
+        if env::var_os("BOT_TOKEN").is_none() {
+            env::set_var("BOT_TOKEN", "token");
+        }
+        if env::var_os("LOCALIZATION_BASE_PATH").is_none() {
+            env::set_var(
+                "LOCALIZATION_BASE_PATH",
+                concat!(env!("CARGO_MANIFEST_DIR"), "/../../localization/locales"),
+            );
+        }
+
+        configuration::install();
+        cache::install();
+        Lazy::force(&CLIENT);
+    });
+}
+
+/// Executes a command, returning the requests it has sent concerning the interaction.
+///
+/// # Errors
+///
+/// Returns an error when the command fails to execute.
+pub async fn execute(
+    command: &(dyn Command + Send + Sync),
+    interaction: Interaction,
+) -> miette::Result<Vec<CapturedRequest>> {
+    prepare();
+
+    let id = interaction.id;
+    let token = interaction.token.clone();
+    let locale = interaction
+        .locale
+        .clone()
+        .unwrap_or_else(|| String::from("en-GB"));
+
+    let localizer = Localizer::new(&LOCALIZATION_HOLDER, &locale);
+    let interaction_client = CLIENT.interaction(interaction.application_id);
+
+    command
+        .execute(interaction, &interaction_client, localizer)
+        .await?;
+
+    Ok(MOCK_DISCORD.requests_for(id, &token))
+}
+
+/// Runs a command through a middleware chain, followed by the middlewares of its plugin and the
+/// command itself, returning the requests it has sent concerning the interaction.
+///
+/// Unlike [`execute`], this exercises the middlewares applied to the command when invoked.
+///
+/// # Errors
+///
+/// Returns an error when a middleware fails to inspect the invocation, or when the command fails
+/// to execute.
+pub async fn run(
+    middlewares: &MiddlewareChain,
+    command: &(dyn Command + Send + Sync),
+    interaction: Interaction,
+) -> miette::Result<Vec<CapturedRequest>> {
+    prepare();
+
+    let id = interaction.id;
+    let token = interaction.token.clone();
+    let locale = interaction
+        .locale
+        .clone()
+        .unwrap_or_else(|| String::from("en-GB"));
+
+    let localizer = Localizer::new(&LOCALIZATION_HOLDER, &locale);
+    let interaction_client = CLIENT.interaction(interaction.application_id);
+
+    middlewares
+        .run(command, interaction, &interaction_client, localizer)
+        .await?;
+
+    Ok(MOCK_DISCORD.requests_for(id, &token))
+}
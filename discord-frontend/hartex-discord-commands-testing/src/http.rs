@@ -0,0 +1,265 @@
+/*
+ * SPDX-License-Identifier: AGPL-3.0-only
+ *
+ * This file is part of HarTex.
+ *
+ * HarTex
+ * Copyright (c) 2021-2024 HarTex Project Developers
+ *
+ * HarTex is free software; you can redistribute it and/or modify
+ * it under the terms of the GNU Affero General Public License as published by
+ * the Free Software Foundation; either version 3 of the License, or
+ * (at your option) any later version.
+ *
+ * HarTex is distributed in the hope that it will be useful,
+ * but WITHOUT ANY WARRANTY; without even the implied warranty of
+ * MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+ * GNU Affero General Public License for more details.
+ *
+ * You should have received a copy of the GNU Affero General Public License along
+ * with HarTex. If not, see <https://www.gnu.org/licenses/>.
+ */
+
+//! # Mock Discord HTTP Server
+//!
+//! A mock Discord HTTP server, to which the proxied Discord HTTP client sends its requests
+//! during tests. Every request is captured, and responded to with the response registered for its
+//! route, if any.
+//!
+//! The server runs on a dedicated thread, such that it outlives the runtimes of individual tests.
+
+use std::collections::HashMap;
+use std::convert::Infallible;
+use std::net::SocketAddr;
+use std::sync::Arc;
+use std::sync::Mutex;
+use std::thread;
+
+use hartex_discord_core::discord::model::id::marker::InteractionMarker;
+use hartex_discord_core::discord::model::id::Id;
+use hartex_discord_core::tokio::net::TcpListener;
+use hartex_discord_core::tokio::runtime::Builder;
+use hartex_discord_core::tokio::spawn;
+use http_body_util::BodyExt;
+use http_body_util::Full;
+use hyper::body::Bytes;
+use hyper::body::Incoming;
+use hyper::header::CONTENT_TYPE;
+use hyper::server::conn::http1;
+use hyper::service::service_fn;
+use hyper::Request;
+use hyper::Response;
+use hyper::StatusCode;
+use hyper_util::rt::TokioIo;
+use once_cell::sync::Lazy;
+use serde_json::Value;
+
+/// The mock Discord HTTP server shared by the tests of a test binary.
+pub static MOCK_DISCORD: Lazy<MockDiscord> = Lazy::new(MockDiscord::start);
+
+/// A request captured by the mock Discord HTTP server.
+#[derive(Clone, Debug)]
+pub struct CapturedRequest {
+    /// The method of the request.
+    pub method: String,
+    /// The path of the request, such as `/api/v10/interactions/1/token/callback`.
+    pub path: String,
+    /// The JSON body of the request, or `null` if the request has no JSON body.
+    pub body: Value,
+}
+
+impl CapturedRequest {
+    /// The content of the message sent by the request, if any.
+    #[must_use]
+    pub fn content(&self) -> Option<&str> {
+        self.message().get("content").and_then(Value::as_str)
+    }
+
+    /// The embeds of the message sent by the request.
+    #[must_use]
+    pub fn embeds(&self) -> &[Value] {
+        self.message()
+            .get("embeds")
+            .and_then(Value::as_array)
+            .map_or(&[], Vec::as_slice)
+    }
+
+    /// The components of the message sent by the request.
+    #[must_use]
+    pub fn components(&self) -> &[Value] {
+        self.message()
+            .get("components")
+            .and_then(Value::as_array)
+            .map_or(&[], Vec::as_slice)
+    }
+
+    /// The flags of the message sent by the request.
+    #[must_use]
+    pub fn flags(&self) -> u64 {
+        self.message()
+            .get("flags")
+            .and_then(Value::as_u64)
+            .unwrap_or_default()
+    }
+
+    /// The message sent by the request, which is nested under `data` for interaction responses.
+    fn message(&self) -> &Value {
+        self.body.get("data").unwrap_or(&self.body)
+    }
+}
+
+/// The state of the mock Discord HTTP server.
+#[derive(Default)]
+struct State {
+    requests: Vec<CapturedRequest>,
+    routes: HashMap<(String, String), (StatusCode, Value)>,
+}
+
+/// The mock Discord HTTP server.
+pub struct MockDiscord {
+    address: SocketAddr,
+    state: Arc<Mutex<State>>,
+}
+
+impl MockDiscord {
+    /// Starts the mock Discord HTTP server on a dedicated thread.
+    fn start() -> Self {
+        let listener = std::net::TcpListener::bind("127.0.0.1:0").unwrap();
+        listener.set_nonblocking(true).unwrap();
+
+        let address = listener.local_addr().unwrap();
+        let state = Arc::new(Mutex::new(State::default()));
+
+        let cloned = state.clone();
+        thread::spawn(move || {
+            Builder::new_multi_thread()
+                .enable_all()
+                .build()
+                .unwrap()
+                .block_on(serve(listener, cloned));
+        });
+
+        Self { address, state }
+    }
+
+    /// The address the mock Discord HTTP server is listening on.
+    #[must_use]
+    pub fn address(&self) -> SocketAddr {
+        self.address
+    }
+
+    /// Registers the response to a route.
+    ///
+    /// Requests to routes without a registered response are responded to with `204 No Content`
+    /// for interaction callbacks, and `404 Not Found` otherwise.
+    ///
+    /// # Panics
+    ///
+    /// Panics when the state lock is poisoned.
+    pub fn route(&self, method: &str, path: &str, status: StatusCode, body: Value) {
+        self.state
+            .lock()
+            .unwrap()
+            .routes
+            .insert((method.to_uppercase(), path.to_string()), (status, body));
+    }
+
+    /// Returns the captured requests concerning an interaction.
+    ///
+    /// # Panics
+    ///
+    /// Panics when the state lock is poisoned.
+    #[must_use]
+    pub fn requests_for(&self, id: Id<InteractionMarker>, token: &str) -> Vec<CapturedRequest> {
+        let id = format!("/{id}/");
+        let token = format!("/{token}");
+
+        self.state
+            .lock()
+            .unwrap()
+            .requests
+            .iter()
+            .filter(|request| request.path.contains(&id) || request.path.contains(&token))
+            .cloned()
+            .collect()
+    }
+
+    /// Returns every captured request.
+    ///
+    /// # Panics
+    ///
+    /// Panics when the state lock is poisoned.
+    #[must_use]
+    pub fn requests(&self) -> Vec<CapturedRequest> {
+        self.state.lock().unwrap().requests.clone()
+    }
+}
+
+/// Accepts connections, serving each of them on a separate task.
+async fn serve(listener: std::net::TcpListener, state: Arc<Mutex<State>>) {
+    let listener = TcpListener::from_std(listener).unwrap();
+
+    loop {
+        let Ok((stream, _)) = listener.accept().await else {
+            continue;
+        };
+
+        let state = state.clone();
+        spawn(async move {
+            let service = service_fn(move |request| handle(request, state.clone()));
+
+            let _ = http1::Builder::new()
+                .serve_connection(TokioIo::new(stream), service)
+                .await;
+        });
+    }
+}
+
+/// Captures a request and responds with the response registered for its route.
+async fn handle(
+    request: Request<Incoming>,
+    state: Arc<Mutex<State>>,
+) -> Result<Response<Full<Bytes>>, Infallible> {
+    let method = request.method().to_string();
+    let path = request.uri().path().to_string();
+    let bytes = request
+        .into_body()
+        .collect()
+        .await
+        .map(|collected| collected.to_bytes())
+        .unwrap_or_default();
+    let body = serde_json::from_slice(&bytes).unwrap_or(Value::Null);
+
+    let (status, response) = {
+        let mut state = state.lock().unwrap();
+        let response = state
+            .routes
+            .get(&(method.clone(), path.clone()))
+            .cloned()
+            .unwrap_or_else(|| {
+                if path.ends_with("/callback") {
+                    (StatusCode::NO_CONTENT, Value::Null)
+                } else {
+                    (
+                        StatusCode::NOT_FOUND,
+                        serde_json::json!({ "code": 0, "message": "404: Not Found" }),
+                    )
+                }
+            });
+        state.requests.push(CapturedRequest { method, path, body });
+
+        response
+    };
+
+    let bytes = if status == StatusCode::NO_CONTENT {
+        Bytes::new()
+    } else {
+        Bytes::from(serde_json::to_vec(&response).unwrap())
+    };
+
+    Ok(Response::builder()
+        .status(status)
+        .header(CONTENT_TYPE, "application/json")
+        .body(Full::new(bytes))
+        .unwrap())
+}
@@ -0,0 +1,46 @@
+/*
+ * SPDX-License-Identifier: AGPL-3.0-only
+ *
+ * This file is part of HarTex.
+ *
+ * HarTex
+ * Copyright (c) 2021-2024 HarTex Project Developers
+ *
+ * HarTex is free software; you can redistribute it and/or modify
+ * it under the terms of the GNU Affero General Public License as published by
+ * the Free Software Foundation; either version 3 of the License, or
+ * (at your option) any later version.
+ *
+ * HarTex is distributed in the hope that it will be useful,
+ * but WITHOUT ANY WARRANTY; without even the implied warranty of
+ * MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+ * GNU Affero General Public License for more details.
+ *
+ * You should have received a copy of the GNU Affero General Public License along
+ * with HarTex. If not, see <https://www.gnu.org/licenses/>.
+ */
+
+//! # Command Test Harness
+//!
+//! This crate provides an offline test harness for command implementations: interactions are
+//! constructed from fixtures, the requests a command sends to Discord are captured by a mock
+//! Discord HTTP server, and the entity cache and the configuration of guilds are held in memory.
+//!
+//! ```ignore
+//! let interaction = InteractionFixture::command("about").build();
+//! let requests = harness::execute(&About, interaction).await?;
+//!
+//! snapshot::assert_snapshot("about", &requests[0].body);
+//! ```
+
+#![deny(clippy::pedantic)]
+#![deny(unsafe_code)]
+#![deny(warnings)]
+
+pub use hartex_discord_configuration_provider::memory as configuration;
+pub use hartex_discord_entitycache_repositories::memory as cache;
+
+pub mod fixtures;
+pub mod harness;
+pub mod http;
+pub mod snapshot;
@@ -0,0 +1,68 @@
+/*
+ * SPDX-License-Identifier: AGPL-3.0-only
+ *
+ * This file is part of HarTex.
+ *
+ * HarTex
+ * Copyright (c) 2021-2024 HarTex Project Developers
+ *
+ * HarTex is free software; you can redistribute it and/or modify
+ * it under the terms of the GNU Affero General Public License as published by
+ * the Free Software Foundation; either version 3 of the License, or
+ * (at your option) any later version.
+ *
+ * HarTex is distributed in the hope that it will be useful,
+ * but WITHOUT ANY WARRANTY; without even the implied warranty of
+ * MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+ * GNU Affero General Public License for more details.
+ *
+ * You should have received a copy of the GNU Affero General Public License along
+ * with HarTex. If not, see <https://www.gnu.org/licenses/>.
+ */
+
+//! # Snapshots
+//!
+//! Compares JSON values, such as captured responses, against snapshots committed alongside the
+//! tests.
+//!
+//! Set the `UPDATE_SNAPSHOTS` environment variable to rewrite the snapshots instead.
+
+use std::env;
+use std::fs;
+use std::path::Path;
+
+use serde_json::Value;
+
+/// Asserts that a value matches its snapshot.
+///
+/// # Panics
+///
+/// Panics when the value does not match its snapshot, or when the snapshot cannot be read or
+/// written.
+pub fn assert_snapshot(path: impl AsRef<Path>, actual: &Value) {
+    let path = path.as_ref();
+
+    if env::var_os("UPDATE_SNAPSHOTS").is_some() {
+        if let Some(parent) = path.parent() {
+            fs::create_dir_all(parent).unwrap();
+        }
+
+        let mut json = serde_json::to_string_pretty(actual).unwrap();
+        json.push('\n');
+        fs::write(path, json).unwrap();
+
+        return;
+    }
+
+    let snapshot = fs::read_to_string(path)
+        .unwrap_or_else(|error| panic!("failed to read snapshot {}: {error}", path.display()));
+    let expected = serde_json::from_str::<Value>(&snapshot).unwrap();
+
+    assert!(
+        &expected == actual,
+        "value diverged from snapshot {}; rerun with `UPDATE_SNAPSHOTS=1` to update it\n\
+        expected: {expected:#}\n\
+        actual: {actual:#}",
+        path.display()
+    );
+}
@@ -42,7 +42,11 @@ use miette::IntoDiagnostic;
 use crate::general::General;
 
 /// The `about` command declaration.
-#[command(name = "about", plugin = General)]
+#[command(
+    name = "about",
+    plugin = General,
+    description = "About HarTex",
+)]
 pub struct About;
 
 #[async_trait]
@@ -40,7 +40,11 @@ use miette::IntoDiagnostic;
 use crate::general::General;
 
 /// The `contributors` command declaration.
-#[command(name = "contributors", plugin = General)]
+#[command(
+    name = "contributors",
+    plugin = General,
+    description = "Awesome people who contribute to HarTex",
+)]
 pub struct Contributors;
 
 #[async_trait]
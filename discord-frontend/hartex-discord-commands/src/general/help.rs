@@ -0,0 +1,491 @@
+/*
+ * SPDX-License-Identifier: AGPL-3.0-only
+ *
+ * This file is part of HarTex.
+ *
+ * HarTex
+ * Copyright (c) 2021-2024 HarTex Project Developers
+ *
+ * HarTex is free software; you can redistribute it and/or modify
+ * it under the terms of the GNU Affero General Public License as published by
+ * the Free Software Foundation; either version 3 of the License, or
+ * (at your option) any later version.
+ *
+ * HarTex is distributed in the hope that it will be useful,
+ * but WITHOUT ANY WARRANTY; without even the implied warranty of
+ * MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+ * GNU Affero General Public License for more details.
+ *
+ * You should have received a copy of the GNU Affero General Public License along
+ * with HarTex. If not, see <https://www.gnu.org/licenses/>.
+ */
+//! # The Help Command
+//!
+//! This command lists the commands available to the user grouped by their plugins, or shows the
+//! details of a specific command. The listing and the details can be navigated with a select menu.
+
+use std::collections::BTreeMap;
+
+use async_trait::async_trait;
+use hartex_discord_commands_core::command;
+use hartex_discord_commands_core::middleware::permissions::PermissionLevel;
+use hartex_discord_commands_core::middleware::permissions::RequiredPermissions;
+use hartex_discord_commands_core::middleware::plugin::PluginEnabled;
+use hartex_discord_commands_core::middleware::MiddlewareChain;
+use hartex_discord_commands_core::options::resolved;
+use hartex_discord_commands_core::options::CommandOptions;
+use hartex_discord_commands_core::spec::CommandOptionSpec;
+use hartex_discord_commands_core::traits::Command;
+use hartex_discord_commands_core::traits::CommandMetadata;
+use hartex_discord_commands_core::traits::PluginMetadata;
+use hartex_discord_commands_core::CommandOptions;
+use hartex_discord_core::discord::http::client::InteractionClient;
+use hartex_discord_core::discord::model::application::command::CommandOptionType;
+use hartex_discord_core::discord::model::application::interaction::Interaction;
+use hartex_discord_core::discord::model::application::interaction::InteractionData;
+use hartex_discord_core::discord::model::channel::message::component::ActionRow;
+use hartex_discord_core::discord::model::channel::message::component::SelectMenu;
+use hartex_discord_core::discord::model::channel::message::component::SelectMenuOption;
+use hartex_discord_core::discord::model::channel::message::component::SelectMenuType;
+use hartex_discord_core::discord::model::channel::message::Component;
+use hartex_discord_core::discord::model::channel::message::Embed;
+use hartex_discord_core::discord::model::http::interaction::InteractionResponse;
+use hartex_discord_core::discord::model::http::interaction::InteractionResponseType;
+use hartex_discord_core::discord::util::builder::embed::EmbedBuilder;
+use hartex_discord_core::discord::util::builder::embed::EmbedFieldBuilder;
+use hartex_discord_core::discord::util::builder::InteractionResponseDataBuilder;
+use hartex_discord_utils::interaction::ephemeral_error_response;
+use hartex_discord_utils::markdown::MarkdownStyle;
+use hartex_localization_core::Localizer;
+use hartex_localization_core::LOCALIZATION_HOLDER;
+use miette::IntoDiagnostic;
+
+use crate::general::General;
+
+/// The custom id of the select menu for navigating the help pages.
+const SELECT_MENU_CUSTOM_ID: &str = "help:select";
+
+/// The value of the select menu option leading back to the command listing.
+const OVERVIEW_VALUE: &str = "*";
+
+/// The maximum number of options a select menu may have.
+const MAX_SELECT_MENU_OPTIONS: usize = 25;
+
+/// The maximum length of the label and description of a select menu option.
+const MAX_SELECT_MENU_OPTION_LENGTH: usize = 100;
+
+/// The options of the `help` command.
+#[derive(CommandOptions)]
+struct HelpOptions {
+    command: Option<String>,
+}
+
+/// The `help` command declaration.
+#[command(
+    name = "help",
+    plugin = General,
+    description = "Lists the commands available to you, or shows the details of a command.",
+    options = [
+        string(
+            name = "command",
+            description = "The command to show the details of.",
+            required = false,
+        ),
+    ],
+)]
+pub struct Help;
+
+#[async_trait]
+impl Command for Help {
+    async fn execute(
+        &self,
+        interaction: Interaction,
+        interaction_client: &InteractionClient<'_>,
+        localizer: Localizer<'_>,
+    ) -> miette::Result<()> {
+        let Some(InteractionData::ApplicationCommand(data)) = interaction.data.clone() else {
+            miette::bail!("interaction is not an application command");
+        };
+        let options =
+            HelpOptions::from_options(&data.options, resolved(&interaction)).into_diagnostic()?;
+
+        let commands = visible_commands(&interaction).await?;
+
+        let response = match options.command {
+            Some(query) => {
+                let Some(command) = find_command(&commands, &query)? else {
+                    interaction_client
+                        .create_response(
+                            interaction.id,
+                            &interaction.token,
+                            &ephemeral_error_response(
+                                localizer.general_plugin_help_error_unknown_command(
+                                    query.discord_inline_code(),
+                                )?,
+                            ),
+                        )
+                        .await
+                        .into_diagnostic()?;
+
+                    return Ok(());
+                };
+
+                page_response(
+                    InteractionResponseType::ChannelMessageWithSource,
+                    detail_embed(command, &localizer)?,
+                    &commands,
+                    &localizer,
+                )?
+            }
+            None => page_response(
+                InteractionResponseType::ChannelMessageWithSource,
+                overview_embed(&commands, &localizer)?,
+                &commands,
+                &localizer,
+            )?,
+        };
+
+        interaction_client
+            .create_response(interaction.id, &interaction.token, &response)
+            .await
+            .into_diagnostic()?;
+
+        Ok(())
+    }
+
+    async fn handle_component(
+        &self,
+        interaction: Interaction,
+        interaction_client: &InteractionClient<'_>,
+        localizer: Localizer<'_>,
+    ) -> miette::Result<()> {
+        let Some(InteractionData::MessageComponent(data)) = interaction.data.clone() else {
+            miette::bail!("interaction is not a message component interaction");
+        };
+        if data.custom_id != SELECT_MENU_CUSTOM_ID {
+            miette::bail!("unknown help component `{}`", data.custom_id);
+        }
+
+        let commands = visible_commands(&interaction).await?;
+
+        let selected = data.values.first().map_or(OVERVIEW_VALUE, String::as_str);
+        let embed = match commands.iter().find(|command| command.name() == selected) {
+            Some(command) => detail_embed(command.as_ref(), &localizer)?,
+            None => overview_embed(&commands, &localizer)?,
+        };
+
+        interaction_client
+            .create_response(
+                interaction.id,
+                &interaction.token,
+                &page_response(
+                    InteractionResponseType::UpdateMessage,
+                    embed,
+                    &commands,
+                    &localizer,
+                )?,
+            )
+            .await
+            .into_diagnostic()?;
+
+        Ok(())
+    }
+}
+
+/// Returns the commands the invoking user may see, that is those usable where the help command is
+/// invoked and that the invoking user would be permitted to run.
+///
+/// Commands are checked against the same access control middlewares as their invocations, along
+/// with the middlewares of their plugins and themselves, such as [`DashboardAdmin`].
+///
+/// [`DashboardAdmin`]: hartex_discord_commands_core::middleware::permissions::DashboardAdmin
+async fn visible_commands(
+    interaction: &Interaction,
+) -> miette::Result<Vec<Box<dyn Command + Send + Sync>>> {
+    let middlewares = MiddlewareChain::new()
+        .with(PluginEnabled)
+        .with(RequiredPermissions)
+        .with(PermissionLevel);
+
+    let mut commands = Vec::new();
+    for command in crate::commands() {
+        if interaction.guild_id.is_none() && command.spec().dm_permission == Some(false) {
+            continue;
+        }
+
+        if !middlewares.permits(command.as_ref(), interaction).await? {
+            continue;
+        }
+
+        commands.push(command);
+    }
+
+    Ok(commands)
+}
+
+/// Looks up a command by its name or any of its localized names.
+fn find_command<'a>(
+    commands: &'a [Box<dyn Command + Send + Sync>],
+    query: &str,
+) -> miette::Result<Option<&'a (dyn Command + Send + Sync)>> {
+    let query = query.trim().trim_start_matches('/').to_lowercase();
+
+    for command in commands {
+        let name = command.name();
+        if name == query {
+            return Ok(Some(command.as_ref()));
+        }
+
+        for language in LOCALIZATION_HOLDER.languages() {
+            let localized =
+                LOCALIZATION_HOLDER.format_message(language, &format!("command-{name}-name"))?;
+            if localized.is_some_and(|localized| localized == query) {
+                return Ok(Some(command.as_ref()));
+            }
+        }
+    }
+
+    Ok(None)
+}
+
+/// Returns the localization of the name or description of a command or option from its
+/// `command-*` message, or the declared one if there is no such message.
+fn localized(localizer: &Localizer<'_>, message: &str, default: &str) -> miette::Result<String> {
+    Ok(localizer
+        .localize_by_name(message)?
+        .unwrap_or_else(|| default.to_string()))
+}
+
+/// Builds the embed listing the commands grouped by their plugins.
+fn overview_embed(
+    commands: &[Box<dyn Command + Send + Sync>],
+    localizer: &Localizer<'_>,
+) -> miette::Result<Embed> {
+    let mut plugins = BTreeMap::<String, Vec<String>>::new();
+    for command in commands {
+        let spec = command.spec();
+        let message = format!("command-{}", spec.name);
+        let name = localized(localizer, &format!("{message}-name"), &spec.name)?;
+        let description = localized(
+            localizer,
+            &format!("{message}-description"),
+            spec.description.as_deref().unwrap_or_default(),
+        )?;
+
+        plugins
+            .entry(command.plugin().name())
+            .or_default()
+            .push(format!(
+                "{} - {description}",
+                format!("/{name}").discord_inline_code()
+            ));
+    }
+
+    let description = if plugins.is_empty() {
+        localizer.general_plugin_help_embed_no_commands()?
+    } else {
+        localizer.general_plugin_help_embed_description()?
+    };
+
+    let mut embed = EmbedBuilder::new()
+        .color(0x41_A0_DE)
+        .title(localizer.general_plugin_help_embed_title()?)
+        .description(description);
+    for (plugin, lines) in plugins {
+        let plugin_name = localizer
+            .localize_by_name(&format!("help-plugin-{plugin}"))?
+            .unwrap_or(plugin);
+
+        embed = embed.field(EmbedFieldBuilder::new(plugin_name, lines.join("\n")));
+    }
+
+    embed.validate().into_diagnostic().map(EmbedBuilder::build)
+}
+
+/// Builds the embed showing the details of a command, including its usages and examples.
+fn detail_embed(
+    command: &(dyn Command + Send + Sync),
+    localizer: &Localizer<'_>,
+) -> miette::Result<Embed> {
+    let spec = command.spec();
+    let message = format!("command-{}", spec.name);
+    let name = localized(localizer, &format!("{message}-name"), &spec.name)?;
+    let description = localized(
+        localizer,
+        &format!("{message}-description"),
+        spec.description.as_deref().unwrap_or_default(),
+    )?;
+
+    let mut usages = Vec::new();
+    usage_lines(
+        &format!("/{name}"),
+        &description,
+        &message,
+        &spec.options,
+        localizer,
+        &mut usages,
+    )?;
+
+    let plugin = command.plugin().name();
+    let plugin_name = localizer
+        .localize_by_name(&format!("help-plugin-{plugin}"))?
+        .unwrap_or(plugin);
+
+    let mut embed = EmbedBuilder::new()
+        .color(0x41_A0_DE)
+        .title(localizer.general_plugin_help_detail_embed_title(format!("/{name}"))?)
+        .description(format!(
+            "{description}\n\n{}\n{}",
+            localizer
+                .general_plugin_help_detail_usage_heading()?
+                .discord_bold(),
+            usages.join("\n")
+        ))
+        .field(
+            EmbedFieldBuilder::new(
+                localizer.general_plugin_help_detail_plugin_field_name()?,
+                plugin_name,
+            )
+            .inline(),
+        );
+
+    if let Some(examples) = localizer.localize_by_name(&format!("help-example-{}", spec.name))? {
+        embed = embed.field(EmbedFieldBuilder::new(
+            localizer.general_plugin_help_detail_examples_field_name()?,
+            examples.discord_codeblock(),
+        ));
+    }
+
+    embed.validate().into_diagnostic().map(EmbedBuilder::build)
+}
+
+/// Appends the usage lines of a command or subcommand, recursing into subcommands and subcommand
+/// groups such that every invocable path is listed with its options.
+fn usage_lines(
+    path: &str,
+    description: &str,
+    message: &str,
+    options: &[CommandOptionSpec],
+    localizer: &Localizer<'_>,
+    lines: &mut Vec<String>,
+) -> miette::Result<()> {
+    let subcommands = options
+        .iter()
+        .filter(|option| {
+            matches!(
+                option.kind,
+                CommandOptionType::SubCommand | CommandOptionType::SubCommandGroup
+            )
+        })
+        .collect::<Vec<_>>();
+
+    if !subcommands.is_empty() {
+        for subcommand in subcommands {
+            let message = format!("{message}-{}", subcommand.name);
+            let name = localized(localizer, &format!("{message}-name"), &subcommand.name)?;
+            let description = localized(
+                localizer,
+                &format!("{message}-description"),
+                &subcommand.description,
+            )?;
+
+            usage_lines(
+                &format!("{path} {name}"),
+                &description,
+                &message,
+                subcommand.options.as_deref().unwrap_or_default(),
+                localizer,
+                lines,
+            )?;
+        }
+
+        return Ok(());
+    }
+
+    let mut usage = path.to_string();
+    let mut details = Vec::new();
+    for option in options {
+        let message = format!("{message}-{}", option.name);
+        let name = localized(localizer, &format!("{message}-name"), &option.name)?;
+        let description = localized(
+            localizer,
+            &format!("{message}-description"),
+            &option.description,
+        )?;
+
+        if option.required.unwrap_or_default() {
+            usage.push_str(&format!(" <{name}>"));
+        } else {
+            usage.push_str(&format!(" [{name}]"));
+        }
+        details.push(format!("- {}: {description}", name.discord_inline_code()));
+    }
+
+    lines.push(format!("{} - {description}", usage.discord_inline_code()));
+    lines.extend(details);
+
+    Ok(())
+}
+
+/// Builds the response of a help page, with the select menu for navigating to other pages.
+fn page_response(
+    kind: InteractionResponseType,
+    embed: Embed,
+    commands: &[Box<dyn Command + Send + Sync>],
+    localizer: &Localizer<'_>,
+) -> miette::Result<InteractionResponse> {
+    let mut options = vec![SelectMenuOption {
+        default: false,
+        description: None,
+        emoji: None,
+        label: localizer.general_plugin_help_select_overview_option()?,
+        value: OVERVIEW_VALUE.to_string(),
+    }];
+    for command in commands.iter().take(MAX_SELECT_MENU_OPTIONS - 1) {
+        let spec = command.spec();
+        let message = format!("command-{}", spec.name);
+        let name = localized(localizer, &format!("{message}-name"), &spec.name)?;
+        let description = localized(
+            localizer,
+            &format!("{message}-description"),
+            spec.description.as_deref().unwrap_or_default(),
+        )?;
+
+        options.push(SelectMenuOption {
+            default: false,
+            description: Some(truncate(&description)),
+            emoji: None,
+            label: truncate(&format!("/{name}")),
+            value: spec.name,
+        });
+    }
+
+    let select_menu = Component::SelectMenu(SelectMenu {
+        channel_types: None,
+        custom_id: SELECT_MENU_CUSTOM_ID.to_string(),
+        default_values: None,
+        disabled: false,
+        kind: SelectMenuType::Text,
+        max_values: Some(1),
+        min_values: Some(1),
+        options: Some(options),
+        placeholder: Some(localizer.general_plugin_help_select_placeholder()?),
+    });
+
+    Ok(InteractionResponse {
+        kind,
+        data: Some(
+            InteractionResponseDataBuilder::new()
+                .embeds(vec![embed])
+                .components(vec![Component::ActionRow(ActionRow {
+                    components: vec![select_menu],
+                })])
+                .build(),
+        ),
+    })
+}
+
+/// Truncates a label or description of a select menu option to the maximum length allowed.
+fn truncate(text: &str) -> String {
+    text.chars().take(MAX_SELECT_MENU_OPTION_LENGTH).collect()
+}
@@ -25,6 +25,8 @@
 //! Command list:
 //! - about
 //! - contributors
+//! - help
+//! - plugins
 
 use async_trait::async_trait;
 use hartex_discord_commands_core::plugin;
@@ -34,6 +36,8 @@ use hartex_discord_core::discord::model::id::Id;
 
 pub mod about;
 pub mod contributors;
+pub mod help;
+pub mod plugins;
 
 /// The general plugin.
 #[plugin(name = "general")]
@@ -44,4 +48,8 @@ impl Plugin for General {
     async fn enabled(&self, _: Id<GuildMarker>) -> miette::Result<bool> {
         Ok(true)
     }
+
+    fn toggleable(&self) -> bool {
+        false
+    }
 }
@@ -0,0 +1,166 @@
+/*
+ * SPDX-License-Identifier: AGPL-3.0-only
+ *
+ * This file is part of HarTex.
+ *
+ * HarTex
+ * Copyright (c) 2021-2024 HarTex Project Developers
+ *
+ * HarTex is free software; you can redistribute it and/or modify
+ * it under the terms of the GNU Affero General Public License as published by
+ * the Free Software Foundation; either version 3 of the License, or
+ * (at your option) any later version.
+ *
+ * HarTex is distributed in the hope that it will be useful,
+ * but WITHOUT ANY WARRANTY; without even the implied warranty of
+ * MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+ * GNU Affero General Public License for more details.
+ *
+ * You should have received a copy of the GNU Affero General Public License along
+ * with HarTex. If not, see <https://www.gnu.org/licenses/>.
+ */
+//! # The Plugins Command
+//!
+//! This command allows administrators of the dashboard to list, enable and disable the plugins of
+//! the guild.
+
+use async_trait::async_trait;
+use hartex_discord_commands_core::command;
+use hartex_discord_commands_core::middleware::permissions::DashboardAdmin;
+use hartex_discord_commands_core::traits::Command;
+use hartex_discord_commands_core::traits::Plugin;
+use hartex_discord_commands_core::traits::PluginMetadata;
+use hartex_discord_configuration_provider::ConfigurationProvider;
+use hartex_discord_core::discord::http::client::InteractionClient;
+use hartex_discord_core::discord::model::application::interaction::Interaction;
+use hartex_discord_utils::interaction::content_response;
+use hartex_discord_utils::interaction::ephemeral_error_response;
+use hartex_discord_utils::markdown::MarkdownStyle;
+use hartex_localization_core::Localizer;
+use miette::IntoDiagnostic;
+
+use crate::general::General;
+
+mod plugins_disable;
+mod plugins_enable;
+mod plugins_list;
+
+/// The `plugins` command declaration.
+#[command(
+    name = "plugins",
+    plugin = General,
+    description = "List, enable and disable the plugins of this server.",
+    dm_permission = false,
+    middlewares = [DashboardAdmin],
+    subcommands = [
+        plugins_list::PluginsList,
+        plugins_enable::PluginsEnable,
+        plugins_disable::PluginsDisable,
+    ],
+)]
+pub struct Plugins;
+
+#[async_trait]
+impl Command for Plugins {}
+
+/// Enables or disables a plugin of the guild, responding with the outcome.
+///
+/// The plugin name is validated against the plugins provided by the bot. Updating the stored
+/// configuration notifies every worker of the change.
+pub(crate) async fn toggle(
+    interaction: &Interaction,
+    interaction_client: &InteractionClient<'_>,
+    localizer: &Localizer<'_>,
+    name: &str,
+    enabled: bool,
+) -> miette::Result<()> {
+    let guild_id = interaction.guild_id.unwrap();
+    let name = name.trim().to_lowercase();
+
+    let plugins = crate::plugins();
+    let Some(plugin) = plugins.iter().find(|plugin| plugin.name() == name) else {
+        let plugin_names = plugins
+            .iter()
+            .map(|plugin| plugin.name().discord_inline_code())
+            .collect::<Vec<_>>()
+            .join(", ");
+        let plugins_error_unknown_plugin = localizer.general_plugin_plugins_error_unknown_plugin(
+            name.discord_inline_code(),
+            plugin_names,
+        )?;
+
+        return respond_error(
+            interaction,
+            interaction_client,
+            plugins_error_unknown_plugin,
+        )
+        .await;
+    };
+
+    if !plugin.toggleable() {
+        let plugins_error_not_toggleable =
+            localizer.general_plugin_plugins_error_not_toggleable(name.discord_inline_code())?;
+
+        return respond_error(
+            interaction,
+            interaction_client,
+            plugins_error_not_toggleable,
+        )
+        .await;
+    }
+
+    let Some(enabled_plugins) = ConfigurationProvider::enabled_plugins(guild_id).await? else {
+        let plugins_error_unconfigured = localizer.general_plugin_plugins_error_unconfigured()?;
+
+        return respond_error(interaction, interaction_client, plugins_error_unconfigured).await;
+    };
+
+    if enabled_plugins.contains(&name) == enabled {
+        let message = if enabled {
+            localizer
+                .general_plugin_plugins_enable_error_already_enabled(name.discord_inline_code())?
+        } else {
+            localizer
+                .general_plugin_plugins_disable_error_already_disabled(name.discord_inline_code())?
+        };
+
+        return respond_error(interaction, interaction_client, message).await;
+    }
+
+    ConfigurationProvider::set_plugin_enabled(guild_id, name.clone(), enabled).await?;
+
+    let message = if enabled {
+        localizer.general_plugin_plugins_enable_success(name.discord_inline_code())?
+    } else {
+        localizer.general_plugin_plugins_disable_success(name.discord_inline_code())?
+    };
+
+    interaction_client
+        .create_response(
+            interaction.id,
+            &interaction.token,
+            &content_response(message),
+        )
+        .await
+        .into_diagnostic()?;
+
+    Ok(())
+}
+
+/// Responds to the interaction with an ephemeral error message.
+async fn respond_error(
+    interaction: &Interaction,
+    interaction_client: &InteractionClient<'_>,
+    message: String,
+) -> miette::Result<()> {
+    interaction_client
+        .create_response(
+            interaction.id,
+            &interaction.token,
+            &ephemeral_error_response(message),
+        )
+        .await
+        .into_diagnostic()?;
+
+    Ok(())
+}
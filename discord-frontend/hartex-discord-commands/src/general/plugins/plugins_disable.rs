@@ -0,0 +1,79 @@
+/*
+ * SPDX-License-Identifier: AGPL-3.0-only
+ *
+ * This file is part of HarTex.
+ *
+ * HarTex
+ * Copyright (c) 2021-2024 HarTex Project Developers
+ *
+ * HarTex is free software; you can redistribute it and/or modify
+ * it under the terms of the GNU Affero General Public License as published by
+ * the Free Software Foundation; either version 3 of the License, or
+ * (at your option) any later version.
+ *
+ * HarTex is distributed in the hope that it will be useful,
+ * but WITHOUT ANY WARRANTY; without even the implied warranty of
+ * MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+ * GNU Affero General Public License for more details.
+ *
+ * You should have received a copy of the GNU Affero General Public License along
+ * with HarTex. If not, see <https://www.gnu.org/licenses/>.
+ */
+//! # The Plugins Disable Subcommand
+//!
+//! This command disables a plugin of the guild.
+
+use async_trait::async_trait;
+use hartex_discord_commands_core::options::resolved;
+use hartex_discord_commands_core::options::CommandOptions;
+use hartex_discord_commands_core::subcommand;
+use hartex_discord_commands_core::traits::Subcommand;
+use hartex_discord_commands_core::CommandOptions;
+use hartex_discord_core::discord::http::client::InteractionClient;
+use hartex_discord_core::discord::model::application::interaction::application_command::CommandDataOption;
+use hartex_discord_core::discord::model::application::interaction::Interaction;
+use hartex_localization_core::Localizer;
+use miette::IntoDiagnostic;
+
+/// The options of the `plugins disable` command.
+#[derive(CommandOptions)]
+struct PluginsDisableOptions {
+    name: String,
+}
+
+/// The `plugins disable` subcommand declaration.
+#[subcommand(
+    name = "disable",
+    description = "Disable a plugin of this server.",
+    options = [
+        string(
+            name = "name",
+            description = "The name of the plugin to disable.",
+            required = true,
+        ),
+    ],
+)]
+pub struct PluginsDisable;
+
+#[async_trait]
+impl Subcommand for PluginsDisable {
+    async fn execute(
+        &self,
+        interaction: Interaction,
+        interaction_client: &InteractionClient<'_>,
+        option: CommandDataOption,
+        localizer: Localizer<'_>,
+    ) -> miette::Result<()> {
+        let options = PluginsDisableOptions::from_subcommand(&option, resolved(&interaction))
+            .into_diagnostic()?;
+
+        super::toggle(
+            &interaction,
+            interaction_client,
+            &localizer,
+            &options.name,
+            false,
+        )
+        .await
+    }
+}
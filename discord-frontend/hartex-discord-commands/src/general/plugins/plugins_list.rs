@@ -0,0 +1,91 @@
+/*
+ * SPDX-License-Identifier: AGPL-3.0-only
+ *
+ * This file is part of HarTex.
+ *
+ * HarTex
+ * Copyright (c) 2021-2024 HarTex Project Developers
+ *
+ * HarTex is free software; you can redistribute it and/or modify
+ * it under the terms of the GNU Affero General Public License as published by
+ * the Free Software Foundation; either version 3 of the License, or
+ * (at your option) any later version.
+ *
+ * HarTex is distributed in the hope that it will be useful,
+ * but WITHOUT ANY WARRANTY; without even the implied warranty of
+ * MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+ * GNU Affero General Public License for more details.
+ *
+ * You should have received a copy of the GNU Affero General Public License along
+ * with HarTex. If not, see <https://www.gnu.org/licenses/>.
+ */
+//! # The Plugins List Subcommand
+//!
+//! This command lists the plugins provided by the bot and whether they are enabled in the guild.
+
+use async_trait::async_trait;
+use hartex_discord_commands_core::subcommand;
+use hartex_discord_commands_core::traits::Plugin;
+use hartex_discord_commands_core::traits::PluginMetadata;
+use hartex_discord_commands_core::traits::Subcommand;
+use hartex_discord_core::discord::http::client::InteractionClient;
+use hartex_discord_core::discord::model::application::interaction::application_command::CommandDataOption;
+use hartex_discord_core::discord::model::application::interaction::Interaction;
+use hartex_discord_core::discord::util::builder::embed::EmbedBuilder;
+use hartex_discord_utils::interaction::embed_response;
+use hartex_discord_utils::markdown::MarkdownStyle;
+use hartex_localization_core::Localizer;
+use miette::IntoDiagnostic;
+
+/// The `plugins list` subcommand declaration.
+#[subcommand(
+    name = "list",
+    description = "List the plugins of this server and whether they are enabled."
+)]
+pub struct PluginsList;
+
+#[async_trait]
+impl Subcommand for PluginsList {
+    async fn execute(
+        &self,
+        interaction: Interaction,
+        interaction_client: &InteractionClient<'_>,
+        _: CommandDataOption,
+        localizer: Localizer<'_>,
+    ) -> miette::Result<()> {
+        let guild_id = interaction.guild_id.unwrap();
+
+        let plugins_list_enabled = localizer.general_plugin_plugins_list_enabled()?;
+        let plugins_list_disabled = localizer.general_plugin_plugins_list_disabled()?;
+
+        let mut lines = Vec::new();
+        for plugin in crate::plugins() {
+            let state = if plugin.enabled(guild_id).await? {
+                &plugins_list_enabled
+            } else {
+                &plugins_list_disabled
+            };
+
+            lines.push(format!("{}: {state}", plugin.name().discord_inline_code()));
+        }
+
+        let embed = EmbedBuilder::new()
+            .color(0x41_A0_DE)
+            .title(localizer.general_plugin_plugins_list_embed_title()?)
+            .description(lines.join("\n"))
+            .validate()
+            .into_diagnostic()?
+            .build();
+
+        interaction_client
+            .create_response(
+                interaction.id,
+                &interaction.token,
+                &embed_response(vec![embed]),
+            )
+            .await
+            .into_diagnostic()?;
+
+        Ok(())
+    }
+}
@@ -33,5 +33,38 @@
 #![feature(if_let_guard)]
 #![feature(let_chains)]
 
+use hartex_discord_commands_core::traits::Command;
+use hartex_discord_commands_core::traits::Plugin;
+
+use crate::general::about::About;
+use crate::general::contributors::Contributors;
+use crate::general::help::Help;
+use crate::general::plugins::Plugins;
+use crate::general::General;
+use crate::management::infraction::Infraction;
+use crate::management::Management;
+use crate::utilities::info::Info;
+use crate::utilities::Utilities;
+
 pub mod general;
+pub mod management;
 pub mod utilities;
+
+/// Returns every command provided by the bot.
+#[must_use]
+pub fn commands() -> Vec<Box<dyn Command + Send + Sync>> {
+    vec![
+        Box::new(About),
+        Box::new(Contributors),
+        Box::new(Help),
+        Box::new(Plugins),
+        Box::new(Info),
+        Box::new(Infraction),
+    ]
+}
+
+/// Returns every plugin provided by the bot.
+#[must_use]
+pub fn plugins() -> Vec<Box<dyn Plugin + Send + Sync>> {
+    vec![Box::new(General), Box::new(Management), Box::new(Utilities)]
+}
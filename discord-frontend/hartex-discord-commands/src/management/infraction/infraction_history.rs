@@ -0,0 +1,192 @@
+/*
+ * SPDX-License-Identifier: AGPL-3.0-only
+ *
+ * This file is part of HarTex.
+ *
+ * HarTex
+ * Copyright (c) 2021-2024 HarTex Project Developers
+ *
+ * HarTex is free software; you can redistribute it and/or modify
+ * it under the terms of the GNU Affero General Public License as published by
+ * the Free Software Foundation; either version 3 of the License, or
+ * (at your option) any later version.
+ *
+ * HarTex is distributed in the hope that it will be useful,
+ * but WITHOUT ANY WARRANTY; without even the implied warranty of
+ * MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+ * GNU Affero General Public License for more details.
+ *
+ * You should have received a copy of the GNU Affero General Public License along
+ * with HarTex. If not, see <https://www.gnu.org/licenses/>.
+ */
+
+//! # The Infraction History Subcommand
+//!
+//! This command returns the infractions of a user in the current server, paginated.
+
+use std::pin::Pin;
+
+use async_trait::async_trait;
+use hartex_database_queries::discord_frontend::queries::infraction_count_by_user_id_and_guild_id::infraction_count_by_user_id_and_guild_id;
+use hartex_database_queries::discord_frontend::queries::infraction_select_by_user_id_and_guild_id::infraction_select_by_user_id_and_guild_id;
+use hartex_discord_commands_core::options::resolved;
+use hartex_discord_commands_core::options::CommandOptions;
+use hartex_discord_commands_core::subcommand;
+use hartex_discord_commands_core::traits::Subcommand;
+use hartex_discord_commands_core::CommandOptions;
+use hartex_discord_core::discord::http::client::InteractionClient;
+use hartex_discord_core::discord::mention::Mention;
+use hartex_discord_core::discord::model::application::interaction::application_command::CommandDataOption;
+use hartex_discord_core::discord::model::application::interaction::Interaction;
+use hartex_discord_core::discord::model::id::marker::UserMarker;
+use hartex_discord_core::discord::model::id::Id;
+use hartex_discord_core::discord::util::builder::embed::EmbedBuilder;
+use hartex_discord_core::discord::util::builder::embed::EmbedFooterBuilder;
+use hartex_discord_utils::interaction::embed_response;
+use hartex_discord_utils::markdown::MarkdownStyle;
+use hartex_discord_utils::DATABASE_POOL;
+use hartex_localization_core::Localizer;
+use miette::IntoDiagnostic;
+use tokio_postgres::GenericClient;
+
+/// The number of infractions displayed per page.
+const INFRACTIONS_PER_PAGE: i64 = 10;
+
+/// The options of the `infraction history` command.
+#[derive(CommandOptions)]
+struct InfractionHistoryOptions {
+    user: Id<UserMarker>,
+    #[option(min = 1)]
+    page: Option<i64>,
+}
+
+/// The `infraction history` subcommand declaration.
+#[subcommand(
+    name = "history",
+    description = "The infraction history of a specific user.",
+    options = [
+        user(
+            name = "user",
+            description = "The user to obtain the infraction history for.",
+            required = true,
+        ),
+        integer(
+            name = "page",
+            description = "The page of the infraction history to display.",
+            required = false,
+            min_value = 1,
+        ),
+    ],
+)]
+pub struct InfractionHistory;
+
+#[async_trait]
+impl Subcommand for InfractionHistory {
+    async fn execute(
+        &self,
+        interaction: Interaction,
+        interaction_client: &InteractionClient<'_>,
+        option: CommandDataOption,
+        localizer: Localizer<'_>,
+    ) -> miette::Result<()> {
+        let options = InfractionHistoryOptions::from_subcommand(&option, resolved(&interaction))
+            .into_diagnostic()?;
+
+        let guild_id = interaction.guild_id.unwrap().to_string();
+        let user_id = options.user;
+
+        let pinned = Pin::static_ref(&DATABASE_POOL).await;
+        let pooled = pinned.get().await.into_diagnostic()?;
+        let client = pooled.client();
+
+        let total = infraction_count_by_user_id_and_guild_id()
+            .bind(client, &user_id.to_string(), &guild_id)
+            .one()
+            .await
+            .into_diagnostic()?;
+        let page_count = ((total + INFRACTIONS_PER_PAGE - 1) / INFRACTIONS_PER_PAGE).max(1);
+        let page = options.page.unwrap_or(1).clamp(1, page_count);
+
+        let infractions = infraction_select_by_user_id_and_guild_id()
+            .bind(
+                client,
+                &user_id.to_string(),
+                &guild_id,
+                &INFRACTIONS_PER_PAGE,
+                &((page - 1) * INFRACTIONS_PER_PAGE),
+            )
+            .all()
+            .await
+            .into_diagnostic()?;
+
+        let infraction_history_embed_title =
+            localizer.management_plugin_infraction_history_embed_title()?;
+        let infraction_history_embed_description = localizer
+            .management_plugin_infraction_history_embed_description(
+                user_id.mention().to_string(),
+            )?;
+        let infraction_history_embed_empty =
+            localizer.management_plugin_infraction_history_embed_empty()?;
+        let infraction_history_embed_footer = localizer
+            .management_plugin_infraction_history_embed_footer(
+                page.to_string(),
+                page_count.to_string(),
+                total.to_string(),
+            )?;
+        let infraction_history_embed_pardoned =
+            localizer.management_plugin_infraction_history_embed_pardoned()?;
+
+        let entries = if infractions.is_empty() {
+            infraction_history_embed_empty
+        } else {
+            infractions
+                .into_iter()
+                .map(|infraction| {
+                    let entry = format!(
+                        "{} {} {} - {}",
+                        format!("#{}", infraction.case_number).discord_inline_code(),
+                        infraction.kind.discord_bold(),
+                        infraction
+                            .created_at
+                            .unix_timestamp()
+                            .to_string()
+                            .discord_relative_timestamp(),
+                        infraction.reason,
+                    );
+
+                    if infraction.pardoned {
+                        format!(
+                            "{} ({infraction_history_embed_pardoned})",
+                            entry.discord_strikethrough()
+                        )
+                    } else {
+                        entry
+                    }
+                })
+                .collect::<Vec<_>>()
+                .join("\n")
+        };
+
+        let embed = EmbedBuilder::new()
+            .color(0x41_A0_DE)
+            .title(infraction_history_embed_title)
+            .description(format!(
+                "{infraction_history_embed_description}\n\n{entries}"
+            ))
+            .footer(EmbedFooterBuilder::new(infraction_history_embed_footer).build())
+            .validate()
+            .into_diagnostic()?
+            .build();
+
+        interaction_client
+            .create_response(
+                interaction.id,
+                &interaction.token,
+                &embed_response(vec![embed]),
+            )
+            .await
+            .into_diagnostic()?;
+
+        Ok(())
+    }
+}
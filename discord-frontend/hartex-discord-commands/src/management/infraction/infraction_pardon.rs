@@ -0,0 +1,155 @@
+/*
+ * SPDX-License-Identifier: AGPL-3.0-only
+ *
+ * This file is part of HarTex.
+ *
+ * HarTex
+ * Copyright (c) 2021-2024 HarTex Project Developers
+ *
+ * HarTex is free software; you can redistribute it and/or modify
+ * it under the terms of the GNU Affero General Public License as published by
+ * the Free Software Foundation; either version 3 of the License, or
+ * (at your option) any later version.
+ *
+ * HarTex is distributed in the hope that it will be useful,
+ * but WITHOUT ANY WARRANTY; without even the implied warranty of
+ * MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+ * GNU Affero General Public License for more details.
+ *
+ * You should have received a copy of the GNU Affero General Public License along
+ * with HarTex. If not, see <https://www.gnu.org/licenses/>.
+ */
+
+//! # The Infraction Pardon Subcommand
+//!
+//! This command pardons an infraction case.
+
+use std::pin::Pin;
+
+use async_trait::async_trait;
+use hartex_database_queries::discord_frontend::queries::infraction_select_by_case_number_and_guild_id::infraction_select_by_case_number_and_guild_id;
+use hartex_database_queries::discord_frontend::queries::infraction_update_pardoned::infraction_update_pardoned;
+use hartex_discord_commands_core::options::resolved;
+use hartex_discord_commands_core::options::CommandOptions;
+use hartex_discord_commands_core::subcommand;
+use hartex_discord_commands_core::traits::Subcommand;
+use hartex_discord_commands_core::CommandOptions;
+use hartex_discord_core::discord::http::client::InteractionClient;
+use hartex_discord_core::discord::model::application::interaction::application_command::CommandDataOption;
+use hartex_discord_core::discord::model::application::interaction::Interaction;
+use hartex_discord_utils::interaction::content_response;
+use hartex_discord_utils::interaction::ephemeral_error_response;
+use hartex_discord_utils::DATABASE_POOL;
+use hartex_localization_core::Localizer;
+use miette::IntoDiagnostic;
+
+/// The options of the `infraction pardon` command.
+#[derive(CommandOptions)]
+struct InfractionPardonOptions {
+    #[option(name = "case", min = 1)]
+    case_number: i64,
+}
+
+/// The `infraction pardon` subcommand declaration.
+#[subcommand(
+    name = "pardon",
+    description = "Pardon a specific infraction case.",
+    options = [
+        integer(
+            name = "case",
+            description = "The case number of the infraction.",
+            required = true,
+            min_value = 1,
+        ),
+    ],
+)]
+pub struct InfractionPardon;
+
+#[async_trait]
+impl Subcommand for InfractionPardon {
+    async fn execute(
+        &self,
+        interaction: Interaction,
+        interaction_client: &InteractionClient<'_>,
+        option: CommandDataOption,
+        localizer: Localizer<'_>,
+    ) -> miette::Result<()> {
+        let options = InfractionPardonOptions::from_subcommand(&option, resolved(&interaction))
+            .into_diagnostic()?;
+
+        let guild_id = interaction.guild_id.unwrap().to_string();
+        let case_number = options.case_number;
+
+        let pinned = Pin::static_ref(&DATABASE_POOL).await;
+        let mut pooled = pinned.get().await.into_diagnostic()?;
+        let transaction = pooled.transaction().await.into_diagnostic()?;
+
+        let Some(infraction) = infraction_select_by_case_number_and_guild_id()
+            .bind(&transaction, &case_number, &guild_id)
+            .opt()
+            .await
+            .into_diagnostic()?
+        else {
+            let infraction_error_unknown_case = localizer
+                .management_plugin_infraction_error_unknown_case(case_number.to_string())?;
+
+            interaction_client
+                .create_response(
+                    interaction.id,
+                    &interaction.token,
+                    &ephemeral_error_response(infraction_error_unknown_case),
+                )
+                .await
+                .into_diagnostic()?;
+
+            return Ok(());
+        };
+
+        if infraction.pardoned {
+            let infraction_pardon_error_already_pardoned = localizer
+                .management_plugin_infraction_pardon_error_already_pardoned(
+                    case_number.to_string(),
+                )?;
+
+            interaction_client
+                .create_response(
+                    interaction.id,
+                    &interaction.token,
+                    &ephemeral_error_response(infraction_pardon_error_already_pardoned),
+                )
+                .await
+                .into_diagnostic()?;
+
+            return Ok(());
+        }
+
+        infraction_update_pardoned()
+            .bind(&transaction, &true, &case_number, &guild_id)
+            .await
+            .into_diagnostic()?;
+        super::audit(
+            &transaction,
+            &interaction,
+            case_number,
+            "pardon",
+            None,
+            None,
+        )
+        .await?;
+        transaction.commit().await.into_diagnostic()?;
+
+        let infraction_pardon_success =
+            localizer.management_plugin_infraction_pardon_success(case_number.to_string())?;
+
+        interaction_client
+            .create_response(
+                interaction.id,
+                &interaction.token,
+                &content_response(infraction_pardon_success),
+            )
+            .await
+            .into_diagnostic()?;
+
+        Ok(())
+    }
+}
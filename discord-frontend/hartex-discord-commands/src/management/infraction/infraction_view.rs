@@ -0,0 +1,196 @@
+/*
+ * SPDX-License-Identifier: AGPL-3.0-only
+ *
+ * This file is part of HarTex.
+ *
+ * HarTex
+ * Copyright (c) 2021-2024 HarTex Project Developers
+ *
+ * HarTex is free software; you can redistribute it and/or modify
+ * it under the terms of the GNU Affero General Public License as published by
+ * the Free Software Foundation; either version 3 of the License, or
+ * (at your option) any later version.
+ *
+ * HarTex is distributed in the hope that it will be useful,
+ * but WITHOUT ANY WARRANTY; without even the implied warranty of
+ * MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+ * GNU Affero General Public License for more details.
+ *
+ * You should have received a copy of the GNU Affero General Public License along
+ * with HarTex. If not, see <https://www.gnu.org/licenses/>.
+ */
+
+//! # The Infraction View Subcommand
+//!
+//! This command returns the details of an infraction case.
+
+use std::pin::Pin;
+
+use async_trait::async_trait;
+use hartex_database_queries::discord_frontend::queries::infraction_select_by_case_number_and_guild_id::infraction_select_by_case_number_and_guild_id;
+use hartex_discord_commands_core::options::resolved;
+use hartex_discord_commands_core::options::CommandOptions;
+use hartex_discord_commands_core::subcommand;
+use hartex_discord_commands_core::traits::Subcommand;
+use hartex_discord_commands_core::CommandOptions;
+use hartex_discord_core::discord::http::client::InteractionClient;
+use hartex_discord_core::discord::model::application::interaction::application_command::CommandDataOption;
+use hartex_discord_core::discord::model::application::interaction::Interaction;
+use hartex_discord_core::discord::util::builder::embed::EmbedBuilder;
+use hartex_discord_core::discord::util::builder::embed::EmbedFieldBuilder;
+use hartex_discord_utils::interaction::embed_response;
+use hartex_discord_utils::interaction::ephemeral_error_response;
+use hartex_discord_utils::localizable::Localizable;
+use hartex_discord_utils::markdown::MarkdownStyle;
+use hartex_discord_utils::DATABASE_POOL;
+use hartex_localization_core::Localizer;
+use miette::IntoDiagnostic;
+use tokio_postgres::GenericClient;
+
+/// The options of the `infraction view` command.
+#[derive(CommandOptions)]
+struct InfractionViewOptions {
+    #[option(name = "case", min = 1)]
+    case_number: i64,
+}
+
+/// The `infraction view` subcommand declaration.
+#[subcommand(
+    name = "view",
+    description = "The details of a specific infraction case.",
+    options = [
+        integer(
+            name = "case",
+            description = "The case number of the infraction.",
+            required = true,
+            min_value = 1,
+        ),
+    ],
+)]
+pub struct InfractionView;
+
+#[async_trait]
+impl Subcommand for InfractionView {
+    async fn execute(
+        &self,
+        interaction: Interaction,
+        interaction_client: &InteractionClient<'_>,
+        option: CommandDataOption,
+        localizer: Localizer<'_>,
+    ) -> miette::Result<()> {
+        let options = InfractionViewOptions::from_subcommand(&option, resolved(&interaction))
+            .into_diagnostic()?;
+
+        let langid_locale = interaction
+            .locale
+            .clone()
+            .and_then(|locale| locale.parse().ok());
+
+        let case_number = options.case_number;
+
+        let pinned = Pin::static_ref(&DATABASE_POOL).await;
+        let pooled = pinned.get().await.into_diagnostic()?;
+        let client = pooled.client();
+
+        let Some(infraction) = infraction_select_by_case_number_and_guild_id()
+            .bind(
+                client,
+                &case_number,
+                &interaction.guild_id.unwrap().to_string(),
+            )
+            .opt()
+            .await
+            .into_diagnostic()?
+        else {
+            let infraction_error_unknown_case = localizer
+                .management_plugin_infraction_error_unknown_case(case_number.to_string())?;
+
+            interaction_client
+                .create_response(
+                    interaction.id,
+                    &interaction.token,
+                    &ephemeral_error_response(infraction_error_unknown_case),
+                )
+                .await
+                .into_diagnostic()?;
+
+            return Ok(());
+        };
+
+        let infraction_view_embed_title =
+            localizer.management_plugin_infraction_view_embed_title(case_number.to_string())?;
+        let infraction_view_embed_user_field_name =
+            localizer.management_plugin_infraction_view_embed_user_field_name()?;
+        let infraction_view_embed_moderator_field_name =
+            localizer.management_plugin_infraction_view_embed_moderator_field_name()?;
+        let infraction_view_embed_kind_field_name =
+            localizer.management_plugin_infraction_view_embed_kind_field_name()?;
+        let infraction_view_embed_reason_field_name =
+            localizer.management_plugin_infraction_view_embed_reason_field_name()?;
+        let infraction_view_embed_created_field_name =
+            localizer.management_plugin_infraction_view_embed_created_field_name()?;
+        let infraction_view_embed_pardoned_field_name =
+            localizer.management_plugin_infraction_view_embed_pardoned_field_name()?;
+
+        let embed = EmbedBuilder::new()
+            .color(0x41_A0_DE)
+            .title(infraction_view_embed_title)
+            .field(
+                EmbedFieldBuilder::new(
+                    infraction_view_embed_user_field_name,
+                    format!("<@{}>", infraction.user_id),
+                )
+                .inline(),
+            )
+            .field(
+                EmbedFieldBuilder::new(
+                    infraction_view_embed_moderator_field_name,
+                    format!("<@{}>", infraction.moderator_id),
+                )
+                .inline(),
+            )
+            .field(
+                EmbedFieldBuilder::new(
+                    infraction_view_embed_kind_field_name,
+                    infraction.kind.discord_inline_code(),
+                )
+                .inline(),
+            )
+            .field(EmbedFieldBuilder::new(
+                infraction_view_embed_reason_field_name,
+                infraction.reason,
+            ))
+            .field(
+                EmbedFieldBuilder::new(
+                    infraction_view_embed_created_field_name,
+                    infraction
+                        .created_at
+                        .unix_timestamp()
+                        .to_string()
+                        .discord_relative_timestamp(),
+                )
+                .inline(),
+            )
+            .field(
+                EmbedFieldBuilder::new(
+                    infraction_view_embed_pardoned_field_name,
+                    infraction.pardoned.localize(langid_locale)?,
+                )
+                .inline(),
+            )
+            .validate()
+            .into_diagnostic()?
+            .build();
+
+        interaction_client
+            .create_response(
+                interaction.id,
+                &interaction.token,
+                &embed_response(vec![embed]),
+            )
+            .await
+            .into_diagnostic()?;
+
+        Ok(())
+    }
+}
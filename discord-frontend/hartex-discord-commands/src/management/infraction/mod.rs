@@ -0,0 +1,91 @@
+/*
+ * SPDX-License-Identifier: AGPL-3.0-only
+ *
+ * This file is part of HarTex.
+ *
+ * HarTex
+ * Copyright (c) 2021-2024 HarTex Project Developers
+ *
+ * HarTex is free software; you can redistribute it and/or modify
+ * it under the terms of the GNU Affero General Public License as published by
+ * the Free Software Foundation; either version 3 of the License, or
+ * (at your option) any later version.
+ *
+ * HarTex is distributed in the hope that it will be useful,
+ * but WITHOUT ANY WARRANTY; without even the implied warranty of
+ * MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+ * GNU Affero General Public License for more details.
+ *
+ * You should have received a copy of the GNU Affero General Public License along
+ * with HarTex. If not, see <https://www.gnu.org/licenses/>.
+ */
+
+//! # The Infraction Command
+//!
+//! This command allows moderators to look up, edit, pardon and delete infraction cases.
+
+use async_trait::async_trait;
+use hartex_database_queries::discord_frontend::queries::infraction_audit_log_insert::infraction_audit_log_insert;
+use hartex_discord_commands_core::command;
+use hartex_discord_commands_core::traits::Command;
+use hartex_discord_core::discord::model::application::interaction::Interaction;
+use miette::IntoDiagnostic;
+use time::OffsetDateTime;
+use tokio_postgres::Transaction;
+
+use crate::management::Management;
+
+mod infraction_delete;
+mod infraction_history;
+mod infraction_pardon;
+mod infraction_reason;
+mod infraction_view;
+
+/// The `infraction` command declaration.
+#[command(
+    name = "infraction",
+    plugin = Management,
+    required_permissions = Permissions::MODERATE_MEMBERS,
+    description = "Look up and manage infraction cases.",
+    dm_permission = false,
+    subcommands = [
+        infraction_history::InfractionHistory,
+        infraction_view::InfractionView,
+        infraction_reason::InfractionReason,
+        infraction_pardon::InfractionPardon,
+        infraction_delete::InfractionDelete,
+    ],
+)]
+pub struct Infraction;
+
+#[async_trait]
+impl Command for Infraction {}
+
+/// Records a mutation performed on an infraction case in the audit log.
+///
+/// This is run within the same transaction as the mutation itself, such that a mutation is never
+/// committed without its corresponding audit log entry.
+pub(crate) async fn audit(
+    transaction: &Transaction<'_>,
+    interaction: &Interaction,
+    case_number: i64,
+    action: &str,
+    old_reason: Option<String>,
+    new_reason: Option<String>,
+) -> miette::Result<()> {
+    infraction_audit_log_insert()
+        .bind(
+            transaction,
+            &interaction.guild_id.unwrap().to_string(),
+            &case_number,
+            &interaction.author_id().unwrap().to_string(),
+            &action,
+            &old_reason,
+            &new_reason,
+            &OffsetDateTime::now_utc(),
+        )
+        .await
+        .into_diagnostic()?;
+
+    Ok(())
+}
@@ -27,9 +27,12 @@
 use std::env;
 use std::time::SystemTime;
 
+use async_trait::async_trait;
 use hartex_backend_models::uptime::UptimeQuery;
 use hartex_backend_models::uptime::UptimeResponse;
 use hartex_backend_models::Response;
+use hartex_discord_commands_core::subcommand;
+use hartex_discord_commands_core::traits::Subcommand;
 use hartex_discord_core::discord::http::client::InteractionClient;
 use hartex_discord_core::discord::model::application::interaction::application_command::CommandDataOption;
 use hartex_discord_core::discord::model::application::interaction::Interaction;
@@ -52,79 +55,86 @@ use hyper_util::rt::TokioIo;
 use miette::IntoDiagnostic;
 use miette::Report;
 
-/// Executes the `info bot` command
-pub async fn execute(
-    interaction: Interaction,
-    interaction_client: &InteractionClient<'_>,
-    _: CommandDataOption,
-    localizer: Localizer<'_>,
-) -> miette::Result<()> {
-    let api_domain = env::var("API_DOMAIN").into_diagnostic()?;
-    let uri = format!("http://{}/api/v0110/stats/uptime", api_domain.clone());
-    let now = SystemTime::now();
-
-    let stream = TcpStream::connect(api_domain).await.into_diagnostic()?;
-    let (mut sender, connection) = handshake(TokioIo::new(stream)).await.into_diagnostic()?;
-
-    spawn(async move {
-        if let Err(err) = connection.await {
-            log::error!("TCP connection failed: {:?}", err);
-        }
-    });
-
-    log::debug!("sending a request to {}", &uri);
-
-    let query = UptimeQuery::new("HarTex Nightly");
-    let request = Request::builder()
-        .uri(uri)
-        .method(Method::POST)
-        .header(ACCEPT, "application/json")
-        .header(CONTENT_TYPE, "application/json")
-        .body(serde_json::to_string(&query).into_diagnostic()?)
-        .into_diagnostic()?;
-
-    let result = sender.send_request(request).await.into_diagnostic()?;
-    log::debug!("deserializing result");
-    let body = result.collect().await.into_diagnostic()?.aggregate();
-    let response: Response<UptimeResponse> =
-        serde_json::from_reader(body.reader()).into_diagnostic()?;
-
-    let latency = now.elapsed().into_diagnostic()?.as_millis();
-
-    let data = response.data();
-    let timestamp = data
-        .ok_or(Report::msg("failed to obtain uptime data"))?
-        .start_timestamp();
-
-    let botinfo_embed_botstarted_field_name =
-        localizer.utilities_plugin_botinfo_embed_botstarted_field_name()?;
-    let botinfo_embed_latency_field_name =
-        localizer.utilities_plugin_botinfo_embed_latency_field_name()?;
-    let botinfo_embed_title = localizer.utilities_plugin_botinfo_embed_title()?;
-
-    let embed = EmbedBuilder::new()
-        .color(0x41_A0_DE)
-        .field(EmbedFieldBuilder::new(
-            botinfo_embed_botstarted_field_name,
-            timestamp.to_string().discord_relative_timestamp(),
-        ))
-        .field(EmbedFieldBuilder::new(
-            botinfo_embed_latency_field_name,
-            latency.to_string().discord_inline_code(),
-        ))
-        .title(botinfo_embed_title)
-        .validate()
-        .into_diagnostic()?
-        .build();
-
-    interaction_client
-        .create_response(
-            interaction.id,
-            &interaction.token,
-            &embed_response(vec![embed]),
-        )
-        .await
-        .into_diagnostic()?;
-
-    Ok(())
+/// The `info bot` subcommand declaration.
+#[subcommand(name = "bot", description = "Runtime information of the bot.")]
+pub struct InfoBot;
+
+#[async_trait]
+impl Subcommand for InfoBot {
+    async fn execute(
+        &self,
+        interaction: Interaction,
+        interaction_client: &InteractionClient<'_>,
+        _: CommandDataOption,
+        localizer: Localizer<'_>,
+    ) -> miette::Result<()> {
+        let api_domain = env::var("API_DOMAIN").into_diagnostic()?;
+        let uri = format!("http://{}/api/v0110/stats/uptime", api_domain.clone());
+        let now = SystemTime::now();
+
+        let stream = TcpStream::connect(api_domain).await.into_diagnostic()?;
+        let (mut sender, connection) = handshake(TokioIo::new(stream)).await.into_diagnostic()?;
+
+        spawn(async move {
+            if let Err(err) = connection.await {
+                log::error!("TCP connection failed: {:?}", err);
+            }
+        });
+
+        log::debug!("sending a request to {}", &uri);
+
+        let query = UptimeQuery::new("HarTex Nightly");
+        let request = Request::builder()
+            .uri(uri)
+            .method(Method::POST)
+            .header(ACCEPT, "application/json")
+            .header(CONTENT_TYPE, "application/json")
+            .body(serde_json::to_string(&query).into_diagnostic()?)
+            .into_diagnostic()?;
+
+        let result = sender.send_request(request).await.into_diagnostic()?;
+        log::debug!("deserializing result");
+        let body = result.collect().await.into_diagnostic()?.aggregate();
+        let response: Response<UptimeResponse> =
+            serde_json::from_reader(body.reader()).into_diagnostic()?;
+
+        let latency = now.elapsed().into_diagnostic()?.as_millis();
+
+        let data = response.data();
+        let timestamp = data
+            .ok_or(Report::msg("failed to obtain uptime data"))?
+            .start_timestamp();
+
+        let botinfo_embed_botstarted_field_name =
+            localizer.utilities_plugin_botinfo_embed_botstarted_field_name()?;
+        let botinfo_embed_latency_field_name =
+            localizer.utilities_plugin_botinfo_embed_latency_field_name()?;
+        let botinfo_embed_title = localizer.utilities_plugin_botinfo_embed_title()?;
+
+        let embed = EmbedBuilder::new()
+            .color(0x41_A0_DE)
+            .field(EmbedFieldBuilder::new(
+                botinfo_embed_botstarted_field_name,
+                timestamp.to_string().discord_relative_timestamp(),
+            ))
+            .field(EmbedFieldBuilder::new(
+                botinfo_embed_latency_field_name,
+                latency.to_string().discord_inline_code(),
+            ))
+            .title(botinfo_embed_title)
+            .validate()
+            .into_diagnostic()?
+            .build();
+
+        interaction_client
+            .create_response(
+                interaction.id,
+                &interaction.token,
+                &embed_response(vec![embed]),
+            )
+            .await
+            .into_diagnostic()?;
+
+        Ok(())
+    }
 }
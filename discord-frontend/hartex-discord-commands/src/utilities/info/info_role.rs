@@ -24,114 +24,144 @@
 //!
 //! This command returns informatiomn about a role.
 
+use async_trait::async_trait;
 use hartex_discord_cdn::Cdn;
+use hartex_discord_commands_core::options::resolved;
+use hartex_discord_commands_core::options::CommandOptions;
+use hartex_discord_commands_core::subcommand;
+use hartex_discord_commands_core::traits::Subcommand;
+use hartex_discord_commands_core::CommandOptions;
 use hartex_discord_core::discord::http::client::InteractionClient;
 use hartex_discord_core::discord::mention::Mention;
 use hartex_discord_core::discord::model::application::interaction::application_command::CommandDataOption;
 use hartex_discord_core::discord::model::application::interaction::Interaction;
+use hartex_discord_core::discord::model::id::marker::RoleMarker;
+use hartex_discord_core::discord::model::id::Id;
 use hartex_discord_core::discord::util::builder::embed::EmbedBuilder;
 use hartex_discord_core::discord::util::builder::embed::EmbedFieldBuilder;
 use hartex_discord_core::discord::util::builder::embed::ImageSource;
 use hartex_discord_core::discord::util::snowflake::Snowflake;
 use hartex_discord_entitycache_core::traits::Repository;
 use hartex_discord_entitycache_repositories::role::CachedRoleRepository;
-use hartex_discord_utils::commands::CommandDataOptionExt;
-use hartex_discord_utils::commands::CommandDataOptionsExt;
 use hartex_discord_utils::interaction::embed_response;
 use hartex_discord_utils::localizable::Localizable;
 use hartex_discord_utils::markdown::MarkdownStyle;
 use hartex_localization_core::Localizer;
 use miette::IntoDiagnostic;
 
-/// Executes the `info emoji` command.
-pub async fn execute(
-    interaction: Interaction,
-    interaction_client: &InteractionClient<'_>,
-    option: CommandDataOption,
-    localizer: Localizer<'_>,
-) -> miette::Result<()> {
-    let options = option.assume_subcommand();
+/// The options of the `info role` command.
+#[derive(CommandOptions)]
+struct InfoRoleOptions {
+    role: Id<RoleMarker>,
+}
 
-    let langid_locale = interaction
-        .locale
-        .clone()
-        .and_then(|locale| locale.parse().ok());
+/// The `info role` subcommand declaration.
+#[subcommand(
+    name = "role",
+    description = "Information about a specific role.",
+    options = [
+        role(
+            name = "role",
+            description = "The role to obtain information for.",
+            required = true,
+        ),
+    ],
+)]
+pub struct InfoRole;
 
-    let role_id = options.role_value_of("role");
+#[async_trait]
+impl Subcommand for InfoRole {
+    async fn execute(
+        &self,
+        interaction: Interaction,
+        interaction_client: &InteractionClient<'_>,
+        option: CommandDataOption,
+        localizer: Localizer<'_>,
+    ) -> miette::Result<()> {
+        let options =
+            InfoRoleOptions::from_subcommand(&option, resolved(&interaction)).into_diagnostic()?;
 
-    let roleinfo_embed_generalinfo_field_name =
-        localizer.utilities_plugin_roleinfo_embed_generalinfo_field_name()?;
-    let roleinfo_embed_generalinfo_id_subfield_name =
-        localizer.utilities_plugin_roleinfo_embed_generalinfo_id_subfield_name()?;
-    let roleinfo_embed_generalinfo_created_subfield_name =
-        localizer.utilities_plugin_roleinfo_embed_generalinfo_created_subfield_name()?;
-    let roleinfo_embed_generalinfo_color_subfield_name =
-        localizer.utilities_plugin_roleinfo_embed_generalinfo_color_subfield_name()?;
-    let roleinfo_embed_description =
-        localizer.utilities_plugin_roleinfo_embed_description(role_id.mention().to_string())?;
-    let roleinfo_embed_attributes_field_name =
-        localizer.utilities_plugin_roleinfo_embed_attributes_field_name()?;
-    let roleinfo_embed_attributes_hoist_subfield_name =
-        localizer.utilities_plugin_roleinfo_embed_attributes_hoist_subfield_name()?;
-    let roleinfo_embed_attributes_managed_subfield_name =
-        localizer.utilities_plugin_roleinfo_embed_attributes_managed_subfield_name()?;
-    let roleinfo_embed_attributes_mentionable_subfield_name =
-        localizer.utilities_plugin_roleinfo_embed_attributes_mentionable_subfield_name()?;
-    let roleinfo_embed_attributes_position_subfield_name =
-        localizer.utilities_plugin_roleinfo_embed_attributes_position_subfield_name()?;
+        let langid_locale = interaction
+            .locale
+            .clone()
+            .and_then(|locale| locale.parse().ok());
 
-    let role = CachedRoleRepository
-        .get((interaction.guild_id.unwrap(), role_id))
-        .await
-        .into_diagnostic()?;
+        let role_id = options.role;
 
-    let mut builder = EmbedBuilder::new()
-        .color(0x41_A0_DE)
-        .description(roleinfo_embed_description)
-        .field(EmbedFieldBuilder::new(
-            format!("<:role:1139004530277765211> {roleinfo_embed_generalinfo_field_name}"),
-            format!(
-                "{} {}\n{} {}\n{} `#{:06X}`",
-                roleinfo_embed_generalinfo_id_subfield_name,
-                role.id.to_string().discord_inline_code(),
-                roleinfo_embed_generalinfo_created_subfield_name,
-                (role.id.timestamp() / 1000)
-                    .to_string()
-                    .discord_relative_timestamp(),
-                roleinfo_embed_generalinfo_color_subfield_name,
-                role.color,
-            ),
-        ))
-        .field(EmbedFieldBuilder::new(
-            roleinfo_embed_attributes_field_name,
-            format!(
-                "{} {}\n{} {}\n{} {}\n{} {}",
-                roleinfo_embed_attributes_hoist_subfield_name,
-                role.hoist.localize(langid_locale.clone())?,
-                roleinfo_embed_attributes_managed_subfield_name,
-                role.managed.localize(langid_locale.clone())?,
-                roleinfo_embed_attributes_mentionable_subfield_name,
-                role.mentionable.localize(langid_locale)?,
-                roleinfo_embed_attributes_position_subfield_name,
-                role.position,
-            ),
-        ));
+        let roleinfo_embed_generalinfo_field_name =
+            localizer.utilities_plugin_roleinfo_embed_generalinfo_field_name()?;
+        let roleinfo_embed_generalinfo_id_subfield_name =
+            localizer.utilities_plugin_roleinfo_embed_generalinfo_id_subfield_name()?;
+        let roleinfo_embed_generalinfo_created_subfield_name =
+            localizer.utilities_plugin_roleinfo_embed_generalinfo_created_subfield_name()?;
+        let roleinfo_embed_generalinfo_color_subfield_name =
+            localizer.utilities_plugin_roleinfo_embed_generalinfo_color_subfield_name()?;
+        let roleinfo_embed_description =
+            localizer.utilities_plugin_roleinfo_embed_description(role_id.mention().to_string())?;
+        let roleinfo_embed_attributes_field_name =
+            localizer.utilities_plugin_roleinfo_embed_attributes_field_name()?;
+        let roleinfo_embed_attributes_hoist_subfield_name =
+            localizer.utilities_plugin_roleinfo_embed_attributes_hoist_subfield_name()?;
+        let roleinfo_embed_attributes_managed_subfield_name =
+            localizer.utilities_plugin_roleinfo_embed_attributes_managed_subfield_name()?;
+        let roleinfo_embed_attributes_mentionable_subfield_name =
+            localizer.utilities_plugin_roleinfo_embed_attributes_mentionable_subfield_name()?;
+        let roleinfo_embed_attributes_position_subfield_name =
+            localizer.utilities_plugin_roleinfo_embed_attributes_position_subfield_name()?;
 
-    if let Some(icon) = role.icon {
-        builder =
-            builder.thumbnail(ImageSource::url(Cdn::role_icon(role.id, icon)).into_diagnostic()?);
-    }
+        let role = CachedRoleRepository
+            .get((interaction.guild_id.unwrap(), role_id))
+            .await
+            .into_diagnostic()?;
+
+        let mut builder = EmbedBuilder::new()
+            .color(0x41_A0_DE)
+            .description(roleinfo_embed_description)
+            .field(EmbedFieldBuilder::new(
+                format!("<:role:1139004530277765211> {roleinfo_embed_generalinfo_field_name}"),
+                format!(
+                    "{} {}\n{} {}\n{} `#{:06X}`",
+                    roleinfo_embed_generalinfo_id_subfield_name,
+                    role.id.to_string().discord_inline_code(),
+                    roleinfo_embed_generalinfo_created_subfield_name,
+                    (role.id.timestamp() / 1000)
+                        .to_string()
+                        .discord_relative_timestamp(),
+                    roleinfo_embed_generalinfo_color_subfield_name,
+                    role.color,
+                ),
+            ))
+            .field(EmbedFieldBuilder::new(
+                roleinfo_embed_attributes_field_name,
+                format!(
+                    "{} {}\n{} {}\n{} {}\n{} {}",
+                    roleinfo_embed_attributes_hoist_subfield_name,
+                    role.hoist.localize(langid_locale.clone())?,
+                    roleinfo_embed_attributes_managed_subfield_name,
+                    role.managed.localize(langid_locale.clone())?,
+                    roleinfo_embed_attributes_mentionable_subfield_name,
+                    role.mentionable.localize(langid_locale)?,
+                    roleinfo_embed_attributes_position_subfield_name,
+                    role.position,
+                ),
+            ));
 
-    let embed = builder.validate().into_diagnostic()?.build();
+        if let Some(icon) = role.icon {
+            builder = builder
+                .thumbnail(ImageSource::url(Cdn::role_icon(role.id, icon)).into_diagnostic()?);
+        }
 
-    interaction_client
-        .create_response(
-            interaction.id,
-            &interaction.token,
-            &embed_response(vec![embed]),
-        )
-        .await
-        .into_diagnostic()?;
+        let embed = builder.validate().into_diagnostic()?.build();
 
-    Ok(())
+        interaction_client
+            .create_response(
+                interaction.id,
+                &interaction.token,
+                &embed_response(vec![embed]),
+            )
+            .await
+            .into_diagnostic()?;
+
+        Ok(())
+    }
 }
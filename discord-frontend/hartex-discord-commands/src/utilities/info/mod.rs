@@ -24,12 +24,8 @@
 
 use async_trait::async_trait;
 use hartex_discord_commands_core::command;
+use hartex_discord_commands_core::middleware::cooldown::Cooldown;
 use hartex_discord_commands_core::traits::Command;
-use hartex_discord_core::discord::http::client::InteractionClient;
-use hartex_discord_core::discord::model::application::interaction::application_command::CommandOptionValue;
-use hartex_discord_core::discord::model::application::interaction::Interaction;
-use hartex_discord_core::discord::model::application::interaction::InteractionData;
-use hartex_localization_core::Localizer;
 
 use crate::utilities::Utilities;
 
@@ -40,76 +36,20 @@ mod info_server;
 mod info_user;
 
 /// The `info` command declaration.
-#[command(name = "info", plugin = Utilities)]
+#[command(
+    name = "info",
+    plugin = Utilities,
+    description = "Information about a certain entity.",
+    middlewares = [Cooldown::from_secs(3)],
+    subcommands = [
+        info_server::InfoServer,
+        info_bot::InfoBot,
+        info_role::InfoRole,
+        info_user::InfoUser,
+        info_emoji::InfoEmoji,
+    ],
+)]
 pub struct Info;
 
 #[async_trait]
-impl Command for Info {
-    async fn execute(
-        &self,
-        interaction: Interaction,
-        interaction_client: &InteractionClient<'_>,
-        localizer: Localizer<'_>,
-    ) -> miette::Result<()> {
-        let Some(InteractionData::ApplicationCommand(command)) = interaction.clone().data else {
-            unreachable!()
-        };
-
-        let Some(subcommand) = command
-            .options
-            .iter()
-            .find(|option| matches!(option.value, CommandOptionValue::SubCommand(_)))
-        else {
-            unreachable!()
-        };
-
-        match subcommand.name.as_str() {
-            "bot" => {
-                info_bot::execute(
-                    interaction,
-                    interaction_client,
-                    subcommand.clone(),
-                    localizer,
-                )
-                .await
-            }
-            "emoji" => {
-                info_emoji::execute(
-                    interaction,
-                    interaction_client,
-                    subcommand.clone(),
-                    localizer,
-                )
-                .await
-            }
-            "role" => {
-                info_role::execute(
-                    interaction,
-                    interaction_client,
-                    subcommand.clone(),
-                    localizer,
-                )
-                .await
-            }
-            "server" => {
-                info_server::execute(
-                    interaction,
-                    interaction_client,
-                    subcommand.clone(),
-                    localizer,
-                )
-                .await
-            }
-            "user" => {
-                info_user::execute(
-                    interaction,
-                    interaction_client,
-                    subcommand.clone(),
-                    localizer,
-                )
-                .await
-            }
-            _ => unreachable!(),
-        }
-    }
-}
+impl Command for Info {}
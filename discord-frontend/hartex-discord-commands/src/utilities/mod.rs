@@ -27,7 +27,9 @@
 
 use async_trait::async_trait;
 use hartex_discord_commands_core::plugin;
+use hartex_discord_commands_core::traits::ConfiguredPlugin;
 use hartex_discord_commands_core::traits::Plugin;
+use hartex_discord_configuration_models::plugins::utilities::UtilitiesPlugin;
 
 pub mod info;
 
@@ -37,3 +39,8 @@ pub struct Utilities;
 
 #[async_trait]
 impl Plugin for Utilities {}
+
+#[async_trait]
+impl ConfiguredPlugin for Utilities {
+    type Configuration = UtilitiesPlugin;
+}
@@ -0,0 +1,281 @@
+/*
+ * SPDX-License-Identifier: AGPL-3.0-only
+ *
+ * This file is part of HarTex.
+ *
+ * HarTex
+ * Copyright (c) 2021-2024 HarTex Project Developers
+ *
+ * HarTex is free software; you can redistribute it and/or modify
+ * it under the terms of the GNU Affero General Public License as published by
+ * the Free Software Foundation; either version 3 of the License, or
+ * (at your option) any later version.
+ *
+ * HarTex is distributed in the hope that it will be useful,
+ * but WITHOUT ANY WARRANTY; without even the implied warranty of
+ * MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+ * GNU Affero General Public License for more details.
+ *
+ * You should have received a copy of the GNU Affero General Public License along
+ * with HarTex. If not, see <https://www.gnu.org/licenses/>.
+ */
+
+use std::collections::HashSet;
+
+use hartex_discord_commands::general::about::About;
+use hartex_discord_commands::general::contributors::Contributors;
+use hartex_discord_commands::general::help::Help;
+use hartex_discord_commands::general::plugins::Plugins;
+use hartex_discord_commands_core::middleware::blacklist::Blacklist;
+use hartex_discord_commands_core::middleware::cooldown::Cooldown;
+use hartex_discord_commands_core::middleware::MiddlewareChain;
+use hartex_discord_commands_testing::configuration;
+use hartex_discord_commands_testing::fixtures;
+use hartex_discord_commands_testing::fixtures::InteractionFixture;
+use hartex_discord_commands_testing::fixtures::GUILD_ID;
+use hartex_discord_commands_testing::fixtures::USER_ID;
+use hartex_discord_commands_testing::harness;
+use hartex_discord_core::discord::model::application::command::CommandOptionType;
+use hartex_discord_core::discord::model::id::Id;
+use hartex_discord_core::tokio;
+
+#[tokio::test]
+async fn about_responds_with_embed() {
+    let interaction = InteractionFixture::command("about").build();
+    let requests = harness::execute(&About, interaction).await.unwrap();
+
+    assert_eq!(requests.len(), 1);
+    assert!(requests[0].path.ends_with("/callback"));
+
+    let embeds = requests[0].embeds();
+    assert_eq!(embeds.len(), 1);
+    assert_eq!(embeds[0]["author"]["name"], "About HarTex");
+}
+
+#[tokio::test]
+async fn about_is_localized() {
+    let interaction = InteractionFixture::command("about").locale("ja").build();
+    let requests = harness::execute(&About, interaction).await.unwrap();
+
+    let embeds = requests[0].embeds();
+    assert_eq!(embeds[0]["author"]["name"], "HarTex について");
+}
+
+#[tokio::test]
+async fn contributors_responds_with_embed() {
+    let interaction = InteractionFixture::command("contributors").build();
+    let requests = harness::execute(&Contributors, interaction).await.unwrap();
+
+    assert_eq!(requests.len(), 1);
+    assert_eq!(requests[0].embeds().len(), 1);
+}
+
+#[tokio::test]
+async fn help_lists_commands_by_plugin() {
+    let interaction = InteractionFixture::command("help").direct_message().build();
+    let requests = harness::execute(&Help, interaction).await.unwrap();
+
+    let embeds = requests[0].embeds();
+    assert_eq!(embeds[0]["title"], "Help");
+
+    let plugins = embeds[0]["fields"]
+        .as_array()
+        .unwrap()
+        .iter()
+        .map(|field| field["name"].as_str().unwrap())
+        .collect::<Vec<_>>();
+    assert_eq!(plugins, ["General", "Utilities"]);
+
+    let components = requests[0].components();
+    assert_eq!(components[0]["components"][0]["custom_id"], "help:select");
+}
+
+#[tokio::test]
+async fn help_shows_localized_command_details() {
+    let interaction = InteractionFixture::command("help")
+        .direct_message()
+        .locale("ja")
+        .option(fixtures::option(
+            "command",
+            CommandOptionType::String,
+            "情報",
+        ))
+        .build();
+    let requests = harness::execute(&Help, interaction).await.unwrap();
+
+    let embeds = requests[0].embeds();
+    assert!(embeds[0]["title"].as_str().unwrap().contains("/情報"));
+
+    let description = embeds[0]["description"].as_str().unwrap();
+    assert!(description.contains("/情報 サーバー [詳しく]"));
+    assert!(embeds[0]["fields"][1]["value"]
+        .as_str()
+        .unwrap()
+        .contains("/情報 ユーザー ユーザー:@HarTex"));
+}
+
+#[tokio::test]
+async fn help_rejects_unknown_commands() {
+    let interaction = InteractionFixture::command("help")
+        .direct_message()
+        .option(fixtures::option(
+            "command",
+            CommandOptionType::String,
+            "unknown",
+        ))
+        .build();
+    let requests = harness::execute(&Help, interaction).await.unwrap();
+
+    assert_eq!(requests[0].flags(), 64);
+    assert!(requests[0].content().unwrap().contains("`unknown`"));
+}
+
+#[tokio::test]
+async fn plugins_enable_rejects_unknown_plugins() {
+    let interaction = InteractionFixture::command("plugins")
+        .subcommand(
+            "enable",
+            vec![fixtures::option(
+                "name",
+                CommandOptionType::String,
+                "unknown",
+            )],
+        )
+        .build();
+    let requests = harness::execute(&Plugins, interaction).await.unwrap();
+
+    assert_eq!(requests[0].flags(), 64);
+    let content = requests[0].content().unwrap();
+    assert!(content.contains("`unknown`"));
+    assert!(content.contains("`management`"));
+}
+
+#[tokio::test]
+async fn plugins_disable_rejects_plugins_always_enabled() {
+    let interaction = InteractionFixture::command("plugins")
+        .subcommand(
+            "disable",
+            vec![fixtures::option(
+                "name",
+                CommandOptionType::String,
+                "general",
+            )],
+        )
+        .build();
+    let requests = harness::execute(&Plugins, interaction).await.unwrap();
+
+    assert_eq!(requests[0].flags(), 64);
+    assert!(requests[0].content().unwrap().contains("always enabled"));
+}
+
+/// Invokes `plugins enable` with an unknown plugin, as the given user.
+fn plugins_enable_unknown(user_id: u64) -> InteractionFixture {
+    InteractionFixture::command("plugins")
+        .subcommand(
+            "enable",
+            vec![fixtures::option(
+                "name",
+                CommandOptionType::String,
+                "unknown",
+            )],
+        )
+        .user_id(user_id)
+}
+
+#[tokio::test]
+async fn plugins_runs_for_dashboard_admins() {
+    harness::prepare();
+    configuration::install().set_dashboard_admins(Id::new(GUILD_ID), vec![Id::new(USER_ID)]);
+
+    let interaction = plugins_enable_unknown(USER_ID).build();
+    let requests = harness::run(&MiddlewareChain::new(), &Plugins, interaction)
+        .await
+        .unwrap();
+
+    assert_eq!(requests[0].flags(), 64);
+    assert!(requests[0].content().unwrap().contains("`unknown`"));
+}
+
+#[tokio::test]
+async fn plugins_rejects_users_other_than_dashboard_admins() {
+    harness::prepare();
+    configuration::install().set_dashboard_admins(Id::new(GUILD_ID), vec![Id::new(USER_ID)]);
+
+    let interaction = plugins_enable_unknown(USER_ID + 1).build();
+    let requests = harness::run(&MiddlewareChain::new(), &Plugins, interaction)
+        .await
+        .unwrap();
+
+    assert_eq!(
+        requests[0].content().unwrap(),
+        "Only administrators of the dashboard of this server can use this command."
+    );
+}
+
+#[tokio::test]
+async fn plugins_enable_enables_plugin() {
+    harness::prepare();
+    let guild_id = Id::new(GUILD_ID + 1);
+    let configuration = configuration::install();
+    configuration.set_dashboard_admins(guild_id, vec![Id::new(USER_ID)]);
+
+    let interaction = InteractionFixture::command("plugins")
+        .subcommand(
+            "enable",
+            vec![fixtures::option(
+                "name",
+                CommandOptionType::String,
+                "utilities",
+            )],
+        )
+        .guild_id(guild_id.get())
+        .build();
+    let requests = harness::run(&MiddlewareChain::new(), &Plugins, interaction)
+        .await
+        .unwrap();
+
+    assert_eq!(requests[0].flags(), 0);
+    assert!(configuration
+        .guild(guild_id)
+        .unwrap()
+        .enabled_plugins
+        .contains(&String::from("utilities")));
+}
+
+#[tokio::test]
+async fn contributors_cooldown_starts_once_every_middleware_passed() {
+    let user_id = Id::new(USER_ID + 2);
+    let rejecting = MiddlewareChain::new()
+        .with(Cooldown::from_secs(60))
+        .with(Blacklist::new(HashSet::new(), HashSet::from([user_id])));
+    let cooldown = MiddlewareChain::new().with(Cooldown::from_secs(60));
+
+    for _ in 0..2 {
+        let interaction = InteractionFixture::command("contributors")
+            .user_id(user_id.get())
+            .build();
+        let requests = harness::run(&rejecting, &Contributors, interaction)
+            .await
+            .unwrap();
+        assert!(requests[0]
+            .content()
+            .unwrap()
+            .contains("not allowed to use this bot"));
+    }
+
+    let interaction = InteractionFixture::command("contributors")
+        .user_id(user_id.get())
+        .build();
+    let requests = harness::run(&cooldown, &Contributors, interaction)
+        .await
+        .unwrap();
+    assert_eq!(requests[0].embeds().len(), 1);
+
+    let interaction = InteractionFixture::command("contributors")
+        .user_id(user_id.get())
+        .build();
+    let requests = harness::run(&cooldown, &Contributors, interaction)
+        .await
+        .unwrap();
+    assert!(requests[0].content().unwrap().contains("on cooldown"));
+}
@@ -0,0 +1,69 @@
+/*
+ * SPDX-License-Identifier: AGPL-3.0-only
+ *
+ * This file is part of HarTex.
+ *
+ * HarTex
+ * Copyright (c) 2021-2024 HarTex Project Developers
+ *
+ * HarTex is free software; you can redistribute it and/or modify
+ * it under the terms of the GNU Affero General Public License as published by
+ * the Free Software Foundation; either version 3 of the License, or
+ * (at your option) any later version.
+ *
+ * HarTex is distributed in the hope that it will be useful,
+ * but WITHOUT ANY WARRANTY; without even the implied warranty of
+ * MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+ * GNU Affero General Public License for more details.
+ *
+ * You should have received a copy of the GNU Affero General Public License along
+ * with HarTex. If not, see <https://www.gnu.org/licenses/>.
+ */
+
+use std::env;
+use std::fs;
+use std::path::PathBuf;
+
+use hartex_discord_commands_core::traits::CommandMetadata;
+use hartex_discord_commands_core::traits::PluginMetadata;
+use serde_json::Value;
+
+/// Checks that the specification declared for every command matches its committed specification
+/// in `hartex-discord-commands-spec`.
+///
+/// Set the `UPDATE_SPECS` environment variable to rewrite the committed specifications instead.
+#[test]
+fn command_specs_match_committed_specs() {
+    let root = PathBuf::from(env!("CARGO_MANIFEST_DIR")).join("../hartex-discord-commands-spec");
+    let update = env::var_os("UPDATE_SPECS").is_some();
+
+    let mut diverged = Vec::new();
+    for command in hartex_discord_commands::commands() {
+        let path = root
+            .join(format!("{}-plugin", command.plugin().name()))
+            .join(format!("{}.json", command.name()));
+        let generated = serde_json::to_value(command.spec()).unwrap();
+
+        if update {
+            let mut json = serde_json::to_string_pretty(&generated).unwrap();
+            json.push('\n');
+            fs::write(&path, json).unwrap();
+
+            continue;
+        }
+
+        let committed = fs::read_to_string(&path)
+            .unwrap_or_else(|error| panic!("failed to read {}: {error}", path.display()));
+        let committed = serde_json::from_str::<Value>(&committed).unwrap();
+
+        if generated != committed {
+            diverged.push(path.display().to_string());
+        }
+    }
+
+    assert!(
+        diverged.is_empty(),
+        "command specifications diverged from their declarations: {diverged:?}; \
+        rerun with `UPDATE_SPECS=1` to update them"
+    );
+}
@@ -0,0 +1,268 @@
+/*
+ * SPDX-License-Identifier: AGPL-3.0-only
+ *
+ * This file is part of HarTex.
+ *
+ * HarTex
+ * Copyright (c) 2021-2024 HarTex Project Developers
+ *
+ * HarTex is free software; you can redistribute it and/or modify
+ * it under the terms of the GNU Affero General Public License as published by
+ * the Free Software Foundation; either version 3 of the License, or
+ * (at your option) any later version.
+ *
+ * HarTex is distributed in the hope that it will be useful,
+ * but WITHOUT ANY WARRANTY; without even the implied warranty of
+ * MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+ * GNU Affero General Public License for more details.
+ *
+ * You should have received a copy of the GNU Affero General Public License along
+ * with HarTex. If not, see <https://www.gnu.org/licenses/>.
+ */
+
+use hartex_discord_commands::utilities::info::Info;
+use hartex_discord_commands_core::middleware::permissions::PermissionLevel;
+use hartex_discord_commands_core::middleware::plugin::PluginEnabled;
+use hartex_discord_commands_core::middleware::MiddlewareChain;
+use hartex_discord_commands_testing::configuration;
+use hartex_discord_commands_testing::fixtures;
+use hartex_discord_commands_testing::fixtures::InteractionFixture;
+use hartex_discord_commands_testing::fixtures::GUILD_ID;
+use hartex_discord_commands_testing::fixtures::USER_ID;
+use hartex_discord_commands_testing::harness;
+use hartex_discord_commands_testing::snapshot::assert_snapshot;
+use hartex_discord_configuration_provider::ConfigurationProvider;
+use hartex_discord_core::discord::model::application::command::CommandOptionType;
+use hartex_discord_core::discord::model::guild::Emoji;
+use hartex_discord_core::discord::model::id::Id;
+use hartex_discord_core::tokio;
+use hartex_discord_entitycache_core::traits::Repository;
+use hartex_discord_entitycache_entities::emoji::EmojiEntity;
+use hartex_discord_entitycache_repositories::emoji::CachedEmojiRepository;
+use serde_json::json;
+
+/// The id of the role of the invoking member in tests of permission levels.
+const ROLE_ID: u64 = 6000;
+
+/// The middlewares checking whether the plugin of a command is enabled and whether the invoking
+/// member has the permission level required by the command.
+fn access_control() -> MiddlewareChain {
+    MiddlewareChain::new()
+        .with(PluginEnabled)
+        .with(PermissionLevel)
+}
+
+/// Configures a guild with the utilities plugin enabled.
+fn enable_utilities(guild_id: u64) {
+    configuration::install().update(Id::new(guild_id), |guild| {
+        guild.enabled_plugins = vec![String::from("utilities")];
+    });
+}
+
+/// Invokes `info emoji` with the given emoji.
+fn info_emoji(emoji: &str) -> InteractionFixture {
+    InteractionFixture::command("info").subcommand(
+        "emoji",
+        vec![fixtures::option("emoji", CommandOptionType::String, emoji)],
+    )
+}
+
+#[tokio::test]
+async fn info_emoji_rejects_unicode_emojis() {
+    let interaction = info_emoji("🦀").build();
+    let requests = harness::execute(&Info, interaction).await.unwrap();
+
+    assert_snapshot(
+        concat!(
+            env!("CARGO_MANIFEST_DIR"),
+            "/tests/snapshots/info_emoji_rejects_unicode_emojis.json"
+        ),
+        &requests[0].body,
+    );
+}
+
+#[tokio::test]
+async fn info_emoji_rejects_unknown_emojis() {
+    let interaction = info_emoji("<:unknown:100000000000000001>").build();
+    let requests = harness::execute(&Info, interaction).await.unwrap();
+
+    assert_snapshot(
+        concat!(
+            env!("CARGO_MANIFEST_DIR"),
+            "/tests/snapshots/info_emoji_rejects_unknown_emojis.json"
+        ),
+        &requests[0].body,
+    );
+}
+
+#[tokio::test]
+async fn info_emoji_responds_with_cached_emoji() {
+    let emoji = serde_json::from_value::<Emoji>(json!({
+        "animated": false,
+        "available": true,
+        "id": "100000000000000002",
+        "managed": false,
+        "name": "hartex",
+        "require_colons": true,
+        "roles": [],
+    }))
+    .unwrap();
+    CachedEmojiRepository
+        .upsert(EmojiEntity::from((Id::new(GUILD_ID), emoji)))
+        .await
+        .unwrap();
+
+    let interaction = info_emoji("<:hartex:100000000000000002>").build();
+    let requests = harness::execute(&Info, interaction).await.unwrap();
+
+    let embeds = requests[0].embeds();
+    assert_eq!(embeds.len(), 1);
+
+    let field = embeds[0]["fields"][0]["value"].as_str().unwrap();
+    assert!(field.contains("`100000000000000002`"));
+    assert!(field.contains("hartex"));
+    assert!(field.contains(&format!("`{GUILD_ID}`")));
+}
+
+#[tokio::test]
+async fn info_rejects_when_utilities_disabled() {
+    harness::prepare();
+
+    let interaction = info_emoji("🦀").guild_id(GUILD_ID + 1).build();
+    let requests = harness::run(&access_control(), &Info, interaction)
+        .await
+        .unwrap();
+
+    assert!(requests[0]
+        .content()
+        .unwrap()
+        .contains("plugin is not enabled"));
+}
+
+#[tokio::test]
+async fn info_runs_when_utilities_enabled() {
+    harness::prepare();
+    enable_utilities(GUILD_ID + 2);
+
+    let interaction = info_emoji("🦀")
+        .guild_id(GUILD_ID + 2)
+        .user_id(USER_ID + 2)
+        .build();
+    let requests = harness::run(&access_control(), &Info, interaction)
+        .await
+        .unwrap();
+
+    assert_snapshot(
+        concat!(
+            env!("CARGO_MANIFEST_DIR"),
+            "/tests/snapshots/info_emoji_rejects_unicode_emojis.json"
+        ),
+        &requests[0].body,
+    );
+}
+
+#[tokio::test]
+async fn info_rejects_members_below_command_level() {
+    harness::prepare();
+    enable_utilities(GUILD_ID + 3);
+    configuration::install().update(Id::new(GUILD_ID + 3), |guild| {
+        guild.command_levels.insert(String::from("info"), 50);
+        guild.plugin_levels.insert(String::from("utilities"), 10);
+        guild.role_levels.insert(Id::new(ROLE_ID), 10);
+    });
+
+    let interaction = info_emoji("🦀")
+        .guild_id(GUILD_ID + 3)
+        .roles(&[ROLE_ID])
+        .build();
+    let requests = harness::run(&access_control(), &Info, interaction)
+        .await
+        .unwrap();
+
+    assert!(requests[0]
+        .content()
+        .unwrap()
+        .contains("insufficient permission level"));
+}
+
+#[tokio::test]
+async fn info_runs_for_members_at_plugin_level() {
+    harness::prepare();
+    enable_utilities(GUILD_ID + 4);
+    configuration::install().update(Id::new(GUILD_ID + 4), |guild| {
+        guild.plugin_levels.insert(String::from("utilities"), 30);
+        guild.role_levels.insert(Id::new(ROLE_ID), 10);
+        guild.user_levels.insert(Id::new(USER_ID + 4), 30);
+    });
+
+    let interaction = info_emoji("🦀")
+        .guild_id(GUILD_ID + 4)
+        .user_id(USER_ID + 4)
+        .roles(&[ROLE_ID])
+        .build();
+    let requests = harness::run(&access_control(), &Info, interaction)
+        .await
+        .unwrap();
+
+    assert_snapshot(
+        concat!(
+            env!("CARGO_MANIFEST_DIR"),
+            "/tests/snapshots/info_emoji_rejects_unicode_emojis.json"
+        ),
+        &requests[0].body,
+    );
+}
+
+#[tokio::test]
+async fn info_serves_permission_levels_cached_until_invalidated() {
+    harness::prepare();
+    enable_utilities(GUILD_ID + 5);
+    configuration::install().update(Id::new(GUILD_ID + 5), |guild| {
+        guild.command_levels.insert(String::from("info"), 50);
+    });
+
+    let interaction = info_emoji("🦀")
+        .guild_id(GUILD_ID + 5)
+        .user_id(USER_ID + 5)
+        .build();
+    let requests = harness::run(&access_control(), &Info, interaction)
+        .await
+        .unwrap();
+    assert!(requests[0]
+        .content()
+        .unwrap()
+        .contains("insufficient permission level"));
+
+    configuration::install().update(Id::new(GUILD_ID + 5), |guild| {
+        guild.user_levels.insert(Id::new(USER_ID + 5), 50);
+    });
+
+    let interaction = info_emoji("🦀")
+        .guild_id(GUILD_ID + 5)
+        .user_id(USER_ID + 5)
+        .build();
+    let requests = harness::run(&access_control(), &Info, interaction)
+        .await
+        .unwrap();
+    assert!(requests[0]
+        .content()
+        .unwrap()
+        .contains("insufficient permission level"));
+
+    ConfigurationProvider::invalidate(Id::new(GUILD_ID + 5));
+
+    let interaction = info_emoji("🦀")
+        .guild_id(GUILD_ID + 5)
+        .user_id(USER_ID + 5)
+        .build();
+    let requests = harness::run(&access_control(), &Info, interaction)
+        .await
+        .unwrap();
+    assert_snapshot(
+        concat!(
+            env!("CARGO_MANIFEST_DIR"),
+            "/tests/snapshots/info_emoji_rejects_unicode_emojis.json"
+        ),
+        &requests[0].body,
+    );
+}
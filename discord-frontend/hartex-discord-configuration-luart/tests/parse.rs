@@ -35,6 +35,13 @@ const SAMPLE_CONFIG: &'static str = r#"return {
         colour = hartexconf.colour.rgb(0x768EE5)
     },
 
+    permissions = {
+        roles = { ["1000000000000007"] = 50 },
+        users = { ["1000000000000000"] = 100 },
+        plugins = { management = 50 },
+        commands = { infraction = 80 }
+    },
+
     plugins = {
         management = {
             enabled = true
@@ -90,6 +97,30 @@ Configuration {
             ],
         ),
     },
+    permissions: Some(
+        PermissionLevels {
+            commands: Some(
+                {
+                    "infraction": 80,
+                },
+            ),
+            plugins: Some(
+                {
+                    "management": 50,
+                },
+            ),
+            roles: Some(
+                {
+                    "1000000000000007": 50,
+                },
+            ),
+            users: Some(
+                {
+                    "1000000000000000": 100,
+                },
+            ),
+        },
+    ),
     plugins: Some(
         Plugins {
             management: Some(
@@ -124,3 +155,87 @@ Configuration {
 
     expected.assert_debug_eq(&config);
 }
+
+const GROUP_ALIAS_CONFIG: &'static str = r#"return {
+    dashboard = {
+        admins = { "1000000000000000" }
+    },
+
+    plugins = {
+        modlog = {
+            enabled = true,
+            loggers = {
+                {
+                    channel = "1000000000000006",
+                    events = { "ALL_MESSAGE_EVENTS", "MEMBER_BANNED" },
+                    format = "default"
+                }
+            }
+        }
+    }
+}
+"#;
+
+#[test]
+pub fn parse_group_alias_test() {
+    let config = evaluate_config(GROUP_ALIAS_CONFIG).unwrap();
+    let events = config.plugins.unwrap().modlog.unwrap().loggers[0].events;
+    let expected = expect![
+        r#"
+EventFlags(
+    MESSAGE_DELETED | MESSAGE_UPDATED | MESSAGE_BULK_DELETED | MEMBER_BANNED,
+)
+"#
+    ];
+
+    expected.assert_debug_eq(&events);
+}
+
+const PERMISSION_LEVEL_OUT_OF_RANGE_CONFIG: &'static str = r#"return {
+    dashboard = {
+        admins = { "1000000000000000" }
+    },
+
+    permissions = {
+        users = { ["1000000000000000"] = 101 }
+    }
+}
+"#;
+
+#[test]
+pub fn parse_permission_level_out_of_range_test() {
+    let error = evaluate_config(PERMISSION_LEVEL_OUT_OF_RANGE_CONFIG).unwrap_err();
+
+    assert!(error
+        .to_string()
+        .contains("level 101 of users.1000000000000000 exceeds the maximum level of 100"));
+}
+
+const UNKNOWN_EVENT_FLAG_CONFIG: &'static str = r#"return {
+    dashboard = {
+        admins = { "1000000000000000" }
+    },
+
+    plugins = {
+        modlog = {
+            enabled = true,
+            loggers = {
+                {
+                    channel = "1000000000000006",
+                    events = { "INFRACTION_CREATED" },
+                    format = "default"
+                }
+            }
+        }
+    }
+}
+"#;
+
+#[test]
+pub fn parse_unknown_event_flag_test() {
+    let error = evaluate_config(UNKNOWN_EVENT_FLAG_CONFIG).unwrap_err();
+
+    assert!(error
+        .to_string()
+        .contains("EventFlags: unknown flag INFRACTION_CREATED"));
+}
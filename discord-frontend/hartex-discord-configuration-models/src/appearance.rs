@@ -29,7 +29,7 @@ use mlua::Value;
 use serde::Serialize;
 
 /// The appearance configuration object.
-#[derive(Debug, Serialize)]
+#[derive(Clone, Debug, Serialize)]
 pub struct Appearance {
     /// The role colour of the bot.
     pub colour: Option<u32>,
@@ -21,9 +21,21 @@
  */
 
 //! # Event Bitflags
+//!
+//! The events a modlog logger can listen for. Every event flag is triggered by one or more
+//! gateway events, as defined by [`EventFlags::event_types`].
+//!
+//! Group aliases such as `ALL_MESSAGE_EVENTS` are provided for convenience; they are accepted
+//! anywhere an event flag is.
 
 use bitflags::Flags;
 use hartex_bitflags_utils::FlagsExt;
+use hartex_discord_core::discord::model::gateway::event::DispatchEvent;
+use hartex_discord_core::discord::model::gateway::event::EventType;
+use hartex_discord_core::discord::model::id::marker::ChannelMarker;
+use hartex_discord_core::discord::model::id::marker::RoleMarker;
+use hartex_discord_core::discord::model::id::Id;
+use hartex_discord_core::discord::model::util::ImageHash;
 use mlua::Error;
 use mlua::FromLua;
 use mlua::Lua;
@@ -31,13 +43,221 @@ use mlua::Value;
 use serde::Serialize;
 
 bitflags::bitflags! {
-    #[derive(Debug, Serialize)]
+    #[derive(Clone, Copy, Debug, Eq, PartialEq, Serialize)]
     pub struct EventFlags: u128 {
         const MESSAGE_DELETED = 1;
         const MESSAGE_UPDATED = 1 << 1;
+        const MESSAGE_BULK_DELETED = 1 << 2;
+
+        const MEMBER_JOINED = 1 << 16;
+        const MEMBER_LEFT = 1 << 17;
+        const MEMBER_UPDATED = 1 << 18;
+        const MEMBER_NICKNAME_CHANGED = 1 << 19;
+        const MEMBER_AVATAR_CHANGED = 1 << 20;
+        const MEMBER_ROLES_CHANGED = 1 << 21;
+
+        const ROLE_CREATED = 1 << 32;
+        const ROLE_UPDATED = 1 << 33;
+        const ROLE_DELETED = 1 << 34;
+
+        const CHANNEL_CREATED = 1 << 48;
+        const CHANNEL_UPDATED = 1 << 49;
+        const CHANNEL_DELETED = 1 << 50;
+
+        const MEMBER_BANNED = 1 << 64;
+        const MEMBER_UNBANNED = 1 << 65;
+
+        const VOICE_JOINED = 1 << 80;
+        const VOICE_LEFT = 1 << 81;
+        const VOICE_MOVED = 1 << 82;
+
+        const INVITE_CREATED = 1 << 96;
+        const INVITE_DELETED = 1 << 97;
+
+        const EMOJIS_UPDATED = 1 << 104;
+
+        const ALL_MESSAGE_EVENTS = Self::MESSAGE_DELETED.bits()
+            | Self::MESSAGE_UPDATED.bits()
+            | Self::MESSAGE_BULK_DELETED.bits();
+        const ALL_MEMBER_EVENTS = Self::MEMBER_JOINED.bits()
+            | Self::MEMBER_LEFT.bits()
+            | Self::MEMBER_UPDATED.bits()
+            | Self::MEMBER_NICKNAME_CHANGED.bits()
+            | Self::MEMBER_AVATAR_CHANGED.bits()
+            | Self::MEMBER_ROLES_CHANGED.bits();
+        const ALL_ROLE_EVENTS = Self::ROLE_CREATED.bits()
+            | Self::ROLE_UPDATED.bits()
+            | Self::ROLE_DELETED.bits();
+        const ALL_CHANNEL_EVENTS = Self::CHANNEL_CREATED.bits()
+            | Self::CHANNEL_UPDATED.bits()
+            | Self::CHANNEL_DELETED.bits();
+        const ALL_BAN_EVENTS = Self::MEMBER_BANNED.bits() | Self::MEMBER_UNBANNED.bits();
+        const ALL_VOICE_EVENTS = Self::VOICE_JOINED.bits()
+            | Self::VOICE_LEFT.bits()
+            | Self::VOICE_MOVED.bits();
+        const ALL_INVITE_EVENTS = Self::INVITE_CREATED.bits() | Self::INVITE_DELETED.bits();
+        const ALL_EVENTS = Self::ALL_MESSAGE_EVENTS.bits()
+            | Self::ALL_MEMBER_EVENTS.bits()
+            | Self::ALL_ROLE_EVENTS.bits()
+            | Self::ALL_CHANNEL_EVENTS.bits()
+            | Self::ALL_BAN_EVENTS.bits()
+            | Self::ALL_VOICE_EVENTS.bits()
+            | Self::ALL_INVITE_EVENTS.bits()
+            | Self::EMOJIS_UPDATED.bits();
+    }
+}
+
+/// The gateway events triggering each event flag.
+///
+/// Some flags share the same gateway event (for instance, nickname and avatar changes are both
+/// received as `GUILD_MEMBER_UPDATE`); the payload of the event determines which of them
+/// actually apply, see [`EventFlags::triggered_by`].
+const EVENT_TYPES: &[(EventFlags, &[EventType])] = &[
+    (EventFlags::MESSAGE_DELETED, &[EventType::MessageDelete]),
+    (EventFlags::MESSAGE_UPDATED, &[EventType::MessageUpdate]),
+    (
+        EventFlags::MESSAGE_BULK_DELETED,
+        &[EventType::MessageDeleteBulk],
+    ),
+    (EventFlags::MEMBER_JOINED, &[EventType::MemberAdd]),
+    (EventFlags::MEMBER_LEFT, &[EventType::MemberRemove]),
+    (EventFlags::MEMBER_UPDATED, &[EventType::MemberUpdate]),
+    (
+        EventFlags::MEMBER_NICKNAME_CHANGED,
+        &[EventType::MemberUpdate],
+    ),
+    (
+        EventFlags::MEMBER_AVATAR_CHANGED,
+        &[EventType::MemberUpdate],
+    ),
+    (EventFlags::MEMBER_ROLES_CHANGED, &[EventType::MemberUpdate]),
+    (EventFlags::ROLE_CREATED, &[EventType::RoleCreate]),
+    (EventFlags::ROLE_UPDATED, &[EventType::RoleUpdate]),
+    (EventFlags::ROLE_DELETED, &[EventType::RoleDelete]),
+    (EventFlags::CHANNEL_CREATED, &[EventType::ChannelCreate]),
+    (
+        EventFlags::CHANNEL_UPDATED,
+        &[EventType::ChannelUpdate, EventType::ChannelPinsUpdate],
+    ),
+    (EventFlags::CHANNEL_DELETED, &[EventType::ChannelDelete]),
+    (EventFlags::MEMBER_BANNED, &[EventType::BanAdd]),
+    (EventFlags::MEMBER_UNBANNED, &[EventType::BanRemove]),
+    (EventFlags::VOICE_JOINED, &[EventType::VoiceStateUpdate]),
+    (EventFlags::VOICE_LEFT, &[EventType::VoiceStateUpdate]),
+    (EventFlags::VOICE_MOVED, &[EventType::VoiceStateUpdate]),
+    (EventFlags::INVITE_CREATED, &[EventType::InviteCreate]),
+    (EventFlags::INVITE_DELETED, &[EventType::InviteDelete]),
+    (EventFlags::EMOJIS_UPDATED, &[EventType::GuildEmojisUpdate]),
+];
+
+impl EventFlags {
+    /// Returns the gateway events triggering any of the flags in this set, without duplicates.
+    #[must_use]
+    pub fn event_types(self) -> Vec<EventType> {
+        let mut event_types = Vec::new();
+
+        EVENT_TYPES
+            .iter()
+            .filter(|(flag, _)| self.contains(*flag))
+            .flat_map(|(_, event_types)| event_types.iter())
+            .for_each(|event_type| {
+                if !event_types.contains(event_type) {
+                    event_types.push(*event_type);
+                }
+            });
+
+        event_types
+    }
+
+    /// Returns the event flags triggered by a gateway event.
+    ///
+    /// Where several flags share the gateway event, the payload of the event is compared against
+    /// the previous state of the entity it changes to determine which of them apply. Without the
+    /// previous state, only the flags that can be told from the payload alone are returned.
+    #[must_use]
+    pub fn triggered_by(event: &DispatchEvent, previous: Option<PreviousState<'_>>) -> Self {
+        match event {
+            DispatchEvent::MemberUpdate(member_update) => {
+                let mut flags = Self::MEMBER_UPDATED;
+
+                if let Some(PreviousState::Member {
+                    avatar,
+                    nick,
+                    roles,
+                }) = previous
+                {
+                    if member_update.avatar != avatar {
+                        flags.insert(Self::MEMBER_AVATAR_CHANGED);
+                    }
+
+                    if member_update.nick.as_deref() != nick {
+                        flags.insert(Self::MEMBER_NICKNAME_CHANGED);
+                    }
+
+                    if member_update.roles.len() != roles.len()
+                        || !member_update.roles.iter().all(|role| roles.contains(role))
+                    {
+                        flags.insert(Self::MEMBER_ROLES_CHANGED);
+                    }
+                }
+
+                flags
+            }
+            DispatchEvent::VoiceStateUpdate(voice_state_update) => {
+                match (previous, voice_state_update.0.channel_id) {
+                    (Some(PreviousState::Voice { channel_id: None }), None) => Self::empty(),
+                    (_, None) => Self::VOICE_LEFT,
+                    (
+                        Some(PreviousState::Voice {
+                            channel_id: Some(previous),
+                        }),
+                        Some(current),
+                    ) => {
+                        if previous == current {
+                            // the state changed within the same channel, such as muting
+                            Self::empty()
+                        } else {
+                            Self::VOICE_MOVED
+                        }
+                    }
+                    (_, Some(_)) => Self::VOICE_JOINED,
+                }
+            }
+            _ => {
+                let mut flags = Self::empty();
+
+                EVENT_TYPES
+                    .iter()
+                    .filter(|(_, event_types)| event_types.contains(&event.kind()))
+                    .for_each(|(flag, _)| flags.insert(*flag));
+
+                flags
+            }
+        }
     }
 }
 
+/// The state of an entity before it was changed by a gateway event.
+///
+/// This is used for telling apart the event flags sharing a gateway event.
+#[derive(Clone, Copy, Debug)]
+pub enum PreviousState<'a> {
+    /// The state of a member before a `GUILD_MEMBER_UPDATE` event.
+    Member {
+        /// The guild avatar of the member.
+        avatar: Option<ImageHash>,
+        /// The nickname of the member.
+        nick: Option<&'a str>,
+        /// The roles of the member.
+        roles: &'a [Id<RoleMarker>],
+    },
+    /// The state of a user before a `VOICE_STATE_UPDATE` event.
+    Voice {
+        /// The voice channel the user was connected to.
+        channel_id: Option<Id<ChannelMarker>>,
+    },
+}
+
 impl<'lua> FromLua<'lua> for EventFlags {
     fn from_lua(lua_value: Value<'lua>, _: &'lua Lua) -> mlua::Result<Self> {
         let Value::Table(table) = lua_value.clone() else {
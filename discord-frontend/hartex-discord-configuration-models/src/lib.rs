@@ -40,6 +40,7 @@ use serde::Serialize;
 pub mod appearance;
 pub mod dashboard;
 pub mod eventflags;
+pub mod permissions;
 pub mod plugins;
 
 #[derive(Debug, Serialize)]
@@ -48,6 +49,8 @@ pub struct Configuration {
     pub appearance: Option<appearance::Appearance>,
     /// A dashboard configuration object.
     pub dashboard: dashboard::Dashboard,
+    /// An optional permission levels configuration object.
+    pub permissions: Option<permissions::PermissionLevels>,
     /// An optional plugins configuration object.
     pub plugins: Option<plugins::Plugins>,
 }
@@ -70,11 +73,13 @@ impl<'lua> FromLuaMulti<'lua> for Configuration {
 
         let appearance = value.get("appearance")?;
         let dashboard = value.get("dashboard")?;
+        let permissions = value.get("permissions")?;
         let plugins = value.get("plugins")?;
 
         Ok(Self {
             appearance,
             dashboard,
+            permissions,
             plugins,
         })
     }
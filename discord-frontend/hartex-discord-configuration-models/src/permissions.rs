@@ -0,0 +1,93 @@
+/*
+ * SPDX-License-Identifier: AGPL-3.0-only
+ *
+ * This file is part of HarTex.
+ *
+ * HarTex
+ * Copyright (c) 2021-2024 HarTex Project Developers
+ *
+ * HarTex is free software; you can redistribute it and/or modify
+ * it under the terms of the GNU Affero General Public License as published by
+ * the Free Software Foundation; either version 3 of the License, or
+ * (at your option) any later version.
+ *
+ * HarTex is distributed in the hope that it will be useful,
+ * but WITHOUT ANY WARRANTY; without even the implied warranty of
+ * MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+ * GNU Affero General Public License for more details.
+ *
+ * You should have received a copy of the GNU Affero General Public License along
+ * with HarTex. If not, see <https://www.gnu.org/licenses/>.
+ */
+
+//! # Permission Levels Configuration Object
+
+use std::collections::BTreeMap;
+
+use mlua::Error;
+use mlua::FromLua;
+use mlua::Lua;
+use mlua::Table;
+use mlua::Value;
+use serde::Serialize;
+
+/// The highest permission level that can be configured.
+pub const MAX_PERMISSION_LEVEL: u8 = 100;
+
+/// The permission levels configuration object.
+///
+/// Members are assigned the highest level among the levels configured for their roles and for
+/// themselves, and may run a command only if their level is at least the minimum level of the
+/// command.
+#[allow(clippy::module_name_repetitions)]
+#[derive(Debug, Serialize)]
+pub struct PermissionLevels {
+    /// Overrides of the minimum permission levels of commands, by command name.
+    pub commands: Option<BTreeMap<String, u8>>,
+    /// Overrides of the minimum permission levels of every command in plugins, by plugin name.
+    pub plugins: Option<BTreeMap<String, u8>>,
+    /// The permission levels of roles, by role id.
+    pub roles: Option<BTreeMap<String, u8>>,
+    /// The permission levels of users, by user id.
+    pub users: Option<BTreeMap<String, u8>>,
+}
+
+impl<'lua> FromLua<'lua> for PermissionLevels {
+    fn from_lua(lua_value: Value<'lua>, _: &'lua Lua) -> mlua::Result<Self> {
+        let Value::Table(table) = lua_value.clone() else {
+            return Err(Error::RuntimeError(format!(
+                "PermissionLevels: mismatched value type, expected table, found: {}",
+                lua_value.type_name()
+            )));
+        };
+
+        let commands = levels(&table, "commands")?;
+        let plugins = levels(&table, "plugins")?;
+        let roles = levels(&table, "roles")?;
+        let users = levels(&table, "users")?;
+
+        Ok(Self {
+            commands,
+            plugins,
+            roles,
+            users,
+        })
+    }
+}
+
+/// Obtains a map of permission levels from a table, checking that every level is in range.
+fn levels(table: &Table, key: &str) -> mlua::Result<Option<BTreeMap<String, u8>>> {
+    let levels: Option<BTreeMap<String, u8>> = table.get(key)?;
+
+    if let Some((name, level)) = levels
+        .iter()
+        .flatten()
+        .find(|(_, level)| **level > MAX_PERMISSION_LEVEL)
+    {
+        return Err(Error::RuntimeError(format!(
+            "PermissionLevels: level {level} of {key}.{name} exceeds the maximum level of {MAX_PERMISSION_LEVEL}"
+        )));
+    }
+
+    Ok(levels)
+}
@@ -22,6 +22,8 @@
 
 //! # Modlog Logger Configuration Object
 
+use std::str::FromStr;
+
 use mlua::Error;
 use mlua::FromLua;
 use mlua::Lua;
@@ -32,7 +34,7 @@ use crate::eventflags::EventFlags;
 
 /// The modlog logger configuration object.
 #[allow(clippy::module_name_repetitions)]
-#[derive(Debug, Serialize)]
+#[derive(Clone, Debug, Serialize)]
 pub struct ModlogLogger {
     /// The channel ID associated with this logger.
     pub channel: String,
@@ -64,7 +66,7 @@ impl<'lua> FromLua<'lua> for ModlogLogger {
 }
 
 /// Configures modlog formats.
-#[derive(Debug, Serialize)]
+#[derive(Clone, Debug, Serialize)]
 pub enum ModlogFormat {
     /// Default, text-only format.
     Default,
@@ -78,6 +80,20 @@ impl Default for ModlogFormat {
     }
 }
 
+impl FromStr for ModlogFormat {
+    type Err = String;
+
+    fn from_str(string: &str) -> Result<Self, Self::Err> {
+        match string {
+            "default" => Ok(Self::Default),
+            "pretty" => Ok(Self::Pretty),
+            _ => Err(format!(
+                "ModlogFormat: unexpected variant: {string}, expected either default or pretty"
+            )),
+        }
+    }
+}
+
 impl<'lua> FromLua<'lua> for ModlogFormat {
     fn from_lua(lua_value: Value<'lua>, _: &'lua Lua) -> mlua::Result<Self> {
         let Value::String(string) = lua_value.clone() else {
@@ -93,15 +109,6 @@ impl<'lua> FromLua<'lua> for ModlogFormat {
             )));
         };
 
-        Ok(match rust_string {
-            "default" => Self::Default,
-            "pretty" => Self::Pretty,
-            _ => {
-                return Err(Error::RuntimeError(format!(
-                    "ModlogFormat: unexpected variant: {}, expected either default or pretty",
-                    lua_value.type_name()
-                )))
-            }
-        })
+        rust_string.parse().map_err(Error::RuntimeError)
     }
 }
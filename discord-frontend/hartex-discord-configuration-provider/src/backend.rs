@@ -0,0 +1,129 @@
+/*
+ * SPDX-License-Identifier: AGPL-3.0-only
+ *
+ * This file is part of HarTex.
+ *
+ * HarTex
+ * Copyright (c) 2021-2024 HarTex Project Developers
+ *
+ * HarTex is free software; you can redistribute it and/or modify
+ * it under the terms of the GNU Affero General Public License as published by
+ * the Free Software Foundation; either version 3 of the License, or
+ * (at your option) any later version.
+ *
+ * HarTex is distributed in the hope that it will be useful,
+ * but WITHOUT ANY WARRANTY; without even the implied warranty of
+ * MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+ * GNU Affero General Public License for more details.
+ *
+ * You should have received a copy of the GNU Affero General Public License along
+ * with HarTex. If not, see <https://www.gnu.org/licenses/>.
+ */
+
+//! # Configuration Backends
+//!
+//! The storage the configuration of guilds is read from and written to.
+//!
+//! The configuration is stored in the database unless another backend, such as the in-memory
+//! configuration of the [`memory`](crate::memory) module, is installed with [`install`] before the
+//! configuration is first used.
+
+use std::ptr;
+use std::sync::OnceLock;
+
+use async_trait::async_trait;
+use hartex_discord_configuration_models::appearance::Appearance;
+use hartex_discord_configuration_models::plugins::modlog::logger::ModlogLogger;
+use hartex_discord_core::discord::model::id::marker::GuildMarker;
+use hartex_discord_core::discord::model::id::marker::RoleMarker;
+use hartex_discord_core::discord::model::id::marker::UserMarker;
+use hartex_discord_core::discord::model::id::Id;
+
+use crate::database::DatabaseBackend;
+
+/// The installed configuration backend.
+static BACKEND: OnceLock<&'static dyn ConfigurationBackend> = OnceLock::new();
+
+/// A storage for the configuration of guilds.
+///
+/// See the methods of [`ConfigurationProvider`](crate::ConfigurationProvider) of the same names
+/// for their semantics.
+#[allow(clippy::missing_errors_doc)]
+#[async_trait]
+pub trait ConfigurationBackend: Send + Sync {
+    /// Reads the appearance configuration of a guild, if the guild is configured.
+    async fn appearance(&self, guild_id: Id<GuildMarker>) -> miette::Result<Option<Appearance>>;
+
+    /// Reads the highest permission level configured for a member or any of their roles.
+    async fn permission_level(
+        &self,
+        guild_id: Id<GuildMarker>,
+        user_id: Id<UserMarker>,
+        role_ids: &[Id<RoleMarker>],
+    ) -> miette::Result<u8>;
+
+    /// Reads the minimum permission level configured for a command or its plugin.
+    async fn minimum_permission_level(
+        &self,
+        guild_id: Id<GuildMarker>,
+        plugin: &str,
+        command: &str,
+    ) -> miette::Result<Option<u8>>;
+
+    /// Reads whether a plugin is enabled for a guild.
+    async fn plugin_enabled(&self, guild_id: Id<GuildMarker>, plugin: &str)
+        -> miette::Result<bool>;
+
+    /// Reads the administrators of the dashboard of a guild.
+    async fn dashboard_admins(
+        &self,
+        guild_id: Id<GuildMarker>,
+    ) -> miette::Result<Vec<Id<UserMarker>>>;
+
+    /// Reads the names of the plugins enabled for a guild, if the guild is configured.
+    async fn enabled_plugins(
+        &self,
+        guild_id: Id<GuildMarker>,
+    ) -> miette::Result<Option<Vec<String>>>;
+
+    /// Enables or disables a plugin for a guild, returning whether the configuration changed.
+    async fn set_plugin_enabled(
+        &self,
+        guild_id: Id<GuildMarker>,
+        plugin: &str,
+        enabled: bool,
+    ) -> miette::Result<bool>;
+
+    /// Reads the modlog loggers configured for a guild.
+    async fn modlog_loggers(&self, guild_id: Id<GuildMarker>) -> miette::Result<Vec<ModlogLogger>>;
+
+    /// Records a diagnostic for the configuration of a guild.
+    async fn report_diagnostic(
+        &self,
+        guild_id: Id<GuildMarker>,
+        source: &str,
+        message: &str,
+    ) -> miette::Result<()>;
+}
+
+/// Installs the backend the configuration is read from and written to.
+///
+/// This must be called before the configuration is first used; installing the same backend again
+/// has no effect.
+///
+/// # Panics
+///
+/// Panics when another backend is already in use.
+pub fn install(backend: &'static dyn ConfigurationBackend) {
+    let installed = *BACKEND.get_or_init(|| backend);
+
+    assert!(
+        ptr::addr_eq(installed, backend),
+        "another configuration backend is already in use"
+    );
+}
+
+/// Returns the installed configuration backend, installing the database if none is.
+pub(crate) fn backend() -> &'static dyn ConfigurationBackend {
+    *BACKEND.get_or_init(|| &DatabaseBackend)
+}
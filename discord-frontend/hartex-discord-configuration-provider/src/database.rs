@@ -0,0 +1,256 @@
+/*
+ * SPDX-License-Identifier: AGPL-3.0-only
+ *
+ * This file is part of HarTex.
+ *
+ * HarTex
+ * Copyright (c) 2021-2024 HarTex Project Developers
+ *
+ * HarTex is free software; you can redistribute it and/or modify
+ * it under the terms of the GNU Affero General Public License as published by
+ * the Free Software Foundation; either version 3 of the License, or
+ * (at your option) any later version.
+ *
+ * HarTex is distributed in the hope that it will be useful,
+ * but WITHOUT ANY WARRANTY; without even the implied warranty of
+ * MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+ * GNU Affero General Public License for more details.
+ *
+ * You should have received a copy of the GNU Affero General Public License along
+ * with HarTex. If not, see <https://www.gnu.org/licenses/>.
+ */
+
+//! # Database Configuration
+//!
+//! The configuration backend storing the configuration of guilds in the database.
+
+use std::pin::Pin;
+
+use async_trait::async_trait;
+use hartex_bitflags_utils::FlagsExt;
+use hartex_database_queries::configuration::queries::appearance_select_by_guild_id::appearance_select_by_guild_id;
+use hartex_database_queries::configuration::queries::configuration_diagnostic_insert::configuration_diagnostic_insert;
+use hartex_database_queries::configuration::queries::dashboard_admins_and_enabled_plugins_select_by_guild_id::dashboard_admins_and_enabled_plugins_select_by_guild_id;
+use hartex_database_queries::configuration::queries::enabled_plugins_append::enabled_plugins_append;
+use hartex_database_queries::configuration::queries::enabled_plugins_remove::enabled_plugins_remove;
+use hartex_database_queries::configuration::queries::modlog_loggers_select_by_guild_id::modlog_loggers_select_by_guild_id;
+use hartex_database_queries::configuration::queries::permission_level_override_select_by_guild_id_and_names::permission_level_override_select_by_guild_id_and_names;
+use hartex_database_queries::configuration::queries::permission_level_select_by_guild_id_and_member::permission_level_select_by_guild_id_and_member;
+use hartex_database_queries::configuration::queries::plugin_enabled::plugin_enabled;
+use hartex_discord_configuration_models::appearance::Appearance;
+use hartex_discord_configuration_models::eventflags::EventFlags;
+use hartex_discord_configuration_models::plugins::modlog::logger::ModlogLogger;
+use hartex_discord_core::discord::model::id::marker::GuildMarker;
+use hartex_discord_core::discord::model::id::marker::RoleMarker;
+use hartex_discord_core::discord::model::id::marker::UserMarker;
+use hartex_discord_core::discord::model::id::Id;
+use hartex_discord_utils::DATABASE_POOL;
+use miette::IntoDiagnostic;
+use miette::Report;
+use time::OffsetDateTime;
+use tokio_postgres::GenericClient;
+
+use crate::backend::ConfigurationBackend;
+
+/// The configuration backend storing the configuration of guilds in the database.
+pub struct DatabaseBackend;
+
+#[async_trait]
+impl ConfigurationBackend for DatabaseBackend {
+    async fn appearance(&self, guild_id: Id<GuildMarker>) -> miette::Result<Option<Appearance>> {
+        let pinned = Pin::static_ref(&DATABASE_POOL).await;
+        let pooled = pinned.get().await.into_diagnostic()?;
+        let client = pooled.client();
+
+        let appearance = appearance_select_by_guild_id()
+            .bind(client, &guild_id.to_string())
+            .opt()
+            .await
+            .into_diagnostic()?;
+
+        Ok(appearance.map(|appearance| Appearance {
+            colour: u32::try_from(appearance.appearance_colour)
+                .ok()
+                .filter(|colour| *colour != 0),
+            nickname: Some(appearance.appearance_nickname).filter(|nickname| !nickname.is_empty()),
+        }))
+    }
+
+    async fn permission_level(
+        &self,
+        guild_id: Id<GuildMarker>,
+        user_id: Id<UserMarker>,
+        role_ids: &[Id<RoleMarker>],
+    ) -> miette::Result<u8> {
+        let pinned = Pin::static_ref(&DATABASE_POOL).await;
+        let pooled = pinned.get().await.into_diagnostic()?;
+        let client = pooled.client();
+
+        let level = permission_level_select_by_guild_id_and_member()
+            .bind(
+                client,
+                &guild_id.to_string(),
+                &role_ids.iter().map(ToString::to_string).collect::<Vec<_>>(),
+                &user_id.to_string(),
+            )
+            .one()
+            .await
+            .into_diagnostic()?;
+
+        u8::try_from(level).into_diagnostic()
+    }
+
+    async fn minimum_permission_level(
+        &self,
+        guild_id: Id<GuildMarker>,
+        plugin: &str,
+        command: &str,
+    ) -> miette::Result<Option<u8>> {
+        let pinned = Pin::static_ref(&DATABASE_POOL).await;
+        let pooled = pinned.get().await.into_diagnostic()?;
+        let client = pooled.client();
+
+        let overrides = permission_level_override_select_by_guild_id_and_names()
+            .bind(client, &guild_id.to_string(), &plugin, &command)
+            .all()
+            .await
+            .into_diagnostic()?;
+
+        let level = overrides
+            .iter()
+            .find(|level_override| level_override.kind == "command")
+            .or_else(|| overrides.first())
+            .map(|level_override| u8::try_from(level_override.level))
+            .transpose()
+            .into_diagnostic()?;
+
+        Ok(level)
+    }
+
+    async fn plugin_enabled(
+        &self,
+        guild_id: Id<GuildMarker>,
+        plugin: &str,
+    ) -> miette::Result<bool> {
+        let pinned = Pin::static_ref(&DATABASE_POOL).await;
+        let pooled = pinned.get().await.into_diagnostic()?;
+        let client = pooled.client();
+
+        plugin_enabled()
+            .bind(client, &plugin, &guild_id.to_string())
+            .one()
+            .await
+            .into_diagnostic()
+    }
+
+    async fn dashboard_admins(
+        &self,
+        guild_id: Id<GuildMarker>,
+    ) -> miette::Result<Vec<Id<UserMarker>>> {
+        let pinned = Pin::static_ref(&DATABASE_POOL).await;
+        let pooled = pinned.get().await.into_diagnostic()?;
+        let client = pooled.client();
+
+        let configuration = dashboard_admins_and_enabled_plugins_select_by_guild_id()
+            .bind(client, &guild_id.to_string())
+            .opt()
+            .await
+            .into_diagnostic()?;
+
+        Ok(configuration
+            .map(|configuration| configuration.dashboard_admins)
+            .unwrap_or_default()
+            .iter()
+            .filter_map(|id| id.parse().ok())
+            .collect())
+    }
+
+    async fn enabled_plugins(
+        &self,
+        guild_id: Id<GuildMarker>,
+    ) -> miette::Result<Option<Vec<String>>> {
+        let pinned = Pin::static_ref(&DATABASE_POOL).await;
+        let pooled = pinned.get().await.into_diagnostic()?;
+        let client = pooled.client();
+
+        let configuration = dashboard_admins_and_enabled_plugins_select_by_guild_id()
+            .bind(client, &guild_id.to_string())
+            .opt()
+            .await
+            .into_diagnostic()?;
+
+        Ok(configuration.map(|configuration| configuration.enabled_plugins))
+    }
+
+    async fn set_plugin_enabled(
+        &self,
+        guild_id: Id<GuildMarker>,
+        plugin: &str,
+        enabled: bool,
+    ) -> miette::Result<bool> {
+        let pinned = Pin::static_ref(&DATABASE_POOL).await;
+        let pooled = pinned.get().await.into_diagnostic()?;
+        let client = pooled.client();
+
+        let guild_id = guild_id.to_string();
+        let updated = if enabled {
+            enabled_plugins_append()
+                .bind(client, &plugin, &guild_id)
+                .await
+        } else {
+            enabled_plugins_remove()
+                .bind(client, &plugin, &guild_id)
+                .await
+        }
+        .into_diagnostic()?;
+
+        Ok(updated > 0)
+    }
+
+    async fn modlog_loggers(&self, guild_id: Id<GuildMarker>) -> miette::Result<Vec<ModlogLogger>> {
+        let pinned = Pin::static_ref(&DATABASE_POOL).await;
+        let pooled = pinned.get().await.into_diagnostic()?;
+        let client = pooled.client();
+
+        let loggers = modlog_loggers_select_by_guild_id()
+            .bind(client, &guild_id.to_string())
+            .all()
+            .await
+            .into_diagnostic()?;
+
+        loggers
+            .into_iter()
+            .map(|logger| {
+                Ok(ModlogLogger {
+                    channel: logger.channel_id,
+                    events: EventFlags::from_names(logger.events),
+                    format: logger.format.parse().map_err(Report::msg)?,
+                })
+            })
+            .collect()
+    }
+
+    async fn report_diagnostic(
+        &self,
+        guild_id: Id<GuildMarker>,
+        source: &str,
+        message: &str,
+    ) -> miette::Result<()> {
+        let pinned = Pin::static_ref(&DATABASE_POOL).await;
+        let pooled = pinned.get().await.into_diagnostic()?;
+        let client = pooled.client();
+
+        configuration_diagnostic_insert()
+            .bind(
+                client,
+                &guild_id.to_string(),
+                &source,
+                &message,
+                &OffsetDateTime::now_utc(),
+            )
+            .await
+            .into_diagnostic()?;
+
+        Ok(())
+    }
+}
@@ -28,33 +28,362 @@
 #![deny(unsafe_code)]
 #![deny(warnings)]
 
-use std::pin::Pin;
+use std::any::Any;
+use std::any::TypeId;
+use std::collections::HashMap;
+use std::sync::Arc;
+use std::sync::RwLock;
+use std::time::Duration;
+use std::time::Instant;
 
-use hartex_database_queries::configuration::queries::plugin_enabled::plugin_enabled;
+use async_trait::async_trait;
+
+use hartex_discord_configuration_models::appearance::Appearance;
+use hartex_discord_configuration_models::plugins::management::ManagementPlugin;
+use hartex_discord_configuration_models::plugins::modlog::logger::ModlogLogger;
+use hartex_discord_configuration_models::plugins::modlog::ModlogPlugin;
+use hartex_discord_configuration_models::plugins::utilities::UtilitiesPlugin;
 use hartex_discord_core::discord::model::id::marker::GuildMarker;
+use hartex_discord_core::discord::model::id::marker::RoleMarker;
+use hartex_discord_core::discord::model::id::marker::UserMarker;
 use hartex_discord_core::discord::model::id::Id;
-use hartex_discord_utils::DATABASE_POOL;
-use miette::IntoDiagnostic;
-use tokio_postgres::GenericClient;
+use once_cell::sync::Lazy;
+
+pub mod backend;
+pub mod database;
+pub mod memory;
+
+/// The duration for which cached configuration is served before being fetched again.
+///
+/// Cached configuration is also invalidated upon configuration update notifications; this bounds
+/// the staleness of the cache should notifications be missed.
+pub const CONFIGURATION_TTL: Duration = Duration::from_secs(5 * 60);
+
+/// The cached enabled states of plugins and when they were cached, by guild and plugin name.
+static PLUGINS_ENABLED: Lazy<RwLock<HashMap<(Id<GuildMarker>, String), (Instant, bool)>>> =
+    Lazy::new(|| RwLock::new(HashMap::new()));
+
+/// The cached minimum permission levels of commands and when they were cached, by guild, plugin
+/// name and command name.
+#[allow(clippy::type_complexity)]
+static MINIMUM_PERMISSION_LEVELS: Lazy<
+    RwLock<HashMap<(Id<GuildMarker>, String, String), (Instant, Option<u8>)>>,
+> = Lazy::new(|| RwLock::new(HashMap::new()));
+
+/// The cached permission levels of members and when they were cached, by guild, member and the
+/// sorted roles of the member.
+#[allow(clippy::type_complexity)]
+static PERMISSION_LEVELS: Lazy<
+    RwLock<HashMap<(Id<GuildMarker>, Id<UserMarker>, Vec<Id<RoleMarker>>), (Instant, u8)>>,
+> = Lazy::new(|| RwLock::new(HashMap::new()));
+
+/// The cached plugin configuration sections and when they were cached, by guild and
+/// configuration type.
+#[allow(clippy::type_complexity)]
+static PLUGIN_CONFIGURATIONS: Lazy<
+    RwLock<HashMap<(Id<GuildMarker>, TypeId), (Instant, Arc<dyn Any + Send + Sync>)>>,
+> = Lazy::new(|| RwLock::new(HashMap::new()));
+
+/// A plugin configuration section that can be fetched for a guild.
+#[async_trait]
+pub trait PluginConfiguration: Sized {
+    /// The name of the plugin the configuration section belongs to.
+    const PLUGIN: &'static str;
+
+    /// Fetches the configuration section for a certain guild from the database.
+    #[allow(clippy::missing_errors_doc)]
+    async fn fetch(guild_id: Id<GuildMarker>) -> miette::Result<Self>;
+}
+
+#[async_trait]
+impl PluginConfiguration for ManagementPlugin {
+    const PLUGIN: &'static str = "management";
+
+    async fn fetch(guild_id: Id<GuildMarker>) -> miette::Result<Self> {
+        Ok(Self {
+            enabled: ConfigurationProvider::plugin_enabled(guild_id, Self::PLUGIN).await?,
+        })
+    }
+}
+
+#[async_trait]
+impl PluginConfiguration for ModlogPlugin {
+    const PLUGIN: &'static str = "modlog";
+
+    async fn fetch(guild_id: Id<GuildMarker>) -> miette::Result<Self> {
+        Ok(Self {
+            enabled: ConfigurationProvider::plugin_enabled(guild_id, Self::PLUGIN).await?,
+            loggers: ConfigurationProvider::modlog_loggers(guild_id).await?,
+        })
+    }
+}
+
+#[async_trait]
+impl PluginConfiguration for UtilitiesPlugin {
+    const PLUGIN: &'static str = "utilities";
+
+    async fn fetch(guild_id: Id<GuildMarker>) -> miette::Result<Self> {
+        Ok(Self {
+            enabled: ConfigurationProvider::plugin_enabled(guild_id, Self::PLUGIN).await?,
+        })
+    }
+}
 
 /// The configuration provide for fetching configuration.
+///
+/// The configuration is read from and written to the installed
+/// [`ConfigurationBackend`](backend::ConfigurationBackend).
 pub struct ConfigurationProvider;
 
 impl ConfigurationProvider {
+    /// Queries the appearance configuration of a certain guild, if the guild is configured.
+    ///
+    /// An empty nickname and a zero colour are treated as unset.
+    #[allow(clippy::missing_errors_doc)]
+    pub async fn appearance(guild_id: Id<GuildMarker>) -> miette::Result<Option<Appearance>> {
+        backend::backend().appearance(guild_id).await
+    }
+
+    /// Queries the permission level of a member of a certain guild.
+    ///
+    /// This is the highest level among the levels configured for the roles of the member and for
+    /// the member itself, or zero if none are configured.
+    #[allow(clippy::missing_errors_doc)]
+    pub async fn permission_level(
+        guild_id: Id<GuildMarker>,
+        user_id: Id<UserMarker>,
+        role_ids: &[Id<RoleMarker>],
+    ) -> miette::Result<u8> {
+        backend::backend()
+            .permission_level(guild_id, user_id, role_ids)
+            .await
+    }
+
+    /// Queries the minimum permission level configured for a command of a certain guild.
+    ///
+    /// A level configured for the command takes precedence over a level configured for the plugin
+    /// the command belongs to. Returns `None` if neither is configured.
+    #[allow(clippy::missing_errors_doc)]
+    pub async fn minimum_permission_level(
+        guild_id: Id<GuildMarker>,
+        plugin: impl Into<String>,
+        command: impl Into<String>,
+    ) -> miette::Result<Option<u8>> {
+        backend::backend()
+            .minimum_permission_level(guild_id, &plugin.into(), &command.into())
+            .await
+    }
+
+    /// Returns the permission level of a member of a certain guild.
+    ///
+    /// The permission level is cached until the configuration of the guild is invalidated, or for
+    /// at most [`CONFIGURATION_TTL`]. As the cache grows with every member invoking commands,
+    /// expired permission levels are dropped whenever another one is cached.
+    #[allow(clippy::missing_errors_doc)]
+    #[allow(clippy::missing_panics_doc)]
+    pub async fn permission_level_cached(
+        guild_id: Id<GuildMarker>,
+        user_id: Id<UserMarker>,
+        role_ids: &[Id<RoleMarker>],
+    ) -> miette::Result<u8> {
+        let mut role_ids = role_ids.to_vec();
+        role_ids.sort_unstable();
+
+        let key = (guild_id, user_id, role_ids);
+        if let Some((cached_at, level)) = PERMISSION_LEVELS.read().unwrap().get(&key) {
+            if cached_at.elapsed() < CONFIGURATION_TTL {
+                return Ok(*level);
+            }
+        }
+
+        let level = Self::permission_level(guild_id, user_id, &key.2).await?;
+        let mut levels = PERMISSION_LEVELS.write().unwrap();
+        levels.retain(|_, (cached_at, _)| cached_at.elapsed() < CONFIGURATION_TTL);
+        levels.insert(key, (Instant::now(), level));
+
+        Ok(level)
+    }
+
+    /// Returns the minimum permission level configured for a command of a certain guild.
+    ///
+    /// The minimum permission level is cached until the configuration of the guild is
+    /// invalidated, or for at most [`CONFIGURATION_TTL`].
+    #[allow(clippy::missing_errors_doc)]
+    #[allow(clippy::missing_panics_doc)]
+    pub async fn minimum_permission_level_cached(
+        guild_id: Id<GuildMarker>,
+        plugin: impl Into<String>,
+        command: impl Into<String>,
+    ) -> miette::Result<Option<u8>> {
+        let key = (guild_id, plugin.into(), command.into());
+        if let Some((cached_at, level)) = MINIMUM_PERMISSION_LEVELS.read().unwrap().get(&key) {
+            if cached_at.elapsed() < CONFIGURATION_TTL {
+                return Ok(*level);
+            }
+        }
+
+        let level = Self::minimum_permission_level(guild_id, key.1.clone(), key.2.clone()).await?;
+        MINIMUM_PERMISSION_LEVELS
+            .write()
+            .unwrap()
+            .insert(key, (Instant::now(), level));
+
+        Ok(level)
+    }
+
+    /// Returns the configuration section of a plugin for a certain guild.
+    ///
+    /// Configuration sections are cached until the configuration of the guild is invalidated, or
+    /// for at most [`CONFIGURATION_TTL`].
+    #[allow(clippy::missing_errors_doc)]
+    #[allow(clippy::missing_panics_doc)]
+    pub async fn plugin_configuration<T>(guild_id: Id<GuildMarker>) -> miette::Result<Arc<T>>
+    where
+        T: PluginConfiguration + Send + Sync + 'static,
+    {
+        let key = (guild_id, TypeId::of::<T>());
+        let cached = PLUGIN_CONFIGURATIONS
+            .read()
+            .unwrap()
+            .get(&key)
+            .filter(|(cached_at, _)| cached_at.elapsed() < CONFIGURATION_TTL)
+            .map(|(_, cached)| cached.clone());
+        if let Some(configuration) = cached.and_then(|cached| cached.downcast::<T>().ok()) {
+            return Ok(configuration);
+        }
+
+        let configuration = Arc::new(T::fetch(guild_id).await?);
+        PLUGIN_CONFIGURATIONS
+            .write()
+            .unwrap()
+            .insert(key, (Instant::now(), configuration.clone()));
+
+        Ok(configuration)
+    }
+
+    /// Returns whether a specific plugin is enabled for a certain guild.
+    ///
+    /// The enabled state is cached until the configuration of the guild is invalidated, or for
+    /// at most [`CONFIGURATION_TTL`].
+    #[allow(clippy::missing_errors_doc)]
+    #[allow(clippy::missing_panics_doc)]
+    pub async fn plugin_enabled_cached(
+        guild_id: Id<GuildMarker>,
+        plugin: impl Into<String>,
+    ) -> miette::Result<bool> {
+        let key = (guild_id, plugin.into());
+        if let Some((cached_at, enabled)) = PLUGINS_ENABLED.read().unwrap().get(&key) {
+            if cached_at.elapsed() < CONFIGURATION_TTL {
+                return Ok(*enabled);
+            }
+        }
+
+        let enabled = Self::plugin_enabled(guild_id, key.1.clone()).await?;
+        PLUGINS_ENABLED
+            .write()
+            .unwrap()
+            .insert(key, (Instant::now(), enabled));
+
+        Ok(enabled)
+    }
+
+    /// Invalidates the cached configuration of a certain guild, such that it is fetched from the
+    /// database again when next needed.
+    #[allow(clippy::missing_panics_doc)]
+    pub fn invalidate(guild_id: Id<GuildMarker>) {
+        MINIMUM_PERMISSION_LEVELS
+            .write()
+            .unwrap()
+            .retain(|(id, _, _), _| *id != guild_id);
+        PERMISSION_LEVELS
+            .write()
+            .unwrap()
+            .retain(|(id, _, _), _| *id != guild_id);
+        PLUGINS_ENABLED
+            .write()
+            .unwrap()
+            .retain(|(id, _), _| *id != guild_id);
+        PLUGIN_CONFIGURATIONS
+            .write()
+            .unwrap()
+            .retain(|(id, _), _| *id != guild_id);
+    }
+
+    /// Invalidates the cached configuration of every guild.
+    ///
+    /// This is used when configuration update notifications may have been missed, such as after
+    /// the connection receiving them was lost.
+    #[allow(clippy::missing_panics_doc)]
+    pub fn invalidate_all() {
+        MINIMUM_PERMISSION_LEVELS.write().unwrap().clear();
+        PERMISSION_LEVELS.write().unwrap().clear();
+        PLUGINS_ENABLED.write().unwrap().clear();
+        PLUGIN_CONFIGURATIONS.write().unwrap().clear();
+    }
+
     /// Queries whether a specific plugin is enabled for a certain guild.
     #[allow(clippy::missing_errors_doc)]
     pub async fn plugin_enabled(
         guild_id: Id<GuildMarker>,
         plugin: impl Into<String>,
     ) -> miette::Result<bool> {
-        let pinned = Pin::static_ref(&DATABASE_POOL).await;
-        let pooled = pinned.get().await.into_diagnostic()?;
-        let client = pooled.client();
+        backend::backend()
+            .plugin_enabled(guild_id, &plugin.into())
+            .await
+    }
+
+    /// Queries the users that are administrators of the dashboard of a certain guild.
+    ///
+    /// Returns no users if the guild is not configured.
+    #[allow(clippy::missing_errors_doc)]
+    pub async fn dashboard_admins(
+        guild_id: Id<GuildMarker>,
+    ) -> miette::Result<Vec<Id<UserMarker>>> {
+        backend::backend().dashboard_admins(guild_id).await
+    }
+
+    /// Queries the names of the plugins enabled for a certain guild, or `None` if the guild is not
+    /// configured.
+    #[allow(clippy::missing_errors_doc)]
+    pub async fn enabled_plugins(guild_id: Id<GuildMarker>) -> miette::Result<Option<Vec<String>>> {
+        backend::backend().enabled_plugins(guild_id).await
+    }
+
+    /// Enables or disables a specific plugin for a certain guild, returning whether the stored
+    /// configuration was changed.
+    ///
+    /// The cached configuration is not invalidated here: updating the configuration notifies every
+    /// worker through the `guild_configuration_updated` notification channel, upon which the cached
+    /// configuration is invalidated and the plugin lifecycle hooks are dispatched.
+    #[allow(clippy::missing_errors_doc)]
+    pub async fn set_plugin_enabled(
+        guild_id: Id<GuildMarker>,
+        plugin: impl Into<String>,
+        enabled: bool,
+    ) -> miette::Result<bool> {
+        backend::backend()
+            .set_plugin_enabled(guild_id, &plugin.into(), enabled)
+            .await
+    }
 
-        plugin_enabled()
-            .bind(client, &plugin.into(), &guild_id.to_string())
-            .one()
+    /// Queries the modlog loggers configured for a certain guild.
+    #[allow(clippy::missing_errors_doc)]
+    pub async fn modlog_loggers(guild_id: Id<GuildMarker>) -> miette::Result<Vec<ModlogLogger>> {
+        backend::backend().modlog_loggers(guild_id).await
+    }
+
+    /// Reports a diagnostic for the configuration of a certain guild.
+    ///
+    /// Diagnostics describe problems encountered when applying the configuration, such as missing
+    /// permissions, and are surfaced to the users configuring the guild.
+    #[allow(clippy::missing_errors_doc)]
+    pub async fn report_diagnostic(
+        guild_id: Id<GuildMarker>,
+        source: impl Into<String>,
+        message: impl Into<String>,
+    ) -> miette::Result<()> {
+        backend::backend()
+            .report_diagnostic(guild_id, &source.into(), &message.into())
             .await
-            .into_diagnostic()
     }
 }
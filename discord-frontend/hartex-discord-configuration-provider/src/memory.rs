@@ -0,0 +1,251 @@
+/*
+ * SPDX-License-Identifier: AGPL-3.0-only
+ *
+ * This file is part of HarTex.
+ *
+ * HarTex
+ * Copyright (c) 2021-2024 HarTex Project Developers
+ *
+ * HarTex is free software; you can redistribute it and/or modify
+ * it under the terms of the GNU Affero General Public License as published by
+ * the Free Software Foundation; either version 3 of the License, or
+ * (at your option) any later version.
+ *
+ * HarTex is distributed in the hope that it will be useful,
+ * but WITHOUT ANY WARRANTY; without even the implied warranty of
+ * MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+ * GNU Affero General Public License for more details.
+ *
+ * You should have received a copy of the GNU Affero General Public License along
+ * with HarTex. If not, see <https://www.gnu.org/licenses/>.
+ */
+
+//! # In-Memory Configuration
+//!
+//! An in-memory configuration backend, such that code reading and writing the configuration of
+//! guilds can be tested without a database.
+//!
+//! Installing the in-memory configuration with [`install`] is meant for test harnesses only, and
+//! must happen before the configuration is first used.
+
+use std::collections::HashMap;
+use std::sync::Mutex;
+use std::sync::OnceLock;
+
+use async_trait::async_trait;
+use hartex_discord_configuration_models::appearance::Appearance;
+use hartex_discord_configuration_models::plugins::modlog::logger::ModlogLogger;
+use hartex_discord_core::discord::model::id::marker::GuildMarker;
+use hartex_discord_core::discord::model::id::marker::RoleMarker;
+use hartex_discord_core::discord::model::id::marker::UserMarker;
+use hartex_discord_core::discord::model::id::Id;
+
+use crate::backend;
+use crate::backend::ConfigurationBackend;
+
+/// The in-memory configuration, once installed.
+static INSTALLED: OnceLock<MemoryConfiguration> = OnceLock::new();
+
+/// The configuration of a guild held in memory.
+#[derive(Clone, Default)]
+pub struct GuildConfiguration {
+    /// The appearance configuration of the guild.
+    pub appearance: Option<Appearance>,
+    /// The administrators of the dashboard of the guild.
+    pub dashboard_admins: Vec<Id<UserMarker>>,
+    /// The diagnostics reported for the configuration of the guild, as pairs of their sources and
+    /// messages.
+    pub diagnostics: Vec<(String, String)>,
+    /// The names of the plugins enabled for the guild.
+    pub enabled_plugins: Vec<String>,
+    /// The modlog loggers configured for the guild.
+    pub modlog_loggers: Vec<ModlogLogger>,
+    /// The minimum permission levels configured for commands, by command name.
+    pub command_levels: HashMap<String, u8>,
+    /// The minimum permission levels configured for plugins, by plugin name.
+    pub plugin_levels: HashMap<String, u8>,
+    /// The permission levels configured for roles.
+    pub role_levels: HashMap<Id<RoleMarker>, u8>,
+    /// The permission levels configured for users.
+    pub user_levels: HashMap<Id<UserMarker>, u8>,
+}
+
+/// The configuration of guilds held in memory.
+///
+/// Guilds without a configuration behave as guilds that are not configured.
+#[derive(Default)]
+pub struct MemoryConfiguration {
+    /// The configuration of guilds.
+    guilds: Mutex<HashMap<Id<GuildMarker>, GuildConfiguration>>,
+}
+
+impl MemoryConfiguration {
+    /// Returns the configuration of a guild, if the guild is configured.
+    ///
+    /// # Panics
+    ///
+    /// Panics when the configuration lock is poisoned.
+    #[must_use]
+    pub fn guild(&self, guild_id: Id<GuildMarker>) -> Option<GuildConfiguration> {
+        self.guilds.lock().unwrap().get(&guild_id).cloned()
+    }
+
+    /// Updates the configuration of a guild, configuring the guild if it is not.
+    ///
+    /// # Panics
+    ///
+    /// Panics when the configuration lock is poisoned.
+    pub fn update(&self, guild_id: Id<GuildMarker>, update: impl FnOnce(&mut GuildConfiguration)) {
+        update(self.guilds.lock().unwrap().entry(guild_id).or_default());
+    }
+
+    /// Removes the configuration of a guild.
+    ///
+    /// # Panics
+    ///
+    /// Panics when the configuration lock is poisoned.
+    pub fn remove(&self, guild_id: Id<GuildMarker>) {
+        self.guilds.lock().unwrap().remove(&guild_id);
+    }
+
+    /// Sets the administrators of the dashboard of a guild.
+    pub fn set_dashboard_admins(&self, guild_id: Id<GuildMarker>, admins: Vec<Id<UserMarker>>) {
+        self.update(guild_id, |guild| guild.dashboard_admins = admins);
+    }
+
+    /// Reads a value from the configuration of a guild, or returns the default value if the
+    /// guild is not configured.
+    fn read<T: Default>(
+        &self,
+        guild_id: Id<GuildMarker>,
+        read: impl FnOnce(&GuildConfiguration) -> T,
+    ) -> T {
+        self.guilds
+            .lock()
+            .unwrap()
+            .get(&guild_id)
+            .map(read)
+            .unwrap_or_default()
+    }
+}
+
+#[async_trait]
+impl ConfigurationBackend for MemoryConfiguration {
+    async fn appearance(&self, guild_id: Id<GuildMarker>) -> miette::Result<Option<Appearance>> {
+        Ok(self.read(guild_id, |guild| guild.appearance.clone()))
+    }
+
+    async fn permission_level(
+        &self,
+        guild_id: Id<GuildMarker>,
+        user_id: Id<UserMarker>,
+        role_ids: &[Id<RoleMarker>],
+    ) -> miette::Result<u8> {
+        Ok(self.read(guild_id, |guild| {
+            role_ids
+                .iter()
+                .filter_map(|role_id| guild.role_levels.get(role_id))
+                .chain(guild.user_levels.get(&user_id))
+                .copied()
+                .max()
+                .unwrap_or_default()
+        }))
+    }
+
+    async fn minimum_permission_level(
+        &self,
+        guild_id: Id<GuildMarker>,
+        plugin: &str,
+        command: &str,
+    ) -> miette::Result<Option<u8>> {
+        Ok(self.read(guild_id, |guild| {
+            guild
+                .command_levels
+                .get(command)
+                .or_else(|| guild.plugin_levels.get(plugin))
+                .copied()
+        }))
+    }
+
+    async fn plugin_enabled(
+        &self,
+        guild_id: Id<GuildMarker>,
+        plugin: &str,
+    ) -> miette::Result<bool> {
+        Ok(self.read(guild_id, |guild| {
+            guild
+                .enabled_plugins
+                .iter()
+                .any(|enabled| enabled == plugin)
+        }))
+    }
+
+    async fn dashboard_admins(
+        &self,
+        guild_id: Id<GuildMarker>,
+    ) -> miette::Result<Vec<Id<UserMarker>>> {
+        Ok(self.read(guild_id, |guild| guild.dashboard_admins.clone()))
+    }
+
+    async fn enabled_plugins(
+        &self,
+        guild_id: Id<GuildMarker>,
+    ) -> miette::Result<Option<Vec<String>>> {
+        Ok(self.read(guild_id, |guild| Some(guild.enabled_plugins.clone())))
+    }
+
+    async fn set_plugin_enabled(
+        &self,
+        guild_id: Id<GuildMarker>,
+        plugin: &str,
+        enabled: bool,
+    ) -> miette::Result<bool> {
+        let mut guilds = self.guilds.lock().unwrap();
+        let Some(guild) = guilds.get_mut(&guild_id) else {
+            return Ok(false);
+        };
+
+        let was_enabled = guild.enabled_plugins.iter().any(|name| name == plugin);
+        if enabled && !was_enabled {
+            guild.enabled_plugins.push(plugin.to_string());
+        } else if !enabled && was_enabled {
+            guild.enabled_plugins.retain(|name| name != plugin);
+        }
+
+        Ok(enabled != was_enabled)
+    }
+
+    async fn modlog_loggers(&self, guild_id: Id<GuildMarker>) -> miette::Result<Vec<ModlogLogger>> {
+        Ok(self.read(guild_id, |guild| guild.modlog_loggers.clone()))
+    }
+
+    async fn report_diagnostic(
+        &self,
+        guild_id: Id<GuildMarker>,
+        source: &str,
+        message: &str,
+    ) -> miette::Result<()> {
+        self.update(guild_id, |guild| {
+            guild
+                .diagnostics
+                .push((source.to_string(), message.to_string()));
+        });
+
+        Ok(())
+    }
+}
+
+/// Installs the in-memory configuration as the configuration backend, returning it.
+///
+/// The in-memory configuration stays installed for the rest of the process; installing it again
+/// returns the configuration installed first.
+///
+/// # Panics
+///
+/// Panics when another configuration backend is already in use.
+pub fn install() -> &'static MemoryConfiguration {
+    let memory = INSTALLED.get_or_init(MemoryConfiguration::default);
+    backend::install(memory);
+
+    memory
+}
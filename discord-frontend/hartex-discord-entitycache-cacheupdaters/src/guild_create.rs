@@ -27,13 +27,19 @@
 use hartex_discord_core::discord::model::gateway::payload::incoming::GuildCreate;
 use hartex_discord_entitycache_core::error::CacheResult;
 use hartex_discord_entitycache_core::traits::Repository;
+use hartex_discord_entitycache_entities::channel::ChannelEntity;
 use hartex_discord_entitycache_entities::emoji::EmojiEntity;
 use hartex_discord_entitycache_entities::guild::GuildEntity;
 use hartex_discord_entitycache_entities::role::RoleEntity;
+use hartex_discord_entitycache_entities::voice_state::VoiceStateEntity;
+use hartex_discord_entitycache_repositories::channel::CachedChannelRepository;
 use hartex_discord_entitycache_repositories::emoji::CachedEmojiRepository;
 use hartex_discord_entitycache_repositories::guild::CachedGuildRepository;
 use hartex_discord_entitycache_repositories::role::CachedRoleRepository;
+use hartex_discord_entitycache_repositories::thread::CachedThreadRepository;
+use hartex_discord_entitycache_repositories::voice_state::CachedVoiceStateRepository;
 
+use crate::thread_create::upsert_thread;
 use crate::CacheUpdater;
 
 impl CacheUpdater for GuildCreate {
@@ -54,6 +60,61 @@ impl CacheUpdater for GuildCreate {
                 .await?;
         }
 
-        Ok(())
+        for channel in &self.0.channels {
+            CachedChannelRepository
+                .upsert(ChannelEntity::from((self.0.id, channel.clone())))
+                .await?;
+        }
+
+        for thread in &self.0.threads {
+            upsert_thread(self.0.id, thread).await?;
+        }
+
+        for voice_state in
+            (self.0.voice_states.iter()).filter(|voice_state| voice_state.channel_id.is_some())
+        {
+            CachedVoiceStateRepository
+                .upsert(VoiceStateEntity::from((self.0.id, voice_state.clone())))
+                .await?;
+        }
+
+        // the event carries every role, emoji, channel, active thread and voice state of the
+        // guild, hence any other role, emoji, channel or thread has been deleted (or archived) and
+        // any other user has disconnected from voice while the guild was unavailable
+        let ids = (self.0.roles.iter())
+            .map(|role| role.id)
+            .collect::<Vec<_>>();
+        CachedRoleRepository
+            .retain_in_guild(self.0.id, &ids)
+            .await?;
+
+        let ids = (self.0.emojis.iter())
+            .map(|emoji| emoji.id)
+            .collect::<Vec<_>>();
+        CachedEmojiRepository
+            .retain_in_guild(self.0.id, &ids)
+            .await?;
+
+        let ids = (self.0.channels.iter())
+            .map(|channel| channel.id)
+            .collect::<Vec<_>>();
+        CachedChannelRepository
+            .retain_in_guild(self.0.id, &ids)
+            .await?;
+
+        let ids = (self.0.threads.iter())
+            .map(|thread| thread.id)
+            .collect::<Vec<_>>();
+        CachedThreadRepository
+            .retain_in_guild(self.0.id, &ids)
+            .await?;
+
+        let user_ids = (self.0.voice_states.iter())
+            .filter(|voice_state| voice_state.channel_id.is_some())
+            .map(|voice_state| voice_state.user_id)
+            .collect::<Vec<_>>();
+        CachedVoiceStateRepository
+            .retain_in_guild(self.0.id, &user_ids)
+            .await
     }
 }
@@ -0,0 +1,66 @@
+/*
+ * SPDX-License-Identifier: AGPL-3.0-only
+ *
+ * This file is part of HarTex.
+ *
+ * HarTex
+ * Copyright (c) 2021-2024 HarTex Project Developers
+ *
+ * HarTex is free software; you can redistribute it and/or modify
+ * it under the terms of the GNU Affero General Public License as published by
+ * the Free Software Foundation; either version 3 of the License, or
+ * (at your option) any later version.
+ *
+ * HarTex is distributed in the hope that it will be useful,
+ * but WITHOUT ANY WARRANTY; without even the implied warranty of
+ * MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+ * GNU Affero General Public License for more details.
+ *
+ * You should have received a copy of the GNU Affero General Public License along
+ * with HarTex. If not, see <https://www.gnu.org/licenses/>.
+ */
+
+//! # Guild Delete Cache Updater
+//!
+//! An implementation of a cache updater for the guild delete event.
+
+use hartex_discord_core::discord::model::gateway::payload::incoming::GuildDelete;
+use hartex_discord_core::discord::model::id::marker::GuildMarker;
+use hartex_discord_core::discord::model::id::Id;
+use hartex_discord_entitycache_core::error::CacheResult;
+use hartex_discord_entitycache_core::traits::Repository;
+use hartex_discord_entitycache_repositories::channel::CachedChannelRepository;
+use hartex_discord_entitycache_repositories::emoji::CachedEmojiRepository;
+use hartex_discord_entitycache_repositories::guild::CachedGuildRepository;
+use hartex_discord_entitycache_repositories::member::CachedMemberRepository;
+use hartex_discord_entitycache_repositories::role::CachedRoleRepository;
+use hartex_discord_entitycache_repositories::thread::CachedThreadRepository;
+use hartex_discord_entitycache_repositories::voice_state::CachedVoiceStateRepository;
+
+use crate::CacheUpdater;
+
+impl CacheUpdater for GuildDelete {
+    async fn update(&self) -> CacheResult<()> {
+        // unavailable guilds are merely inaccessible during an outage; they are kept cached
+        // until they become available again and are refreshed by their guild create event
+        if self.unavailable {
+            return Ok(());
+        }
+
+        delete_guild(self.id).await
+    }
+}
+
+/// Deletes a guild along with its channels, emojis, members, roles, threads and voice states.
+pub(crate) async fn delete_guild(guild_id: Id<GuildMarker>) -> CacheResult<()> {
+    CachedChannelRepository.delete_by_guild_id(guild_id).await?;
+    CachedEmojiRepository.delete_by_guild_id(guild_id).await?;
+    CachedMemberRepository.delete_by_guild_id(guild_id).await?;
+    CachedRoleRepository.delete_by_guild_id(guild_id).await?;
+    CachedThreadRepository.delete_by_guild_id(guild_id).await?;
+    CachedVoiceStateRepository
+        .delete_by_guild_id(guild_id)
+        .await?;
+
+    CachedGuildRepository.delete(guild_id).await
+}
@@ -0,0 +1,65 @@
+/*
+ * SPDX-License-Identifier: AGPL-3.0-only
+ *
+ * This file is part of HarTex.
+ *
+ * HarTex
+ * Copyright (c) 2021-2024 HarTex Project Developers
+ *
+ * HarTex is free software; you can redistribute it and/or modify
+ * it under the terms of the GNU Affero General Public License as published by
+ * the Free Software Foundation; either version 3 of the License, or
+ * (at your option) any later version.
+ *
+ * HarTex is distributed in the hope that it will be useful,
+ * but WITHOUT ANY WARRANTY; without even the implied warranty of
+ * MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+ * GNU Affero General Public License for more details.
+ *
+ * You should have received a copy of the GNU Affero General Public License along
+ * with HarTex. If not, see <https://www.gnu.org/licenses/>.
+ */
+
+//! # Guild Update Cache Updater
+//!
+//! An implementation of a cache updater for the guild update event.
+
+use hartex_discord_core::discord::model::gateway::payload::incoming::GuildUpdate;
+use hartex_discord_entitycache_core::error::CacheResult;
+use hartex_discord_entitycache_core::traits::Repository;
+use hartex_discord_entitycache_entities::role::RoleEntity;
+use hartex_discord_entitycache_repositories::guild::CachedGuildRepository;
+use hartex_discord_entitycache_repositories::role::CachedRoleRepository;
+
+use crate::CacheUpdater;
+
+impl CacheUpdater for GuildUpdate {
+    async fn update(&self) -> CacheResult<()> {
+        for role in &self.0.roles {
+            CachedRoleRepository
+                .upsert(RoleEntity::from((self.0.id, role.clone())))
+                .await?;
+        }
+
+        // guild updates are partial; guilds that are not already cached cannot be
+        // reconstructed from them and are therefore left uncached
+        let mut entity = match CachedGuildRepository.get(self.0.id).await {
+            Ok(entity) => entity,
+            Err(error) if error.is_not_found() => return Ok(()),
+            Err(error) => return Err(error),
+        };
+
+        entity.default_message_notifications = self.0.default_message_notifications;
+        entity.explicit_content_filter = self.0.explicit_content_filter;
+        entity.features.clone_from(&self.0.features);
+        entity.icon = self.0.icon;
+        entity.mfa_level = self.0.mfa_level;
+        entity.name.clone_from(&self.0.name);
+        entity.owner_id = self.0.owner_id;
+        entity.premium_subscription_count = self.0.premium_subscription_count;
+        entity.premium_tier = self.0.premium_tier;
+        entity.verification_level = self.0.verification_level;
+
+        CachedGuildRepository.upsert(entity).await
+    }
+}
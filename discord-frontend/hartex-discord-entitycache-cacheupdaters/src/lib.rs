@@ -31,8 +31,31 @@
 
 use hartex_discord_entitycache_core::error::CacheResult;
 
+pub mod channel_create;
+pub mod channel_delete;
+pub mod channel_update;
 pub mod guild_create;
+pub mod guild_delete;
+pub mod guild_emojis_update;
+pub mod guild_member_add;
 pub mod guild_member_chunk;
+pub mod guild_member_remove;
+pub mod guild_member_update;
+pub mod guild_role_create;
+pub mod guild_role_delete;
+pub mod guild_role_update;
+pub mod guild_update;
+pub mod message_create;
+pub mod message_delete;
+pub mod message_delete_bulk;
+pub mod message_update;
+pub mod ready;
+pub mod thread_create;
+pub mod thread_delete;
+pub mod thread_list_sync;
+pub mod thread_update;
+pub mod user_update;
+pub mod voice_state_update;
 
 /// A trait for all cache updaters to implement.
 pub trait CacheUpdater {
@@ -0,0 +1,65 @@
+/*
+ * SPDX-License-Identifier: AGPL-3.0-only
+ *
+ * This file is part of HarTex.
+ *
+ * HarTex
+ * Copyright (c) 2021-2024 HarTex Project Developers
+ *
+ * HarTex is free software; you can redistribute it and/or modify
+ * it under the terms of the GNU Affero General Public License as published by
+ * the Free Software Foundation; either version 3 of the License, or
+ * (at your option) any later version.
+ *
+ * HarTex is distributed in the hope that it will be useful,
+ * but WITHOUT ANY WARRANTY; without even the implied warranty of
+ * MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+ * GNU Affero General Public License for more details.
+ *
+ * You should have received a copy of the GNU Affero General Public License along
+ * with HarTex. If not, see <https://www.gnu.org/licenses/>.
+ */
+
+//! # Message Create Cache Updater
+//!
+//! An implementation of a cache updater for the message create event.
+
+use hartex_discord_core::discord::model::channel::message::Embed;
+use hartex_discord_core::discord::model::channel::Attachment;
+use hartex_discord_core::discord::model::gateway::payload::incoming::MessageCreate;
+use hartex_discord_entitycache_core::error::CacheResult;
+use hartex_discord_entitycache_core::traits::Repository;
+use hartex_discord_entitycache_entities::message::MessageEntity;
+use hartex_discord_entitycache_repositories::message::CachedMessageRepository;
+
+use crate::CacheUpdater;
+
+impl CacheUpdater for MessageCreate {
+    async fn update(&self) -> CacheResult<()> {
+        let entity = MessageEntity::from((
+            self.0.author.id,
+            attachments_summary(&self.0.attachments),
+            embeds_summary(&self.0.embeds),
+            false,
+            self.0.clone(),
+        ));
+
+        CachedMessageRepository.upsert(entity).await
+    }
+}
+
+/// Summarizes the attachments of a message as their filenames.
+pub(crate) fn attachments_summary(attachments: &[Attachment]) -> Vec<String> {
+    attachments
+        .iter()
+        .map(|attachment| attachment.filename.clone())
+        .collect()
+}
+
+/// Summarizes the embeds of a message as their titles, or their kinds if they have no title.
+pub(crate) fn embeds_summary(embeds: &[Embed]) -> Vec<String> {
+    embeds
+        .iter()
+        .map(|embed| embed.title.clone().unwrap_or_else(|| embed.kind.clone()))
+        .collect()
+}
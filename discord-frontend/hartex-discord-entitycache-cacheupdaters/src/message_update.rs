@@ -0,0 +1,64 @@
+/*
+ * SPDX-License-Identifier: AGPL-3.0-only
+ *
+ * This file is part of HarTex.
+ *
+ * HarTex
+ * Copyright (c) 2021-2024 HarTex Project Developers
+ *
+ * HarTex is free software; you can redistribute it and/or modify
+ * it under the terms of the GNU Affero General Public License as published by
+ * the Free Software Foundation; either version 3 of the License, or
+ * (at your option) any later version.
+ *
+ * HarTex is distributed in the hope that it will be useful,
+ * but WITHOUT ANY WARRANTY; without even the implied warranty of
+ * MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+ * GNU Affero General Public License for more details.
+ *
+ * You should have received a copy of the GNU Affero General Public License along
+ * with HarTex. If not, see <https://www.gnu.org/licenses/>.
+ */
+
+//! # Message Update Cache Updater
+//!
+//! An implementation of a cache updater for the message update event.
+
+use hartex_discord_core::discord::model::gateway::payload::incoming::MessageUpdate;
+use hartex_discord_entitycache_core::error::CacheResult;
+use hartex_discord_entitycache_core::traits::Repository;
+use hartex_discord_entitycache_repositories::message::CachedMessageRepository;
+
+use crate::message_create::attachments_summary;
+use crate::message_create::embeds_summary;
+use crate::CacheUpdater;
+
+impl CacheUpdater for MessageUpdate {
+    async fn update(&self) -> CacheResult<()> {
+        // message updates are partial; messages that are not already cached cannot be
+        // reconstructed from them and are therefore left uncached
+        let mut entity = match CachedMessageRepository.get(self.id).await {
+            Ok(entity) => entity,
+            Err(error) if error.is_not_found() => return Ok(()),
+            Err(error) => return Err(error),
+        };
+
+        if let Some(content) = &self.content {
+            entity.content.clone_from(content);
+        }
+
+        if let Some(attachments) = &self.attachments {
+            entity.attachments = attachments_summary(attachments);
+        }
+
+        if let Some(embeds) = &self.embeds {
+            entity.embeds = embeds_summary(embeds);
+        }
+
+        if self.edited_timestamp.is_some() {
+            entity.edited_timestamp = self.edited_timestamp;
+        }
+
+        CachedMessageRepository.upsert(entity).await
+    }
+}
@@ -0,0 +1,59 @@
+/*
+ * SPDX-License-Identifier: AGPL-3.0-only
+ *
+ * This file is part of HarTex.
+ *
+ * HarTex
+ * Copyright (c) 2021-2024 HarTex Project Developers
+ *
+ * HarTex is free software; you can redistribute it and/or modify
+ * it under the terms of the GNU Affero General Public License as published by
+ * the Free Software Foundation; either version 3 of the License, or
+ * (at your option) any later version.
+ *
+ * HarTex is distributed in the hope that it will be useful,
+ * but WITHOUT ANY WARRANTY; without even the implied warranty of
+ * MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+ * GNU Affero General Public License for more details.
+ *
+ * You should have received a copy of the GNU Affero General Public License along
+ * with HarTex. If not, see <https://www.gnu.org/licenses/>.
+ */
+
+//! # Ready Cache Updater
+//!
+//! An implementation of a cache updater for the ready event.
+
+use std::collections::HashSet;
+
+use hartex_discord_core::discord::model::gateway::payload::incoming::Ready;
+use hartex_discord_entitycache_core::error::CacheResult;
+use hartex_discord_entitycache_repositories::guild::CachedGuildRepository;
+
+use crate::guild_delete::delete_guild;
+use crate::CacheUpdater;
+
+impl CacheUpdater for Ready {
+    async fn update(&self) -> CacheResult<()> {
+        // every guild of the shard is listed as unavailable; cached guilds of the shard that are
+        // not listed have been left while the shard was disconnected
+        let listed = self
+            .guilds
+            .iter()
+            .map(|guild| guild.id)
+            .collect::<HashSet<_>>();
+        let (number, total) = self.shard.map_or((0, 1), |shard| {
+            (u64::from(shard.number()), u64::from(shard.total()))
+        });
+
+        for guild_id in CachedGuildRepository.guild_ids().await? {
+            if (guild_id.get() >> 22) % total != number || listed.contains(&guild_id) {
+                continue;
+            }
+
+            delete_guild(guild_id).await?;
+        }
+
+        Ok(())
+    }
+}
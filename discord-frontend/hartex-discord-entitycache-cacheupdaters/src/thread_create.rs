@@ -0,0 +1,64 @@
+/*
+ * SPDX-License-Identifier: AGPL-3.0-only
+ *
+ * This file is part of HarTex.
+ *
+ * HarTex
+ * Copyright (c) 2021-2024 HarTex Project Developers
+ *
+ * HarTex is free software; you can redistribute it and/or modify
+ * it under the terms of the GNU Affero General Public License as published by
+ * the Free Software Foundation; either version 3 of the License, or
+ * (at your option) any later version.
+ *
+ * HarTex is distributed in the hope that it will be useful,
+ * but WITHOUT ANY WARRANTY; without even the implied warranty of
+ * MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+ * GNU Affero General Public License for more details.
+ *
+ * You should have received a copy of the GNU Affero General Public License along
+ * with HarTex. If not, see <https://www.gnu.org/licenses/>.
+ */
+
+//! # Thread Create Cache Updater
+//!
+//! An implementation of a cache updater for the thread create event.
+
+use hartex_discord_core::discord::model::channel::Channel;
+use hartex_discord_core::discord::model::gateway::payload::incoming::ThreadCreate;
+use hartex_discord_core::discord::model::id::marker::GuildMarker;
+use hartex_discord_core::discord::model::id::Id;
+use hartex_discord_entitycache_core::error::CacheResult;
+use hartex_discord_entitycache_core::traits::Repository;
+use hartex_discord_entitycache_entities::thread::ThreadEntity;
+use hartex_discord_entitycache_repositories::thread::CachedThreadRepository;
+
+use crate::CacheUpdater;
+
+impl CacheUpdater for ThreadCreate {
+    async fn update(&self) -> CacheResult<()> {
+        let Some(guild_id) = self.guild_id else {
+            return Ok(());
+        };
+
+        upsert_thread(guild_id, &self.0).await
+    }
+}
+
+/// Caches an active thread, or removes it from the cache if it has been archived.
+pub(crate) async fn upsert_thread(guild_id: Id<GuildMarker>, thread: &Channel) -> CacheResult<()> {
+    let (archived, locked) = thread
+        .thread_metadata
+        .as_ref()
+        .map_or((false, false), |metadata| {
+            (metadata.archived, metadata.locked)
+        });
+
+    if archived {
+        return CachedThreadRepository.delete(thread.id).await;
+    }
+
+    CachedThreadRepository
+        .upsert(ThreadEntity::from((guild_id, locked, thread.clone())))
+        .await
+}
@@ -0,0 +1,57 @@
+/*
+ * SPDX-License-Identifier: AGPL-3.0-only
+ *
+ * This file is part of HarTex.
+ *
+ * HarTex
+ * Copyright (c) 2021-2024 HarTex Project Developers
+ *
+ * HarTex is free software; you can redistribute it and/or modify
+ * it under the terms of the GNU Affero General Public License as published by
+ * the Free Software Foundation; either version 3 of the License, or
+ * (at your option) any later version.
+ *
+ * HarTex is distributed in the hope that it will be useful,
+ * but WITHOUT ANY WARRANTY; without even the implied warranty of
+ * MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+ * GNU Affero General Public License for more details.
+ *
+ * You should have received a copy of the GNU Affero General Public License along
+ * with HarTex. If not, see <https://www.gnu.org/licenses/>.
+ */
+
+//! # Thread List Sync Cache Updater
+//!
+//! An implementation of a cache updater for the thread list sync event.
+
+use hartex_discord_core::discord::model::gateway::payload::incoming::ThreadListSync;
+use hartex_discord_entitycache_core::error::CacheResult;
+use hartex_discord_entitycache_repositories::thread::CachedThreadRepository;
+
+use crate::thread_create::upsert_thread;
+use crate::CacheUpdater;
+
+impl CacheUpdater for ThreadListSync {
+    async fn update(&self) -> CacheResult<()> {
+        for thread in &self.threads {
+            upsert_thread(self.guild_id, thread).await?;
+        }
+
+        // the event carries every active thread of the synced channels (or of the whole guild
+        // when no channels are given), hence any other thread is no longer active
+        let ids = self
+            .threads
+            .iter()
+            .map(|thread| thread.id)
+            .collect::<Vec<_>>();
+        if self.channel_ids.is_empty() {
+            CachedThreadRepository
+                .retain_in_guild(self.guild_id, &ids)
+                .await
+        } else {
+            CachedThreadRepository
+                .retain_in_parents(self.guild_id, &self.channel_ids, &ids)
+                .await
+        }
+    }
+}
@@ -0,0 +1,51 @@
+/*
+ * SPDX-License-Identifier: AGPL-3.0-only
+ *
+ * This file is part of HarTex.
+ *
+ * HarTex
+ * Copyright (c) 2021-2024 HarTex Project Developers
+ *
+ * HarTex is free software; you can redistribute it and/or modify
+ * it under the terms of the GNU Affero General Public License as published by
+ * the Free Software Foundation; either version 3 of the License, or
+ * (at your option) any later version.
+ *
+ * HarTex is distributed in the hope that it will be useful,
+ * but WITHOUT ANY WARRANTY; without even the implied warranty of
+ * MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+ * GNU Affero General Public License for more details.
+ *
+ * You should have received a copy of the GNU Affero General Public License along
+ * with HarTex. If not, see <https://www.gnu.org/licenses/>.
+ */
+
+//! # User Update Cache Updater
+//!
+//! An implementation of a cache updater for the user update event.
+
+use hartex_discord_core::discord::model::gateway::payload::incoming::UserUpdate;
+use hartex_discord_entitycache_core::error::CacheResult;
+use hartex_discord_entitycache_core::traits::Repository;
+use hartex_discord_entitycache_repositories::user::CachedUserRepository;
+
+use crate::CacheUpdater;
+
+impl CacheUpdater for UserUpdate {
+    async fn update(&self) -> CacheResult<()> {
+        // the current user is cached as a member of the guilds of the bot; if it is not
+        // cached yet, it will be once its guilds are
+        let mut entity = match CachedUserRepository.get(self.0.id).await {
+            Ok(entity) => entity,
+            Err(error) if error.is_not_found() => return Ok(()),
+            Err(error) => return Err(error),
+        };
+
+        entity.avatar = self.0.avatar;
+        entity.discriminator = self.0.discriminator;
+        entity.global_name.clone_from(&self.0.global_name);
+        entity.name.clone_from(&self.0.name);
+
+        CachedUserRepository.upsert(entity).await
+    }
+}
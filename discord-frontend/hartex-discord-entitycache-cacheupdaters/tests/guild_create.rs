@@ -0,0 +1,146 @@
+/*
+ * SPDX-License-Identifier: AGPL-3.0-only
+ *
+ * This file is part of HarTex.
+ *
+ * HarTex
+ * Copyright (c) 2021-2024 HarTex Project Developers
+ *
+ * HarTex is free software; you can redistribute it and/or modify
+ * it under the terms of the GNU Affero General Public License as published by
+ * the Free Software Foundation; either version 3 of the License, or
+ * (at your option) any later version.
+ *
+ * HarTex is distributed in the hope that it will be useful,
+ * but WITHOUT ANY WARRANTY; without even the implied warranty of
+ * MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+ * GNU Affero General Public License for more details.
+ *
+ * You should have received a copy of the GNU Affero General Public License along
+ * with HarTex. If not, see <https://www.gnu.org/licenses/>.
+ */
+
+use hartex_discord_core::discord::model::gateway::payload::incoming::GuildCreate;
+use hartex_discord_core::discord::model::guild::Emoji;
+use hartex_discord_core::discord::model::guild::Guild;
+use hartex_discord_core::discord::model::guild::Role;
+use hartex_discord_core::discord::model::id::Id;
+use hartex_discord_core::tokio;
+use hartex_discord_entitycache_cacheupdaters::CacheUpdater;
+use hartex_discord_entitycache_core::error::CacheError;
+use hartex_discord_entitycache_core::traits::Repository;
+use hartex_discord_entitycache_entities::emoji::EmojiEntity;
+use hartex_discord_entitycache_entities::role::RoleEntity;
+use hartex_discord_entitycache_repositories::emoji::CachedEmojiRepository;
+use hartex_discord_entitycache_repositories::memory;
+use hartex_discord_entitycache_repositories::role::CachedRoleRepository;
+use serde_json::json;
+use serde_json::Value;
+
+const GUILD_ID: u64 = 1000;
+const OTHER_GUILD_ID: u64 = 1001;
+
+fn role(id: u64) -> Value {
+    json!({
+        "color": 0,
+        "flags": 0,
+        "hoist": false,
+        "id": id.to_string(),
+        "managed": false,
+        "mentionable": false,
+        "name": "role",
+        "permissions": "0",
+        "position": 0,
+    })
+}
+
+fn emoji(id: u64) -> Value {
+    json!({
+        "animated": false,
+        "available": true,
+        "id": id.to_string(),
+        "managed": false,
+        "name": "emoji",
+        "require_colons": true,
+        "roles": [],
+    })
+}
+
+fn guild(roles: &[u64], emojis: &[u64]) -> Guild {
+    serde_json::from_value(json!({
+        "afk_timeout": 300,
+        "channels": [],
+        "default_message_notifications": 0,
+        "emojis": emojis.iter().copied().map(emoji).collect::<Vec<_>>(),
+        "explicit_content_filter": 0,
+        "features": [],
+        "id": GUILD_ID.to_string(),
+        "large": false,
+        "member_count": 1,
+        "members": [],
+        "mfa_level": 0,
+        "name": "guild",
+        "nsfw_level": 0,
+        "owner_id": "2000",
+        "preferred_locale": "en-GB",
+        "premium_progress_bar_enabled": false,
+        "premium_tier": 0,
+        "presences": [],
+        "roles": roles.iter().copied().map(role).collect::<Vec<_>>(),
+        "stickers": [],
+        "system_channel_flags": 0,
+        "threads": [],
+        "verification_level": 0,
+        "voice_states": [],
+    }))
+    .unwrap()
+}
+
+async fn upsert_role(guild_id: u64, id: u64) {
+    let role = serde_json::from_value::<Role>(role(id)).unwrap();
+    CachedRoleRepository
+        .upsert(RoleEntity::from((Id::new(guild_id), role)))
+        .await
+        .unwrap();
+}
+
+async fn upsert_emoji(guild_id: u64, id: u64) {
+    let emoji = serde_json::from_value::<Emoji>(emoji(id)).unwrap();
+    CachedEmojiRepository
+        .upsert(EmojiEntity::from((Id::new(guild_id), emoji)))
+        .await
+        .unwrap();
+}
+
+#[tokio::test]
+async fn guild_create_removes_stale_roles_and_emojis() {
+    memory::install();
+
+    upsert_role(GUILD_ID, 3001).await;
+    upsert_role(OTHER_GUILD_ID, 3002).await;
+    upsert_emoji(GUILD_ID, 4001).await;
+    upsert_emoji(OTHER_GUILD_ID, 4002).await;
+
+    GuildCreate(guild(&[3000], &[4000])).update().await.unwrap();
+
+    let guild_id = Id::new(GUILD_ID);
+    assert!(CachedRoleRepository
+        .get((guild_id, Id::new(3000)))
+        .await
+        .is_ok());
+    assert!(matches!(
+        CachedRoleRepository.get((guild_id, Id::new(3001))).await,
+        Err(CacheError::NotFound)
+    ));
+    assert!(CachedRoleRepository
+        .get((Id::new(OTHER_GUILD_ID), Id::new(3002)))
+        .await
+        .is_ok());
+
+    assert!(CachedEmojiRepository.get(Id::new(4000)).await.is_ok());
+    assert!(matches!(
+        CachedEmojiRepository.get(Id::new(4001)).await,
+        Err(CacheError::NotFound)
+    ));
+    assert!(CachedEmojiRepository.get(Id::new(4002)).await.is_ok());
+}
@@ -29,6 +29,8 @@ use std::fmt::Display;
 use std::fmt::Formatter;
 
 use bb8::RunError;
+use serde_json::Error as JsonError;
+use tokio_postgres::error::SqlState;
 use tokio_postgres::Error as PostgresError;
 
 /// A cache error..
@@ -38,6 +40,10 @@ pub enum CacheError {
     Bb8(RunError<PostgresError>),
     /// Error related to environment variables.
     Env(VarError),
+    /// A JSON-encoded field of the entity could not be decoded.
+    Json(JsonError),
+    /// The entity is not present in the cache.
+    NotFound,
     /// A postgres error occurred.
     Postgres(PostgresError),
 }
@@ -47,11 +53,25 @@ impl Display for CacheError {
         match self {
             Self::Bb8(error) => writeln!(f, "bb8 postgres error: {error}"),
             Self::Env(error) => writeln!(f, "env error: {error}"),
+            Self::Json(error) => writeln!(f, "json error: {error}"),
+            Self::NotFound => writeln!(f, "entity not found in cache"),
             Self::Postgres(error) => writeln!(f, "postgres error: {error}"),
         }
     }
 }
 
+impl CacheError {
+    /// Whether the error signifies that the entity is not present in the cache.
+    #[must_use]
+    pub fn is_not_found(&self) -> bool {
+        match self {
+            Self::NotFound => true,
+            Self::Postgres(error) => error.code() == Some(&SqlState::NO_DATA),
+            _ => false,
+        }
+    }
+}
+
 impl Error for CacheError {}
 
 impl From<RunError<PostgresError>> for CacheError {
@@ -66,6 +86,12 @@ impl From<PostgresError> for CacheError {
     }
 }
 
+impl From<JsonError> for CacheError {
+    fn from(error: JsonError) -> Self {
+        Self::Json(error)
+    }
+}
+
 impl From<VarError> for CacheError {
     fn from(error: VarError) -> Self {
         Self::Env(error)
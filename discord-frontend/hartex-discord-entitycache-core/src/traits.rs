@@ -42,4 +42,8 @@ pub trait Repository<T: Entity> {
     /// Upserts an entity into the repository.
     #[allow(async_fn_in_trait)]
     async fn upsert(&self, entity: T) -> CacheResult<()>;
+
+    /// Deletes an entity from the repository.
+    #[allow(async_fn_in_trait)]
+    async fn delete(&self, entity_id: T::Id) -> CacheResult<()>;
 }
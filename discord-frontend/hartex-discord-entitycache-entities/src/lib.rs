@@ -29,8 +29,12 @@
 #![deny(warnings)]
 #![allow(deprecated)]
 
+pub mod channel;
 pub mod emoji;
 pub mod guild;
 pub mod member;
+pub mod message;
 pub mod role;
+pub mod thread;
 pub mod user;
+pub mod voice_state;
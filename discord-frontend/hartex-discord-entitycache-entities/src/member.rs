@@ -28,7 +28,7 @@ use hartex_discord_entitycache_core::entity;
     from = "twilight_model::guild::Member",
     assume = ["CachedMemberSelectByGuildId", "CachedMemberSelectByUserIdAndGuildId"],
     id = ["guild_id", "user_id"],
-    include = ["flags", "joined_at", "nick", "roles"],
+    include = ["avatar", "flags", "joined_at", "nick", "roles"],
     extra = [
         "guild_id": "Id<GuildMarker>",
         "user_id": "Id<UserMarker>",
@@ -0,0 +1,46 @@
+/*
+ * SPDX-License-Identifier: AGPL-3.0-only
+ *
+ * This file is part of HarTex.
+ *
+ * HarTex
+ * Copyright (c) 2021-2024 HarTex Project Developers
+ *
+ * HarTex is free software; you can redistribute it and/or modify
+ * it under the terms of the GNU Affero General Public License as published by
+ * the Free Software Foundation; either version 3 of the License, or
+ * (at your option) any later version.
+ *
+ * HarTex is distributed in the hope that it will be useful,
+ * but WITHOUT ANY WARRANTY; without even the implied warranty of
+ * MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+ * GNU Affero General Public License for more details.
+ *
+ * You should have received a copy of the GNU Affero General Public License along
+ * with HarTex. If not, see <https://www.gnu.org/licenses/>.
+ */
+
+//! # Message Entities
+
+use hartex_discord_entitycache_core::entity;
+
+/// A message entity.
+///
+/// Only a summary of the attachments and embeds of a message is cached: the filenames of the
+/// attachments, and the titles (or, failing that, the kinds) of the embeds.
+#[allow(clippy::module_name_repetitions)]
+#[entity(
+    from = "twilight_model::channel::message::Message",
+    assume = ["CachedMessageSelectById"],
+    id = ["id"],
+    include = ["channel_id", "content", "edited_timestamp", "guild_id", "timestamp"],
+    extra = [
+        "author_id": "Id<UserMarker>",
+        "attachments": "Vec<String>",
+        "embeds": "Vec<String>",
+        "deleted": "bool",
+    ],
+    overrides = [],
+    relates = [],
+)]
+pub struct MessageEntity;
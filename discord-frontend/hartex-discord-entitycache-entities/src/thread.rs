@@ -0,0 +1,47 @@
+/*
+ * SPDX-License-Identifier: AGPL-3.0-only
+ *
+ * This file is part of HarTex.
+ *
+ * HarTex
+ * Copyright (c) 2021-2024 HarTex Project Developers
+ *
+ * HarTex is free software; you can redistribute it and/or modify
+ * it under the terms of the GNU Affero General Public License as published by
+ * the Free Software Foundation; either version 3 of the License, or
+ * (at your option) any later version.
+ *
+ * HarTex is distributed in the hope that it will be useful,
+ * but WITHOUT ANY WARRANTY; without even the implied warranty of
+ * MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+ * GNU Affero General Public License for more details.
+ *
+ * You should have received a copy of the GNU Affero General Public License along
+ * with HarTex. If not, see <https://www.gnu.org/licenses/>.
+ */
+
+//! # Thread Entities
+
+use hartex_discord_entitycache_core::entity;
+
+/// A thread entity.
+///
+/// Only active threads are cached; threads are removed from the cache once they are archived.
+#[allow(clippy::module_name_repetitions)]
+#[entity(
+    from = "twilight_model::channel::Channel",
+    assume = ["CachedThreadSelectByGuildId", "CachedThreadSelectById", "CachedThreadSelectByParentId"],
+    id = ["id"],
+    include = ["kind", "name", "owner_id", "parent_id", "rate_limit_per_user"],
+    extra = [
+        "guild_id": "Id<GuildMarker>",
+        "locked": "bool",
+    ],
+    overrides = [
+        "ChannelType": "twilight_model::channel::ChannelType"
+    ],
+    relates = [
+        unique "GuildEntity": via "guild_id" as "id",
+    ],
+)]
+pub struct ThreadEntity;
@@ -52,11 +52,14 @@ const PRELUDE_AND_PRIMITIVES: [&str; 21] = [
 ];
 
 /// Valid entities for inter-entity relationships.
-const VALID_ENTITIES: [(&str, &str); 5] = [
+const VALID_ENTITIES: [(&str, &str); 8] = [
+    ("ChannelEntity", "crate::channel::ChannelEntity"),
     ("EmojiEntity", "crate::emoji::EmojiEntity"),
     ("GuildEntity", "crate::guild::GuildEntity"),
     ("MemberEntity", "crate::member::MemberEntity"),
+    ("MessageEntity", "crate::message::MessageEntity"),
     ("RoleEntity", "crate::role::RoleEntity"),
+    ("ThreadEntity", "crate::thread::ThreadEntity"),
     ("UserEntity", "crate::user::UserEntity"),
 ];
 
@@ -93,6 +96,10 @@ pub struct EntityMacroInput where
     equal6: Token![=],
     relates_array: RelatesArray,
     comma6??: Token![,],
+    json_ident??: Ident,
+    equal8??: Token![=],
+    json_array??: LitStrArray,
+    comma8??: Token![,],
 );
 
 impl_bracket_parse!(
@@ -167,6 +174,11 @@ pub fn implement_entity(input: &EntityMacroInput, item_struct: &ItemStruct) -> O
         overrides_ident == "overrides";
         relates_ident == "relates"
     );
+    if let Some(json_ident) = &input.json_ident
+        && json_ident != "json"
+    {
+        bail(json_ident, "expected `json`")?;
+    }
 
     let type_key = input.from_lit_str.value();
     let rfind_index = type_key.rfind(':').unwrap();
@@ -223,8 +235,33 @@ pub fn implement_entity(input: &EntityMacroInput, item_struct: &ItemStruct) -> O
     });
     let id_fields = id_fields.collect::<Vec<_>>();
 
+    let json_fields = (input.json_array.iter())
+        .flat_map(|array| array.elements.iter())
+        .filter_map(|lit_str| {
+            if fields.contains(&lit_str.value()) {
+                return Some(lit_str.value());
+            }
+
+            lit_str
+                .span()
+                .unwrap()
+                .error(format!(
+                    "field `{}` is not stored as part of the entity",
+                    lit_str.value()
+                ))
+                .help("only included fields can be decoded from JSON")
+                .emit();
+            any_not_found = true;
+            None
+        });
+    let json_fields = json_fields.collect::<Vec<_>>();
+
     input.relates_array.elements.iter().for_each(|element| {
-        if !fields.contains(&element.value.value()) && !id_fields.contains(&element.value.value()) {
+        if !fields.contains(&element.value.value())
+            && !id_fields.contains(&element.value.value())
+            && !(input.extra_fields_array.elements.iter())
+                .any(|extra| extra.key.value() == element.value.value())
+        {
             (element.value.span().unwrap())
                 .error(format!(
                     "field `{}` cannot be found in type `{type_key}`",
@@ -243,7 +280,11 @@ pub fn implement_entity(input: &EntityMacroInput, item_struct: &ItemStruct) -> O
     let item_struct_name = item_struct.ident.clone();
     let maker = |field: &Field| {
         let field_name = Ident::new(&field.name, Span::call_site());
-        make_field_decl_and_assignments(&field_name, &type_of(&field.ty, input))
+        make_field_decl_and_assignments(
+            &field_name,
+            &type_of(&field.ty, input),
+            json_fields.contains(&field.name),
+        )
     };
 
     macro_rules! filterer {
@@ -390,7 +431,7 @@ pub fn implement_entity(input: &EntityMacroInput, item_struct: &ItemStruct) -> O
 
                         #full_query_function_call
 
-                        Ok(data.into_iter().map(|thing| #ret_type::from(thing)).collect())
+                        data.into_iter().map(#ret_type::try_from).collect()
                     }
                 }
             }
@@ -409,7 +450,7 @@ pub fn implement_entity(input: &EntityMacroInput, item_struct: &ItemStruct) -> O
 
                         #full_query_function_call
 
-                        Ok(#ret_type::from(data))
+                        #ret_type::try_from(data)
                     }
                 }
             }
@@ -429,9 +470,10 @@ pub fn implement_entity(input: &EntityMacroInput, item_struct: &ItemStruct) -> O
                     quote! {hartex_database_queries::discord_frontend::queries::#ident_snake::#ident_pascal};
 
                 quote! {
-                    impl From<#full_ident> for #item_struct_name {
-                        fn from(model: #full_ident) -> Self {
-                            Self { #(#field_assignments_with_necessary_casts),* }
+                    impl TryFrom<#full_ident> for #item_struct_name {
+                        type Error = hartex_discord_entitycache_core::error::CacheError;
+                        fn try_from(model: #full_ident) -> Result<Self, Self::Error> {
+                            Ok(Self { #(#field_assignments_with_necessary_casts),* })
                         }
                     }
                 }
@@ -442,6 +484,7 @@ pub fn implement_entity(input: &EntityMacroInput, item_struct: &ItemStruct) -> O
             use tokio_postgres::GenericClient;
 
             #(#attrs)*
+            #[derive(Clone)]
             #item_struct_vis struct #item_struct_name {
                 #(#fields_tokens),*
             }
@@ -466,7 +509,7 @@ pub fn implement_entity(input: &EntityMacroInput, item_struct: &ItemStruct) -> O
 
     let fields = input.extra_fields_array.elements.iter().map(|element| {
         let ident = Ident::new(&element.key.value(), Span::call_site());
-        make_field_decl_and_assignments(&ident, &type_of(&element.value.value(), input))
+        make_field_decl_and_assignments(&ident, &type_of(&element.value.value(), input), false)
     });
     let (extra_fields_tokens, _, extra_fields_assignment_tokens_with_necessary_casts): (
         Vec<_>,
@@ -490,9 +533,10 @@ pub fn implement_entity(input: &EntityMacroInput, item_struct: &ItemStruct) -> O
                 quote! {hartex_database_queries::discord_frontend::queries::#ident_snake::#ident_pascal};
 
             quote! {
-                impl From<#full_ident> for #item_struct_name {
-                    fn from(model: #full_ident) -> Self {
-                        Self { #(#field_assignments_with_necessary_casts),*, #(#extra_fields_assignment_tokens_with_necessary_casts),* }
+                impl TryFrom<#full_ident> for #item_struct_name {
+                    type Error = hartex_discord_entitycache_core::error::CacheError;
+                    fn try_from(model: #full_ident) -> Result<Self, Self::Error> {
+                        Ok(Self { #(#field_assignments_with_necessary_casts),*, #(#extra_fields_assignment_tokens_with_necessary_casts),* })
                     }
                 }
             }
@@ -503,6 +547,7 @@ pub fn implement_entity(input: &EntityMacroInput, item_struct: &ItemStruct) -> O
         use tokio_postgres::GenericClient;
 
         #(#attrs)*
+        #[derive(Clone)]
         #item_struct_vis struct #item_struct_name {
             #(#fields_tokens),*,
             #(#extra_fields_tokens),*
@@ -562,7 +607,23 @@ fn expand_fully_qualified_type_name(to_expand: &str, overrides_array: &KeyValueA
 fn make_field_decl_and_assignments(
     field_name: &Ident,
     field_type: &Type,
+    json: bool,
 ) -> (TokenStream, TokenStream, TokenStream) {
+    // Fields explicitly stored as JSON
+    if json && field_type.is("Option") {
+        return (
+            quote! {pub #field_name: #field_type},
+            quote! {#field_name: model.#field_name},
+            quote! {#field_name: model.#field_name.map(serde_json::from_value).transpose()?},
+        );
+    } else if json {
+        return (
+            quote! {pub #field_name: #field_type},
+            quote! {#field_name: model.#field_name},
+            quote! {#field_name: serde_json::from_value(model.#field_name)?},
+        );
+    }
+
     // Field name special case
     if field_name == "discriminator" {
         return (
@@ -573,7 +634,8 @@ fn make_field_decl_and_assignments(
     }
 
     // Field type special case
-    if field_type.is_enum("DefaultMessageNotificationLevel")
+    if field_type.is_enum("ChannelType")
+        || field_type.is_enum("DefaultMessageNotificationLevel")
         || field_type.is_enum("ExplicitContentFilter")
         || field_type.is_enum("MfaLevel")
         || field_type.is_enum("PremiumTier")
@@ -590,6 +652,12 @@ fn make_field_decl_and_assignments(
             quote! {#field_name: model.#field_name},
             quote! {#field_name: std::str::FromStr::from_str(&model.#field_name).unwrap()},
         )
+    } else if field_type.is_option_of("Id") {
+        (
+            quote! {pub #field_name: #field_type},
+            quote! {#field_name: model.#field_name},
+            quote! {#field_name: model.#field_name.as_deref().map(|str| std::str::FromStr::from_str(str).unwrap())},
+        )
     } else if field_type.is("MemberFlags") {
         (
             quote! {pub #field_name: #field_type},
@@ -614,6 +682,12 @@ fn make_field_decl_and_assignments(
             quote! {#field_name: model.#field_name},
             quote! {#field_name: model.#field_name.map(|timestamp| twilight_model::util::Timestamp::from_secs(timestamp.unix_timestamp()).unwrap())},
         )
+    } else if field_type.is("Timestamp") {
+        (
+            quote! {pub #field_name: #field_type},
+            quote! {#field_name: model.#field_name},
+            quote! {#field_name: twilight_model::util::Timestamp::from_secs(model.#field_name.unix_timestamp()).unwrap()},
+        )
     } else if field_type.is("i64") {
         (
             quote! {pub #field_name: #field_type},
@@ -626,6 +700,12 @@ fn make_field_decl_and_assignments(
             quote! {#field_name: model.#field_name},
             quote! {#field_name: model.#field_name as u32},
         )
+    } else if field_type.is_option_of("u16") {
+        (
+            quote! {pub #field_name: #field_type},
+            quote! {#field_name: model.#field_name},
+            quote! {#field_name: model.#field_name.map(|i| i as u16)},
+        )
     } else if field_type.is_option_of("u64") {
         (
             quote! {pub #field_name: #field_type},
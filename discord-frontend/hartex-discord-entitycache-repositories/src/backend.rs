@@ -0,0 +1,164 @@
+/*
+ * SPDX-License-Identifier: AGPL-3.0-only
+ *
+ * This file is part of HarTex.
+ *
+ * HarTex
+ * Copyright (c) 2021-2024 HarTex Project Developers
+ *
+ * HarTex is free software; you can redistribute it and/or modify
+ * it under the terms of the GNU Affero General Public License as published by
+ * the Free Software Foundation; either version 3 of the License, or
+ * (at your option) any later version.
+ *
+ * HarTex is distributed in the hope that it will be useful,
+ * but WITHOUT ANY WARRANTY; without even the implied warranty of
+ * MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+ * GNU Affero General Public License for more details.
+ *
+ * You should have received a copy of the GNU Affero General Public License along
+ * with HarTex. If not, see <https://www.gnu.org/licenses/>.
+ */
+
+//! # Entity Cache Backends
+//!
+//! The storage the entities of the entity cache are read from and written to.
+//!
+//! The repositories are backed by the database unless another backend, such as the in-memory
+//! repository of the [`memory`](crate::memory) module, is installed with [`install`] before the
+//! entity cache is first used.
+//!
+//! Reconciling the entities of a guild with those the gateway reports is performed on the
+//! installed backend as well. The other bulk operations of the repositories, such as deleting
+//! every entity of a guild, are maintenance operations of the cache updaters and are always
+//! performed on the database.
+
+use std::ptr;
+use std::sync::OnceLock;
+
+use async_trait::async_trait;
+use hartex_discord_core::discord::model::id::marker::GuildMarker;
+use hartex_discord_core::discord::model::id::Id;
+use hartex_discord_entitycache_core::error::CacheResult;
+use hartex_discord_entitycache_core::traits::Entity;
+use hartex_discord_entitycache_entities::channel::ChannelEntity;
+use hartex_discord_entitycache_entities::emoji::EmojiEntity;
+use hartex_discord_entitycache_entities::guild::GuildEntity;
+use hartex_discord_entitycache_entities::member::MemberEntity;
+use hartex_discord_entitycache_entities::message::MessageEntity;
+use hartex_discord_entitycache_entities::role::RoleEntity;
+use hartex_discord_entitycache_entities::thread::ThreadEntity;
+use hartex_discord_entitycache_entities::user::UserEntity;
+use hartex_discord_entitycache_entities::voice_state::VoiceStateEntity;
+
+/// The installed entity cache backend.
+static BACKEND: OnceLock<&'static dyn CacheBackend> = OnceLock::new();
+
+/// A storage for entities of a certain type.
+#[async_trait]
+pub trait Backend<T: Entity>: Send + Sync {
+    /// Retrieves an entity from the storage.
+    async fn get(&self, entity_id: T::Id) -> CacheResult<T>;
+
+    /// Upserts an entity into the storage.
+    async fn upsert(&self, entity: T) -> CacheResult<()>;
+
+    /// Deletes an entity from the storage.
+    async fn delete(&self, entity_id: T::Id) -> CacheResult<()>;
+}
+
+/// A storage for entities of a certain type belonging to guilds.
+#[async_trait]
+pub trait GuildBackend<T: GuildScoped>: Backend<T> {
+    /// Deletes every entity of a guild other than the given ones from the storage.
+    async fn retain_in_guild(&self, guild_id: Id<GuildMarker>, entity_ids: &[T::Id])
+        -> CacheResult<()>;
+}
+
+/// An entity belonging to a guild.
+pub trait GuildScoped: Entity {
+    /// The id of the guild the entity belongs to.
+    fn guild_id(&self) -> Id<GuildMarker>;
+}
+
+impl GuildScoped for ChannelEntity {
+    fn guild_id(&self) -> Id<GuildMarker> {
+        self.guild_id
+    }
+}
+
+impl GuildScoped for EmojiEntity {
+    fn guild_id(&self) -> Id<GuildMarker> {
+        self.guild_id
+    }
+}
+
+impl GuildScoped for RoleEntity {
+    fn guild_id(&self) -> Id<GuildMarker> {
+        self.guild_id
+    }
+}
+
+impl GuildScoped for ThreadEntity {
+    fn guild_id(&self) -> Id<GuildMarker> {
+        self.guild_id
+    }
+}
+
+impl GuildScoped for VoiceStateEntity {
+    fn guild_id(&self) -> Id<GuildMarker> {
+        self.guild_id
+    }
+}
+
+/// A storage for entities of every type held by the entity cache.
+pub trait CacheBackend:
+    GuildBackend<ChannelEntity>
+    + GuildBackend<EmojiEntity>
+    + Backend<GuildEntity>
+    + Backend<MemberEntity>
+    + Backend<MessageEntity>
+    + GuildBackend<RoleEntity>
+    + GuildBackend<ThreadEntity>
+    + Backend<UserEntity>
+    + GuildBackend<VoiceStateEntity>
+{
+}
+
+impl<B> CacheBackend for B where
+    B: GuildBackend<ChannelEntity>
+        + GuildBackend<EmojiEntity>
+        + Backend<GuildEntity>
+        + Backend<MemberEntity>
+        + Backend<MessageEntity>
+        + GuildBackend<RoleEntity>
+        + GuildBackend<ThreadEntity>
+        + Backend<UserEntity>
+        + GuildBackend<VoiceStateEntity>
+{
+}
+
+/// The entity cache backend storing entities in the database, through the in-process L1 cache.
+pub struct DatabaseBackend;
+
+/// Installs the backend the entities of the entity cache are read from and written to.
+///
+/// This must be called before the entity cache is first used; installing the same backend again
+/// has no effect.
+///
+/// # Panics
+///
+/// Panics when another backend is already in use.
+pub fn install(backend: &'static dyn CacheBackend) {
+    let installed = *BACKEND.get_or_init(|| backend);
+
+    assert!(
+        ptr::addr_eq(installed, backend),
+        "another entity cache backend is already in use"
+    );
+}
+
+/// Returns the installed entity cache backend, installing the database if none is.
+pub(crate) fn backend() -> &'static dyn CacheBackend {
+    *BACKEND.get_or_init(|| &DatabaseBackend)
+}
@@ -0,0 +1,192 @@
+/*
+ * SPDX-License-Identifier: AGPL-3.0-only
+ *
+ * This file is part of HarTex.
+ *
+ * HarTex
+ * Copyright (c) 2021-2024 HarTex Project Developers
+ *
+ * HarTex is free software; you can redistribute it and/or modify
+ * it under the terms of the GNU Affero General Public License as published by
+ * the Free Software Foundation; either version 3 of the License, or
+ * (at your option) any later version.
+ *
+ * HarTex is distributed in the hope that it will be useful,
+ * but WITHOUT ANY WARRANTY; without even the implied warranty of
+ * MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+ * GNU Affero General Public License for more details.
+ *
+ * You should have received a copy of the GNU Affero General Public License along
+ * with HarTex. If not, see <https://www.gnu.org/licenses/>.
+ */
+
+use std::pin::Pin;
+use std::str::FromStr;
+
+use async_trait::async_trait;
+use hartex_database_queries::discord_frontend::queries::cached_channel_delete_by_guild_id::cached_channel_delete_by_guild_id;
+use hartex_database_queries::discord_frontend::queries::cached_channel_delete_by_guild_id_except_ids::cached_channel_delete_by_guild_id_except_ids;
+use hartex_database_queries::discord_frontend::queries::cached_channel_delete_by_id::cached_channel_delete_by_id;
+use hartex_database_queries::discord_frontend::queries::cached_channel_select_by_id::cached_channel_select_by_id;
+use hartex_database_queries::discord_frontend::queries::cached_channel_upsert::cached_channel_upsert;
+use hartex_discord_core::discord::model::channel::ChannelType;
+use hartex_discord_core::discord::model::id::marker::ChannelMarker;
+use hartex_discord_core::discord::model::id::marker::GuildMarker;
+use hartex_discord_core::discord::model::id::Id;
+use hartex_discord_entitycache_core::error::CacheResult;
+use hartex_discord_entitycache_core::traits::Entity;
+use hartex_discord_entitycache_core::traits::Repository;
+use hartex_discord_entitycache_entities::channel::ChannelEntity;
+use hartex_discord_utils::DATABASE_POOL;
+use tokio_postgres::GenericClient;
+
+use crate::backend;
+use crate::backend::Backend;
+use crate::backend::DatabaseBackend;
+use crate::backend::GuildBackend;
+use crate::l1;
+
+/// Repository for channel entities.
+pub struct CachedChannelRepository;
+
+impl CachedChannelRepository {
+    /// Deletes every channel of a guild.
+    #[allow(clippy::missing_errors_doc)]
+    #[allow(clippy::missing_panics_doc)]
+    pub async fn delete_by_guild_id(&self, guild_id: Id<GuildMarker>) -> CacheResult<()> {
+        let pinned = Pin::static_ref(&DATABASE_POOL).await;
+        let pooled = pinned.get().await?;
+        let client = pooled.client();
+
+        let ids = cached_channel_delete_by_guild_id()
+            .bind(client, &guild_id.to_string())
+            .all()
+            .await?;
+
+        for id in ids {
+            l1::invalidate::<ChannelEntity>(&Id::<ChannelMarker>::from_str(&id).unwrap());
+        }
+
+        Ok(())
+    }
+
+    /// Deletes every channel of a guild other than the given ones.
+    #[allow(clippy::missing_errors_doc)]
+    pub async fn retain_in_guild(
+        &self,
+        guild_id: Id<GuildMarker>,
+        ids: &[Id<ChannelMarker>],
+    ) -> CacheResult<()> {
+        GuildBackend::<ChannelEntity>::retain_in_guild(backend::backend(), guild_id, ids).await
+    }
+}
+
+impl Repository<ChannelEntity> for CachedChannelRepository {
+    async fn get(&self, id: <ChannelEntity as Entity>::Id) -> CacheResult<ChannelEntity> {
+        Backend::<ChannelEntity>::get(backend::backend(), id).await
+    }
+
+    async fn upsert(&self, entity: ChannelEntity) -> CacheResult<()> {
+        Backend::<ChannelEntity>::upsert(backend::backend(), entity).await
+    }
+
+    async fn delete(&self, id: <ChannelEntity as Entity>::Id) -> CacheResult<()> {
+        Backend::<ChannelEntity>::delete(backend::backend(), id).await
+    }
+}
+
+#[async_trait]
+impl Backend<ChannelEntity> for DatabaseBackend {
+    async fn get(&self, id: <ChannelEntity as Entity>::Id) -> CacheResult<ChannelEntity> {
+        if let Some(entity) = l1::get::<ChannelEntity>(&id) {
+            return Ok(entity);
+        }
+
+        let ticket = l1::ticket::<ChannelEntity>();
+
+        let pinned = Pin::static_ref(&DATABASE_POOL).await;
+        let pooled = pinned.get().await?;
+        let client = pooled.client();
+
+        let data = cached_channel_select_by_id()
+            .bind(client, &id.to_string())
+            .one()
+            .await?;
+
+        let entity = ChannelEntity::try_from(data)?;
+        l1::insert(&entity, ticket);
+
+        Ok(entity)
+    }
+
+    async fn upsert(&self, entity: ChannelEntity) -> CacheResult<()> {
+        let pinned = Pin::static_ref(&DATABASE_POOL).await;
+        let pooled = pinned.get().await?;
+        let client = pooled.client();
+
+        cached_channel_upsert()
+            .bind(
+                client,
+                &entity.id.to_string(),
+                &entity.guild_id.to_string(),
+                &i16::from(<ChannelType as Into<u8>>::into(entity.kind)),
+                &entity.name,
+                &entity.nsfw,
+                &entity.parent_id.map(|id| id.to_string()),
+                &entity
+                    .permission_overwrites
+                    .as_ref()
+                    .map(|overwrites| serde_json::to_value(overwrites).unwrap()),
+                &entity.position,
+                &entity.rate_limit_per_user.map(i32::from),
+                &entity.topic,
+            )
+            .await?;
+
+        l1::invalidate::<ChannelEntity>(&entity.id());
+
+        Ok(())
+    }
+
+    async fn delete(&self, id: <ChannelEntity as Entity>::Id) -> CacheResult<()> {
+        let pinned = Pin::static_ref(&DATABASE_POOL).await;
+        let pooled = pinned.get().await?;
+        let client = pooled.client();
+
+        cached_channel_delete_by_id()
+            .bind(client, &id.to_string())
+            .await?;
+
+        l1::invalidate::<ChannelEntity>(&id);
+
+        Ok(())
+    }
+}
+
+#[async_trait]
+impl GuildBackend<ChannelEntity> for DatabaseBackend {
+    async fn retain_in_guild(
+        &self,
+        guild_id: Id<GuildMarker>,
+        ids: &[Id<ChannelMarker>],
+    ) -> CacheResult<()> {
+        let pinned = Pin::static_ref(&DATABASE_POOL).await;
+        let pooled = pinned.get().await?;
+        let client = pooled.client();
+
+        let ids = cached_channel_delete_by_guild_id_except_ids()
+            .bind(
+                client,
+                &guild_id.to_string(),
+                &ids.iter().map(ToString::to_string).collect::<Vec<_>>(),
+            )
+            .all()
+            .await?;
+
+        for id in ids {
+            l1::invalidate::<ChannelEntity>(&Id::<ChannelMarker>::from_str(&id).unwrap());
+        }
+
+        Ok(())
+    }
+}
@@ -21,9 +21,17 @@
  */
 
 use std::pin::Pin;
+use std::str::FromStr;
 
+use async_trait::async_trait;
+use hartex_database_queries::discord_frontend::queries::cached_emoji_delete_by_guild_id::cached_emoji_delete_by_guild_id;
+use hartex_database_queries::discord_frontend::queries::cached_emoji_delete_by_guild_id_except_ids::cached_emoji_delete_by_guild_id_except_ids;
+use hartex_database_queries::discord_frontend::queries::cached_emoji_delete_by_id::cached_emoji_delete_by_id;
 use hartex_database_queries::discord_frontend::queries::cached_emoji_select_by_id::cached_emoji_select_by_id;
 use hartex_database_queries::discord_frontend::queries::cached_emoji_upsert::cached_emoji_upsert;
+use hartex_discord_core::discord::model::id::marker::EmojiMarker;
+use hartex_discord_core::discord::model::id::marker::GuildMarker;
+use hartex_discord_core::discord::model::id::Id;
 use hartex_discord_entitycache_core::error::CacheResult;
 use hartex_discord_entitycache_core::traits::Entity;
 use hartex_discord_entitycache_core::traits::Repository;
@@ -31,11 +39,70 @@ use hartex_discord_entitycache_entities::emoji::EmojiEntity;
 use hartex_discord_utils::DATABASE_POOL;
 use tokio_postgres::GenericClient;
 
+use crate::backend;
+use crate::backend::Backend;
+use crate::backend::DatabaseBackend;
+use crate::backend::GuildBackend;
+use crate::l1;
+
 /// Repository for emoji entities.
 pub struct CachedEmojiRepository;
 
+impl CachedEmojiRepository {
+    /// Deletes every emoji of a guild.
+    #[allow(clippy::missing_errors_doc)]
+    #[allow(clippy::missing_panics_doc)]
+    pub async fn delete_by_guild_id(&self, guild_id: Id<GuildMarker>) -> CacheResult<()> {
+        let pinned = Pin::static_ref(&DATABASE_POOL).await;
+        let pooled = pinned.get().await?;
+        let client = pooled.client();
+
+        let ids = cached_emoji_delete_by_guild_id()
+            .bind(client, &guild_id.to_string())
+            .all()
+            .await?;
+
+        for id in ids {
+            l1::invalidate::<EmojiEntity>(&Id::from_str(&id).unwrap());
+        }
+
+        Ok(())
+    }
+
+    /// Deletes every emoji of a guild other than the given ones.
+    #[allow(clippy::missing_errors_doc)]
+    pub async fn retain_in_guild(
+        &self,
+        guild_id: Id<GuildMarker>,
+        ids: &[Id<EmojiMarker>],
+    ) -> CacheResult<()> {
+        GuildBackend::<EmojiEntity>::retain_in_guild(backend::backend(), guild_id, ids).await
+    }
+}
+
 impl Repository<EmojiEntity> for CachedEmojiRepository {
     async fn get(&self, id: <EmojiEntity as Entity>::Id) -> CacheResult<EmojiEntity> {
+        Backend::<EmojiEntity>::get(backend::backend(), id).await
+    }
+
+    async fn upsert(&self, entity: EmojiEntity) -> CacheResult<()> {
+        Backend::<EmojiEntity>::upsert(backend::backend(), entity).await
+    }
+
+    async fn delete(&self, id: <EmojiEntity as Entity>::Id) -> CacheResult<()> {
+        Backend::<EmojiEntity>::delete(backend::backend(), id).await
+    }
+}
+
+#[async_trait]
+impl Backend<EmojiEntity> for DatabaseBackend {
+    async fn get(&self, id: <EmojiEntity as Entity>::Id) -> CacheResult<EmojiEntity> {
+        if let Some(entity) = l1::get::<EmojiEntity>(&id) {
+            return Ok(entity);
+        }
+
+        let ticket = l1::ticket::<EmojiEntity>();
+
         let pinned = Pin::static_ref(&DATABASE_POOL).await;
         let pooled = pinned.get().await?;
         let client = pooled.client();
@@ -45,7 +112,10 @@ impl Repository<EmojiEntity> for CachedEmojiRepository {
             .one()
             .await?;
 
-        Ok(EmojiEntity::from(data))
+        let entity = EmojiEntity::try_from(data)?;
+        l1::insert(&entity, ticket);
+
+        Ok(entity)
     }
 
     async fn upsert(&self, entity: EmojiEntity) -> CacheResult<()> {
@@ -64,6 +134,50 @@ impl Repository<EmojiEntity> for CachedEmojiRepository {
             )
             .await?;
 
+        l1::invalidate::<EmojiEntity>(&entity.id());
+
+        Ok(())
+    }
+
+    async fn delete(&self, id: <EmojiEntity as Entity>::Id) -> CacheResult<()> {
+        let pinned = Pin::static_ref(&DATABASE_POOL).await;
+        let pooled = pinned.get().await?;
+        let client = pooled.client();
+
+        cached_emoji_delete_by_id()
+            .bind(client, &id.to_string())
+            .await?;
+
+        l1::invalidate::<EmojiEntity>(&id);
+
+        Ok(())
+    }
+}
+
+#[async_trait]
+impl GuildBackend<EmojiEntity> for DatabaseBackend {
+    async fn retain_in_guild(
+        &self,
+        guild_id: Id<GuildMarker>,
+        ids: &[Id<EmojiMarker>],
+    ) -> CacheResult<()> {
+        let pinned = Pin::static_ref(&DATABASE_POOL).await;
+        let pooled = pinned.get().await?;
+        let client = pooled.client();
+
+        let ids = cached_emoji_delete_by_guild_id_except_ids()
+            .bind(
+                client,
+                &guild_id.to_string(),
+                &ids.iter().map(ToString::to_string).collect::<Vec<_>>(),
+            )
+            .all()
+            .await?;
+
+        for id in ids {
+            l1::invalidate::<EmojiEntity>(&Id::from_str(&id).unwrap());
+        }
+
         Ok(())
     }
 }
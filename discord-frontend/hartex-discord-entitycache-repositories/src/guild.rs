@@ -24,14 +24,20 @@
 
 use std::borrow::Cow;
 use std::pin::Pin;
+use std::str::FromStr;
 
+use async_trait::async_trait;
+use hartex_database_queries::discord_frontend::queries::cached_guild_delete_by_id::cached_guild_delete_by_id;
 use hartex_database_queries::discord_frontend::queries::cached_guild_select_by_id::cached_guild_select_by_id;
+use hartex_database_queries::discord_frontend::queries::cached_guild_select_ids::cached_guild_select_ids;
 use hartex_database_queries::discord_frontend::queries::cached_guild_upsert::cached_guild_upsert;
 use hartex_discord_core::discord::model::guild::DefaultMessageNotificationLevel;
 use hartex_discord_core::discord::model::guild::ExplicitContentFilter;
 use hartex_discord_core::discord::model::guild::MfaLevel;
 use hartex_discord_core::discord::model::guild::PremiumTier;
 use hartex_discord_core::discord::model::guild::VerificationLevel;
+use hartex_discord_core::discord::model::id::marker::GuildMarker;
+use hartex_discord_core::discord::model::id::Id;
 use hartex_discord_entitycache_core::error::CacheResult;
 use hartex_discord_entitycache_core::traits::Entity;
 use hartex_discord_entitycache_core::traits::Repository;
@@ -39,13 +45,57 @@ use hartex_discord_entitycache_entities::guild::GuildEntity;
 use hartex_discord_utils::DATABASE_POOL;
 use tokio_postgres::GenericClient;
 
+use crate::backend;
+use crate::backend::Backend;
+use crate::backend::DatabaseBackend;
+use crate::l1;
+
 /// Repository for guild entities.
 pub struct CachedGuildRepository;
 
+impl CachedGuildRepository {
+    /// Retrieves the identifiers of every cached guild.
+    #[allow(clippy::missing_errors_doc)]
+    #[allow(clippy::missing_panics_doc)]
+    pub async fn guild_ids(&self) -> CacheResult<Vec<Id<GuildMarker>>> {
+        let pinned = Pin::static_ref(&DATABASE_POOL).await;
+        let pooled = pinned.get().await?;
+        let client = pooled.client();
+
+        let ids = cached_guild_select_ids().bind(client).all().await?;
+
+        Ok(ids
+            .into_iter()
+            .map(|id| Id::<GuildMarker>::from_str(&id).unwrap())
+            .collect())
+    }
+}
+
 impl Repository<GuildEntity> for CachedGuildRepository {
+    async fn get(&self, id: <GuildEntity as Entity>::Id) -> CacheResult<GuildEntity> {
+        Backend::<GuildEntity>::get(backend::backend(), id).await
+    }
+
+    async fn upsert(&self, entity: GuildEntity) -> CacheResult<()> {
+        Backend::<GuildEntity>::upsert(backend::backend(), entity).await
+    }
+
+    async fn delete(&self, id: <GuildEntity as Entity>::Id) -> CacheResult<()> {
+        Backend::<GuildEntity>::delete(backend::backend(), id).await
+    }
+}
+
+#[async_trait]
+impl Backend<GuildEntity> for DatabaseBackend {
     #[allow(clippy::cast_possible_truncation)]
     #[allow(clippy::cast_sign_loss)]
     async fn get(&self, id: <GuildEntity as Entity>::Id) -> CacheResult<GuildEntity> {
+        if let Some(entity) = l1::get::<GuildEntity>(&id) {
+            return Ok(entity);
+        }
+
+        let ticket = l1::ticket::<GuildEntity>();
+
         let pinned = Pin::static_ref(&DATABASE_POOL).await;
         let pooled = pinned.get().await?;
         let client = pooled.client();
@@ -55,7 +105,10 @@ impl Repository<GuildEntity> for CachedGuildRepository {
             .one()
             .await?;
 
-        Ok(GuildEntity::from(data))
+        let entity = GuildEntity::try_from(data)?;
+        l1::insert(&entity, ticket);
+
+        Ok(entity)
     }
 
     #[allow(clippy::cast_possible_wrap)]
@@ -92,6 +145,22 @@ impl Repository<GuildEntity> for CachedGuildRepository {
             )
             .await?;
 
+        l1::invalidate::<GuildEntity>(&entity.id());
+
+        Ok(())
+    }
+
+    async fn delete(&self, id: <GuildEntity as Entity>::Id) -> CacheResult<()> {
+        let pinned = Pin::static_ref(&DATABASE_POOL).await;
+        let pooled = pinned.get().await?;
+        let client = pooled.client();
+
+        cached_guild_delete_by_id()
+            .bind(client, &id.to_string())
+            .await?;
+
+        l1::invalidate::<GuildEntity>(&id);
+
         Ok(())
     }
 }
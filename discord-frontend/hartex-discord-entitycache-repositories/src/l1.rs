@@ -0,0 +1,673 @@
+/*
+ * SPDX-License-Identifier: AGPL-3.0-only
+ *
+ * This file is part of HarTex.
+ *
+ * HarTex
+ * Copyright (c) 2021-2024 HarTex Project Developers
+ *
+ * HarTex is free software; you can redistribute it and/or modify
+ * it under the terms of the GNU Affero General Public License as published by
+ * the Free Software Foundation; either version 3 of the License, or
+ * (at your option) any later version.
+ *
+ * HarTex is distributed in the hope that it will be useful,
+ * but WITHOUT ANY WARRANTY; without even the implied warranty of
+ * MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+ * GNU Affero General Public License for more details.
+ *
+ * You should have received a copy of the GNU Affero General Public License along
+ * with HarTex. If not, see <https://www.gnu.org/licenses/>.
+ */
+
+//! # L1 Cache
+//!
+//! An optional in-process cache in front of the repositories, holding the most recently used
+//! entities of each type up to a bounded number and for a bounded time, such that hot entities
+//! are not queried from the database on every use.
+//!
+//! The cache is disabled until it is configured with [`configure`]. Entities are discarded from
+//! the cache when they are upserted or deleted through the repositories of this process, or when
+//! an invalidation published by another process is applied with [`apply_invalidation`] or
+//! [`apply_guild_invalidation`]. The invalidations of this process are passed to the publisher set
+//! with [`set_publisher`], if any.
+//!
+//! Entities read from the database are inserted with a [`Ticket`] taken before reading them, such
+//! that an entity invalidated while it is being read is not inserted.
+
+use std::any::Any;
+use std::any::TypeId;
+use std::collections::BTreeMap;
+use std::collections::HashMap;
+use std::collections::VecDeque;
+use std::env;
+use std::hash::Hash;
+use std::sync::LazyLock;
+use std::sync::Mutex;
+use std::sync::OnceLock;
+use std::time::Duration;
+use std::time::Instant;
+
+use hartex_discord_core::discord::model::id::marker::GuildMarker;
+use hartex_discord_core::discord::model::id::Id;
+use hartex_discord_entitycache_core::traits::Entity;
+use hartex_discord_entitycache_entities::channel::ChannelEntity;
+use hartex_discord_entitycache_entities::emoji::EmojiEntity;
+use hartex_discord_entitycache_entities::guild::GuildEntity;
+use hartex_discord_entitycache_entities::member::MemberEntity;
+use hartex_discord_entitycache_entities::message::MessageEntity;
+use hartex_discord_entitycache_entities::role::RoleEntity;
+use hartex_discord_entitycache_entities::thread::ThreadEntity;
+use hartex_discord_entitycache_entities::user::UserEntity;
+use hartex_discord_entitycache_entities::voice_state::VoiceStateEntity;
+use hartex_discord_internal_events::EntityCacheGuildInvalidation;
+use hartex_discord_internal_events::EntityCacheInvalidation;
+use hartex_discord_internal_events::EntityKind;
+use hartex_discord_internal_events::InternalEvent;
+use serde::de::DeserializeOwned;
+use serde::Serialize;
+
+/// The configuration of the cache, set when it is enabled.
+static CONFIGURATION: OnceLock<L1Configuration> = OnceLock::new();
+
+/// The cached entities, keyed by their type.
+static CACHES: LazyLock<Mutex<Caches>> = LazyLock::new(|| Mutex::new(HashMap::new()));
+
+/// The publisher of the invalidations of this process.
+static PUBLISHER: OnceLock<Box<dyn Fn(InternalEvent) + Send + Sync>> = OnceLock::new();
+
+/// The caches of the entities of each type.
+type Caches = HashMap<TypeId, Box<dyn Any + Send>>;
+
+/// The configuration of the cache.
+#[derive(Clone, Copy, Debug)]
+pub struct L1Configuration {
+    /// The maximum number of entities of each type held.
+    pub capacity: usize,
+    /// The time after which a held entity is discarded.
+    pub ttl: Duration,
+}
+
+impl L1Configuration {
+    /// The time after which a held entity is discarded, unless configured otherwise.
+    pub const DEFAULT_TTL: Duration = Duration::from_secs(60);
+
+    /// Reads the configuration from the `ENTITYCACHE_L1_CAPACITY` and `ENTITYCACHE_L1_TTL_SECS`
+    /// environment variables, returning `None` if the cache is not to be enabled.
+    #[must_use]
+    pub fn from_env() -> Option<Self> {
+        let capacity = env::var("ENTITYCACHE_L1_CAPACITY")
+            .ok()?
+            .parse::<usize>()
+            .ok()
+            .filter(|capacity| *capacity > 0)?;
+        let ttl = env::var("ENTITYCACHE_L1_TTL_SECS")
+            .ok()
+            .and_then(|ttl| ttl.parse::<u64>().ok())
+            .map_or(Self::DEFAULT_TTL, Duration::from_secs);
+
+        Some(Self { capacity, ttl })
+    }
+}
+
+/// A ticket for inserting an entity into the cache, taken before the entity is read from the
+/// database.
+///
+/// The entity is not inserted if it is invalidated after the ticket is taken, as the entity read
+/// may then be stale, or if the ticket is older than the time after which held entities are
+/// discarded.
+#[derive(Clone, Copy, Debug)]
+pub struct Ticket {
+    issued: Instant,
+    tick: u64,
+}
+
+/// An entity that can be held in the cache.
+pub trait L1Entity:
+    Entity<Id: Clone + Eq + Hash + Send + Serialize + DeserializeOwned + 'static>
+    + Clone
+    + Send
+    + 'static
+{
+    /// The kind of the entity, identifying its type in invalidations.
+    const KIND: EntityKind;
+
+    /// The guild of the entity with an identifier, if determined by the identifier, such that
+    /// the entity is discarded when the entities of its guild are invalidated.
+    #[must_use]
+    fn guild_id(_: &Self::Id) -> Option<Id<GuildMarker>> {
+        None
+    }
+}
+
+impl L1Entity for ChannelEntity {
+    const KIND: EntityKind = EntityKind::Channel;
+}
+
+impl L1Entity for EmojiEntity {
+    const KIND: EntityKind = EntityKind::Emoji;
+}
+
+impl L1Entity for GuildEntity {
+    const KIND: EntityKind = EntityKind::Guild;
+}
+
+impl L1Entity for MemberEntity {
+    const KIND: EntityKind = EntityKind::Member;
+
+    fn guild_id((guild_id, _): &Self::Id) -> Option<Id<GuildMarker>> {
+        Some(*guild_id)
+    }
+}
+
+impl L1Entity for MessageEntity {
+    const KIND: EntityKind = EntityKind::Message;
+}
+
+impl L1Entity for RoleEntity {
+    const KIND: EntityKind = EntityKind::Role;
+
+    fn guild_id((guild_id, _): &Self::Id) -> Option<Id<GuildMarker>> {
+        Some(*guild_id)
+    }
+}
+
+impl L1Entity for ThreadEntity {
+    const KIND: EntityKind = EntityKind::Thread;
+}
+
+impl L1Entity for UserEntity {
+    const KIND: EntityKind = EntityKind::User;
+}
+
+impl L1Entity for VoiceStateEntity {
+    const KIND: EntityKind = EntityKind::VoiceState;
+
+    fn guild_id((guild_id, _): &Self::Id) -> Option<Id<GuildMarker>> {
+        Some(*guild_id)
+    }
+}
+
+/// Enables the cache with a configuration.
+///
+/// The cache can only be configured once; later configurations are ignored.
+pub fn configure(configuration: L1Configuration) {
+    let _ = CONFIGURATION.set(configuration);
+}
+
+/// Sets the publisher of the invalidations of this process.
+///
+/// The publisher can only be set once; later publishers are ignored.
+pub fn set_publisher(publisher: impl Fn(InternalEvent) + Send + Sync + 'static) {
+    let _ = PUBLISHER.set(Box::new(publisher));
+}
+
+/// Retrieves an entity from the cache, if held and not expired.
+///
+/// # Panics
+///
+/// Panics when the cache lock is poisoned.
+#[must_use]
+pub fn get<T: L1Entity>(id: &T::Id) -> Option<T> {
+    CONFIGURATION.get()?;
+
+    CACHES
+        .lock()
+        .unwrap()
+        .get_mut(&TypeId::of::<T>())
+        .and_then(|cache| cache.downcast_mut::<Lru<T::Id, T>>())
+        .and_then(|cache| cache.get(id))
+}
+
+/// Takes a ticket for inserting an entity, to be taken before the entity is read from the
+/// database.
+///
+/// # Panics
+///
+/// Panics when the cache lock is poisoned.
+#[must_use]
+pub fn ticket<T: L1Entity>() -> Ticket {
+    let Some(configuration) = CONFIGURATION.get() else {
+        return Ticket {
+            issued: Instant::now(),
+            tick: 0,
+        };
+    };
+
+    // the cache is created here such that invalidations are recorded until the insertion
+    cache::<T>(&mut CACHES.lock().unwrap(), *configuration).ticket()
+}
+
+/// Holds an entity read from the database in the cache, evicting the least recently used entity
+/// of its type if the capacity is reached.
+///
+/// The entity is not held if it was invalidated after the ticket was taken.
+///
+/// # Panics
+///
+/// Panics when the cache lock is poisoned.
+pub fn insert<T: L1Entity>(entity: &T, ticket: Ticket) {
+    let Some(configuration) = CONFIGURATION.get() else {
+        return;
+    };
+
+    cache::<T>(&mut CACHES.lock().unwrap(), *configuration).insert(
+        entity.id(),
+        entity.clone(),
+        ticket,
+    );
+}
+
+/// Discards an entity from the cache and publishes its invalidation.
+///
+/// This is called by the repositories after the entity is upserted or deleted.
+pub fn invalidate<T: L1Entity>(id: &T::Id) {
+    remove::<T>(id);
+
+    let Some(publisher) = PUBLISHER.get() else {
+        return;
+    };
+
+    if let Ok(id) = serde_json::to_value(id) {
+        publisher(InternalEvent::EntityCacheInvalidation(
+            EntityCacheInvalidation { kind: T::KIND, id },
+        ));
+    }
+}
+
+/// Discards the entities of a guild from the cache and publishes their invalidation.
+///
+/// This is called by the repositories after the entities of a guild are deleted, in place of
+/// invalidating every entity.
+pub fn invalidate_guild<T: L1Entity>(guild_id: Id<GuildMarker>) {
+    remove_guild::<T>(guild_id);
+
+    let Some(publisher) = PUBLISHER.get() else {
+        return;
+    };
+
+    if let Ok(guild_id) = serde_json::to_value(guild_id) {
+        publisher(InternalEvent::EntityCacheGuildInvalidation(
+            EntityCacheGuildInvalidation {
+                kind: T::KIND,
+                guild_id,
+            },
+        ));
+    }
+}
+
+/// Applies an invalidation published by another process, discarding the entity from the cache.
+///
+/// # Errors
+///
+/// Returns an error if the identifier of the invalidated entity cannot be deserialized.
+pub fn apply_invalidation(invalidation: &EntityCacheInvalidation) -> serde_json::Result<()> {
+    fn apply<T: L1Entity>(id: &serde_json::Value) -> serde_json::Result<()> {
+        remove::<T>(&serde_json::from_value(id.clone())?);
+
+        Ok(())
+    }
+
+    match invalidation.kind {
+        EntityKind::Channel => apply::<ChannelEntity>(&invalidation.id),
+        EntityKind::Emoji => apply::<EmojiEntity>(&invalidation.id),
+        EntityKind::Guild => apply::<GuildEntity>(&invalidation.id),
+        EntityKind::Member => apply::<MemberEntity>(&invalidation.id),
+        EntityKind::Message => apply::<MessageEntity>(&invalidation.id),
+        EntityKind::Role => apply::<RoleEntity>(&invalidation.id),
+        EntityKind::Thread => apply::<ThreadEntity>(&invalidation.id),
+        EntityKind::User => apply::<UserEntity>(&invalidation.id),
+        EntityKind::VoiceState => apply::<VoiceStateEntity>(&invalidation.id),
+    }
+}
+
+/// Applies a guild invalidation published by another process, discarding the entities of the
+/// guild from the cache.
+///
+/// # Errors
+///
+/// Returns an error if the identifier of the guild cannot be deserialized.
+pub fn apply_guild_invalidation(
+    invalidation: &EntityCacheGuildInvalidation,
+) -> serde_json::Result<()> {
+    let guild_id = serde_json::from_value(invalidation.guild_id.clone())?;
+
+    match invalidation.kind {
+        EntityKind::Channel => remove_guild::<ChannelEntity>(guild_id),
+        EntityKind::Emoji => remove_guild::<EmojiEntity>(guild_id),
+        EntityKind::Guild => remove_guild::<GuildEntity>(guild_id),
+        EntityKind::Member => remove_guild::<MemberEntity>(guild_id),
+        EntityKind::Message => remove_guild::<MessageEntity>(guild_id),
+        EntityKind::Role => remove_guild::<RoleEntity>(guild_id),
+        EntityKind::Thread => remove_guild::<ThreadEntity>(guild_id),
+        EntityKind::User => remove_guild::<UserEntity>(guild_id),
+        EntityKind::VoiceState => remove_guild::<VoiceStateEntity>(guild_id),
+    }
+
+    Ok(())
+}
+
+/// The cache of the entities of a type, created if absent.
+fn cache<T: L1Entity>(caches: &mut Caches, configuration: L1Configuration) -> &mut Lru<T::Id, T> {
+    caches
+        .entry(TypeId::of::<T>())
+        .or_insert_with(|| Box::new(Lru::<T::Id, T>::new(configuration, T::guild_id)))
+        .downcast_mut::<Lru<T::Id, T>>()
+        .unwrap()
+}
+
+fn remove_guild<T: L1Entity>(guild_id: Id<GuildMarker>) {
+    let Some(configuration) = CONFIGURATION.get() else {
+        return;
+    };
+
+    // the cache is created if absent such that the invalidation is recorded for outstanding
+    // tickets
+    cache::<T>(&mut CACHES.lock().unwrap(), *configuration).invalidate_guild(guild_id);
+}
+
+fn remove<T: L1Entity>(id: &T::Id) {
+    if CONFIGURATION.get().is_none() {
+        return;
+    }
+
+    if let Some(cache) = CACHES
+        .lock()
+        .unwrap()
+        .get_mut(&TypeId::of::<T>())
+        .and_then(|cache| cache.downcast_mut::<Lru<T::Id, T>>())
+    {
+        cache.invalidate(id);
+    }
+}
+
+/// What an invalidation applies to.
+enum Invalidated<K> {
+    /// A single entry.
+    Key(K),
+    /// The entries of a guild.
+    Guild(Id<GuildMarker>),
+}
+
+/// A size-bounded least recently used cache with expiring entries.
+struct Lru<K, V> {
+    configuration: L1Configuration,
+    /// The entries, with the time they were inserted and the tick they were last used at.
+    entries: HashMap<K, (V, Instant, u64)>,
+    /// Determines the guild of the entry with a key, if any.
+    guild_of: fn(&K) -> Option<Id<GuildMarker>>,
+    /// The ticks the guilds were last invalidated at, for as long as a ticket may be outstanding.
+    guild_invalidations: HashMap<Id<GuildMarker>, u64>,
+    /// The ticks the keys were last invalidated at, for as long as a ticket may be outstanding.
+    invalidations: HashMap<K, u64>,
+    /// The invalidations, in the order they happened, with the time they happened at.
+    invalidation_order: VecDeque<(Instant, u64, Invalidated<K>)>,
+    /// The keys of the entries, by the tick they were last used at.
+    order: BTreeMap<u64, K>,
+    tick: u64,
+}
+
+impl<K: Clone + Eq + Hash, V: Clone> Lru<K, V> {
+    fn new(configuration: L1Configuration, guild_of: fn(&K) -> Option<Id<GuildMarker>>) -> Self {
+        Self {
+            configuration,
+            entries: HashMap::new(),
+            guild_of,
+            guild_invalidations: HashMap::new(),
+            invalidations: HashMap::new(),
+            invalidation_order: VecDeque::new(),
+            order: BTreeMap::new(),
+            tick: 0,
+        }
+    }
+
+    fn ticket(&mut self) -> Ticket {
+        self.tick += 1;
+
+        Ticket {
+            issued: Instant::now(),
+            tick: self.tick,
+        }
+    }
+
+    fn get(&mut self, key: &K) -> Option<V> {
+        let (_, inserted, _) = self.entries.get(key)?;
+        if inserted.elapsed() > self.configuration.ttl {
+            self.remove(key);
+            return None;
+        }
+
+        self.tick += 1;
+        let (value, _, used) = self.entries.get_mut(key)?;
+        self.order.remove(used);
+        *used = self.tick;
+        self.order.insert(self.tick, key.clone());
+
+        Some(value.clone())
+    }
+
+    fn insert(&mut self, key: K, value: V, ticket: Ticket) {
+        self.prune_invalidations();
+
+        // tickets older than the expiry of entries are no longer covered by the invalidations
+        // kept, and entities invalidated since the ticket was taken may be stale
+        if ticket.issued.elapsed() > self.configuration.ttl
+            || self
+                .invalidations
+                .get(&key)
+                .is_some_and(|tick| *tick >= ticket.tick)
+            || (self.guild_of)(&key)
+                .and_then(|guild_id| self.guild_invalidations.get(&guild_id))
+                .is_some_and(|tick| *tick >= ticket.tick)
+        {
+            return;
+        }
+
+        self.remove(&key);
+
+        while self.entries.len() >= self.configuration.capacity {
+            let Some((_, evicted)) = self.order.pop_first() else {
+                break;
+            };
+            self.entries.remove(&evicted);
+        }
+
+        self.tick += 1;
+        self.order.insert(self.tick, key.clone());
+        self.entries.insert(key, (value, Instant::now(), self.tick));
+    }
+
+    /// Discards an entry, rejecting the insertions of tickets taken before.
+    fn invalidate(&mut self, key: &K) {
+        self.remove(key);
+        self.prune_invalidations();
+
+        self.tick += 1;
+        self.invalidations.insert(key.clone(), self.tick);
+        self.invalidation_order.push_back((
+            Instant::now(),
+            self.tick,
+            Invalidated::Key(key.clone()),
+        ));
+    }
+
+    /// Discards the entries of a guild, rejecting the insertions of tickets taken before.
+    fn invalidate_guild(&mut self, guild_id: Id<GuildMarker>) {
+        let keys = self
+            .entries
+            .keys()
+            .filter(|key| (self.guild_of)(key) == Some(guild_id))
+            .cloned()
+            .collect::<Vec<_>>();
+        for key in &keys {
+            self.remove(key);
+        }
+        self.prune_invalidations();
+
+        self.tick += 1;
+        self.guild_invalidations.insert(guild_id, self.tick);
+        self.invalidation_order.push_back((
+            Instant::now(),
+            self.tick,
+            Invalidated::Guild(guild_id),
+        ));
+    }
+
+    fn remove(&mut self, key: &K) {
+        if let Some((_, _, used)) = self.entries.remove(key) {
+            self.order.remove(&used);
+        }
+    }
+
+    /// Forgets the invalidations older than the expiry of entries, against which no ticket can
+    /// be inserted anymore.
+    fn prune_invalidations(&mut self) {
+        while self
+            .invalidation_order
+            .front()
+            .is_some_and(|(invalidated, _, _)| invalidated.elapsed() > self.configuration.ttl)
+        {
+            let Some((_, tick, invalidated)) = self.invalidation_order.pop_front() else {
+                break;
+            };
+
+            match invalidated {
+                Invalidated::Key(key) if self.invalidations.get(&key) == Some(&tick) => {
+                    self.invalidations.remove(&key);
+                }
+                Invalidated::Guild(guild_id)
+                    if self.guild_invalidations.get(&guild_id) == Some(&tick) =>
+                {
+                    self.guild_invalidations.remove(&guild_id);
+                }
+                _ => {}
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::thread;
+    use std::time::Duration;
+
+    use hartex_discord_core::discord::model::id::marker::GuildMarker;
+    use hartex_discord_core::discord::model::id::Id;
+
+    use super::L1Configuration;
+    use super::Lru;
+
+    fn lru(capacity: usize, ttl: Duration) -> Lru<u64, &'static str> {
+        Lru::new(L1Configuration { capacity, ttl }, |_| None)
+    }
+
+    #[test]
+    fn evicts_least_recently_used_entry() {
+        let mut lru = lru(2, L1Configuration::DEFAULT_TTL);
+        let ticket = lru.ticket();
+        lru.insert(1, "one", ticket);
+        lru.insert(2, "two", ticket);
+
+        // using the first entry makes the second the least recently used
+        assert_eq!(lru.get(&1), Some("one"));
+        lru.insert(3, "three", ticket);
+
+        assert_eq!(lru.get(&1), Some("one"));
+        assert_eq!(lru.get(&2), None);
+        assert_eq!(lru.get(&3), Some("three"));
+    }
+
+    #[test]
+    fn reinserting_entry_does_not_evict() {
+        let mut lru = lru(2, L1Configuration::DEFAULT_TTL);
+        let ticket = lru.ticket();
+        lru.insert(1, "one", ticket);
+        lru.insert(2, "two", ticket);
+        lru.insert(2, "deux", ticket);
+
+        assert_eq!(lru.get(&1), Some("one"));
+        assert_eq!(lru.get(&2), Some("deux"));
+    }
+
+    #[test]
+    fn expires_entries_after_ttl() {
+        let mut lru = lru(2, Duration::from_millis(20));
+        let ticket = lru.ticket();
+        lru.insert(1, "one", ticket);
+        assert_eq!(lru.get(&1), Some("one"));
+
+        thread::sleep(Duration::from_millis(40));
+
+        assert_eq!(lru.get(&1), None);
+        assert!(lru.entries.is_empty());
+        assert!(lru.order.is_empty());
+    }
+
+    #[test]
+    fn rejects_insertions_of_tickets_taken_before_invalidation() {
+        let mut lru = lru(2, L1Configuration::DEFAULT_TTL);
+        let stale = lru.ticket();
+        lru.invalidate(&1);
+        lru.insert(1, "stale", stale);
+        assert_eq!(lru.get(&1), None);
+
+        // other entries are unaffected by the invalidation
+        lru.insert(2, "two", stale);
+        assert_eq!(lru.get(&2), Some("two"));
+
+        let fresh = lru.ticket();
+        lru.insert(1, "fresh", fresh);
+        assert_eq!(lru.get(&1), Some("fresh"));
+    }
+
+    #[test]
+    fn rejects_insertions_of_expired_tickets() {
+        let mut lru = lru(2, Duration::from_millis(20));
+        let ticket = lru.ticket();
+
+        thread::sleep(Duration::from_millis(40));
+
+        lru.insert(1, "one", ticket);
+        assert_eq!(lru.get(&1), None);
+    }
+
+    #[test]
+    fn forgets_expired_invalidations() {
+        let mut lru = lru(2, Duration::from_millis(20));
+        lru.invalidate(&1);
+        assert_eq!(lru.invalidations.len(), 1);
+
+        thread::sleep(Duration::from_millis(40));
+
+        lru.invalidate(&2);
+        assert_eq!(lru.invalidations.len(), 1);
+        assert_eq!(lru.invalidation_order.len(), 1);
+    }
+
+    #[test]
+    fn invalidates_entries_of_guild() {
+        let mut lru = Lru::<(u64, u64), &str>::new(
+            L1Configuration {
+                capacity: 4,
+                ttl: L1Configuration::DEFAULT_TTL,
+            },
+            |(guild_id, _)| Id::<GuildMarker>::new_checked(*guild_id),
+        );
+        let stale = lru.ticket();
+        lru.insert((1, 1), "one", stale);
+        lru.insert((1, 2), "two", stale);
+        lru.insert((2, 1), "other", stale);
+
+        lru.invalidate_guild(Id::new(1));
+        assert_eq!(lru.get(&(1, 1)), None);
+        assert_eq!(lru.get(&(1, 2)), None);
+        assert_eq!(lru.get(&(2, 1)), Some("other"));
+
+        lru.insert((1, 3), "stale", stale);
+        assert_eq!(lru.get(&(1, 3)), None);
+
+        let fresh = lru.ticket();
+        lru.insert((1, 3), "fresh", fresh);
+        assert_eq!(lru.get(&(1, 3)), Some("fresh"));
+    }
+}
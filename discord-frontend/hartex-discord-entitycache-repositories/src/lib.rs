@@ -27,8 +27,15 @@
 #![deny(unsafe_code)]
 #![deny(warnings)]
 
+pub mod backend;
+pub mod channel;
 pub mod emoji;
 pub mod guild;
+pub mod l1;
 pub mod member;
+pub mod memory;
+pub mod message;
 pub mod role;
+pub mod thread;
 pub mod user;
+pub mod voice_state;
@@ -21,9 +21,18 @@
  */
 
 use std::pin::Pin;
+use std::str::FromStr;
 
+use async_trait::async_trait;
+use hartex_database_queries::discord_frontend::queries::cached_member_delete_by_guild_id::cached_member_delete_by_guild_id;
+use hartex_database_queries::discord_frontend::queries::cached_member_delete_by_user_id_and_guild_id::cached_member_delete_by_user_id_and_guild_id;
+use hartex_database_queries::discord_frontend::queries::cached_member_remove_role::cached_member_remove_role;
 use hartex_database_queries::discord_frontend::queries::cached_member_select_by_user_id_and_guild_id::cached_member_select_by_user_id_and_guild_id;
 use hartex_database_queries::discord_frontend::queries::cached_member_upsert::cached_member_upsert;
+use hartex_discord_core::discord::model::id::marker::GuildMarker;
+use hartex_discord_core::discord::model::id::marker::RoleMarker;
+use hartex_discord_core::discord::model::id::marker::UserMarker;
+use hartex_discord_core::discord::model::id::Id;
 use hartex_discord_entitycache_core::error::CacheResult;
 use hartex_discord_entitycache_core::traits::Entity;
 use hartex_discord_entitycache_core::traits::Repository;
@@ -32,15 +41,86 @@ use hartex_discord_utils::DATABASE_POOL;
 use time::OffsetDateTime;
 use tokio_postgres::GenericClient;
 
+use crate::backend;
+use crate::backend::Backend;
+use crate::backend::DatabaseBackend;
+use crate::l1;
+
 /// Repository for member entities.
 pub struct CachedMemberRepository;
 
+impl CachedMemberRepository {
+    /// Deletes every member of a guild.
+    #[allow(clippy::missing_errors_doc)]
+    pub async fn delete_by_guild_id(&self, guild_id: Id<GuildMarker>) -> CacheResult<()> {
+        let pinned = Pin::static_ref(&DATABASE_POOL).await;
+        let pooled = pinned.get().await?;
+        let client = pooled.client();
+
+        cached_member_delete_by_guild_id()
+            .bind(client, &guild_id.to_string())
+            .await?;
+
+        l1::invalidate_guild::<MemberEntity>(guild_id);
+
+        Ok(())
+    }
+
+    /// Removes a role from every member of a guild having it.
+    #[allow(clippy::missing_errors_doc)]
+    #[allow(clippy::missing_panics_doc)]
+    pub async fn remove_role(
+        &self,
+        guild_id: Id<GuildMarker>,
+        role_id: Id<RoleMarker>,
+    ) -> CacheResult<()> {
+        let pinned = Pin::static_ref(&DATABASE_POOL).await;
+        let pooled = pinned.get().await?;
+        let client = pooled.client();
+
+        let user_ids = cached_member_remove_role()
+            .bind(client, &role_id.to_string(), &guild_id.to_string())
+            .all()
+            .await?;
+
+        for user_id in user_ids {
+            l1::invalidate::<MemberEntity>(&(
+                guild_id,
+                Id::<UserMarker>::from_str(&user_id).unwrap(),
+            ));
+        }
+
+        Ok(())
+    }
+}
+
 impl Repository<MemberEntity> for CachedMemberRepository {
+    async fn get(&self, id: <MemberEntity as Entity>::Id) -> CacheResult<MemberEntity> {
+        Backend::<MemberEntity>::get(backend::backend(), id).await
+    }
+
+    async fn upsert(&self, entity: MemberEntity) -> CacheResult<()> {
+        Backend::<MemberEntity>::upsert(backend::backend(), entity).await
+    }
+
+    async fn delete(&self, id: <MemberEntity as Entity>::Id) -> CacheResult<()> {
+        Backend::<MemberEntity>::delete(backend::backend(), id).await
+    }
+}
+
+#[async_trait]
+impl Backend<MemberEntity> for DatabaseBackend {
     #[allow(clippy::cast_sign_loss)]
     async fn get(
         &self,
         (guild_id, user_id): <MemberEntity as Entity>::Id,
     ) -> CacheResult<MemberEntity> {
+        if let Some(entity) = l1::get::<MemberEntity>(&(guild_id, user_id)) {
+            return Ok(entity);
+        }
+
+        let ticket = l1::ticket::<MemberEntity>();
+
         let pinned = Pin::static_ref(&DATABASE_POOL).await;
         let pooled = pinned.get().await?;
         let client = pooled.client();
@@ -50,7 +130,10 @@ impl Repository<MemberEntity> for CachedMemberRepository {
             .one()
             .await?;
 
-        Ok(MemberEntity::from(data))
+        let entity = MemberEntity::try_from(data)?;
+        l1::insert(&entity, ticket);
+
+        Ok(entity)
     }
 
     #[allow(clippy::cast_possible_wrap)]
@@ -62,6 +145,7 @@ impl Repository<MemberEntity> for CachedMemberRepository {
         cached_member_upsert()
             .bind(
                 client,
+                &entity.avatar.map(|hash| hash.to_string()),
                 &(entity.flags.bits() as i64),
                 &entity.joined_at.map(|timestamp| {
                     OffsetDateTime::from_unix_timestamp(timestamp.as_secs()).unwrap()
@@ -77,6 +161,22 @@ impl Repository<MemberEntity> for CachedMemberRepository {
             )
             .await?;
 
+        l1::invalidate::<MemberEntity>(&entity.id());
+
+        Ok(())
+    }
+
+    async fn delete(&self, (guild_id, user_id): <MemberEntity as Entity>::Id) -> CacheResult<()> {
+        let pinned = Pin::static_ref(&DATABASE_POOL).await;
+        let pooled = pinned.get().await?;
+        let client = pooled.client();
+
+        cached_member_delete_by_user_id_and_guild_id()
+            .bind(client, &user_id.to_string(), &guild_id.to_string())
+            .await?;
+
+        l1::invalidate::<MemberEntity>(&(guild_id, user_id));
+
         Ok(())
     }
 }
@@ -0,0 +1,150 @@
+/*
+ * SPDX-License-Identifier: AGPL-3.0-only
+ *
+ * This file is part of HarTex.
+ *
+ * HarTex
+ * Copyright (c) 2021-2024 HarTex Project Developers
+ *
+ * HarTex is free software; you can redistribute it and/or modify
+ * it under the terms of the GNU Affero General Public License as published by
+ * the Free Software Foundation; either version 3 of the License, or
+ * (at your option) any later version.
+ *
+ * HarTex is distributed in the hope that it will be useful,
+ * but WITHOUT ANY WARRANTY; without even the implied warranty of
+ * MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+ * GNU Affero General Public License for more details.
+ *
+ * You should have received a copy of the GNU Affero General Public License along
+ * with HarTex. If not, see <https://www.gnu.org/licenses/>.
+ */
+
+//! # In-Memory Repository
+//!
+//! An in-memory repository holding entities of every type, such that code reading from the
+//! entity cache can be tested without a database.
+//!
+//! Installing the in-memory repository with [`install`] is meant for test harnesses only, and must
+//! happen before the entity cache is first used.
+
+use std::any::Any;
+use std::any::TypeId;
+use std::collections::HashMap;
+use std::hash::Hash;
+use std::sync::Mutex;
+use std::sync::OnceLock;
+
+use async_trait::async_trait;
+use hartex_discord_core::discord::model::id::marker::GuildMarker;
+use hartex_discord_core::discord::model::id::Id;
+use hartex_discord_entitycache_core::error::CacheError;
+use hartex_discord_entitycache_core::error::CacheResult;
+use hartex_discord_entitycache_core::traits::Entity;
+
+use crate::backend;
+use crate::backend::Backend;
+use crate::backend::GuildBackend;
+use crate::backend::GuildScoped;
+
+/// The in-memory repository, once installed.
+static INSTALLED: OnceLock<MemoryRepository> = OnceLock::new();
+
+/// A repository holding entities of every type in memory.
+#[derive(Default)]
+pub struct MemoryRepository {
+    /// The entities in the repository, keyed by their type.
+    entities: Mutex<HashMap<TypeId, Box<dyn Any + Send>>>,
+}
+
+impl MemoryRepository {
+    /// Removes every entity from the repository.
+    ///
+    /// # Panics
+    ///
+    /// Panics when the repository lock is poisoned.
+    pub fn clear(&self) {
+        self.entities.lock().unwrap().clear();
+    }
+}
+
+#[async_trait]
+impl<T> Backend<T> for MemoryRepository
+where
+    T: Entity + Clone + Send + 'static,
+    T::Id: Eq + Hash + Send + 'static,
+{
+    async fn get(&self, id: T::Id) -> CacheResult<T> {
+        self.entities
+            .lock()
+            .unwrap()
+            .get(&TypeId::of::<T>())
+            .and_then(|entities| entities.downcast_ref::<HashMap<T::Id, T>>())
+            .and_then(|entities| entities.get(&id))
+            .cloned()
+            .ok_or(CacheError::NotFound)
+    }
+
+    async fn upsert(&self, entity: T) -> CacheResult<()> {
+        self.entities
+            .lock()
+            .unwrap()
+            .entry(TypeId::of::<T>())
+            .or_insert_with(|| Box::new(HashMap::<T::Id, T>::new()))
+            .downcast_mut::<HashMap<T::Id, T>>()
+            .unwrap()
+            .insert(entity.id(), entity);
+
+        Ok(())
+    }
+
+    async fn delete(&self, id: T::Id) -> CacheResult<()> {
+        if let Some(entities) = self
+            .entities
+            .lock()
+            .unwrap()
+            .get_mut(&TypeId::of::<T>())
+            .and_then(|entities| entities.downcast_mut::<HashMap<T::Id, T>>())
+        {
+            entities.remove(&id);
+        }
+
+        Ok(())
+    }
+}
+
+#[async_trait]
+impl<T> GuildBackend<T> for MemoryRepository
+where
+    T: GuildScoped + Clone + Send + 'static,
+    T::Id: Eq + Hash + Send + Sync + 'static,
+{
+    async fn retain_in_guild(&self, guild_id: Id<GuildMarker>, ids: &[T::Id]) -> CacheResult<()> {
+        if let Some(entities) = self
+            .entities
+            .lock()
+            .unwrap()
+            .get_mut(&TypeId::of::<T>())
+            .and_then(|entities| entities.downcast_mut::<HashMap<T::Id, T>>())
+        {
+            entities.retain(|id, entity| entity.guild_id() != guild_id || ids.contains(id));
+        }
+
+        Ok(())
+    }
+}
+
+/// Installs the in-memory repository as the entity cache backend, returning it.
+///
+/// The in-memory repository stays installed for the rest of the process; installing it again
+/// returns the repository installed first.
+///
+/// # Panics
+///
+/// Panics when another entity cache backend is already in use.
+pub fn install() -> &'static MemoryRepository {
+    let memory = INSTALLED.get_or_init(MemoryRepository::default);
+    backend::install(memory);
+
+    memory
+}
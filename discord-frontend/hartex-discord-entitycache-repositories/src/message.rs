@@ -0,0 +1,194 @@
+/*
+ * SPDX-License-Identifier: AGPL-3.0-only
+ *
+ * This file is part of HarTex.
+ *
+ * HarTex
+ * Copyright (c) 2021-2024 HarTex Project Developers
+ *
+ * HarTex is free software; you can redistribute it and/or modify
+ * it under the terms of the GNU Affero General Public License as published by
+ * the Free Software Foundation; either version 3 of the License, or
+ * (at your option) any later version.
+ *
+ * HarTex is distributed in the hope that it will be useful,
+ * but WITHOUT ANY WARRANTY; without even the implied warranty of
+ * MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+ * GNU Affero General Public License for more details.
+ *
+ * You should have received a copy of the GNU Affero General Public License along
+ * with HarTex. If not, see <https://www.gnu.org/licenses/>.
+ */
+
+//! # Message Repository
+//!
+//! Messages are cached with a bounded retention policy: at most [`MAX_MESSAGES_PER_CHANNEL`]
+//! messages are kept per channel, and messages older than [`MAX_MESSAGE_AGE`] are periodically
+//! pruned with [`CachedMessageRepository::prune_expired`].
+
+use std::pin::Pin;
+use std::time::Duration;
+
+use async_trait::async_trait;
+use hartex_database_queries::discord_frontend::queries::cached_message_delete_by_id::cached_message_delete_by_id;
+use hartex_database_queries::discord_frontend::queries::cached_message_mark_deleted::cached_message_mark_deleted;
+use hartex_database_queries::discord_frontend::queries::cached_message_prune_before::cached_message_prune_before;
+use hartex_database_queries::discord_frontend::queries::cached_message_prune_by_channel_id::cached_message_prune_by_channel_id;
+use hartex_database_queries::discord_frontend::queries::cached_message_select_by_id::cached_message_select_by_id;
+use hartex_database_queries::discord_frontend::queries::cached_message_upsert::cached_message_upsert;
+use hartex_discord_core::discord::model::id::marker::MessageMarker;
+use hartex_discord_core::discord::model::id::Id;
+use hartex_discord_entitycache_core::error::CacheResult;
+use hartex_discord_entitycache_core::traits::Entity;
+use hartex_discord_entitycache_core::traits::Repository;
+use hartex_discord_entitycache_entities::message::MessageEntity;
+use hartex_discord_utils::DATABASE_POOL;
+use time::OffsetDateTime;
+use tokio_postgres::GenericClient;
+
+use crate::backend;
+use crate::backend::Backend;
+use crate::backend::DatabaseBackend;
+use crate::l1;
+
+/// The maximum number of messages cached per channel.
+pub const MAX_MESSAGES_PER_CHANNEL: i64 = 500;
+
+/// The maximum age of a cached message.
+pub const MAX_MESSAGE_AGE: Duration = Duration::from_secs(7 * 24 * 60 * 60);
+
+/// Repository for message entities.
+pub struct CachedMessageRepository;
+
+impl CachedMessageRepository {
+    /// Marks messages as deleted.
+    ///
+    /// Deleted messages are kept in the cache until they are pruned, such that their contents
+    /// are still available when logging the deletion.
+    #[allow(clippy::missing_errors_doc)]
+    pub async fn mark_deleted(&self, ids: &[Id<MessageMarker>]) -> CacheResult<()> {
+        let pinned = Pin::static_ref(&DATABASE_POOL).await;
+        let pooled = pinned.get().await?;
+        let client = pooled.client();
+
+        cached_message_mark_deleted()
+            .bind(
+                client,
+                &ids.iter().map(ToString::to_string).collect::<Vec<_>>(),
+            )
+            .await?;
+
+        for id in ids {
+            l1::invalidate::<MessageEntity>(id);
+        }
+
+        Ok(())
+    }
+
+    /// Prunes the messages older than [`MAX_MESSAGE_AGE`].
+    ///
+    /// This scans the entire table and is therefore meant to be run periodically rather than on
+    /// every upsert. Pruned messages may be served from the in-process cache until they expire
+    /// from it.
+    #[allow(clippy::missing_errors_doc)]
+    pub async fn prune_expired(&self) -> CacheResult<u64> {
+        let pinned = Pin::static_ref(&DATABASE_POOL).await;
+        let pooled = pinned.get().await?;
+        let client = pooled.client();
+
+        let pruned = cached_message_prune_before()
+            .bind(client, &(OffsetDateTime::now_utc() - MAX_MESSAGE_AGE))
+            .await?;
+
+        Ok(pruned)
+    }
+}
+
+impl Repository<MessageEntity> for CachedMessageRepository {
+    async fn get(&self, id: <MessageEntity as Entity>::Id) -> CacheResult<MessageEntity> {
+        Backend::<MessageEntity>::get(backend::backend(), id).await
+    }
+
+    async fn upsert(&self, entity: MessageEntity) -> CacheResult<()> {
+        Backend::<MessageEntity>::upsert(backend::backend(), entity).await
+    }
+
+    async fn delete(&self, id: <MessageEntity as Entity>::Id) -> CacheResult<()> {
+        Backend::<MessageEntity>::delete(backend::backend(), id).await
+    }
+}
+
+#[async_trait]
+impl Backend<MessageEntity> for DatabaseBackend {
+    async fn get(&self, id: <MessageEntity as Entity>::Id) -> CacheResult<MessageEntity> {
+        if let Some(entity) = l1::get::<MessageEntity>(&id) {
+            return Ok(entity);
+        }
+
+        let ticket = l1::ticket::<MessageEntity>();
+
+        let pinned = Pin::static_ref(&DATABASE_POOL).await;
+        let pooled = pinned.get().await?;
+        let client = pooled.client();
+
+        let data = cached_message_select_by_id()
+            .bind(client, &id.to_string())
+            .one()
+            .await?;
+
+        let entity = MessageEntity::try_from(data)?;
+        l1::insert(&entity, ticket);
+
+        Ok(entity)
+    }
+
+    async fn upsert(&self, entity: MessageEntity) -> CacheResult<()> {
+        let pinned = Pin::static_ref(&DATABASE_POOL).await;
+        let pooled = pinned.get().await?;
+        let client = pooled.client();
+
+        cached_message_upsert()
+            .bind(
+                client,
+                &entity.id.to_string(),
+                &entity.channel_id.to_string(),
+                &entity.guild_id.map(|id| id.to_string()),
+                &entity.author_id.to_string(),
+                &entity.content,
+                &entity.attachments,
+                &entity.embeds,
+                &OffsetDateTime::from_unix_timestamp(entity.timestamp.as_secs()).unwrap(),
+                &entity.edited_timestamp.map(|timestamp| {
+                    OffsetDateTime::from_unix_timestamp(timestamp.as_secs()).unwrap()
+                }),
+                &entity.deleted,
+            )
+            .await?;
+
+        cached_message_prune_by_channel_id()
+            .bind(
+                client,
+                &entity.channel_id.to_string(),
+                &MAX_MESSAGES_PER_CHANNEL,
+            )
+            .await?;
+
+        l1::invalidate::<MessageEntity>(&entity.id());
+
+        Ok(())
+    }
+
+    async fn delete(&self, id: <MessageEntity as Entity>::Id) -> CacheResult<()> {
+        let pinned = Pin::static_ref(&DATABASE_POOL).await;
+        let pooled = pinned.get().await?;
+        let client = pooled.client();
+
+        cached_message_delete_by_id()
+            .bind(client, &id.to_string())
+            .await?;
+
+        l1::invalidate::<MessageEntity>(&id);
+
+        Ok(())
+    }
+}
@@ -23,6 +23,10 @@
 use std::pin::Pin;
 use std::str::FromStr;
 
+use async_trait::async_trait;
+use hartex_database_queries::discord_frontend::queries::cached_role_delete_by_guild_id::cached_role_delete_by_guild_id;
+use hartex_database_queries::discord_frontend::queries::cached_role_delete_by_guild_id_except_ids::cached_role_delete_by_guild_id_except_ids;
+use hartex_database_queries::discord_frontend::queries::cached_role_delete_by_id_and_guild_id::cached_role_delete_by_id_and_guild_id;
 use hartex_database_queries::discord_frontend::queries::cached_role_select_by_guild_id::cached_role_select_by_guild_id;
 use hartex_database_queries::discord_frontend::queries::cached_role_select_by_id_and_guild_id::cached_role_select_by_id_and_guild_id;
 use hartex_database_queries::discord_frontend::queries::cached_role_upsert::cached_role_upsert;
@@ -36,10 +40,44 @@ use hartex_discord_entitycache_entities::role::RoleEntity;
 use hartex_discord_utils::DATABASE_POOL;
 use tokio_postgres::GenericClient;
 
+use crate::backend;
+use crate::backend::Backend;
+use crate::backend::DatabaseBackend;
+use crate::backend::GuildBackend;
+use crate::l1;
+
 /// Repository for role entities.
 pub struct CachedRoleRepository;
 
 impl CachedRoleRepository {
+    /// Deletes every role of a guild.
+    #[allow(clippy::missing_errors_doc)]
+    pub async fn delete_by_guild_id(&self, guild_id: Id<GuildMarker>) -> CacheResult<()> {
+        let pinned = Pin::static_ref(&DATABASE_POOL).await;
+        let pooled = pinned.get().await?;
+        let client = pooled.client();
+
+        cached_role_delete_by_guild_id()
+            .bind(client, &guild_id.to_string())
+            .await?;
+
+        l1::invalidate_guild::<RoleEntity>(guild_id);
+
+        Ok(())
+    }
+
+    /// Deletes every role of a guild other than the given ones.
+    #[allow(clippy::missing_errors_doc)]
+    pub async fn retain_in_guild(
+        &self,
+        guild_id: Id<GuildMarker>,
+        ids: &[Id<RoleMarker>],
+    ) -> CacheResult<()> {
+        let ids = ids.iter().map(|id| (guild_id, *id)).collect::<Vec<_>>();
+
+        GuildBackend::<RoleEntity>::retain_in_guild(backend::backend(), guild_id, &ids).await
+    }
+
     // todo: add relationship to get all roles from a guild
     #[allow(clippy::missing_errors_doc)]
     #[allow(clippy::missing_panics_doc)]
@@ -64,10 +102,31 @@ impl CachedRoleRepository {
 }
 
 impl Repository<RoleEntity> for CachedRoleRepository {
+    async fn get(&self, id: <RoleEntity as Entity>::Id) -> CacheResult<RoleEntity> {
+        Backend::<RoleEntity>::get(backend::backend(), id).await
+    }
+
+    async fn upsert(&self, entity: RoleEntity) -> CacheResult<()> {
+        Backend::<RoleEntity>::upsert(backend::backend(), entity).await
+    }
+
+    async fn delete(&self, id: <RoleEntity as Entity>::Id) -> CacheResult<()> {
+        Backend::<RoleEntity>::delete(backend::backend(), id).await
+    }
+}
+
+#[async_trait]
+impl Backend<RoleEntity> for DatabaseBackend {
     #[allow(clippy::cast_lossless)]
     #[allow(clippy::cast_possible_truncation)]
     #[allow(clippy::cast_sign_loss)]
     async fn get(&self, (guild_id, id): <RoleEntity as Entity>::Id) -> CacheResult<RoleEntity> {
+        if let Some(entity) = l1::get::<RoleEntity>(&(guild_id, id)) {
+            return Ok(entity);
+        }
+
+        let ticket = l1::ticket::<RoleEntity>();
+
         let pinned = Pin::static_ref(&DATABASE_POOL).await;
         let pooled = pinned.get().await?;
         let client = pooled.client();
@@ -77,7 +136,10 @@ impl Repository<RoleEntity> for CachedRoleRepository {
             .one()
             .await?;
 
-        Ok(RoleEntity::from(data))
+        let entity = RoleEntity::try_from(data)?;
+        l1::insert(&entity, ticket);
+
+        Ok(entity)
     }
 
     #[allow(clippy::cast_lossless)]
@@ -103,6 +165,50 @@ impl Repository<RoleEntity> for CachedRoleRepository {
             )
             .await?;
 
+        l1::invalidate::<RoleEntity>(&entity.id());
+
+        Ok(())
+    }
+
+    async fn delete(&self, (guild_id, id): <RoleEntity as Entity>::Id) -> CacheResult<()> {
+        let pinned = Pin::static_ref(&DATABASE_POOL).await;
+        let pooled = pinned.get().await?;
+        let client = pooled.client();
+
+        cached_role_delete_by_id_and_guild_id()
+            .bind(client, &id.to_string(), &guild_id.to_string())
+            .await?;
+
+        l1::invalidate::<RoleEntity>(&(guild_id, id));
+
+        Ok(())
+    }
+}
+
+#[async_trait]
+impl GuildBackend<RoleEntity> for DatabaseBackend {
+    async fn retain_in_guild(
+        &self,
+        guild_id: Id<GuildMarker>,
+        ids: &[<RoleEntity as Entity>::Id],
+    ) -> CacheResult<()> {
+        let pinned = Pin::static_ref(&DATABASE_POOL).await;
+        let pooled = pinned.get().await?;
+        let client = pooled.client();
+
+        let ids = cached_role_delete_by_guild_id_except_ids()
+            .bind(
+                client,
+                &guild_id.to_string(),
+                &ids.iter().map(|(_, id)| id.to_string()).collect::<Vec<_>>(),
+            )
+            .all()
+            .await?;
+
+        for id in ids {
+            l1::invalidate::<RoleEntity>(&(guild_id, Id::from_str(&id).unwrap()));
+        }
+
         Ok(())
     }
 }
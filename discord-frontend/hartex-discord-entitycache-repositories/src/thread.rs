@@ -0,0 +1,228 @@
+/*
+ * SPDX-License-Identifier: AGPL-3.0-only
+ *
+ * This file is part of HarTex.
+ *
+ * HarTex
+ * Copyright (c) 2021-2024 HarTex Project Developers
+ *
+ * HarTex is free software; you can redistribute it and/or modify
+ * it under the terms of the GNU Affero General Public License as published by
+ * the Free Software Foundation; either version 3 of the License, or
+ * (at your option) any later version.
+ *
+ * HarTex is distributed in the hope that it will be useful,
+ * but WITHOUT ANY WARRANTY; without even the implied warranty of
+ * MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+ * GNU Affero General Public License for more details.
+ *
+ * You should have received a copy of the GNU Affero General Public License along
+ * with HarTex. If not, see <https://www.gnu.org/licenses/>.
+ */
+
+use std::pin::Pin;
+use std::str::FromStr;
+
+use async_trait::async_trait;
+use hartex_database_queries::discord_frontend::queries::cached_thread_delete_by_guild_id::cached_thread_delete_by_guild_id;
+use hartex_database_queries::discord_frontend::queries::cached_thread_delete_by_guild_id_except_ids::cached_thread_delete_by_guild_id_except_ids;
+use hartex_database_queries::discord_frontend::queries::cached_thread_delete_by_id::cached_thread_delete_by_id;
+use hartex_database_queries::discord_frontend::queries::cached_thread_delete_by_parent_id::cached_thread_delete_by_parent_id;
+use hartex_database_queries::discord_frontend::queries::cached_thread_select_by_id::cached_thread_select_by_id;
+use hartex_database_queries::discord_frontend::queries::cached_thread_upsert::cached_thread_upsert;
+use hartex_discord_core::discord::model::channel::ChannelType;
+use hartex_discord_core::discord::model::id::marker::ChannelMarker;
+use hartex_discord_core::discord::model::id::marker::GuildMarker;
+use hartex_discord_core::discord::model::id::Id;
+use hartex_discord_entitycache_core::error::CacheResult;
+use hartex_discord_entitycache_core::traits::Entity;
+use hartex_discord_entitycache_core::traits::Repository;
+use hartex_discord_entitycache_entities::thread::ThreadEntity;
+use hartex_discord_utils::DATABASE_POOL;
+use tokio_postgres::GenericClient;
+
+use crate::backend;
+use crate::backend::Backend;
+use crate::backend::DatabaseBackend;
+use crate::backend::GuildBackend;
+use crate::l1;
+
+/// Repository for thread entities.
+pub struct CachedThreadRepository;
+
+impl CachedThreadRepository {
+    /// Deletes every thread of a guild.
+    #[allow(clippy::missing_errors_doc)]
+    #[allow(clippy::missing_panics_doc)]
+    pub async fn delete_by_guild_id(&self, guild_id: Id<GuildMarker>) -> CacheResult<()> {
+        let pinned = Pin::static_ref(&DATABASE_POOL).await;
+        let pooled = pinned.get().await?;
+        let client = pooled.client();
+
+        let ids = cached_thread_delete_by_guild_id()
+            .bind(client, &guild_id.to_string())
+            .all()
+            .await?;
+
+        for id in ids {
+            l1::invalidate::<ThreadEntity>(&Id::<ChannelMarker>::from_str(&id).unwrap());
+        }
+
+        Ok(())
+    }
+
+    /// Deletes every thread of a channel.
+    #[allow(clippy::missing_errors_doc)]
+    #[allow(clippy::missing_panics_doc)]
+    pub async fn delete_by_parent_id(&self, parent_id: Id<ChannelMarker>) -> CacheResult<()> {
+        let pinned = Pin::static_ref(&DATABASE_POOL).await;
+        let pooled = pinned.get().await?;
+        let client = pooled.client();
+
+        let ids = cached_thread_delete_by_parent_id()
+            .bind(client, &parent_id.to_string())
+            .all()
+            .await?;
+
+        for id in ids {
+            l1::invalidate::<ThreadEntity>(&Id::<ChannelMarker>::from_str(&id).unwrap());
+        }
+
+        Ok(())
+    }
+
+    /// Deletes every thread of a guild other than the given ones.
+    #[allow(clippy::missing_errors_doc)]
+    pub async fn retain_in_guild(
+        &self,
+        guild_id: Id<GuildMarker>,
+        ids: &[Id<ChannelMarker>],
+    ) -> CacheResult<()> {
+        GuildBackend::<ThreadEntity>::retain_in_guild(backend::backend(), guild_id, ids).await
+    }
+
+    /// Deletes every thread of the given parent channels of a guild other than the given ones.
+    #[allow(clippy::missing_errors_doc)]
+    pub async fn retain_in_parents(
+        &self,
+        guild_id: Id<GuildMarker>,
+        parent_ids: &[Id<ChannelMarker>],
+        ids: &[Id<ChannelMarker>],
+    ) -> CacheResult<()> {
+        retain(guild_id, Some(parent_ids), ids).await
+    }
+}
+
+impl Repository<ThreadEntity> for CachedThreadRepository {
+    async fn get(&self, id: <ThreadEntity as Entity>::Id) -> CacheResult<ThreadEntity> {
+        Backend::<ThreadEntity>::get(backend::backend(), id).await
+    }
+
+    async fn upsert(&self, entity: ThreadEntity) -> CacheResult<()> {
+        Backend::<ThreadEntity>::upsert(backend::backend(), entity).await
+    }
+
+    async fn delete(&self, id: <ThreadEntity as Entity>::Id) -> CacheResult<()> {
+        Backend::<ThreadEntity>::delete(backend::backend(), id).await
+    }
+}
+
+#[async_trait]
+impl Backend<ThreadEntity> for DatabaseBackend {
+    async fn get(&self, id: <ThreadEntity as Entity>::Id) -> CacheResult<ThreadEntity> {
+        if let Some(entity) = l1::get::<ThreadEntity>(&id) {
+            return Ok(entity);
+        }
+
+        let ticket = l1::ticket::<ThreadEntity>();
+
+        let pinned = Pin::static_ref(&DATABASE_POOL).await;
+        let pooled = pinned.get().await?;
+        let client = pooled.client();
+
+        let data = cached_thread_select_by_id()
+            .bind(client, &id.to_string())
+            .one()
+            .await?;
+
+        let entity = ThreadEntity::try_from(data)?;
+        l1::insert(&entity, ticket);
+
+        Ok(entity)
+    }
+
+    async fn upsert(&self, entity: ThreadEntity) -> CacheResult<()> {
+        let pinned = Pin::static_ref(&DATABASE_POOL).await;
+        let pooled = pinned.get().await?;
+        let client = pooled.client();
+
+        cached_thread_upsert()
+            .bind(
+                client,
+                &entity.id.to_string(),
+                &entity.guild_id.to_string(),
+                &i16::from(<ChannelType as Into<u8>>::into(entity.kind)),
+                &entity.locked,
+                &entity.name,
+                &entity.owner_id.map(|id| id.to_string()),
+                &entity.parent_id.map(|id| id.to_string()),
+                &entity.rate_limit_per_user.map(i32::from),
+            )
+            .await?;
+
+        l1::invalidate::<ThreadEntity>(&entity.id());
+
+        Ok(())
+    }
+
+    async fn delete(&self, id: <ThreadEntity as Entity>::Id) -> CacheResult<()> {
+        let pinned = Pin::static_ref(&DATABASE_POOL).await;
+        let pooled = pinned.get().await?;
+        let client = pooled.client();
+
+        cached_thread_delete_by_id()
+            .bind(client, &id.to_string())
+            .await?;
+
+        l1::invalidate::<ThreadEntity>(&id);
+
+        Ok(())
+    }
+}
+
+#[async_trait]
+impl GuildBackend<ThreadEntity> for DatabaseBackend {
+    async fn retain_in_guild(
+        &self,
+        guild_id: Id<GuildMarker>,
+        ids: &[Id<ChannelMarker>],
+    ) -> CacheResult<()> {
+        retain(guild_id, None, ids).await
+    }
+}
+
+async fn retain(
+    guild_id: Id<GuildMarker>,
+    parent_ids: Option<&[Id<ChannelMarker>]>,
+    ids: &[Id<ChannelMarker>],
+) -> CacheResult<()> {
+    let pinned = Pin::static_ref(&DATABASE_POOL).await;
+    let pooled = pinned.get().await?;
+    let client = pooled.client();
+
+    let ids = cached_thread_delete_by_guild_id_except_ids()
+        .bind(
+            client,
+            &guild_id.to_string(),
+            &parent_ids.map(|ids| ids.iter().map(ToString::to_string).collect::<Vec<_>>()),
+            &ids.iter().map(ToString::to_string).collect::<Vec<_>>(),
+        )
+        .all()
+        .await?;
+
+    for id in ids {
+        l1::invalidate::<ThreadEntity>(&Id::<ChannelMarker>::from_str(&id).unwrap());
+    }
+
+    Ok(())
+}
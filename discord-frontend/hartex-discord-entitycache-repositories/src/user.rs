@@ -22,6 +22,8 @@
 
 use std::pin::Pin;
 
+use async_trait::async_trait;
+use hartex_database_queries::discord_frontend::queries::cached_user_delete_by_id::cached_user_delete_by_id;
 use hartex_database_queries::discord_frontend::queries::cached_user_select_by_id::cached_user_select_by_id;
 use hartex_database_queries::discord_frontend::queries::cached_user_upsert::cached_user_upsert;
 use hartex_discord_entitycache_core::error::CacheResult;
@@ -31,11 +33,37 @@ use hartex_discord_entitycache_entities::user::UserEntity;
 use hartex_discord_utils::DATABASE_POOL;
 use tokio_postgres::GenericClient;
 
+use crate::backend;
+use crate::backend::Backend;
+use crate::backend::DatabaseBackend;
+use crate::l1;
+
 /// Repository for user entities.
 pub struct CachedUserRepository;
 
 impl Repository<UserEntity> for CachedUserRepository {
     async fn get(&self, id: <UserEntity as Entity>::Id) -> CacheResult<UserEntity> {
+        Backend::<UserEntity>::get(backend::backend(), id).await
+    }
+
+    async fn upsert(&self, entity: UserEntity) -> CacheResult<()> {
+        Backend::<UserEntity>::upsert(backend::backend(), entity).await
+    }
+
+    async fn delete(&self, id: <UserEntity as Entity>::Id) -> CacheResult<()> {
+        Backend::<UserEntity>::delete(backend::backend(), id).await
+    }
+}
+
+#[async_trait]
+impl Backend<UserEntity> for DatabaseBackend {
+    async fn get(&self, id: <UserEntity as Entity>::Id) -> CacheResult<UserEntity> {
+        if let Some(entity) = l1::get::<UserEntity>(&id) {
+            return Ok(entity);
+        }
+
+        let ticket = l1::ticket::<UserEntity>();
+
         let pinned = Pin::static_ref(&DATABASE_POOL).await;
         let pooled = pinned.get().await?;
         let client = pooled.client();
@@ -45,7 +73,10 @@ impl Repository<UserEntity> for CachedUserRepository {
             .one()
             .await?;
 
-        Ok(UserEntity::from(data))
+        let entity = UserEntity::try_from(data)?;
+        l1::insert(&entity, ticket);
+
+        Ok(entity)
     }
 
     async fn upsert(&self, entity: UserEntity) -> CacheResult<()> {
@@ -65,6 +96,22 @@ impl Repository<UserEntity> for CachedUserRepository {
             )
             .await?;
 
+        l1::invalidate::<UserEntity>(&entity.id());
+
+        Ok(())
+    }
+
+    async fn delete(&self, id: <UserEntity as Entity>::Id) -> CacheResult<()> {
+        let pinned = Pin::static_ref(&DATABASE_POOL).await;
+        let pooled = pinned.get().await?;
+        let client = pooled.client();
+
+        cached_user_delete_by_id()
+            .bind(client, &id.to_string())
+            .await?;
+
+        l1::invalidate::<UserEntity>(&id);
+
         Ok(())
     }
 }
@@ -0,0 +1,194 @@
+/*
+ * SPDX-License-Identifier: AGPL-3.0-only
+ *
+ * This file is part of HarTex.
+ *
+ * HarTex
+ * Copyright (c) 2021-2024 HarTex Project Developers
+ *
+ * HarTex is free software; you can redistribute it and/or modify
+ * it under the terms of the GNU Affero General Public License as published by
+ * the Free Software Foundation; either version 3 of the License, or
+ * (at your option) any later version.
+ *
+ * HarTex is distributed in the hope that it will be useful,
+ * but WITHOUT ANY WARRANTY; without even the implied warranty of
+ * MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+ * GNU Affero General Public License for more details.
+ *
+ * You should have received a copy of the GNU Affero General Public License along
+ * with HarTex. If not, see <https://www.gnu.org/licenses/>.
+ */
+
+use std::pin::Pin;
+use std::str::FromStr;
+
+use async_trait::async_trait;
+use hartex_database_queries::discord_frontend::queries::cached_voice_state_delete_by_guild_id::cached_voice_state_delete_by_guild_id;
+use hartex_database_queries::discord_frontend::queries::cached_voice_state_delete_by_guild_id_except_user_ids::cached_voice_state_delete_by_guild_id_except_user_ids;
+use hartex_database_queries::discord_frontend::queries::cached_voice_state_delete_by_user_id_and_guild_id::cached_voice_state_delete_by_user_id_and_guild_id;
+use hartex_database_queries::discord_frontend::queries::cached_voice_state_select_by_user_id_and_guild_id::cached_voice_state_select_by_user_id_and_guild_id;
+use hartex_database_queries::discord_frontend::queries::cached_voice_state_upsert::cached_voice_state_upsert;
+use hartex_discord_core::discord::model::id::marker::GuildMarker;
+use hartex_discord_core::discord::model::id::marker::UserMarker;
+use hartex_discord_core::discord::model::id::Id;
+use hartex_discord_entitycache_core::error::CacheResult;
+use hartex_discord_entitycache_core::traits::Entity;
+use hartex_discord_entitycache_core::traits::Repository;
+use hartex_discord_entitycache_entities::voice_state::VoiceStateEntity;
+use hartex_discord_utils::DATABASE_POOL;
+use tokio_postgres::GenericClient;
+
+use crate::backend;
+use crate::backend::Backend;
+use crate::backend::DatabaseBackend;
+use crate::backend::GuildBackend;
+use crate::l1;
+
+/// Repository for voice state entities.
+pub struct CachedVoiceStateRepository;
+
+impl CachedVoiceStateRepository {
+    /// Deletes every voice state of a guild.
+    #[allow(clippy::missing_errors_doc)]
+    #[allow(clippy::missing_panics_doc)]
+    pub async fn delete_by_guild_id(&self, guild_id: Id<GuildMarker>) -> CacheResult<()> {
+        let pinned = Pin::static_ref(&DATABASE_POOL).await;
+        let pooled = pinned.get().await?;
+        let client = pooled.client();
+
+        let user_ids = cached_voice_state_delete_by_guild_id()
+            .bind(client, &guild_id.to_string())
+            .all()
+            .await?;
+
+        for user_id in user_ids {
+            l1::invalidate::<VoiceStateEntity>(&(guild_id, Id::from_str(&user_id).unwrap()));
+        }
+
+        Ok(())
+    }
+
+    /// Deletes every voice state of a guild other than those of the given users.
+    #[allow(clippy::missing_errors_doc)]
+    pub async fn retain_in_guild(
+        &self,
+        guild_id: Id<GuildMarker>,
+        user_ids: &[Id<UserMarker>],
+    ) -> CacheResult<()> {
+        let ids = user_ids
+            .iter()
+            .map(|user_id| (guild_id, *user_id))
+            .collect::<Vec<_>>();
+
+        GuildBackend::<VoiceStateEntity>::retain_in_guild(backend::backend(), guild_id, &ids).await
+    }
+}
+
+impl Repository<VoiceStateEntity> for CachedVoiceStateRepository {
+    async fn get(&self, id: <VoiceStateEntity as Entity>::Id) -> CacheResult<VoiceStateEntity> {
+        Backend::<VoiceStateEntity>::get(backend::backend(), id).await
+    }
+
+    async fn upsert(&self, entity: VoiceStateEntity) -> CacheResult<()> {
+        Backend::<VoiceStateEntity>::upsert(backend::backend(), entity).await
+    }
+
+    async fn delete(&self, id: <VoiceStateEntity as Entity>::Id) -> CacheResult<()> {
+        Backend::<VoiceStateEntity>::delete(backend::backend(), id).await
+    }
+}
+
+#[async_trait]
+impl Backend<VoiceStateEntity> for DatabaseBackend {
+    async fn get(
+        &self,
+        (guild_id, user_id): <VoiceStateEntity as Entity>::Id,
+    ) -> CacheResult<VoiceStateEntity> {
+        if let Some(entity) = l1::get::<VoiceStateEntity>(&(guild_id, user_id)) {
+            return Ok(entity);
+        }
+
+        let ticket = l1::ticket::<VoiceStateEntity>();
+
+        let pinned = Pin::static_ref(&DATABASE_POOL).await;
+        let pooled = pinned.get().await?;
+        let client = pooled.client();
+
+        let data = cached_voice_state_select_by_user_id_and_guild_id()
+            .bind(client, &user_id.to_string(), &guild_id.to_string())
+            .one()
+            .await?;
+
+        let entity = VoiceStateEntity::try_from(data)?;
+        l1::insert(&entity, ticket);
+
+        Ok(entity)
+    }
+
+    async fn upsert(&self, entity: VoiceStateEntity) -> CacheResult<()> {
+        let pinned = Pin::static_ref(&DATABASE_POOL).await;
+        let pooled = pinned.get().await?;
+        let client = pooled.client();
+
+        cached_voice_state_upsert()
+            .bind(
+                client,
+                &entity.channel_id.map(|id| id.to_string()),
+                &entity.guild_id.to_string(),
+                &entity.user_id.to_string(),
+            )
+            .await?;
+
+        l1::invalidate::<VoiceStateEntity>(&entity.id());
+
+        Ok(())
+    }
+
+    async fn delete(
+        &self,
+        (guild_id, user_id): <VoiceStateEntity as Entity>::Id,
+    ) -> CacheResult<()> {
+        let pinned = Pin::static_ref(&DATABASE_POOL).await;
+        let pooled = pinned.get().await?;
+        let client = pooled.client();
+
+        cached_voice_state_delete_by_user_id_and_guild_id()
+            .bind(client, &user_id.to_string(), &guild_id.to_string())
+            .await?;
+
+        l1::invalidate::<VoiceStateEntity>(&(guild_id, user_id));
+
+        Ok(())
+    }
+}
+
+#[async_trait]
+impl GuildBackend<VoiceStateEntity> for DatabaseBackend {
+    async fn retain_in_guild(
+        &self,
+        guild_id: Id<GuildMarker>,
+        ids: &[<VoiceStateEntity as Entity>::Id],
+    ) -> CacheResult<()> {
+        let pinned = Pin::static_ref(&DATABASE_POOL).await;
+        let pooled = pinned.get().await?;
+        let client = pooled.client();
+
+        let user_ids = cached_voice_state_delete_by_guild_id_except_user_ids()
+            .bind(
+                client,
+                &guild_id.to_string(),
+                &ids.iter()
+                    .map(|(_, user_id)| user_id.to_string())
+                    .collect::<Vec<_>>(),
+            )
+            .all()
+            .await?;
+
+        for user_id in user_ids {
+            l1::invalidate::<VoiceStateEntity>(&(guild_id, Id::from_str(&user_id).unwrap()));
+        }
+
+        Ok(())
+    }
+}
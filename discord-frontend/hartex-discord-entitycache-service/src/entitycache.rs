@@ -25,8 +25,15 @@
 use hartex_discord_core::discord::model::gateway::event::DispatchEvent;
 use hartex_discord_core::discord::model::gateway::event::GatewayEvent;
 use hartex_discord_entitycache_cacheupdaters::CacheUpdater;
+use hartex_discord_entitycache_core::traits::Repository;
+use hartex_discord_entitycache_repositories::member::CachedMemberRepository;
+use hartex_discord_entitycache_repositories::voice_state::CachedVoiceStateRepository;
+use hartex_discord_internal_events::EntityKind;
 use hartex_log::log;
 use miette::IntoDiagnostic;
+use serde_json::json;
+
+use crate::invalidation;
 
 /// Update entity cache.
 pub async fn update(event: GatewayEvent) -> miette::Result<()> {
@@ -35,14 +42,139 @@ pub async fn update(event: GatewayEvent) -> miette::Result<()> {
     };
 
     match dispatch {
+        DispatchEvent::ChannelCreate(channel_create) => {
+            log::trace!("updating cache using CHANNEL_CREATE event");
+            channel_create.update().await.into_diagnostic()?;
+        }
+        DispatchEvent::ChannelDelete(channel_delete) => {
+            log::trace!("updating cache using CHANNEL_DELETE event");
+            channel_delete.update().await.into_diagnostic()?;
+        }
+        DispatchEvent::ChannelUpdate(channel_update) => {
+            log::trace!("updating cache using CHANNEL_UPDATE event");
+            channel_update.update().await.into_diagnostic()?;
+        }
         DispatchEvent::GuildCreate(guild_create) => {
             log::trace!("updating cache using GUILD_CREATE event");
             guild_create.update().await.into_diagnostic()?;
         }
+        DispatchEvent::GuildDelete(guild_delete) => {
+            log::trace!("updating cache using GUILD_DELETE event");
+            guild_delete.update().await.into_diagnostic()?;
+        }
+        DispatchEvent::GuildEmojisUpdate(guild_emojis_update) => {
+            log::trace!("updating cache using GUILD_EMOJIS_UPDATE event");
+            guild_emojis_update.update().await.into_diagnostic()?;
+        }
+        DispatchEvent::GuildUpdate(guild_update) => {
+            log::trace!("updating cache using GUILD_UPDATE event");
+            guild_update.update().await.into_diagnostic()?;
+        }
+        DispatchEvent::MemberAdd(member_add) => {
+            log::trace!("updating cache using GUILD_MEMBER_ADD event");
+            member_add.update().await.into_diagnostic()?;
+        }
         DispatchEvent::MemberChunk(member_chunk) => {
             log::trace!("updating cache using GUILD_MEMBER_CHUNK event");
             member_chunk.update().await.into_diagnostic()?;
         }
+        DispatchEvent::MemberRemove(member_remove) => {
+            log::trace!("updating cache using GUILD_MEMBER_REMOVE event");
+            member_remove.update().await.into_diagnostic()?;
+        }
+        DispatchEvent::MemberUpdate(member_update) => {
+            log::trace!("updating cache using GUILD_MEMBER_UPDATE event");
+
+            // the previous state is read before the update overwrites it
+            let previous = match CachedMemberRepository
+                .get((member_update.guild_id, member_update.user.id))
+                .await
+            {
+                Ok(member) => Some(json!({
+                    "avatar": member.avatar,
+                    "nick": member.nick,
+                    "roles": member.roles,
+                })),
+                Err(error) if error.is_not_found() => None,
+                Err(error) => return Err(error).into_diagnostic(),
+            };
+
+            member_update.update().await.into_diagnostic()?;
+            invalidation::publish_update(EntityKind::Member, &member_update, previous);
+        }
+        DispatchEvent::MessageCreate(message_create) => {
+            log::trace!("updating cache using MESSAGE_CREATE event");
+            message_create.update().await.into_diagnostic()?;
+        }
+        DispatchEvent::MessageDelete(message_delete) => {
+            log::trace!("updating cache using MESSAGE_DELETE event");
+            message_delete.update().await.into_diagnostic()?;
+        }
+        DispatchEvent::MessageDeleteBulk(message_delete_bulk) => {
+            log::trace!("updating cache using MESSAGE_DELETE_BULK event");
+            message_delete_bulk.update().await.into_diagnostic()?;
+        }
+        DispatchEvent::MessageUpdate(message_update) => {
+            log::trace!("updating cache using MESSAGE_UPDATE event");
+            message_update.update().await.into_diagnostic()?;
+        }
+        DispatchEvent::Ready(ready) => {
+            log::trace!("updating cache using READY event");
+            ready.update().await.into_diagnostic()?;
+        }
+        DispatchEvent::RoleCreate(role_create) => {
+            log::trace!("updating cache using GUILD_ROLE_CREATE event");
+            role_create.update().await.into_diagnostic()?;
+        }
+        DispatchEvent::RoleDelete(role_delete) => {
+            log::trace!("updating cache using GUILD_ROLE_DELETE event");
+            role_delete.update().await.into_diagnostic()?;
+        }
+        DispatchEvent::RoleUpdate(role_update) => {
+            log::trace!("updating cache using GUILD_ROLE_UPDATE event");
+            role_update.update().await.into_diagnostic()?;
+        }
+        DispatchEvent::ThreadCreate(thread_create) => {
+            log::trace!("updating cache using THREAD_CREATE event");
+            thread_create.update().await.into_diagnostic()?;
+        }
+        DispatchEvent::ThreadDelete(thread_delete) => {
+            log::trace!("updating cache using THREAD_DELETE event");
+            thread_delete.update().await.into_diagnostic()?;
+        }
+        DispatchEvent::ThreadListSync(thread_list_sync) => {
+            log::trace!("updating cache using THREAD_LIST_SYNC event");
+            thread_list_sync.update().await.into_diagnostic()?;
+        }
+        DispatchEvent::ThreadUpdate(thread_update) => {
+            log::trace!("updating cache using THREAD_UPDATE event");
+            thread_update.update().await.into_diagnostic()?;
+        }
+        DispatchEvent::UserUpdate(user_update) => {
+            log::trace!("updating cache using USER_UPDATE event");
+            user_update.update().await.into_diagnostic()?;
+        }
+        DispatchEvent::VoiceStateUpdate(voice_state_update) => {
+            log::trace!("updating cache using VOICE_STATE_UPDATE event");
+
+            // only guild voice states are cached
+            let Some(guild_id) = voice_state_update.0.guild_id else {
+                return Ok(());
+            };
+
+            // the previous state is read before the update overwrites it
+            let previous = match CachedVoiceStateRepository
+                .get((guild_id, voice_state_update.0.user_id))
+                .await
+            {
+                Ok(voice_state) => Some(json!({ "channel_id": voice_state.channel_id })),
+                Err(error) if error.is_not_found() => None,
+                Err(error) => return Err(error).into_diagnostic(),
+            };
+
+            voice_state_update.update().await.into_diagnostic()?;
+            invalidation::publish_update(EntityKind::VoiceState, &voice_state_update, previous);
+        }
         _ => (),
     }
 
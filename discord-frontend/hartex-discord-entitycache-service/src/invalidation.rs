@@ -0,0 +1,93 @@
+/*
+ * SPDX-License-Identifier: AGPL-3.0-only
+ *
+ * This file is part of HarTex.
+ *
+ * HarTex
+ * Copyright (c) 2021-2024 HarTex Project Developers
+ *
+ * HarTex is free software; you can redistribute it and/or modify
+ * it under the terms of the GNU Affero General Public License as published by
+ * the Free Software Foundation; either version 3 of the License, or
+ * (at your option) any later version.
+ *
+ * HarTex is distributed in the hope that it will be useful,
+ * but WITHOUT ANY WARRANTY; without even the implied warranty of
+ * MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+ * GNU Affero General Public License for more details.
+ *
+ * You should have received a copy of the GNU Affero General Public License along
+ * with HarTex. If not, see <https://www.gnu.org/licenses/>.
+ */
+
+//! # Cache Invalidations
+//!
+//! Publishes the invalidations of the entities updated by this service onto the internal event
+//! topic, such that other processes can discard their stale copies, along with the updates
+//! carrying the previous state of the entities changed by some gateway events.
+
+use std::sync::OnceLock;
+
+use hartex_discord_entitycache_repositories::l1;
+use hartex_discord_internal_events::EntityCacheUpdate;
+use hartex_discord_internal_events::EntityKind;
+use hartex_discord_internal_events::InternalEvent;
+use hartex_log::log;
+use rdkafka::producer::FutureProducer;
+use rdkafka::producer::FutureRecord;
+use serde::Serialize;
+use serde_json::Value;
+
+/// The producer and the topic the internal events are published with.
+static PUBLISHER: OnceLock<(FutureProducer, String)> = OnceLock::new();
+
+/// Publishes the invalidations of the entity repositories and the updates with a producer onto a
+/// topic.
+pub fn publish_to(producer: FutureProducer, topic: String) {
+    let _ = PUBLISHER.set((producer, topic));
+
+    l1::set_publisher(|event: InternalEvent| {
+        publish("INTERNAL_EVENT_ENTITYCACHE_INVALIDATION", &event);
+    });
+}
+
+/// Publishes the update of an entity by a gateway event, along with the state of the entity
+/// before the event was applied.
+pub fn publish_update(kind: EntityKind, event: &impl Serialize, previous: Option<Value>) {
+    let event = match serde_json::to_value(event) {
+        Ok(event) => event,
+        Err(error) => {
+            log::warn!("failed to serialize entity cache update: {error:?}");
+            return;
+        }
+    };
+
+    publish(
+        "INTERNAL_EVENT_ENTITYCACHE_UPDATE",
+        &InternalEvent::EntityCacheUpdate(EntityCacheUpdate {
+            kind,
+            event,
+            previous,
+        }),
+    );
+}
+
+/// Publishes an internal event with a key, if a producer is set.
+fn publish(key: &str, event: &InternalEvent) {
+    let Some((producer, topic)) = PUBLISHER.get() else {
+        return;
+    };
+
+    let bytes = match serde_json::to_vec(event) {
+        Ok(bytes) => bytes,
+        Err(error) => {
+            log::warn!("failed to serialize internal event: {error:?}");
+            return;
+        }
+    };
+
+    let record = FutureRecord::to(topic).key(key).payload(&bytes);
+    if let Err((error, _)) = producer.send_result(record) {
+        log::warn!("failed to publish internal event: {error:?}");
+    }
+}
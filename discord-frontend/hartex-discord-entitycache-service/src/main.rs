@@ -41,17 +41,21 @@ use hartex_discord_core::dotenvy;
 use hartex_discord_core::tokio;
 use hartex_discord_core::tokio::signal;
 use hartex_kafka_utils::traits::ClientConfigUtils;
+use hartex_kafka_utils::types::CompressionType;
 use hartex_log::log;
 use miette::IntoDiagnostic;
 use rdkafka::consumer::Consumer;
 use rdkafka::consumer::StreamConsumer;
 use rdkafka::error::KafkaError;
+use rdkafka::producer::FutureProducer;
 use rdkafka::ClientConfig;
 use rdkafka::Message;
 use serde::de::DeserializeSeed;
 use serde_scan::scan;
 
 mod entitycache;
+mod invalidation;
+mod prune;
 
 /// The entrypoint for the entitycache update service.
 #[tokio::main(flavor = "multi_thread")]
@@ -67,6 +71,16 @@ pub async fn main() -> miette::Result<()> {
         .map(String::from)
         .collect::<Vec<_>>();
     let topic = env::var("KAFKA_TOPIC_INBOUND_DISCORD_GATEWAY_PAYLOAD_CACHE").into_diagnostic()?;
+    let internal_event_topic = env::var("KAFKA_TOPIC_INTERNAL_EVENT_PAYLOAD").into_diagnostic()?;
+
+    let producer = ClientConfig::new()
+        .bootstrap_servers(bootstrap_servers.clone().into_iter())
+        .compression_type(CompressionType::Lz4)
+        .delivery_timeout_ms(30000)
+        .create::<FutureProducer>()
+        .into_diagnostic()?;
+    invalidation::publish_to(producer, internal_event_topic);
+    prune::spawn_pruner();
 
     let consumer = ClientConfig::new()
         .bootstrap_servers(bootstrap_servers.into_iter())
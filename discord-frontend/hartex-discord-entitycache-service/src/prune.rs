@@ -0,0 +1,51 @@
+/*
+ * SPDX-License-Identifier: AGPL-3.0-only
+ *
+ * This file is part of HarTex.
+ *
+ * HarTex
+ * Copyright (c) 2021-2024 HarTex Project Developers
+ *
+ * HarTex is free software; you can redistribute it and/or modify
+ * it under the terms of the GNU Affero General Public License as published by
+ * the Free Software Foundation; either version 3 of the License, or
+ * (at your option) any later version.
+ *
+ * HarTex is distributed in the hope that it will be useful,
+ * but WITHOUT ANY WARRANTY; without even the implied warranty of
+ * MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+ * GNU Affero General Public License for more details.
+ *
+ * You should have received a copy of the GNU Affero General Public License along
+ * with HarTex. If not, see <https://www.gnu.org/licenses/>.
+ */
+
+//! # Message Pruning
+//!
+//! Periodically prunes the cached messages that exceeded their maximum age.
+
+use std::time::Duration;
+
+use hartex_discord_core::tokio::spawn;
+use hartex_discord_core::tokio::time;
+use hartex_discord_entitycache_repositories::message::CachedMessageRepository;
+use hartex_log::log;
+
+/// The interval at which expired messages are pruned.
+const PRUNE_INTERVAL: Duration = Duration::from_secs(60 * 60);
+
+/// Spawns the task pruning expired messages.
+pub fn spawn_pruner() {
+    spawn(async {
+        let mut interval = time::interval(PRUNE_INTERVAL);
+
+        loop {
+            interval.tick().await;
+
+            match CachedMessageRepository.prune_expired().await {
+                Ok(pruned) => log::trace!("pruned {pruned} expired messages"),
+                Err(error) => log::error!("failed to prune expired messages: {error:?}"),
+            }
+        }
+    });
+}
@@ -21,3 +21,77 @@
  */
 
 //! # Payloads for internal events used within HarTex.
+//!
+//! Internal events are sent between the services of the bot through the internal event topic,
+//! serialized as JSON.
+
+#![deny(clippy::pedantic)]
+#![deny(unsafe_code)]
+#![deny(warnings)]
+
+use serde::Deserialize;
+use serde::Serialize;
+use serde_json::Value;
+
+/// An internal event.
+#[derive(Clone, Debug, Deserialize, Serialize)]
+#[serde(tag = "type", content = "data", rename_all = "SCREAMING_SNAKE_CASE")]
+pub enum InternalEvent {
+    /// An entity of the entity cache was upserted or deleted.
+    EntityCacheInvalidation(EntityCacheInvalidation),
+    /// Every entity of a kind belonging to a guild was deleted from the entity cache.
+    EntityCacheGuildInvalidation(EntityCacheGuildInvalidation),
+    /// A gateway event changing an entity was applied to the entity cache.
+    EntityCacheUpdate(EntityCacheUpdate),
+}
+
+/// The invalidation of an entity of the entity cache, published after the entity is upserted or
+/// deleted such that copies of the entity held in other processes are discarded.
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct EntityCacheInvalidation {
+    /// The kind of the entity.
+    pub kind: EntityKind,
+    /// The identifier of the entity, serialized as JSON.
+    pub id: Value,
+}
+
+/// The invalidation of every entity of a kind belonging to a guild, published after the entities
+/// are deleted in bulk in place of invalidating them one by one.
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct EntityCacheGuildInvalidation {
+    /// The kind of the entities.
+    pub kind: EntityKind,
+    /// The identifier of the guild, serialized as JSON.
+    pub guild_id: Value,
+}
+
+/// A gateway event applied to the entity cache, published with the state of the entity it changed
+/// from before it was applied.
+///
+/// What a gateway event changed can no longer be told from the entity cache once the event is
+/// applied to it; this is only published for the gateway events whose consumers need to know.
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct EntityCacheUpdate {
+    /// The kind of the changed entity.
+    pub kind: EntityKind,
+    /// The payload of the gateway event, serialized as JSON.
+    pub event: Value,
+    /// The state of the entity before the event was applied, serialized as JSON, or `None` if the
+    /// entity was not cached.
+    pub previous: Option<Value>,
+}
+
+/// The kind of an entity of the entity cache.
+#[derive(Clone, Copy, Debug, Deserialize, Eq, PartialEq, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum EntityKind {
+    Channel,
+    Emoji,
+    Guild,
+    Member,
+    Message,
+    Role,
+    Thread,
+    User,
+    VoiceState,
+}
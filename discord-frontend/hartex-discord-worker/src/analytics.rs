@@ -0,0 +1,213 @@
+/*
+ * SPDX-License-Identifier: AGPL-3.0-only
+ *
+ * This file is part of HarTex.
+ *
+ * HarTex
+ * Copyright (c) 2021-2024 HarTex Project Developers
+ *
+ * HarTex is free software; you can redistribute it and/or modify
+ * it under the terms of the GNU Affero General Public License as published by
+ * the Free Software Foundation; either version 3 of the License, or
+ * (at your option) any later version.
+ *
+ * HarTex is distributed in the hope that it will be useful,
+ * but WITHOUT ANY WARRANTY; without even the implied warranty of
+ * MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+ * GNU Affero General Public License for more details.
+ *
+ * You should have received a copy of the GNU Affero General Public License along
+ * with HarTex. If not, see <https://www.gnu.org/licenses/>.
+ */
+
+//! # Command Usage Analytics
+//!
+//! Records every command invocation, along with its daily rollup, for aggregated usage statistics
+//! to be exposed by the API backend.
+
+use std::pin::Pin;
+
+use async_trait::async_trait;
+use hartex_database_queries::discord_frontend::queries::command_usage_insert::command_usage_insert;
+use hartex_database_queries::discord_frontend::queries::command_usage_rollup_upsert::command_usage_rollup_upsert;
+use hartex_discord_commands_core::middleware::Invocation;
+use hartex_discord_commands_core::middleware::Middleware;
+use hartex_discord_core::discord::model::application::interaction::application_command::CommandOptionValue;
+use hartex_discord_core::discord::model::application::interaction::Interaction;
+use hartex_discord_core::discord::model::application::interaction::InteractionData;
+use hartex_discord_core::tokio::spawn;
+use hartex_discord_utils::DATABASE_POOL;
+use hartex_log::log;
+use miette::IntoDiagnostic;
+use sha2::Digest;
+use sha2::Sha224;
+use time::OffsetDateTime;
+
+/// A recorded command invocation.
+struct CommandUsage {
+    command: String,
+    subcommand: Option<String>,
+    guild_id: Option<String>,
+    locale: String,
+    latency_ms: i64,
+    outcome: &'static str,
+    error_hash: Option<String>,
+    invoked_at: OffsetDateTime,
+}
+
+/// Records command invocations.
+///
+/// Component interactions of commands are not recorded as invocations.
+pub struct UsageAnalytics;
+
+#[async_trait]
+impl Middleware for UsageAnalytics {
+    #[allow(clippy::cast_possible_truncation)]
+    async fn after(
+        &self,
+        invocation: &Invocation<'_>,
+        result: &miette::Result<()>,
+    ) -> miette::Result<()> {
+        if !matches!(
+            invocation.interaction.data,
+            Some(InteractionData::ApplicationCommand(_))
+        ) {
+            return Ok(());
+        }
+
+        let elapsed = invocation.started.elapsed();
+        let (outcome, error_hash) = match result {
+            Ok(()) if invocation.short_circuited => ("rejected", None),
+            Ok(()) => ("success", None),
+            Err(error) => ("failure", Some(error_hash(error))),
+        };
+
+        let interaction = invocation.interaction;
+        let usage = CommandUsage {
+            command: invocation.command.name(),
+            subcommand: subcommand(interaction),
+            guild_id: interaction.guild_id.map(|guild_id| guild_id.to_string()),
+            locale: interaction
+                .locale
+                .clone()
+                .unwrap_or_else(|| String::from("en-GB")),
+            latency_ms: elapsed.as_millis() as i64,
+            outcome,
+            error_hash,
+            invoked_at: OffsetDateTime::now_utc() - elapsed,
+        };
+
+        // recording is done in the background such that it does not delay later middlewares
+        spawn(async move {
+            if let Err(error) = record(usage).await {
+                log::error!("failed to record command usage: {error:?}");
+            }
+        });
+
+        Ok(())
+    }
+}
+
+/// Returns a stable hash of the kind of an error, such that failures caused by the same kind of
+/// error can be grouped.
+///
+/// Unlike the error codes reported to users, the hash does not depend on the time of the error.
+fn error_hash(error: &miette::Report) -> String {
+    let mut hasher = Sha224::new();
+    hasher.update(error_kind(error).as_bytes());
+
+    hasher.finalize().map(|int| format!("{int:x}")).join("")
+}
+
+/// Returns the kind of an error: its diagnostic code if it has one, or otherwise its message
+/// with the parts that vary between occurrences removed.
+///
+/// Numbers (such as ids) and quoted text (such as user input) are masked, such that the kind
+/// neither identifies the invocation nor contains user data.
+fn error_kind(error: &miette::Report) -> String {
+    if let Some(code) = error.code() {
+        return code.to_string();
+    }
+
+    let message = strip_ansi_escapes::strip_str(error.to_string());
+    let mut kind = String::with_capacity(message.len());
+    let mut quote = None;
+
+    for character in message.chars() {
+        match quote {
+            Some(closing) if character == closing => {
+                kind.push(character);
+                quote = None;
+            }
+            Some(_) => (),
+            None if matches!(character, '`' | '"') => {
+                kind.push(character);
+                kind.push('_');
+                quote = Some(character);
+            }
+            None if character.is_ascii_digit() => {
+                if !kind.ends_with('#') {
+                    kind.push('#');
+                }
+            }
+            None => kind.push(character),
+        }
+    }
+
+    kind
+}
+
+/// Returns the invoked subcommand of an interaction, prefixed by its subcommand group if any.
+fn subcommand(interaction: &Interaction) -> Option<String> {
+    let Some(InteractionData::ApplicationCommand(command)) = &interaction.data else {
+        return None;
+    };
+
+    command
+        .options
+        .iter()
+        .find_map(|option| match &option.value {
+            CommandOptionValue::SubCommand(_) => Some(option.name.clone()),
+            CommandOptionValue::SubCommandGroup(options) => options
+                .first()
+                .map(|subcommand| format!("{} {}", option.name, subcommand.name)),
+            _ => None,
+        })
+}
+
+/// Records a command invocation and updates its daily rollup.
+async fn record(usage: CommandUsage) -> miette::Result<()> {
+    let pinned = Pin::static_ref(&DATABASE_POOL).await;
+    let mut pooled = pinned.get().await.into_diagnostic()?;
+    let transaction = pooled.transaction().await.into_diagnostic()?;
+
+    command_usage_insert()
+        .bind(
+            &transaction,
+            &usage.command,
+            &usage.subcommand,
+            &usage.guild_id,
+            &usage.locale,
+            &usage.latency_ms,
+            &usage.outcome,
+            &usage.error_hash,
+            &usage.invoked_at,
+        )
+        .await
+        .into_diagnostic()?;
+
+    command_usage_rollup_upsert()
+        .bind(
+            &transaction,
+            &usage.invoked_at,
+            &usage.command,
+            &usage.guild_id.unwrap_or_default(),
+            &i64::from(usage.outcome == "failure"),
+            &i64::from(usage.outcome == "rejected"),
+            &usage.latency_ms,
+        )
+        .await
+        .into_diagnostic()?;
+
+    transaction.commit().await.into_diagnostic()
+}
@@ -0,0 +1,244 @@
+/*
+ * SPDX-License-Identifier: AGPL-3.0-only
+ *
+ * This file is part of HarTex.
+ *
+ * HarTex
+ * Copyright (c) 2021-2024 HarTex Project Developers
+ *
+ * HarTex is free software; you can redistribute it and/or modify
+ * it under the terms of the GNU Affero General Public License as published by
+ * the Free Software Foundation; either version 3 of the License, or
+ * (at your option) any later version.
+ *
+ * HarTex is distributed in the hope that it will be useful,
+ * but WITHOUT ANY WARRANTY; without even the implied warranty of
+ * MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+ * GNU Affero General Public License for more details.
+ *
+ * You should have received a copy of the GNU Affero General Public License along
+ * with HarTex. If not, see <https://www.gnu.org/licenses/>.
+ */
+
+//! # Appearance Application
+//!
+//! This module applies the appearance configuration of a guild, that is, the nickname of the bot
+//! and the colour of its managed integration role.
+//!
+//! The appearance is applied when a guild becomes available and whenever its configuration is
+//! updated, the latter being signalled through the `guild_configuration_updated` notification
+//! channel of the database. Failures to apply the appearance are reported as configuration
+//! diagnostics.
+//!
+//! The connection listening for the notifications is re-established with an exponential backoff
+//! whenever it is lost.
+
+use std::env;
+use std::str::FromStr;
+use std::time::Duration;
+
+use futures_util::stream;
+use futures_util::StreamExt;
+use hartex_discord_configuration_provider::ConfigurationProvider;
+use hartex_discord_core::discord::model::id::marker::GuildMarker;
+use hartex_discord_core::discord::model::id::Id;
+use hartex_discord_core::tokio::spawn;
+use hartex_discord_core::tokio::sync::mpsc;
+use hartex_discord_core::tokio::time;
+use hartex_discord_utils::CLIENT;
+use hartex_log::log;
+use miette::IntoDiagnostic;
+use tokio_postgres::AsyncMessage;
+use tokio_postgres::NoTls;
+
+/// The source of the diagnostics reported by this module.
+const DIAGNOSTIC_SOURCE: &str = "appearance";
+
+/// The database notification channel signalling configuration updates.
+const NOTIFICATION_CHANNEL: &str = "guild_configuration_updated";
+
+/// The delay before the first attempt to re-establish a lost listener connection.
+const INITIAL_RECONNECT_DELAY: Duration = Duration::from_secs(1);
+
+/// The maximum delay between attempts to re-establish a lost listener connection.
+const MAX_RECONNECT_DELAY: Duration = Duration::from_secs(60);
+
+/// Apply the appearance configuration of a guild.
+///
+/// Failures to apply individual settings are reported as configuration diagnostics rather than
+/// returned, as they are usually caused by the configuration (or missing permissions) rather
+/// than the bot.
+pub async fn apply(guild_id: Id<GuildMarker>) -> miette::Result<()> {
+    let Some(appearance) = ConfigurationProvider::appearance(guild_id).await? else {
+        return Ok(());
+    };
+
+    if let Some(nickname) = &appearance.nickname {
+        if let Err(error) = CLIENT
+            .update_current_member(guild_id)
+            .nick(Some(nickname))
+            .await
+        {
+            ConfigurationProvider::report_diagnostic(
+                guild_id,
+                DIAGNOSTIC_SOURCE,
+                format!("failed to set the nickname of the bot: {error}"),
+            )
+            .await?;
+        }
+    }
+
+    if let Some(colour) = appearance.colour {
+        let result = async {
+            let current_user = CLIENT
+                .current_user()
+                .await
+                .into_diagnostic()?
+                .model()
+                .await
+                .into_diagnostic()?;
+            let roles = CLIENT
+                .roles(guild_id)
+                .await
+                .into_diagnostic()?
+                .model()
+                .await
+                .into_diagnostic()?;
+
+            miette::Ok((current_user, roles))
+        }
+        .await;
+        let (current_user, roles) = match result {
+            Ok(result) => result,
+            Err(error) => {
+                ConfigurationProvider::report_diagnostic(
+                    guild_id,
+                    DIAGNOSTIC_SOURCE,
+                    format!("failed to set the role colour of the bot: {error}"),
+                )
+                .await?;
+
+                return Ok(());
+            }
+        };
+
+        let Some(role) = roles.iter().find(|role| {
+            role.tags
+                .as_ref()
+                .and_then(|tags| tags.bot_id)
+                .is_some_and(|bot_id| bot_id == current_user.id)
+        }) else {
+            ConfigurationProvider::report_diagnostic(
+                guild_id,
+                DIAGNOSTIC_SOURCE,
+                "failed to set the role colour of the bot: the managed role of the bot cannot be found",
+            )
+            .await?;
+
+            return Ok(());
+        };
+
+        if let Err(error) = CLIENT
+            .update_role(guild_id, role.id)
+            .color(Some(colour))
+            .await
+        {
+            ConfigurationProvider::report_diagnostic(
+                guild_id,
+                DIAGNOSTIC_SOURCE,
+                format!("failed to set the role colour of the bot: {error}"),
+            )
+            .await?;
+        }
+    }
+
+    Ok(())
+}
+
+/// Listen for configuration updates, dispatching the plugin lifecycle hooks and applying the
+/// appearance configuration of the updated guilds.
+///
+/// This only returns if the database URL is not configured; a lost connection is re-established
+/// with an exponential backoff.
+pub async fn listen() -> miette::Result<()> {
+    let hartex_pgsql_url = env::var("HARTEX_NIGHTLY_PGSQL_URL").into_diagnostic()?;
+    let mut delay = INITIAL_RECONNECT_DELAY;
+
+    loop {
+        match listen_once(&hartex_pgsql_url).await {
+            // the connection was established before being lost, so start over with the initial
+            // delay
+            Ok(()) => delay = INITIAL_RECONNECT_DELAY,
+            Err(error) => log::error!("failed to listen for configuration updates: {error:?}"),
+        }
+
+        log::warn!("configuration update listener disconnected; reconnecting in {delay:?}");
+        time::sleep(delay).await;
+        delay = (delay * 2).min(MAX_RECONNECT_DELAY);
+    }
+}
+
+/// Listen for configuration updates on a single connection, until the connection is lost.
+async fn listen_once(hartex_pgsql_url: &str) -> miette::Result<()> {
+    let (client, mut connection) = tokio_postgres::connect(hartex_pgsql_url, NoTls)
+        .await
+        .into_diagnostic()?;
+
+    let mut messages = stream::poll_fn(move |context| connection.poll_message(context));
+    let (sender, mut receiver) = mpsc::unbounded_channel();
+
+    // the connection must be polled for the notifications to be received
+    spawn(async move {
+        while let Some(message) = messages.next().await {
+            match message {
+                Ok(AsyncMessage::Notification(notification)) => {
+                    if sender.send(notification.payload().to_string()).is_err() {
+                        break;
+                    }
+                }
+                Ok(_) => (),
+                Err(error) => {
+                    log::error!("configuration update listener connection failed: {error}");
+                    break;
+                }
+            }
+        }
+    });
+
+    client
+        .batch_execute(&format!("LISTEN {NOTIFICATION_CHANNEL}"))
+        .await
+        .into_diagnostic()?;
+
+    // updates may have been missed while the listener was not connected
+    ConfigurationProvider::invalidate_all();
+
+    while let Some(payload) = receiver.recv().await {
+        let Ok(guild_id) = Id::<GuildMarker>::from_str(&payload) else {
+            log::warn!("received configuration update for invalid guild id: {payload}");
+
+            continue;
+        };
+
+        log::trace!("configuration of guild {guild_id} was updated");
+        crate::lifecycle::configuration_changed(guild_id).await;
+
+        if let Err(error) = apply(guild_id).await {
+            log::error!("failed to apply appearance for guild {guild_id}: {error:?}");
+
+            if let Err(error) = ConfigurationProvider::report_diagnostic(
+                guild_id,
+                DIAGNOSTIC_SOURCE,
+                format!("failed to apply the appearance: {error}"),
+            )
+            .await
+            {
+                log::error!(
+                    "failed to report appearance diagnostic for guild {guild_id}: {error:?}"
+                );
+            }
+        }
+    }
+
+    Ok(())
+}
@@ -62,6 +62,12 @@ pub async fn invoke(
 ) -> miette::Result<()> {
     let topic = env::var("KAFKA_TOPIC_OUTBOUND_COMMUNICATION").into_diagnostic()?;
 
+    if let GatewayEvent::Dispatch(_, dispatch) = &event {
+        if let Err(error) = crate::modlog::handle(dispatch).await {
+            log::error!("failed to handle event for the modlog: {error:?}");
+        }
+    }
+
     #[allow(clippy::collapsible_match)]
     match event {
         GatewayEvent::Dispatch(seq, dispatch) => match dispatch {
@@ -94,6 +100,15 @@ pub async fn invoke(
                     println!("{:?}", Err::<(), KafkaError>(error).into_diagnostic());
                 }
 
+                crate::lifecycle::guild_create(guild_create.id).await;
+
+                if let Err(error) = crate::appearance::apply(guild_create.id).await {
+                    log::error!(
+                        "failed to apply appearance for guild {}: {error:?}",
+                        guild_create.id
+                    );
+                }
+
                 Ok(())
             }
             DispatchEvent::InteractionCreate(interaction_create)
@@ -127,7 +142,49 @@ pub async fn invoke(
 
                 Ok(())
             }
+            DispatchEvent::InteractionCreate(interaction_create)
+                if interaction_create.kind == InteractionType::MessageComponent =>
+            {
+                log::trace!(
+                    "shard {shard} has received INTERACTION_CREATE payload from Discord (sequence {seq})"
+                );
+
+                let interaction_client = CLIENT.interaction(interaction_create.application_id);
+
+                if let Err(error) = AssertUnwindSafe(crate::interaction::message_component(
+                    interaction_create.clone(),
+                    &interaction_client,
+                ))
+                .catch_unwind()
+                .await
+                {
+                    crate::errorhandler::handle_interaction_error(
+                        ErrorPayload::Panic(
+                            error
+                                .downcast_ref::<String>()
+                                .unwrap_or(&String::new())
+                                .to_string(),
+                        ),
+                        interaction_create,
+                        &interaction_client,
+                    )
+                    .await;
+                }
+
+                Ok(())
+            }
+            DispatchEvent::GuildDelete(guild_delete) => {
+                log::trace!(
+                    "shard {shard} has received GUILD_DELETE payload from Discord (sequence {seq})"
+                );
+
+                crate::lifecycle::guild_delete(&guild_delete).await;
+
+                Ok(())
+            }
             DispatchEvent::Ready(ready) => {
+                crate::lifecycle::ready(&ready);
+
                 log::info!(
                     "{}#{} (shard {shard}) has received READY payload from Discord (gateway v{}) (sequence {seq})",
                     ready.user.name,
@@ -21,24 +21,29 @@
  */
 
 use std::collections::HashMap;
+use std::collections::HashSet;
+use std::env;
 
-use hartex_discord_commands::general::about::About;
-use hartex_discord_commands::general::contributors::Contributors;
-use hartex_discord_commands::utilities::info::Info;
+use hartex_discord_commands_core::middleware::blacklist::Blacklist;
+use hartex_discord_commands_core::middleware::logging::Logging;
+use hartex_discord_commands_core::middleware::maintenance::Maintenance;
+use hartex_discord_commands_core::middleware::metrics::Metrics;
+use hartex_discord_commands_core::middleware::permissions::PermissionLevel;
+use hartex_discord_commands_core::middleware::permissions::RequiredPermissions;
+use hartex_discord_commands_core::middleware::plugin::PluginEnabled;
+use hartex_discord_commands_core::middleware::MiddlewareChain;
 use hartex_discord_commands_core::traits::Command;
 use hartex_discord_commands_core::traits::CommandMetadata;
 use hartex_discord_core::discord::http::client::InteractionClient;
 use hartex_discord_core::discord::model::application::interaction::InteractionData;
 use hartex_discord_core::discord::model::gateway::payload::incoming::InteractionCreate;
-use hartex_discord_core::discord::model::http::interaction::InteractionResponse;
-use hartex_discord_core::discord::model::http::interaction::InteractionResponseType;
-use hartex_discord_core::discord::util::builder::InteractionResponseDataBuilder;
+use hartex_discord_core::discord::model::id::Id;
 use hartex_localization_core::Localizer;
 use hartex_localization_core::LOCALIZATION_HOLDER;
 use hartex_log::log;
-use miette::IntoDiagnostic;
 use once_cell::sync::Lazy;
 
+use crate::analytics::UsageAnalytics;
 use crate::errorhandler::ErrorPayload;
 
 /// Lookup table for commands provided by the bot.
@@ -47,13 +52,47 @@ use crate::errorhandler::ErrorPayload;
 /// can be executed via dynamic dispatch without the need of match arms and if guards.
 pub static COMMAND_LOOKUP: Lazy<HashMap<String, Box<dyn Command + Send + Sync>>> =
     Lazy::new(|| {
-        let mut map = HashMap::<String, Box<dyn Command + Send + Sync>>::new();
-        map.insert(About.name(), Box::new(About));
-        map.insert(Contributors.name(), Box::new(Contributors));
-        map.insert(Info.name(), Box::new(Info));
-        map
+        hartex_discord_commands::commands()
+            .into_iter()
+            .map(|command| (command.name(), command))
+            .collect()
     });
 
+/// The middlewares applied to every command.
+pub static MIDDLEWARES: Lazy<MiddlewareChain> = Lazy::new(|| {
+    MiddlewareChain::new()
+        .with(Logging)
+        .with(Metrics)
+        .with(UsageAnalytics)
+        .with(Maintenance::new(ids_from_env("MAINTENANCE_EXEMPT_USERS")))
+        .with(Blacklist::new(
+            ids_from_env("BLACKLISTED_GUILDS"),
+            ids_from_env("BLACKLISTED_USERS"),
+        ))
+        .with(PluginEnabled)
+        .with(RequiredPermissions)
+        .with(PermissionLevel)
+});
+
+/// Parses a comma-separated list of ids from an environment variable, which may be absent.
+///
+/// # Panics
+///
+/// Panics if any of the ids is malformed. As the middleware chain is initialized on startup, this
+/// prevents the worker from starting with an incomplete blacklist or exemption list.
+fn ids_from_env<T>(name: &str) -> HashSet<Id<T>> {
+    env::var(name)
+        .unwrap_or_default()
+        .split(',')
+        .map(str::trim)
+        .filter(|id| !id.is_empty())
+        .map(|id| {
+            id.parse()
+                .unwrap_or_else(|error| panic!("malformed id `{id}` in {name}: {error}"))
+        })
+        .collect()
+}
+
 /// Handle an application command interaction.
 #[allow(clippy::large_futures)]
 pub async fn application_command(
@@ -73,56 +112,59 @@ pub async fn application_command(
     let localizer = Localizer::new(&LOCALIZATION_HOLDER, locale);
 
     let command = COMMAND_LOOKUP.get(&command.name).unwrap();
-    let plugin = command.plugin();
-    if !plugin.enabled(interaction_create.guild_id.unwrap()).await? {
-        interaction_client
-            .create_response(
-                interaction_create.id,
-                &interaction_create.token,
-                &InteractionResponse {
-                    kind: InteractionResponseType::ChannelMessageWithSource,
-                    data: Some(
-                        InteractionResponseDataBuilder::new()
-                            .content(localizer.error_error_plugin_disabled(plugin.name())?)
-                            .build(),
-                    ),
-                },
-            )
-            .await
-            .into_diagnostic()?;
-
-        return Ok(());
+    if let Err(error) = MIDDLEWARES
+        .run(command.as_ref(), cloned.0, interaction_client, localizer)
+        .await
+    {
+        crate::errorhandler::handle_interaction_error(
+            ErrorPayload::Miette(error),
+            interaction_create,
+            interaction_client,
+        )
+        .await;
     }
 
-    let permissions = command.required_permissions();
-    let member_permissions = interaction_create
-        .member
-        .clone()
-        .unwrap()
-        .permissions
-        .unwrap();
-    if !member_permissions.contains(permissions) {
-        interaction_client
-            .create_response(
-                interaction_create.id,
-                &interaction_create.token,
-                &InteractionResponse {
-                    kind: InteractionResponseType::ChannelMessageWithSource,
-                    data: Some(
-                        InteractionResponseDataBuilder::new()
-                            .content(localizer.error_error_insufficient_permissions()?)
-                            .build(),
-                    ),
-                },
-            )
-            .await
-            .into_diagnostic()?;
+    Ok(())
+}
+
+/// Handle a message component interaction.
+///
+/// The interaction is routed to the command whose name prefixes the custom id of the component.
+#[allow(clippy::large_futures)]
+pub async fn message_component(
+    interaction_create: Box<InteractionCreate>,
+    interaction_client: &InteractionClient<'_>,
+) -> miette::Result<()> {
+    let Some(InteractionData::MessageComponent(component)) = interaction_create.data.clone() else {
+        unreachable!("this should not be possible")
+    };
+
+    let name = component
+        .custom_id
+        .split_once(':')
+        .map_or(component.custom_id.as_str(), |(name, _)| name);
+
+    log::trace!("running component interaction of command {name}");
+
+    let Some(command) = COMMAND_LOOKUP.get(name) else {
+        log::warn!(
+            "received component interaction with unroutable custom id {}",
+            component.custom_id
+        );
 
         return Ok(());
-    }
+    };
+
+    let locale = interaction_create.locale.as_deref().unwrap_or("en-GB");
+    let localizer = Localizer::new(&LOCALIZATION_HOLDER, locale);
 
-    if let Err(error) = command
-        .execute(cloned.0, interaction_client, localizer)
+    if let Err(error) = MIDDLEWARES
+        .run_component(
+            command.as_ref(),
+            interaction_create.0.clone(),
+            interaction_client,
+            localizer,
+        )
         .await
     {
         crate::errorhandler::handle_interaction_error(
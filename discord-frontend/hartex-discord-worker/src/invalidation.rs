@@ -0,0 +1,113 @@
+/*
+ * SPDX-License-Identifier: AGPL-3.0-only
+ *
+ * This file is part of HarTex.
+ *
+ * HarTex
+ * Copyright (c) 2021-2024 HarTex Project Developers
+ *
+ * HarTex is free software; you can redistribute it and/or modify
+ * it under the terms of the GNU Affero General Public License as published by
+ * the Free Software Foundation; either version 3 of the License, or
+ * (at your option) any later version.
+ *
+ * HarTex is distributed in the hope that it will be useful,
+ * but WITHOUT ANY WARRANTY; without even the implied warranty of
+ * MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+ * GNU Affero General Public License for more details.
+ *
+ * You should have received a copy of the GNU Affero General Public License along
+ * with HarTex. If not, see <https://www.gnu.org/licenses/>.
+ */
+
+//! # Cache Invalidations
+//!
+//! This module keeps the L1 cache of the entity repositories coherent with the entity cache,
+//! discarding the entities invalidated by the entitycache service as its invalidations are
+//! received from the internal event topic.
+//!
+//! Every worker holds its own L1 cache and must therefore receive every invalidation. Rather than
+//! having the partitions of the topic balanced between the workers of a consumer group, every
+//! worker is assigned every partition, starting from the invalidations published after it starts.
+
+use std::env;
+use std::time::Duration;
+
+use futures_util::StreamExt;
+use hartex_discord_entitycache_repositories::l1;
+use hartex_discord_internal_events::InternalEvent;
+use hartex_kafka_utils::traits::ClientConfigUtils;
+use hartex_log::log;
+use miette::IntoDiagnostic;
+use rdkafka::consumer::Consumer;
+use rdkafka::consumer::StreamConsumer;
+use rdkafka::message::Message;
+use rdkafka::ClientConfig;
+use rdkafka::Offset;
+use rdkafka::TopicPartitionList;
+
+/// The time to wait for the metadata of the internal event topic.
+const METADATA_TIMEOUT: Duration = Duration::from_secs(10);
+
+/// Listen for cache invalidations and apply them to the L1 cache.
+pub async fn listen(bootstrap_servers: Vec<String>) -> miette::Result<()> {
+    let topic = env::var("KAFKA_TOPIC_INTERNAL_EVENT_PAYLOAD").into_diagnostic()?;
+
+    // offsets are never committed, as invalidations published before the worker started do not
+    // concern its cache
+    let consumer = ClientConfig::new()
+        .bootstrap_servers(bootstrap_servers.into_iter())
+        .group_id("com.github.teamhartex.hartex.internal.event.consumer")
+        .set("auto.offset.reset", "latest")
+        .set("enable.auto.commit", "false")
+        .create::<StreamConsumer>()
+        .into_diagnostic()?;
+
+    let metadata = consumer
+        .fetch_metadata(Some(&topic), METADATA_TIMEOUT)
+        .into_diagnostic()?;
+    let mut partitions = TopicPartitionList::new();
+    for partition in metadata
+        .topics()
+        .iter()
+        .flat_map(|topic| topic.partitions())
+    {
+        partitions
+            .add_partition_offset(&topic, partition.id(), Offset::End)
+            .into_diagnostic()?;
+    }
+
+    consumer.assign(&partitions).into_diagnostic()?;
+
+    while let Some(result) = consumer.stream().next().await {
+        let message = match result {
+            Ok(message) => message,
+            Err(error) => {
+                log::warn!("failed to receive internal event: {error:?}");
+                continue;
+            }
+        };
+
+        let Some(bytes) = message.payload() else {
+            continue;
+        };
+
+        match serde_json::from_slice::<InternalEvent>(bytes) {
+            Ok(InternalEvent::EntityCacheInvalidation(invalidation)) => {
+                if let Err(error) = l1::apply_invalidation(&invalidation) {
+                    log::warn!("failed to apply cache invalidation: {error:?}");
+                }
+            }
+            Ok(InternalEvent::EntityCacheGuildInvalidation(invalidation)) => {
+                if let Err(error) = l1::apply_guild_invalidation(&invalidation) {
+                    log::warn!("failed to apply guild cache invalidation: {error:?}");
+                }
+            }
+            // updates are handled by the modlog of a single worker instead, see `modlog::listen`
+            Ok(InternalEvent::EntityCacheUpdate(_)) => (),
+            Err(error) => log::warn!("failed to deserialize internal event: {error:?}"),
+        }
+    }
+
+    Ok(())
+}
@@ -0,0 +1,190 @@
+/*
+ * SPDX-License-Identifier: AGPL-3.0-only
+ *
+ * This file is part of HarTex.
+ *
+ * HarTex
+ * Copyright (c) 2021-2024 HarTex Project Developers
+ *
+ * HarTex is free software; you can redistribute it and/or modify
+ * it under the terms of the GNU Affero General Public License as published by
+ * the Free Software Foundation; either version 3 of the License, or
+ * (at your option) any later version.
+ *
+ * HarTex is distributed in the hope that it will be useful,
+ * but WITHOUT ANY WARRANTY; without even the implied warranty of
+ * MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+ * GNU Affero General Public License for more details.
+ *
+ * You should have received a copy of the GNU Affero General Public License along
+ * with HarTex. If not, see <https://www.gnu.org/licenses/>.
+ */
+
+//! # Plugin Lifecycle
+//!
+//! This module dispatches the lifecycle hooks of plugins: the bot joining or leaving a guild, the
+//! configuration of a guild being changed, and plugins being enabled or disabled as a result.
+//!
+//! Errors returned from hooks are logged rather than propagated, such that a failing plugin does
+//! not prevent the hooks of other plugins from running.
+//!
+//! The plugins enabled in every guild known to this worker are tracked, such that enable and
+//! disable hooks are dispatched by comparing against the last seen state, regardless of whether
+//! the configuration of the guild happens to be cached.
+
+use std::collections::HashMap;
+use std::collections::HashSet;
+use std::sync::Mutex;
+
+use hartex_discord_commands_core::traits::Plugin;
+use hartex_discord_commands_core::traits::PluginMetadata;
+use hartex_discord_configuration_provider::ConfigurationProvider;
+use hartex_discord_core::discord::model::gateway::payload::incoming::GuildDelete;
+use hartex_discord_core::discord::model::gateway::payload::incoming::Ready;
+use hartex_discord_core::discord::model::id::marker::GuildMarker;
+use hartex_discord_core::discord::model::id::Id;
+use hartex_log::log;
+use once_cell::sync::Lazy;
+
+/// The plugins provided by the bot.
+pub static PLUGINS: Lazy<Vec<Box<dyn Plugin + Send + Sync>>> =
+    Lazy::new(hartex_discord_commands::plugins);
+
+/// Guilds the bot is already in as of the last `READY` payload, but that have yet to be received
+/// with a `GUILD_CREATE` payload.
+///
+/// `GUILD_CREATE` payloads of these guilds do not signify the bot joining the guild.
+static PENDING_GUILDS: Lazy<Mutex<HashSet<Id<GuildMarker>>>> =
+    Lazy::new(|| Mutex::new(HashSet::new()));
+
+/// The last seen enabled states of plugins by plugin name, by guild, for the guilds known to
+/// this worker.
+static ENABLED_PLUGINS: Lazy<Mutex<HashMap<Id<GuildMarker>, HashMap<String, bool>>>> =
+    Lazy::new(|| Mutex::new(HashMap::new()));
+
+/// Records the guilds the bot is already in upon receiving a `READY` payload.
+pub fn ready(ready: &Ready) {
+    PENDING_GUILDS
+        .lock()
+        .unwrap()
+        .extend(ready.guilds.iter().map(|guild| guild.id));
+}
+
+/// Dispatches the guild join hooks upon receiving a `GUILD_CREATE` payload, unless the guild is
+/// one the bot is already in.
+///
+/// Guilds the bot is already in include those in the last `READY` payload as well as those
+/// already known to this worker, which are received again once they become available after an
+/// outage.
+pub async fn guild_create(guild_id: Id<GuildMarker>) {
+    let pending = PENDING_GUILDS.lock().unwrap().remove(&guild_id);
+    let known = ENABLED_PLUGINS.lock().unwrap().contains_key(&guild_id);
+
+    let enabled_plugins = enabled_plugins(guild_id).await;
+    ENABLED_PLUGINS
+        .lock()
+        .unwrap()
+        .insert(guild_id, enabled_plugins);
+
+    if pending || known {
+        return;
+    }
+
+    for plugin in PLUGINS.iter() {
+        if let Err(error) = plugin.on_guild_join(guild_id).await {
+            log::error!(
+                "guild join hook of plugin {} failed for guild {guild_id}: {error:?}",
+                plugin.name()
+            );
+        }
+    }
+}
+
+/// Dispatches the guild leave hooks upon receiving a `GUILD_DELETE` payload, unless the guild
+/// merely became unavailable.
+pub async fn guild_delete(guild_delete: &GuildDelete) {
+    if guild_delete.unavailable {
+        return;
+    }
+
+    let guild_id = guild_delete.id;
+    ConfigurationProvider::invalidate(guild_id);
+    ENABLED_PLUGINS.lock().unwrap().remove(&guild_id);
+
+    for plugin in PLUGINS.iter() {
+        if let Err(error) = plugin.on_guild_leave(guild_id).await {
+            log::error!(
+                "guild leave hook of plugin {} failed for guild {guild_id}: {error:?}",
+                plugin.name()
+            );
+        }
+    }
+}
+
+/// Invalidates the cached configuration of a guild upon its configuration being changed, and
+/// dispatches the enable, disable and configuration change hooks.
+///
+/// Enable and disable hooks are only dispatched for guilds known to this worker, as the plugins
+/// previously enabled in other guilds are unknown.
+pub async fn configuration_changed(guild_id: Id<GuildMarker>) {
+    ConfigurationProvider::invalidate(guild_id);
+
+    let enabled_plugins = enabled_plugins(guild_id).await;
+    let previously_enabled = {
+        let mut known = ENABLED_PLUGINS.lock().unwrap();
+        known.get_mut(&guild_id).map(|previous| {
+            // plugins whose enabled state cannot be determined keep their last seen state
+            let snapshot = previous.clone();
+            previous.extend(enabled_plugins.clone());
+
+            snapshot
+        })
+    };
+
+    for plugin in PLUGINS.iter() {
+        let name = plugin.name();
+        let previous = previously_enabled
+            .as_ref()
+            .and_then(|previously_enabled| previously_enabled.get(&name));
+        let result = match (previous, enabled_plugins.get(&name)) {
+            (Some(false), Some(true)) => plugin.on_enable(guild_id).await,
+            (Some(true), Some(false)) => plugin.on_disable(guild_id).await,
+            _ => Ok(()),
+        };
+
+        if let Err(error) = result {
+            log::error!(
+                "enable or disable hook of plugin {} failed for guild {guild_id}: {error:?}",
+                plugin.name()
+            );
+        }
+
+        if let Err(error) = plugin.on_configuration_change(guild_id).await {
+            log::error!(
+                "configuration change hook of plugin {} failed for guild {guild_id}: {error:?}",
+                plugin.name()
+            );
+        }
+    }
+}
+
+/// Returns the enabled states of the plugins in a guild, by plugin name.
+///
+/// Plugins whose enabled state cannot be determined are logged and left out.
+async fn enabled_plugins(guild_id: Id<GuildMarker>) -> HashMap<String, bool> {
+    let mut enabled_plugins = HashMap::new();
+
+    for plugin in PLUGINS.iter() {
+        match plugin.enabled(guild_id).await {
+            Ok(enabled) => {
+                enabled_plugins.insert(plugin.name(), enabled);
+            }
+            Err(error) => log::error!(
+                "failed to determine whether plugin {} is enabled for guild {guild_id}: {error:?}",
+                plugin.name()
+            ),
+        }
+    }
+
+    enabled_plugins
+}
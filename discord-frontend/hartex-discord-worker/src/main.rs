@@ -33,10 +33,13 @@ use std::str;
 use std::str::Utf8Error;
 
 use futures_util::StreamExt;
+use hartex_discord_commands_core::middleware::maintenance;
 use hartex_discord_core::discord::model::gateway::event::GatewayEventDeserializer;
 use hartex_discord_core::dotenvy;
 use hartex_discord_core::tokio;
 use hartex_discord_core::tokio::signal;
+use hartex_discord_entitycache_repositories::l1;
+use hartex_discord_entitycache_repositories::l1::L1Configuration;
 use hartex_discord_utils::CLIENT;
 use hartex_discord_utils::TOKEN;
 use hartex_kafka_utils::traits::ClientConfigUtils;
@@ -56,11 +59,18 @@ use serde_scan::scan;
 use crate::error::ConsumerError;
 use crate::error::ConsumerErrorKind;
 use crate::interaction::COMMAND_LOOKUP;
+use crate::interaction::MIDDLEWARES;
+use crate::modlog::MODLOG_QUEUE;
 
+mod analytics;
+mod appearance;
 mod error;
 mod errorhandler;
 mod eventcallback;
 mod interaction;
+mod invalidation;
+mod lifecycle;
+mod modlog;
 
 /// Entry point.
 #[allow(clippy::large_futures)]
@@ -73,8 +83,18 @@ pub async fn main() -> miette::Result<()> {
 
     Lazy::force(&CLIENT);
     Lazy::force(&COMMAND_LOOKUP);
+    Lazy::force(&MIDDLEWARES);
+    Lazy::force(&MODLOG_QUEUE);
     Lazy::force(&TOKEN);
 
+    maintenance::set_enabled(env::var("MAINTENANCE_MODE").is_ok_and(|value| value == "true"));
+
+    tokio::spawn(async {
+        if let Err(error) = appearance::listen().await {
+            log::error!("configuration update listener failed: {error:?}");
+        }
+    });
+
     let bootstrap_servers = env::var("KAFKA_BOOTSTRAP_SERVERS")
         .into_diagnostic()?
         .split(';')
@@ -82,6 +102,28 @@ pub async fn main() -> miette::Result<()> {
         .collect::<Vec<_>>();
     let topic = env::var("KAFKA_TOPIC_INBOUND_DISCORD_GATEWAY_PAYLOAD").into_diagnostic()?;
 
+    let modlog_bootstrap_servers = bootstrap_servers.clone();
+    tokio::spawn(async move {
+        if let Err(error) = modlog::listen(modlog_bootstrap_servers).await {
+            log::error!("modlog entity cache update listener failed: {error:?}");
+        }
+    });
+
+    if let Some(configuration) = L1Configuration::from_env() {
+        log::trace!(
+            "enabling entity cache l1 with a capacity of {}",
+            configuration.capacity
+        );
+        l1::configure(configuration);
+
+        let bootstrap_servers = bootstrap_servers.clone();
+        tokio::spawn(async move {
+            if let Err(error) = invalidation::listen(bootstrap_servers).await {
+                log::error!("cache invalidation listener failed: {error:?}");
+            }
+        });
+    }
+
     let producer = ClientConfig::new()
         .bootstrap_servers(bootstrap_servers.clone().into_iter())
         .compression_type(CompressionType::Lz4)
@@ -0,0 +1,1117 @@
+/*
+ * SPDX-License-Identifier: AGPL-3.0-only
+ *
+ * This file is part of HarTex.
+ *
+ * HarTex
+ * Copyright (c) 2021-2024 HarTex Project Developers
+ *
+ * HarTex is free software; you can redistribute it and/or modify
+ * it under the terms of the GNU Affero General Public License as published by
+ * the Free Software Foundation; either version 3 of the License, or
+ * (at your option) any later version.
+ *
+ * HarTex is distributed in the hope that it will be useful,
+ * but WITHOUT ANY WARRANTY; without even the implied warranty of
+ * MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+ * GNU Affero General Public License for more details.
+ *
+ * You should have received a copy of the GNU Affero General Public License along
+ * with HarTex. If not, see <https://www.gnu.org/licenses/>.
+ */
+
+//! # Modlog Runtime
+//!
+//! This module emits modlog messages to the channels of the loggers configured for a guild,
+//! for every gateway event matching the event flags of the loggers.
+//!
+//! Messages are not sent immediately; instead they are queued per channel and flushed
+//! periodically, such that bursts of events are batched into as few messages as possible and
+//! the rate limits of the channels are respected.
+//!
+//! Member and voice state updates are not handled as they are received from the gateway, as the
+//! event flags they trigger depend on what they changed. They are instead handled once the
+//! entitycache service has applied them, from the updates it publishes with the previous state of
+//! the entities they changed.
+
+use std::collections::HashMap;
+use std::collections::VecDeque;
+use std::env;
+use std::str::FromStr;
+use std::sync::RwLock;
+use std::time::Duration;
+
+use futures_util::StreamExt;
+use hartex_discord_configuration_models::eventflags::EventFlags;
+use hartex_discord_configuration_models::eventflags::PreviousState;
+use hartex_discord_configuration_models::plugins::modlog::logger::ModlogFormat;
+use hartex_discord_configuration_models::plugins::modlog::ModlogPlugin;
+use hartex_discord_configuration_provider::ConfigurationProvider;
+use hartex_discord_core::discord::http::error::Error;
+use hartex_discord_core::discord::http::error::ErrorType;
+use hartex_discord_core::discord::mention::Mention;
+use hartex_discord_core::discord::model::channel::message::AllowedMentions;
+use hartex_discord_core::discord::model::channel::message::Embed;
+use hartex_discord_core::discord::model::gateway::event::DispatchEvent;
+use hartex_discord_core::discord::model::gateway::payload::incoming::MemberUpdate;
+use hartex_discord_core::discord::model::gateway::payload::incoming::VoiceStateUpdate;
+use hartex_discord_core::discord::model::id::marker::ChannelMarker;
+use hartex_discord_core::discord::model::id::marker::GuildMarker;
+use hartex_discord_core::discord::model::id::marker::RoleMarker;
+use hartex_discord_core::discord::model::id::Id;
+use hartex_discord_core::discord::model::util::ImageHash;
+use hartex_discord_core::discord::util::builder::embed::EmbedBuilder;
+use hartex_discord_core::discord::util::builder::embed::EmbedFieldBuilder;
+use hartex_discord_core::tokio::select;
+use hartex_discord_core::tokio::spawn;
+use hartex_discord_core::tokio::sync::mpsc;
+use hartex_discord_core::tokio::sync::mpsc::UnboundedReceiver;
+use hartex_discord_core::tokio::sync::mpsc::UnboundedSender;
+use hartex_discord_core::tokio::time;
+use hartex_discord_entitycache_core::traits::Repository;
+use hartex_discord_entitycache_entities::message::MessageEntity;
+use hartex_discord_entitycache_repositories::message::CachedMessageRepository;
+use hartex_discord_internal_events::EntityCacheUpdate;
+use hartex_discord_internal_events::EntityKind;
+use hartex_discord_internal_events::InternalEvent;
+use hartex_discord_utils::markdown::MarkdownStyle;
+use hartex_discord_utils::CLIENT;
+use hartex_kafka_utils::traits::ClientConfigUtils;
+use hartex_localization_core::Localizer;
+use hartex_localization_core::LOCALIZATION_HOLDER;
+use hartex_log::log;
+use miette::IntoDiagnostic;
+use once_cell::sync::Lazy;
+use rdkafka::consumer::Consumer;
+use rdkafka::consumer::StreamConsumer;
+use rdkafka::message::Message;
+use rdkafka::ClientConfig;
+use serde::Deserialize;
+
+/// The interval at which queued modlog messages are flushed.
+const FLUSH_INTERVAL: Duration = Duration::from_secs(2);
+
+/// The maximum number of messages sent to a single channel per flush.
+///
+/// Together with the flush interval, this keeps the bot well below the rate limit of five
+/// messages per five seconds per channel.
+const MESSAGES_PER_FLUSH: usize = 2;
+
+/// The maximum length of the content of a message.
+const MAX_CONTENT_LENGTH: usize = 2000;
+
+/// The maximum number of embeds in a message.
+const MAX_EMBEDS: usize = 10;
+
+/// The maximum length of the value of an embed field.
+const MAX_FIELD_VALUE_LENGTH: usize = 1024;
+
+/// The colour of embeds for entities being created.
+const CREATED_COLOR: u32 = 0x33_CC_66;
+
+/// The colour of embeds for entities being updated.
+const UPDATED_COLOR: u32 = 0xFF_99_33;
+
+/// The colour of embeds for entities being deleted.
+const DELETED_COLOR: u32 = 0xFF_33_33;
+
+/// The queue of modlog messages that are yet to be sent.
+///
+/// The first access to this spawns the task that batches and sends the queued messages.
+pub static MODLOG_QUEUE: Lazy<UnboundedSender<ModlogEntry>> = Lazy::new(|| {
+    let (sender, receiver) = mpsc::unbounded_channel();
+    spawn(batch(receiver));
+
+    sender
+});
+
+/// The preferred locales of the guilds, used for localizing modlog messages.
+///
+/// This is populated from the guild payloads received.
+static GUILD_LOCALES: Lazy<RwLock<HashMap<Id<GuildMarker>, String>>> =
+    Lazy::new(|| RwLock::new(HashMap::new()));
+
+/// A modlog message queued to be sent to a channel.
+pub struct ModlogEntry {
+    channel_id: Id<ChannelMarker>,
+    message: ModlogMessage,
+}
+
+/// A rendered modlog message.
+enum ModlogMessage {
+    /// A message in the default, text-only format.
+    Text(String),
+    /// A message in the pretty format.
+    Embed(Embed),
+}
+
+/// The summary of an event other than a single message being deleted or updated, from which its
+/// modlog message is rendered in either format.
+struct Summary {
+    /// The text of the message in the default format.
+    text: String,
+    /// The title of the embed in the pretty format.
+    title: String,
+    /// The colour of the embed in the pretty format.
+    color: u32,
+    /// The names and values of the fields of the embed in the pretty format.
+    fields: Vec<(String, String)>,
+}
+
+/// The state of a member before an update, as published by the entitycache service.
+#[derive(Deserialize)]
+struct PreviousMember {
+    avatar: Option<ImageHash>,
+    nick: Option<String>,
+    roles: Vec<Id<RoleMarker>>,
+}
+
+/// The voice state of a user before an update, as published by the entitycache service.
+#[derive(Deserialize)]
+struct PreviousVoiceState {
+    channel_id: Option<Id<ChannelMarker>>,
+}
+
+/// Handle an event for the modlog, queueing a message for every logger listening for it.
+#[allow(clippy::missing_panics_doc)]
+pub async fn handle(event: &DispatchEvent) -> miette::Result<()> {
+    match event {
+        DispatchEvent::GuildCreate(guild_create) => {
+            GUILD_LOCALES
+                .write()
+                .unwrap()
+                .insert(guild_create.id, guild_create.preferred_locale.clone());
+
+            return Ok(());
+        }
+        DispatchEvent::GuildUpdate(guild_update) => {
+            GUILD_LOCALES
+                .write()
+                .unwrap()
+                .insert(guild_update.id, guild_update.preferred_locale.clone());
+
+            return Ok(());
+        }
+        // these are handled once applied to the entity cache, see `listen`
+        DispatchEvent::MemberUpdate(_) | DispatchEvent::VoiceStateUpdate(_) => return Ok(()),
+        _ => (),
+    }
+
+    emit(event, None).await
+}
+
+/// Listen for the entity cache updates published by the entitycache service, handling the events
+/// they carry with the previous state of the entities they changed.
+///
+/// Unlike cache invalidations, every update is handled by a single worker of the consumer group.
+pub async fn listen(bootstrap_servers: Vec<String>) -> miette::Result<()> {
+    let topic = env::var("KAFKA_TOPIC_INTERNAL_EVENT_PAYLOAD").into_diagnostic()?;
+
+    let consumer = ClientConfig::new()
+        .bootstrap_servers(bootstrap_servers.into_iter())
+        .group_id("com.github.teamhartex.hartex.internal.event.modlog.consumer")
+        .create::<StreamConsumer>()
+        .into_diagnostic()?;
+
+    consumer.subscribe(&[&topic]).into_diagnostic()?;
+
+    while let Some(result) = consumer.stream().next().await {
+        let message = match result {
+            Ok(message) => message,
+            Err(error) => {
+                log::warn!("failed to receive internal event: {error:?}");
+                continue;
+            }
+        };
+
+        let Some(bytes) = message.payload() else {
+            continue;
+        };
+
+        let update = match serde_json::from_slice::<InternalEvent>(bytes) {
+            Ok(InternalEvent::EntityCacheUpdate(update)) => update,
+            Ok(_) => continue,
+            Err(error) => {
+                log::warn!("failed to deserialize internal event: {error:?}");
+                continue;
+            }
+        };
+
+        if let Err(error) = handle_update(update).await {
+            log::error!("failed to handle entity cache update for the modlog: {error:?}");
+        }
+    }
+
+    Ok(())
+}
+
+/// Handle an entity cache update for the modlog, with the previous state of the entity it
+/// changed.
+async fn handle_update(update: EntityCacheUpdate) -> miette::Result<()> {
+    match update.kind {
+        EntityKind::Member => {
+            let member_update =
+                serde_json::from_value::<MemberUpdate>(update.event).into_diagnostic()?;
+            let previous = update
+                .previous
+                .map(serde_json::from_value::<PreviousMember>)
+                .transpose()
+                .into_diagnostic()?;
+
+            emit(
+                &DispatchEvent::MemberUpdate(Box::new(member_update)),
+                previous.as_ref().map(|previous| PreviousState::Member {
+                    avatar: previous.avatar,
+                    nick: previous.nick.as_deref(),
+                    roles: &previous.roles,
+                }),
+            )
+            .await
+        }
+        EntityKind::VoiceState => {
+            let voice_state_update =
+                serde_json::from_value::<VoiceStateUpdate>(update.event).into_diagnostic()?;
+
+            // only the voice states of connected users are cached, hence users without a cached
+            // voice state were not connected
+            let channel_id = update
+                .previous
+                .map(serde_json::from_value::<PreviousVoiceState>)
+                .transpose()
+                .into_diagnostic()?
+                .and_then(|previous| previous.channel_id);
+
+            emit(
+                &DispatchEvent::VoiceStateUpdate(Box::new(voice_state_update)),
+                Some(PreviousState::Voice { channel_id }),
+            )
+            .await
+        }
+        _ => Ok(()),
+    }
+}
+
+/// Queue a message for every logger listening for an event, given the previous state of the
+/// entity it changed if relevant.
+async fn emit(event: &DispatchEvent, previous: Option<PreviousState<'_>>) -> miette::Result<()> {
+    let Some((guild_id, flags)) = triggered_flags(event, previous) else {
+        return Ok(());
+    };
+
+    let configuration =
+        ConfigurationProvider::plugin_configuration::<ModlogPlugin>(guild_id).await?;
+    if !configuration.enabled || configuration.loggers.is_empty() {
+        return Ok(());
+    }
+
+    let locale = GUILD_LOCALES
+        .read()
+        .unwrap()
+        .get(&guild_id)
+        .cloned()
+        .unwrap_or_else(|| String::from("en-GB"));
+    let localizer = Localizer::new(&LOCALIZATION_HOLDER, &locale);
+
+    // deleted messages are only known by their IDs; their contents are retrieved from the cache
+    let cached = match event {
+        DispatchEvent::MessageDelete(message_delete) => {
+            CachedMessageRepository.get(message_delete.id).await.ok()
+        }
+        _ => None,
+    };
+
+    for logger in configuration
+        .loggers
+        .iter()
+        .filter(|logger| logger.events.intersects(flags))
+    {
+        let Ok(channel_id) = Id::<ChannelMarker>::from_str(&logger.channel) else {
+            log::warn!(
+                "modlog logger in guild {guild_id} has an invalid channel id: {}",
+                logger.channel
+            );
+
+            continue;
+        };
+
+        let Some(message) = render(event, flags, cached.as_ref(), &logger.format, &localizer)?
+        else {
+            continue;
+        };
+
+        MODLOG_QUEUE
+            .send(ModlogEntry {
+                channel_id,
+                message,
+            })
+            .into_diagnostic()?;
+    }
+
+    Ok(())
+}
+
+/// Returns the guild and the event flags an event triggers, if any.
+fn triggered_flags(
+    event: &DispatchEvent,
+    previous: Option<PreviousState<'_>>,
+) -> Option<(Id<GuildMarker>, EventFlags)> {
+    let guild_id = match event {
+        DispatchEvent::BanAdd(ban_add) => ban_add.guild_id,
+        DispatchEvent::BanRemove(ban_remove) => ban_remove.guild_id,
+        DispatchEvent::ChannelCreate(channel_create) => channel_create.guild_id?,
+        DispatchEvent::ChannelDelete(channel_delete) => channel_delete.guild_id?,
+        DispatchEvent::ChannelPinsUpdate(channel_pins_update) => channel_pins_update.guild_id?,
+        DispatchEvent::ChannelUpdate(channel_update) => channel_update.guild_id?,
+        DispatchEvent::GuildEmojisUpdate(guild_emojis_update) => guild_emojis_update.guild_id,
+        DispatchEvent::InviteCreate(invite_create) => invite_create.guild_id,
+        DispatchEvent::InviteDelete(invite_delete) => invite_delete.guild_id,
+        DispatchEvent::MemberAdd(member_add) => member_add.guild_id,
+        DispatchEvent::MemberRemove(member_remove) => member_remove.guild_id,
+        DispatchEvent::MemberUpdate(member_update) => member_update.guild_id,
+        DispatchEvent::MessageDelete(message_delete) => message_delete.guild_id?,
+        DispatchEvent::MessageDeleteBulk(message_delete_bulk) => message_delete_bulk.guild_id?,
+        DispatchEvent::MessageUpdate(message_update) => message_update.guild_id?,
+        DispatchEvent::RoleCreate(role_create) => role_create.guild_id,
+        DispatchEvent::RoleDelete(role_delete) => role_delete.guild_id,
+        DispatchEvent::RoleUpdate(role_update) => role_update.guild_id,
+        DispatchEvent::VoiceStateUpdate(voice_state_update) => voice_state_update.0.guild_id?,
+        _ => return None,
+    };
+
+    let flags = EventFlags::triggered_by(event, previous);
+    if flags.is_empty() {
+        return None;
+    }
+
+    Some((guild_id, flags))
+}
+
+/// Render the modlog message of an event in the given format.
+///
+/// The cached message is used to render the contents of a deleted message, if available.
+#[allow(clippy::too_many_lines)]
+fn render(
+    event: &DispatchEvent,
+    flags: EventFlags,
+    cached: Option<&MessageEntity>,
+    format: &ModlogFormat,
+    localizer: &Localizer<'_>,
+) -> miette::Result<Option<ModlogMessage>> {
+    let message = match (event, format) {
+        (DispatchEvent::MessageDelete(message_delete), ModlogFormat::Default) => {
+            let text = match cached {
+                Some(cached) => localizer.modlog_modlog_message_deleted_cached(
+                    message_delete.channel_id.mention().to_string(),
+                    cached.content.clone(),
+                    message_delete.id.to_string().discord_inline_code(),
+                    cached.author_id.mention().to_string(),
+                )?,
+                None => localizer.modlog_modlog_message_deleted(
+                    message_delete.channel_id.mention().to_string(),
+                    message_delete.id.to_string().discord_inline_code(),
+                )?,
+            };
+
+            ModlogMessage::Text(text)
+        }
+        (DispatchEvent::MessageDelete(message_delete), ModlogFormat::Pretty) => {
+            let modlog_message_deleted_title = localizer.modlog_modlog_message_deleted_title()?;
+            let modlog_embed_attachments_field_name =
+                localizer.modlog_modlog_embed_attachments_field_name()?;
+            let modlog_embed_author_field_name =
+                localizer.modlog_modlog_embed_author_field_name()?;
+            let modlog_embed_channel_field_name =
+                localizer.modlog_modlog_embed_channel_field_name()?;
+            let modlog_embed_content_field_name =
+                localizer.modlog_modlog_embed_content_field_name()?;
+            let modlog_embed_message_field_name =
+                localizer.modlog_modlog_embed_message_field_name()?;
+
+            let mut builder = EmbedBuilder::new()
+                .color(DELETED_COLOR)
+                .title(modlog_message_deleted_title);
+
+            if let Some(cached) = cached {
+                builder = builder.field(
+                    EmbedFieldBuilder::new(
+                        modlog_embed_author_field_name,
+                        cached.author_id.mention().to_string(),
+                    )
+                    .inline(),
+                );
+            }
+
+            builder = builder
+                .field(
+                    EmbedFieldBuilder::new(
+                        modlog_embed_channel_field_name,
+                        message_delete.channel_id.mention().to_string(),
+                    )
+                    .inline(),
+                )
+                .field(
+                    EmbedFieldBuilder::new(
+                        modlog_embed_message_field_name,
+                        message_delete.id.to_string().discord_inline_code(),
+                    )
+                    .inline(),
+                );
+
+            if let Some(cached) = cached.filter(|cached| !cached.content.is_empty()) {
+                builder = builder.field(EmbedFieldBuilder::new(
+                    modlog_embed_content_field_name,
+                    truncate(&cached.content, MAX_FIELD_VALUE_LENGTH),
+                ));
+            }
+
+            if let Some(cached) = cached.filter(|cached| !cached.attachments.is_empty()) {
+                builder = builder.field(EmbedFieldBuilder::new(
+                    modlog_embed_attachments_field_name,
+                    truncate(&cached.attachments.join("\n"), MAX_FIELD_VALUE_LENGTH),
+                ));
+            }
+
+            ModlogMessage::Embed(builder.validate().into_diagnostic()?.build())
+        }
+        (DispatchEvent::MessageUpdate(message_update), ModlogFormat::Default) => {
+            let user_mention = match &message_update.author {
+                Some(author) => author.id.mention().to_string(),
+                None => localizer.modlog_modlog_unknown_user()?,
+            };
+
+            ModlogMessage::Text(localizer.modlog_modlog_message_updated(
+                message_update.channel_id.mention().to_string(),
+                message_update.id.to_string().discord_inline_code(),
+                user_mention,
+            )?)
+        }
+        (DispatchEvent::MessageUpdate(message_update), ModlogFormat::Pretty) => {
+            let modlog_message_updated_title = localizer.modlog_modlog_message_updated_title()?;
+            let modlog_embed_author_field_name =
+                localizer.modlog_modlog_embed_author_field_name()?;
+            let modlog_embed_channel_field_name =
+                localizer.modlog_modlog_embed_channel_field_name()?;
+            let modlog_embed_content_field_name =
+                localizer.modlog_modlog_embed_content_field_name()?;
+            let modlog_embed_message_field_name =
+                localizer.modlog_modlog_embed_message_field_name()?;
+
+            let user_mention = match &message_update.author {
+                Some(author) => author.id.mention().to_string(),
+                None => localizer.modlog_modlog_unknown_user()?,
+            };
+
+            let mut builder = EmbedBuilder::new()
+                .color(UPDATED_COLOR)
+                .title(modlog_message_updated_title)
+                .field(
+                    EmbedFieldBuilder::new(modlog_embed_author_field_name, user_mention).inline(),
+                )
+                .field(
+                    EmbedFieldBuilder::new(
+                        modlog_embed_channel_field_name,
+                        message_update.channel_id.mention().to_string(),
+                    )
+                    .inline(),
+                )
+                .field(
+                    EmbedFieldBuilder::new(
+                        modlog_embed_message_field_name,
+                        message_update.id.to_string().discord_inline_code(),
+                    )
+                    .inline(),
+                );
+
+            if let Some(content) = message_update
+                .content
+                .as_ref()
+                .filter(|content| !content.is_empty())
+            {
+                builder = builder.field(EmbedFieldBuilder::new(
+                    modlog_embed_content_field_name,
+                    truncate(content, MAX_FIELD_VALUE_LENGTH),
+                ));
+            }
+
+            ModlogMessage::Embed(builder.validate().into_diagnostic()?.build())
+        }
+        _ => {
+            let Some(summary) = summarize(event, flags, localizer)? else {
+                return Ok(None);
+            };
+
+            match format {
+                ModlogFormat::Default => ModlogMessage::Text(summary.text),
+                ModlogFormat::Pretty => {
+                    let builder = summary.fields.into_iter().fold(
+                        EmbedBuilder::new()
+                            .color(summary.color)
+                            .title(summary.title),
+                        |builder, (name, value)| {
+                            builder.field(
+                                EmbedFieldBuilder::new(
+                                    name,
+                                    truncate(&value, MAX_FIELD_VALUE_LENGTH),
+                                )
+                                .inline(),
+                            )
+                        },
+                    );
+
+                    ModlogMessage::Embed(builder.validate().into_diagnostic()?.build())
+                }
+            }
+        }
+    };
+
+    Ok(Some(message))
+}
+
+/// Summarize an event other than a single message being deleted or updated.
+///
+/// The event flags it triggered determine which message is used for events triggering one of
+/// several flags, such as voice state updates.
+#[allow(clippy::too_many_lines)]
+fn summarize(
+    event: &DispatchEvent,
+    flags: EventFlags,
+    localizer: &Localizer<'_>,
+) -> miette::Result<Option<Summary>> {
+    let summary = match event {
+        DispatchEvent::BanAdd(ban_add) => Summary {
+            text: localizer.modlog_modlog_member_banned(ban_add.user.id.mention().to_string())?,
+            title: localizer.modlog_modlog_member_banned_title()?,
+            color: DELETED_COLOR,
+            fields: vec![(
+                localizer.modlog_modlog_embed_user_field_name()?,
+                ban_add.user.id.mention().to_string(),
+            )],
+        },
+        DispatchEvent::BanRemove(ban_remove) => Summary {
+            text: localizer
+                .modlog_modlog_member_unbanned(ban_remove.user.id.mention().to_string())?,
+            title: localizer.modlog_modlog_member_unbanned_title()?,
+            color: CREATED_COLOR,
+            fields: vec![(
+                localizer.modlog_modlog_embed_user_field_name()?,
+                ban_remove.user.id.mention().to_string(),
+            )],
+        },
+        DispatchEvent::ChannelCreate(channel_create) => Summary {
+            text: localizer
+                .modlog_modlog_channel_created(channel_create.id.mention().to_string())?,
+            title: localizer.modlog_modlog_channel_created_title()?,
+            color: CREATED_COLOR,
+            fields: vec![(
+                localizer.modlog_modlog_embed_channel_field_name()?,
+                channel_create.id.mention().to_string(),
+            )],
+        },
+        DispatchEvent::ChannelDelete(channel_delete) => {
+            // the channel no longer exists, so it cannot be mentioned
+            let channel_name = channel_delete
+                .name
+                .clone()
+                .unwrap_or_else(|| channel_delete.id.to_string())
+                .discord_inline_code();
+
+            Summary {
+                text: localizer.modlog_modlog_channel_deleted(channel_name.clone())?,
+                title: localizer.modlog_modlog_channel_deleted_title()?,
+                color: DELETED_COLOR,
+                fields: vec![(
+                    localizer.modlog_modlog_embed_channel_field_name()?,
+                    channel_name,
+                )],
+            }
+        }
+        DispatchEvent::ChannelPinsUpdate(channel_pins_update) => Summary {
+            text: localizer.modlog_modlog_channel_pins_updated(
+                channel_pins_update.channel_id.mention().to_string(),
+            )?,
+            title: localizer.modlog_modlog_channel_pins_updated_title()?,
+            color: UPDATED_COLOR,
+            fields: vec![(
+                localizer.modlog_modlog_embed_channel_field_name()?,
+                channel_pins_update.channel_id.mention().to_string(),
+            )],
+        },
+        DispatchEvent::ChannelUpdate(channel_update) => Summary {
+            text: localizer
+                .modlog_modlog_channel_updated(channel_update.id.mention().to_string())?,
+            title: localizer.modlog_modlog_channel_updated_title()?,
+            color: UPDATED_COLOR,
+            fields: vec![(
+                localizer.modlog_modlog_embed_channel_field_name()?,
+                channel_update.id.mention().to_string(),
+            )],
+        },
+        DispatchEvent::GuildEmojisUpdate(guild_emojis_update) => Summary {
+            text: localizer.modlog_modlog_emojis_updated(guild_emojis_update.emojis.len())?,
+            title: localizer.modlog_modlog_emojis_updated_title()?,
+            color: UPDATED_COLOR,
+            fields: vec![(
+                localizer.modlog_modlog_embed_count_field_name()?,
+                guild_emojis_update.emojis.len().to_string(),
+            )],
+        },
+        DispatchEvent::InviteCreate(invite_create) => {
+            let user_mention = match &invite_create.inviter {
+                Some(inviter) => inviter.id.mention().to_string(),
+                None => localizer.modlog_modlog_unknown_user()?,
+            };
+
+            Summary {
+                text: localizer.modlog_modlog_invite_created(
+                    invite_create.channel_id.mention().to_string(),
+                    invite_create.code.clone().discord_inline_code(),
+                    user_mention.clone(),
+                )?,
+                title: localizer.modlog_modlog_invite_created_title()?,
+                color: CREATED_COLOR,
+                fields: vec![
+                    (
+                        localizer.modlog_modlog_embed_code_field_name()?,
+                        invite_create.code.clone().discord_inline_code(),
+                    ),
+                    (
+                        localizer.modlog_modlog_embed_channel_field_name()?,
+                        invite_create.channel_id.mention().to_string(),
+                    ),
+                    (
+                        localizer.modlog_modlog_embed_user_field_name()?,
+                        user_mention,
+                    ),
+                ],
+            }
+        }
+        DispatchEvent::InviteDelete(invite_delete) => Summary {
+            text: localizer.modlog_modlog_invite_deleted(
+                invite_delete.channel_id.mention().to_string(),
+                invite_delete.code.clone().discord_inline_code(),
+            )?,
+            title: localizer.modlog_modlog_invite_deleted_title()?,
+            color: DELETED_COLOR,
+            fields: vec![
+                (
+                    localizer.modlog_modlog_embed_code_field_name()?,
+                    invite_delete.code.clone().discord_inline_code(),
+                ),
+                (
+                    localizer.modlog_modlog_embed_channel_field_name()?,
+                    invite_delete.channel_id.mention().to_string(),
+                ),
+            ],
+        },
+        DispatchEvent::MemberAdd(member_add) => Summary {
+            text: localizer
+                .modlog_modlog_member_joined(member_add.member.user.id.mention().to_string())?,
+            title: localizer.modlog_modlog_member_joined_title()?,
+            color: CREATED_COLOR,
+            fields: vec![(
+                localizer.modlog_modlog_embed_user_field_name()?,
+                member_add.member.user.id.mention().to_string(),
+            )],
+        },
+        DispatchEvent::MemberRemove(member_remove) => Summary {
+            text: localizer
+                .modlog_modlog_member_left(member_remove.user.id.mention().to_string())?,
+            title: localizer.modlog_modlog_member_left_title()?,
+            color: DELETED_COLOR,
+            fields: vec![(
+                localizer.modlog_modlog_embed_user_field_name()?,
+                member_remove.user.id.mention().to_string(),
+            )],
+        },
+        DispatchEvent::MemberUpdate(member_update) => {
+            let nickname = match &member_update.nick {
+                Some(nick) => nick.clone(),
+                None => localizer.modlog_modlog_none()?,
+            };
+            let roles = if member_update.roles.is_empty() {
+                localizer.modlog_modlog_none()?
+            } else {
+                member_update
+                    .roles
+                    .iter()
+                    .map(|role_id| role_id.mention().to_string())
+                    .collect::<Vec<_>>()
+                    .join(" ")
+            };
+
+            Summary {
+                text: localizer
+                    .modlog_modlog_member_updated(member_update.user.id.mention().to_string())?,
+                title: localizer.modlog_modlog_member_updated_title()?,
+                color: UPDATED_COLOR,
+                fields: vec![
+                    (
+                        localizer.modlog_modlog_embed_user_field_name()?,
+                        member_update.user.id.mention().to_string(),
+                    ),
+                    (
+                        localizer.modlog_modlog_embed_nickname_field_name()?,
+                        nickname,
+                    ),
+                    (localizer.modlog_modlog_embed_roles_field_name()?, roles),
+                ],
+            }
+        }
+        DispatchEvent::MessageDeleteBulk(message_delete_bulk) => Summary {
+            text: localizer.modlog_modlog_messages_bulk_deleted(
+                message_delete_bulk.channel_id.mention().to_string(),
+                message_delete_bulk.ids.len(),
+            )?,
+            title: localizer.modlog_modlog_messages_bulk_deleted_title()?,
+            color: DELETED_COLOR,
+            fields: vec![
+                (
+                    localizer.modlog_modlog_embed_channel_field_name()?,
+                    message_delete_bulk.channel_id.mention().to_string(),
+                ),
+                (
+                    localizer.modlog_modlog_embed_count_field_name()?,
+                    message_delete_bulk.ids.len().to_string(),
+                ),
+            ],
+        },
+        DispatchEvent::RoleCreate(role_create) => Summary {
+            text: localizer
+                .modlog_modlog_role_created(role_create.role.id.mention().to_string())?,
+            title: localizer.modlog_modlog_role_created_title()?,
+            color: CREATED_COLOR,
+            fields: vec![(
+                localizer.modlog_modlog_embed_role_field_name()?,
+                role_create.role.id.mention().to_string(),
+            )],
+        },
+        DispatchEvent::RoleDelete(role_delete) => Summary {
+            // the role no longer exists, so it cannot be mentioned
+            text: localizer.modlog_modlog_role_deleted(
+                role_delete.role_id.to_string().discord_inline_code(),
+            )?,
+            title: localizer.modlog_modlog_role_deleted_title()?,
+            color: DELETED_COLOR,
+            fields: vec![(
+                localizer.modlog_modlog_embed_role_field_name()?,
+                role_delete.role_id.to_string().discord_inline_code(),
+            )],
+        },
+        DispatchEvent::RoleUpdate(role_update) => Summary {
+            text: localizer
+                .modlog_modlog_role_updated(role_update.role.id.mention().to_string())?,
+            title: localizer.modlog_modlog_role_updated_title()?,
+            color: UPDATED_COLOR,
+            fields: vec![(
+                localizer.modlog_modlog_embed_role_field_name()?,
+                role_update.role.id.mention().to_string(),
+            )],
+        },
+        DispatchEvent::VoiceStateUpdate(voice_state_update) => {
+            let user_mention = voice_state_update.0.user_id.mention().to_string();
+            let mut fields = vec![(
+                localizer.modlog_modlog_embed_user_field_name()?,
+                user_mention.clone(),
+            )];
+
+            let Some(channel_id) = voice_state_update.0.channel_id else {
+                return Ok(Some(Summary {
+                    text: localizer.modlog_modlog_voice_left(user_mention)?,
+                    title: localizer.modlog_modlog_voice_left_title()?,
+                    color: DELETED_COLOR,
+                    fields,
+                }));
+            };
+
+            fields.push((
+                localizer.modlog_modlog_embed_channel_field_name()?,
+                channel_id.mention().to_string(),
+            ));
+
+            if flags.contains(EventFlags::VOICE_MOVED) {
+                Summary {
+                    text: localizer.modlog_modlog_voice_moved(
+                        channel_id.mention().to_string(),
+                        user_mention,
+                    )?,
+                    title: localizer.modlog_modlog_voice_moved_title()?,
+                    color: UPDATED_COLOR,
+                    fields,
+                }
+            } else {
+                Summary {
+                    text: localizer.modlog_modlog_voice_joined(
+                        channel_id.mention().to_string(),
+                        user_mention,
+                    )?,
+                    title: localizer.modlog_modlog_voice_joined_title()?,
+                    color: CREATED_COLOR,
+                    fields,
+                }
+            }
+        }
+        _ => return Ok(None),
+    };
+
+    Ok(Some(summary))
+}
+
+/// Truncates a string to at most the given number of characters.
+fn truncate(string: &str, length: usize) -> String {
+    if string.chars().count() <= length {
+        return string.to_string();
+    }
+
+    let mut truncated = string.chars().take(length - 1).collect::<String>();
+    truncated.push('…');
+
+    truncated
+}
+
+/// Batches the queued modlog messages per channel and periodically sends them.
+async fn batch(mut receiver: UnboundedReceiver<ModlogEntry>) {
+    let mut pending = HashMap::<Id<ChannelMarker>, VecDeque<ModlogMessage>>::new();
+    let mut interval = time::interval(FLUSH_INTERVAL);
+
+    loop {
+        select! {
+            entry = receiver.recv() => {
+                let Some(entry) = entry else {
+                    break;
+                };
+
+                pending.entry(entry.channel_id).or_default().push_back(entry.message);
+            }
+            _ = interval.tick() => {
+                for (channel_id, messages) in &mut pending {
+                    if let Err(error) = flush(*channel_id, messages).await {
+                        log::error!("failed to send modlog messages to channel {channel_id}: {error:?}");
+                    }
+                }
+
+                pending.retain(|_, messages| !messages.is_empty());
+            }
+        }
+    }
+}
+
+/// Sends the queued messages of a channel, merging consecutive messages of the same format into
+/// a single message where possible.
+///
+/// Messages are only removed from the queue once they have been sent, such that messages failing
+/// to be sent are retried on the next flush, unless Discord rejected them outright.
+async fn flush(
+    channel_id: Id<ChannelMarker>,
+    messages: &mut VecDeque<ModlogMessage>,
+) -> miette::Result<()> {
+    for _ in 0..MESSAGES_PER_FLUSH {
+        let (result, count) = match messages.front() {
+            Some(ModlogMessage::Text(text)) => {
+                let mut content = truncate(text, MAX_CONTENT_LENGTH);
+                let mut count = 1;
+                while let Some(ModlogMessage::Text(next)) = messages.get(count) {
+                    if content.chars().count() + next.chars().count() + 1 > MAX_CONTENT_LENGTH {
+                        break;
+                    }
+
+                    content.push('\n');
+                    content.push_str(next);
+                    count += 1;
+                }
+
+                let result = CLIENT
+                    .create_message(channel_id)
+                    .allowed_mentions(Some(&AllowedMentions::default()))
+                    .content(&content)
+                    .await;
+
+                (result, count)
+            }
+            Some(ModlogMessage::Embed(_)) => {
+                let embeds = messages
+                    .iter()
+                    .map_while(|message| match message {
+                        ModlogMessage::Embed(embed) => Some(embed.clone()),
+                        ModlogMessage::Text(_) => None,
+                    })
+                    .take(MAX_EMBEDS)
+                    .collect::<Vec<_>>();
+
+                let result = CLIENT.create_message(channel_id).embeds(&embeds).await;
+
+                (result, embeds.len())
+            }
+            None => break,
+        };
+
+        match result {
+            Ok(_) => {
+                messages.drain(..count);
+            }
+            Err(error) if rejected(&error) => {
+                log::error!(
+                    "discarding {count} modlog messages rejected by channel {channel_id}: {error:?}"
+                );
+
+                messages.drain(..count);
+            }
+            Err(error) => return Err(error).into_diagnostic(),
+        }
+    }
+
+    Ok(())
+}
+
+/// Returns whether a request failed because Discord rejected it, such that retrying it would fail
+/// again (for example when the channel was deleted or the bot lacks permissions in it).
+fn rejected(error: &Error) -> bool {
+    match error.kind() {
+        ErrorType::Response { status, .. } => status.is_client_error() && status.get() != 429,
+        _ => false,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::env;
+    use std::sync::Once;
+
+    use hartex_discord_core::discord::model::gateway::payload::incoming::InviteDelete;
+    use hartex_discord_core::discord::model::gateway::payload::incoming::RoleDelete;
+    use serde_json::json;
+
+    use super::*;
+
+    static PREPARE: Once = Once::new();
+
+    fn localizer() -> Localizer<'static> {
+        PREPARE.call_once(|| {
+            if env::var_os("LOCALIZATION_BASE_PATH").is_none() {
+                env::set_var(
+                    "LOCALIZATION_BASE_PATH",
+                    concat!(env!("CARGO_MANIFEST_DIR"), "/../../localization/locales"),
+                );
+            }
+        });
+
+        Localizer::new(&LOCALIZATION_HOLDER, "en-GB")
+    }
+
+    fn role_delete() -> DispatchEvent {
+        DispatchEvent::RoleDelete(RoleDelete {
+            guild_id: Id::new(1),
+            role_id: Id::new(2),
+        })
+    }
+
+    fn voice_state_update(channel_id: Option<&str>) -> DispatchEvent {
+        let voice_state_update = serde_json::from_value::<VoiceStateUpdate>(json!({
+            "channel_id": channel_id,
+            "deaf": false,
+            "guild_id": "1",
+            "mute": false,
+            "request_to_speak_timestamp": null,
+            "self_deaf": false,
+            "self_mute": true,
+            "self_stream": false,
+            "self_video": false,
+            "session_id": "session",
+            "suppress": false,
+            "user_id": "4"
+        }))
+        .unwrap();
+
+        DispatchEvent::VoiceStateUpdate(Box::new(voice_state_update))
+    }
+
+    #[test]
+    fn role_delete_triggers_role_deleted() {
+        assert_eq!(
+            triggered_flags(&role_delete(), None),
+            Some((Id::new(1), EventFlags::ROLE_DELETED))
+        );
+    }
+
+    #[test]
+    fn invite_delete_triggers_invite_deleted() {
+        let event = DispatchEvent::InviteDelete(InviteDelete {
+            channel_id: Id::new(3),
+            code: String::from("hartex"),
+            guild_id: Id::new(1),
+        });
+
+        assert_eq!(
+            triggered_flags(&event, None),
+            Some((Id::new(1), EventFlags::INVITE_DELETED))
+        );
+    }
+
+    #[test]
+    fn voice_state_update_in_same_channel_triggers_nothing() {
+        let previous = PreviousState::Voice {
+            channel_id: Some(Id::new(3)),
+        };
+
+        assert_eq!(
+            triggered_flags(&voice_state_update(Some("3")), Some(previous)),
+            None
+        );
+    }
+
+    #[test]
+    fn voice_state_update_triggers_voice_events() {
+        let disconnected = PreviousState::Voice { channel_id: None };
+        let connected = PreviousState::Voice {
+            channel_id: Some(Id::new(5)),
+        };
+
+        assert_eq!(
+            triggered_flags(&voice_state_update(Some("3")), Some(disconnected)),
+            Some((Id::new(1), EventFlags::VOICE_JOINED))
+        );
+        assert_eq!(
+            triggered_flags(&voice_state_update(Some("3")), Some(connected)),
+            Some((Id::new(1), EventFlags::VOICE_MOVED))
+        );
+        assert_eq!(
+            triggered_flags(&voice_state_update(None), Some(connected)),
+            Some((Id::new(1), EventFlags::VOICE_LEFT))
+        );
+    }
+
+    #[test]
+    fn role_delete_renders_text() {
+        let Some(ModlogMessage::Text(text)) = render(
+            &role_delete(),
+            EventFlags::ROLE_DELETED,
+            None,
+            &ModlogFormat::Default,
+            &localizer(),
+        )
+        .unwrap() else {
+            panic!("expected a text message");
+        };
+
+        assert!(text.contains("was deleted"));
+        assert!(text.contains("`2`"));
+    }
+
+    #[test]
+    fn role_delete_renders_embed() {
+        let Some(ModlogMessage::Embed(embed)) = render(
+            &role_delete(),
+            EventFlags::ROLE_DELETED,
+            None,
+            &ModlogFormat::Pretty,
+            &localizer(),
+        )
+        .unwrap() else {
+            panic!("expected an embed");
+        };
+
+        assert_eq!(embed.title.as_deref(), Some("Role Deleted"));
+        assert_eq!(embed.color, Some(DELETED_COLOR));
+        assert_eq!(embed.fields.len(), 1);
+        assert!(embed.fields[0].value.contains("`2`"));
+    }
+}
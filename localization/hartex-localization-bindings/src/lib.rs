@@ -165,6 +165,30 @@ pub fn generate_bindings(_: TokenStream) -> TokenStream {
                 }
             }
 
+            /// Localizes a message without arguments by its name, which is only known at runtime.
+            ///
+            /// Falls back to the default bundle when the message is absent from the bundle of the
+            /// language, and returns `None` when it is absent from both.
+            pub fn localize_by_name(&self, name: &str) -> miette::Result<Option<String>> {
+                let mut bundle = self.localizations.get_bundle(self.language);
+                if !bundle.has_message(name) {
+                    bundle = self.localizations.get_bundle("en-GB");
+                }
+
+                let Some(pattern) = bundle.get_message(name).and_then(|message| message.value()) else {
+                    return Ok(None);
+                };
+                let mut errors = Vec::new();
+                let localized = bundle.format_pattern(pattern, None, &mut errors);
+
+                if errors.is_empty() {
+                    return Ok(Some(localized.to_string()));
+                }
+
+                let errors = errors.iter().map(ToString::to_string).collect::<Vec<_>>();
+                Err(miette::Report::msg(format!("errors found when localizing message: {}", errors.join(","))))
+            }
+
             fn localize(&self, name: &str, arguments: Option<fluent_bundle::FluentArgs<'a>>) -> miette::Result<String> {
                 let bundle = self.localizations.get_bundle(self.language);
 
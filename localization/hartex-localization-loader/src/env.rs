@@ -26,7 +26,14 @@ use std::env;
 use std::path::PathBuf;
 
 /// Returns the containing directory of the localization files.
+///
+/// The `LOCALIZATION_BASE_PATH` environment variable takes precedence over the directory inferred
+/// from the current directory, if it is set.
 pub fn base_path() -> PathBuf {
+    if let Some(path) = env::var_os("LOCALIZATION_BASE_PATH") {
+        return PathBuf::from(path);
+    }
+
     let mut dir = env::current_dir().expect("failed to get current dir");
 
     if dir.ends_with("localization") {
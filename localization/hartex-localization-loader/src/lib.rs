@@ -88,6 +88,36 @@ impl LocalizationBundleHolder {
             .get(lang)
             .unwrap_or_else(|| self.get_bundle("en-GB"))
     }
+
+    /// The language IDs of the loaded localization bundles.
+    pub fn languages(&self) -> impl Iterator<Item = &str> {
+        self.bundles.keys().map(String::as_str)
+    }
+
+    /// Format a message without arguments from the bundle of a language.
+    ///
+    /// Unlike [`get_bundle`](Self::get_bundle), this does not fall back to the default bundle, and
+    /// returns `None` when the language or the message is absent.
+    pub fn format_message(&self, lang: &str, name: &str) -> miette::Result<Option<String>> {
+        let Some(bundle) = self.bundles.get(lang) else {
+            return Ok(None);
+        };
+        let Some(pattern) = bundle.get_message(name).and_then(|message| message.value()) else {
+            return Ok(None);
+        };
+
+        let mut errors = Vec::new();
+        let formatted = bundle.format_pattern(pattern, None, &mut errors);
+        if !errors.is_empty() {
+            let errors = errors.iter().map(ToString::to_string).collect::<Vec<_>>();
+            miette::bail!(
+                "errors found when formatting message {name} of language {lang}: {}",
+                errors.join(",")
+            );
+        }
+
+        Ok(Some(formatted.into_owned()))
+    }
 }
 
 /// Load a bundle from a path.
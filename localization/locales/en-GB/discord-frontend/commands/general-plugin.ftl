@@ -30,3 +30,43 @@ contributors-embed-global-admin-field-name=Global Administrator & Lead Developer
 contributors-embed-front-dev-field-name=Frontend Developer
 contributors-embed-translation-team-field-name=Translation Team
 contributors-embed-footer=This is not a complete list (preserve more screen real estate) - but thanks to all who contributed!
+help-detail-embed-title=Help: {$command}
+help-detail-examples-field-name=Examples
+help-detail-plugin-field-name=Plugin
+help-detail-usage-heading=Usage
+help-embed-description=Below are the commands available to you, grouped by plugin. Select a command from the menu below to view its details.
+help-embed-no-commands=There are no commands available to you here.
+help-embed-title=Help
+help-error-unknown-command=The command {$command} does not exist or is not available to you.
+help-example-about=/about
+help-example-contributors=/contributors
+help-example-help=
+    /help
+    /help command:info
+help-example-info=
+    /info server verbose:True
+    /info user user:@HarTex
+    /info emoji emoji::hartex:
+help-example-infraction=
+    /infraction history user:@User page:2
+    /infraction view case:42
+    /infraction reason case:42 reason:Spamming in general chat
+help-example-plugins=
+    /plugins list
+    /plugins enable name:management
+    /plugins disable name:utilities
+help-plugin-general=General
+help-plugin-management=Management
+help-plugin-utilities=Utilities
+help-select-overview-option=Overview
+help-select-placeholder=Select a command to view its details...
+plugins-disable-error-already-disabled=The plugin {$plugin} is already disabled.
+plugins-disable-success=The plugin {$plugin} has been disabled.
+plugins-enable-error-already-enabled=The plugin {$plugin} is already enabled.
+plugins-enable-success=The plugin {$plugin} has been enabled.
+plugins-error-not-toggleable=The plugin {$plugin} is always enabled and cannot be enabled or disabled.
+plugins-error-unconfigured=This server has not been configured yet. Please configure it in the dashboard first.
+plugins-error-unknown-plugin=There is no plugin named {$plugin}. Available plugins: {$plugins}.
+plugins-list-disabled=Disabled
+plugins-list-embed-title=Plugins
+plugins-list-enabled=Enabled
@@ -0,0 +1,39 @@
+#
+# SPDX-License-Identifier: AGPL-3.0-only
+#
+# This file is part of HarTex.
+#
+# HarTex
+# Copyright (c) 2021-2024 HarTex Project Developers
+#
+# HarTex is free software; you can redistribute it and/or modify
+# it under the terms of the GNU Affero General Public License as published by
+# the Free Software Foundation; either version 3 of the License, or
+# (at your option) any later version.
+#
+# HarTex is distributed in the hope that it will be useful,
+# but WITHOUT ANY WARRANTY; without even the implied warranty of
+# MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+# GNU Affero General Public License for more details.
+#
+# You should have received a copy of the GNU Affero General Public License along
+# with HarTex. If not, see <https://www.gnu.org/licenses/>.
+#
+
+infraction-error-unknown-case=Case `#{$caseNumber}` does not exist in this server.
+infraction-history-embed-title=Infraction History
+infraction-history-embed-description=Infractions of {$userMention}:
+infraction-history-embed-empty=This user has no infractions in this server.
+infraction-history-embed-footer=Page {$page} of {$pageCount} · {$total} infraction(s) in total
+infraction-history-embed-pardoned=pardoned
+infraction-view-embed-title=Case #{$caseNumber}
+infraction-view-embed-user-field-name=User:
+infraction-view-embed-moderator-field-name=Moderator:
+infraction-view-embed-kind-field-name=Type:
+infraction-view-embed-reason-field-name=Reason:
+infraction-view-embed-created-field-name=Created:
+infraction-view-embed-pardoned-field-name=Pardoned:
+infraction-reason-success=The reason of case `#{$caseNumber}` has been updated.
+infraction-pardon-success=Case `#{$caseNumber}` has been pardoned.
+infraction-pardon-error-already-pardoned=Case `#{$caseNumber}` has already been pardoned.
+infraction-delete-success=Case `#{$caseNumber}` has been deleted.
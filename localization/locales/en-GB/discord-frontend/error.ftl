@@ -27,4 +27,9 @@ error-line-one=:x: This command encountered{ $errorKind ->
 } error. Please provide the following error code for support.
 error-line-two=Error code:
 error-plugin-disabled=The `{$plugin}` plugin is not enabled. Please enable it in the guild configuration.
-error-insufficient-permissions=Invoking user has insufficient permissions.
\ No newline at end of file
+error-insufficient-permissions=Invoking user has insufficient permissions.
+error-insufficient-permission-level=Invoking user has insufficient permission level: `{$level}` is below the required level `{$required}`.
+error-maintenance=The bot is currently under maintenance. Please try again later.
+error-blacklisted=You are not allowed to use this bot.
+error-cooldown=This command is on cooldown. Please try again in {$seconds} second(s).
+error-not-dashboard-admin=Only administrators of the dashboard of this server can use this command.
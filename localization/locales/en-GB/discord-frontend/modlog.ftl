@@ -0,0 +1,78 @@
+#
+# SPDX-License-Identifier: AGPL-3.0-only
+#
+# This file is part of HarTex.
+#
+# HarTex
+# Copyright (c) 2021-2024 HarTex Project Developers
+#
+# HarTex is free software; you can redistribute it and/or modify
+# it under the terms of the GNU Affero General Public License as published by
+# the Free Software Foundation; either version 3 of the License, or
+# (at your option) any later version.
+#
+# HarTex is distributed in the hope that it will be useful,
+# but WITHOUT ANY WARRANTY; without even the implied warranty of
+# MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+# GNU Affero General Public License for more details.
+#
+# You should have received a copy of the GNU Affero General Public License along
+# with HarTex. If not, see <https://www.gnu.org/licenses/>.
+#
+
+modlog-channel-created=:new: Channel {$channelMention} was created.
+modlog-channel-created-title=Channel Created
+modlog-channel-deleted=:wastebasket: Channel {$channelName} was deleted.
+modlog-channel-deleted-title=Channel Deleted
+modlog-channel-pins-updated=:pushpin: The pinned messages in {$channelMention} were updated.
+modlog-channel-pins-updated-title=Pinned Messages Updated
+modlog-channel-updated=:pencil: Channel {$channelMention} was updated.
+modlog-channel-updated-title=Channel Updated
+modlog-embed-attachments-field-name=Attachments:
+modlog-embed-author-field-name=Author:
+modlog-embed-channel-field-name=Channel:
+modlog-embed-code-field-name=Code:
+modlog-embed-content-field-name=Content:
+modlog-embed-count-field-name=Count:
+modlog-embed-message-field-name=Message:
+modlog-embed-nickname-field-name=Nickname:
+modlog-embed-role-field-name=Role:
+modlog-embed-roles-field-name=Roles:
+modlog-embed-user-field-name=User:
+modlog-emojis-updated=:smiley: The emojis of the server were updated; it now has {$count} emojis.
+modlog-emojis-updated-title=Emojis Updated
+modlog-invite-created=:envelope: Invite {$code} to {$channelMention} was created by {$userMention}.
+modlog-invite-created-title=Invite Created
+modlog-invite-deleted=:wastebasket: Invite {$code} to {$channelMention} was deleted.
+modlog-invite-deleted-title=Invite Deleted
+modlog-member-banned=:hammer: {$userMention} was banned.
+modlog-member-banned-title=Member Banned
+modlog-member-joined=:inbox_tray: {$userMention} joined the server.
+modlog-member-joined-title=Member Joined
+modlog-member-left=:outbox_tray: {$userMention} left the server.
+modlog-member-left-title=Member Left
+modlog-member-unbanned=:unlock: {$userMention} was unbanned.
+modlog-member-unbanned-title=Member Unbanned
+modlog-member-updated=:bust_in_silhouette: {$userMention} was updated.
+modlog-member-updated-title=Member Updated
+modlog-message-deleted=:wastebasket: Message {$messageId} was deleted in {$channelMention}.
+modlog-message-deleted-cached=:wastebasket: Message {$messageId} by {$userMention} was deleted in {$channelMention}: {$content}
+modlog-message-deleted-title=Message Deleted
+modlog-message-updated=:pencil: Message {$messageId} by {$userMention} was edited in {$channelMention}.
+modlog-message-updated-title=Message Edited
+modlog-messages-bulk-deleted=:wastebasket: {$count} messages were deleted in {$channelMention}.
+modlog-messages-bulk-deleted-title=Messages Deleted
+modlog-none=None
+modlog-role-created=:new: Role {$roleMention} was created.
+modlog-role-created-title=Role Created
+modlog-role-deleted=:wastebasket: Role {$roleId} was deleted.
+modlog-role-deleted-title=Role Deleted
+modlog-role-updated=:pencil: Role {$roleMention} was updated.
+modlog-role-updated-title=Role Updated
+modlog-unknown-user=Unknown User
+modlog-voice-joined=:loud_sound: {$userMention} joined {$channelMention}.
+modlog-voice-joined-title=Voice Channel Joined
+modlog-voice-left=:mute: {$userMention} left a voice channel.
+modlog-voice-left-title=Voice Channel Left
+modlog-voice-moved=:twisted_rightwards_arrows: {$userMention} moved to {$channelMention}.
+modlog-voice-moved-title=Voice Channel Changed
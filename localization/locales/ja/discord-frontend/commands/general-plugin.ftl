@@ -30,3 +30,68 @@ contributors-embed-global-admin-field-name=グローバル管理人＆統率開
 contributors-embed-front-dev-field-name=フロントエンド開発
 contributors-embed-translation-team-field-name=翻訳チーム
 contributors-embed-footer=不完全だけど（何卒スクリーンの値段高い不動産を保つやで）、全部の貢献者にありがとう！
+help-detail-embed-title=ヘルプ：{$command}
+help-detail-examples-field-name=例
+help-detail-plugin-field-name=プラグイン
+help-detail-usage-heading=使い方
+help-embed-description=利用可能なコマンドはプラグインごとに以下の通り。下のメニューからコマンドを選ぶと詳細が表示される。
+help-embed-no-commands=ここで利用可能なコマンドはない。
+help-embed-title=ヘルプ
+help-error-unknown-command=コマンド {$command} は存在しない、または利用できない。
+help-example-about=/関連情報
+help-example-contributors=/貢献者
+help-example-help=
+    /ヘルプ
+    /ヘルプ コマンド:情報
+help-example-info=
+    /情報 サーバー 詳しく:True
+    /情報 ユーザー ユーザー:@HarTex
+    /情報 emoji emoji::hartex:
+help-example-infraction=
+    /処罰 履歴 ユーザー:@User ページ:2
+    /処罰 表示 ケース:42
+    /処罰 理由 ケース:42 理由:雑談チャンネルでのスパム
+help-example-plugins=
+    /プラグイン 一覧
+    /プラグイン 有効化 名前:management
+    /プラグイン 無効化 名前:utilities
+help-plugin-general=一般
+help-plugin-management=管理
+help-plugin-utilities=ユーティリティ
+help-select-overview-option=概要
+help-select-placeholder=詳細を見るコマンドを選択…
+plugins-disable-error-already-disabled=プラグイン {$plugin} は既に無効です。
+plugins-disable-success=プラグイン {$plugin} を無効化した。
+plugins-enable-error-already-enabled=プラグイン {$plugin} は既に有効です。
+plugins-enable-success=プラグイン {$plugin} を有効化した。
+plugins-error-not-toggleable=プラグイン {$plugin} は常に有効で、有効化も無効化もできません。
+plugins-error-unconfigured=本鯖はまだ設定されていません。先にダッシュボードで設定してください。
+plugins-error-unknown-plugin={$plugin} というプラグインはありません。利用可能なプラグイン：{$plugins}。
+plugins-list-disabled=無効
+plugins-list-embed-title=プラグイン
+plugins-list-enabled=有効
+
+command-about-name=関連情報
+command-about-description=HarTex について
+command-contributors-name=貢献者
+command-contributors-description=HarTex へ貢献したやさしい人々
+command-help-name=ヘルプ
+command-help-description=利用可能なコマンドの一覧、またはコマンドの詳細を表示します。
+command-help-command-name=コマンド
+command-help-command-description=詳細を表示するコマンド。
+command-latency-name=遅延
+command-latency-description=遅延情報を取得
+command-plugins-name=プラグイン
+command-plugins-description=本鯖のプラグインを一覧、有効化、無効化する。
+command-plugins-list-name=一覧
+command-plugins-list-description=本鯖のプラグインとその有効状態を一覧する。
+command-plugins-enable-name=有効化
+command-plugins-enable-description=本鯖のプラグインを有効化する。
+command-plugins-enable-name-name=名前
+command-plugins-enable-name-description=有効化するプラグインの名前。
+command-plugins-disable-name=無効化
+command-plugins-disable-description=本鯖のプラグインを無効化する。
+command-plugins-disable-name-name=名前
+command-plugins-disable-name-description=無効化するプラグインの名前。
+command-uptime-name=平常運転時間
+command-uptime-description=平常運転時間を取得
@@ -0,0 +1,66 @@
+#
+# SPDX-License-Identifier: AGPL-3.0-only
+#
+# This file is part of HarTex.
+#
+# HarTex
+# Copyright (c) 2021-2024 HarTex Project Developers
+#
+# HarTex is free software; you can redistribute it and/or modify
+# it under the terms of the GNU Affero General Public License as published by
+# the Free Software Foundation; either version 3 of the License, or
+# (at your option) any later version.
+#
+# HarTex is distributed in the hope that it will be useful,
+# but WITHOUT ANY WARRANTY; without even the implied warranty of
+# MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+# GNU Affero General Public License for more details.
+#
+# You should have received a copy of the GNU Affero General Public License along
+# with HarTex. If not, see <https://www.gnu.org/licenses/>.
+#
+
+infraction-error-unknown-case=この鯖にはケース`#{$caseNumber}`が存在しません。
+infraction-history-embed-title=処罰履歴
+infraction-history-embed-description={$userMention}の処罰：
+infraction-history-embed-empty=このユーザーはこの鯖で処罰されたことがありません。
+infraction-history-embed-footer={$pageCount}ページ中{$page}ページ目 · 合計{$total}件
+infraction-history-embed-pardoned=赦免済み
+infraction-view-embed-title=ケース #{$caseNumber}
+infraction-view-embed-user-field-name=ユーザー：
+infraction-view-embed-moderator-field-name=モデレーター：
+infraction-view-embed-kind-field-name=種類：
+infraction-view-embed-reason-field-name=理由：
+infraction-view-embed-created-field-name=作った時：
+infraction-view-embed-pardoned-field-name=赦免済み：
+infraction-reason-success=ケース`#{$caseNumber}`の理由を更新しました。
+infraction-pardon-success=ケース`#{$caseNumber}`を赦免しました。
+infraction-pardon-error-already-pardoned=ケース`#{$caseNumber}`は既に赦免されています。
+infraction-delete-success=ケース`#{$caseNumber}`を削除しました。
+
+command-infraction-name=処罰
+command-infraction-description=処罰のケースを調べ、管理する。
+command-infraction-history-name=履歴
+command-infraction-history-description=特定のユーザーの処罰履歴。
+command-infraction-history-user-name=ユーザー
+command-infraction-history-user-description=どちら様の処罰履歴を得る？
+command-infraction-history-page-name=ページ
+command-infraction-history-page-description=表示する処罰履歴のページ。
+command-infraction-view-name=表示
+command-infraction-view-description=特定のケースの詳細。
+command-infraction-view-case-name=ケース
+command-infraction-view-case-description=処罰のケース番号。
+command-infraction-reason-name=理由
+command-infraction-reason-description=特定のケースの理由を更新する。
+command-infraction-reason-case-name=ケース
+command-infraction-reason-case-description=処罰のケース番号。
+command-infraction-reason-reason-name=理由
+command-infraction-reason-reason-description=処罰の新しい理由。
+command-infraction-pardon-name=赦免
+command-infraction-pardon-description=特定のケースを赦免する。
+command-infraction-pardon-case-name=ケース
+command-infraction-pardon-case-description=処罰のケース番号。
+command-infraction-delete-name=削除
+command-infraction-delete-description=特定のケースを削除する。
+command-infraction-delete-case-name=ケース
+command-infraction-delete-case-description=処罰のケース番号。
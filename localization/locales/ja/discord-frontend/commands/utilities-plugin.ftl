@@ -78,3 +78,20 @@ userinfo-embed-serverpresence-nickname-subfield-name=サーバーニックネー
 userinfo-embed-serverpresence-joinedat-subfield-name=参加済：
 userinfo-embed-serverpresence-roles-subfield-name=ロール（10個まで表示）
 userinfo-embed-serverpresence-flags-subfield-name=フラグ：
+
+command-info-name=情報
+command-info-description=指定実体の情報。
+command-info-server-name=サーバー
+command-info-server-description=このサーバーの情報。
+command-info-server-verbose-name=詳しく
+command-info-server-verbose-description=情報はもっと詳しく？
+command-info-bot-name=ボット
+command-info-bot-description=ボットの運転情報。
+command-info-role-name=ロール
+command-info-role-description=特定のロールの情報。
+command-info-role-role-name=ロール
+command-info-role-role-description=どっちのロール？
+command-info-user-name=ユーザー
+command-info-user-description=特定のユーザーの情報。
+command-info-user-user-name=ユーザー
+command-info-user-user-description=どちら様の情報を得る？指定なしでこちら様の情報を得るよ。
@@ -32,3 +32,8 @@ error-line-one=
 error-line-two=エラーコード：
 error-plugin-disabled=`{$plugin}` が無効です。本鯖の設定にこのプラグインを有効にしてください。
 error-insufficient-permissions=ユーザーは必要な許可がありません。
+error-insufficient-permission-level=ユーザーの権限レベルが足りません：`{$level}` は必要なレベル `{$required}` より低いです。
+error-maintenance=ボットは現在メンテナンス中です。しばらくしてからもう一度お試しください。
+error-blacklisted=このボットを使用することは許可されていません。
+error-cooldown=このコマンドはクールダウン中です。{$seconds} 秒後にもう一度お試しください。
+error-not-dashboard-admin=このコマンドは本鯖のダッシュボード管理人のみ使用できます。
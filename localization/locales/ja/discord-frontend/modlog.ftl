@@ -0,0 +1,78 @@
+#
+# SPDX-License-Identifier: AGPL-3.0-only
+#
+# This file is part of HarTex.
+#
+# HarTex
+# Copyright (c) 2021-2024 HarTex Project Developers
+#
+# HarTex is free software; you can redistribute it and/or modify
+# it under the terms of the GNU Affero General Public License as published by
+# the Free Software Foundation; either version 3 of the License, or
+# (at your option) any later version.
+#
+# HarTex is distributed in the hope that it will be useful,
+# but WITHOUT ANY WARRANTY; without even the implied warranty of
+# MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+# GNU Affero General Public License for more details.
+#
+# You should have received a copy of the GNU Affero General Public License along
+# with HarTex. If not, see <https://www.gnu.org/licenses/>.
+#
+
+modlog-channel-created=:new: チャンネル{$channelMention}が作成されました。
+modlog-channel-created-title=チャンネル作成
+modlog-channel-deleted=:wastebasket: チャンネル{$channelName}が削除されました。
+modlog-channel-deleted-title=チャンネル削除
+modlog-channel-pins-updated=:pushpin: {$channelMention}のピン留めメッセージが更新されました。
+modlog-channel-pins-updated-title=ピン留め更新
+modlog-channel-updated=:pencil: チャンネル{$channelMention}が更新されました。
+modlog-channel-updated-title=チャンネル更新
+modlog-embed-attachments-field-name=添付ファイル：
+modlog-embed-author-field-name=作者：
+modlog-embed-channel-field-name=チャンネル：
+modlog-embed-code-field-name=コード：
+modlog-embed-content-field-name=内容：
+modlog-embed-count-field-name=件数：
+modlog-embed-message-field-name=メッセージ：
+modlog-embed-nickname-field-name=ニックネーム：
+modlog-embed-role-field-name=ロール：
+modlog-embed-roles-field-name=ロール一覧：
+modlog-embed-user-field-name=ユーザー：
+modlog-emojis-updated=:smiley: サーバーの絵文字が更新されました。現在の絵文字は{$count}個です。
+modlog-emojis-updated-title=絵文字更新
+modlog-invite-created=:envelope: {$userMention}が{$channelMention}への招待{$code}を作成しました。
+modlog-invite-created-title=招待作成
+modlog-invite-deleted=:wastebasket: {$channelMention}への招待{$code}が削除されました。
+modlog-invite-deleted-title=招待削除
+modlog-member-banned=:hammer: {$userMention}がBANされました。
+modlog-member-banned-title=メンバーBAN
+modlog-member-joined=:inbox_tray: {$userMention}がサーバーに参加しました。
+modlog-member-joined-title=メンバー参加
+modlog-member-left=:outbox_tray: {$userMention}がサーバーから退出しました。
+modlog-member-left-title=メンバー退出
+modlog-member-unbanned=:unlock: {$userMention}のBANが解除されました。
+modlog-member-unbanned-title=メンバーBAN解除
+modlog-member-updated=:bust_in_silhouette: {$userMention}が更新されました。
+modlog-member-updated-title=メンバー更新
+modlog-message-deleted=:wastebasket: {$channelMention}でメッセージ{$messageId}が削除されました。
+modlog-message-deleted-cached=:wastebasket: {$channelMention}で{$userMention}のメッセージ{$messageId}が削除されました：{$content}
+modlog-message-deleted-title=メッセージ削除
+modlog-message-updated=:pencil: {$channelMention}で{$userMention}のメッセージ{$messageId}が編集されました。
+modlog-message-updated-title=メッセージ編集
+modlog-messages-bulk-deleted=:wastebasket: {$channelMention}で{$count}件のメッセージが削除されました。
+modlog-messages-bulk-deleted-title=メッセージ一括削除
+modlog-none=なし
+modlog-role-created=:new: ロール{$roleMention}が作成されました。
+modlog-role-created-title=ロール作成
+modlog-role-deleted=:wastebasket: ロール{$roleId}が削除されました。
+modlog-role-deleted-title=ロール削除
+modlog-role-updated=:pencil: ロール{$roleMention}が更新されました。
+modlog-role-updated-title=ロール更新
+modlog-unknown-user=不明なユーザー
+modlog-voice-joined=:loud_sound: {$userMention}が{$channelMention}に参加しました。
+modlog-voice-joined-title=ボイスチャンネル参加
+modlog-voice-left=:mute: {$userMention}がボイスチャンネルから退出しました。
+modlog-voice-left-title=ボイスチャンネル退出
+modlog-voice-moved=:twisted_rightwards_arrows: {$userMention}が{$channelMention}に移動しました。
+modlog-voice-moved-title=ボイスチャンネル移動
@@ -30,3 +30,68 @@ contributors-embed-global-admin-field-name=全局管理员 & 首席开发人员
 contributors-embed-front-dev-field-name=前端开发人员
 contributors-embed-translation-team-field-name=翻译组
 contributors-embed-footer=这并不是一个完整的名单（保留更多的屏幕空间）但感谢所有的贡献者！
+help-detail-embed-title=帮助：{$command}
+help-detail-examples-field-name=示例
+help-detail-plugin-field-name=插件
+help-detail-usage-heading=用法
+help-embed-description=以下是你可以使用的命令，按插件分类。从下方菜单中选择一个命令以查看其详细信息。
+help-embed-no-commands=这里没有你可以使用的命令。
+help-embed-title=帮助
+help-error-unknown-command=命令 {$command} 不存在或你无法使用。
+help-example-about=/关于
+help-example-contributors=/贡献者
+help-example-help=
+    /帮助
+    /帮助 命令:信息
+help-example-info=
+    /信息 服务器 详细:True
+    /信息 用户 用户:@HarTex
+    /信息 emoji emoji::hartex:
+help-example-infraction=
+    /处罚 记录 用户:@User 页:2
+    /处罚 查看 案例:42
+    /处罚 原因 案例:42 原因:在闲聊频道刷屏
+help-example-plugins=
+    /插件 列表
+    /插件 启用 名称:management
+    /插件 禁用 名称:utilities
+help-plugin-general=通用
+help-plugin-management=管理
+help-plugin-utilities=实用工具
+help-select-overview-option=概览
+help-select-placeholder=选择一个命令以查看详细信息…
+plugins-disable-error-already-disabled=插件 {$plugin} 已被禁用。
+plugins-disable-success=已禁用插件 {$plugin}。
+plugins-enable-error-already-enabled=插件 {$plugin} 已被启用。
+plugins-enable-success=已启用插件 {$plugin}。
+plugins-error-not-toggleable=插件 {$plugin} 始终启用，无法启用或禁用。
+plugins-error-unconfigured=本服务器尚未配置，请先在仪表板中进行配置。
+plugins-error-unknown-plugin=不存在名为 {$plugin} 的插件。可用的插件：{$plugins}。
+plugins-list-disabled=已禁用
+plugins-list-embed-title=插件
+plugins-list-enabled=已启用
+
+command-about-name=关于
+command-about-description=关于 HarTex
+command-contributors-name=贡献者
+command-contributors-description=为 HarTex 做出杰出贡献的人
+command-help-name=帮助
+command-help-description=列出你可以使用的命令，或显示某个命令的详细信息。
+command-help-command-name=命令
+command-help-command-description=要显示详细信息的命令。
+command-latency-name=延迟
+command-latency-description=获取延迟信息
+command-plugins-name=插件
+command-plugins-description=列出、启用和禁用本服务器的插件。
+command-plugins-list-name=列表
+command-plugins-list-description=列出本服务器的插件及其启用状态。
+command-plugins-enable-name=启用
+command-plugins-enable-description=启用本服务器的插件。
+command-plugins-enable-name-name=名称
+command-plugins-enable-name-description=要启用的插件名称。
+command-plugins-disable-name=禁用
+command-plugins-disable-description=禁用本服务器的插件。
+command-plugins-disable-name-name=名称
+command-plugins-disable-name-description=要禁用的插件名称。
+command-uptime-name=正常运行时间
+command-uptime-description=获取正常运行时间信息
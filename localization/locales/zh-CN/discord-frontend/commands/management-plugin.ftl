@@ -0,0 +1,66 @@
+#
+# SPDX-License-Identifier: AGPL-3.0-only
+#
+# This file is part of HarTex.
+#
+# HarTex
+# Copyright (c) 2021-2024 HarTex Project Developers
+#
+# HarTex is free software; you can redistribute it and/or modify
+# it under the terms of the GNU Affero General Public License as published by
+# the Free Software Foundation; either version 3 of the License, or
+# (at your option) any later version.
+#
+# HarTex is distributed in the hope that it will be useful,
+# but WITHOUT ANY WARRANTY; without even the implied warranty of
+# MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+# GNU Affero General Public License for more details.
+#
+# You should have received a copy of the GNU Affero General Public License along
+# with HarTex. If not, see <https://www.gnu.org/licenses/>.
+#
+
+infraction-error-unknown-case=此服务器中不存在案例 `#{$caseNumber}`。
+infraction-history-embed-title=处罚记录
+infraction-history-embed-description={$userMention} 的处罚：
+infraction-history-embed-empty=此用户在此服务器中没有任何处罚。
+infraction-history-embed-footer=第 {$page} 页，共 {$pageCount} 页 · 共 {$total} 项处罚
+infraction-history-embed-pardoned=已赦免
+infraction-view-embed-title=案例 #{$caseNumber}
+infraction-view-embed-user-field-name=用户：
+infraction-view-embed-moderator-field-name=管理员：
+infraction-view-embed-kind-field-name=类型：
+infraction-view-embed-reason-field-name=原因：
+infraction-view-embed-created-field-name=创建时间：
+infraction-view-embed-pardoned-field-name=已赦免：
+infraction-reason-success=案例 `#{$caseNumber}` 的原因已更新。
+infraction-pardon-success=案例 `#{$caseNumber}` 已被赦免。
+infraction-pardon-error-already-pardoned=案例 `#{$caseNumber}` 已经被赦免。
+infraction-delete-success=案例 `#{$caseNumber}` 已被删除。
+
+command-infraction-name=处罚
+command-infraction-description=查询和管理处罚案例。
+command-infraction-history-name=记录
+command-infraction-history-description=指定用户的处罚记录。
+command-infraction-history-user-name=用户
+command-infraction-history-user-description=要获取处罚记录的用户。
+command-infraction-history-page-name=页
+command-infraction-history-page-description=要显示的处罚记录页。
+command-infraction-view-name=查看
+command-infraction-view-description=指定处罚案例的详细信息。
+command-infraction-view-case-name=案例
+command-infraction-view-case-description=处罚的案例编号。
+command-infraction-reason-name=原因
+command-infraction-reason-description=更新指定处罚案例的原因。
+command-infraction-reason-case-name=案例
+command-infraction-reason-case-description=处罚的案例编号。
+command-infraction-reason-reason-name=原因
+command-infraction-reason-reason-description=处罚的新原因。
+command-infraction-pardon-name=赦免
+command-infraction-pardon-description=赦免指定处罚案例。
+command-infraction-pardon-case-name=案例
+command-infraction-pardon-case-description=处罚的案例编号。
+command-infraction-delete-name=删除
+command-infraction-delete-description=删除指定处罚案例。
+command-infraction-delete-case-name=案例
+command-infraction-delete-case-description=处罚的案例编号。
@@ -78,4 +78,21 @@ userinfo-embed-serverpresence-nickname-subfield-name=服务器昵称：
 userinfo-embed-serverpresence-roles-subfield-name=身份组（最多显示10条）：
 userinfo-embed-serverpresence-joinedat-subfield-name=已加入：
 userinfo-embed-serverpresence-roles-subfield-name=身份组（最多显示10条）：
-userinfo-embed-serverpresence-flags-subfield-name=标记：
\ No newline at end of file
+userinfo-embed-serverpresence-flags-subfield-name=标记：
+
+command-info-name=信息
+command-info-description=有关特定实体的信息。
+command-info-server-name=服务器
+command-info-server-description=关于当前服务器的信息。
+command-info-server-verbose-name=详细
+command-info-server-verbose-description=是否在返回的嵌入中显示更详细的信息。
+command-info-bot-name=机器人
+command-info-bot-description=机器人的运行时信息。
+command-info-role-name=身份组
+command-info-role-description=关于指定身份组的信息。
+command-info-role-role-name=身份组
+command-info-role-role-description=要获取信息的身份组。
+command-info-user-name=用户
+command-info-user-description=关于指定用户的信息。
+command-info-user-user-name=用户
+command-info-user-user-description=要获取信息的用户。如果未指定，则返回当前用户的信息。
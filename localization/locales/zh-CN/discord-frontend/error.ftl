@@ -27,4 +27,9 @@ error-line-one=:x: 次命令遇到了{ $errorKind ->
 }错误，请提供以下错误代码以获得支持。
 error-line-two=错误代码：
 error-plugin-disabled=`{$plugin}`插件未启用。请在服务器配置中启用。
-error-insufficient-permissions=使用命令的用户权限不足。
\ No newline at end of file
+error-insufficient-permissions=使用命令的用户权限不足。
+error-insufficient-permission-level=使用命令的用户权限等级不足：`{$level}` 低于所需等级 `{$required}`。
+error-maintenance=机器人目前正在维护中，请稍后再试。
+error-blacklisted=你不被允许使用此机器人。
+error-cooldown=此命令正在冷却中，请在 {$seconds} 秒后再试。
+error-not-dashboard-admin=只有本服务器仪表板的管理员才能使用此命令。
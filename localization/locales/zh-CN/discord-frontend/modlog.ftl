@@ -0,0 +1,78 @@
+#
+# SPDX-License-Identifier: AGPL-3.0-only
+#
+# This file is part of HarTex.
+#
+# HarTex
+# Copyright (c) 2021-2024 HarTex Project Developers
+#
+# HarTex is free software; you can redistribute it and/or modify
+# it under the terms of the GNU Affero General Public License as published by
+# the Free Software Foundation; either version 3 of the License, or
+# (at your option) any later version.
+#
+# HarTex is distributed in the hope that it will be useful,
+# but WITHOUT ANY WARRANTY; without even the implied warranty of
+# MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+# GNU Affero General Public License for more details.
+#
+# You should have received a copy of the GNU Affero General Public License along
+# with HarTex. If not, see <https://www.gnu.org/licenses/>.
+#
+
+modlog-channel-created=:new: 频道 {$channelMention} 已被创建。
+modlog-channel-created-title=频道已创建
+modlog-channel-deleted=:wastebasket: 频道 {$channelName} 已被删除。
+modlog-channel-deleted-title=频道已删除
+modlog-channel-pins-updated=:pushpin: {$channelMention} 中的置顶消息已被更新。
+modlog-channel-pins-updated-title=置顶消息已更新
+modlog-channel-updated=:pencil: 频道 {$channelMention} 已被更新。
+modlog-channel-updated-title=频道已更新
+modlog-embed-attachments-field-name=附件：
+modlog-embed-author-field-name=作者：
+modlog-embed-channel-field-name=频道：
+modlog-embed-code-field-name=代码：
+modlog-embed-content-field-name=内容：
+modlog-embed-count-field-name=数量：
+modlog-embed-message-field-name=消息：
+modlog-embed-nickname-field-name=昵称：
+modlog-embed-role-field-name=身份组：
+modlog-embed-roles-field-name=身份组列表：
+modlog-embed-user-field-name=用户：
+modlog-emojis-updated=:smiley: 服务器的表情已被更新；现在共有 {$count} 个表情。
+modlog-emojis-updated-title=表情已更新
+modlog-invite-created=:envelope: {$userMention} 创建了前往 {$channelMention} 的邀请 {$code}。
+modlog-invite-created-title=邀请已创建
+modlog-invite-deleted=:wastebasket: 前往 {$channelMention} 的邀请 {$code} 已被删除。
+modlog-invite-deleted-title=邀请已删除
+modlog-member-banned=:hammer: {$userMention} 已被封禁。
+modlog-member-banned-title=成员已封禁
+modlog-member-joined=:inbox_tray: {$userMention} 加入了服务器。
+modlog-member-joined-title=成员已加入
+modlog-member-left=:outbox_tray: {$userMention} 离开了服务器。
+modlog-member-left-title=成员已离开
+modlog-member-unbanned=:unlock: {$userMention} 已被解除封禁。
+modlog-member-unbanned-title=成员已解除封禁
+modlog-member-updated=:bust_in_silhouette: {$userMention} 已被更新。
+modlog-member-updated-title=成员已更新
+modlog-message-deleted=:wastebasket: {$channelMention} 中的消息 {$messageId} 已被删除。
+modlog-message-deleted-cached=:wastebasket: {$userMention} 在 {$channelMention} 中的消息 {$messageId} 已被删除：{$content}
+modlog-message-deleted-title=消息已删除
+modlog-message-updated=:pencil: {$userMention} 在 {$channelMention} 中的消息 {$messageId} 已被编辑。
+modlog-message-updated-title=消息已编辑
+modlog-messages-bulk-deleted=:wastebasket: {$channelMention} 中的 {$count} 条消息已被删除。
+modlog-messages-bulk-deleted-title=消息已批量删除
+modlog-none=无
+modlog-role-created=:new: 身份组 {$roleMention} 已被创建。
+modlog-role-created-title=身份组已创建
+modlog-role-deleted=:wastebasket: 身份组 {$roleId} 已被删除。
+modlog-role-deleted-title=身份组已删除
+modlog-role-updated=:pencil: 身份组 {$roleMention} 已被更新。
+modlog-role-updated-title=身份组已更新
+modlog-unknown-user=未知用户
+modlog-voice-joined=:loud_sound: {$userMention} 加入了 {$channelMention}。
+modlog-voice-joined-title=已加入语音频道
+modlog-voice-left=:mute: {$userMention} 离开了语音频道。
+modlog-voice-left-title=已离开语音频道
+modlog-voice-moved=:twisted_rightwards_arrows: {$userMention} 移动到了 {$channelMention}。
+modlog-voice-moved-title=已切换语音频道
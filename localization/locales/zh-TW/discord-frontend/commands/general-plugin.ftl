@@ -30,3 +30,68 @@ contributors-embed-global-admin-field-name=全域管理員 & 首席開發人員
 contributors-embed-front-dev-field-name=前端開發人員
 contributors-embed-translation-team-field-name=翻譯團隊
 contributors-embed-footer=這不是完整的名單（以保留更多螢幕空間）－但感謝所有做出貢獻的人！
+help-detail-embed-title=說明：{$command}
+help-detail-examples-field-name=範例
+help-detail-plugin-field-name=插件
+help-detail-usage-heading=用法
+help-embed-description=以下是你可以使用的指令，按插件分類。從下方選單中選擇一個指令以檢視其詳細資訊。
+help-embed-no-commands=這裡沒有你可以使用的指令。
+help-embed-title=說明
+help-error-unknown-command=指令 {$command} 不存在或你無法使用。
+help-example-about=/關於
+help-example-contributors=/貢獻者
+help-example-help=
+    /說明
+    /說明 指令:資訊
+help-example-info=
+    /資訊 伺服器 詳細:True
+    /資訊 使用者 使用者:@HarTex
+    /資訊 emoji emoji::hartex:
+help-example-infraction=
+    /處罰 紀錄 使用者:@User 頁:2
+    /處罰 檢視 案例:42
+    /處罰 原因 案例:42 原因:在閒聊頻道洗版
+help-example-plugins=
+    /插件 列表
+    /插件 啟用 名稱:management
+    /插件 停用 名稱:utilities
+help-plugin-general=一般
+help-plugin-management=管理
+help-plugin-utilities=實用工具
+help-select-overview-option=總覽
+help-select-placeholder=選擇一個指令以檢視詳細資訊…
+plugins-disable-error-already-disabled=插件 {$plugin} 已被停用。
+plugins-disable-success=已停用插件 {$plugin}。
+plugins-enable-error-already-enabled=插件 {$plugin} 已被啟用。
+plugins-enable-success=已啟用插件 {$plugin}。
+plugins-error-not-toggleable=插件 {$plugin} 始終啟用，無法啟用或停用。
+plugins-error-unconfigured=本伺服器尚未設定，請先在儀表板中進行設定。
+plugins-error-unknown-plugin=不存在名為 {$plugin} 的插件。可用的插件：{$plugins}。
+plugins-list-disabled=已停用
+plugins-list-embed-title=插件
+plugins-list-enabled=已啟用
+
+command-about-name=關於
+command-about-description=關於 HarTex
+command-contributors-name=貢獻者
+command-contributors-description=為 HarTex 做出傑出貢獻的人
+command-help-name=說明
+command-help-description=列出你可以使用的指令，或顯示某個指令的詳細資訊。
+command-help-command-name=指令
+command-help-command-description=要顯示詳細資訊的指令。
+command-latency-name=延遲
+command-latency-description=獲取延遲資訊
+command-plugins-name=插件
+command-plugins-description=列出、啟用和停用本伺服器的插件。
+command-plugins-list-name=列表
+command-plugins-list-description=列出本伺服器的插件及其啟用狀態。
+command-plugins-enable-name=啟用
+command-plugins-enable-description=啟用本伺服器的插件。
+command-plugins-enable-name-name=名稱
+command-plugins-enable-name-description=要啟用的插件名稱。
+command-plugins-disable-name=停用
+command-plugins-disable-description=停用本伺服器的插件。
+command-plugins-disable-name-name=名稱
+command-plugins-disable-name-description=要停用的插件名稱。
+command-uptime-name=正常運行時間
+command-uptime-description=獲取正常運行時間資訊
@@ -0,0 +1,66 @@
+#
+# SPDX-License-Identifier: AGPL-3.0-only
+#
+# This file is part of HarTex.
+#
+# HarTex
+# Copyright (c) 2021-2024 HarTex Project Developers
+#
+# HarTex is free software; you can redistribute it and/or modify
+# it under the terms of the GNU Affero General Public License as published by
+# the Free Software Foundation; either version 3 of the License, or
+# (at your option) any later version.
+#
+# HarTex is distributed in the hope that it will be useful,
+# but WITHOUT ANY WARRANTY; without even the implied warranty of
+# MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+# GNU Affero General Public License for more details.
+#
+# You should have received a copy of the GNU Affero General Public License along
+# with HarTex. If not, see <https://www.gnu.org/licenses/>.
+#
+
+infraction-error-unknown-case=此伺服器中不存在案例 `#{$caseNumber}`。
+infraction-history-embed-title=處罰紀錄
+infraction-history-embed-description={$userMention} 的處罰：
+infraction-history-embed-empty=此使用者在此伺服器中沒有任何處罰。
+infraction-history-embed-footer=第 {$page} 頁，共 {$pageCount} 頁 · 共 {$total} 項處罰
+infraction-history-embed-pardoned=已赦免
+infraction-view-embed-title=案例 #{$caseNumber}
+infraction-view-embed-user-field-name=使用者：
+infraction-view-embed-moderator-field-name=管理員：
+infraction-view-embed-kind-field-name=類型：
+infraction-view-embed-reason-field-name=原因：
+infraction-view-embed-created-field-name=建立時間：
+infraction-view-embed-pardoned-field-name=已赦免：
+infraction-reason-success=案例 `#{$caseNumber}` 的原因已更新。
+infraction-pardon-success=案例 `#{$caseNumber}` 已被赦免。
+infraction-pardon-error-already-pardoned=案例 `#{$caseNumber}` 已經被赦免。
+infraction-delete-success=案例 `#{$caseNumber}` 已被刪除。
+
+command-infraction-name=處罰
+command-infraction-description=查詢和管理處罰案例。
+command-infraction-history-name=紀錄
+command-infraction-history-description=特定使用者的處罰紀錄。
+command-infraction-history-user-name=使用者
+command-infraction-history-user-description=要獲取處罰紀錄的使用者。
+command-infraction-history-page-name=頁
+command-infraction-history-page-description=要顯示的處罰紀錄頁。
+command-infraction-view-name=檢視
+command-infraction-view-description=特定處罰案例的詳細資訊。
+command-infraction-view-case-name=案例
+command-infraction-view-case-description=處罰的案例編號。
+command-infraction-reason-name=原因
+command-infraction-reason-description=更新特定處罰案例的原因。
+command-infraction-reason-case-name=案例
+command-infraction-reason-case-description=處罰的案例編號。
+command-infraction-reason-reason-name=原因
+command-infraction-reason-reason-description=處罰的新原因。
+command-infraction-pardon-name=赦免
+command-infraction-pardon-description=赦免特定處罰案例。
+command-infraction-pardon-case-name=案例
+command-infraction-pardon-case-description=處罰的案例編號。
+command-infraction-delete-name=刪除
+command-infraction-delete-description=刪除特定處罰案例。
+command-infraction-delete-case-name=案例
+command-infraction-delete-case-description=處罰的案例編號。
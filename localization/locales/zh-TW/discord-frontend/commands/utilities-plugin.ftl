@@ -78,3 +78,20 @@ userinfo-embed-serverpresence-nickname-subfield-name=伺服器暱稱：
 userinfo-embed-serverpresence-joinedat-subfield-name=加入於：
 userinfo-embed-serverpresence-roles-subfield-name=身分組（最多顯示 10 個）：
 userinfo-embed-serverpresence-flags-subfield-name=旗標：
+
+command-info-name=資訊
+command-info-description=關於特定實體的資訊。
+command-info-server-name=伺服器
+command-info-server-description=關於當前伺服器的資訊。
+command-info-server-verbose-name=詳細
+command-info-server-verbose-description=是否在返回的嵌入中顯示更多詳細資訊。
+command-info-bot-name=機器人
+command-info-bot-description=機器人的運行資訊。
+command-info-role-name=身分組
+command-info-role-description=關於特定身分組的資訊。
+command-info-role-role-name=身分組
+command-info-role-role-description=要獲取資訊的身分組。
+command-info-user-name=使用者
+command-info-user-description=關於特定使用者的資訊。
+command-info-user-user-name=使用者
+command-info-user-user-description=要獲取資訊的使用者。若未指定，則返回當前使用者的資訊。
@@ -28,3 +28,8 @@ error-line-one=:x: 這個指令發生了{ $errorKind ->
 error-line-two=錯誤代碼：
 error-plugin-disabled=插件 `{$plugin}` 未啟用。請在伺服器設定中啟用。
 error-insufficient-permissions=使用者權限不足。
+error-insufficient-permission-level=使用者權限等級不足：`{$level}` 低於所需等級 `{$required}`。
+error-maintenance=機器人目前正在維護中，請稍後再試。
+error-blacklisted=你不被允許使用此機器人。
+error-cooldown=此指令正在冷卻中，請在 {$seconds} 秒後再試。
+error-not-dashboard-admin=只有本伺服器儀表板的管理員才能使用此指令。
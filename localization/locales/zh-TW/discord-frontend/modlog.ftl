@@ -0,0 +1,78 @@
+#
+# SPDX-License-Identifier: AGPL-3.0-only
+#
+# This file is part of HarTex.
+#
+# HarTex
+# Copyright (c) 2021-2024 HarTex Project Developers
+#
+# HarTex is free software; you can redistribute it and/or modify
+# it under the terms of the GNU Affero General Public License as published by
+# the Free Software Foundation; either version 3 of the License, or
+# (at your option) any later version.
+#
+# HarTex is distributed in the hope that it will be useful,
+# but WITHOUT ANY WARRANTY; without even the implied warranty of
+# MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+# GNU Affero General Public License for more details.
+#
+# You should have received a copy of the GNU Affero General Public License along
+# with HarTex. If not, see <https://www.gnu.org/licenses/>.
+#
+
+modlog-channel-created=:new: 頻道 {$channelMention} 已被建立。
+modlog-channel-created-title=頻道已建立
+modlog-channel-deleted=:wastebasket: 頻道 {$channelName} 已被刪除。
+modlog-channel-deleted-title=頻道已刪除
+modlog-channel-pins-updated=:pushpin: {$channelMention} 中的釘選訊息已被更新。
+modlog-channel-pins-updated-title=釘選訊息已更新
+modlog-channel-updated=:pencil: 頻道 {$channelMention} 已被更新。
+modlog-channel-updated-title=頻道已更新
+modlog-embed-attachments-field-name=附件：
+modlog-embed-author-field-name=作者：
+modlog-embed-channel-field-name=頻道：
+modlog-embed-code-field-name=代碼：
+modlog-embed-content-field-name=內容：
+modlog-embed-count-field-name=數量：
+modlog-embed-message-field-name=訊息：
+modlog-embed-nickname-field-name=暱稱：
+modlog-embed-role-field-name=身分組：
+modlog-embed-roles-field-name=身分組列表：
+modlog-embed-user-field-name=使用者：
+modlog-emojis-updated=:smiley: 伺服器的表情符號已被更新；現在共有 {$count} 個表情符號。
+modlog-emojis-updated-title=表情符號已更新
+modlog-invite-created=:envelope: {$userMention} 建立了前往 {$channelMention} 的邀請 {$code}。
+modlog-invite-created-title=邀請已建立
+modlog-invite-deleted=:wastebasket: 前往 {$channelMention} 的邀請 {$code} 已被刪除。
+modlog-invite-deleted-title=邀請已刪除
+modlog-member-banned=:hammer: {$userMention} 已被停權。
+modlog-member-banned-title=成員已停權
+modlog-member-joined=:inbox_tray: {$userMention} 加入了伺服器。
+modlog-member-joined-title=成員已加入
+modlog-member-left=:outbox_tray: {$userMention} 離開了伺服器。
+modlog-member-left-title=成員已離開
+modlog-member-unbanned=:unlock: {$userMention} 已被解除停權。
+modlog-member-unbanned-title=成員已解除停權
+modlog-member-updated=:bust_in_silhouette: {$userMention} 已被更新。
+modlog-member-updated-title=成員已更新
+modlog-message-deleted=:wastebasket: {$channelMention} 中的訊息 {$messageId} 已被刪除。
+modlog-message-deleted-cached=:wastebasket: {$userMention} 在 {$channelMention} 中的訊息 {$messageId} 已被刪除：{$content}
+modlog-message-deleted-title=訊息已刪除
+modlog-message-updated=:pencil: {$userMention} 在 {$channelMention} 中的訊息 {$messageId} 已被編輯。
+modlog-message-updated-title=訊息已編輯
+modlog-messages-bulk-deleted=:wastebasket: {$channelMention} 中的 {$count} 則訊息已被刪除。
+modlog-messages-bulk-deleted-title=訊息已批次刪除
+modlog-none=無
+modlog-role-created=:new: 身分組 {$roleMention} 已被建立。
+modlog-role-created-title=身分組已建立
+modlog-role-deleted=:wastebasket: 身分組 {$roleId} 已被刪除。
+modlog-role-deleted-title=身分組已刪除
+modlog-role-updated=:pencil: 身分組 {$roleMention} 已被更新。
+modlog-role-updated-title=身分組已更新
+modlog-unknown-user=未知使用者
+modlog-voice-joined=:loud_sound: {$userMention} 加入了 {$channelMention}。
+modlog-voice-joined-title=已加入語音頻道
+modlog-voice-left=:mute: {$userMention} 離開了語音頻道。
+modlog-voice-left-title=已離開語音頻道
+modlog-voice-moved=:twisted_rightwards_arrows: {$userMention} 移動到了 {$channelMention}。
+modlog-voice-moved-title=已切換語音頻道
@@ -33,9 +33,15 @@ use tokio_rustls::rustls::RootCertStore;
 use tokio_rustls::TlsConnector;
 use webpki_roots::TLS_SERVER_ROOTS;
 
-/// Construct a TLS stream.
+/// Construct a TLS stream to Discord.
 #[allow(clippy::missing_errors_doc)]
 pub async fn tls_stream() -> miette::Result<TlsStream<TcpStream>> {
+    tls_stream_to("discord.com", 443).await
+}
+
+/// Construct a TLS stream to an arbitrary host.
+#[allow(clippy::missing_errors_doc)]
+pub async fn tls_stream_to(host: &str, port: u16) -> miette::Result<TlsStream<TcpStream>> {
     let mut root_cert_store = RootCertStore::empty();
     root_cert_store.extend(TLS_SERVER_ROOTS.iter().cloned());
     let tls_config = Arc::new(
@@ -45,11 +51,9 @@ pub async fn tls_stream() -> miette::Result<TlsStream<TcpStream>> {
     );
 
     let connector = TlsConnector::from(tls_config);
-    let dns_name = ServerName::try_from("discord.com").into_diagnostic()?;
+    let dns_name = ServerName::try_from(host.to_string()).into_diagnostic()?;
 
-    let stream = TcpStream::connect("discord.com:443")
-        .await
-        .into_diagnostic()?;
+    let stream = TcpStream::connect((host, port)).await.into_diagnostic()?;
 
     connector.connect(dns_name, stream).await.into_diagnostic()
 }
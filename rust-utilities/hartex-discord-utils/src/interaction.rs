@@ -28,6 +28,19 @@ use hartex_discord_core::discord::model::http::interaction::InteractionResponse;
 use hartex_discord_core::discord::model::http::interaction::InteractionResponseType;
 use hartex_discord_core::discord::util::builder::InteractionResponseDataBuilder;
 
+/// Constructs a text response.
+#[must_use]
+pub fn content_response(message: impl Into<String>) -> InteractionResponse {
+    InteractionResponse {
+        kind: InteractionResponseType::ChannelMessageWithSource,
+        data: Some(
+            InteractionResponseDataBuilder::new()
+                .content(message)
+                .build(),
+        ),
+    }
+}
+
 /// Constructs an embed response.
 #[must_use]
 pub fn embed_response(embeds: Vec<Embed>) -> InteractionResponse {
@@ -41,7 +41,6 @@ use hartex_discord_core::discord::http::Client;
 use once_cell::sync::Lazy;
 use tokio_postgres::NoTls;
 
-pub mod commands;
 pub mod hyper;
 pub mod interaction;
 pub mod localizable;
@@ -49,10 +48,15 @@ pub mod markdown;
 pub mod postgres;
 
 /// A proxied Discord HTTP cliemt.
+///
+/// The proxy is at `localhost:3000` unless overridden by the `DISCORD_HTTP_PROXY` environment
+/// variable.
 pub static CLIENT: Lazy<Client> = Lazy::new(|| {
+    let proxy = env::var("DISCORD_HTTP_PROXY").unwrap_or_else(|_| String::from("localhost:3000"));
+
     Client::builder()
         .token(TOKEN.deref().to_owned())
-        .proxy(String::from("localhost:3000"), true)
+        .proxy(proxy, true)
         .ratelimiter(None)
         .build()
 });
@@ -43,6 +43,19 @@ return {
         colour = hartexconf.colour.rgb(0x768EE5)
     },
 
+    -- Permission levels, ranging from 0 to 100, used for restricting who can run commands.
+    -- Members are assigned the highest level among the levels of their roles and themselves.
+    permissions = {
+        -- Permission levels of roles, by role id.
+        roles = { ["2000000000000000"] = 50 },
+        -- Permission levels of users, by user id.
+        users = { ["1000000000000000"] = 100 },
+        -- Minimum permission levels required for every command of a plugin, by plugin name.
+        plugins = { management = 50 },
+        -- Minimum permission levels required for a command, by command name; these take precedence over plugins.
+        commands = { infraction = 80 }
+    },
+
     -- Configuration for various plugins.
     plugins = {
         -- Configuration for the Management plugin.
@@ -59,8 +72,8 @@ return {
                 {
                     -- The channel for the logger,
                     channel = "3943943943943943",
-                    -- Array of events this logger listens for.
-                    events = {"MESSAGE_DELETE", "MESSAGE_UPDATE"},
+                    -- Array of events this logger listens for; group aliases such as `ALL_MESSAGE_EVENTS` are also accepted.
+                    events = {"MESSAGE_DELETED", "MESSAGE_UPDATED"},
                     -- Formatting of log messages sent for this logger.
                     -- Options: `pretty` (embeds), `default` (default when unspecified, just text)
                     format = "pretty"